@@ -9,22 +9,42 @@ pub struct Model {
     pub name: String,
     pub icon_hash: Option<String>,
     pub last_sync_at: DateTimeUtc,
+    pub status: String,
+    pub removed_at: Option<DateTimeUtc>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
+    #[sea_orm(has_many = "super::broadcast::Entity")]
+    Broadcast,
     #[sea_orm(has_many = "super::discord_guild_channel::Entity")]
     DiscordGuildChannel,
     #[sea_orm(has_many = "super::discord_guild_member::Entity")]
     DiscordGuildMember,
     #[sea_orm(has_many = "super::discord_guild_role::Entity")]
     DiscordGuildRole,
+    #[sea_orm(has_many = "super::doctrine::Entity")]
+    Doctrine,
     #[sea_orm(has_many = "super::fleet_category::Entity")]
     FleetCategory,
+    #[sea_orm(has_many = "super::fleet_relay::Entity")]
+    FleetRelay,
+    #[sea_orm(has_one = "super::guild_settings::Entity")]
+    GuildSettings,
     #[sea_orm(has_many = "super::ping_format::Entity")]
     PingFormat,
     #[sea_orm(has_many = "super::ping_group::Entity")]
     PingGroup,
+    #[sea_orm(has_many = "super::stand_down::Entity")]
+    StandDown,
+    #[sea_orm(has_many = "super::webhook::Entity")]
+    Webhook,
+}
+
+impl Related<super::broadcast::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Broadcast.def()
+    }
 }
 
 impl Related<super::discord_guild_channel::Entity> for Entity {
@@ -45,12 +65,30 @@ impl Related<super::discord_guild_role::Entity> for Entity {
     }
 }
 
+impl Related<super::doctrine::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Doctrine.def()
+    }
+}
+
 impl Related<super::fleet_category::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::FleetCategory.def()
     }
 }
 
+impl Related<super::fleet_relay::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::FleetRelay.def()
+    }
+}
+
+impl Related<super::guild_settings::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::GuildSettings.def()
+    }
+}
+
 impl Related<super::ping_format::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::PingFormat.def()
@@ -63,4 +101,16 @@ impl Related<super::ping_group::Entity> for Entity {
     }
 }
 
+impl Related<super::stand_down::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::StandDown.def()
+    }
+}
+
+impl Related<super::webhook::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Webhook.def()
+    }
+}
+
 impl ActiveModelBehavior for ActiveModel {}
@@ -9,6 +9,7 @@ pub struct Model {
     pub guild_id: String,
     pub name: String,
     pub position: i32,
+    pub kind: String,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -21,10 +22,18 @@ pub enum Relation {
         on_delete = "Cascade"
     )]
     DiscordGuild,
+    #[sea_orm(has_one = "super::channel_list_settings::Entity")]
+    ChannelListSettings,
     #[sea_orm(has_many = "super::fleet_category_channel::Entity")]
     FleetCategoryChannel,
 }
 
+impl Related<super::channel_list_settings::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ChannelListSettings.def()
+    }
+}
+
 impl Related<super::discord_guild::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::DiscordGuild.def()
@@ -10,6 +10,8 @@ pub struct Model {
     pub name: String,
     pub color: String,
     pub position: i16,
+    pub permissions: String,
+    pub mentionable: bool,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -15,6 +15,7 @@ pub struct Model {
     pub hidden: bool,
     pub disable_reminder: bool,
     pub created_at: DateTimeUtc,
+    pub archived: bool,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -27,10 +28,14 @@ pub enum Relation {
         on_delete = "Cascade"
     )]
     FleetCategory,
+    #[sea_orm(has_many = "super::fleet_dm_reminder::Entity")]
+    FleetDmReminder,
     #[sea_orm(has_many = "super::fleet_field_value::Entity")]
     FleetFieldValue,
     #[sea_orm(has_many = "super::fleet_message::Entity")]
     FleetMessage,
+    #[sea_orm(has_many = "super::fleet_subscription::Entity")]
+    FleetSubscription,
     #[sea_orm(
         belongs_to = "super::user::Entity",
         from = "Column::CommanderId",
@@ -74,4 +79,16 @@ impl Related<super::ping_format_field::Entity> for Entity {
     }
 }
 
+impl Related<super::fleet_dm_reminder::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::FleetDmReminder.def()
+    }
+}
+
+impl Related<super::fleet_subscription::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::FleetSubscription.def()
+    }
+}
+
 impl ActiveModelBehavior for ActiveModel {}
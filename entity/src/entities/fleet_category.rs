@@ -13,10 +13,13 @@ pub struct Model {
     pub ping_reminder: Option<i32>,
     pub max_pre_ping: Option<i32>,
     pub ping_group_id: Option<i32>,
+    pub staging_system_id: Option<i32>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
+    #[sea_orm(has_many = "super::category_subscription::Entity")]
+    CategorySubscription,
     #[sea_orm(
         belongs_to = "super::discord_guild::Entity",
         from = "Column::GuildId",
@@ -33,6 +36,8 @@ pub enum Relation {
     FleetCategoryChannel,
     #[sea_orm(has_many = "super::fleet_category_ping_role::Entity")]
     FleetCategoryPingRole,
+    #[sea_orm(has_many = "super::fleet_template::Entity")]
+    FleetTemplate,
     #[sea_orm(
         belongs_to = "super::ping_format::Entity",
         from = "Column::PingFormatId",
@@ -41,6 +46,8 @@ pub enum Relation {
         on_delete = "Cascade"
     )]
     PingFormat,
+    #[sea_orm(has_many = "super::stand_down_fleet::Entity")]
+    StandDownFleet,
 }
 
 impl Related<super::discord_guild::Entity> for Entity {
@@ -73,12 +80,24 @@ impl Related<super::fleet_category_ping_role::Entity> for Entity {
     }
 }
 
+impl Related<super::fleet_template::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::FleetTemplate.def()
+    }
+}
+
 impl Related<super::ping_format::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::PingFormat.def()
     }
 }
 
+impl Related<super::stand_down_fleet::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::StandDownFleet.def()
+    }
+}
+
 impl Related<super::discord_guild_channel::Entity> for Entity {
     fn to() -> RelationDef {
         super::fleet_category_channel::Relation::DiscordGuildChannel.def()
@@ -92,4 +111,10 @@ impl Related<super::discord_guild_channel::Entity> for Entity {
     }
 }
 
+impl Related<super::category_subscription::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::CategorySubscription.def()
+    }
+}
+
 impl ActiveModelBehavior for ActiveModel {}
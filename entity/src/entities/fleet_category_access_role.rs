@@ -11,6 +11,7 @@ pub struct Model {
     pub can_view: bool,
     pub can_create: bool,
     pub can_manage: bool,
+    pub can_broadcast: bool,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -8,6 +8,7 @@ pub struct Model {
     pub fleet_category_id: i32,
     #[sea_orm(primary_key, auto_increment = false)]
     pub channel_id: String,
+    pub crosspost: bool,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -11,6 +11,7 @@ pub struct Model {
     pub message_id: String,
     pub message_type: String,
     pub created_at: DateTimeUtc,
+    pub thread_id: Option<String>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
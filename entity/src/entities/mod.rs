@@ -2,21 +2,40 @@
 
 pub mod prelude;
 
+pub mod broadcast;
+pub mod category_subscription;
 pub mod channel_fleet_list;
+pub mod channel_list_settings;
 pub mod discord_guild;
 pub mod discord_guild_channel;
 pub mod discord_guild_member;
 pub mod discord_guild_role;
+pub mod doctrine;
+pub mod doctrine_fit;
+pub mod doctrine_fit_module;
 pub mod fleet;
 pub mod fleet_category;
 pub mod fleet_category_access_role;
 pub mod fleet_category_channel;
 pub mod fleet_category_ping_role;
+pub mod fleet_dm_reminder;
 pub mod fleet_field_value;
 pub mod fleet_message;
+pub mod fleet_relay;
+pub mod fleet_subscription;
+pub mod fleet_template;
+pub mod fleet_template_field_value;
+pub mod guild_settings;
+pub mod notification_preference;
 pub mod ping_format;
 pub mod ping_format_field;
 pub mod ping_format_field_value;
 pub mod ping_group;
+pub mod relayed_fleet;
+pub mod stand_down;
+pub mod stand_down_fleet;
+pub mod stand_down_fleet_field_value;
 pub mod user;
 pub mod user_discord_guild_role;
+pub mod webhook;
+pub mod webhook_delivery;
@@ -22,6 +22,8 @@ pub enum Relation {
     DiscordGuild,
     #[sea_orm(has_many = "super::fleet_category::Entity")]
     FleetCategory,
+    #[sea_orm(has_many = "super::guild_settings::Entity")]
+    GuildSettings,
     #[sea_orm(has_many = "super::ping_format_field::Entity")]
     PingFormatField,
 }
@@ -38,6 +40,12 @@ impl Related<super::fleet_category::Entity> for Entity {
     }
 }
 
+impl Related<super::guild_settings::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::GuildSettings.def()
+    }
+}
+
 impl Related<super::ping_format_field::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::PingFormatField.def()
@@ -16,6 +16,8 @@ pub struct Model {
 pub enum Relation {
     #[sea_orm(has_many = "super::fleet_field_value::Entity")]
     FleetFieldValue,
+    #[sea_orm(has_many = "super::fleet_template_field_value::Entity")]
+    FleetTemplateFieldValue,
     #[sea_orm(
         belongs_to = "super::ping_format::Entity",
         from = "Column::PingFormatId",
@@ -26,6 +28,8 @@ pub enum Relation {
     PingFormat,
     #[sea_orm(has_many = "super::ping_format_field_value::Entity")]
     PingFormatFieldValue,
+    #[sea_orm(has_many = "super::stand_down_fleet_field_value::Entity")]
+    StandDownFleetFieldValue,
 }
 
 impl Related<super::fleet_field_value::Entity> for Entity {
@@ -34,6 +38,12 @@ impl Related<super::fleet_field_value::Entity> for Entity {
     }
 }
 
+impl Related<super::fleet_template_field_value::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::FleetTemplateFieldValue.def()
+    }
+}
+
 impl Related<super::ping_format::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::PingFormat.def()
@@ -46,6 +56,12 @@ impl Related<super::ping_format_field_value::Entity> for Entity {
     }
 }
 
+impl Related<super::stand_down_fleet_field_value::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::StandDownFleetFieldValue.def()
+    }
+}
+
 impl Related<super::fleet::Entity> for Entity {
     fn to() -> RelationDef {
         super::fleet_field_value::Relation::Fleet.def()
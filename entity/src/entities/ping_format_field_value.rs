@@ -6,7 +6,7 @@ use sea_orm::entity::prelude::*;
 pub struct Model {
     #[sea_orm(primary_key)]
     pub id: i32,
-    pub ping_format_field_id: String,
+    pub ping_format_field_id: i32,
     pub value: String,
 }
 
@@ -21,6 +21,8 @@ pub enum Relation {
         on_delete = "Cascade"
     )]
     DiscordGuild,
+    #[sea_orm(has_many = "super::stand_down::Entity")]
+    StandDown,
 }
 
 impl Related<super::discord_guild::Entity> for Entity {
@@ -29,4 +31,10 @@ impl Related<super::discord_guild::Entity> for Entity {
     }
 }
 
+impl Related<super::stand_down::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::StandDown.def()
+    }
+}
+
 impl ActiveModelBehavior for ActiveModel {}
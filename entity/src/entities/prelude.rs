@@ -1,20 +1,39 @@
 //! `SeaORM` Entity, @generated by sea-orm-codegen 2.0.0-rc.11
 
+pub use super::broadcast::Entity as Broadcast;
+pub use super::category_subscription::Entity as CategorySubscription;
 pub use super::channel_fleet_list::Entity as ChannelFleetList;
+pub use super::channel_list_settings::Entity as ChannelListSettings;
 pub use super::discord_guild::Entity as DiscordGuild;
 pub use super::discord_guild_channel::Entity as DiscordGuildChannel;
 pub use super::discord_guild_member::Entity as DiscordGuildMember;
 pub use super::discord_guild_role::Entity as DiscordGuildRole;
+pub use super::doctrine::Entity as Doctrine;
+pub use super::doctrine_fit::Entity as DoctrineFit;
+pub use super::doctrine_fit_module::Entity as DoctrineFitModule;
 pub use super::fleet::Entity as Fleet;
 pub use super::fleet_category::Entity as FleetCategory;
 pub use super::fleet_category_access_role::Entity as FleetCategoryAccessRole;
 pub use super::fleet_category_channel::Entity as FleetCategoryChannel;
 pub use super::fleet_category_ping_role::Entity as FleetCategoryPingRole;
+pub use super::fleet_dm_reminder::Entity as FleetDmReminder;
 pub use super::fleet_field_value::Entity as FleetFieldValue;
 pub use super::fleet_message::Entity as FleetMessage;
+pub use super::fleet_relay::Entity as FleetRelay;
+pub use super::fleet_subscription::Entity as FleetSubscription;
+pub use super::fleet_template::Entity as FleetTemplate;
+pub use super::fleet_template_field_value::Entity as FleetTemplateFieldValue;
+pub use super::guild_settings::Entity as GuildSettings;
+pub use super::notification_preference::Entity as NotificationPreference;
 pub use super::ping_format::Entity as PingFormat;
 pub use super::ping_format_field::Entity as PingFormatField;
 pub use super::ping_format_field_value::Entity as PingFormatFieldValue;
 pub use super::ping_group::Entity as PingGroup;
+pub use super::relayed_fleet::Entity as RelayedFleet;
+pub use super::stand_down::Entity as StandDown;
+pub use super::stand_down_fleet::Entity as StandDownFleet;
+pub use super::stand_down_fleet_field_value::Entity as StandDownFleetFieldValue;
 pub use super::user::Entity as User;
 pub use super::user_discord_guild_role::Entity as UserDiscordGuildRole;
+pub use super::webhook::Entity as Webhook;
+pub use super::webhook_delivery::Entity as WebhookDelivery;
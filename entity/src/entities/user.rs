@@ -14,8 +14,16 @@ pub struct Model {
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
+    #[sea_orm(has_many = "super::category_subscription::Entity")]
+    CategorySubscription,
     #[sea_orm(has_many = "super::fleet::Entity")]
     Fleet,
+    #[sea_orm(has_many = "super::fleet_dm_reminder::Entity")]
+    FleetDmReminder,
+    #[sea_orm(has_many = "super::fleet_subscription::Entity")]
+    FleetSubscription,
+    #[sea_orm(has_one = "super::notification_preference::Entity")]
+    NotificationPreference,
     #[sea_orm(has_many = "super::user_discord_guild_role::Entity")]
     UserDiscordGuildRole,
 }
@@ -41,4 +49,28 @@ impl Related<super::discord_guild_role::Entity> for Entity {
     }
 }
 
+impl Related<super::category_subscription::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::CategorySubscription.def()
+    }
+}
+
+impl Related<super::fleet_dm_reminder::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::FleetDmReminder.def()
+    }
+}
+
+impl Related<super::fleet_subscription::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::FleetSubscription.def()
+    }
+}
+
+impl Related<super::notification_preference::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::NotificationPreference.def()
+    }
+}
+
 impl ActiveModelBehavior for ActiveModel {}
@@ -20,6 +20,34 @@ mod m20251223_000017_create_ping_group;
 mod m20251223_000018_add_ping_group_to_fleet_category;
 mod m20251225_000019_add_value_type_to_ping_format_fields;
 mod m20251225_000020_create_ping_format_field_values;
+mod m20251227_000021_create_webhook;
+mod m20251227_000022_create_webhook_delivery;
+mod m20251228_000023_align_postgres_column_types;
+mod m20251229_000024_add_channel_kind;
+mod m20251230_000025_add_guild_status;
+mod m20251231_000026_add_role_permissions;
+mod m20260101_000027_create_category_subscription;
+mod m20260101_000028_create_fleet_subscription;
+mod m20260101_000029_create_notification_preference;
+mod m20260101_000030_create_fleet_dm_reminder;
+mod m20260102_000031_create_fleet_relay;
+mod m20260102_000032_create_relayed_fleet;
+mod m20260103_000033_create_guild_settings;
+mod m20260104_000034_add_channel_list_settings;
+mod m20260105_000035_add_staging_systems;
+mod m20260106_000036_add_downtime_settings;
+mod m20260107_000037_create_doctrine;
+mod m20260107_000038_create_doctrine_fit;
+mod m20260107_000039_create_doctrine_fit_module;
+mod m20260108_000040_create_fleet_template;
+mod m20260108_000041_create_fleet_template_field_value;
+mod m20260109_000042_create_stand_down;
+mod m20260109_000043_create_stand_down_fleet;
+mod m20260109_000044_create_stand_down_fleet_field_value;
+mod m20260110_000045_add_broadcast_permissions;
+mod m20260110_000046_create_broadcast;
+mod m20260111_000047_fix_ping_format_field_value_foreign_key;
+mod m20260111_000048_add_fleet_archived;
 
 pub struct Migrator;
 
@@ -46,7 +74,37 @@ impl MigratorTrait for Migrator {
             Box::new(m20251223_000017_create_ping_group::Migration),
             Box::new(m20251223_000018_add_ping_group_to_fleet_category::Migration),
             Box::new(m20251225_000019_add_value_type_to_ping_format_fields::Migration),
+            // Creates the default values table on new databases before the original
+            // migration, which can't create its foreign key on PostgreSQL
+            Box::new(m20260111_000047_fix_ping_format_field_value_foreign_key::Migration),
             Box::new(m20251225_000020_create_ping_format_field_values::Migration),
+            Box::new(m20251227_000021_create_webhook::Migration),
+            Box::new(m20251227_000022_create_webhook_delivery::Migration),
+            Box::new(m20251228_000023_align_postgres_column_types::Migration),
+            Box::new(m20251229_000024_add_channel_kind::Migration),
+            Box::new(m20251230_000025_add_guild_status::Migration),
+            Box::new(m20251231_000026_add_role_permissions::Migration),
+            Box::new(m20260101_000027_create_category_subscription::Migration),
+            Box::new(m20260101_000028_create_fleet_subscription::Migration),
+            Box::new(m20260101_000029_create_notification_preference::Migration),
+            Box::new(m20260101_000030_create_fleet_dm_reminder::Migration),
+            Box::new(m20260102_000031_create_fleet_relay::Migration),
+            Box::new(m20260102_000032_create_relayed_fleet::Migration),
+            Box::new(m20260103_000033_create_guild_settings::Migration),
+            Box::new(m20260104_000034_add_channel_list_settings::Migration),
+            Box::new(m20260105_000035_add_staging_systems::Migration),
+            Box::new(m20260106_000036_add_downtime_settings::Migration),
+            Box::new(m20260107_000037_create_doctrine::Migration),
+            Box::new(m20260107_000038_create_doctrine_fit::Migration),
+            Box::new(m20260107_000039_create_doctrine_fit_module::Migration),
+            Box::new(m20260108_000040_create_fleet_template::Migration),
+            Box::new(m20260108_000041_create_fleet_template_field_value::Migration),
+            Box::new(m20260109_000042_create_stand_down::Migration),
+            Box::new(m20260109_000043_create_stand_down_fleet::Migration),
+            Box::new(m20260109_000044_create_stand_down_fleet_field_value::Migration),
+            Box::new(m20260110_000045_add_broadcast_permissions::Migration),
+            Box::new(m20260110_000046_create_broadcast::Migration),
+            Box::new(m20260111_000048_add_fleet_archived::Migration),
         ]
     }
 }
@@ -41,4 +41,6 @@ pub enum DiscordGuild {
     Name,
     IconHash,
     LastSyncAt,
+    Status,
+    RemovedAt,
 }
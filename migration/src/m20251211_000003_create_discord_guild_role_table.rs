@@ -49,4 +49,6 @@ pub enum DiscordGuildRole {
     Name,
     Color,
     Position,
+    Permissions,
+    Mentionable,
 }
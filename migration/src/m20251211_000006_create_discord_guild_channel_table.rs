@@ -44,4 +44,5 @@ pub enum DiscordGuildChannel {
     GuildId,
     Name,
     Position,
+    Kind,
 }
@@ -62,4 +62,5 @@ pub enum FleetCategory {
     PingReminder,
     MaxPrePing,
     PingGroupId,
+    StagingSystemId,
 }
@@ -70,4 +70,5 @@ pub enum FleetCategoryAccessRole {
     CanView,
     CanCreate,
     CanManage,
+    CanBroadcast,
 }
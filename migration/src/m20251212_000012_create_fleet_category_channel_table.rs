@@ -57,4 +57,5 @@ pub enum FleetCategoryChannel {
     Table,
     FleetCategoryId,
     ChannelId,
+    Crosspost,
 }
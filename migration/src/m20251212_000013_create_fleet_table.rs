@@ -70,4 +70,5 @@ pub enum Fleet {
     Hidden,
     DisableReminder,
     CreatedAt,
+    Archived,
 }
@@ -100,4 +100,5 @@ pub enum FleetMessage {
     MessageId,
     MessageType,
     CreatedAt,
+    ThreadId,
 }
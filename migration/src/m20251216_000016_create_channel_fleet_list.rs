@@ -53,7 +53,7 @@ impl MigrationTrait for Migration {
 }
 
 #[derive(DeriveIden)]
-enum ChannelFleetList {
+pub enum ChannelFleetList {
     Table,
     Id,
     ChannelId,
@@ -61,4 +61,5 @@ enum ChannelFleetList {
     LastMessageAt,
     CreatedAt,
     UpdatedAt,
+    OverflowMessageIds,
 }
@@ -0,0 +1,59 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use super::m20251211_000002_create_discord_guild_table::DiscordGuild;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Webhook::Table)
+                    .if_not_exists()
+                    .col(pk_auto(Webhook::Id))
+                    .col(string(Webhook::GuildId))
+                    .col(string(Webhook::Name))
+                    .col(string(Webhook::Url))
+                    .col(string(Webhook::Secret))
+                    .col(string(Webhook::Events))
+                    .col(boolean(Webhook::Enabled).default(true))
+                    .col(
+                        timestamp(Webhook::CreatedAt)
+                            .default(Expr::current_timestamp())
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_webhook_guild_id")
+                            .from(Webhook::Table, Webhook::GuildId)
+                            .to(DiscordGuild::Table, DiscordGuild::GuildId)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Webhook::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum Webhook {
+    Table,
+    Id,
+    GuildId,
+    Name,
+    Url,
+    Secret,
+    Events,
+    Enabled,
+    CreatedAt,
+}
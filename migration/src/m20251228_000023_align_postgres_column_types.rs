@@ -0,0 +1,81 @@
+//! Aligns column types with the entities when running on PostgreSQL.
+//!
+//! The entities map timestamps to `DateTime<Utc>` which PostgreSQL only decodes from
+//! `timestamp with time zone` columns, while the earlier migrations create plain
+//! `timestamp` columns. `ping_format_field.ping_format_id` was also created as a
+//! `bigint` while the entity & referenced primary key are 32-bit integers. SQLite
+//! doesn't enforce declared column types, so this migration is a no-op there.
+
+use sea_orm_migration::{prelude::*, sea_orm::DatabaseBackend};
+
+/// Timestamp columns created by earlier migrations as `(table, column)` pairs
+const TIMESTAMP_COLUMNS: [(&str, &str); 13] = [
+    ("user", "last_guild_sync_at"),
+    ("user", "last_role_sync_at"),
+    ("discord_guild", "last_sync_at"),
+    ("fleet", "fleet_time"),
+    ("fleet", "created_at"),
+    ("fleet_message", "created_at"),
+    ("channel_fleet_list", "last_message_at"),
+    ("channel_fleet_list", "created_at"),
+    ("channel_fleet_list", "updated_at"),
+    ("webhook", "created_at"),
+    ("webhook_delivery", "next_attempt_at"),
+    ("webhook_delivery", "last_attempt_at"),
+    ("webhook_delivery", "created_at"),
+];
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if manager.get_database_backend() != DatabaseBackend::Postgres {
+            return Ok(());
+        }
+
+        // Existing values were written as UTC, interpret them as such during conversion
+        for (table, column) in TIMESTAMP_COLUMNS {
+            manager
+                .get_connection()
+                .execute_unprepared(&format!(
+                    r#"ALTER TABLE "{table}" ALTER COLUMN "{column}" TYPE TIMESTAMP WITH TIME ZONE USING "{column}" AT TIME ZONE 'UTC'"#
+                ))
+                .await?;
+        }
+
+        manager
+            .get_connection()
+            .execute_unprepared(
+                r#"ALTER TABLE "ping_format_field" ALTER COLUMN "ping_format_id" TYPE INTEGER"#,
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if manager.get_database_backend() != DatabaseBackend::Postgres {
+            return Ok(());
+        }
+
+        manager
+            .get_connection()
+            .execute_unprepared(
+                r#"ALTER TABLE "ping_format_field" ALTER COLUMN "ping_format_id" TYPE BIGINT"#,
+            )
+            .await?;
+
+        for (table, column) in TIMESTAMP_COLUMNS {
+            manager
+                .get_connection()
+                .execute_unprepared(&format!(
+                    r#"ALTER TABLE "{table}" ALTER COLUMN "{column}" TYPE TIMESTAMP WITHOUT TIME ZONE USING "{column}" AT TIME ZONE 'UTC'"#
+                ))
+                .await?;
+        }
+
+        Ok(())
+    }
+}
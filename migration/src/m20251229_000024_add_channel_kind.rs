@@ -0,0 +1,80 @@
+//! Supports announcement & forum channels as fleet notification targets.
+//!
+//! Adds the channel type to synced guild channels, an opt-in to crosspost messages
+//! sent to announcement channels and the forum post thread a fleet message was sent in.
+
+use sea_orm_migration::{prelude::*, schema::*};
+
+use super::{
+    m20251211_000006_create_discord_guild_channel_table::DiscordGuildChannel,
+    m20251212_000012_create_fleet_category_channel_table::FleetCategoryChannel,
+    m20251212_000015_create_fleet_message_table::FleetMessage,
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(DiscordGuildChannel::Table)
+                    .add_column(string(DiscordGuildChannel::Kind).default("text"))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(FleetCategoryChannel::Table)
+                    .add_column(boolean(FleetCategoryChannel::Crosspost).default(false))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(FleetMessage::Table)
+                    .add_column(string_null(FleetMessage::ThreadId))
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(FleetMessage::Table)
+                    .drop_column(FleetMessage::ThreadId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(FleetCategoryChannel::Table)
+                    .drop_column(FleetCategoryChannel::Crosspost)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(DiscordGuildChannel::Table)
+                    .drop_column(DiscordGuildChannel::Kind)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
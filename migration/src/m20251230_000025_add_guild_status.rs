@@ -0,0 +1,59 @@
+//! Tracks whether the bot is still a member of each Discord guild.
+//!
+//! Adds the guild status (`active`, `removed` or `rejoined`) and when the bot was
+//! removed from the guild, allowing a removed guild's data to be kept until an admin
+//! restores or purges it.
+
+use sea_orm_migration::{prelude::*, schema::*};
+
+use super::m20251211_000002_create_discord_guild_table::DiscordGuild;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(DiscordGuild::Table)
+                    .add_column(string(DiscordGuild::Status).default("active"))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(DiscordGuild::Table)
+                    .add_column(timestamp_with_time_zone_null(DiscordGuild::RemovedAt))
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(DiscordGuild::Table)
+                    .drop_column(DiscordGuild::RemovedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(DiscordGuild::Table)
+                    .drop_column(DiscordGuild::Status)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
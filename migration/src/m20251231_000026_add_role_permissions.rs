@@ -0,0 +1,59 @@
+//! Stores the permissions and mentionability of each synced Discord role.
+//!
+//! Used to diagnose whether the bot can send, embed and mention in the channels
+//! configured on fleet categories and whether configured ping roles can be pinged.
+//! Existing roles are filled in by the next guild sync.
+
+use sea_orm_migration::{prelude::*, schema::*};
+
+use super::m20251211_000003_create_discord_guild_role_table::DiscordGuildRole;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(DiscordGuildRole::Table)
+                    .add_column(string(DiscordGuildRole::Permissions).default("0"))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(DiscordGuildRole::Table)
+                    .add_column(boolean(DiscordGuildRole::Mentionable).default(false))
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(DiscordGuildRole::Table)
+                    .drop_column(DiscordGuildRole::Mentionable)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(DiscordGuildRole::Table)
+                    .drop_column(DiscordGuildRole::Permissions)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,68 @@
+//! Stores the fleet categories users subscribed to for direct message notifications.
+
+use sea_orm_migration::{prelude::*, schema::*};
+
+use super::m20251210_000001_create_user_table::User;
+use super::m20251212_000009_create_fleet_category_table::FleetCategory;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(CategorySubscription::Table)
+                    .if_not_exists()
+                    .col(string(CategorySubscription::UserId))
+                    .col(integer(CategorySubscription::CategoryId))
+                    .col(
+                        timestamp_with_time_zone(CategorySubscription::CreatedAt)
+                            .default(Expr::current_timestamp())
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_category_subscription_user_id")
+                            .from(CategorySubscription::Table, CategorySubscription::UserId)
+                            .to(User::Table, User::DiscordId)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_category_subscription_category_id")
+                            .from(
+                                CategorySubscription::Table,
+                                CategorySubscription::CategoryId,
+                            )
+                            .to(FleetCategory::Table, FleetCategory::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .primary_key(
+                        Index::create()
+                            .col(CategorySubscription::UserId)
+                            .col(CategorySubscription::CategoryId),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(CategorySubscription::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum CategorySubscription {
+    Table,
+    UserId,
+    CategoryId,
+    CreatedAt,
+}
@@ -0,0 +1,65 @@
+//! Stores the individual fleets users subscribed to for direct message notifications.
+
+use sea_orm_migration::{prelude::*, schema::*};
+
+use super::m20251210_000001_create_user_table::User;
+use super::m20251212_000013_create_fleet_table::Fleet;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(FleetSubscription::Table)
+                    .if_not_exists()
+                    .col(string(FleetSubscription::UserId))
+                    .col(integer(FleetSubscription::FleetId))
+                    .col(
+                        timestamp_with_time_zone(FleetSubscription::CreatedAt)
+                            .default(Expr::current_timestamp())
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_fleet_subscription_user_id")
+                            .from(FleetSubscription::Table, FleetSubscription::UserId)
+                            .to(User::Table, User::DiscordId)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_fleet_subscription_fleet_id")
+                            .from(FleetSubscription::Table, FleetSubscription::FleetId)
+                            .to(Fleet::Table, Fleet::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .primary_key(
+                        Index::create()
+                            .col(FleetSubscription::UserId)
+                            .col(FleetSubscription::FleetId),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(FleetSubscription::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum FleetSubscription {
+    Table,
+    UserId,
+    FleetId,
+    CreatedAt,
+}
@@ -0,0 +1,63 @@
+//! Stores the direct message notification preferences of each user.
+//!
+//! Users without a row use the defaults: direct messages enabled for every
+//! notification type and no personal reminder.
+
+use sea_orm_migration::{prelude::*, schema::*};
+
+use super::m20251210_000001_create_user_table::User;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(NotificationPreference::Table)
+                    .if_not_exists()
+                    .col(string(NotificationPreference::UserId).primary_key())
+                    .col(boolean(NotificationPreference::DmEnabled).default(true))
+                    .col(boolean(NotificationPreference::NotifyCreated).default(true))
+                    .col(boolean(NotificationPreference::NotifyTimeChanged).default(true))
+                    .col(boolean(NotificationPreference::NotifyCancelled).default(true))
+                    .col(integer_null(NotificationPreference::ReminderMinutes))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_notification_preference_user_id")
+                            .from(
+                                NotificationPreference::Table,
+                                NotificationPreference::UserId,
+                            )
+                            .to(User::Table, User::DiscordId)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(NotificationPreference::Table)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum NotificationPreference {
+    Table,
+    UserId,
+    DmEnabled,
+    NotifyCreated,
+    NotifyTimeChanged,
+    NotifyCancelled,
+    ReminderMinutes,
+}
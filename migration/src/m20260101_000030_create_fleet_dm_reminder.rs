@@ -0,0 +1,68 @@
+//! Tracks the personal reminders sent to subscribers by direct message.
+//!
+//! Rows are removed when the fleet time changes so the reminder is sent again
+//! ahead of the new time.
+
+use sea_orm_migration::{prelude::*, schema::*};
+
+use super::m20251210_000001_create_user_table::User;
+use super::m20251212_000013_create_fleet_table::Fleet;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(FleetDmReminder::Table)
+                    .if_not_exists()
+                    .col(integer(FleetDmReminder::FleetId))
+                    .col(string(FleetDmReminder::UserId))
+                    .col(
+                        timestamp_with_time_zone(FleetDmReminder::SentAt)
+                            .default(Expr::current_timestamp())
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_fleet_dm_reminder_fleet_id")
+                            .from(FleetDmReminder::Table, FleetDmReminder::FleetId)
+                            .to(Fleet::Table, Fleet::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_fleet_dm_reminder_user_id")
+                            .from(FleetDmReminder::Table, FleetDmReminder::UserId)
+                            .to(User::Table, User::DiscordId)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .primary_key(
+                        Index::create()
+                            .col(FleetDmReminder::FleetId)
+                            .col(FleetDmReminder::UserId),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(FleetDmReminder::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum FleetDmReminder {
+    Table,
+    FleetId,
+    UserId,
+    SentAt,
+}
@@ -0,0 +1,99 @@
+//! Stores the relay links forwarding the fleets of a category to a category of
+//! another guild, along with the target guild's answer to the relay request.
+
+use sea_orm_migration::{prelude::*, schema::*};
+
+use super::m20251211_000002_create_discord_guild_table::DiscordGuild;
+use super::m20251212_000009_create_fleet_category_table::FleetCategory;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(FleetRelay::Table)
+                    .if_not_exists()
+                    .col(pk_auto(FleetRelay::Id))
+                    .col(integer(FleetRelay::SourceCategoryId))
+                    .col(string(FleetRelay::TargetGuildId))
+                    .col(integer_null(FleetRelay::TargetCategoryId))
+                    .col(string(FleetRelay::Status).default("pending"))
+                    .col(
+                        timestamp_with_time_zone(FleetRelay::CreatedAt)
+                            .default(Expr::current_timestamp())
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_fleet_relay_source_category_id")
+                            .from(FleetRelay::Table, FleetRelay::SourceCategoryId)
+                            .to(FleetCategory::Table, FleetCategory::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_fleet_relay_target_guild_id")
+                            .from(FleetRelay::Table, FleetRelay::TargetGuildId)
+                            .to(DiscordGuild::Table, DiscordGuild::GuildId)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_fleet_relay_target_category_id")
+                            .from(FleetRelay::Table, FleetRelay::TargetCategoryId)
+                            .to(FleetCategory::Table, FleetCategory::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // One relay request per category & target guild
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_fleet_relay_source_target")
+                    .table(FleetRelay::Table)
+                    .col(FleetRelay::SourceCategoryId)
+                    .col(FleetRelay::TargetGuildId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_fleet_relay_source_target")
+                    .table(FleetRelay::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(FleetRelay::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum FleetRelay {
+    Table,
+    Id,
+    SourceCategoryId,
+    TargetGuildId,
+    TargetCategoryId,
+    Status,
+    CreatedAt,
+}
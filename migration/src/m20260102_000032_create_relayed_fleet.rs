@@ -0,0 +1,65 @@
+//! Links the read-only fleet copies posted in a target guild to the fleet they were
+//! relayed from so edits & cancellations can be propagated.
+
+use sea_orm_migration::{prelude::*, schema::*};
+
+use super::m20251212_000013_create_fleet_table::Fleet;
+use super::m20260102_000031_create_fleet_relay::FleetRelay;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(RelayedFleet::Table)
+                    .if_not_exists()
+                    .col(integer(RelayedFleet::FleetId).primary_key())
+                    .col(integer(RelayedFleet::SourceFleetId))
+                    .col(integer(RelayedFleet::RelayId))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_relayed_fleet_fleet_id")
+                            .from(RelayedFleet::Table, RelayedFleet::FleetId)
+                            .to(Fleet::Table, Fleet::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_relayed_fleet_source_fleet_id")
+                            .from(RelayedFleet::Table, RelayedFleet::SourceFleetId)
+                            .to(Fleet::Table, Fleet::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_relayed_fleet_relay_id")
+                            .from(RelayedFleet::Table, RelayedFleet::RelayId)
+                            .to(FleetRelay::Table, FleetRelay::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(RelayedFleet::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum RelayedFleet {
+    Table,
+    FleetId,
+    SourceFleetId,
+    RelayId,
+}
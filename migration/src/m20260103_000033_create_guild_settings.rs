@@ -0,0 +1,82 @@
+//! Stores the display & behaviour settings of each guild.
+//!
+//! Guilds without a row use the defaults: the built-in embed colours, no extra
+//! timezones, every upcoming fleet in lists and a 5 minute form-up catch-up window.
+
+use sea_orm_migration::{prelude::*, schema::*};
+
+use super::m20251211_000002_create_discord_guild_table::DiscordGuild;
+use super::m20251212_000007_create_ping_format_table::PingFormat;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(GuildSettings::Table)
+                    .if_not_exists()
+                    .col(string(GuildSettings::GuildId).primary_key())
+                    .col(integer(GuildSettings::CreationColor))
+                    .col(integer(GuildSettings::UpdateColor))
+                    .col(integer(GuildSettings::ReminderColor))
+                    .col(integer(GuildSettings::FormupColor))
+                    .col(integer(GuildSettings::CancelColor))
+                    .col(integer(GuildSettings::ListColor))
+                    .col(string(GuildSettings::TimeLabel))
+                    .col(string(GuildSettings::ExtraTimezones).default(""))
+                    .col(integer_null(GuildSettings::ListLookaheadHours))
+                    .col(integer(GuildSettings::FormupCatchupMinutes))
+                    .col(integer_null(GuildSettings::DefaultPingFormatId))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_guild_settings_guild_id")
+                            .from(GuildSettings::Table, GuildSettings::GuildId)
+                            .to(DiscordGuild::Table, DiscordGuild::GuildId)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_guild_settings_default_ping_format_id")
+                            .from(GuildSettings::Table, GuildSettings::DefaultPingFormatId)
+                            .to(PingFormat::Table, PingFormat::Id)
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(GuildSettings::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum GuildSettings {
+    Table,
+    GuildId,
+    CreationColor,
+    UpdateColor,
+    ReminderColor,
+    FormupColor,
+    CancelColor,
+    ListColor,
+    TimeLabel,
+    ExtraTimezones,
+    ListLookaheadHours,
+    FormupCatchupMinutes,
+    DefaultPingFormatId,
+    StagingSystemId,
+    DowntimeStartMinute,
+    DowntimeDurationMinutes,
+    DowntimePolicy,
+    BroadcastRoleIds,
+}
@@ -0,0 +1,82 @@
+//! Makes the upcoming fleets list configurable per channel & able to span messages.
+//!
+//! Adds the list settings of a channel and the IDs of the additional messages a long
+//! upcoming fleets list is continued in. Channels without settings list every upcoming
+//! fleet in a single flat list as before.
+
+use sea_orm_migration::{prelude::*, schema::*};
+
+use super::{
+    m20251211_000006_create_discord_guild_channel_table::DiscordGuildChannel,
+    m20251216_000016_create_channel_fleet_list::ChannelFleetList,
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(ChannelListSettings::Table)
+                    .if_not_exists()
+                    .col(string(ChannelListSettings::ChannelId).primary_key())
+                    .col(integer_null(ChannelListSettings::LookaheadHours))
+                    .col(integer_null(ChannelListSettings::MaxEntries))
+                    .col(boolean(ChannelListSettings::GroupByDay).default(false))
+                    .col(boolean(ChannelListSettings::SplitByCategory).default(false))
+                    .col(boolean(ChannelListSettings::ShowRevealedHidden).default(false))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_channel_list_settings_channel_id")
+                            .from(ChannelListSettings::Table, ChannelListSettings::ChannelId)
+                            .to(DiscordGuildChannel::Table, DiscordGuildChannel::ChannelId)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(ChannelFleetList::Table)
+                    .add_column(string(ChannelFleetList::OverflowMessageIds).default(""))
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(ChannelFleetList::Table)
+                    .drop_column(ChannelFleetList::OverflowMessageIds)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(ChannelListSettings::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum ChannelListSettings {
+    Table,
+    ChannelId,
+    LookaheadHours,
+    MaxEntries,
+    GroupByDay,
+    SplitByCategory,
+    ShowRevealedHidden,
+}
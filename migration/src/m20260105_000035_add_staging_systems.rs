@@ -0,0 +1,61 @@
+//! Stores the staging system of each guild & the staging override of each category.
+//!
+//! Fleets forming up in a solar system show the stargate route from this system. The
+//! columns hold solar system IDs of the bundled EVE static map rather than foreign keys.
+
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::{
+    m20251212_000009_create_fleet_category_table::FleetCategory,
+    m20260103_000033_create_guild_settings::GuildSettings,
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(GuildSettings::Table)
+                    .add_column(integer_null(GuildSettings::StagingSystemId))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(FleetCategory::Table)
+                    .add_column(integer_null(FleetCategory::StagingSystemId))
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(FleetCategory::Table)
+                    .drop_column(FleetCategory::StagingSystemId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(GuildSettings::Table)
+                    .drop_column(GuildSettings::StagingSystemId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
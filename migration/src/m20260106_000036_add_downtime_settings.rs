@@ -0,0 +1,65 @@
+//! Stores the EVE downtime window of each guild & how fleets scheduled during it are
+//! handled.
+//!
+//! Existing guilds default to the regular 11:00-11:15 UTC downtime with a warning when a
+//! fleet is scheduled into it.
+
+use sea_orm_migration::{prelude::*, schema::*};
+
+use super::m20260103_000033_create_guild_settings::GuildSettings;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(GuildSettings::Table)
+                    .add_column(integer(GuildSettings::DowntimeStartMinute).default(11 * 60))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(GuildSettings::Table)
+                    .add_column(integer(GuildSettings::DowntimeDurationMinutes).default(15))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(GuildSettings::Table)
+                    .add_column(string(GuildSettings::DowntimePolicy).default("warn"))
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        for column in [
+            GuildSettings::DowntimePolicy,
+            GuildSettings::DowntimeDurationMinutes,
+            GuildSettings::DowntimeStartMinute,
+        ] {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(GuildSettings::Table)
+                        .drop_column(column)
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+}
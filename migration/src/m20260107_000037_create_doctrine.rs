@@ -0,0 +1,76 @@
+//! Stores the doctrine library of each guild.
+//!
+//! A doctrine is a named set of ship fits, its fits are stored in `doctrine_fit`.
+//! Doctrine ping format fields reference doctrines by ID.
+
+use sea_orm_migration::{prelude::*, schema::*};
+
+use super::m20251211_000002_create_discord_guild_table::DiscordGuild;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Doctrine::Table)
+                    .if_not_exists()
+                    .col(pk_auto(Doctrine::Id))
+                    .col(string(Doctrine::GuildId))
+                    .col(string(Doctrine::Name))
+                    .col(text_null(Doctrine::Description))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_doctrine_guild_id")
+                            .from(Doctrine::Table, Doctrine::GuildId)
+                            .to(DiscordGuild::Table, DiscordGuild::GuildId)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Doctrine names are unique within a guild so pings can refer to them by name
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_doctrine_guild_id_name")
+                    .table(Doctrine::Table)
+                    .col(Doctrine::GuildId)
+                    .col(Doctrine::Name)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_doctrine_guild_id_name")
+                    .table(Doctrine::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(Doctrine::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum Doctrine {
+    Table,
+    Id,
+    GuildId,
+    Name,
+    Description,
+}
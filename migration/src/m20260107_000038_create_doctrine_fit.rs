@@ -0,0 +1,54 @@
+//! Stores the ship fits of each doctrine.
+//!
+//! Fits are pasted in EFT format and stored parsed, the hull here & the modules in
+//! `doctrine_fit_module`. `position` keeps the order the fits were pasted in.
+
+use sea_orm_migration::{prelude::*, schema::*};
+
+use super::m20260107_000037_create_doctrine::Doctrine;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(DoctrineFit::Table)
+                    .if_not_exists()
+                    .col(pk_auto(DoctrineFit::Id))
+                    .col(integer(DoctrineFit::DoctrineId))
+                    .col(string(DoctrineFit::Name))
+                    .col(string(DoctrineFit::Hull))
+                    .col(integer(DoctrineFit::Position))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_doctrine_fit_doctrine_id")
+                            .from(DoctrineFit::Table, DoctrineFit::DoctrineId)
+                            .to(Doctrine::Table, Doctrine::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(DoctrineFit::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum DoctrineFit {
+    Table,
+    Id,
+    DoctrineId,
+    Name,
+    Hull,
+    Position,
+}
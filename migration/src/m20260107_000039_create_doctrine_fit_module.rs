@@ -0,0 +1,61 @@
+//! Stores the modules, drones & cargo of each doctrine fit.
+//!
+//! `section` is the index of the blank line separated EFT block the item was listed in
+//! (low, mid & high slots, rigs, subsystems, drones, cargo) so the fit can be exported
+//! again in the same layout. Empty slot markers aren't stored.
+
+use sea_orm_migration::{prelude::*, schema::*};
+
+use super::m20260107_000038_create_doctrine_fit::DoctrineFit;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(DoctrineFitModule::Table)
+                    .if_not_exists()
+                    .col(pk_auto(DoctrineFitModule::Id))
+                    .col(integer(DoctrineFitModule::FitId))
+                    .col(integer(DoctrineFitModule::Section))
+                    .col(integer(DoctrineFitModule::Position))
+                    .col(string(DoctrineFitModule::Name))
+                    .col(string_null(DoctrineFitModule::Charge))
+                    .col(integer(DoctrineFitModule::Quantity).default(1))
+                    .col(boolean(DoctrineFitModule::Offline).default(false))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_doctrine_fit_module_fit_id")
+                            .from(DoctrineFitModule::Table, DoctrineFitModule::FitId)
+                            .to(DoctrineFit::Table, DoctrineFit::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(DoctrineFitModule::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum DoctrineFitModule {
+    Table,
+    Id,
+    FitId,
+    Section,
+    Position,
+    Name,
+    Charge,
+    Quantity,
+    Offline,
+}
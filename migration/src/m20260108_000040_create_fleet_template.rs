@@ -0,0 +1,96 @@
+//! Stores the fleet templates FCs prefill the fleet creation form with.
+//!
+//! A template holds everything of a fleet but its time. Templates are private to the user
+//! who saved them unless shared, shared templates can be used by everyone able to create
+//! fleets in the template's category. Field values are stored in
+//! `fleet_template_field_value`.
+
+use sea_orm_migration::{prelude::*, schema::*};
+
+use super::{
+    m20251210_000001_create_user_table::User,
+    m20251212_000009_create_fleet_category_table::FleetCategory,
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(FleetTemplate::Table)
+                    .if_not_exists()
+                    .col(pk_auto(FleetTemplate::Id))
+                    .col(integer(FleetTemplate::CategoryId))
+                    .col(string(FleetTemplate::OwnerId))
+                    .col(string(FleetTemplate::Name))
+                    .col(boolean(FleetTemplate::Shared).default(false).not_null())
+                    .col(string(FleetTemplate::FleetName))
+                    .col(string_null(FleetTemplate::CommanderId))
+                    .col(text_null(FleetTemplate::Description))
+                    .col(boolean(FleetTemplate::Hidden).default(false).not_null())
+                    .col(
+                        boolean(FleetTemplate::DisableReminder)
+                            .default(false)
+                            .not_null(),
+                    )
+                    .col(
+                        timestamp_with_time_zone(FleetTemplate::CreatedAt)
+                            .default(Expr::current_timestamp())
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_fleet_template_category_id")
+                            .from(FleetTemplate::Table, FleetTemplate::CategoryId)
+                            .to(FleetCategory::Table, FleetCategory::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_fleet_template_owner_id")
+                            .from(FleetTemplate::Table, FleetTemplate::OwnerId)
+                            .to(User::Table, User::DiscordId)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    // Keep the template when its commander leaves, it's used without one
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_fleet_template_commander_id")
+                            .from(FleetTemplate::Table, FleetTemplate::CommanderId)
+                            .to(User::Table, User::DiscordId)
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(FleetTemplate::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum FleetTemplate {
+    Table,
+    Id,
+    CategoryId,
+    OwnerId,
+    Name,
+    Shared,
+    FleetName,
+    CommanderId,
+    Description,
+    Hidden,
+    DisableReminder,
+    CreatedAt,
+}
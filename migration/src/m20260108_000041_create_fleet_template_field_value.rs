@@ -0,0 +1,73 @@
+//! Stores the ping format field values of fleet templates, keyed like fleet field values.
+
+use sea_orm_migration::{prelude::*, schema::*};
+
+use super::{
+    m20251212_000008_create_ping_format_fields_table::PingFormatField,
+    m20260108_000040_create_fleet_template::FleetTemplate,
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(FleetTemplateFieldValue::Table)
+                    .if_not_exists()
+                    .col(integer(FleetTemplateFieldValue::TemplateId))
+                    .col(integer(FleetTemplateFieldValue::FieldId))
+                    .col(string(FleetTemplateFieldValue::Value))
+                    .primary_key(
+                        Index::create()
+                            .col(FleetTemplateFieldValue::TemplateId)
+                            .col(FleetTemplateFieldValue::FieldId),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_fleet_template_field_value_template_id")
+                            .from(
+                                FleetTemplateFieldValue::Table,
+                                FleetTemplateFieldValue::TemplateId,
+                            )
+                            .to(FleetTemplate::Table, FleetTemplate::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_fleet_template_field_value_field_id")
+                            .from(
+                                FleetTemplateFieldValue::Table,
+                                FleetTemplateFieldValue::FieldId,
+                            )
+                            .to(PingFormatField::Table, PingFormatField::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(FleetTemplateFieldValue::Table)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum FleetTemplateFieldValue {
+    Table,
+    TemplateId,
+    FieldId,
+    Value,
+}
@@ -0,0 +1,99 @@
+//! Stores emergency stand-downs which cancelled every fleet of a guild or ping group
+//! within a time window.
+//!
+//! The cancelled fleets are snapshotted in `stand_down_fleet` so the stand-down can be
+//! resumed later, `resumed_at` is set once that happened.
+
+use sea_orm_migration::{prelude::*, schema::*};
+
+use super::{
+    m20251210_000001_create_user_table::User,
+    m20251211_000002_create_discord_guild_table::DiscordGuild,
+    m20251223_000017_create_ping_group::PingGroup,
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(StandDown::Table)
+                    .if_not_exists()
+                    .col(pk_auto(StandDown::Id))
+                    .col(string(StandDown::GuildId))
+                    .col(integer_null(StandDown::PingGroupId))
+                    .col(text(StandDown::Reason))
+                    .col(timestamp_with_time_zone(StandDown::WindowStart))
+                    .col(timestamp_with_time_zone(StandDown::WindowEnd))
+                    .col(string_null(StandDown::CreatedBy))
+                    .col(
+                        timestamp_with_time_zone(StandDown::CreatedAt)
+                            .default(Expr::current_timestamp())
+                            .not_null(),
+                    )
+                    .col(timestamp_with_time_zone_null(StandDown::ResumedAt))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_stand_down_guild_id")
+                            .from(StandDown::Table, StandDown::GuildId)
+                            .to(DiscordGuild::Table, DiscordGuild::GuildId)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    // Keep the stand-down when its ping group is removed, it then applied
+                    // to the categories the group had at the time
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_stand_down_ping_group_id")
+                            .from(StandDown::Table, StandDown::PingGroupId)
+                            .to(PingGroup::Table, PingGroup::Id)
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_stand_down_created_by")
+                            .from(StandDown::Table, StandDown::CreatedBy)
+                            .to(User::Table, User::DiscordId)
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_stand_down_guild_id")
+                    .table(StandDown::Table)
+                    .col(StandDown::GuildId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(StandDown::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum StandDown {
+    Table,
+    Id,
+    GuildId,
+    PingGroupId,
+    Reason,
+    WindowStart,
+    WindowEnd,
+    CreatedBy,
+    CreatedAt,
+    ResumedAt,
+}
@@ -0,0 +1,84 @@
+//! Stores snapshots of the fleets cancelled by a stand-down so resuming it can recreate
+//! them. Field values are stored in `stand_down_fleet_field_value`.
+
+use sea_orm_migration::{prelude::*, schema::*};
+
+use super::{
+    m20251210_000001_create_user_table::User,
+    m20251212_000009_create_fleet_category_table::FleetCategory,
+    m20260109_000042_create_stand_down::StandDown,
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(StandDownFleet::Table)
+                    .if_not_exists()
+                    .col(pk_auto(StandDownFleet::Id))
+                    .col(integer(StandDownFleet::StandDownId))
+                    .col(integer(StandDownFleet::CategoryId))
+                    .col(string(StandDownFleet::Name))
+                    .col(string(StandDownFleet::CommanderId))
+                    .col(timestamp_with_time_zone(StandDownFleet::FleetTime))
+                    .col(text_null(StandDownFleet::Description))
+                    .col(boolean(StandDownFleet::Hidden).default(false).not_null())
+                    .col(
+                        boolean(StandDownFleet::DisableReminder)
+                            .default(false)
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_stand_down_fleet_stand_down_id")
+                            .from(StandDownFleet::Table, StandDownFleet::StandDownId)
+                            .to(StandDown::Table, StandDown::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_stand_down_fleet_category_id")
+                            .from(StandDownFleet::Table, StandDownFleet::CategoryId)
+                            .to(FleetCategory::Table, FleetCategory::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_stand_down_fleet_commander_id")
+                            .from(StandDownFleet::Table, StandDownFleet::CommanderId)
+                            .to(User::Table, User::DiscordId)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(StandDownFleet::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum StandDownFleet {
+    Table,
+    Id,
+    StandDownId,
+    CategoryId,
+    Name,
+    CommanderId,
+    FleetTime,
+    Description,
+    Hidden,
+    DisableReminder,
+}
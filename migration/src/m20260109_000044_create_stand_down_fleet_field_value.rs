@@ -0,0 +1,73 @@
+//! Stores the ping format field values of fleets snapshotted by a stand-down.
+
+use sea_orm_migration::{prelude::*, schema::*};
+
+use super::{
+    m20251212_000008_create_ping_format_fields_table::PingFormatField,
+    m20260109_000043_create_stand_down_fleet::StandDownFleet,
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(StandDownFleetFieldValue::Table)
+                    .if_not_exists()
+                    .col(integer(StandDownFleetFieldValue::StandDownFleetId))
+                    .col(integer(StandDownFleetFieldValue::FieldId))
+                    .col(string(StandDownFleetFieldValue::Value))
+                    .primary_key(
+                        Index::create()
+                            .col(StandDownFleetFieldValue::StandDownFleetId)
+                            .col(StandDownFleetFieldValue::FieldId),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_stand_down_fleet_field_value_stand_down_fleet_id")
+                            .from(
+                                StandDownFleetFieldValue::Table,
+                                StandDownFleetFieldValue::StandDownFleetId,
+                            )
+                            .to(StandDownFleet::Table, StandDownFleet::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_stand_down_fleet_field_value_field_id")
+                            .from(
+                                StandDownFleetFieldValue::Table,
+                                StandDownFleetFieldValue::FieldId,
+                            )
+                            .to(PingFormatField::Table, PingFormatField::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(StandDownFleetFieldValue::Table)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum StandDownFleetFieldValue {
+    Table,
+    StandDownFleetId,
+    FieldId,
+    Value,
+}
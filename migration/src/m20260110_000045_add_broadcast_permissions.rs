@@ -0,0 +1,63 @@
+//! Stores who can post ad-hoc broadcast pings.
+//!
+//! Access roles with `can_broadcast` can broadcast to the channels & ping roles of their
+//! category. Roles listed in the guild's `broadcast_role_ids` can broadcast to those of
+//! every category of the guild. The role IDs are stored comma-separated like the extra
+//! timezones of the guild settings.
+
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::{
+    m20251212_000010_create_fleet_category_access_role_table::FleetCategoryAccessRole,
+    m20260103_000033_create_guild_settings::GuildSettings,
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(FleetCategoryAccessRole::Table)
+                    .add_column(boolean(FleetCategoryAccessRole::CanBroadcast).default(false))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(GuildSettings::Table)
+                    .add_column(string(GuildSettings::BroadcastRoleIds).default(""))
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(GuildSettings::Table)
+                    .drop_column(GuildSettings::BroadcastRoleIds)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(FleetCategoryAccessRole::Table)
+                    .drop_column(FleetCategoryAccessRole::CanBroadcast)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
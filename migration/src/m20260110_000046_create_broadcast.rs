@@ -0,0 +1,106 @@
+//! Stores ad-hoc broadcast pings posted without a fleet, e.g. "CTA now, undock in 10".
+//!
+//! Broadcasts are posted right away or at `scheduled_at`, `sent_at` is set once they were
+//! posted & `cancelled_at` when a scheduled broadcast was cancelled before that. The
+//! target channel & ping role IDs are stored comma-separated, as they only need to be
+//! read back together with the broadcast, and are kept as the history log.
+
+use sea_orm_migration::{prelude::*, schema::*};
+
+use super::{
+    m20251210_000001_create_user_table::User,
+    m20251211_000002_create_discord_guild_table::DiscordGuild,
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Broadcast::Table)
+                    .if_not_exists()
+                    .col(pk_auto(Broadcast::Id))
+                    .col(string(Broadcast::GuildId))
+                    .col(string_null(Broadcast::AuthorId))
+                    .col(string(Broadcast::Title))
+                    .col(text(Broadcast::Message))
+                    .col(string(Broadcast::ChannelIds))
+                    .col(string(Broadcast::RoleIds).default(""))
+                    .col(timestamp_with_time_zone_null(Broadcast::ScheduledAt))
+                    .col(timestamp_with_time_zone_null(Broadcast::SentAt))
+                    .col(timestamp_with_time_zone_null(Broadcast::CancelledAt))
+                    .col(integer(Broadcast::PostedCount).default(0))
+                    .col(
+                        timestamp_with_time_zone(Broadcast::CreatedAt)
+                            .default(Expr::current_timestamp())
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_broadcast_guild_id")
+                            .from(Broadcast::Table, Broadcast::GuildId)
+                            .to(DiscordGuild::Table, DiscordGuild::GuildId)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_broadcast_author_id")
+                            .from(Broadcast::Table, Broadcast::AuthorId)
+                            .to(User::Table, User::DiscordId)
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_broadcast_guild_id")
+                    .table(Broadcast::Table)
+                    .col(Broadcast::GuildId)
+                    .to_owned(),
+            )
+            .await?;
+
+        // Scheduler polls for scheduled broadcasts which are due
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_broadcast_scheduled_at")
+                    .table(Broadcast::Table)
+                    .col(Broadcast::ScheduledAt)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Broadcast::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum Broadcast {
+    Table,
+    Id,
+    GuildId,
+    AuthorId,
+    Title,
+    Message,
+    ChannelIds,
+    RoleIds,
+    ScheduledAt,
+    SentAt,
+    CancelledAt,
+    PostedCount,
+    CreatedAt,
+}
@@ -0,0 +1,156 @@
+//! Stores the field of a ping format field's default value as an integer.
+//!
+//! `ping_format_field_value.ping_format_field_id` was created as a string column, which
+//! PostgreSQL can't reference the integer primary key of `ping_format_field` with. The
+//! column is converted to an integer with a cascading foreign key on every backend so
+//! default values are always deleted alongside their field.
+//!
+//! This migration is listed ahead of the one creating the table. On new databases it
+//! creates the table itself, turning the original migration into a no-op as it only
+//! creates the table if it doesn't exist yet. Existing databases already created the
+//! table, there the column is converted & default values of deleted fields are dropped.
+
+use sea_orm_migration::{prelude::*, schema::*, sea_orm::DatabaseBackend};
+
+use crate::m20251212_000008_create_ping_format_fields_table::PingFormatField;
+
+/// Name of the foreign key from a default value to its field
+const FOREIGN_KEY_NAME: &str = "fk_ping_format_field_value_ping_format_field_id";
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if !manager.has_table("ping_format_field_value").await? {
+            return manager
+                .create_table(value_table(PingFormatFieldValue::Table, true))
+                .await;
+        }
+
+        let db = manager.get_connection();
+        match manager.get_database_backend() {
+            DatabaseBackend::Postgres => {
+                db.execute_unprepared(
+                    r#"DELETE FROM "ping_format_field_value" WHERE CAST("ping_format_field_id" AS INTEGER) NOT IN (SELECT "id" FROM "ping_format_field")"#,
+                )
+                .await?;
+                db.execute_unprepared(
+                    r#"ALTER TABLE "ping_format_field_value" ALTER COLUMN "ping_format_field_id" TYPE INTEGER USING CAST("ping_format_field_id" AS INTEGER)"#,
+                )
+                .await?;
+                db.execute_unprepared(&format!(
+                    r#"ALTER TABLE "ping_format_field_value" DROP CONSTRAINT IF EXISTS "{FOREIGN_KEY_NAME}""#
+                ))
+                .await?;
+                manager
+                    .create_foreign_key(
+                        ForeignKey::create()
+                            .name(FOREIGN_KEY_NAME)
+                            .from(
+                                PingFormatFieldValue::Table,
+                                PingFormatFieldValue::PingFormatFieldId,
+                            )
+                            .to(PingFormatField::Table, PingFormatField::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade)
+                            .to_owned(),
+                    )
+                    .await
+            }
+            // SQLite can't alter the type of a column, rebuild the table instead
+            _ => rebuild_table(manager, true).await,
+        }
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // On new databases the original migration already dropped the table it created
+        if !manager.has_table("ping_format_field_value").await? {
+            return Ok(());
+        }
+
+        let db = manager.get_connection();
+        match manager.get_database_backend() {
+            DatabaseBackend::Postgres => {
+                db.execute_unprepared(&format!(
+                    r#"ALTER TABLE "ping_format_field_value" DROP CONSTRAINT IF EXISTS "{FOREIGN_KEY_NAME}""#
+                ))
+                .await?;
+                db.execute_unprepared(
+                    r#"ALTER TABLE "ping_format_field_value" ALTER COLUMN "ping_format_field_id" TYPE VARCHAR"#,
+                )
+                .await?;
+                Ok(())
+            }
+            _ => rebuild_table(manager, false).await,
+        }
+    }
+}
+
+/// Builds the CREATE TABLE statement of the default values table
+///
+/// # Arguments
+/// - `table` - Name of the table to create
+/// - `integer_field_id` - Whether the field ID is stored as an integer or as a string
+fn value_table(table: PingFormatFieldValue, integer_field_id: bool) -> TableCreateStatement {
+    Table::create()
+        .table(table)
+        .if_not_exists()
+        .col(pk_auto(PingFormatFieldValue::Id))
+        .col(if integer_field_id {
+            integer(PingFormatFieldValue::PingFormatFieldId)
+        } else {
+            string(PingFormatFieldValue::PingFormatFieldId)
+        })
+        .col(string(PingFormatFieldValue::Value))
+        .foreign_key(
+            ForeignKey::create()
+                .name(FOREIGN_KEY_NAME)
+                .from(table, PingFormatFieldValue::PingFormatFieldId)
+                .to(PingFormatField::Table, PingFormatField::Id)
+                .on_delete(ForeignKeyAction::Cascade)
+                .on_update(ForeignKeyAction::Cascade),
+        )
+        .to_owned()
+}
+
+/// Rebuilds the default values table on SQLite with the field ID as the given type
+///
+/// Copies the default values of fields that still exist into a new table, then replaces
+/// the old table with it.
+async fn rebuild_table(manager: &SchemaManager<'_>, integer_field_id: bool) -> Result<(), DbErr> {
+    let db = manager.get_connection();
+
+    manager
+        .create_table(value_table(
+            PingFormatFieldValue::NewTable,
+            integer_field_id,
+        ))
+        .await?;
+    db.execute_unprepared(&format!(
+        r#"INSERT INTO "ping_format_field_value_new" ("id", "ping_format_field_id", "value") SELECT "id", CAST("ping_format_field_id" AS {}), "value" FROM "ping_format_field_value" WHERE CAST("ping_format_field_id" AS INTEGER) IN (SELECT "id" FROM "ping_format_field")"#,
+        if integer_field_id { "INTEGER" } else { "TEXT" }
+    ))
+    .await?;
+    manager
+        .drop_table(Table::drop().table(PingFormatFieldValue::Table).to_owned())
+        .await?;
+    manager
+        .rename_table(
+            Table::rename()
+                .table(PingFormatFieldValue::NewTable, PingFormatFieldValue::Table)
+                .to_owned(),
+        )
+        .await
+}
+
+#[derive(DeriveIden, Clone, Copy)]
+enum PingFormatFieldValue {
+    Table,
+    #[sea_orm(iden = "ping_format_field_value_new")]
+    NewTable,
+    Id,
+    PingFormatFieldId,
+    Value,
+}
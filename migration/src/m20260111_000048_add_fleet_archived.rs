@@ -0,0 +1,36 @@
+//! Archives fleets instead of deleting them when they fall outside the retention policy.
+//!
+//! Archived fleets keep their field values & history but are left out of the calendar &
+//! timeline views of the timerboard.
+
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::m20251212_000013_create_fleet_table::Fleet;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Fleet::Table)
+                    .add_column(boolean(Fleet::Archived).default(false))
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Fleet::Table)
+                    .drop_column(Fleet::Archived)
+                    .to_owned(),
+            )
+            .await
+    }
+}
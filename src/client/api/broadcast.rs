@@ -0,0 +1,37 @@
+use crate::{
+    client::model::error::ApiError,
+    model::broadcast::{BroadcastDto, BroadcastOverviewDto, CreateBroadcastDto},
+};
+
+use super::helper::{delete, get, parse_response, post, send_request, serialize_json};
+
+/// GET /api/guilds/{guild_id}/broadcasts
+/// Get the channels & roles the user can broadcast to & the guild's recent broadcasts
+pub async fn get_broadcasts(guild_id: u64) -> Result<BroadcastOverviewDto, ApiError> {
+    let url = format!("/api/guilds/{}/broadcasts", guild_id);
+
+    let response = send_request(|| get(&url)).await?;
+    parse_response(response).await
+}
+
+/// POST /api/guilds/{guild_id}/broadcasts
+/// Post an ad-hoc broadcast now or schedule it for later
+pub async fn create_broadcast(
+    guild_id: u64,
+    dto: CreateBroadcastDto,
+) -> Result<BroadcastDto, ApiError> {
+    let url = format!("/api/guilds/{}/broadcasts", guild_id);
+    let body = serialize_json(&dto)?;
+
+    let response = send_request(|| post(&url).body(body.clone())).await?;
+    parse_response(response).await
+}
+
+/// DELETE /api/guilds/{guild_id}/broadcasts/{broadcast_id}
+/// Cancel a scheduled broadcast before it is posted
+pub async fn cancel_broadcast(guild_id: u64, broadcast_id: i32) -> Result<BroadcastDto, ApiError> {
+    let url = format!("/api/guilds/{}/broadcasts/{}", guild_id, broadcast_id);
+
+    let response = send_request(|| delete(&url)).await?;
+    parse_response(response).await
+}
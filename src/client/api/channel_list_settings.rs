@@ -0,0 +1,30 @@
+use crate::{
+    client::model::error::ApiError,
+    model::channel_list_settings::{ChannelListSettingsDto, UpdateChannelListSettingsDto},
+};
+
+use super::helper::{get, parse_response, put, send_request, serialize_json};
+
+pub async fn get_channel_list_settings(
+    guild_id: u64,
+) -> Result<Vec<ChannelListSettingsDto>, ApiError> {
+    let url = format!("/api/admin/servers/{}/list-settings", guild_id);
+
+    let response = send_request(|| get(&url)).await?;
+    parse_response(response).await
+}
+
+pub async fn update_channel_list_settings(
+    guild_id: u64,
+    channel_id: u64,
+    payload: UpdateChannelListSettingsDto,
+) -> Result<ChannelListSettingsDto, ApiError> {
+    let url = format!(
+        "/api/admin/servers/{}/list-settings/{}",
+        guild_id, channel_id
+    );
+    let body = serialize_json(&payload)?;
+
+    let response = send_request(|| put(&url).body(body.clone())).await?;
+    parse_response(response).await
+}
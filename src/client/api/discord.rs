@@ -1,9 +1,12 @@
 use crate::{
     client::model::error::ApiError,
-    model::discord::{PaginatedDiscordGuildChannelsDto, PaginatedDiscordGuildRolesDto},
+    model::discord::{
+        ChannelDiagnosticsDto, PaginatedDiscordGuildChannelsDto, PaginatedDiscordGuildRolesDto,
+        RoleDiagnosticsDto,
+    },
 };
 
-use super::helper::{get, parse_response, send_request};
+use super::helper::{get, parse_empty_response, parse_response, post, send_request};
 
 /// Get paginated roles for a guild
 pub async fn get_discord_guild_roles(
@@ -34,3 +37,42 @@ pub async fn get_discord_guild_channels(
     let response = send_request(|| get(&url)).await?;
     parse_response(response).await
 }
+
+/// Check the bot's permissions in a channel
+pub async fn get_discord_channel_diagnostics(
+    guild_id: u64,
+    channel_id: u64,
+) -> Result<ChannelDiagnosticsDto, ApiError> {
+    let url = format!(
+        "/api/admin/servers/{}/channels/{}/diagnostics",
+        guild_id, channel_id
+    );
+
+    let response = send_request(|| get(&url)).await?;
+    parse_response(response).await
+}
+
+/// Send a test message to a channel
+pub async fn send_discord_test_message(guild_id: u64, channel_id: u64) -> Result<(), ApiError> {
+    let url = format!(
+        "/api/admin/servers/{}/channels/{}/test-message",
+        guild_id, channel_id
+    );
+
+    let response = send_request(|| post(&url)).await?;
+    parse_empty_response(response).await
+}
+
+/// Check whether the bot can ping a role
+pub async fn get_discord_role_diagnostics(
+    guild_id: u64,
+    role_id: u64,
+) -> Result<RoleDiagnosticsDto, ApiError> {
+    let url = format!(
+        "/api/admin/servers/{}/roles/{}/diagnostics",
+        guild_id, role_id
+    );
+
+    let response = send_request(|| get(&url)).await?;
+    parse_response(response).await
+}
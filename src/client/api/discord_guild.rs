@@ -1,6 +1,6 @@
 use crate::{client::model::error::ApiError, model::discord::DiscordGuildDto};
 
-use super::helper::{get, parse_response, send_request};
+use super::helper::{get, parse_response, post, send_request};
 
 /// Get all Discord guilds that the user has admin access to
 pub async fn get_all_discord_guilds() -> Result<Vec<DiscordGuildDto>, ApiError> {
@@ -14,3 +14,23 @@ pub async fn get_discord_guild_by_id(guild_id: u64) -> Result<DiscordGuildDto, A
     let response = send_request(|| get(&url)).await?;
     parse_response(response).await
 }
+
+/// Restore a Discord guild the bot was added back to
+pub async fn restore_discord_guild(guild_id: u64) -> Result<DiscordGuildDto, ApiError> {
+    let url = format!("/api/admin/servers/{}/restore", guild_id);
+    let response = send_request(|| post(&url)).await?;
+    parse_response(response).await
+}
+
+/// Purge the data of a Discord guild the bot was removed from
+///
+/// Returns the guild if it starts over after the bot was added back, None if it was deleted
+pub async fn purge_discord_guild(guild_id: u64) -> Result<Option<DiscordGuildDto>, ApiError> {
+    let url = format!("/api/admin/servers/{}/purge", guild_id);
+    let response = send_request(|| post(&url)).await?;
+
+    if response.status() == 204 {
+        return Ok(None);
+    }
+    parse_response(response).await.map(Some)
+}
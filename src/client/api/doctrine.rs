@@ -0,0 +1,57 @@
+use crate::{
+    client::model::error::ApiError,
+    model::doctrine::{CreateDoctrineDto, DoctrineDto, PaginatedDoctrinesDto, UpdateDoctrineDto},
+};
+
+use super::helper::{
+    delete, get, parse_empty_response, parse_response, post, put, send_request, serialize_json,
+};
+
+pub async fn create_doctrine(guild_id: u64, payload: CreateDoctrineDto) -> Result<(), ApiError> {
+    let url = format!("/api/admin/servers/{}/doctrine", guild_id);
+    let body = serialize_json(&payload)?;
+
+    let response = send_request(|| post(&url).body(body.clone())).await?;
+    parse_empty_response(response).await
+}
+
+pub async fn get_paginated_doctrines(
+    guild_id: u64,
+    page: u64,
+    per_page: u64,
+) -> Result<PaginatedDoctrinesDto, ApiError> {
+    let url = format!(
+        "/api/admin/servers/{}/doctrines?page={}&entries={}",
+        guild_id, page, per_page
+    );
+
+    let response = send_request(|| get(&url)).await?;
+    parse_response(response).await
+}
+
+pub async fn update_doctrine(
+    guild_id: u64,
+    id: i32,
+    payload: UpdateDoctrineDto,
+) -> Result<(), ApiError> {
+    let url = format!("/api/admin/servers/{}/doctrine/{}", guild_id, id);
+    let body = serialize_json(&payload)?;
+
+    let response = send_request(|| put(&url).body(body.clone())).await?;
+    parse_empty_response(response).await
+}
+
+pub async fn delete_doctrine(guild_id: u64, id: i32) -> Result<(), ApiError> {
+    let url = format!("/api/admin/servers/{}/doctrine/{}", guild_id, id);
+
+    let response = send_request(|| delete(&url)).await?;
+    parse_empty_response(response).await
+}
+
+/// Gets the guild's doctrine library for the doctrine picker of the fleet form
+pub async fn get_guild_doctrines(guild_id: u64) -> Result<Vec<DoctrineDto>, ApiError> {
+    let url = format!("/api/guilds/{}/doctrines", guild_id);
+
+    let response = send_request(|| get(&url)).await?;
+    parse_response(response).await
+}
@@ -1,9 +1,17 @@
+use chrono::{DateTime, Utc};
+use futures_util::StreamExt;
+use gloo_net::eventsource::futures::{EventSource, EventSourceSubscription};
+
 use crate::{
     client::model::error::ApiError,
     model::{
         category::FleetCategoryDetailsDto,
         discord::DiscordGuildMemberDto,
-        fleet::{CreateFleetDto, FleetDto, PaginatedFleetsDto, UpdateFleetDto},
+        fleet::{
+            BulkFleetActionDto, CloneFleetDto, CreateFleetDto, FleetCalendarDto, FleetDto,
+            FleetEventDto, PaginatedFleetsDto, PaginatedGuildFleetsDto, UpdateFleetDto,
+        },
+        paste::{ParsePasteDto, ParsedPasteDto},
     },
 };
 
@@ -25,6 +33,22 @@ pub async fn get_category_details(
     parse_response(response).await
 }
 
+/// POST /api/guilds/{guild_id}/categories/{category_id}/parse-paste
+/// Parse a pasted notification or ping into a prefilled fleet for review
+pub async fn parse_paste(
+    guild_id: u64,
+    category_id: i32,
+    text: String,
+) -> Result<ParsedPasteDto, ApiError> {
+    let url = format!(
+        "/api/guilds/{}/categories/{}/parse-paste",
+        guild_id, category_id
+    );
+    let body = serialize_json(&ParsePasteDto { text })?;
+    let response = send_request(|| post(&url).body(body.clone())).await?;
+    parse_response(response).await
+}
+
 /// GET /api/guilds/{guild_id}/members
 /// Get all members of a guild for FC selection
 pub async fn get_guild_members(guild_id: u64) -> Result<Vec<DiscordGuildMemberDto>, ApiError> {
@@ -57,6 +81,39 @@ pub async fn get_fleets(
     parse_response(response).await
 }
 
+/// GET /api/fleets
+/// Get paginated fleets across all of the user's guilds, optionally limited to some guilds
+pub async fn get_user_fleets(
+    page: u64,
+    per_page: u64,
+    guild_ids: Option<Vec<u64>>,
+) -> Result<PaginatedGuildFleetsDto, ApiError> {
+    let mut url = format!("/api/fleets?page={}&per_page={}", page, per_page);
+    if let Some(guild_ids) = guild_ids {
+        let guild_ids: Vec<String> = guild_ids.iter().map(|id| id.to_string()).collect();
+        url.push_str(&format!("&guild_ids={}", guild_ids.join(",")));
+    }
+    let response = send_request(|| get(&url)).await?;
+    parse_response(response).await
+}
+
+/// GET /api/guilds/{guild_id}/fleets/calendar
+/// Get fleets within a time range for the calendar views
+pub async fn get_fleet_calendar(
+    guild_id: u64,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+) -> Result<FleetCalendarDto, ApiError> {
+    let url = format!(
+        "/api/guilds/{}/fleets/calendar?start={}&end={}",
+        guild_id,
+        start.timestamp(),
+        end.timestamp()
+    );
+    let response = send_request(|| get(&url)).await?;
+    parse_response(response).await
+}
+
 /// GET /api/guilds/{guild_id}/fleets/{fleet_id}
 /// Get fleet details by ID
 pub async fn get_fleet(guild_id: u64, fleet_id: i32) -> Result<FleetDto, ApiError> {
@@ -85,3 +142,76 @@ pub async fn delete_fleet(guild_id: u64, fleet_id: i32) -> Result<(), ApiError>
     let response = send_request(|| delete(&url)).await?;
     parse_empty_response(response).await
 }
+
+/// POST /api/guilds/{guild_id}/fleets/{fleet_id}/clone
+/// Copy a fleet with a new time
+pub async fn clone_fleet(
+    guild_id: u64,
+    fleet_id: i32,
+    dto: CloneFleetDto,
+) -> Result<FleetDto, ApiError> {
+    let url = format!("/api/guilds/{}/fleets/{}/clone", guild_id, fleet_id);
+    let body = serialize_json(&dto)?;
+    let response = send_request(|| post(&url).body(body.clone())).await?;
+    parse_response(response).await
+}
+
+/// POST /api/guilds/{guild_id}/fleets/bulk
+/// Shift, cancel or re-categorise several fleets at once
+pub async fn bulk_update_fleets(guild_id: u64, dto: BulkFleetActionDto) -> Result<(), ApiError> {
+    let url = format!("/api/guilds/{}/fleets/bulk", guild_id);
+    let body = serialize_json(&dto)?;
+    let response = send_request(|| post(&url).body(body.clone())).await?;
+    parse_empty_response(response).await
+}
+
+/// Open stream of live fleet changes, the connection is closed when dropped
+pub struct FleetEventStream {
+    // Kept alive alongside the subscription, dropping it closes the connection
+    _source: EventSource,
+    subscription: EventSourceSubscription,
+}
+
+impl FleetEventStream {
+    /// Waits for the next fleet change
+    ///
+    /// Connection errors are returned without ending the stream as the browser
+    /// reconnects on its own, changes made while disconnected are missed.
+    pub async fn next(&mut self) -> Option<Result<FleetEventDto, ApiError>> {
+        let item = self.subscription.next().await?;
+
+        Some(match item {
+            Ok((_, message)) => {
+                let data = message.data().as_string().unwrap_or_default();
+                serde_json::from_str(&data).map_err(|e| ApiError {
+                    status: 500,
+                    message: format!("Failed to parse fleet event: {}", e),
+                })
+            }
+            Err(e) => Err(ApiError {
+                status: 0,
+                message: format!("Fleet event stream disconnected: {}", e),
+            }),
+        })
+    }
+}
+
+/// GET /api/guilds/{guild_id}/fleets/events
+/// Open a Server-Sent Events stream of live fleet changes
+pub fn stream_fleet_events(guild_id: u64) -> Result<FleetEventStream, ApiError> {
+    let url = format!("/api/guilds/{}/fleets/events", guild_id);
+
+    let mut source = EventSource::new(&url).map_err(|e| ApiError {
+        status: 500,
+        message: format!("Failed to open fleet event stream: {}", e),
+    })?;
+    let subscription = source.subscribe("message").map_err(|e| ApiError {
+        status: 500,
+        message: format!("Failed to subscribe to fleet events: {}", e),
+    })?;
+
+    Ok(FleetEventStream {
+        _source: source,
+        subscription,
+    })
+}
@@ -0,0 +1,52 @@
+use crate::{
+    client::model::error::ApiError,
+    model::fleet_relay::{AcceptFleetRelayDto, CreateFleetRelayDto, FleetRelayDto, FleetRelaysDto},
+};
+
+use super::helper::{
+    delete, get, parse_empty_response, parse_response, post, send_request, serialize_json,
+};
+
+pub async fn get_fleet_relays(guild_id: u64) -> Result<FleetRelaysDto, ApiError> {
+    let url = format!("/api/admin/servers/{}/relays", guild_id);
+
+    let response = send_request(|| get(&url)).await?;
+    parse_response(response).await
+}
+
+pub async fn create_fleet_relay(
+    guild_id: u64,
+    payload: CreateFleetRelayDto,
+) -> Result<FleetRelayDto, ApiError> {
+    let url = format!("/api/admin/servers/{}/relays", guild_id);
+    let body = serialize_json(&payload)?;
+
+    let response = send_request(|| post(&url).body(body.clone())).await?;
+    parse_response(response).await
+}
+
+pub async fn accept_fleet_relay(
+    guild_id: u64,
+    id: i32,
+    payload: AcceptFleetRelayDto,
+) -> Result<FleetRelayDto, ApiError> {
+    let url = format!("/api/admin/servers/{}/relays/{}/accept", guild_id, id);
+    let body = serialize_json(&payload)?;
+
+    let response = send_request(|| post(&url).body(body.clone())).await?;
+    parse_response(response).await
+}
+
+pub async fn decline_fleet_relay(guild_id: u64, id: i32) -> Result<FleetRelayDto, ApiError> {
+    let url = format!("/api/admin/servers/{}/relays/{}/decline", guild_id, id);
+
+    let response = send_request(|| post(&url)).await?;
+    parse_response(response).await
+}
+
+pub async fn delete_fleet_relay(guild_id: u64, id: i32) -> Result<(), ApiError> {
+    let url = format!("/api/admin/servers/{}/relays/{}", guild_id, id);
+
+    let response = send_request(|| delete(&url)).await?;
+    parse_empty_response(response).await
+}
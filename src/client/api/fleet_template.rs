@@ -0,0 +1,38 @@
+use crate::{
+    client::model::error::ApiError,
+    model::fleet_template::{CreateFleetTemplateDto, FleetTemplateDto},
+};
+
+use super::helper::{
+    delete, get, parse_empty_response, parse_response, post, send_request, serialize_json,
+};
+
+/// GET /api/guilds/{guild_id}/fleet-templates
+/// Get the user's own templates & the templates shared with their categories
+pub async fn get_fleet_templates(guild_id: u64) -> Result<Vec<FleetTemplateDto>, ApiError> {
+    let url = format!("/api/guilds/{}/fleet-templates", guild_id);
+
+    let response = send_request(|| get(&url)).await?;
+    parse_response(response).await
+}
+
+/// POST /api/guilds/{guild_id}/fleet-templates
+/// Save the fleet creation form as a template
+pub async fn create_fleet_template(
+    guild_id: u64,
+    dto: CreateFleetTemplateDto,
+) -> Result<FleetTemplateDto, ApiError> {
+    let url = format!("/api/guilds/{}/fleet-templates", guild_id);
+    let body = serialize_json(&dto)?;
+
+    let response = send_request(|| post(&url).body(body.clone())).await?;
+    parse_response(response).await
+}
+
+/// DELETE /api/guilds/{guild_id}/fleet-templates/{template_id}
+pub async fn delete_fleet_template(guild_id: u64, template_id: i32) -> Result<(), ApiError> {
+    let url = format!("/api/guilds/{}/fleet-templates/{}", guild_id, template_id);
+
+    let response = send_request(|| delete(&url)).await?;
+    parse_empty_response(response).await
+}
@@ -0,0 +1,21 @@
+use crate::{client::model::error::ApiError, model::guild_settings::GuildSettingsDto};
+
+use super::helper::{get, parse_response, put, send_request, serialize_json};
+
+pub async fn get_guild_settings(guild_id: u64) -> Result<GuildSettingsDto, ApiError> {
+    let url = format!("/api/admin/servers/{}/settings", guild_id);
+
+    let response = send_request(|| get(&url)).await?;
+    parse_response(response).await
+}
+
+pub async fn update_guild_settings(
+    guild_id: u64,
+    payload: GuildSettingsDto,
+) -> Result<GuildSettingsDto, ApiError> {
+    let url = format!("/api/admin/servers/{}/settings", guild_id);
+    let body = serialize_json(&payload)?;
+
+    let response = send_request(|| put(&url).body(body.clone())).await?;
+    parse_response(response).await
+}
@@ -1,8 +1,17 @@
+pub mod broadcast;
 pub mod category;
+pub mod channel_list_settings;
 pub mod discord;
 pub mod discord_guild;
+pub mod doctrine;
 pub mod fleet;
+pub mod fleet_relay;
+pub mod fleet_template;
+pub mod guild_settings;
 pub mod helper;
 pub mod ping_format;
 pub mod ping_group;
+pub mod solar_system;
+pub mod stand_down;
 pub mod user;
+pub mod webhook;
@@ -0,0 +1,30 @@
+use crate::{client::model::error::ApiError, model::solar_system::SolarSystemDto};
+
+use super::helper::{get, parse_response, send_request};
+
+pub async fn search_solar_systems(
+    search: &str,
+    limit: usize,
+) -> Result<Vec<SolarSystemDto>, ApiError> {
+    let url = format!(
+        "/api/eve/solar-systems?search={}&limit={}",
+        encode_query_value(search),
+        limit
+    );
+
+    let response = send_request(|| get(&url)).await?;
+    parse_response(response).await
+}
+
+/// Percent-encodes a query parameter value, leaving only unreserved characters as is
+fn encode_query_value(value: &str) -> String {
+    value
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (byte as char).to_string()
+            }
+            _ => format!("%{:02X}", byte),
+        })
+        .collect()
+}
@@ -0,0 +1,43 @@
+use crate::{
+    client::model::error::ApiError,
+    model::stand_down::{CreateStandDownDto, StandDownDto, StandDownOverviewDto},
+};
+
+use super::helper::{get, parse_response, post, send_request, serialize_json};
+
+/// GET /api/guilds/{guild_id}/stand-downs
+/// Get the scopes the user can stand down & the guild's recent stand-downs
+pub async fn get_stand_downs(guild_id: u64) -> Result<StandDownOverviewDto, ApiError> {
+    let url = format!("/api/guilds/{}/stand-downs", guild_id);
+
+    let response = send_request(|| get(&url)).await?;
+    parse_response(response).await
+}
+
+/// POST /api/guilds/{guild_id}/stand-downs
+/// Cancel every fleet of a guild or ping group within a time window
+pub async fn create_stand_down(
+    guild_id: u64,
+    dto: CreateStandDownDto,
+) -> Result<StandDownDto, ApiError> {
+    let url = format!("/api/guilds/{}/stand-downs", guild_id);
+    let body = serialize_json(&dto)?;
+
+    let response = send_request(|| post(&url).body(body.clone())).await?;
+    parse_response(response).await
+}
+
+/// POST /api/guilds/{guild_id}/stand-downs/{stand_down_id}/resume
+/// Restore the fleets cancelled by a stand-down
+pub async fn resume_stand_down(
+    guild_id: u64,
+    stand_down_id: i32,
+) -> Result<StandDownDto, ApiError> {
+    let url = format!(
+        "/api/guilds/{}/stand-downs/{}/resume",
+        guild_id, stand_down_id
+    );
+
+    let response = send_request(|| post(&url)).await?;
+    parse_response(response).await
+}
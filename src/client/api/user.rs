@@ -1,11 +1,15 @@
 use crate::{
     client::{
-        api::helper::{delete, get, parse_empty_response, parse_response, post, send_request},
+        api::helper::{
+            delete, get, parse_empty_response, parse_response, post, put, send_request,
+            serialize_json,
+        },
         model::error::ApiError,
     },
     model::{
         category::FleetCategoryListItemDto,
         discord::DiscordGuildDto,
+        subscription::{NotificationPreferenceDto, SubscriptionsDto},
         user::{PaginatedUsersDto, UserDto},
     },
 };
@@ -45,3 +49,56 @@ pub async fn get_user_manageable_categories(
     let response = send_request(|| get(&url)).await?;
     parse_response(response).await
 }
+
+pub async fn get_notification_preferences() -> Result<NotificationPreferenceDto, ApiError> {
+    let response = send_request(|| get("/api/user/notifications")).await?;
+    parse_response(response).await
+}
+
+pub async fn update_notification_preferences(
+    payload: NotificationPreferenceDto,
+) -> Result<NotificationPreferenceDto, ApiError> {
+    let body = serialize_json(&payload)?;
+    let response = send_request(|| put("/api/user/notifications").body(body.clone())).await?;
+    parse_response(response).await
+}
+
+pub async fn get_subscriptions(guild_id: u64) -> Result<SubscriptionsDto, ApiError> {
+    let url = format!("/api/user/guilds/{}/subscriptions", guild_id);
+    let response = send_request(|| get(&url)).await?;
+    parse_response(response).await
+}
+
+pub async fn set_category_subscription(
+    guild_id: u64,
+    category_id: i32,
+    subscribed: bool,
+) -> Result<(), ApiError> {
+    let url = format!(
+        "/api/user/guilds/{}/categories/{}/subscription",
+        guild_id, category_id
+    );
+    let response = if subscribed {
+        send_request(|| put(&url)).await?
+    } else {
+        send_request(|| delete(&url)).await?
+    };
+    parse_empty_response(response).await
+}
+
+pub async fn set_fleet_subscription(
+    guild_id: u64,
+    fleet_id: i32,
+    subscribed: bool,
+) -> Result<(), ApiError> {
+    let url = format!(
+        "/api/user/guilds/{}/fleets/{}/subscription",
+        guild_id, fleet_id
+    );
+    let response = if subscribed {
+        send_request(|| put(&url)).await?
+    } else {
+        send_request(|| delete(&url)).await?
+    };
+    parse_empty_response(response).await
+}
@@ -0,0 +1,84 @@
+use crate::{
+    client::model::error::ApiError,
+    model::webhook::{
+        CreateWebhookDto, PaginatedWebhookDeliveriesDto, PaginatedWebhooksDto, UpdateWebhookDto,
+        WebhookDeliveryDto, WebhookDto,
+    },
+};
+
+use super::helper::{
+    delete, get, parse_empty_response, parse_response, post, put, send_request, serialize_json,
+};
+
+pub async fn create_webhook(
+    guild_id: u64,
+    payload: CreateWebhookDto,
+) -> Result<WebhookDto, ApiError> {
+    let url = format!("/api/admin/servers/{}/webhooks", guild_id);
+    let body = serialize_json(&payload)?;
+
+    let response = send_request(|| post(&url).body(body.clone())).await?;
+    parse_response(response).await
+}
+
+pub async fn get_paginated_webhooks(
+    guild_id: u64,
+    page: u64,
+    per_page: u64,
+) -> Result<PaginatedWebhooksDto, ApiError> {
+    let url = format!(
+        "/api/admin/servers/{}/webhooks?page={}&entries={}",
+        guild_id, page, per_page
+    );
+
+    let response = send_request(|| get(&url)).await?;
+    parse_response(response).await
+}
+
+pub async fn update_webhook(
+    guild_id: u64,
+    id: i32,
+    payload: UpdateWebhookDto,
+) -> Result<WebhookDto, ApiError> {
+    let url = format!("/api/admin/servers/{}/webhooks/{}", guild_id, id);
+    let body = serialize_json(&payload)?;
+
+    let response = send_request(|| put(&url).body(body.clone())).await?;
+    parse_response(response).await
+}
+
+pub async fn delete_webhook(guild_id: u64, id: i32) -> Result<(), ApiError> {
+    let url = format!("/api/admin/servers/{}/webhooks/{}", guild_id, id);
+
+    let response = send_request(|| delete(&url)).await?;
+    parse_empty_response(response).await
+}
+
+pub async fn get_paginated_webhook_deliveries(
+    guild_id: u64,
+    id: i32,
+    page: u64,
+    per_page: u64,
+) -> Result<PaginatedWebhookDeliveriesDto, ApiError> {
+    let url = format!(
+        "/api/admin/servers/{}/webhooks/{}/deliveries?page={}&entries={}",
+        guild_id, id, page, per_page
+    );
+
+    let response = send_request(|| get(&url)).await?;
+    parse_response(response).await
+}
+
+pub async fn replay_webhook_delivery(
+    guild_id: u64,
+    id: i32,
+    delivery_id: i32,
+) -> Result<WebhookDeliveryDto, ApiError> {
+    let url = format!(
+        "/api/admin/servers/{}/webhooks/{}/deliveries/{}/replay",
+        guild_id, id, delivery_id
+    );
+
+    let response = send_request(|| post(&url)).await?;
+    parse_response(response).await
+}
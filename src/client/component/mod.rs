@@ -5,6 +5,7 @@ pub mod page;
 pub mod pagination;
 pub mod protected_layout;
 pub mod searchable_dropdown;
+pub mod solar_system_picker;
 
 pub use header::Header;
 pub use layout::Layout;
@@ -13,3 +14,4 @@ pub use page::Page;
 pub use pagination::{Pagination, PaginationData};
 pub use protected_layout::{RequiresAdmin, RequiresLoggedIn};
 pub use searchable_dropdown::{DropdownItem, SearchableDropdown, SelectedItem, SelectedItemsList};
+pub use solar_system_picker::SolarSystemPicker;
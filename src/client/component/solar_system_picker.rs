@@ -0,0 +1,106 @@
+use dioxus::prelude::*;
+
+use crate::{
+    client::component::searchable_dropdown::{DropdownItem, SearchableDropdown},
+    model::solar_system::SolarSystemDto,
+};
+
+#[cfg(feature = "web")]
+use crate::client::api::solar_system::search_solar_systems;
+
+/// Number of solar systems suggested while typing
+const SUGGESTION_LIMIT: usize = 10;
+
+/// Solar system input with autocomplete from the bundled EVE static map
+///
+/// Emits the canonical system name once a suggestion is picked, or None when cleared.
+#[component]
+pub fn SolarSystemPicker(
+    value: Option<String>,
+    placeholder: String,
+    disabled: bool,
+    on_change: EventHandler<Option<String>>,
+) -> Element {
+    let search = use_signal(String::new);
+    let mut show_dropdown = use_signal(|| false);
+    let mut suggestions = use_signal(Vec::<SolarSystemDto>::new);
+
+    #[cfg(feature = "web")]
+    {
+        let future = use_resource(move || async move {
+            let query = search();
+            if query.trim().is_empty() {
+                return Ok(Vec::new());
+            }
+            search_solar_systems(&query, SUGGESTION_LIMIT).await
+        });
+
+        use_effect(move || match &*future.read_unchecked() {
+            Some(Ok(systems)) => suggestions.set(systems.clone()),
+            Some(Err(err)) => {
+                dioxus_logger::tracing::error!("Failed to search solar systems: {}", err);
+                suggestions.set(Vec::new());
+            }
+            None => {}
+        });
+    }
+
+    let current_value = value.clone().unwrap_or_default();
+    let display_value = value.filter(|value| !value.is_empty());
+
+    rsx! {
+        div {
+            class: "flex items-center gap-2",
+            div {
+                class: "flex-1",
+                SearchableDropdown {
+                    search_query: search,
+                    placeholder,
+                    display_value,
+                    disabled,
+                    has_items: !suggestions().is_empty(),
+                    show_dropdown_signal: Some(show_dropdown),
+                    empty_message: "Type to search solar systems".to_string(),
+                    not_found_message: "No matching solar systems found".to_string(),
+
+                    for system in suggestions() {
+                        {
+                            let name = system.name.clone();
+                            let is_selected = name == current_value;
+
+                            rsx! {
+                                DropdownItem {
+                                    key: "{system.id}",
+                                    selected: is_selected,
+                                    on_select: move |_| {
+                                        on_change.call(Some(name.clone()));
+                                        show_dropdown.set(false);
+                                    },
+                                    div {
+                                        class: "flex items-center justify-between gap-3",
+                                        div {
+                                            class: "flex flex-col",
+                                            span { class: "font-semibold", "{system.name}" }
+                                            span { class: "text-sm opacity-70", "{system.constellation} · {system.region}" }
+                                        }
+                                        span { class: "font-mono {system.security_class()}", "{system.security_label()}" }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            if !current_value.is_empty() {
+                button {
+                    r#type: "button",
+                    class: "btn btn-ghost btn-sm btn-square",
+                    title: "Clear",
+                    disabled,
+                    onclick: move |_| on_change.call(None),
+                    "✕"
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,214 @@
+use dioxus::prelude::*;
+use dioxus_logger::tracing;
+
+use crate::{
+    client::{component::modal::ConfirmationModal, model::error::ApiError},
+    model::discord::{DiscordGuildDto, DiscordGuildStatus},
+};
+
+use super::GuildsCache;
+
+#[cfg(feature = "web")]
+use crate::client::api::discord_guild::{purge_discord_guild, restore_discord_guild};
+
+/// Card for a server the bot was removed from, offering to restore or purge its data
+#[component]
+pub fn InactiveGuildCard(guild: DiscordGuildDto, mut cache: Signal<GuildsCache>) -> Element {
+    let guild_id = guild.guild_id;
+
+    let mut is_restoring = use_signal(|| false);
+    let mut show_purge_modal = use_signal(|| false);
+    let mut is_purging = use_signal(|| false);
+    let mut error = use_signal(|| None::<ApiError>);
+
+    #[cfg(feature = "web")]
+    let restore_future = use_resource(move || async move {
+        if is_restoring() {
+            Some(restore_discord_guild(guild_id).await)
+        } else {
+            None
+        }
+    });
+
+    #[cfg(feature = "web")]
+    use_effect(move || {
+        if let Some(Some(result)) = restore_future.read_unchecked().as_ref() {
+            match result {
+                Ok(restored) => {
+                    if let Some(guilds) = cache.write().data.as_mut() {
+                        if let Some(entry) = guilds.iter_mut().find(|g| g.guild_id == guild_id) {
+                            *entry = restored.clone();
+                        }
+                    }
+                    error.set(None);
+                }
+                Err(err) => {
+                    tracing::error!("Failed to restore server: {}", err);
+                    error.set(Some(err.clone()));
+                }
+            }
+            is_restoring.set(false);
+        }
+    });
+
+    #[cfg(feature = "web")]
+    let purge_future = use_resource(move || async move {
+        if is_purging() {
+            Some(purge_discord_guild(guild_id).await)
+        } else {
+            None
+        }
+    });
+
+    #[cfg(feature = "web")]
+    use_effect(move || {
+        if let Some(Some(result)) = purge_future.read_unchecked().as_ref() {
+            match result {
+                Ok(purged) => {
+                    if let Some(guilds) = cache.write().data.as_mut() {
+                        match purged {
+                            Some(purged) => {
+                                if let Some(entry) =
+                                    guilds.iter_mut().find(|g| g.guild_id == guild_id)
+                                {
+                                    *entry = purged.clone();
+                                }
+                            }
+                            None => guilds.retain(|g| g.guild_id != guild_id),
+                        }
+                    }
+                    show_purge_modal.set(false);
+                    error.set(None);
+                }
+                Err(err) => {
+                    tracing::error!("Failed to purge server: {}", err);
+                    error.set(Some(err.clone()));
+                }
+            }
+            is_purging.set(false);
+        }
+    });
+
+    let (badge_class, description) = match guild.status {
+        DiscordGuildStatus::Rejoined => (
+            "badge badge-info badge-sm",
+            "The bot was added back to this server. Restore its previous configuration and fleets, or purge them to start over.",
+        ),
+        _ => (
+            "badge badge-warning badge-sm",
+            "The bot was removed from this server. Its fleets are hidden and notifications are paused until the bot is added back.",
+        ),
+    };
+    let can_restore = guild.status == DiscordGuildStatus::Rejoined;
+    let guild_name = guild.name.clone();
+
+    rsx! {
+        div {
+            class: "card bg-base-200 opacity-90",
+            div {
+                class: "card-body gap-3",
+                div {
+                    class: "flex items-center gap-4",
+                    if let Some(icon_hash) = &guild.icon_hash {
+                        img {
+                            src: "https://cdn.discordapp.com/icons/{guild.guild_id}/{icon_hash}.png",
+                            alt: "{guild.name} icon",
+                            class: "w-12 h-12 rounded-full grayscale",
+                        }
+                    } else {
+                        div {
+                            class: "w-12 h-12 rounded-full bg-neutral flex items-center justify-center font-bold",
+                            "{guild.name.chars().next().unwrap_or('?')}"
+                        }
+                    }
+                    div {
+                        class: "flex-1",
+                        div {
+                            class: "flex items-center gap-2",
+                            h3 {
+                                class: "font-semibold",
+                                "{guild.name}"
+                            }
+                            span { class: badge_class, "{guild.status.label()}" }
+                        }
+                        p {
+                            class: "text-sm opacity-70",
+                            "ID: {guild.guild_id}"
+                        }
+                        if let Some(removed_at) = guild.removed_at {
+                            p {
+                                class: "text-xs opacity-60",
+                                "Removed "
+                                {removed_at.format("%Y-%m-%d %H:%M").to_string()}
+                                " UTC"
+                            }
+                        }
+                    }
+                }
+                p {
+                    class: "text-sm opacity-80",
+                    "{description}"
+                }
+                if let Some(err) = error() {
+                    p {
+                        class: "text-sm text-error",
+                        "{err.message}"
+                    }
+                }
+                div {
+                    class: "card-actions justify-end",
+                    if can_restore {
+                        button {
+                            class: "btn btn-sm btn-primary",
+                            disabled: is_restoring() || is_purging(),
+                            onclick: move |_| is_restoring.set(true),
+                            if is_restoring() {
+                                span { class: "loading loading-spinner loading-sm" }
+                                "Restoring..."
+                            } else {
+                                "Restore"
+                            }
+                        }
+                    }
+                    button {
+                        class: "btn btn-sm btn-error",
+                        disabled: is_restoring() || is_purging(),
+                        onclick: move |_| show_purge_modal.set(true),
+                        "Purge"
+                    }
+                }
+            }
+        }
+
+        ConfirmationModal {
+            show: show_purge_modal,
+            title: "Purge Server Data".to_string(),
+            message: rsx!(
+                div {
+                    class: "py-4",
+                    p {
+                        "Are you sure you want to purge the data of "
+                        span { class: "font-bold", "\"{guild_name}\"" }
+                        "?"
+                    }
+                    p {
+                        class: "mt-4",
+                        if can_restore {
+                            "All fleet categories, fleets, ping groups, ping formats and webhooks of this server will be deleted and it will start over with an empty configuration."
+                        } else {
+                            "The server and all of its fleet categories, fleets, ping groups, ping formats and webhooks will be deleted."
+                        }
+                        " This action cannot be undone."
+                    }
+                }
+            ),
+            confirm_text: "Purge".to_string(),
+            confirm_class: "btn-error".to_string(),
+            is_processing: is_purging(),
+            processing_text: "Purging...".to_string(),
+            on_confirm: move |_| {
+                is_purging.set(true);
+            },
+        }
+    }
+}
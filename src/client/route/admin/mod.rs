@@ -1,3 +1,4 @@
+mod inactive_guild;
 pub mod server;
 pub mod user;
 
@@ -15,7 +16,12 @@ use crate::client::{
     model::error::ApiError,
     router::Route,
 };
-use crate::model::{discord::DiscordGuildDto, user::UserDto};
+use crate::model::{
+    discord::{DiscordGuildDto, DiscordGuildStatus},
+    user::UserDto,
+};
+
+use inactive_guild::InactiveGuildCard;
 
 #[cfg(feature = "web")]
 use crate::client::api::discord_guild::get_all_discord_guilds;
@@ -135,34 +141,38 @@ pub fn AdminServers() -> Element {
                     div {
                         class: "grid grid-cols-1 md:grid-cols-2 lg:grid-cols-3 gap-4",
                         for guild in guild_list {
-                            Link {
-                                to: Route::ServerAdminFleetCategory { guild_id: guild.guild_id },
-                                class: "card bg-base-200 hover:bg-base-300 transition-colors",
-                                div {
-                                    class: "card-body",
+                            if guild.status != DiscordGuildStatus::Active {
+                                InactiveGuildCard { key: "{guild.guild_id}", guild: guild.clone(), cache }
+                            } else {
+                                Link {
+                                    to: Route::ServerAdminFleetCategory { guild_id: guild.guild_id },
+                                    class: "card bg-base-200 hover:bg-base-300 transition-colors",
                                     div {
-                                        class: "flex items-center gap-4",
-                                        if let Some(icon_hash) = &guild.icon_hash {
-                                            img {
-                                                src: "https://cdn.discordapp.com/icons/{guild.guild_id}/{icon_hash}.png",
-                                                alt: "{guild.name} icon",
-                                                class: "w-12 h-12 rounded-full",
-                                            }
-                                        } else {
-                                            div {
-                                                class: "w-12 h-12 rounded-full bg-neutral flex items-center justify-center font-bold",
-                                                "{guild.name.chars().next().unwrap_or('?')}"
-                                            }
-                                        }
+                                        class: "card-body",
                                         div {
-                                            class: "flex-1",
-                                            h3 {
-                                                class: "font-semibold",
-                                                "{guild.name}"
+                                            class: "flex items-center gap-4",
+                                            if let Some(icon_hash) = &guild.icon_hash {
+                                                img {
+                                                    src: "https://cdn.discordapp.com/icons/{guild.guild_id}/{icon_hash}.png",
+                                                    alt: "{guild.name} icon",
+                                                    class: "w-12 h-12 rounded-full",
+                                                }
+                                            } else {
+                                                div {
+                                                    class: "w-12 h-12 rounded-full bg-neutral flex items-center justify-center font-bold",
+                                                    "{guild.name.chars().next().unwrap_or('?')}"
+                                                }
                                             }
-                                            p {
-                                                class: "text-sm opacity-70",
-                                                "ID: {guild.guild_id}"
+                                            div {
+                                                class: "flex-1",
+                                                h3 {
+                                                    class: "font-semibold",
+                                                    "{guild.name}"
+                                                }
+                                                p {
+                                                    class: "text-sm opacity-70",
+                                                    "ID: {guild.guild_id}"
+                                                }
                                             }
                                         }
                                     }
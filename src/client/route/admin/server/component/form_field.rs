@@ -1,8 +1,8 @@
 use dioxus::prelude::*;
 
 use crate::{
-    client::component::{DropdownItem, SearchableDropdown},
-    model::ping_format::PingFormatDto,
+    client::component::{DropdownItem, SearchableDropdown, SolarSystemPicker},
+    model::{discord::DiscordChannelKind, ping_format::PingFormatDto},
 };
 
 use super::{
@@ -26,6 +26,8 @@ pub struct ChannelData {
     pub id: u64,
     pub name: String,
     pub position: i32,
+    pub kind: DiscordChannelKind,
+    pub crosspost: bool,
 }
 
 /// Access role with permissions
@@ -35,6 +37,7 @@ pub struct AccessRoleData {
     pub can_view: bool,
     pub can_create: bool,
     pub can_manage: bool,
+    pub can_broadcast: bool,
 }
 
 /// Form field values
@@ -47,6 +50,7 @@ pub struct FormFieldData {
     pub ping_cooldown_str: String,
     pub ping_reminder_str: String,
     pub max_pre_ping_str: String,
+    pub staging_system: Option<String>,
     pub active_tab: ConfigTab,
     pub role_search_query: String,
     pub channel_search_query: String,
@@ -364,6 +368,31 @@ pub fn FleetCategoryFormFields(
             }
         }
 
+        // Staging System Override (Optional)
+        div {
+            class: "form-control w-full flex flex-col gap-2",
+            label {
+                class: "label",
+                span {
+                    class: "label-text",
+                    "Staging System (optional)"
+                }
+            }
+            SolarSystemPicker {
+                value: form_fields().staging_system,
+                placeholder: "Search solar systems...".to_string(),
+                disabled: is_submitting,
+                on_change: move |name: Option<String>| form_fields.write().staging_system = name,
+            }
+            label {
+                class: "label",
+                span {
+                    class: "label-text-alt break-words",
+                    "Overrides the server's staging system for jump routes to form-up systems"
+                }
+            }
+        }
+
         // Divider
         div {
             class: "divider"
@@ -17,6 +17,7 @@ use super::{
 use crate::{
     client::api::{
         category::{create_fleet_category, get_fleet_category_by_id, update_fleet_category},
+        guild_settings::get_guild_settings,
         ping_format::get_ping_formats,
         ping_group::get_paginated_ping_groups,
     },
@@ -73,6 +74,25 @@ pub fn CreateCategoryModal(
         }
     });
 
+    // Preselect the guild's default ping format when modal opens
+    #[cfg(feature = "web")]
+    let settings_future = use_resource(move || async move {
+        if show() {
+            get_guild_settings(guild_id).await.ok()
+        } else {
+            None
+        }
+    });
+
+    #[cfg(feature = "web")]
+    use_effect(move || {
+        if let Some(Some(settings)) = settings_future.read_unchecked().as_ref() {
+            if form_fields.peek().ping_format_id.is_none() {
+                form_fields.write().ping_format_id = settings.default_ping_format_id;
+            }
+        }
+    });
+
     // Fetch ping groups when modal opens
     #[cfg(feature = "web")]
     let ping_groups_future = use_resource(move || async move {
@@ -119,6 +139,7 @@ pub fn CreateCategoryModal(
                         can_view: r.can_view,
                         can_create: r.can_create,
                         can_manage: r.can_manage,
+                        can_broadcast: r.can_broadcast,
                     })
                     .collect();
 
@@ -140,6 +161,8 @@ pub fn CreateCategoryModal(
                         channel_id: c.id,
                         channel_name: String::new(), // Server will populate
                         position: 0,                 // Server will populate
+                        kind: c.kind,
+                        crosspost: c.crosspost,
                     })
                     .collect();
 
@@ -150,6 +173,7 @@ pub fn CreateCategoryModal(
                     ping_lead_time: durations.ping_cooldown,
                     ping_reminder: durations.ping_reminder,
                     max_pre_ping: durations.max_pre_ping,
+                    staging_system: form_fields().staging_system,
                     access_roles,
                     ping_roles,
                     channels,
@@ -368,6 +392,7 @@ pub fn EditCategoryModal(
                     .as_ref()
                     .map(format_duration)
                     .unwrap_or_default(),
+                staging_system: category.staging_system.clone(),
                 active_tab: Default::default(),
                 role_search_query: String::new(),
                 channel_search_query: String::new(),
@@ -384,6 +409,7 @@ pub fn EditCategoryModal(
                         can_view: ar.can_view,
                         can_create: ar.can_create,
                         can_manage: ar.can_manage,
+                        can_broadcast: ar.can_broadcast,
                     })
                     .collect(),
                 ping_roles: category
@@ -403,6 +429,8 @@ pub fn EditCategoryModal(
                         id: c.channel_id,
                         name: c.channel_name.clone(),
                         position: c.position,
+                        kind: c.kind,
+                        crosspost: c.crosspost,
                     })
                     .collect(),
             });
@@ -447,6 +475,7 @@ pub fn EditCategoryModal(
                         can_view: r.can_view,
                         can_create: r.can_create,
                         can_manage: r.can_manage,
+                        can_broadcast: r.can_broadcast,
                     })
                     .collect();
 
@@ -468,6 +497,8 @@ pub fn EditCategoryModal(
                         channel_id: c.id,
                         channel_name: String::new(), // Server will populate
                         position: 0,                 // Server will populate
+                        kind: c.kind,
+                        crosspost: c.crosspost,
                     })
                     .collect();
 
@@ -478,6 +509,7 @@ pub fn EditCategoryModal(
                     ping_lead_time: durations.ping_cooldown,
                     ping_reminder: durations.ping_reminder,
                     max_pre_ping: durations.max_pre_ping,
+                    staging_system: form_fields().staging_system,
                     access_roles,
                     ping_roles,
                     channels,
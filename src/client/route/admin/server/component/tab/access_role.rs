@@ -114,6 +114,7 @@ pub fn AccessRolesTab(
                                             can_view: true,
                                             can_create: false,
                                             can_manage: false,
+                                            can_broadcast: false,
                                         };
                                         form_fields.write().access_roles.push(new_access_role);
                                         role_search_query.set(String::new());
@@ -137,7 +138,7 @@ pub fn AccessRolesTab(
             // List of access roles with scrollable container
             SelectedItemsList {
                 label: "Configured Access Roles".to_string(),
-                empty_message: "No access roles configured. Add roles to control who can view, create, or manage fleets in this category.".to_string(),
+                empty_message: "No access roles configured. Add roles to control who can view, create, manage or broadcast to fleets in this category.".to_string(),
                 is_empty: sorted_access_roles().is_empty(),
                 for access_role in sorted_access_roles() {
                     {
@@ -147,6 +148,7 @@ pub fn AccessRolesTab(
                         let can_view = access_role.can_view;
                         let can_create = access_role.can_create;
                         let can_manage = access_role.can_manage;
+                        let can_broadcast = access_role.can_broadcast;
                         // Find the actual index in form_fields
                         let actual_index = form_fields().access_roles.iter().position(|ar| ar.role.id == role_id).unwrap_or(0);
                         rsx! {
@@ -205,6 +207,19 @@ pub fn AccessRolesTab(
                                             }
                                         }
                                     }
+                                    label {
+                                        class: "label cursor-pointer gap-2",
+                                        span { class: "label-text text-xs", "Broadcast" }
+                                        input {
+                                            r#type: "checkbox",
+                                            class: "checkbox checkbox-sm [transition:none]",
+                                            checked: can_broadcast,
+                                            disabled: is_submitting,
+                                            onchange: move |evt| {
+                                                form_fields.write().access_roles[actual_index].can_broadcast = evt.checked();
+                                            }
+                                        }
+                                    }
                                 }
                             }
                         }
@@ -1,9 +1,10 @@
 use dioxus::prelude::*;
 
 use crate::client::component::{DropdownItem, SearchableDropdown, SelectedItem, SelectedItemsList};
-use crate::model::discord::DiscordGuildChannelDto;
+use crate::model::discord::{DiscordChannelKind, DiscordGuildChannelDto};
 
 use super::super::form_field::{ChannelData, FormFieldData};
+use super::diagnostics::{ChannelDiagnosticsNotice, TestMessageButton};
 
 #[cfg(feature = "web")]
 use crate::client::api::discord::get_discord_guild_channels;
@@ -94,6 +95,7 @@ pub fn ChannelsTab(
                     for channel in filtered_channels() {
                         {
                             let channel_name = channel.name.clone();
+                            let kind_label = channel.kind.label();
                             rsx! {
                                 DropdownItem {
                                     key: "{channel.channel_id}",
@@ -102,12 +104,17 @@ pub fn ChannelsTab(
                                             id: channel.channel_id,
                                             name: channel.name.clone(),
                                             position: channel.position,
+                                            kind: channel.kind,
+                                            crosspost: false,
                                         };
                                         form_fields.write().channels.push(new_channel);
                                         channel_search_query.set(String::new());
                                         channel_dropdown_open.set(false);
                                     },
                                     "# {channel_name}"
+                                    if let Some(label) = kind_label {
+                                        span { class: "badge badge-ghost badge-sm ml-2", "{label}" }
+                                    }
                                 }
                             }
                         }
@@ -124,6 +131,8 @@ pub fn ChannelsTab(
                     {
                         let channel_id = channel.id;
                         let channel_name = channel.name.clone();
+                        let kind = channel.kind;
+                        let crosspost = channel.crosspost;
                         // Find the actual index in form_fields
                         let actual_index = form_fields().channels.iter().position(|c| c.id == channel_id).unwrap_or(0);
                         rsx! {
@@ -136,7 +145,27 @@ pub fn ChannelsTab(
                                 div {
                                     class: "flex-1 font-medium",
                                     "# {channel_name}"
+                                    if let Some(label) = kind.label() {
+                                        span { class: "badge badge-ghost badge-sm ml-2", "{label}" }
+                                    }
+                                    ChannelDiagnosticsNotice { guild_id, channel_id }
                                 }
+                                if kind == DiscordChannelKind::Announcement {
+                                    label {
+                                        class: "label cursor-pointer gap-2",
+                                        span { class: "label-text text-xs", "Crosspost" }
+                                        input {
+                                            r#type: "checkbox",
+                                            class: "checkbox checkbox-sm [transition:none]",
+                                            checked: crosspost,
+                                            disabled: is_submitting,
+                                            onchange: move |evt| {
+                                                form_fields.write().channels[actual_index].crosspost = evt.checked();
+                                            }
+                                        }
+                                    }
+                                }
+                                TestMessageButton { guild_id, channel_id, disabled: is_submitting }
                             }
                         }
                     }
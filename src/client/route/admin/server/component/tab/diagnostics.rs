@@ -0,0 +1,140 @@
+use dioxus::prelude::*;
+
+use crate::{
+    client::model::error::ApiError,
+    model::discord::{ChannelDiagnosticsDto, RoleDiagnosticsDto},
+};
+
+#[cfg(feature = "web")]
+use crate::client::api::discord::{
+    get_discord_channel_diagnostics, get_discord_role_diagnostics, send_discord_test_message,
+};
+
+/// Warns about permissions the bot is missing in a configured channel
+#[component]
+pub fn ChannelDiagnosticsNotice(guild_id: u64, channel_id: u64) -> Element {
+    let mut diagnostics = use_signal(|| None::<Result<ChannelDiagnosticsDto, ApiError>>);
+
+    #[cfg(feature = "web")]
+    {
+        let future = use_resource(move || async move {
+            get_discord_channel_diagnostics(guild_id, channel_id).await
+        });
+
+        use_effect(move || {
+            if let Some(result) = future.read_unchecked().as_ref() {
+                diagnostics.set(Some(result.clone()));
+            }
+        });
+    }
+
+    rsx! {
+        match diagnostics() {
+            Some(Ok(result)) => rsx! {
+                if let Some(error) = result.error {
+                    p { class: "text-xs text-warning font-normal", "{error}" }
+                } else if !result.missing_permissions.is_empty() {
+                    p {
+                        class: "text-xs text-warning font-normal",
+                        "Bot is missing: "
+                        {result.missing_permissions.iter().map(|p| p.label()).collect::<Vec<_>>().join(", ")}
+                    }
+                }
+            },
+            Some(Err(err)) => rsx! {
+                p { class: "text-xs text-warning font-normal", "Couldn't check permissions: {err.message}" }
+            },
+            None => rsx! {},
+        }
+    }
+}
+
+/// Sends a test message to a configured channel
+#[component]
+pub fn TestMessageButton(guild_id: u64, channel_id: u64, disabled: bool) -> Element {
+    let mut is_sending = use_signal(|| false);
+    let mut result = use_signal(|| None::<Result<(), ApiError>>);
+
+    #[cfg(feature = "web")]
+    {
+        let future = use_resource(move || async move {
+            if is_sending() {
+                Some(send_discord_test_message(guild_id, channel_id).await)
+            } else {
+                None
+            }
+        });
+
+        use_effect(move || {
+            if let Some(Some(sent)) = future.read_unchecked().as_ref() {
+                result.set(Some(sent.clone()));
+                is_sending.set(false);
+            }
+        });
+    }
+
+    let (class, tip) = match result() {
+        Some(Ok(())) => ("btn btn-xs btn-success", "Test message sent".to_string()),
+        Some(Err(err)) => ("btn btn-xs btn-warning", err.message),
+        None => (
+            "btn btn-xs",
+            "Send a test message to this channel".to_string(),
+        ),
+    };
+
+    rsx! {
+        div {
+            class: "tooltip tooltip-left",
+            "data-tip": "{tip}",
+            button {
+                r#type: "button",
+                class,
+                disabled: disabled || is_sending(),
+                onclick: move |_| {
+                    result.set(None);
+                    is_sending.set(true);
+                },
+                if is_sending() {
+                    span { class: "loading loading-spinner loading-xs" }
+                } else {
+                    "Test"
+                }
+            }
+        }
+    }
+}
+
+/// Warns when the bot can't ping a configured ping role
+#[component]
+pub fn RoleDiagnosticsNotice(guild_id: u64, role_id: u64) -> Element {
+    let mut diagnostics = use_signal(|| None::<Result<RoleDiagnosticsDto, ApiError>>);
+
+    #[cfg(feature = "web")]
+    {
+        let future =
+            use_resource(
+                move || async move { get_discord_role_diagnostics(guild_id, role_id).await },
+            );
+
+        use_effect(move || {
+            if let Some(result) = future.read_unchecked().as_ref() {
+                diagnostics.set(Some(result.clone()));
+            }
+        });
+    }
+
+    rsx! {
+        match diagnostics() {
+            Some(Ok(result)) if !result.can_ping() => rsx! {
+                p {
+                    class: "text-xs text-warning font-normal",
+                    "Role isn't mentionable and the bot lacks the Mention @everyone, @here, and All Roles permission"
+                }
+            },
+            Some(Err(err)) => rsx! {
+                p { class: "text-xs text-warning font-normal", "Couldn't check role: {err.message}" }
+            },
+            _ => rsx! {},
+        }
+    }
+}
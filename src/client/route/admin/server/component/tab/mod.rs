@@ -1,5 +1,6 @@
 mod access_role;
 mod channel;
+mod diagnostics;
 mod ping_role;
 
 pub use access_role::AccessRolesTab;
@@ -4,6 +4,7 @@ use crate::client::component::{DropdownItem, SearchableDropdown, SelectedItem, S
 use crate::model::discord::DiscordGuildRoleDto;
 
 use super::super::form_field::{FormFieldData, RoleData};
+use super::diagnostics::RoleDiagnosticsNotice;
 
 #[cfg(feature = "web")]
 use crate::client::api::discord::get_discord_guild_roles;
@@ -150,6 +151,7 @@ pub fn PingRolesTab(
                                 div {
                                     class: "flex-1 font-medium",
                                     "{role_name}"
+                                    RoleDiagnosticsNotice { guild_id, role_id }
                                 }
                             }
                         }
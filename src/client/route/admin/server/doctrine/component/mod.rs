@@ -0,0 +1,2 @@
+pub mod modal;
+pub mod table;
@@ -0,0 +1,354 @@
+use dioxus::prelude::*;
+use dioxus_logger::tracing;
+
+use crate::{
+    client::component::Modal,
+    model::doctrine::{CreateDoctrineDto, DoctrineDto, UpdateDoctrineDto},
+};
+
+#[cfg(feature = "web")]
+use crate::client::api::doctrine::{create_doctrine, update_doctrine};
+
+/// Form field values
+#[derive(Clone, Default)]
+struct FormFieldsData {
+    name: String,
+    description: String,
+    eft: String,
+}
+
+impl FormFieldsData {
+    /// Validates the form, fits are parsed & validated by the server
+    fn validate(&self) -> Option<String> {
+        if self.name.trim().is_empty() {
+            return Some("Doctrine name is required".to_string());
+        }
+        if self.eft.trim().is_empty() {
+            return Some("Paste at least one fit in EFT format".to_string());
+        }
+
+        None
+    }
+
+    fn description(&self) -> Option<String> {
+        let description = self.description.trim();
+        (!description.is_empty()).then(|| description.to_string())
+    }
+}
+
+#[component]
+pub fn CreateDoctrineModal(
+    guild_id: u64,
+    mut show: Signal<bool>,
+    mut refetch_trigger: Signal<u32>,
+) -> Element {
+    let mut form_fields = use_signal(FormFieldsData::default);
+    let mut submit_data = use_signal(|| None::<CreateDoctrineDto>);
+    let mut should_submit = use_signal(|| false);
+    let mut error = use_signal(|| None::<String>);
+
+    // Reset form when modal opens
+    use_effect(move || {
+        if show() {
+            form_fields.set(FormFieldsData::default());
+            submit_data.set(None);
+            should_submit.set(false);
+            error.set(None);
+        }
+    });
+
+    // Handle form submission with use_resource
+    #[cfg(feature = "web")]
+    let future = use_resource(move || async move {
+        if should_submit() {
+            let payload = submit_data()?;
+            Some(create_doctrine(guild_id, payload).await)
+        } else {
+            None
+        }
+    });
+
+    #[cfg(feature = "web")]
+    use_effect(move || {
+        if let Some(Some(result)) = future.read_unchecked().as_ref() {
+            match result {
+                Ok(_) => {
+                    form_fields.set(FormFieldsData::default());
+                    submit_data.set(None);
+                    error.set(None);
+                    should_submit.set(false);
+                    refetch_trigger.set(refetch_trigger() + 1);
+                    show.set(false);
+                }
+                Err(err) => {
+                    tracing::error!("Failed to create doctrine: {}", err);
+                    error.set(Some(err.message.clone()));
+                    should_submit.set(false);
+                }
+            }
+        }
+    });
+
+    let on_submit = move |evt: Event<FormData>| {
+        evt.prevent_default();
+
+        let fields = form_fields();
+        if let Some(validation_error) = fields.validate() {
+            error.set(Some(validation_error));
+            return;
+        }
+
+        error.set(None);
+        submit_data.set(Some(CreateDoctrineDto {
+            name: fields.name.trim().to_string(),
+            description: fields.description(),
+            eft: fields.eft.clone(),
+        }));
+        should_submit.set(true);
+    };
+
+    let is_submitting = should_submit();
+
+    rsx!(
+        Modal {
+            show,
+            title: "Create Doctrine".to_string(),
+            prevent_close: is_submitting,
+            form {
+                class: "flex flex-col gap-4",
+                onsubmit: on_submit,
+
+                DoctrineFormFields {
+                    form_fields,
+                    is_submitting,
+                }
+
+                // Error Message
+                if let Some(err) = error() {
+                    div {
+                        class: "alert alert-error mt-4",
+                        span { "{err}" }
+                    }
+                }
+
+                // Modal Actions
+                div {
+                    class: "modal-action",
+                    button {
+                        r#type: "button",
+                        class: "btn",
+                        onclick: move |_| show.set(false),
+                        disabled: is_submitting,
+                        "Cancel"
+                    }
+                    button {
+                        r#type: "submit",
+                        class: "btn btn-primary",
+                        disabled: is_submitting,
+                        if is_submitting {
+                            span { class: "loading loading-spinner loading-sm mr-2" }
+                            "Creating..."
+                        } else {
+                            "Create"
+                        }
+                    }
+                }
+            }
+        }
+    )
+}
+
+#[component]
+pub fn EditDoctrineModal(
+    guild_id: u64,
+    mut show: Signal<bool>,
+    doctrine_to_edit: Signal<Option<DoctrineDto>>,
+    mut refetch_trigger: Signal<u32>,
+) -> Element {
+    let mut form_fields = use_signal(FormFieldsData::default);
+    let mut doctrine_id = use_signal(|| 0i32);
+    let mut submit_data = use_signal(|| None::<UpdateDoctrineDto>);
+    let mut should_submit = use_signal(|| false);
+    let mut error = use_signal(|| None::<String>);
+
+    // Initialize form when modal opens with new data
+    use_effect(move || {
+        if show() {
+            if let Some(doctrine) = doctrine_to_edit() {
+                form_fields.set(FormFieldsData {
+                    name: doctrine.name.clone(),
+                    description: doctrine.description.clone().unwrap_or_default(),
+                    eft: doctrine.eft(),
+                });
+                doctrine_id.set(doctrine.id);
+                submit_data.set(None);
+                error.set(None);
+                should_submit.set(false);
+            }
+        }
+    });
+
+    // Handle form submission with use_resource
+    #[cfg(feature = "web")]
+    let future = use_resource(move || async move {
+        if should_submit() {
+            let payload = submit_data()?;
+            Some(update_doctrine(guild_id, doctrine_id(), payload).await)
+        } else {
+            None
+        }
+    });
+
+    #[cfg(feature = "web")]
+    use_effect(move || {
+        if let Some(Some(result)) = future.read_unchecked().as_ref() {
+            match result {
+                Ok(_) => {
+                    form_fields.set(FormFieldsData::default());
+                    submit_data.set(None);
+                    error.set(None);
+                    should_submit.set(false);
+                    refetch_trigger.set(refetch_trigger() + 1);
+                    show.set(false);
+                }
+                Err(err) => {
+                    tracing::error!("Failed to update doctrine: {}", err);
+                    error.set(Some(err.message.clone()));
+                    should_submit.set(false);
+                }
+            }
+        }
+    });
+
+    let on_submit = move |evt: Event<FormData>| {
+        evt.prevent_default();
+
+        let fields = form_fields();
+        if let Some(validation_error) = fields.validate() {
+            error.set(Some(validation_error));
+            return;
+        }
+
+        error.set(None);
+        submit_data.set(Some(UpdateDoctrineDto {
+            name: fields.name.trim().to_string(),
+            description: fields.description(),
+            eft: fields.eft.clone(),
+        }));
+        should_submit.set(true);
+    };
+
+    let is_submitting = should_submit();
+
+    rsx!(
+        Modal {
+            show,
+            title: "Edit Doctrine".to_string(),
+            prevent_close: is_submitting,
+            form {
+                class: "flex flex-col gap-4",
+                onsubmit: on_submit,
+
+                DoctrineFormFields {
+                    form_fields,
+                    is_submitting,
+                }
+
+                // Error Message
+                if let Some(err) = error() {
+                    div {
+                        class: "alert alert-error mt-4",
+                        span { "{err}" }
+                    }
+                }
+
+                // Modal Actions
+                div {
+                    class: "modal-action",
+                    button {
+                        r#type: "button",
+                        class: "btn",
+                        onclick: move |_| show.set(false),
+                        disabled: is_submitting,
+                        "Cancel"
+                    }
+                    button {
+                        r#type: "submit",
+                        class: "btn btn-primary",
+                        disabled: is_submitting,
+                        if is_submitting {
+                            span { class: "loading loading-spinner loading-sm mr-2" }
+                            "Updating..."
+                        } else {
+                            "Update"
+                        }
+                    }
+                }
+            }
+        }
+    )
+}
+
+#[component]
+fn DoctrineFormFields(mut form_fields: Signal<FormFieldsData>, is_submitting: bool) -> Element {
+    rsx!(
+        // Name field
+        div {
+            class: "form-control w-full flex flex-col gap-2",
+            label {
+                class: "label",
+                span { class: "label-text", "Doctrine Name" }
+            }
+            input {
+                r#type: "text",
+                class: "input input-bordered w-full",
+                placeholder: "e.g., Muninns, Ferox Fleet",
+                value: "{form_fields().name}",
+                disabled: is_submitting,
+                oninput: move |evt| {
+                    form_fields.write().name = evt.value();
+                }
+            }
+        }
+
+        // Description field
+        div {
+            class: "form-control w-full flex flex-col gap-2",
+            label {
+                class: "label",
+                span { class: "label-text", "Description (Optional)" }
+            }
+            textarea {
+                class: "textarea textarea-bordered w-full",
+                placeholder: "e.g., Bring links & 3 spare cap boosters",
+                value: "{form_fields().description}",
+                disabled: is_submitting,
+                oninput: move |evt| {
+                    form_fields.write().description = evt.value();
+                }
+            }
+        }
+
+        // EFT fits field
+        div {
+            class: "form-control w-full flex flex-col gap-2",
+            label {
+                class: "label",
+                span { class: "label-text", "Fits (EFT Format)" }
+            }
+            textarea {
+                class: "textarea textarea-bordered h-64 w-full font-mono text-sm",
+                placeholder: "[Muninn, Muninn - Fleet]\nDamage Control II\n...\n\n[Scimitar, Scimitar - Logi]\n...",
+                value: "{form_fields().eft}",
+                disabled: is_submitting,
+                oninput: move |evt| {
+                    form_fields.write().eft = evt.value();
+                }
+            }
+            label {
+                class: "label",
+                span { class: "label-text-alt opacity-70", "Copy fits from the in-game fitting window or Pyfa, each fit starts with its [Hull, Fit name] line" }
+            }
+        }
+    )
+}
@@ -0,0 +1,162 @@
+use dioxus::prelude::*;
+use dioxus_logger::tracing;
+
+use crate::{
+    client::{component::modal::ConfirmationModal, route::admin::server::DoctrinesCache},
+    model::doctrine::{DoctrineDto, PaginatedDoctrinesDto},
+};
+
+use super::modal::EditDoctrineModal;
+
+#[cfg(feature = "web")]
+use crate::client::api::doctrine::delete_doctrine;
+
+#[component]
+pub fn DoctrinesTable(
+    data: PaginatedDoctrinesDto,
+    guild_id: u64,
+    mut cache: Signal<DoctrinesCache>,
+    mut refetch_trigger: Signal<u32>,
+) -> Element {
+    let mut show_delete_modal = use_signal(|| false);
+    let mut doctrine_to_delete = use_signal(|| None::<(i32, String)>);
+    let mut is_deleting = use_signal(|| false);
+
+    let mut show_edit_modal = use_signal(|| false);
+    let mut doctrine_to_edit = use_signal(|| None::<DoctrineDto>);
+
+    // Handle deletion with use_resource
+    #[cfg(feature = "web")]
+    let delete_future = use_resource(move || async move {
+        if is_deleting() {
+            if let Some((id, _)) = doctrine_to_delete() {
+                Some(delete_doctrine(guild_id, id).await)
+            } else {
+                None
+            }
+        } else {
+            None
+        }
+    });
+
+    #[cfg(feature = "web")]
+    use_effect(move || {
+        if let Some(Some(result)) = delete_future.read_unchecked().as_ref() {
+            match result {
+                Ok(_) => {
+                    // Trigger refetch
+                    refetch_trigger.set(refetch_trigger() + 1);
+                    // Close modal (data persists for smooth animation)
+                    show_delete_modal.set(false);
+                    is_deleting.set(false);
+                }
+                Err(err) => {
+                    tracing::error!("Failed to delete doctrine: {}", err);
+                    is_deleting.set(false);
+                }
+            }
+        }
+    });
+
+    rsx!(
+        div {
+            class: "overflow-x-auto",
+            table {
+                class: "table table-zebra w-full",
+                thead {
+                    tr {
+                        th { "Name" }
+                        th { "Hulls" }
+                        th { "Fits" }
+                        th {
+                            class: "text-right",
+                            "Actions"
+                        }
+                    }
+                }
+                tbody {
+                    for doctrine in &data.items {
+                        {
+                            let doctrine_id = doctrine.id;
+                            let doctrine_for_edit = doctrine.clone();
+                            let name_for_delete = doctrine.name.clone();
+                            let hulls = doctrine.hull_list();
+                            let fit_count = doctrine.fits.len();
+
+                            rsx! {
+                                tr {
+                                    td {
+                                        div { class: "font-bold", "{doctrine.name}" }
+                                        if let Some(description) = &doctrine.description {
+                                            div { class: "text-sm opacity-70", "{description}" }
+                                        }
+                                    }
+                                    td { "{hulls}" }
+                                    td { "{fit_count}" }
+                                    td {
+                                        div {
+                                            class: "flex gap-2 justify-end",
+                                            button {
+                                                class: "btn btn-sm btn-primary",
+                                                onclick: move |_| {
+                                                    doctrine_to_edit.set(Some(doctrine_for_edit.clone()));
+                                                    show_edit_modal.set(true);
+                                                },
+                                                "Edit"
+                                            }
+                                            button {
+                                                class: "btn btn-sm btn-error",
+                                                onclick: move |_| {
+                                                    doctrine_to_delete.set(Some((doctrine_id, name_for_delete.clone())));
+                                                    show_delete_modal.set(true);
+                                                },
+                                                "Delete"
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        // Delete Confirmation Modal
+        ConfirmationModal {
+            show: show_delete_modal,
+            title: "Delete Doctrine".to_string(),
+            message: rsx!(
+                if let Some((_, name)) = doctrine_to_delete() {
+                    div {
+                        class: "py-4",
+                        p {
+                            "Are you sure you want to delete the doctrine "
+                            span { class: "font-bold", "\"{name}\"" }
+                            "?"
+                        }
+                        p {
+                            class: "mt-4",
+                            "Fleets using it will show an unknown doctrine. This action cannot be undone."
+                        }
+                    }
+                }
+            ),
+            confirm_text: "Delete".to_string(),
+            confirm_class: "btn-error".to_string(),
+            is_processing: is_deleting(),
+            processing_text: "Deleting...".to_string(),
+            on_confirm: move |_| {
+                is_deleting.set(true);
+            },
+        }
+
+        // Edit Doctrine Modal
+        EditDoctrineModal {
+            guild_id,
+            show: show_edit_modal,
+            doctrine_to_edit,
+            refetch_trigger
+        }
+    )
+}
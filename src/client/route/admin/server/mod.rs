@@ -1,10 +1,18 @@
 mod component;
 
+pub mod doctrine;
 pub mod ping_format;
 pub mod ping_group;
+pub mod relay;
+pub mod settings;
+pub mod webhook;
 
+pub use doctrine::ServerAdminDoctrine;
 pub use ping_format::ServerAdminPingFormat;
 pub use ping_group::ServerAdminPingGroup;
+pub use relay::ServerAdminRelay;
+pub use settings::ServerAdminSettings;
+pub use webhook::ServerAdminWebhook;
 
 use dioxus::prelude::*;
 use dioxus_logger::tracing;
@@ -21,7 +29,8 @@ use crate::{
     },
     model::{
         category::PaginatedFleetCategoriesDto, discord::DiscordGuildDto,
-        ping_format::PaginatedPingFormatsDto, ping_group::PaginatedPingGroupsDto,
+        doctrine::PaginatedDoctrinesDto, ping_format::PaginatedPingFormatsDto,
+        ping_group::PaginatedPingGroupsDto, webhook::PaginatedWebhooksDto,
     },
 };
 
@@ -77,6 +86,24 @@ pub struct PingGroupsCache {
     pub per_page: u64,
 }
 
+/// Cached doctrines data for a specific guild
+#[derive(Clone, PartialEq)]
+pub struct DoctrinesCache {
+    pub guild_id: u64,
+    pub data: Option<PaginatedDoctrinesDto>,
+    pub page: u64,
+    pub per_page: u64,
+}
+
+/// Cached webhooks data for a specific guild
+#[derive(Clone, PartialEq)]
+pub struct WebhooksCache {
+    pub guild_id: u64,
+    pub data: Option<PaginatedWebhooksDto>,
+    pub page: u64,
+    pub per_page: u64,
+}
+
 /// Layout component that provides guild context for server admin pages
 /// This layout is automatically dropped when navigating away from server admin pages,
 /// which cleans up the guild context.
@@ -115,6 +142,24 @@ pub fn ServerAdminLayout() -> Element {
         })
     });
 
+    use_context_provider(|| {
+        Signal::new(DoctrinesCache {
+            guild_id: 0,
+            data: None,
+            page: 0,
+            per_page: 10,
+        })
+    });
+
+    use_context_provider(|| {
+        Signal::new(WebhooksCache {
+            guild_id: 0,
+            data: None,
+            page: 0,
+            per_page: 10,
+        })
+    });
+
     rsx! {
         // Render child routes (ServerAdminFleetCategory or ServerAdminPingFormat)
         Outlet::<Route> {}
@@ -126,6 +171,10 @@ pub enum ServerAdminTab {
     FleetCategories,
     PingFormats,
     PingGroup,
+    Doctrines,
+    Webhooks,
+    Relays,
+    Settings,
 }
 
 #[component]
@@ -191,6 +240,30 @@ pub fn ActionTabs(guild_id: u64, active_tab: ServerAdminTab) -> Element {
                 class: if active_tab == ServerAdminTab::PingFormats { "tab tab-active" } else { "tab" },
                 "Ping Formats"
             }
+            Link {
+                to: Route::ServerAdminDoctrine { guild_id },
+                role: "tab",
+                class: if active_tab == ServerAdminTab::Doctrines { "tab tab-active" } else { "tab" },
+                "Doctrines"
+            }
+            Link {
+                to: Route::ServerAdminWebhook { guild_id },
+                role: "tab",
+                class: if active_tab == ServerAdminTab::Webhooks { "tab tab-active" } else { "tab" },
+                "Webhooks"
+            }
+            Link {
+                to: Route::ServerAdminRelay { guild_id },
+                role: "tab",
+                class: if active_tab == ServerAdminTab::Relays { "tab tab-active" } else { "tab" },
+                "Relays"
+            }
+            Link {
+                to: Route::ServerAdminSettings { guild_id },
+                role: "tab",
+                class: if active_tab == ServerAdminTab::Settings { "tab tab-active" } else { "tab" },
+                "Settings"
+            }
         }
     )
 }
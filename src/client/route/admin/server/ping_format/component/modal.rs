@@ -488,7 +488,7 @@ fn PingFormatFormFields(mut form_fields: Signal<FormFieldsData>, is_submitting:
                                             r#type: "radio",
                                             class: "radio radio-sm",
                                             name: "field_type_{index}",
-                                            checked: !is_text_type,
+                                            checked: matches!(field_type, PingFormatFieldType::Bool),
                                             disabled: is_submitting,
                                             onchange: move |_| {
                                                 form_fields.write().fields[index].field_type = PingFormatFieldType::Bool;
@@ -498,6 +498,38 @@ fn PingFormatFormFields(mut form_fields: Signal<FormFieldsData>, is_submitting:
                                         }
                                         span { class: "label-text", "Checkbox" }
                                     }
+                                    label {
+                                        class: "label cursor-pointer gap-2",
+                                        input {
+                                            r#type: "radio",
+                                            class: "radio radio-sm",
+                                            name: "field_type_{index}",
+                                            checked: matches!(field_type, PingFormatFieldType::SolarSystem),
+                                            disabled: is_submitting,
+                                            onchange: move |_| {
+                                                form_fields.write().fields[index].field_type = PingFormatFieldType::SolarSystem;
+                                                // Solar systems are picked from the static map rather than defaults
+                                                form_fields.write().fields[index].default_values.clear();
+                                            }
+                                        }
+                                        span { class: "label-text", "Solar System" }
+                                    }
+                                    label {
+                                        class: "label cursor-pointer gap-2",
+                                        input {
+                                            r#type: "radio",
+                                            class: "radio radio-sm",
+                                            name: "field_type_{index}",
+                                            checked: matches!(field_type, PingFormatFieldType::Doctrine),
+                                            disabled: is_submitting,
+                                            onchange: move |_| {
+                                                form_fields.write().fields[index].field_type = PingFormatFieldType::Doctrine;
+                                                // Doctrines are picked from the guild's doctrine library
+                                                form_fields.write().fields[index].default_values.clear();
+                                            }
+                                        }
+                                        span { class: "label-text", "Doctrine" }
+                                    }
                                 }
 
                                 // Default values section (only for text type)
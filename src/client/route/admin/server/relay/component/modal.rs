@@ -0,0 +1,409 @@
+use dioxus::prelude::*;
+use dioxus_logger::tracing;
+
+use crate::{
+    client::component::Modal,
+    model::{
+        category::FleetCategoryListItemDto,
+        discord::DiscordGuildDto,
+        fleet_relay::{AcceptFleetRelayDto, CreateFleetRelayDto, FleetRelayDto},
+    },
+};
+
+#[cfg(feature = "web")]
+use crate::model::discord::DiscordGuildStatus;
+
+#[cfg(feature = "web")]
+use crate::client::api::{
+    category::get_fleet_categories,
+    discord_guild::get_all_discord_guilds,
+    fleet_relay::{accept_fleet_relay, create_fleet_relay},
+};
+
+/// Most categories listed when choosing the category of a relay
+#[cfg(feature = "web")]
+const CATEGORY_LIMIT: u64 = 100;
+
+#[component]
+pub fn CreateRelayModal(
+    guild_id: u64,
+    mut show: Signal<bool>,
+    mut refetch_trigger: Signal<u32>,
+) -> Element {
+    let mut categories = use_signal(Vec::<FleetCategoryListItemDto>::new);
+    let mut guilds = use_signal(Vec::<DiscordGuildDto>::new);
+    let mut category_id = use_signal(|| None::<i32>);
+    let mut target_guild_id = use_signal(|| None::<u64>);
+    let mut submit_data = use_signal(|| None::<CreateFleetRelayDto>);
+    let mut should_submit = use_signal(|| false);
+    let mut error = use_signal(|| None::<String>);
+
+    // Reset form when modal opens
+    use_effect(move || {
+        if show() {
+            category_id.set(None);
+            target_guild_id.set(None);
+            submit_data.set(None);
+            should_submit.set(false);
+            error.set(None);
+        }
+    });
+
+    #[cfg(feature = "web")]
+    {
+        let options_future = use_resource(move || async move {
+            if !show() {
+                return None;
+            }
+            let categories = get_fleet_categories(guild_id, 0, CATEGORY_LIMIT).await;
+            let guilds = get_all_discord_guilds().await;
+            Some((categories, guilds))
+        });
+
+        use_effect(move || {
+            if let Some(Some((category_result, guild_result))) =
+                options_future.read_unchecked().as_ref()
+            {
+                match category_result {
+                    Ok(data) => categories.set(data.categories.clone()),
+                    Err(err) => {
+                        tracing::error!("Failed to fetch fleet categories: {}", err);
+                        error.set(Some(err.message.clone()));
+                    }
+                }
+                match guild_result {
+                    Ok(data) => guilds.set(
+                        data.iter()
+                            .filter(|guild| {
+                                guild.guild_id != guild_id
+                                    && guild.status == DiscordGuildStatus::Active
+                            })
+                            .cloned()
+                            .collect(),
+                    ),
+                    Err(err) => {
+                        tracing::error!("Failed to fetch servers: {}", err);
+                        error.set(Some(err.message.clone()));
+                    }
+                }
+            }
+        });
+
+        let future = use_resource(move || async move {
+            if should_submit() {
+                let payload = submit_data()?;
+                Some(create_fleet_relay(guild_id, payload).await)
+            } else {
+                None
+            }
+        });
+
+        use_effect(move || {
+            if let Some(Some(result)) = future.read_unchecked().as_ref() {
+                match result {
+                    Ok(_) => {
+                        submit_data.set(None);
+                        error.set(None);
+                        should_submit.set(false);
+                        refetch_trigger.set(refetch_trigger() + 1);
+                        show.set(false);
+                    }
+                    Err(err) => {
+                        tracing::error!("Failed to create fleet relay: {}", err);
+                        error.set(Some(err.message.clone()));
+                        should_submit.set(false);
+                    }
+                }
+            }
+        });
+    }
+
+    let on_submit = move |evt: Event<FormData>| {
+        evt.prevent_default();
+
+        let (Some(source_category_id), Some(target_guild_id)) = (category_id(), target_guild_id())
+        else {
+            error.set(Some("Select a category and a server".to_string()));
+            return;
+        };
+
+        error.set(None);
+        submit_data.set(Some(CreateFleetRelayDto {
+            source_category_id,
+            target_guild_id,
+        }));
+        should_submit.set(true);
+    };
+
+    let is_submitting = should_submit();
+
+    rsx!(
+        Modal {
+            show,
+            title: "Relay Category".to_string(),
+            prevent_close: is_submitting,
+            form {
+                class: "flex flex-col gap-4",
+                onsubmit: on_submit,
+
+                div {
+                    class: "flex flex-col gap-2",
+                    label {
+                        class: "label",
+                        span { class: "label-text", "Category" }
+                    }
+                    select {
+                        class: "select select-bordered w-full",
+                        disabled: is_submitting,
+                        onchange: move |e| category_id.set(e.value().parse::<i32>().ok()),
+                        option {
+                            value: "",
+                            selected: category_id().is_none(),
+                            "Select a category"
+                        }
+                        for category in categories() {
+                            option {
+                                key: "{category.id}",
+                                value: "{category.id}",
+                                selected: Some(category.id) == category_id(),
+                                "{category.name}"
+                            }
+                        }
+                    }
+                }
+
+                div {
+                    class: "flex flex-col gap-2",
+                    label {
+                        class: "label",
+                        span { class: "label-text", "Server" }
+                    }
+                    select {
+                        class: "select select-bordered w-full",
+                        disabled: is_submitting,
+                        onchange: move |e| target_guild_id.set(e.value().parse::<u64>().ok()),
+                        option {
+                            value: "",
+                            selected: target_guild_id().is_none(),
+                            "Select a server"
+                        }
+                        for guild in guilds() {
+                            option {
+                                key: "{guild.guild_id}",
+                                value: "{guild.guild_id}",
+                                selected: Some(guild.guild_id) == target_guild_id(),
+                                "{guild.name}"
+                            }
+                        }
+                    }
+                }
+
+                p {
+                    class: "text-sm opacity-70",
+                    "The server's admins choose the category relayed fleets are posted in when accepting the relay."
+                }
+
+                // Error Message
+                if let Some(err) = error() {
+                    div {
+                        class: "alert alert-error mt-4",
+                        span { "{err}" }
+                    }
+                }
+
+                // Modal Actions
+                div {
+                    class: "modal-action",
+                    button {
+                        r#type: "button",
+                        class: "btn",
+                        onclick: move |_| show.set(false),
+                        disabled: is_submitting,
+                        "Cancel"
+                    }
+                    button {
+                        r#type: "submit",
+                        class: "btn btn-primary",
+                        disabled: is_submitting,
+                        if is_submitting {
+                            span { class: "loading loading-spinner loading-sm mr-2" }
+                            "Requesting..."
+                        } else {
+                            "Request Relay"
+                        }
+                    }
+                }
+            }
+        }
+    )
+}
+
+#[component]
+pub fn AcceptRelayModal(
+    guild_id: u64,
+    mut show: Signal<bool>,
+    relay: Signal<Option<FleetRelayDto>>,
+    mut refetch_trigger: Signal<u32>,
+) -> Element {
+    let mut categories = use_signal(Vec::<FleetCategoryListItemDto>::new);
+    let mut category_id = use_signal(|| None::<i32>);
+    let mut submit_data = use_signal(|| None::<(i32, AcceptFleetRelayDto)>);
+    let mut should_submit = use_signal(|| false);
+    let mut error = use_signal(|| None::<String>);
+
+    // Initialize form when modal opens with new data
+    use_effect(move || {
+        if show() {
+            category_id.set(relay().and_then(|relay| relay.target_category_id));
+            submit_data.set(None);
+            should_submit.set(false);
+            error.set(None);
+        }
+    });
+
+    #[cfg(feature = "web")]
+    {
+        let categories_future = use_resource(move || async move {
+            if !show() {
+                return None;
+            }
+            Some(get_fleet_categories(guild_id, 0, CATEGORY_LIMIT).await)
+        });
+
+        use_effect(move || {
+            if let Some(Some(result)) = categories_future.read_unchecked().as_ref() {
+                match result {
+                    Ok(data) => categories.set(data.categories.clone()),
+                    Err(err) => {
+                        tracing::error!("Failed to fetch fleet categories: {}", err);
+                        error.set(Some(err.message.clone()));
+                    }
+                }
+            }
+        });
+
+        let future = use_resource(move || async move {
+            if should_submit() {
+                let (id, payload) = submit_data()?;
+                Some(accept_fleet_relay(guild_id, id, payload).await)
+            } else {
+                None
+            }
+        });
+
+        use_effect(move || {
+            if let Some(Some(result)) = future.read_unchecked().as_ref() {
+                match result {
+                    Ok(_) => {
+                        submit_data.set(None);
+                        error.set(None);
+                        should_submit.set(false);
+                        refetch_trigger.set(refetch_trigger() + 1);
+                        show.set(false);
+                    }
+                    Err(err) => {
+                        tracing::error!("Failed to accept fleet relay: {}", err);
+                        error.set(Some(err.message.clone()));
+                        should_submit.set(false);
+                    }
+                }
+            }
+        });
+    }
+
+    let on_submit = move |evt: Event<FormData>| {
+        evt.prevent_default();
+
+        let (Some(relay), Some(target_category_id)) = (relay(), category_id()) else {
+            error.set(Some(
+                "Select the category relayed fleets are posted in".to_string(),
+            ));
+            return;
+        };
+
+        error.set(None);
+        submit_data.set(Some((relay.id, AcceptFleetRelayDto { target_category_id })));
+        should_submit.set(true);
+    };
+
+    let is_submitting = should_submit();
+
+    rsx!(
+        Modal {
+            show,
+            title: "Accept Relay".to_string(),
+            prevent_close: is_submitting,
+            form {
+                class: "flex flex-col gap-4",
+                onsubmit: on_submit,
+
+                if let Some(relay) = relay() {
+                    p {
+                        "Fleets of "
+                        span { class: "font-bold", "\"{relay.source_category_name}\"" }
+                        " in "
+                        span { class: "font-bold", "{relay.source_guild_name}" }
+                        " will be posted in the chosen category, pinging its roles in its channels."
+                    }
+                }
+
+                div {
+                    class: "flex flex-col gap-2",
+                    label {
+                        class: "label",
+                        span { class: "label-text", "Category" }
+                    }
+                    select {
+                        class: "select select-bordered w-full",
+                        disabled: is_submitting,
+                        onchange: move |e| category_id.set(e.value().parse::<i32>().ok()),
+                        option {
+                            value: "",
+                            selected: category_id().is_none(),
+                            "Select a category"
+                        }
+                        for category in categories() {
+                            option {
+                                key: "{category.id}",
+                                value: "{category.id}",
+                                selected: Some(category.id) == category_id(),
+                                "{category.name}"
+                            }
+                        }
+                    }
+                }
+
+                // Error Message
+                if let Some(err) = error() {
+                    div {
+                        class: "alert alert-error mt-4",
+                        span { "{err}" }
+                    }
+                }
+
+                // Modal Actions
+                div {
+                    class: "modal-action",
+                    button {
+                        r#type: "button",
+                        class: "btn",
+                        onclick: move |_| show.set(false),
+                        disabled: is_submitting,
+                        "Cancel"
+                    }
+                    button {
+                        r#type: "submit",
+                        class: "btn btn-primary",
+                        disabled: is_submitting,
+                        if is_submitting {
+                            span { class: "loading loading-spinner loading-sm mr-2" }
+                            "Accepting..."
+                        } else {
+                            "Accept"
+                        }
+                    }
+                }
+            }
+        }
+    )
+}
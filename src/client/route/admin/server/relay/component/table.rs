@@ -0,0 +1,217 @@
+use dioxus::prelude::*;
+use dioxus_logger::tracing;
+
+use crate::{
+    client::component::modal::ConfirmationModal,
+    model::fleet_relay::{FleetRelayDto, FleetRelayStatus},
+};
+
+use super::modal::AcceptRelayModal;
+
+#[cfg(feature = "web")]
+use crate::client::api::fleet_relay::{decline_fleet_relay, delete_fleet_relay};
+
+/// Table of a guild's outgoing or incoming relays
+///
+/// Incoming relays can be accepted or declined, either side can remove a relay.
+#[component]
+pub fn RelaysTable(
+    relays: Vec<FleetRelayDto>,
+    guild_id: u64,
+    incoming: bool,
+    mut refetch_trigger: Signal<u32>,
+) -> Element {
+    let mut show_delete_modal = use_signal(|| false);
+    let mut relay_to_delete = use_signal(|| None::<FleetRelayDto>);
+    let mut is_deleting = use_signal(|| false);
+
+    let mut show_accept_modal = use_signal(|| false);
+    let mut relay_to_accept = use_signal(|| None::<FleetRelayDto>);
+
+    let mut relay_to_decline = use_signal(|| None::<i32>);
+
+    // Handle deletion with use_resource
+    #[cfg(feature = "web")]
+    let delete_future = use_resource(move || async move {
+        if is_deleting() {
+            if let Some(relay) = relay_to_delete() {
+                Some(delete_fleet_relay(guild_id, relay.id).await)
+            } else {
+                None
+            }
+        } else {
+            None
+        }
+    });
+
+    #[cfg(feature = "web")]
+    use_effect(move || {
+        if let Some(Some(result)) = delete_future.read_unchecked().as_ref() {
+            match result {
+                Ok(_) => {
+                    refetch_trigger.set(refetch_trigger() + 1);
+                    show_delete_modal.set(false);
+                    is_deleting.set(false);
+                }
+                Err(err) => {
+                    tracing::error!("Failed to remove fleet relay: {}", err);
+                    is_deleting.set(false);
+                }
+            }
+        }
+    });
+
+    #[cfg(feature = "web")]
+    let decline_future = use_resource(move || async move {
+        let id = relay_to_decline()?;
+        Some(decline_fleet_relay(guild_id, id).await)
+    });
+
+    #[cfg(feature = "web")]
+    use_effect(move || {
+        if let Some(Some(result)) = decline_future.read_unchecked().as_ref() {
+            if let Err(err) = result {
+                tracing::error!("Failed to decline fleet relay: {}", err);
+            }
+            relay_to_decline.set(None);
+            refetch_trigger.set(refetch_trigger() + 1);
+        }
+    });
+
+    rsx!(
+        div {
+            class: "overflow-x-auto",
+            table {
+                class: "table table-zebra w-full",
+                thead {
+                    tr {
+                        if incoming {
+                            th { "From Server" }
+                            th { "Category" }
+                            th { "Posted In" }
+                        } else {
+                            th { "Category" }
+                            th { "To Server" }
+                            th { "Posted In" }
+                        }
+                        th { "Status" }
+                        th {
+                            class: "text-right",
+                            "Actions"
+                        }
+                    }
+                }
+                tbody {
+                    for relay in relays {
+                        {
+                            let relay_for_accept = relay.clone();
+                            let relay_for_delete = relay.clone();
+                            let relay_id = relay.id;
+                            let target_category = relay
+                                .target_category_name
+                                .clone()
+                                .unwrap_or_else(|| "-".to_string());
+                            let status_class = match relay.status {
+                                FleetRelayStatus::Pending => "badge badge-warning badge-sm",
+                                FleetRelayStatus::Accepted => "badge badge-success badge-sm",
+                                FleetRelayStatus::Declined => "badge badge-ghost badge-sm",
+                            };
+
+                            rsx! {
+                                tr {
+                                    key: "{relay.id}",
+                                    if incoming {
+                                        td { "{relay.source_guild_name}" }
+                                        td { "{relay.source_category_name}" }
+                                    } else {
+                                        td { "{relay.source_category_name}" }
+                                        td { "{relay.target_guild_name}" }
+                                    }
+                                    td { "{target_category}" }
+                                    td {
+                                        span { class: status_class, "{relay.status.label()}" }
+                                    }
+                                    td {
+                                        div {
+                                            class: "flex gap-2 justify-end",
+                                            if incoming {
+                                                button {
+                                                    class: "btn btn-sm btn-primary",
+                                                    onclick: move |_| {
+                                                        relay_to_accept.set(Some(relay_for_accept.clone()));
+                                                        show_accept_modal.set(true);
+                                                    },
+                                                    if relay.status == FleetRelayStatus::Accepted {
+                                                        "Change Category"
+                                                    } else {
+                                                        "Accept"
+                                                    }
+                                                }
+                                                if relay.status != FleetRelayStatus::Declined {
+                                                    button {
+                                                        class: "btn btn-sm",
+                                                        disabled: relay_to_decline().is_some(),
+                                                        onclick: move |_| relay_to_decline.set(Some(relay_id)),
+                                                        "Decline"
+                                                    }
+                                                }
+                                            }
+                                            button {
+                                                class: "btn btn-sm btn-error",
+                                                onclick: move |_| {
+                                                    relay_to_delete.set(Some(relay_for_delete.clone()));
+                                                    show_delete_modal.set(true);
+                                                },
+                                                "Remove"
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        // Remove Confirmation Modal
+        ConfirmationModal {
+            show: show_delete_modal,
+            title: "Remove Relay".to_string(),
+            message: rsx!(
+                if let Some(relay) = relay_to_delete() {
+                    div {
+                        class: "py-4",
+                        p {
+                            "Are you sure you want to remove the relay of "
+                            span { class: "font-bold", "\"{relay.source_category_name}\"" }
+                            " from "
+                            span { class: "font-bold", "{relay.source_guild_name}" }
+                            " to "
+                            span { class: "font-bold", "{relay.target_guild_name}" }
+                            "?"
+                        }
+                        p {
+                            class: "mt-4",
+                            "Fleets already relayed are kept as regular fleets of the target server."
+                        }
+                    }
+                }
+            ),
+            confirm_text: "Remove".to_string(),
+            confirm_class: "btn-error".to_string(),
+            is_processing: is_deleting(),
+            processing_text: "Removing...".to_string(),
+            on_confirm: move |_| {
+                is_deleting.set(true);
+            },
+        }
+
+        AcceptRelayModal {
+            guild_id,
+            show: show_accept_modal,
+            relay: relay_to_accept,
+            refetch_trigger
+        }
+    )
+}
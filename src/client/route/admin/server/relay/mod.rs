@@ -0,0 +1,225 @@
+mod component;
+
+use component::{modal::CreateRelayModal, table::RelaysTable};
+use dioxus::prelude::*;
+use dioxus_logger::tracing;
+
+use crate::{
+    client::{
+        component::{
+            page::{ErrorPage, LoadingPage},
+            Page,
+        },
+        constant::SITE_NAME,
+        model::error::ApiError,
+        route::admin::server::{ActionTabs, GuildInfoHeader, ServerAdminTab},
+        router::Route,
+    },
+    model::{discord::DiscordGuildDto, fleet_relay::FleetRelaysDto},
+};
+
+#[cfg(feature = "web")]
+use crate::client::api::{discord_guild::get_discord_guild_by_id, fleet_relay::get_fleet_relays};
+
+#[component]
+pub fn ServerAdminRelay(guild_id: u64) -> Element {
+    let mut guild = use_context::<Signal<Option<DiscordGuildDto>>>();
+    let mut error = use_signal(|| None::<ApiError>);
+
+    // Fetch guild data using use_resource if not already cached
+    #[cfg(feature = "web")]
+    {
+        let mut should_fetch = use_signal(|| false);
+
+        // Check cache and initiate fetch if needed
+        use_effect(use_reactive!(|guild_id| {
+            // Skip if already fetching
+            if should_fetch() {
+                return;
+            }
+
+            // Only run resource if we need to fetch
+            let needs_fetch = guild.read().as_ref().map(|g| g.guild_id) != Some(guild_id);
+
+            if needs_fetch {
+                should_fetch.set(true);
+            }
+        }));
+
+        let future = use_resource(move || async move {
+            if should_fetch() {
+                Some(get_discord_guild_by_id(guild_id).await)
+            } else {
+                None
+            }
+        });
+
+        use_effect(move || {
+            if let Some(Some(result)) = future.read_unchecked().as_ref() {
+                match result {
+                    Ok(guild_data) => {
+                        guild.set(Some(guild_data.clone()));
+                        error.set(None);
+                    }
+                    Err(err) => {
+                        tracing::error!("Failed to fetch guild: {}", err);
+                        guild.set(None);
+                        error.set(Some(err.clone()));
+                    }
+                }
+            }
+        });
+    }
+
+    rsx!(
+        Title { "Relays | {SITE_NAME}" }
+        if let Some(guild_data) = guild.read().clone() {
+            Page {
+                class: "flex flex-col items-center w-full h-full",
+                div {
+                    class: "w-full max-w-6xl",
+                    Link {
+                        to: Route::AdminServers {},
+                        class: "btn btn-ghost mb-4",
+                        "← Back to Servers"
+                    }
+                    GuildInfoHeader { guild_data: guild_data.clone() }
+                    ActionTabs { guild_id, active_tab: ServerAdminTab::Relays }
+                    div {
+                        class: "space-y-6",
+                        RelaySection { guild_id }
+                    }
+                }
+            }
+        } else if let Some(err) = error() {
+            ErrorPage { status: err.status, message: err.message }
+        } else {
+            LoadingPage { }
+        }
+    )
+}
+
+#[component]
+fn RelaySection(guild_id: u64) -> Element {
+    let mut relays = use_signal(|| None::<FleetRelaysDto>);
+    let mut error = use_signal(|| None::<ApiError>);
+    let mut show_create_modal = use_signal(|| false);
+    let refetch_trigger = use_signal(|| 0u32);
+
+    // Fetch relays - resource automatically re-runs when refetch_trigger changes
+    #[cfg(feature = "web")]
+    let future = use_resource(move || async move {
+        let _ = refetch_trigger();
+        get_fleet_relays(guild_id).await
+    });
+
+    #[cfg(feature = "web")]
+    use_effect(move || {
+        if let Some(result) = future.read_unchecked().as_ref() {
+            match result {
+                Ok(data) => {
+                    relays.set(Some(data.clone()));
+                    error.set(None);
+                }
+                Err(err) => {
+                    tracing::error!("Failed to fetch fleet relays: {}", err);
+                    relays.set(None);
+                    error.set(Some(err.clone()));
+                }
+            }
+        }
+    });
+
+    rsx!(
+        div {
+            class: "card bg-base-200",
+            div {
+                class: "card-body",
+                div {
+                    class: "flex justify-between items-center mb-4",
+                    div {
+                        h2 {
+                            class: "card-title",
+                            "Outgoing Relays"
+                        }
+                        p {
+                            class: "text-sm opacity-70",
+                            "Fleets of these categories are posted in other servers once their admins accept the relay."
+                        }
+                    }
+                    button {
+                        class: "btn btn-primary",
+                        onclick: move |_| show_create_modal.set(true),
+                        "Relay Category"
+                    }
+                }
+
+                if let Some(data) = relays() {
+                    if data.outgoing.is_empty() {
+                        div {
+                            class: "text-center py-8 opacity-50",
+                            "No categories relayed to other servers"
+                        }
+                    } else {
+                        RelaysTable {
+                            relays: data.outgoing.clone(),
+                            guild_id,
+                            incoming: false,
+                            refetch_trigger
+                        }
+                    }
+                } else if let Some(err) = error() {
+                    div {
+                        class: "alert alert-error",
+                        span { "Error loading relays: {err.message}" }
+                    }
+                } else {
+                    div {
+                        class: "text-center py-8",
+                        span { class: "loading loading-spinner loading-lg" }
+                    }
+                }
+
+                CreateRelayModal {
+                    guild_id,
+                    show: show_create_modal,
+                    refetch_trigger
+                }
+            }
+        }
+
+        div {
+            class: "card bg-base-200",
+            div {
+                class: "card-body",
+                div {
+                    class: "mb-4",
+                    h2 {
+                        class: "card-title",
+                        "Incoming Relays"
+                    }
+                    p {
+                        class: "text-sm opacity-70",
+                        "Accepted relays post fleets of other servers in the chosen category using its channels & ping roles. Relayed fleets can only be changed in the server they were relayed from."
+                    }
+                }
+
+                if let Some(data) = relays() {
+                    if data.incoming.is_empty() {
+                        div {
+                            class: "text-center py-8 opacity-50",
+                            "No relays requested by other servers"
+                        }
+                    } else {
+                        RelaysTable {
+                            relays: data.incoming.clone(),
+                            guild_id,
+                            incoming: true,
+                            refetch_trigger
+                        }
+                    }
+                }
+            }
+        }
+    )
+}
@@ -0,0 +1,251 @@
+use dioxus::prelude::*;
+use dioxus_logger::tracing;
+
+use crate::{
+    client::model::error::ApiError,
+    model::channel_list_settings::{ChannelListSettingsDto, UpdateChannelListSettingsDto},
+};
+
+#[cfg(feature = "web")]
+use crate::client::api::channel_list_settings::{
+    get_channel_list_settings, update_channel_list_settings,
+};
+
+use super::MAX_LIST_LOOKAHEAD_HOURS;
+
+/// Most fleets an upcoming fleets list can be limited to
+const MAX_LIST_ENTRIES: i32 = 200;
+
+/// Card listing the upcoming fleets list settings of each channel categories post in
+#[component]
+pub fn ChannelListSettingsSection(guild_id: u64) -> Element {
+    let mut channels = use_signal(|| None::<Result<Vec<ChannelListSettingsDto>, ApiError>>);
+
+    #[cfg(feature = "web")]
+    {
+        let future = use_resource(move || async move { get_channel_list_settings(guild_id).await });
+
+        use_effect(move || {
+            if let Some(result) = future.read_unchecked().as_ref() {
+                if let Err(err) = result {
+                    tracing::error!("Failed to fetch channel list settings: {}", err);
+                }
+                channels.set(Some(result.clone()));
+            }
+        });
+    }
+
+    rsx!(
+        div {
+            class: "card bg-base-200",
+            div {
+                class: "card-body",
+                div {
+                    class: "mb-4",
+                    h2 {
+                        class: "card-title",
+                        "Upcoming Fleets Lists"
+                    }
+                    p {
+                        class: "text-sm opacity-70",
+                        "Configure the upcoming fleets list of each channel categories post in. Empty lookahead uses the server's lookahead, long lists continue in additional messages."
+                    }
+                }
+
+                match channels() {
+                    Some(Ok(data)) => rsx! {
+                        if data.is_empty() {
+                            div {
+                                class: "text-center py-8 opacity-50",
+                                "No channels with an upcoming fleets list"
+                            }
+                        } else {
+                            div {
+                                class: "overflow-x-auto",
+                                table {
+                                    class: "table table-zebra w-full",
+                                    thead {
+                                        tr {
+                                            th { "Channel" }
+                                            th { "Lookahead (hours)" }
+                                            th { "Max Fleets" }
+                                            th { "Group by Day" }
+                                            th { "Split by Category" }
+                                            th { "Revealed Hidden Fleets" }
+                                            th { class: "text-right", "Actions" }
+                                        }
+                                    }
+                                    tbody {
+                                        for channel in data {
+                                            ChannelListSettingsRow {
+                                                key: "{channel.channel_id}",
+                                                guild_id,
+                                                channel,
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    },
+                    Some(Err(err)) => rsx! {
+                        div {
+                            class: "alert alert-error",
+                            span { "Error loading list settings: {err.message}" }
+                        }
+                    },
+                    None => rsx! {
+                        div {
+                            class: "text-center py-8",
+                            span { class: "loading loading-spinner loading-lg" }
+                        }
+                    },
+                }
+            }
+        }
+    )
+}
+
+#[component]
+fn ChannelListSettingsRow(guild_id: u64, channel: ChannelListSettingsDto) -> Element {
+    let channel_id = channel.channel_id;
+    let mut settings = use_signal(|| UpdateChannelListSettingsDto {
+        lookahead_hours: channel.lookahead_hours,
+        max_entries: channel.max_entries,
+        group_by_day: channel.group_by_day,
+        split_by_category: channel.split_by_category,
+        show_revealed_hidden: channel.show_revealed_hidden,
+    });
+    let mut is_saving = use_signal(|| false);
+    let mut save_result = use_signal(|| None::<Result<(), ApiError>>);
+
+    #[cfg(feature = "web")]
+    {
+        let save_future = use_resource(move || async move {
+            if !is_saving() {
+                return None;
+            }
+            let payload = settings.peek().clone();
+            Some(update_channel_list_settings(guild_id, channel_id, payload).await)
+        });
+
+        use_effect(move || {
+            if let Some(Some(result)) = save_future.read_unchecked().as_ref() {
+                match result {
+                    Ok(_) => save_result.set(Some(Ok(()))),
+                    Err(err) => {
+                        tracing::error!("Failed to update channel list settings: {}", err);
+                        save_result.set(Some(Err(err.clone())));
+                    }
+                }
+                is_saving.set(false);
+            }
+        });
+    }
+
+    let mut update_settings = move |update: &dyn Fn(&mut UpdateChannelListSettingsDto)| {
+        update(&mut settings.write());
+        save_result.set(None);
+    };
+
+    let current = settings();
+    let is_valid = current
+        .lookahead_hours
+        .is_none_or(|hours| (1..=MAX_LIST_LOOKAHEAD_HOURS).contains(&hours))
+        && current
+            .max_entries
+            .is_none_or(|max| (1..=MAX_LIST_ENTRIES).contains(&max));
+
+    rsx!(
+        tr {
+            td { "#{channel.channel_name}" }
+            td {
+                input {
+                    r#type: "number",
+                    class: "input input-bordered input-sm w-24",
+                    min: "1",
+                    max: "{MAX_LIST_LOOKAHEAD_HOURS}",
+                    value: current.lookahead_hours.map(|h| h.to_string()).unwrap_or_default(),
+                    oninput: move |evt| {
+                        let hours = evt.value().trim().parse::<i32>().ok();
+                        update_settings(&|s| s.lookahead_hours = hours);
+                    },
+                }
+            }
+            td {
+                input {
+                    r#type: "number",
+                    class: "input input-bordered input-sm w-24",
+                    min: "1",
+                    max: "{MAX_LIST_ENTRIES}",
+                    value: current.max_entries.map(|m| m.to_string()).unwrap_or_default(),
+                    oninput: move |evt| {
+                        let max = evt.value().trim().parse::<i32>().ok();
+                        update_settings(&|s| s.max_entries = max);
+                    },
+                }
+            }
+            td {
+                input {
+                    r#type: "checkbox",
+                    class: "toggle toggle-sm",
+                    checked: current.group_by_day,
+                    onchange: move |evt| {
+                        let checked = evt.checked();
+                        update_settings(&|s| s.group_by_day = checked);
+                    },
+                }
+            }
+            td {
+                input {
+                    r#type: "checkbox",
+                    class: "toggle toggle-sm",
+                    checked: current.split_by_category,
+                    onchange: move |evt| {
+                        let checked = evt.checked();
+                        update_settings(&|s| s.split_by_category = checked);
+                    },
+                }
+            }
+            td {
+                input {
+                    r#type: "checkbox",
+                    class: "toggle toggle-sm",
+                    checked: current.show_revealed_hidden,
+                    onchange: move |evt| {
+                        let checked = evt.checked();
+                        update_settings(&|s| s.show_revealed_hidden = checked);
+                    },
+                }
+            }
+            td {
+                div {
+                    class: "flex items-center justify-end gap-3",
+                    match save_result() {
+                        Some(Ok(())) => rsx! {
+                            span { class: "text-sm text-success", "Saved" }
+                        },
+                        Some(Err(err)) => rsx! {
+                            span { class: "text-sm text-error", "{err.message}" }
+                        },
+                        None => rsx! {},
+                    }
+                    button {
+                        r#type: "button",
+                        class: "btn btn-primary btn-sm",
+                        disabled: is_saving() || !is_valid,
+                        onclick: move |_| {
+                            save_result.set(None);
+                            is_saving.set(true);
+                        },
+                        if is_saving() {
+                            span { class: "loading loading-spinner loading-xs" }
+                        } else {
+                            "Save"
+                        }
+                    }
+                }
+            }
+        }
+    )
+}
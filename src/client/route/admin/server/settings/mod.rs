@@ -0,0 +1,669 @@
+mod list;
+
+use chrono::{NaiveTime, Timelike};
+use dioxus::prelude::*;
+use dioxus_logger::tracing;
+
+use list::ChannelListSettingsSection;
+
+use crate::{
+    client::{
+        component::{
+            page::{ErrorPage, LoadingPage},
+            DropdownItem, Page, SearchableDropdown, SelectedItem, SelectedItemsList,
+            SolarSystemPicker,
+        },
+        constant::SITE_NAME,
+        model::error::ApiError,
+        route::admin::server::{ActionTabs, GuildInfoHeader, ServerAdminTab},
+        router::Route,
+    },
+    model::{
+        discord::{DiscordGuildDto, DiscordGuildRoleDto},
+        guild_settings::{DowntimePolicy, GuildSettingsDto},
+        ping_format::PingFormatDto,
+    },
+};
+
+#[cfg(feature = "web")]
+use crate::client::api::{
+    discord::get_discord_guild_roles,
+    discord_guild::get_discord_guild_by_id,
+    guild_settings::{get_guild_settings, update_guild_settings},
+    ping_format::get_ping_formats,
+};
+
+/// Longest lookahead window of the upcoming fleets list, in hours
+const MAX_LIST_LOOKAHEAD_HOURS: i32 = 24 * 30;
+
+/// Longest window after fleet time a missed form-up ping is still sent, in minutes
+const MAX_FORMUP_CATCHUP_MINUTES: i32 = 60;
+
+/// Longest EVE downtime window, in minutes
+const MAX_DOWNTIME_MINUTES: u32 = 120;
+
+/// Policies admins can pick for fleets scheduled during downtime
+const DOWNTIME_POLICIES: [DowntimePolicy; 3] = [
+    DowntimePolicy::Ignore,
+    DowntimePolicy::Warn,
+    DowntimePolicy::Block,
+];
+
+/// Formats an embed colour as a `#rrggbb` value for colour inputs
+fn format_color(color: u32) -> String {
+    format!("#{:06x}", color)
+}
+
+/// Parses a `#rrggbb` value of a colour input into an embed colour
+fn parse_color(value: &str) -> Option<u32> {
+    u32::from_str_radix(value.trim_start_matches('#'), 16).ok()
+}
+
+#[component]
+pub fn ServerAdminSettings(guild_id: u64) -> Element {
+    let mut guild = use_context::<Signal<Option<DiscordGuildDto>>>();
+    let mut error = use_signal(|| None::<ApiError>);
+
+    // Fetch guild data using use_resource if not already cached
+    #[cfg(feature = "web")]
+    {
+        let mut should_fetch = use_signal(|| false);
+
+        // Check cache and initiate fetch if needed
+        use_effect(use_reactive!(|guild_id| {
+            // Skip if already fetching
+            if should_fetch() {
+                return;
+            }
+
+            // Only run resource if we need to fetch
+            let needs_fetch = guild.read().as_ref().map(|g| g.guild_id) != Some(guild_id);
+
+            if needs_fetch {
+                should_fetch.set(true);
+            }
+        }));
+
+        let future = use_resource(move || async move {
+            if should_fetch() {
+                Some(get_discord_guild_by_id(guild_id).await)
+            } else {
+                None
+            }
+        });
+
+        use_effect(move || {
+            if let Some(Some(result)) = future.read_unchecked().as_ref() {
+                match result {
+                    Ok(guild_data) => {
+                        guild.set(Some(guild_data.clone()));
+                        error.set(None);
+                    }
+                    Err(err) => {
+                        tracing::error!("Failed to fetch guild: {}", err);
+                        guild.set(None);
+                        error.set(Some(err.clone()));
+                    }
+                }
+            }
+        });
+    }
+
+    rsx!(
+        Title { "Settings | {SITE_NAME}" }
+        if let Some(guild_data) = guild.read().clone() {
+            Page {
+                class: "flex flex-col items-center w-full h-full",
+                div {
+                    class: "w-full max-w-6xl",
+                    Link {
+                        to: Route::AdminServers {},
+                        class: "btn btn-ghost mb-4",
+                        "← Back to Servers"
+                    }
+                    GuildInfoHeader { guild_data: guild_data.clone() }
+                    ActionTabs { guild_id, active_tab: ServerAdminTab::Settings }
+                    div {
+                        class: "space-y-6",
+                        SettingsSection { guild_id }
+                        ChannelListSettingsSection { guild_id }
+                    }
+                }
+            }
+        } else if let Some(err) = error() {
+            ErrorPage { status: err.status, message: err.message }
+        } else {
+            LoadingPage { }
+        }
+    )
+}
+
+#[component]
+fn SettingsSection(guild_id: u64) -> Element {
+    let mut settings = use_signal(|| None::<Result<GuildSettingsDto, ApiError>>);
+    let mut ping_formats = use_signal(Vec::<PingFormatDto>::new);
+    let mut is_saving = use_signal(|| false);
+    let mut save_result = use_signal(|| None::<Result<(), ApiError>>);
+
+    #[cfg(feature = "web")]
+    {
+        let settings_future =
+            use_resource(move || async move { get_guild_settings(guild_id).await });
+
+        use_effect(move || {
+            if let Some(result) = settings_future.read_unchecked().as_ref() {
+                if let Err(err) = result {
+                    tracing::error!("Failed to fetch guild settings: {}", err);
+                }
+                settings.set(Some(result.clone()));
+            }
+        });
+
+        let ping_formats_future =
+            use_resource(move || async move { get_ping_formats(guild_id, 0, 100).await.ok() });
+
+        use_effect(move || {
+            if let Some(Some(result)) = ping_formats_future.read_unchecked().as_ref() {
+                ping_formats.set(result.ping_formats.clone());
+            }
+        });
+
+        let save_future = use_resource(move || async move {
+            if !is_saving() {
+                return None;
+            }
+
+            match settings.peek().clone() {
+                Some(Ok(payload)) => Some(update_guild_settings(guild_id, payload).await),
+                _ => None,
+            }
+        });
+
+        use_effect(move || {
+            if let Some(Some(result)) = save_future.read_unchecked().as_ref() {
+                match result {
+                    Ok(saved) => {
+                        settings.set(Some(Ok(saved.clone())));
+                        save_result.set(Some(Ok(())));
+                    }
+                    Err(err) => save_result.set(Some(Err(err.clone()))),
+                }
+                is_saving.set(false);
+            }
+        });
+    }
+
+    let mut update_settings = move |update: &dyn Fn(&mut GuildSettingsDto)| {
+        if let Some(Ok(data)) = settings.write().as_mut() {
+            update(data);
+        }
+        save_result.set(None);
+    };
+
+    let is_valid = match settings() {
+        Some(Ok(data)) => {
+            !data.time_label.trim().is_empty()
+                && data
+                    .list_lookahead_hours
+                    .is_none_or(|hours| (1..=MAX_LIST_LOOKAHEAD_HOURS).contains(&hours))
+                && (1..=MAX_FORMUP_CATCHUP_MINUTES).contains(&data.formup_catchup_minutes)
+                && (1..=MAX_DOWNTIME_MINUTES).contains(&data.downtime.duration_minutes)
+        }
+        _ => false,
+    };
+
+    rsx!(
+        div {
+            class: "card bg-base-200",
+            div {
+                class: "card-body",
+                div {
+                    class: "mb-4",
+                    h2 {
+                        class: "card-title",
+                        "Settings"
+                    }
+                    p {
+                        class: "text-sm opacity-70",
+                        "Customize how fleet notifications of this server look & when they are sent."
+                    }
+                }
+
+                match settings() {
+                    Some(Ok(data)) => rsx! {
+                        div {
+                            class: "space-y-6",
+
+                            // Embed colours
+                            div {
+                                class: "space-y-3",
+                                h3 { class: "font-semibold", "Embed Colors" }
+                                div {
+                                    class: "grid grid-cols-2 md:grid-cols-3 gap-4",
+                                    ColorInput {
+                                        label: "Creation",
+                                        color: data.creation_color,
+                                        on_change: move |color| update_settings(&|s| s.creation_color = color),
+                                    }
+                                    ColorInput {
+                                        label: "Update",
+                                        color: data.update_color,
+                                        on_change: move |color| update_settings(&|s| s.update_color = color),
+                                    }
+                                    ColorInput {
+                                        label: "Reminder",
+                                        color: data.reminder_color,
+                                        on_change: move |color| update_settings(&|s| s.reminder_color = color),
+                                    }
+                                    ColorInput {
+                                        label: "Form-up",
+                                        color: data.formup_color,
+                                        on_change: move |color| update_settings(&|s| s.formup_color = color),
+                                    }
+                                    ColorInput {
+                                        label: "Cancellation",
+                                        color: data.cancel_color,
+                                        on_change: move |color| update_settings(&|s| s.cancel_color = color),
+                                    }
+                                    ColorInput {
+                                        label: "Upcoming Fleets List",
+                                        color: data.list_color,
+                                        on_change: move |color| update_settings(&|s| s.list_color = color),
+                                    }
+                                }
+                            }
+
+                            // Time display
+                            div {
+                                class: "space-y-3",
+                                h3 { class: "font-semibold", "Time Display" }
+                                div {
+                                    class: "flex flex-col gap-2",
+                                    label {
+                                        class: "label",
+                                        span { class: "label-text", "Time label shown after the UTC start time" }
+                                    }
+                                    input {
+                                        r#type: "text",
+                                        class: "input input-bordered w-full",
+                                        maxlength: "32",
+                                        value: "{data.time_label}",
+                                        oninput: move |evt| {
+                                            let label = evt.value();
+                                            update_settings(&|s| s.time_label = label.clone());
+                                        },
+                                    }
+                                }
+                                div {
+                                    class: "flex flex-col gap-2",
+                                    label {
+                                        class: "label",
+                                        span { class: "label-text", "Extra timezones, comma separated (e.g. America/New_York, Europe/Berlin)" }
+                                    }
+                                    input {
+                                        r#type: "text",
+                                        class: "input input-bordered w-full",
+                                        value: data.extra_timezones.join(", "),
+                                        onchange: move |evt| {
+                                            let timezones: Vec<String> = evt
+                                                .value()
+                                                .split(',')
+                                                .map(|timezone| timezone.trim().to_string())
+                                                .filter(|timezone| !timezone.is_empty())
+                                                .collect();
+                                            update_settings(&|s| s.extra_timezones = timezones.clone());
+                                        },
+                                    }
+                                }
+                            }
+
+                            // Notification windows
+                            div {
+                                class: "space-y-3",
+                                h3 { class: "font-semibold", "Notifications" }
+                                div {
+                                    class: "flex items-center justify-between gap-4",
+                                    span { "Upcoming fleets list lookahead (hours, empty to list all)" }
+                                    input {
+                                        r#type: "number",
+                                        class: "input input-bordered input-sm w-24",
+                                        min: "1",
+                                        max: "{MAX_LIST_LOOKAHEAD_HOURS}",
+                                        value: data.list_lookahead_hours.map(|h| h.to_string()).unwrap_or_default(),
+                                        oninput: move |evt| {
+                                            let hours = evt.value().trim().parse::<i32>().ok();
+                                            update_settings(&|s| s.list_lookahead_hours = hours);
+                                        },
+                                    }
+                                }
+                                div {
+                                    class: "flex items-center justify-between gap-4",
+                                    span { "Send missed form-up pings up to (minutes after fleet time)" }
+                                    input {
+                                        r#type: "number",
+                                        class: "input input-bordered input-sm w-24",
+                                        min: "1",
+                                        max: "{MAX_FORMUP_CATCHUP_MINUTES}",
+                                        value: "{data.formup_catchup_minutes}",
+                                        oninput: move |evt| {
+                                            let minutes = evt.value().trim().parse::<i32>().unwrap_or(0);
+                                            update_settings(&|s| s.formup_catchup_minutes = minutes);
+                                        },
+                                    }
+                                }
+                                div {
+                                    class: "flex items-center justify-between gap-4",
+                                    span { "Default ping format of new categories" }
+                                    select {
+                                        class: "select select-bordered select-sm",
+                                        onchange: move |evt| {
+                                            let id = evt.value().parse::<i32>().ok();
+                                            update_settings(&|s| s.default_ping_format_id = id);
+                                        },
+                                        option {
+                                            value: "",
+                                            selected: data.default_ping_format_id.is_none(),
+                                            "None"
+                                        }
+                                        for format in ping_formats() {
+                                            option {
+                                                key: "{format.id}",
+                                                value: "{format.id}",
+                                                selected: Some(format.id) == data.default_ping_format_id,
+                                                "{format.name}"
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+
+                            // EVE downtime
+                            div {
+                                class: "space-y-3",
+                                h3 { class: "font-semibold", "EVE Downtime" }
+                                div {
+                                    class: "flex items-center justify-between gap-4",
+                                    span { "Downtime starts at (UTC)" }
+                                    input {
+                                        r#type: "time",
+                                        class: "input input-bordered input-sm w-32",
+                                        value: format!("{:02}:{:02}", data.downtime.start_minute / 60, data.downtime.start_minute % 60),
+                                        onchange: move |evt| {
+                                            if let Ok(time) = NaiveTime::parse_from_str(&evt.value(), "%H:%M") {
+                                                let minute = time.hour() * 60 + time.minute();
+                                                update_settings(&|s| s.downtime.start_minute = minute);
+                                            }
+                                        },
+                                    }
+                                }
+                                div {
+                                    class: "flex items-center justify-between gap-4",
+                                    span { "Downtime lasts (minutes)" }
+                                    input {
+                                        r#type: "number",
+                                        class: "input input-bordered input-sm w-24",
+                                        min: "1",
+                                        max: "{MAX_DOWNTIME_MINUTES}",
+                                        value: "{data.downtime.duration_minutes}",
+                                        oninput: move |evt| {
+                                            let minutes = evt.value().trim().parse::<u32>().unwrap_or(0);
+                                            update_settings(&|s| s.downtime.duration_minutes = minutes);
+                                        },
+                                    }
+                                }
+                                div {
+                                    class: "flex items-center justify-between gap-4",
+                                    span { "Fleets scheduled during downtime" }
+                                    select {
+                                        class: "select select-bordered select-sm",
+                                        onchange: move |evt| {
+                                            if let Some(policy) = DOWNTIME_POLICIES
+                                                .into_iter()
+                                                .find(|policy| policy.label() == evt.value())
+                                            {
+                                                update_settings(&|s| s.downtime.policy = policy);
+                                            }
+                                        },
+                                        for policy in DOWNTIME_POLICIES {
+                                            option {
+                                                key: "{policy.label()}",
+                                                value: "{policy.label()}",
+                                                selected: policy == data.downtime.policy,
+                                                "{policy.label()}"
+                                            }
+                                        }
+                                    }
+                                }
+                                p {
+                                    class: "text-xs opacity-70",
+                                    "Warn shows a warning before a fleet is scheduled into downtime, Block rejects it. Form-up pings due during downtime are held back until it ends unless downtime is ignored."
+                                }
+                            }
+
+                            // Staging
+                            div {
+                                class: "space-y-3",
+                                h3 { class: "font-semibold", "Staging" }
+                                div {
+                                    class: "flex flex-col gap-2",
+                                    label {
+                                        class: "label",
+                                        span { class: "label-text", "Staging system, fleets show the jump route from here to their form-up system" }
+                                    }
+                                    SolarSystemPicker {
+                                        value: data.staging_system.clone(),
+                                        placeholder: "Search solar systems...".to_string(),
+                                        disabled: is_saving(),
+                                        on_change: move |name: Option<String>| {
+                                            update_settings(&|s| s.staging_system = name.clone());
+                                        },
+                                    }
+                                }
+                            }
+
+                            // Broadcasts
+                            div {
+                                class: "space-y-3",
+                                h3 { class: "font-semibold", "Broadcasts" }
+                                p {
+                                    class: "text-sm opacity-70",
+                                    "Members of these roles can broadcast to every category's channels. Access roles of a category can also be allowed to broadcast to that category."
+                                }
+                                BroadcastRolesInput {
+                                    guild_id,
+                                    role_ids: data.broadcast_role_ids.clone(),
+                                    disabled: is_saving(),
+                                    on_change: move |role_ids: Vec<u64>| {
+                                        update_settings(&|s| s.broadcast_role_ids = role_ids.clone());
+                                    },
+                                }
+                            }
+
+                            if !is_valid {
+                                p {
+                                    class: "text-xs text-error",
+                                    "Time label is required, lookahead must be between 1 and {MAX_LIST_LOOKAHEAD_HOURS} hours, form-up catch-up between 1 and {MAX_FORMUP_CATCHUP_MINUTES} minutes and downtime between 1 and {MAX_DOWNTIME_MINUTES} minutes"
+                                }
+                            }
+
+                            div {
+                                class: "flex items-center justify-end gap-3",
+                                match save_result() {
+                                    Some(Ok(())) => rsx! {
+                                        span { class: "text-sm text-success", "Saved" }
+                                    },
+                                    Some(Err(err)) => rsx! {
+                                        span { class: "text-sm text-error", "{err.message}" }
+                                    },
+                                    None => rsx! {},
+                                }
+                                button {
+                                    r#type: "button",
+                                    class: "btn btn-primary",
+                                    disabled: is_saving() || !is_valid,
+                                    onclick: move |_| {
+                                        save_result.set(None);
+                                        is_saving.set(true);
+                                    },
+                                    if is_saving() {
+                                        span { class: "loading loading-spinner loading-sm" }
+                                    } else {
+                                        "Save"
+                                    }
+                                }
+                            }
+                        }
+                    },
+                    Some(Err(err)) => rsx! {
+                        div {
+                            class: "alert alert-error",
+                            span { "Error loading settings: {err.message}" }
+                        }
+                    },
+                    None => rsx! {
+                        div {
+                            class: "text-center py-8",
+                            span { class: "loading loading-spinner loading-lg" }
+                        }
+                    },
+                }
+            }
+        }
+    )
+}
+
+#[component]
+fn ColorInput(label: String, color: u32, on_change: EventHandler<u32>) -> Element {
+    rsx!(
+        label {
+            class: "flex items-center gap-3 cursor-pointer",
+            input {
+                r#type: "color",
+                class: "w-10 h-10 cursor-pointer",
+                value: format_color(color),
+                oninput: move |evt| {
+                    if let Some(color) = parse_color(&evt.value()) {
+                        on_change.call(color);
+                    }
+                },
+            }
+            span { "{label}" }
+        }
+    )
+}
+
+/// Picker for the roles whose members can broadcast to every category of the guild
+#[component]
+fn BroadcastRolesInput(
+    guild_id: u64,
+    role_ids: Vec<u64>,
+    disabled: bool,
+    on_change: EventHandler<Vec<u64>>,
+) -> Element {
+    let mut available_roles = use_signal(Vec::<DiscordGuildRoleDto>::new);
+    let mut search_query = use_signal(String::new);
+    let mut dropdown_open = use_signal(|| false);
+
+    // Roles are fetched upfront to show the names of the configured roles
+    #[cfg(feature = "web")]
+    {
+        let roles_future =
+            use_resource(
+                move || async move { get_discord_guild_roles(guild_id, 0, 1000).await.ok() },
+            );
+
+        use_effect(move || {
+            if let Some(Some(result)) = roles_future.read_unchecked().as_ref() {
+                available_roles.set(result.roles.clone());
+            }
+        });
+    }
+
+    let mut filtered_roles: Vec<DiscordGuildRoleDto> = available_roles()
+        .into_iter()
+        .filter(|role| !role_ids.contains(&role.role_id))
+        .filter(|role| {
+            role.name
+                .to_lowercase()
+                .contains(&search_query().to_lowercase())
+        })
+        .collect();
+    filtered_roles.sort_by_key(|role| std::cmp::Reverse(role.position));
+
+    let mut selected_roles: Vec<DiscordGuildRoleDto> = available_roles()
+        .into_iter()
+        .filter(|role| role_ids.contains(&role.role_id))
+        .collect();
+    selected_roles.sort_by_key(|role| std::cmp::Reverse(role.position));
+
+    rsx!(
+        div {
+            class: "flex flex-col gap-3",
+            SearchableDropdown {
+                search_query,
+                placeholder: "Search roles...".to_string(),
+                display_value: None,
+                disabled,
+                has_items: !filtered_roles.is_empty(),
+                show_dropdown_signal: Some(dropdown_open),
+                for role in filtered_roles {
+                    {
+                        let role_ids = role_ids.clone();
+                        rsx! {
+                            DropdownItem {
+                                key: "{role.role_id}",
+                                on_select: move |_| {
+                                    let mut updated = role_ids.clone();
+                                    updated.push(role.role_id);
+                                    on_change.call(updated);
+                                    search_query.set(String::new());
+                                    dropdown_open.set(false);
+                                },
+                                div {
+                                    class: "flex items-center gap-2",
+                                    div {
+                                        class: "w-4 h-4 rounded",
+                                        style: "background-color: {role.color};"
+                                    }
+                                    "{role.name}"
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            SelectedItemsList {
+                label: "Broadcast Roles".to_string(),
+                empty_message: "No broadcast roles configured. Only admins & category access roles allowed to broadcast can send broadcasts.".to_string(),
+                is_empty: role_ids.is_empty(),
+                for role in selected_roles {
+                    {
+                        let role_ids = role_ids.clone();
+                        rsx! {
+                            SelectedItem {
+                                key: "{role.role_id}",
+                                disabled,
+                                on_remove: move |_| {
+                                    let updated = role_ids
+                                        .iter()
+                                        .copied()
+                                        .filter(|id| *id != role.role_id)
+                                        .collect();
+                                    on_change.call(updated);
+                                },
+                                div {
+                                    class: "w-4 h-4 rounded flex-shrink-0",
+                                    style: "background-color: {role.color};"
+                                }
+                                div {
+                                    class: "flex-1 font-medium",
+                                    "{role.name}"
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    )
+}
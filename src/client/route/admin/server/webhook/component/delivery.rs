@@ -0,0 +1,186 @@
+use dioxus::prelude::*;
+use dioxus_logger::tracing;
+
+use crate::{
+    client::{
+        component::{Modal, Pagination},
+        model::error::ApiError,
+    },
+    model::webhook::{PaginatedWebhookDeliveriesDto, WebhookDeliveryStatus, WebhookDto},
+};
+
+#[cfg(feature = "web")]
+use crate::client::api::webhook::{get_paginated_webhook_deliveries, replay_webhook_delivery};
+
+/// Modal listing the delivery log of a webhook with the option to replay deliveries
+#[component]
+pub fn WebhookDeliveriesModal(
+    guild_id: u64,
+    show: Signal<bool>,
+    webhook: Signal<Option<WebhookDto>>,
+) -> Element {
+    let mut data = use_signal(|| None::<PaginatedWebhookDeliveriesDto>);
+    let mut error = use_signal(|| None::<ApiError>);
+    let mut page = use_signal(|| 0u64);
+    let per_page = use_signal(|| 10u64);
+    let mut refetch_trigger = use_signal(|| 0u32);
+    let mut delivery_to_replay = use_signal(|| None::<i32>);
+
+    // Reset to the first page whenever the modal opens for a webhook
+    use_effect(move || {
+        if show() {
+            let _ = webhook();
+            page.set(0);
+            data.set(None);
+            error.set(None);
+        }
+    });
+
+    #[cfg(feature = "web")]
+    let future = use_resource(move || async move {
+        let _ = refetch_trigger();
+        if !show() {
+            return None;
+        }
+        let webhook_id = webhook().map(|w| w.id)?;
+        Some(get_paginated_webhook_deliveries(guild_id, webhook_id, page(), per_page()).await)
+    });
+
+    #[cfg(feature = "web")]
+    use_effect(move || {
+        if let Some(Some(result)) = future.read_unchecked().as_ref() {
+            match result {
+                Ok(deliveries) => {
+                    data.set(Some(deliveries.clone()));
+                    error.set(None);
+                }
+                Err(err) => {
+                    tracing::error!("Failed to fetch webhook deliveries: {}", err);
+                    data.set(None);
+                    error.set(Some(err.clone()));
+                }
+            }
+        }
+    });
+
+    // Handle replay with use_resource
+    #[cfg(feature = "web")]
+    let replay_future = use_resource(move || async move {
+        let delivery_id = delivery_to_replay()?;
+        let webhook_id = webhook().map(|w| w.id)?;
+        Some(replay_webhook_delivery(guild_id, webhook_id, delivery_id).await)
+    });
+
+    #[cfg(feature = "web")]
+    use_effect(move || {
+        if let Some(Some(result)) = replay_future.read_unchecked().as_ref() {
+            if let Err(err) = result {
+                tracing::error!("Failed to replay webhook delivery: {}", err);
+                error.set(Some(err.clone()));
+            }
+            delivery_to_replay.set(None);
+            refetch_trigger.set(refetch_trigger() + 1);
+        }
+    });
+
+    let title = webhook()
+        .map(|w| format!("Deliveries: {}", w.name))
+        .unwrap_or_else(|| "Deliveries".to_string());
+    let is_replaying = delivery_to_replay().is_some();
+
+    rsx!(
+        Modal {
+            show,
+            title,
+            prevent_close: false,
+            class: "max-w-4xl",
+            if let Some(deliveries) = data() {
+                if deliveries.items.is_empty() {
+                    div {
+                        class: "text-center py-8 opacity-50",
+                        "No deliveries yet"
+                    }
+                } else {
+                    div {
+                        class: "overflow-x-auto",
+                        table {
+                            class: "table table-zebra table-sm w-full",
+                            thead {
+                                tr {
+                                    th { "Event" }
+                                    th { "Status" }
+                                    th { "Attempts" }
+                                    th { "Response" }
+                                    th { "Created (UTC)" }
+                                    th {
+                                        class: "text-right",
+                                        "Actions"
+                                    }
+                                }
+                            }
+                            tbody {
+                                for delivery in deliveries.items.clone() {
+                                    {
+                                        let delivery_id = delivery.id;
+                                        let response = match (delivery.response_status, &delivery.error) {
+                                            (Some(status), _) => status.to_string(),
+                                            (None, Some(err)) => err.clone(),
+                                            (None, None) => "-".to_string(),
+                                        };
+                                        let (badge_class, status_label) = match delivery.status {
+                                            WebhookDeliveryStatus::Pending => ("badge badge-warning badge-sm", "Pending"),
+                                            WebhookDeliveryStatus::Succeeded => ("badge badge-success badge-sm", "Succeeded"),
+                                            WebhookDeliveryStatus::Failed => ("badge badge-error badge-sm", "Failed"),
+                                        };
+
+                                        rsx! {
+                                            tr {
+                                                td { class: "font-mono text-xs", "{delivery.event.as_str()}" }
+                                                td { span { class: badge_class, "{status_label}" } }
+                                                td { "{delivery.attempts}" }
+                                                td {
+                                                    class: "max-w-xs truncate text-xs",
+                                                    title: "{response}",
+                                                    "{response}"
+                                                }
+                                                td { {delivery.created_at.format("%Y-%m-%d %H:%M").to_string()} }
+                                                td {
+                                                    div {
+                                                        class: "flex justify-end",
+                                                        button {
+                                                            class: "btn btn-xs",
+                                                            disabled: is_replaying || delivery.status == WebhookDeliveryStatus::Pending,
+                                                            onclick: move |_| delivery_to_replay.set(Some(delivery_id)),
+                                                            "Replay"
+                                                        }
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    Pagination {
+                        page,
+                        per_page,
+                        data: deliveries.into(),
+                        on_page_change: move |_| {},
+                        on_per_page_change: move |_| {},
+                    }
+                }
+            } else if let Some(err) = error() {
+                div {
+                    class: "alert alert-error",
+                    span { "Error loading deliveries: {err.message}" }
+                }
+            } else {
+                div {
+                    class: "text-center py-8",
+                    span { class: "loading loading-spinner loading-lg" }
+                }
+            }
+        }
+    )
+}
@@ -0,0 +1,3 @@
+pub mod delivery;
+pub mod modal;
+pub mod table;
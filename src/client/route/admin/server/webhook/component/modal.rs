@@ -0,0 +1,432 @@
+use dioxus::prelude::*;
+use dioxus_logger::tracing;
+
+use crate::{
+    client::component::Modal,
+    model::webhook::{CreateWebhookDto, UpdateWebhookDto, WebhookDto, WebhookEvent},
+};
+
+#[cfg(feature = "web")]
+use crate::client::api::webhook::{create_webhook, update_webhook};
+
+/// Form field values
+#[derive(Clone)]
+struct FormFieldsData {
+    name: String,
+    url: String,
+    events: Vec<WebhookEvent>,
+    enabled: bool,
+}
+
+impl Default for FormFieldsData {
+    fn default() -> Self {
+        Self {
+            name: String::new(),
+            url: String::new(),
+            events: WebhookEvent::ALL.to_vec(),
+            enabled: true,
+        }
+    }
+}
+
+/// Validates the form fields, returning an error message if invalid
+fn validate_form_fields(fields: &FormFieldsData) -> Option<String> {
+    if fields.name.trim().is_empty() {
+        return Some("Webhook name is required".to_string());
+    }
+    let url = fields.url.trim();
+    if !url.starts_with("https://") {
+        return Some("Webhook URL must start with https://".to_string());
+    }
+    if fields.events.is_empty() {
+        return Some("Select at least one event".to_string());
+    }
+    None
+}
+
+#[component]
+pub fn CreateWebhookModal(
+    guild_id: u64,
+    mut show: Signal<bool>,
+    mut refetch_trigger: Signal<u32>,
+) -> Element {
+    let mut form_fields = use_signal(FormFieldsData::default);
+    let mut submit_data = use_signal(|| None::<CreateWebhookDto>);
+    let mut should_submit = use_signal(|| false);
+    let mut error = use_signal(|| None::<String>);
+
+    // Reset form when modal opens
+    use_effect(move || {
+        if show() {
+            form_fields.set(FormFieldsData::default());
+            submit_data.set(None);
+            should_submit.set(false);
+            error.set(None);
+        }
+    });
+
+    // Handle form submission with use_resource
+    #[cfg(feature = "web")]
+    let future = use_resource(move || async move {
+        if should_submit() {
+            let payload = submit_data()?;
+            Some(create_webhook(guild_id, payload).await)
+        } else {
+            None
+        }
+    });
+
+    #[cfg(feature = "web")]
+    use_effect(move || {
+        if let Some(Some(result)) = future.read_unchecked().as_ref() {
+            match result {
+                Ok(_) => {
+                    // Reset form data
+                    form_fields.set(FormFieldsData::default());
+                    submit_data.set(None);
+                    error.set(None);
+                    should_submit.set(false);
+                    refetch_trigger.set(refetch_trigger() + 1);
+                    show.set(false);
+                }
+                Err(err) => {
+                    tracing::error!("Failed to create webhook: {}", err);
+                    error.set(Some(err.message.clone()));
+                    should_submit.set(false);
+                }
+            }
+        }
+    });
+
+    let on_submit = move |evt: Event<FormData>| {
+        evt.prevent_default();
+
+        let fields = form_fields();
+        if let Some(err) = validate_form_fields(&fields) {
+            error.set(Some(err));
+            return;
+        }
+
+        error.set(None);
+        submit_data.set(Some(CreateWebhookDto {
+            name: fields.name.trim().to_string(),
+            url: fields.url.trim().to_string(),
+            events: fields.events,
+            enabled: fields.enabled,
+        }));
+        should_submit.set(true);
+    };
+
+    let is_submitting = should_submit();
+
+    rsx!(
+        Modal {
+            show,
+            title: "Create Webhook".to_string(),
+            prevent_close: is_submitting,
+            form {
+                class: "flex flex-col gap-4",
+                onsubmit: on_submit,
+
+                WebhookFormFields {
+                    form_fields,
+                    is_submitting
+                }
+
+                p {
+                    class: "text-sm opacity-70",
+                    "A signing secret is generated once the webhook is created, edit the webhook to view it."
+                }
+
+                // Error Message
+                if let Some(err) = error() {
+                    div {
+                        class: "alert alert-error mt-4",
+                        span { "{err}" }
+                    }
+                }
+
+                // Modal Actions
+                div {
+                    class: "modal-action",
+                    button {
+                        r#type: "button",
+                        class: "btn",
+                        onclick: move |_| show.set(false),
+                        disabled: is_submitting,
+                        "Cancel"
+                    }
+                    button {
+                        r#type: "submit",
+                        class: "btn btn-primary",
+                        disabled: is_submitting,
+                        if is_submitting {
+                            span { class: "loading loading-spinner loading-sm mr-2" }
+                            "Creating..."
+                        } else {
+                            "Create"
+                        }
+                    }
+                }
+            }
+        }
+    )
+}
+
+#[component]
+pub fn EditWebhookModal(
+    guild_id: u64,
+    mut show: Signal<bool>,
+    webhook_to_edit: Signal<Option<WebhookDto>>,
+    mut refetch_trigger: Signal<u32>,
+) -> Element {
+    let mut form_fields = use_signal(FormFieldsData::default);
+    let mut submit_data = use_signal(|| None::<(i32, UpdateWebhookDto)>);
+    let mut should_submit = use_signal(|| false);
+    let mut error = use_signal(|| None::<String>);
+
+    // Initialize form when modal opens with new data
+    use_effect(move || {
+        if show() {
+            if let Some(webhook) = webhook_to_edit() {
+                form_fields.set(FormFieldsData {
+                    name: webhook.name.clone(),
+                    url: webhook.url.clone(),
+                    events: webhook.events.clone(),
+                    enabled: webhook.enabled,
+                });
+                submit_data.set(None);
+                error.set(None);
+                should_submit.set(false);
+            }
+        }
+    });
+
+    // Handle form submission with use_resource
+    #[cfg(feature = "web")]
+    let future = use_resource(move || async move {
+        if should_submit() {
+            let (id, payload) = submit_data()?;
+            Some(update_webhook(guild_id, id, payload).await)
+        } else {
+            None
+        }
+    });
+
+    #[cfg(feature = "web")]
+    use_effect(move || {
+        if let Some(Some(result)) = future.read_unchecked().as_ref() {
+            match result {
+                Ok(_) => {
+                    // Reset form data
+                    form_fields.set(FormFieldsData::default());
+                    submit_data.set(None);
+                    error.set(None);
+                    should_submit.set(false);
+                    refetch_trigger.set(refetch_trigger() + 1);
+                    show.set(false);
+                }
+                Err(err) => {
+                    tracing::error!("Failed to update webhook: {}", err);
+                    error.set(Some(err.message.clone()));
+                    should_submit.set(false);
+                }
+            }
+        }
+    });
+
+    let on_submit = move |evt: Event<FormData>| {
+        evt.prevent_default();
+
+        let Some(webhook) = webhook_to_edit() else {
+            return;
+        };
+
+        let fields = form_fields();
+        if let Some(err) = validate_form_fields(&fields) {
+            error.set(Some(err));
+            return;
+        }
+
+        error.set(None);
+        submit_data.set(Some((
+            webhook.id,
+            UpdateWebhookDto {
+                name: fields.name.trim().to_string(),
+                url: fields.url.trim().to_string(),
+                events: fields.events,
+                enabled: fields.enabled,
+            },
+        )));
+        should_submit.set(true);
+    };
+
+    let is_submitting = should_submit();
+    let secret = webhook_to_edit().map(|w| w.secret).unwrap_or_default();
+
+    rsx!(
+        Modal {
+            show,
+            title: "Edit Webhook".to_string(),
+            prevent_close: is_submitting,
+            form {
+                class: "flex flex-col gap-4",
+                onsubmit: on_submit,
+
+                WebhookFormFields {
+                    form_fields,
+                    is_submitting
+                }
+
+                // Signing secret (read-only)
+                div {
+                    class: "form-control w-full flex flex-col gap-2",
+                    label {
+                        class: "label",
+                        span { class: "label-text", "Signing Secret" }
+                    }
+                    input {
+                        r#type: "text",
+                        class: "input input-bordered w-full font-mono text-xs",
+                        value: "{secret}",
+                        readonly: true,
+                    }
+                    label {
+                        class: "label",
+                        span {
+                            class: "label-text-alt opacity-70",
+                            "X-Timerboard-Signature is sha256= followed by the hex HMAC-SHA256 of \"<timestamp>.<body>\""
+                        }
+                    }
+                }
+
+                // Error Message
+                if let Some(err) = error() {
+                    div {
+                        class: "alert alert-error mt-4",
+                        span { "{err}" }
+                    }
+                }
+
+                // Modal Actions
+                div {
+                    class: "modal-action",
+                    button {
+                        r#type: "button",
+                        class: "btn",
+                        onclick: move |_| show.set(false),
+                        disabled: is_submitting,
+                        "Cancel"
+                    }
+                    button {
+                        r#type: "submit",
+                        class: "btn btn-primary",
+                        disabled: is_submitting,
+                        if is_submitting {
+                            span { class: "loading loading-spinner loading-sm mr-2" }
+                            "Updating..."
+                        } else {
+                            "Update"
+                        }
+                    }
+                }
+            }
+        }
+    )
+}
+
+#[component]
+fn WebhookFormFields(mut form_fields: Signal<FormFieldsData>, is_submitting: bool) -> Element {
+    rsx!(
+        // Name field
+        div {
+            class: "form-control w-full flex flex-col gap-2",
+            label {
+                class: "label",
+                span { class: "label-text", "Webhook Name" }
+            }
+            input {
+                r#type: "text",
+                class: "input input-bordered w-full",
+                placeholder: "e.g., Killboard Sync, Stats Bot",
+                value: "{form_fields().name}",
+                disabled: is_submitting,
+                oninput: move |evt| {
+                    form_fields.write().name = evt.value();
+                }
+            }
+        }
+
+        // URL field
+        div {
+            class: "form-control w-full flex flex-col gap-2",
+            label {
+                class: "label",
+                span { class: "label-text", "Payload URL" }
+            }
+            input {
+                r#type: "url",
+                class: "input input-bordered w-full",
+                placeholder: "https://example.com/hooks/timerboard",
+                value: "{form_fields().url}",
+                disabled: is_submitting,
+                oninput: move |evt| {
+                    form_fields.write().url = evt.value();
+                }
+            }
+        }
+
+        // Events field
+        div {
+            class: "form-control w-full flex flex-col gap-2",
+            label {
+                class: "label",
+                span { class: "label-text", "Events" }
+            }
+            div {
+                class: "flex flex-wrap gap-4",
+                for event in WebhookEvent::ALL {
+                    label {
+                        class: "label cursor-pointer gap-2",
+                        input {
+                            r#type: "checkbox",
+                            class: "checkbox checkbox-sm [transition:none]",
+                            checked: form_fields().events.contains(&event),
+                            disabled: is_submitting,
+                            onchange: move |evt| {
+                                let mut fields = form_fields.write();
+                                fields.events.retain(|e| *e != event);
+                                if evt.checked() {
+                                    fields.events.push(event);
+                                    // Keep events in display order
+                                    fields.events.sort_by_key(|e| {
+                                        WebhookEvent::ALL.iter().position(|a| a == e)
+                                    });
+                                }
+                            }
+                        }
+                        span { class: "label-text text-xs font-mono", "{event.as_str()}" }
+                    }
+                }
+            }
+        }
+
+        // Enabled field
+        div {
+            class: "form-control w-full",
+            label {
+                class: "label cursor-pointer justify-start gap-4",
+                input {
+                    r#type: "checkbox",
+                    class: "toggle toggle-primary",
+                    checked: form_fields().enabled,
+                    disabled: is_submitting,
+                    onchange: move |evt| {
+                        form_fields.write().enabled = evt.checked();
+                    }
+                }
+                span { class: "label-text", "Enabled" }
+            }
+        }
+    )
+}
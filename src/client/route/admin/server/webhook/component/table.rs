@@ -0,0 +1,199 @@
+use dioxus::prelude::*;
+use dioxus_logger::tracing;
+
+use crate::{
+    client::{component::modal::ConfirmationModal, route::admin::server::WebhooksCache},
+    model::webhook::{PaginatedWebhooksDto, WebhookDto},
+};
+
+use super::{delivery::WebhookDeliveriesModal, modal::EditWebhookModal};
+
+#[cfg(feature = "web")]
+use crate::client::api::webhook::delete_webhook;
+
+#[component]
+pub fn WebhooksTable(
+    data: PaginatedWebhooksDto,
+    guild_id: u64,
+    mut cache: Signal<WebhooksCache>,
+    mut refetch_trigger: Signal<u32>,
+) -> Element {
+    let mut sorted_webhooks = data.items.clone();
+    sorted_webhooks.sort_by_key(|w| w.id);
+
+    let mut show_delete_modal = use_signal(|| false);
+    let mut webhook_to_delete = use_signal(|| None::<(i32, String)>);
+    let mut is_deleting = use_signal(|| false);
+
+    let mut show_edit_modal = use_signal(|| false);
+    let mut webhook_to_edit = use_signal(|| None::<WebhookDto>);
+
+    let mut show_deliveries_modal = use_signal(|| false);
+    let mut webhook_for_deliveries = use_signal(|| None::<WebhookDto>);
+
+    // Handle deletion with use_resource
+    #[cfg(feature = "web")]
+    let delete_future = use_resource(move || async move {
+        if is_deleting() {
+            if let Some((id, _)) = webhook_to_delete() {
+                Some(delete_webhook(guild_id, id).await)
+            } else {
+                None
+            }
+        } else {
+            None
+        }
+    });
+
+    #[cfg(feature = "web")]
+    use_effect(move || {
+        if let Some(Some(result)) = delete_future.read_unchecked().as_ref() {
+            match result {
+                Ok(_) => {
+                    // Trigger refetch
+                    refetch_trigger.set(refetch_trigger() + 1);
+                    // Close modal (data persists for smooth animation)
+                    show_delete_modal.set(false);
+                    is_deleting.set(false);
+                }
+                Err(err) => {
+                    tracing::error!("Failed to delete webhook: {}", err);
+                    is_deleting.set(false);
+                }
+            }
+        }
+    });
+
+    rsx!(
+        div {
+            class: "overflow-x-auto",
+            table {
+                class: "table table-zebra w-full",
+                thead {
+                    tr {
+                        th { "Name" }
+                        th { "URL" }
+                        th { "Events" }
+                        th { "Status" }
+                        th {
+                            class: "text-right",
+                            "Actions"
+                        }
+                    }
+                }
+                tbody {
+                    for webhook in &sorted_webhooks {
+                        {
+                            let webhook_id = webhook.id;
+                            let webhook_clone_for_edit = webhook.clone();
+                            let webhook_clone_for_deliveries = webhook.clone();
+                            let webhook_name_for_delete = webhook.name.clone();
+
+                            rsx! {
+                                tr {
+                                    td { "{webhook.name}" }
+                                    td {
+                                        class: "max-w-xs truncate font-mono text-xs",
+                                        title: "{webhook.url}",
+                                        "{webhook.url}"
+                                    }
+                                    td {
+                                        div {
+                                            class: "flex flex-wrap gap-1",
+                                            for event in &webhook.events {
+                                                span {
+                                                    class: "badge badge-sm badge-outline",
+                                                    "{event.as_str()}"
+                                                }
+                                            }
+                                        }
+                                    }
+                                    td {
+                                        if webhook.enabled {
+                                            span { class: "badge badge-success badge-sm", "Enabled" }
+                                        } else {
+                                            span { class: "badge badge-ghost badge-sm", "Disabled" }
+                                        }
+                                    }
+                                    td {
+                                        div {
+                                            class: "flex gap-2 justify-end",
+                                            button {
+                                                class: "btn btn-sm",
+                                                onclick: move |_| {
+                                                    webhook_for_deliveries.set(Some(webhook_clone_for_deliveries.clone()));
+                                                    show_deliveries_modal.set(true);
+                                                },
+                                                "Deliveries"
+                                            }
+                                            button {
+                                                class: "btn btn-sm btn-primary",
+                                                onclick: move |_| {
+                                                    webhook_to_edit.set(Some(webhook_clone_for_edit.clone()));
+                                                    show_edit_modal.set(true);
+                                                },
+                                                "Edit"
+                                            }
+                                            button {
+                                                class: "btn btn-sm btn-error",
+                                                onclick: move |_| {
+                                                    webhook_to_delete.set(Some((webhook_id, webhook_name_for_delete.clone())));
+                                                    show_delete_modal.set(true);
+                                                },
+                                                "Delete"
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        // Delete Confirmation Modal
+        ConfirmationModal {
+            show: show_delete_modal,
+            title: "Delete Webhook".to_string(),
+            message: rsx!(
+                if let Some((_, name)) = webhook_to_delete() {
+                    div {
+                        class: "py-4",
+                        p {
+                            "Are you sure you want to delete the webhook "
+                            span { class: "font-bold", "\"{name}\"" }
+                            "?"
+                        }
+                        p {
+                            class: "mt-4",
+                            "Its delivery log will be deleted as well. This action cannot be undone."
+                        }
+                    }
+                }
+            ),
+            confirm_text: "Delete".to_string(),
+            confirm_class: "btn-error".to_string(),
+            is_processing: is_deleting(),
+            processing_text: "Deleting...".to_string(),
+            on_confirm: move |_| {
+                is_deleting.set(true);
+            },
+        }
+
+        // Edit Webhook Modal
+        EditWebhookModal {
+            guild_id,
+            show: show_edit_modal,
+            webhook_to_edit,
+            refetch_trigger
+        }
+
+        // Delivery Log Modal
+        WebhookDeliveriesModal {
+            guild_id,
+            show: show_deliveries_modal,
+            webhook: webhook_for_deliveries
+        }
+    )
+}
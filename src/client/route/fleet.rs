@@ -0,0 +1,320 @@
+use chrono::{DateTime, Local, Utc};
+use dioxus::prelude::*;
+use dioxus_logger::tracing;
+use pulldown_cmark::{html, Options, Parser};
+
+use crate::{
+    client::{
+        component::page::{ErrorPage, LoadingPage, Page},
+        constant::SITE_NAME,
+        model::error::ApiError,
+        route::home::FleetCountdown,
+        router::Route,
+    },
+    model::{
+        doctrine::{DoctrineDto, DoctrineFitDto},
+        fleet::FleetDto,
+        solar_system::{FleetRouteDto, SolarSystemDto},
+    },
+};
+
+#[cfg(feature = "web")]
+use crate::client::api::fleet::get_fleet;
+
+/// Shareable page showing a single fleet, linked from Discord embeds & fleet lists
+///
+/// Visibility follows the fleet API, users who can't view the fleet's category or a
+/// hidden fleet that isn't revealed yet get the error page instead.
+#[component]
+pub fn FleetDetail(guild_id: u64, fleet_id: i32) -> Element {
+    let mut fleet = use_signal(|| None::<Result<FleetDto, ApiError>>);
+    let mut current_time = use_signal(Utc::now);
+
+    #[cfg(feature = "web")]
+    use_future(move || async move {
+        loop {
+            gloo_timers::future::TimeoutFuture::new(1_000).await;
+            current_time.set(Utc::now());
+        }
+    });
+
+    #[cfg(feature = "web")]
+    {
+        let future = use_resource(use_reactive!(|(guild_id, fleet_id)| async move {
+            get_fleet(guild_id, fleet_id).await
+        }));
+
+        use_effect(move || match &*future.read_unchecked() {
+            Some(Ok(data)) => {
+                fleet.set(Some(Ok(data.clone())));
+            }
+            Some(Err(err)) => {
+                tracing::error!("Failed to fetch fleet: {}", err);
+                fleet.set(Some(Err(err.clone())));
+            }
+            None => (),
+        });
+    }
+
+    rsx! {
+        if let Some(Ok(fleet)) = fleet() {
+            Title { "{fleet.name} | {SITE_NAME}" }
+            Page {
+                class: "flex flex-col items-center w-full h-full",
+                div {
+                    class: "w-full max-w-3xl px-4 py-6 space-y-6",
+                    Link {
+                        class: "btn btn-ghost btn-sm",
+                        to: Route::Home {},
+                        "Back to timerboard"
+                    }
+                    FleetDetailCard { fleet, current_time }
+                }
+            }
+        } else if let Some(Err(error)) = fleet() {
+            Title { "{SITE_NAME}" }
+            ErrorPage { status: error.status, message: error.message }
+        } else {
+            Title { "{SITE_NAME}" }
+            LoadingPage { }
+        }
+    }
+}
+
+#[component]
+fn FleetDetailCard(fleet: FleetDto, current_time: Signal<DateTime<Utc>>) -> Element {
+    let local_time: DateTime<Local> = fleet.fleet_time.with_timezone(&Local);
+
+    // Ping format fields by name, booleans shown as Yes/No & doctrines by their name
+    // like the Discord embed
+    let mut fields: Vec<(String, String)> = fleet
+        .field_values
+        .iter()
+        .filter(|(_, value)| !value.is_empty())
+        .map(|(name, value)| {
+            let display_value = match (value.as_str(), fleet.doctrines.get(name)) {
+                (_, Some(doctrine)) => format!("{} ({})", doctrine.name, doctrine.hull_list()),
+                ("true", _) => "Yes".to_string(),
+                ("false", _) => "No".to_string(),
+                _ => value.clone(),
+            };
+            (name.clone(), display_value)
+        })
+        .collect();
+    fields.sort();
+
+    let mut doctrines: Vec<(String, DoctrineDto)> = fleet
+        .doctrines
+        .iter()
+        .map(|(name, doctrine)| (name.clone(), doctrine.clone()))
+        .collect();
+    doctrines.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let description_html = fleet
+        .description
+        .as_ref()
+        .filter(|desc| !desc.is_empty())
+        .map(|desc| {
+            let parser = Parser::new_ext(desc, Options::all());
+            let mut html_output = String::new();
+            html::push_html(&mut html_output, parser);
+            html_output
+        });
+
+    rsx! {
+        div {
+            class: "card bg-base-200",
+            div {
+                class: "card-body space-y-4",
+                div {
+                    class: "flex flex-wrap items-start justify-between gap-2",
+                    div {
+                        p { class: "text-sm opacity-60", "{fleet.category_name}" }
+                        h1 { class: "card-title text-2xl", "{fleet.name}" }
+                    }
+                    div {
+                        class: "flex gap-2",
+                        if let Some(source_guild) = &fleet.relayed_from {
+                            span { class: "badge badge-info", "Relayed from {source_guild}" }
+                        }
+                        if fleet.hidden {
+                            span { class: "badge badge-ghost", "Hidden" }
+                        }
+                    }
+                }
+
+                div {
+                    class: "grid grid-cols-1 md:grid-cols-2 gap-4",
+                    DetailField {
+                        label: "Fleet Commander",
+                        value: fleet.commander_name.clone(),
+                    }
+                    div {
+                        class: "flex flex-col gap-1",
+                        span { class: "text-sm opacity-60", "Countdown" }
+                        FleetCountdown { fleet_time: fleet.fleet_time, current_time }
+                    }
+                    DetailField {
+                        label: "Date & Time (UTC)",
+                        value: fleet.fleet_time.format("%Y-%m-%d %H:%M").to_string(),
+                    }
+                    DetailField {
+                        label: "Date & Time (Local)",
+                        value: local_time.format("%Y-%m-%d %H:%M").to_string(),
+                    }
+                    for (name, value) in fields {
+                        if let Some(system) = fleet.solar_systems.get(&name).cloned() {
+                            SolarSystemDetailField {
+                                key: "{name}",
+                                label: name.clone(),
+                                system,
+                            }
+                        } else {
+                            DetailField {
+                                key: "{name}",
+                                label: name.clone(),
+                                value,
+                            }
+                        }
+                    }
+                    if let Some(route) = fleet.route.clone() {
+                        RouteDetailField { route }
+                    }
+                }
+
+                for (name, doctrine) in doctrines {
+                    DoctrineFits { key: "{name}", label: name.clone(), doctrine }
+                }
+
+                div {
+                    class: "space-y-2",
+                    h2 { class: "text-lg font-bold", "Description" }
+                    if let Some(html_output) = description_html {
+                        div {
+                            class: "prose prose-sm max-w-none",
+                            dangerous_inner_html: "{html_output}"
+                        }
+                    } else {
+                        span { class: "opacity-50 italic", "No description provided" }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn DetailField(label: String, value: String) -> Element {
+    rsx! {
+        div {
+            class: "flex flex-col gap-1",
+            span { class: "text-sm opacity-60", "{label}" }
+            span { class: "font-medium", "{value}" }
+        }
+    }
+}
+
+/// Solar system field linked to dotlan, with its security status & region
+#[component]
+fn SolarSystemDetailField(label: String, system: SolarSystemDto) -> Element {
+    rsx! {
+        div {
+            class: "flex flex-col gap-1",
+            span { class: "text-sm opacity-60", "{label}" }
+            span {
+                class: "font-medium flex items-center gap-2",
+                a {
+                    class: "link link-primary",
+                    href: "{system.dotlan_url}",
+                    target: "_blank",
+                    rel: "noopener noreferrer",
+                    "{system.name}"
+                }
+                span { class: system.security_class(), "{system.security_label()}" }
+                span { class: "opacity-70", "{system.region}" }
+            }
+        }
+    }
+}
+
+/// Stargate route from staging linked to dotlan, with its security profile
+#[component]
+fn RouteDetailField(route: FleetRouteDto) -> Element {
+    let jumps_label = match route.jumps {
+        0 => "Forms up in staging".to_string(),
+        1 => "1 jump".to_string(),
+        jumps => format!("{} jumps", jumps),
+    };
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-1",
+            span { class: "text-sm opacity-60", "Route from Staging ({route.staging.name})" }
+            span {
+                class: "font-medium flex flex-wrap items-center gap-2",
+                a {
+                    class: "link link-primary",
+                    href: "{route.dotlan_url}",
+                    target: "_blank",
+                    rel: "noopener noreferrer",
+                    "{jumps_label}"
+                }
+                if route.jumps > 0 {
+                    span { class: "text-success", "{route.high_sec_jumps} HS" }
+                    span { class: "text-warning", "{route.low_sec_jumps} LS" }
+                    span { class: "text-error", "{route.null_sec_jumps} NS" }
+                }
+            }
+        }
+    }
+}
+
+/// Fits of a fleet's doctrine, each with a button copying it in EFT format for importing
+/// into the game client or Pyfa
+#[component]
+fn DoctrineFits(label: String, doctrine: DoctrineDto) -> Element {
+    rsx! {
+        div {
+            class: "space-y-2",
+            h2 { class: "text-lg font-bold", "{label}: {doctrine.name}" }
+            if let Some(description) = &doctrine.description {
+                p { class: "text-sm opacity-70", "{description}" }
+            }
+            div {
+                class: "flex flex-col gap-2",
+                for fit in doctrine.fits.clone() {
+                    DoctrineFitRow { key: "{fit.id}", fit }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn DoctrineFitRow(fit: DoctrineFitDto) -> Element {
+    let mut copied = use_signal(|| false);
+    let eft = fit.eft.clone();
+
+    rsx! {
+        div {
+            class: "flex items-center justify-between gap-2 rounded-box bg-base-100 px-4 py-2",
+            div {
+                span { class: "font-medium", "{fit.name}" }
+                span { class: "ml-2 text-sm opacity-70", "{fit.hull}" }
+            }
+            button {
+                class: "btn btn-sm",
+                onclick: move |_| {
+                    let eval = document::eval(
+                        "let text = await dioxus.recv(); await navigator.clipboard.writeText(text);",
+                    );
+                    match eval.send(eft.clone()) {
+                        Ok(()) => copied.set(true),
+                        Err(err) => tracing::error!("Failed to copy fit: {:?}", err),
+                    }
+                },
+                if copied() { "Copied" } else { "Copy EFT" }
+            }
+        }
+    }
+}
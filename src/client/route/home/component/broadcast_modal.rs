@@ -0,0 +1,404 @@
+use dioxus::prelude::*;
+use dioxus_logger::tracing;
+
+use crate::{
+    client::{
+        component::{modal::ConfirmationModal, Modal},
+        model::error::ApiError,
+    },
+    model::broadcast::{BroadcastDto, BroadcastOverviewDto, CreateBroadcastDto},
+};
+
+#[cfg(feature = "web")]
+use crate::client::api::broadcast::{cancel_broadcast, create_broadcast, get_broadcasts};
+
+/// Button opening the broadcast composer, shown to users who can broadcast to any channel
+#[component]
+pub fn BroadcastButton(guild_id: u64) -> Element {
+    let mut show = use_signal(|| false);
+    let mut overview = use_signal(|| None::<Result<BroadcastOverviewDto, ApiError>>);
+    let reload_trigger = use_signal(|| 0u32);
+
+    // Reload on guild change & after a broadcast was sent or cancelled
+    #[cfg(feature = "web")]
+    {
+        let future = use_resource(use_reactive!(|guild_id| async move {
+            let _ = reload_trigger();
+            get_broadcasts(guild_id).await
+        }));
+
+        use_effect(move || {
+            if let Some(result) = future.read_unchecked().as_ref() {
+                if let Err(err) = result {
+                    tracing::error!("Failed to fetch broadcasts: {}", err);
+                }
+                overview.set(Some(result.clone()));
+            }
+        });
+    }
+
+    let can_broadcast = matches!(overview(), Some(Ok(data)) if !data.channels.is_empty());
+
+    rsx! {
+        if can_broadcast {
+            button {
+                class: "btn btn-outline btn-warning",
+                onclick: move |_| show.set(true),
+                "Broadcast"
+            }
+        }
+        if let Some(Ok(data)) = overview() {
+            BroadcastModal {
+                guild_id,
+                overview: data,
+                show,
+                reload_trigger
+            }
+        }
+    }
+}
+
+/// Modal for composing a broadcast & cancelling scheduled broadcasts
+#[component]
+fn BroadcastModal(
+    guild_id: u64,
+    overview: BroadcastOverviewDto,
+    show: Signal<bool>,
+    mut reload_trigger: Signal<u32>,
+) -> Element {
+    let mut title = use_signal(String::new);
+    let mut message = use_signal(String::new);
+    let channel_ids = use_signal(Vec::<u64>::new);
+    let role_ids = use_signal(Vec::<u64>::new);
+    let mut scheduled_at = use_signal(String::new);
+    let mut show_confirm = use_signal(|| false);
+    let mut submit = use_signal(|| false);
+    let mut pending_cancel = use_signal(|| None::<i32>);
+    let mut error = use_signal(|| None::<String>);
+    let mut success = use_signal(|| None::<String>);
+
+    #[cfg(feature = "web")]
+    {
+        let submit_future = use_resource(move || async move {
+            if !submit() {
+                return None;
+            }
+
+            let scheduled_at = scheduled_at.peek().trim().to_string();
+            let dto = CreateBroadcastDto {
+                title: title.peek().clone(),
+                message: message.peek().clone(),
+                channel_ids: channel_ids.peek().clone(),
+                role_ids: role_ids.peek().clone(),
+                scheduled_at: (!scheduled_at.is_empty()).then_some(scheduled_at),
+            };
+            Some(create_broadcast(guild_id, dto).await)
+        });
+
+        use_effect(move || {
+            if let Some(Some(result)) = submit_future.read_unchecked().as_ref() {
+                match result {
+                    Ok(broadcast) => {
+                        tracing::info!("Broadcast {} created", broadcast.id);
+                        success.set(Some(match broadcast.scheduled_at {
+                            Some(time) => {
+                                format!(
+                                    "Broadcast scheduled for {} UTC",
+                                    time.format("%Y-%m-%d %H:%M")
+                                )
+                            }
+                            None => {
+                                format!("Broadcast posted to {} channel(s)", broadcast.posted_count)
+                            }
+                        }));
+                        title.set(String::new());
+                        message.set(String::new());
+                        scheduled_at.set(String::new());
+                        reload_trigger.set(reload_trigger() + 1);
+                    }
+                    Err(err) => {
+                        tracing::error!("Failed to create broadcast: {}", err);
+                        error.set(Some(err.message.clone()));
+                    }
+                }
+                show_confirm.set(false);
+                submit.set(false);
+            }
+        });
+
+        let cancel_future = use_resource(move || async move {
+            let broadcast_id = pending_cancel()?;
+            Some(cancel_broadcast(guild_id, broadcast_id).await)
+        });
+
+        use_effect(move || {
+            if let Some(Some(result)) = cancel_future.read_unchecked().as_ref() {
+                match result {
+                    Ok(broadcast) => {
+                        tracing::info!("Broadcast {} cancelled", broadcast.id);
+                        success.set(Some("Scheduled broadcast cancelled".to_string()));
+                        reload_trigger.set(reload_trigger() + 1);
+                    }
+                    Err(err) => {
+                        tracing::error!("Failed to cancel broadcast: {}", err);
+                        error.set(Some(err.message.clone()));
+                    }
+                }
+                pending_cancel.set(None);
+            }
+        });
+    }
+
+    let is_processing = submit() || pending_cancel().is_some();
+    let can_submit = !is_processing
+        && !title().trim().is_empty()
+        && !message().trim().is_empty()
+        && !channel_ids().is_empty();
+    let is_scheduled = !scheduled_at().trim().is_empty();
+
+    rsx! {
+        Modal {
+            show,
+            title: "Broadcast",
+            prevent_close: is_processing,
+            div {
+                class: "space-y-6",
+
+                div {
+                    class: "space-y-3",
+                    p {
+                        class: "text-sm text-base-content/70",
+                        "Posts a message to the chosen channels & pings the chosen roles without a scheduled fleet. Times are in UTC."
+                    }
+                    div {
+                        class: "form-control w-full",
+                        label { class: "label", span { class: "label-text", "Title" } }
+                        input {
+                            r#type: "text",
+                            class: "input input-bordered w-full",
+                            maxlength: "256",
+                            placeholder: "Reinforcements needed",
+                            value: "{title}",
+                            disabled: is_processing,
+                            oninput: move |evt| title.set(evt.value()),
+                        }
+                    }
+                    div {
+                        class: "form-control w-full",
+                        label { class: "label", span { class: "label-text", "Message" } }
+                        textarea {
+                            class: "textarea textarea-bordered h-32 w-full",
+                            maxlength: "4000",
+                            placeholder: "Hostiles are attacking our Athanor in 1DQ1-A, everyone able to undock please join comms",
+                            value: "{message}",
+                            disabled: is_processing,
+                            oninput: move |evt| message.set(evt.value()),
+                        }
+                    }
+                    div {
+                        class: "form-control w-full",
+                        label { class: "label", span { class: "label-text", "Channels" } }
+                        div {
+                            class: "flex flex-wrap gap-x-4 gap-y-1",
+                            for channel in overview.channels.clone() {
+                                label {
+                                    key: "{channel.channel_id}",
+                                    class: "label cursor-pointer gap-2",
+                                    input {
+                                        r#type: "checkbox",
+                                        class: "checkbox checkbox-sm [transition:none]",
+                                        checked: channel_ids().contains(&channel.channel_id),
+                                        disabled: is_processing,
+                                        onchange: move |evt| toggle_id(channel_ids, channel.channel_id, evt.checked()),
+                                    }
+                                    span { class: "label-text", "#{channel.name}" }
+                                }
+                            }
+                        }
+                    }
+                    if !overview.roles.is_empty() {
+                        div {
+                            class: "form-control w-full",
+                            label { class: "label", span { class: "label-text", "Ping Roles" } }
+                            div {
+                                class: "flex flex-wrap gap-x-4 gap-y-1",
+                                for role in overview.roles.clone() {
+                                    label {
+                                        key: "{role.role_id}",
+                                        class: "label cursor-pointer gap-2",
+                                        input {
+                                            r#type: "checkbox",
+                                            class: "checkbox checkbox-sm [transition:none]",
+                                            checked: role_ids().contains(&role.role_id),
+                                            disabled: is_processing,
+                                            onchange: move |evt| toggle_id(role_ids, role.role_id, evt.checked()),
+                                        }
+                                        span {
+                                            class: "label-text",
+                                            style: "color: {role.color};",
+                                            "@{role.name}"
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    div {
+                        class: "form-control w-full",
+                        label { class: "label", span { class: "label-text", "Schedule (YYYY-MM-DD HH:MM, empty to send now)" } }
+                        input {
+                            r#type: "text",
+                            class: "input input-bordered w-full",
+                            placeholder: "Send now",
+                            value: "{scheduled_at}",
+                            disabled: is_processing,
+                            oninput: move |evt| scheduled_at.set(evt.value()),
+                        }
+                    }
+                    if let Some(message) = error() {
+                        div { class: "alert alert-error", span { "{message}" } }
+                    }
+                    if let Some(message) = success() {
+                        div { class: "alert alert-success", span { "{message}" } }
+                    }
+                    div {
+                        class: "flex justify-end",
+                        button {
+                            r#type: "button",
+                            class: "btn btn-warning",
+                            disabled: !can_submit,
+                            onclick: move |_| {
+                                error.set(None);
+                                success.set(None);
+                                show_confirm.set(true);
+                            },
+                            if is_scheduled { "Schedule Broadcast" } else { "Send Broadcast" }
+                        }
+                    }
+                }
+
+                // Broadcast history
+                div {
+                    class: "space-y-3",
+                    h3 { class: "font-semibold", "Recent Broadcasts" }
+                    if overview.broadcasts.is_empty() {
+                        p { class: "text-base-content/70", "No broadcasts yet." }
+                    } else {
+                        div {
+                            class: "max-h-64 overflow-y-auto space-y-2",
+                            for broadcast in overview.broadcasts.clone() {
+                                BroadcastHistoryItem {
+                                    key: "{broadcast.id}",
+                                    is_cancelling: pending_cancel() == Some(broadcast.id),
+                                    broadcast,
+                                    is_processing,
+                                    on_cancel: move |broadcast_id| {
+                                        error.set(None);
+                                        success.set(None);
+                                        pending_cancel.set(Some(broadcast_id));
+                                    },
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        ConfirmationModal {
+            show: show_confirm,
+            title: "Send Broadcast".to_string(),
+            message: rsx!(
+                p {
+                    class: "py-4",
+                    if is_scheduled {
+                        "Post "
+                        span { class: "font-bold", "{title}" }
+                        " to {channel_ids().len()} channel(s) pinging {role_ids().len()} role(s) at {scheduled_at} UTC?"
+                    } else {
+                        "Post "
+                        span { class: "font-bold", "{title}" }
+                        " to {channel_ids().len()} channel(s) pinging {role_ids().len()} role(s) now?"
+                    }
+                }
+            ),
+            confirm_text: "Send".to_string(),
+            confirm_class: "btn-warning".to_string(),
+            is_processing: submit(),
+            processing_text: "Sending...".to_string(),
+            on_confirm: move |_| submit.set(true),
+        }
+    }
+}
+
+/// Entry of the broadcast history with its status & a cancel button while scheduled
+#[component]
+fn BroadcastHistoryItem(
+    broadcast: BroadcastDto,
+    is_processing: bool,
+    is_cancelling: bool,
+    on_cancel: EventHandler<i32>,
+) -> Element {
+    let broadcast_id = broadcast.id;
+    let channels = broadcast
+        .channels
+        .iter()
+        .map(|channel| format!("#{}", channel.name))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let status = match (broadcast.sent_at, broadcast.scheduled_at) {
+        (Some(sent_at), _) => format!(
+            "Sent {} · {} channel(s)",
+            sent_at.format("%Y-%m-%d %H:%M"),
+            broadcast.posted_count
+        ),
+        (None, Some(scheduled_at)) => {
+            format!("Scheduled for {}", scheduled_at.format("%Y-%m-%d %H:%M"))
+        }
+        (None, None) => String::new(),
+    };
+    let is_pending = broadcast.sent_at.is_none() && broadcast.cancelled_at.is_none();
+
+    rsx! {
+        div {
+            class: "flex items-center justify-between gap-4 p-2 rounded-box bg-base-200",
+            div {
+                class: "min-w-0",
+                p { class: "font-semibold truncate", "{broadcast.title}" }
+                p {
+                    class: "text-xs text-base-content/70 truncate",
+                    "{status} · {channels}"
+                }
+            }
+            if is_pending {
+                button {
+                    r#type: "button",
+                    class: "btn btn-sm btn-outline",
+                    disabled: is_processing,
+                    onclick: move |_| on_cancel.call(broadcast_id),
+                    if is_cancelling {
+                        span { class: "loading loading-spinner loading-xs" }
+                    } else {
+                        "Cancel"
+                    }
+                }
+            } else if broadcast.cancelled_at.is_some() {
+                span { class: "badge badge-ghost", "Cancelled" }
+            } else {
+                span { class: "badge badge-success badge-outline", "Sent" }
+            }
+        }
+    }
+}
+
+/// Adds or removes an ID from a list of chosen channels or roles
+fn toggle_id(mut ids: Signal<Vec<u64>>, id: u64, checked: bool) {
+    let mut ids = ids.write();
+    if checked {
+        if !ids.contains(&id) {
+            ids.push(id);
+        }
+    } else {
+        ids.retain(|existing| *existing != id);
+    }
+}
@@ -0,0 +1,228 @@
+use dioxus::prelude::*;
+use dioxus_logger::tracing;
+
+use crate::{
+    client::{component::modal::ConfirmationModal, route::home::ManageableCategoriesCache},
+    model::fleet::{BulkFleetAction, BulkFleetActionDto},
+};
+
+#[cfg(feature = "web")]
+use crate::client::api::{fleet::bulk_update_fleets, user::get_user_manageable_categories};
+
+/// Actions applied to every fleet selected in the fleet table at once
+///
+/// Selected fleets can be shifted by a number of minutes, moved to another category or
+/// cancelled. The selection is cleared once the action succeeded.
+#[component]
+pub fn BulkFleetActions(
+    guild_id: u64,
+    mut selected: Signal<Vec<i32>>,
+    mut refetch_trigger: Signal<u32>,
+) -> Element {
+    let mut categories_cache = use_context::<Signal<ManageableCategoriesCache>>();
+
+    let mut shift_minutes = use_signal(|| 30i64);
+    let mut target_category_id = use_signal(|| None::<i32>);
+    let mut pending_action = use_signal(|| None::<BulkFleetAction>);
+    let mut show_cancel_modal = use_signal(|| false);
+    let mut error = use_signal(|| None::<String>);
+
+    // Fetch categories only if not cached or guild changed
+    #[cfg(feature = "web")]
+    {
+        let mut should_fetch = use_signal(|| false);
+
+        use_effect(use_reactive!(|guild_id| {
+            if should_fetch() {
+                return;
+            }
+
+            let mut cache_state = categories_cache.write();
+
+            let needs_fetch = (cache_state.guild_id != Some(guild_id)
+                || cache_state.data.is_none())
+                && !cache_state.is_fetching;
+
+            if needs_fetch {
+                cache_state.is_fetching = true;
+                drop(cache_state);
+                should_fetch.set(true);
+            }
+        }));
+
+        let fetch_future = use_resource(move || async move {
+            if should_fetch() {
+                Some(get_user_manageable_categories(guild_id).await)
+            } else {
+                None
+            }
+        });
+
+        use_effect(move || {
+            if let Some(Some(result)) = fetch_future.read_unchecked().as_ref() {
+                if let Err(err) = result {
+                    tracing::error!("Failed to fetch categories: {}", err);
+                }
+                let mut cache_state = categories_cache.write();
+                cache_state.guild_id = Some(guild_id);
+                cache_state.data = Some(result.clone());
+                cache_state.is_fetching = false;
+                drop(cache_state);
+                should_fetch.set(false);
+            }
+        });
+    }
+
+    // Apply the pending action to the selected fleets
+    #[cfg(feature = "web")]
+    let action_future = use_resource(move || async move {
+        let action = pending_action()?;
+        let dto = BulkFleetActionDto {
+            fleet_ids: selected.peek().clone(),
+            action,
+        };
+        Some(bulk_update_fleets(guild_id, dto).await)
+    });
+
+    #[cfg(feature = "web")]
+    use_effect(move || {
+        if let Some(Some(result)) = action_future.read_unchecked().as_ref() {
+            match result {
+                Ok(()) => {
+                    tracing::info!("Fleets updated successfully");
+                    selected.set(Vec::new());
+                    show_cancel_modal.set(false);
+                    error.set(None);
+                    refetch_trigger.set(refetch_trigger() + 1);
+                }
+                Err(err) => {
+                    tracing::error!("Failed to update fleets: {}", err);
+                    show_cancel_modal.set(false);
+                    error.set(Some(err.message.clone()));
+                }
+            }
+            pending_action.set(None);
+        }
+    });
+
+    let is_processing = pending_action().is_some();
+    let count = selected().len();
+    let categories = match categories_cache.read().data.as_ref() {
+        Some(Ok(categories)) => categories.clone(),
+        _ => Vec::new(),
+    };
+    let has_categories = !categories.is_empty();
+
+    rsx! {
+        div {
+            class: "flex flex-wrap items-center gap-2 p-2 mb-2 rounded-box bg-base-200",
+            span {
+                class: "font-semibold mr-2",
+                if count == 1 { "1 fleet selected" } else { "{count} fleets selected" }
+            }
+            div {
+                class: "join",
+                input {
+                    r#type: "number",
+                    class: "input input-sm input-bordered join-item w-24",
+                    title: "Minutes to shift by, negative values move fleets earlier",
+                    step: "5",
+                    value: "{shift_minutes}",
+                    disabled: is_processing,
+                    oninput: move |evt| {
+                        if let Ok(minutes) = evt.value().parse::<i64>() {
+                            shift_minutes.set(minutes);
+                        }
+                    },
+                }
+                button {
+                    class: "btn btn-sm join-item",
+                    disabled: is_processing || shift_minutes() == 0,
+                    onclick: move |_| {
+                        error.set(None);
+                        pending_action.set(Some(BulkFleetAction::Shift { minutes: shift_minutes() }));
+                    },
+                    "Shift Minutes"
+                }
+            }
+            div {
+                class: "join",
+                select {
+                    class: "select select-sm select-bordered join-item",
+                    disabled: is_processing || !has_categories,
+                    onchange: move |evt| target_category_id.set(evt.value().parse::<i32>().ok()),
+                    option {
+                        value: "",
+                        selected: target_category_id().is_none(),
+                        "Move to category..."
+                    }
+                    for category in categories {
+                        option {
+                            key: "{category.id}",
+                            value: "{category.id}",
+                            selected: target_category_id() == Some(category.id),
+                            "{category.name}"
+                        }
+                    }
+                }
+                button {
+                    class: "btn btn-sm join-item",
+                    disabled: is_processing || target_category_id().is_none(),
+                    onclick: move |_| {
+                        if let Some(category_id) = target_category_id() {
+                            error.set(None);
+                            pending_action.set(Some(BulkFleetAction::Recategorize { category_id }));
+                        }
+                    },
+                    "Move"
+                }
+            }
+            button {
+                class: "btn btn-sm btn-error",
+                disabled: is_processing,
+                onclick: move |_| show_cancel_modal.set(true),
+                "Cancel Fleets"
+            }
+            button {
+                class: "btn btn-sm btn-ghost ml-auto",
+                disabled: is_processing,
+                onclick: move |_| {
+                    error.set(None);
+                    selected.set(Vec::new());
+                },
+                "Clear Selection"
+            }
+            if is_processing {
+                span { class: "loading loading-spinner loading-sm" }
+            }
+        }
+        if let Some(error) = error() {
+            div {
+                class: "alert alert-error mb-2",
+                span { "{error}" }
+            }
+        }
+
+        // Cancel Confirmation Modal
+        ConfirmationModal {
+            show: show_cancel_modal,
+            title: "Cancel Fleets".to_string(),
+            message: rsx!(
+                p {
+                    class: "py-4",
+                    "Are you sure you want to cancel "
+                    span { class: "font-bold", if count == 1 { "1 fleet" } else { "{count} fleets" } }
+                    "? This action cannot be undone."
+                }
+            ),
+            confirm_text: "Cancel Fleets".to_string(),
+            confirm_class: "btn-error".to_string(),
+            is_processing,
+            processing_text: "Cancelling...".to_string(),
+            on_confirm: move |_| {
+                error.set(None);
+                pending_action.set(Some(BulkFleetAction::Cancel));
+            },
+        }
+    }
+}
@@ -1,19 +1,32 @@
 use dioxus::prelude::*;
 use dioxus_logger::tracing;
 
-use crate::client::{component::Modal, route::home::ManageableCategoriesCache};
+use crate::{
+    client::{
+        component::Modal,
+        route::home::{FleetTemplatesCache, ManageableCategoriesCache},
+    },
+    model::fleet_template::FleetTemplateDto,
+};
 
 #[cfg(feature = "web")]
-use crate::client::api::user::get_user_manageable_categories;
+use crate::client::api::{
+    fleet_template::get_fleet_templates, user::get_user_manageable_categories,
+};
 
 /// Modal for selecting which fleet category to create a fleet in
+///
+/// The templates of each category are listed below it, picking one selects its category
+/// & prefills the fleet with the template.
 #[component]
 pub fn CategorySelectionModal(
     guild_id: u64,
     mut show: Signal<bool>,
     on_category_selected: EventHandler<i32>,
+    on_template_selected: EventHandler<FleetTemplateDto>,
 ) -> Element {
     let mut cache = use_context::<Signal<ManageableCategoriesCache>>();
+    let mut templates_cache = use_context::<Signal<FleetTemplatesCache>>();
 
     // Fetch categories only if not cached or guild changed
     #[cfg(feature = "web")]
@@ -71,6 +84,61 @@ pub fn CategorySelectionModal(
         });
     }
 
+    // Fetch templates only if not cached or guild changed
+    #[cfg(feature = "web")]
+    {
+        let mut should_fetch_templates = use_signal(|| false);
+
+        use_effect(use_reactive!(|guild_id| {
+            if should_fetch_templates() {
+                return;
+            }
+
+            let mut cache_state = templates_cache.write();
+
+            let needs_fetch = (cache_state.guild_id != Some(guild_id)
+                || cache_state.data.is_none())
+                && !cache_state.is_fetching;
+
+            if needs_fetch {
+                cache_state.is_fetching = true;
+                drop(cache_state);
+                should_fetch_templates.set(true);
+            }
+        }));
+
+        let templates_future = use_resource(move || async move {
+            if should_fetch_templates() {
+                Some(get_fleet_templates(guild_id).await)
+            } else {
+                None
+            }
+        });
+
+        use_effect(move || {
+            if let Some(Some(result)) = templates_future.read_unchecked().as_ref() {
+                if let Err(err) = result {
+                    tracing::error!("Failed to fetch fleet templates: {}", err);
+                }
+                let mut cache_state = templates_cache.write();
+                cache_state.guild_id = Some(guild_id);
+                cache_state.data = Some(result.clone());
+                cache_state.is_fetching = false;
+                drop(cache_state);
+                should_fetch_templates.set(false);
+            }
+        });
+    }
+
+    // Templates failing to load only hide the shortcuts, categories stay selectable
+    let templates = templates_cache
+        .read()
+        .data
+        .as_ref()
+        .and_then(|result| result.as_ref().ok())
+        .cloned()
+        .unwrap_or_default();
+
     let categories = cache
         .read()
         .data
@@ -101,16 +169,50 @@ pub fn CategorySelectionModal(
                             {
                                 let category_id = category.id;
                                 let category_name = category.name.clone();
+                                let category_templates: Vec<FleetTemplateDto> = templates
+                                    .iter()
+                                    .filter(|template| template.category_id == category_id)
+                                    .cloned()
+                                    .collect();
                                 rsx! {
-                                    button {
+                                    div {
                                         key: "{category_id}",
-                                        class: "block w-full text-left p-4 rounded-box border border-base-300 hover:bg-base-200 hover:border-primary transition-all",
-                                        onclick: move |_| {
-                                            on_category_selected.call(category_id);
-                                        },
-                                        div {
-                                            class: "font-medium text-lg",
-                                            "{category_name}"
+                                        class: "rounded-box border border-base-300 hover:border-primary transition-all",
+                                        button {
+                                            class: "block w-full text-left p-4 rounded-box hover:bg-base-200",
+                                            onclick: move |_| {
+                                                on_category_selected.call(category_id);
+                                            },
+                                            div {
+                                                class: "font-medium text-lg",
+                                                "{category_name}"
+                                            }
+                                        }
+                                        if !category_templates.is_empty() {
+                                            div {
+                                                class: "flex flex-wrap gap-2 px-4 pb-4",
+                                                for template in category_templates {
+                                                    {
+                                                        let template_id = template.id;
+                                                        let template_name = template.name.clone();
+                                                        let is_shared = template.shared;
+                                                        rsx! {
+                                                            button {
+                                                                key: "{template_id}",
+                                                                class: "btn btn-xs btn-outline",
+                                                                title: "Create a fleet from this template",
+                                                                onclick: move |_| {
+                                                                    on_template_selected.call(template.clone());
+                                                                },
+                                                                "{template_name}"
+                                                                if is_shared {
+                                                                    span { class: "badge badge-xs badge-primary", "Shared" }
+                                                                }
+                                                            }
+                                                        }
+                                                    }
+                                                }
+                                            }
                                         }
                                     }
                                 }
@@ -0,0 +1,488 @@
+use chrono::{DateTime, Datelike, Duration, DurationRound, Months, NaiveDate, NaiveTime, Utc};
+use dioxus::prelude::*;
+use dioxus_logger::tracing;
+
+use crate::{
+    client::model::error::ApiError,
+    model::fleet::{FleetCalendarDto, FleetCalendarItemDto},
+};
+
+#[cfg(feature = "web")]
+use crate::client::api::fleet::get_fleet_calendar;
+
+/// Most fleets listed in a month view day before collapsing into "+N more"
+const MONTH_DAY_FLEET_LIMIT: usize = 3;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum CalendarView {
+    Day,
+    Week,
+    Month,
+}
+
+impl CalendarView {
+    /// Returns the UTC time range shown by the view for the given date
+    fn range(self, date: NaiveDate) -> (DateTime<Utc>, DateTime<Utc>) {
+        let (start_date, days) = match self {
+            CalendarView::Day => (date, 1),
+            CalendarView::Week => (week_start(date), 7),
+            // Six full weeks so every month fits the grid
+            CalendarView::Month => (week_start(date.with_day(1).unwrap_or(date)), 42),
+        };
+        let start = start_date.and_time(NaiveTime::MIN).and_utc();
+
+        (start, start + Duration::days(days))
+    }
+
+    /// Returns the date shown after moving one view length backwards or forwards
+    fn step(self, date: NaiveDate, forward: bool) -> NaiveDate {
+        match (self, forward) {
+            (CalendarView::Day, true) => date + Duration::days(1),
+            (CalendarView::Day, false) => date - Duration::days(1),
+            (CalendarView::Week, true) => date + Duration::days(7),
+            (CalendarView::Week, false) => date - Duration::days(7),
+            (CalendarView::Month, true) => date.checked_add_months(Months::new(1)).unwrap_or(date),
+            (CalendarView::Month, false) => date.checked_sub_months(Months::new(1)).unwrap_or(date),
+        }
+    }
+
+    /// Returns the heading describing the shown range
+    fn title(self, date: NaiveDate) -> String {
+        match self {
+            CalendarView::Day => date.format("%A, %d %B %Y").to_string(),
+            CalendarView::Week => {
+                let start = week_start(date);
+                let end = start + Duration::days(6);
+                format!("{} - {}", start.format("%d %b"), end.format("%d %b %Y"))
+            }
+            CalendarView::Month => date.format("%B %Y").to_string(),
+        }
+    }
+}
+
+/// Returns the Monday of the week containing the date
+fn week_start(date: NaiveDate) -> NaiveDate {
+    date - Duration::days(date.weekday().num_days_from_monday() as i64)
+}
+
+/// Returns the fleet time to prefill when an empty slot is clicked
+///
+/// Slots that already ended can't be scheduled in, slots in progress start at the
+/// next 5-minute mark like the creation modal's own default.
+fn slot_fleet_time(
+    slot_start: DateTime<Utc>,
+    slot_end: DateTime<Utc>,
+    now: DateTime<Utc>,
+) -> Option<DateTime<Utc>> {
+    if slot_end <= now {
+        return None;
+    }
+    let next_interval = now.duration_trunc(Duration::minutes(5)).ok()? + Duration::minutes(5);
+
+    Some(slot_start.max(next_interval))
+}
+
+/// Returns the fleets starting within a slot
+fn fleets_in_slot(
+    fleets: &[FleetCalendarItemDto],
+    slot_start: DateTime<Utc>,
+    slot_end: DateTime<Utc>,
+) -> Vec<FleetCalendarItemDto> {
+    fleets
+        .iter()
+        .filter(|item| item.fleet.fleet_time >= slot_start && item.fleet.fleet_time < slot_end)
+        .cloned()
+        .collect()
+}
+
+/// Returns the first fleet whose cooldown window overlaps a slot
+fn cooldown_in_slot(
+    fleets: &[FleetCalendarItemDto],
+    slot_start: DateTime<Utc>,
+    slot_end: DateTime<Utc>,
+) -> Option<&FleetCalendarItemDto> {
+    fleets.iter().find(|item| {
+        item.cooldown().is_some_and(|seconds| {
+            let window = Duration::seconds(seconds);
+            item.fleet.fleet_time - window < slot_end && item.fleet.fleet_time + window > slot_start
+        })
+    })
+}
+
+/// Describes which cooldown a fleet blocks, shown when hovering shaded slots
+fn cooldown_label(item: &FleetCalendarItemDto) -> String {
+    match &item.ping_group_name {
+        Some(group) if item.ping_group_cooldown >= item.category_cooldown => {
+            format!("Ping group cooldown of {} ({})", item.fleet.name, group)
+        }
+        _ => format!(
+            "Category cooldown of {} ({})",
+            item.fleet.name, item.fleet.category_name
+        ),
+    }
+}
+
+/// Week & month calendars and a per-day timeline of a guild's fleets
+///
+/// Shades time blocked by category or ping group cooldowns and lets users with create
+/// permissions click an empty slot to schedule a fleet at that time.
+#[component]
+pub fn FleetCalendar(
+    guild_id: u64,
+    mut view: Signal<CalendarView>,
+    refetch_trigger: Signal<u32>,
+    current_time: Signal<DateTime<Utc>>,
+    on_fleet_selected: EventHandler<i32>,
+    on_slot_selected: EventHandler<DateTime<Utc>>,
+) -> Element {
+    let mut date = use_signal(|| Utc::now().date_naive());
+    let mut calendar = use_signal(|| None::<Result<FleetCalendarDto, ApiError>>);
+
+    #[cfg(feature = "web")]
+    let fetch_future = use_resource(use_reactive!(|guild_id| async move {
+        let _ = refetch_trigger(); // Read trigger to track changes
+        let (start, end) = view().range(date());
+        get_fleet_calendar(guild_id, start, end).await
+    }));
+
+    #[cfg(feature = "web")]
+    use_effect(move || match &*fetch_future.read_unchecked() {
+        Some(Ok(data)) => {
+            calendar.set(Some(Ok(data.clone())));
+        }
+        Some(Err(err)) => {
+            tracing::error!("Failed to fetch fleet calendar: {}", err);
+            calendar.set(Some(Err(err.clone())));
+        }
+        None => (),
+    });
+
+    let view_val = view();
+
+    rsx! {
+        // Navigation
+        div {
+            class: "flex flex-wrap items-center justify-between gap-2 mb-4",
+            div {
+                class: "flex gap-2",
+                button {
+                    class: "btn btn-sm",
+                    onclick: move |_| date.set(view().step(date(), false)),
+                    "Previous"
+                }
+                button {
+                    class: "btn btn-sm",
+                    onclick: move |_| date.set(Utc::now().date_naive()),
+                    "Today"
+                }
+                button {
+                    class: "btn btn-sm",
+                    onclick: move |_| date.set(view().step(date(), true)),
+                    "Next"
+                }
+            }
+            h2 {
+                class: "font-semibold",
+                "{view_val.title(date())} (UTC)"
+            }
+        }
+
+        if let Some(Ok(data)) = calendar() {
+            match view_val {
+                CalendarView::Day => rsx! {
+                    DayTimeline {
+                        date: date(),
+                        fleets: data.fleets.clone(),
+                        current_time,
+                        on_fleet_selected,
+                        on_slot_selected,
+                    }
+                },
+                CalendarView::Week => rsx! {
+                    WeekCalendar {
+                        start: data.start,
+                        fleets: data.fleets.clone(),
+                        current_time,
+                        on_fleet_selected,
+                        on_slot_selected,
+                    }
+                },
+                CalendarView::Month => rsx! {
+                    MonthCalendar {
+                        start: data.start,
+                        month: date().month(),
+                        fleets: data.fleets.clone(),
+                        current_time,
+                        on_day_selected: move |day: NaiveDate| {
+                            date.set(day);
+                            view.set(CalendarView::Day);
+                        },
+                        on_fleet_selected,
+                        on_slot_selected,
+                    }
+                },
+            }
+        } else if let Some(Err(error)) = calendar() {
+            div {
+                class: "alert alert-error",
+                span { "Failed to load fleets: {error.message}" }
+            }
+        } else {
+            div {
+                class: "flex items-center justify-center min-h-[400px]",
+                span { class: "loading loading-spinner loading-lg" }
+            }
+        }
+    }
+}
+
+/// Half-hour timeline of a single day with each fleet's cooldown window shaded
+#[component]
+fn DayTimeline(
+    date: NaiveDate,
+    fleets: Vec<FleetCalendarItemDto>,
+    current_time: Signal<DateTime<Utc>>,
+    on_fleet_selected: EventHandler<i32>,
+    on_slot_selected: EventHandler<DateTime<Utc>>,
+) -> Element {
+    let day_start = date.and_time(NaiveTime::MIN).and_utc();
+    let now = current_time();
+
+    rsx! {
+        div {
+            class: "border border-base-300 rounded-box overflow-hidden",
+            for slot in 0..48 {
+                {
+                    let slot_start = day_start + Duration::minutes(30 * slot);
+                    let slot_end = slot_start + Duration::minutes(30);
+                    let slot_fleets = fleets_in_slot(&fleets, slot_start, slot_end);
+                    let cooldown = cooldown_in_slot(&fleets, slot_start, slot_end);
+                    let cooldown_title = cooldown.map(cooldown_label).unwrap_or_default();
+                    let is_shaded = cooldown.is_some();
+                    let prefill = slot_fleet_time(slot_start, slot_end, now)
+                        .filter(|_| slot_fleets.is_empty());
+
+                    rsx! {
+                        div {
+                            key: "{slot}",
+                            class: "flex min-h-10 border-b border-base-300 last:border-b-0",
+                            class: if is_shaded { "bg-warning/10" },
+                            class: if prefill.is_some() { "cursor-pointer hover:bg-base-200" },
+                            title: "{cooldown_title}",
+                            onclick: move |_| {
+                                if let Some(fleet_time) = prefill {
+                                    on_slot_selected.call(fleet_time);
+                                }
+                            },
+                            div {
+                                class: "w-16 flex-shrink-0 px-2 py-2 font-mono text-xs opacity-60",
+                                {slot_start.format("%H:%M").to_string()}
+                            }
+                            div {
+                                class: "flex flex-wrap flex-1 gap-2 p-1",
+                                for item in slot_fleets {
+                                    {
+                                        let fleet_id = item.fleet.id;
+                                        rsx! {
+                                            button {
+                                                key: "{fleet_id}",
+                                                class: "btn btn-sm btn-outline text-left",
+                                                onclick: move |_| on_fleet_selected.call(fleet_id),
+                                                span {
+                                                    class: "font-mono",
+                                                    {item.fleet.fleet_time.format("%H:%M").to_string()}
+                                                }
+                                                span { class: "font-semibold", "{item.fleet.name}" }
+                                                span { class: "opacity-60", "{item.fleet.category_name} · {item.fleet.commander_name}" }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Seven day grid of hourly slots with cooldown windows shaded
+#[component]
+fn WeekCalendar(
+    start: DateTime<Utc>,
+    fleets: Vec<FleetCalendarItemDto>,
+    current_time: Signal<DateTime<Utc>>,
+    on_fleet_selected: EventHandler<i32>,
+    on_slot_selected: EventHandler<DateTime<Utc>>,
+) -> Element {
+    let now = current_time();
+
+    rsx! {
+        div {
+            class: "overflow-x-auto",
+            table {
+                class: "table table-xs table-fixed w-full min-w-[720px]",
+                thead {
+                    tr {
+                        th { class: "w-14" }
+                        for day in 0..7 {
+                            {
+                                let day_start = start + Duration::days(day);
+                                let is_today = day_start.date_naive() == now.date_naive();
+                                rsx! {
+                                    th {
+                                        key: "{day}",
+                                        class: if is_today { "text-primary-content bg-primary" },
+                                        {day_start.format("%a %d").to_string()}
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+                tbody {
+                    for hour in 0..24 {
+                        tr {
+                            key: "{hour}",
+                            td {
+                                class: "font-mono opacity-60",
+                                "{hour:02}:00"
+                            }
+                            for day in 0..7 {
+                                {
+                                    let slot_start = start + Duration::days(day) + Duration::hours(hour);
+                                    let slot_end = slot_start + Duration::hours(1);
+                                    let slot_fleets = fleets_in_slot(&fleets, slot_start, slot_end);
+                                    let cooldown = cooldown_in_slot(&fleets, slot_start, slot_end);
+                                    let cooldown_title = cooldown.map(cooldown_label).unwrap_or_default();
+                                    let is_shaded = cooldown.is_some();
+                                    let prefill = slot_fleet_time(slot_start, slot_end, now)
+                                        .filter(|_| slot_fleets.is_empty());
+
+                                    rsx! {
+                                        td {
+                                            key: "{day}",
+                                            class: "align-top border border-base-300 h-8",
+                                            class: if is_shaded { "bg-warning/10" },
+                                            class: if prefill.is_some() { "cursor-pointer hover:bg-base-200" },
+                                            title: "{cooldown_title}",
+                                            onclick: move |_| {
+                                                if let Some(fleet_time) = prefill {
+                                                    on_slot_selected.call(fleet_time);
+                                                }
+                                            },
+                                            for item in slot_fleets {
+                                                {
+                                                    let fleet_id = item.fleet.id;
+                                                    rsx! {
+                                                        button {
+                                                            key: "{fleet_id}",
+                                                            class: "badge badge-neutral badge-sm w-full justify-start truncate",
+                                                            title: "{item.fleet.category_name} · {item.fleet.commander_name}",
+                                                            onclick: move |_| on_fleet_selected.call(fleet_id),
+                                                            {item.fleet.fleet_time.format("%H:%M").to_string()}
+                                                            " {item.fleet.name}"
+                                                        }
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Six week grid of days covering a month
+#[component]
+fn MonthCalendar(
+    start: DateTime<Utc>,
+    month: u32,
+    fleets: Vec<FleetCalendarItemDto>,
+    current_time: Signal<DateTime<Utc>>,
+    on_day_selected: EventHandler<NaiveDate>,
+    on_fleet_selected: EventHandler<i32>,
+    on_slot_selected: EventHandler<DateTime<Utc>>,
+) -> Element {
+    let now = current_time();
+
+    rsx! {
+        div {
+            class: "grid grid-cols-7 border-l border-t border-base-300",
+            for weekday in ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"] {
+                div {
+                    key: "{weekday}",
+                    class: "p-2 text-center text-sm font-semibold border-r border-b border-base-300",
+                    "{weekday}"
+                }
+            }
+            for day in 0..42 {
+                {
+                    let day_start = start + Duration::days(day);
+                    let day_end = day_start + Duration::days(1);
+                    let date = day_start.date_naive();
+                    let day_fleets = fleets_in_slot(&fleets, day_start, day_end);
+                    let hidden_count = day_fleets.len().saturating_sub(MONTH_DAY_FLEET_LIMIT);
+                    let is_today = date == now.date_naive();
+                    let prefill = slot_fleet_time(day_start, day_end, now)
+                        .filter(|_| day_fleets.is_empty());
+
+                    rsx! {
+                        div {
+                            key: "{day}",
+                            class: "min-h-24 p-1 border-r border-b border-base-300 flex flex-col gap-1",
+                            class: if date.month() != month { "opacity-40" },
+                            class: if prefill.is_some() { "cursor-pointer hover:bg-base-200" },
+                            onclick: move |_| {
+                                if let Some(fleet_time) = prefill {
+                                    on_slot_selected.call(fleet_time);
+                                }
+                            },
+                            button {
+                                class: "self-end btn btn-xs btn-ghost",
+                                class: if is_today { "btn-active" },
+                                title: "Open day timeline",
+                                onclick: move |evt| {
+                                    evt.stop_propagation();
+                                    on_day_selected.call(date);
+                                },
+                                "{date.day()}"
+                            }
+                            for item in day_fleets.into_iter().take(MONTH_DAY_FLEET_LIMIT) {
+                                {
+                                    let fleet_id = item.fleet.id;
+                                    rsx! {
+                                        button {
+                                            key: "{fleet_id}",
+                                            class: "badge badge-neutral badge-sm w-full justify-start truncate",
+                                            title: "{item.fleet.category_name} · {item.fleet.commander_name}",
+                                            onclick: move |_| on_fleet_selected.call(fleet_id),
+                                            {item.fleet.fleet_time.format("%H:%M").to_string()}
+                                            " {item.fleet.name}"
+                                        }
+                                    }
+                                }
+                            }
+                            if hidden_count > 0 {
+                                button {
+                                    class: "text-xs opacity-60 hover:underline text-left",
+                                    onclick: move |evt| {
+                                        evt.stop_propagation();
+                                        on_day_selected.call(date);
+                                    },
+                                    "+{hidden_count} more"
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,130 @@
+use chrono::{DateTime, Duration, Utc};
+use dioxus::prelude::*;
+use dioxus_logger::tracing;
+
+use crate::{client::component::Modal, model::fleet::CloneFleetDto};
+
+#[cfg(feature = "web")]
+use crate::client::api::fleet::clone_fleet;
+
+/// Modal for copying a fleet to a new time
+///
+/// The time defaults to one day after the original fleet, `on_cloned` receives the ID of
+/// the created copy.
+#[component]
+pub fn CloneFleetModal(
+    guild_id: u64,
+    fleet_id: i32,
+    fleet_name: String,
+    fleet_time: DateTime<Utc>,
+    mut show: Signal<bool>,
+    on_cloned: EventHandler<i32>,
+) -> Element {
+    let mut clone_datetime = use_signal(String::new);
+    let mut is_cloning = use_signal(|| false);
+    let mut error = use_signal(|| None::<String>);
+
+    // Suggest the same time on the next day whenever the modal opens
+    use_effect(use_reactive!(|fleet_time| {
+        if show() {
+            clone_datetime.set(
+                (fleet_time + Duration::days(1))
+                    .format("%Y-%m-%d %H:%M")
+                    .to_string(),
+            );
+            error.set(None);
+        }
+    }));
+
+    #[cfg(feature = "web")]
+    let clone_future = use_resource(move || async move {
+        if is_cloning() {
+            let dto = CloneFleetDto {
+                fleet_time: clone_datetime.peek().clone(),
+            };
+            Some(clone_fleet(guild_id, fleet_id, dto).await)
+        } else {
+            None
+        }
+    });
+
+    #[cfg(feature = "web")]
+    use_effect(move || {
+        if let Some(Some(result)) = clone_future.read_unchecked().as_ref() {
+            match result {
+                Ok(fleet) => {
+                    tracing::info!("Fleet cloned successfully");
+                    show.set(false);
+                    on_cloned.call(fleet.id);
+                }
+                Err(err) => {
+                    tracing::error!("Failed to clone fleet: {}", err);
+                    error.set(Some(format!("Failed to clone fleet: {}", err)));
+                }
+            }
+            is_cloning.set(false);
+        }
+    });
+
+    rsx! {
+        Modal {
+            show,
+            title: "Clone Fleet".to_string(),
+            prevent_close: is_cloning(),
+            div {
+                class: "py-4 space-y-4",
+                p {
+                    "Create a copy of "
+                    span { class: "font-bold", "\"{fleet_name}\"" }
+                    " with the same details at a new time."
+                }
+                label {
+                    class: "form-control w-full",
+                    div {
+                        class: "label",
+                        span { class: "label-text", "Fleet Date & Time (UTC)" }
+                    }
+                    input {
+                        r#type: "text",
+                        class: "input input-bordered w-full font-mono",
+                        placeholder: "YYYY-MM-DD HH:MM",
+                        value: "{clone_datetime}",
+                        disabled: is_cloning(),
+                        oninput: move |evt| clone_datetime.set(evt.value()),
+                    }
+                }
+                if let Some(error) = error() {
+                    div {
+                        class: "alert alert-error",
+                        span { "{error}" }
+                    }
+                }
+            }
+            div {
+                class: "modal-action",
+                button {
+                    r#type: "button",
+                    class: "btn",
+                    disabled: is_cloning(),
+                    onclick: move |_| show.set(false),
+                    "Cancel"
+                }
+                button {
+                    r#type: "button",
+                    class: "btn btn-primary",
+                    disabled: is_cloning() || clone_datetime().trim().is_empty(),
+                    onclick: move |_| {
+                        error.set(None);
+                        is_cloning.set(true);
+                    },
+                    if is_cloning() {
+                        span { class: "loading loading-spinner loading-sm" }
+                        " Cloning..."
+                    } else {
+                        "Clone"
+                    }
+                }
+            }
+        }
+    }
+}
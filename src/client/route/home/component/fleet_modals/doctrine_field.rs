@@ -0,0 +1,97 @@
+use dioxus::prelude::*;
+use dioxus_logger::tracing;
+use std::collections::HashMap;
+
+use crate::model::doctrine::DoctrineDto;
+
+#[cfg(feature = "web")]
+use crate::client::api::doctrine::get_guild_doctrines;
+
+/// Doctrine ping format field picking a doctrine of the guild's doctrine library
+///
+/// Stores the doctrine's ID in `field_values`, the hulls of the selected doctrine are
+/// listed below the select.
+#[component]
+pub fn DoctrineField(
+    guild_id: u64,
+    field_id: i32,
+    field_name: String,
+    mut field_values: Signal<HashMap<i32, String>>,
+    is_submitting: bool,
+) -> Element {
+    let mut doctrines = use_signal(|| None::<Vec<DoctrineDto>>);
+    let mut error = use_signal(|| None::<String>);
+
+    #[cfg(feature = "web")]
+    let future = use_resource(move || async move { get_guild_doctrines(guild_id).await });
+
+    #[cfg(feature = "web")]
+    use_effect(move || {
+        if let Some(result) = future.read_unchecked().as_ref() {
+            match result {
+                Ok(data) => {
+                    doctrines.set(Some(data.clone()));
+                    error.set(None);
+                }
+                Err(err) => {
+                    tracing::error!("Failed to fetch doctrines: {}", err);
+                    error.set(Some(err.message.clone()));
+                }
+            }
+        }
+    });
+
+    let value = field_values().get(&field_id).cloned().unwrap_or_default();
+    let selected = doctrines()
+        .unwrap_or_default()
+        .into_iter()
+        .find(|doctrine| doctrine.id.to_string() == value);
+
+    rsx! {
+        if let Some(err) = error() {
+            div {
+                class: "text-sm text-error",
+                "Failed to load doctrines: {err}"
+            }
+        } else if let Some(list) = doctrines() {
+            select {
+                class: "select select-bordered w-full",
+                disabled: is_submitting,
+                onchange: move |e| {
+                    let id = e.value();
+                    if id.is_empty() {
+                        field_values.write().remove(&field_id);
+                    } else {
+                        field_values.write().insert(field_id, id);
+                    }
+                },
+                option {
+                    value: "",
+                    selected: value.is_empty(),
+                    "Select {field_name.to_lowercase()}"
+                }
+                for doctrine in list {
+                    option {
+                        key: "{doctrine.id}",
+                        value: "{doctrine.id}",
+                        selected: doctrine.id.to_string() == value,
+                        "{doctrine.name}"
+                    }
+                }
+            }
+            if let Some(doctrine) = selected {
+                span {
+                    class: "text-sm opacity-70",
+                    "{doctrine.hull_list()}"
+                }
+            } else if !value.is_empty() {
+                span {
+                    class: "text-sm text-warning",
+                    "The selected doctrine no longer exists"
+                }
+            }
+        } else {
+            span { class: "loading loading-spinner loading-sm" }
+        }
+    }
+}
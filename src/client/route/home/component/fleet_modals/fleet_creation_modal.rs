@@ -1,14 +1,20 @@
-use chrono::{Datelike, Timelike, Utc};
+use chrono::{DateTime, Datelike, Timelike, Utc};
 use dioxus::prelude::*;
 use dioxus_logger::tracing;
 use std::collections::HashMap;
 
 use crate::{
     client::{component::modal::FullScreenModal, model::error::ApiError, store::user::UserState},
-    model::{fleet::CreateFleetDto, ping_format::PingFormatFieldType},
+    model::{
+        fleet::CreateFleetDto, fleet_template::FleetTemplateDto, guild_settings::DowntimePolicy,
+        paste::ParsedPasteDto, ping_format::PingFormatFieldType,
+    },
 };
 
-use super::FleetFormFields;
+use super::{
+    form_fields::parse_fleet_datetime, paste_import::PasteImport, template_picker::TemplatePicker,
+    FleetFormFields,
+};
 use crate::client::route::home::{
     CategoryDetailsCache, GuildMembersCache, ManageableCategoriesCache,
 };
@@ -20,10 +26,15 @@ use crate::client::api::{
 };
 
 /// Modal for creating a new fleet with all required details
+///
+/// `fleet_time` prefills the fleet time when opened from an empty calendar slot,
+/// `template` prefills everything else when picked in the category selection modal.
 #[component]
 pub fn FleetCreationModal(
     guild_id: u64,
     category_id: i32,
+    #[props(default = None)] fleet_time: Option<DateTime<Utc>>,
+    #[props(default = None)] template: Option<FleetTemplateDto>,
     mut show: Signal<bool>,
     on_success: EventHandler<()>,
 ) -> Element {
@@ -78,6 +89,22 @@ pub fn FleetCreationModal(
     let mut hidden = use_signal(|| false);
     let mut disable_reminder = use_signal(|| false);
 
+    // Template the form was last prefilled with, its field values are kept over the
+    // category's defaults
+    let mut applied_template = use_signal(|| None::<FleetTemplateDto>);
+
+    let mut apply_template = move |template: FleetTemplateDto| {
+        fleet_name.set(template.fleet_name.clone());
+        fleet_description.set(template.description.clone().unwrap_or_default());
+        if let Some(commander_id) = template.commander_id {
+            fleet_commander_id.set(Some(commander_id));
+        }
+        field_values.write().extend(template.field_values.clone());
+        hidden.set(template.hidden);
+        disable_reminder.set(template.disable_reminder);
+        applied_template.set(Some(template));
+    };
+
     // Submission state
     let mut is_submitting = use_signal(|| false);
     let mut submission_error = use_signal(|| None::<String>);
@@ -288,7 +315,7 @@ pub fn FleetCreationModal(
     }
 
     // Reset form when modal opens/closes
-    use_effect(use_reactive!(|show| {
+    use_effect(use_reactive!(|show, fleet_time, template| {
         if show() {
             // Reset form fields when modal opens
             fleet_name.set(String::new());
@@ -296,6 +323,17 @@ pub fn FleetCreationModal(
             field_values.set(HashMap::new());
             hidden.set(false);
             disable_reminder.set(false);
+            applied_template.set(None);
+
+            // Use the time of the calendar slot the modal was opened from
+            if let Some(fleet_time) = fleet_time {
+                fleet_datetime.set(fleet_time.format("%Y-%m-%d %H:%M").to_string());
+            }
+
+            // Prefill the template picked in the category selection modal
+            if let Some(template) = template {
+                apply_template(template);
+            }
         }
     }));
 
@@ -315,8 +353,18 @@ pub fn FleetCreationModal(
                             defaults.insert(field.id, field.default_field_values[0].clone());
                         }
                     }
+                    // Solar systems & doctrines are always picked by the fleet commander
+                    PingFormatFieldType::SolarSystem | PingFormatFieldType::Doctrine => {}
                 }
             }
+            // Keep the values of a template of this category applied before the details loaded
+            if let Some(template) = applied_template
+                .peek()
+                .as_ref()
+                .filter(|template| template.category_id == details.id)
+            {
+                defaults.extend(template.field_values.clone());
+            }
             // Always set field_values to defaults (empty map if no defaults)
             field_values.set(defaults);
         }
@@ -371,6 +419,14 @@ pub fn FleetCreationModal(
 
     let guild_members = guild_members_cache.read().data.clone();
 
+    // Guilds blocking downtime reject fleets within it, the form fields show why
+    let is_downtime_blocked = match (category_details(), parse_fleet_datetime(&fleet_datetime())) {
+        (Some(Ok(details)), Some(time)) => {
+            details.downtime.policy == DowntimePolicy::Block && details.downtime.contains(time)
+        }
+        _ => false,
+    };
+
     rsx! {
         FullScreenModal {
             show,
@@ -379,6 +435,39 @@ pub fn FleetCreationModal(
             div {
                 class: "space-y-4 overflow-y-auto max-h-[calc(100vh-200px)] sm:max-h-[calc(90vh-200px)]",
 
+                TemplatePicker {
+                    guild_id,
+                    category_id: selected_category_id(),
+                    is_submitting: is_submitting(),
+                    fleet_name,
+                    fleet_commander_id,
+                    fleet_description,
+                    field_values,
+                    hidden,
+                    disable_reminder,
+                    on_apply: apply_template,
+                }
+
+                PasteImport {
+                    guild_id,
+                    category_id: selected_category_id(),
+                    is_submitting: is_submitting(),
+                    on_parsed: move |parsed: ParsedPasteDto| {
+                        // Only overwrite what the paste provided, keeping defaults otherwise
+                        let fleet = parsed.fleet;
+                        if !fleet.name.is_empty() {
+                            fleet_name.set(fleet.name);
+                        }
+                        if !fleet.fleet_time.is_empty() {
+                            fleet_datetime.set(fleet.fleet_time);
+                        }
+                        if let Some(description) = fleet.description {
+                            fleet_description.set(description);
+                        }
+                        field_values.write().extend(fleet.field_values);
+                    },
+                }
+
                 // Use shared form fields component
                 FleetFormFields {
                     guild_id,
@@ -428,7 +517,7 @@ pub fn FleetCreationModal(
                     }
                     button {
                         class: "btn btn-primary",
-                        disabled: fleet_name().is_empty() || fleet_datetime().is_empty() || fleet_commander_id().is_none() || is_submitting() || datetime_error().is_some(),
+                        disabled: fleet_name().is_empty() || fleet_datetime().is_empty() || fleet_commander_id().is_none() || is_submitting() || datetime_error().is_some() || is_downtime_blocked,
                         onclick: move |_| {
                             is_submitting.set(true);
                             submission_error.set(None);
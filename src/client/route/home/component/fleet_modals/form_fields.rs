@@ -2,6 +2,7 @@ use chrono::{DateTime, Local, NaiveDateTime, TimeZone, Utc};
 use dioxus::prelude::*;
 use std::collections::HashMap;
 
+use super::{doctrine_field::DoctrineField, solar_system_field::SolarSystemField};
 use crate::{
     client::{
         component::searchable_dropdown::{DropdownItem, SearchableDropdown},
@@ -9,10 +10,18 @@ use crate::{
     },
     model::{
         category::FleetCategoryDetailsDto, category::FleetCategoryListItemDto,
-        discord::DiscordGuildMemberDto, ping_format::PingFormatFieldType,
+        discord::DiscordGuildMemberDto, guild_settings::DowntimePolicy,
+        ping_format::PingFormatFieldType,
     },
 };
 
+/// Parses the UTC fleet time stored by the datetime input, formatted "YYYY-MM-DD HH:MM"
+pub fn parse_fleet_datetime(value: &str) -> Option<DateTime<Utc>> {
+    NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M")
+        .ok()
+        .map(|naive| Utc.from_utc_datetime(&naive))
+}
+
 /// Type alias for manageable categories signal
 type ManageableCategoriesSignal = Signal<Option<Result<Vec<FleetCategoryListItemDto>, ApiError>>>;
 
@@ -263,6 +272,29 @@ pub fn FleetFormFields(
                                 }
                             }
                         }
+                        // Warn before scheduling into the guild's EVE downtime window
+                        if let Some(fleet_time) = parse_fleet_datetime(&fleet_datetime()).filter(|time| details.downtime.flags(*time)) {
+                            {
+                                let is_blocked = details.downtime.policy == DowntimePolicy::Block;
+                                let message = format!(
+                                    "{} UTC is within EVE downtime ({}), {}",
+                                    fleet_time.format("%H:%M"),
+                                    details.downtime.label(),
+                                    if is_blocked {
+                                        "pick a time outside of it"
+                                    } else {
+                                        "the form-up ping is held back until downtime ends"
+                                    }
+                                );
+
+                                rsx! {
+                                    div {
+                                        class: if is_blocked { "alert alert-error text-sm mt-2 py-2" } else { "alert alert-warning text-sm mt-2 py-2" },
+                                        span { "{message}" }
+                                    }
+                                }
+                            }
+                        }
                     }
 
                     // Fleet Commander - Searchable Dropdown
@@ -391,6 +423,25 @@ pub fn FleetFormFields(
                                                     }
                                                 }
                                             }
+                                            // Render solar system search for SolarSystem type
+                                            else if matches!(field_type, PingFormatFieldType::SolarSystem) {
+                                                SolarSystemField {
+                                                    field_id,
+                                                    field_name: field_name.clone(),
+                                                    field_values,
+                                                    is_submitting,
+                                                }
+                                            }
+                                            // Render doctrine select for Doctrine type
+                                            else if matches!(field_type, PingFormatFieldType::Doctrine) {
+                                                DoctrineField {
+                                                    guild_id,
+                                                    field_id,
+                                                    field_name: field_name.clone(),
+                                                    field_values,
+                                                    is_submitting,
+                                                }
+                                            }
                                             // Render text input with autocomplete for Text type
                                             else {
                                                 {
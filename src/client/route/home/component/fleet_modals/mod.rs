@@ -1,5 +1,10 @@
+mod clone_modal;
+mod doctrine_field;
 mod fleet_creation_modal;
 mod form_fields;
+mod paste_import;
+mod solar_system_field;
+mod template_picker;
 mod view_edit_modal;
 
 pub use fleet_creation_modal::FleetCreationModal;
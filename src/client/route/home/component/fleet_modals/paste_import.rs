@@ -0,0 +1,104 @@
+use dioxus::prelude::*;
+use dioxus_logger::tracing;
+
+use crate::model::paste::ParsedPasteDto;
+
+#[cfg(feature = "web")]
+use crate::client::api::fleet::parse_paste;
+
+/// Paste box prefilling the fleet creation form from an EVE structure notification or
+/// a text ping
+///
+/// The paste is parsed by the server for the selected category, `on_parsed` receives the
+/// prefilled fleet for the FC to review before creating it.
+#[component]
+pub fn PasteImport(
+    guild_id: u64,
+    category_id: i32,
+    is_submitting: bool,
+    on_parsed: EventHandler<ParsedPasteDto>,
+) -> Element {
+    let mut text = use_signal(String::new);
+    let mut is_parsing = use_signal(|| false);
+    let mut parse_error = use_signal(|| None::<String>);
+    let mut warnings = use_signal(Vec::<String>::new);
+
+    #[cfg(feature = "web")]
+    let parse_future = use_resource(move || async move {
+        if is_parsing() {
+            Some(parse_paste(guild_id, category_id, text.peek().clone()).await)
+        } else {
+            None
+        }
+    });
+
+    #[cfg(feature = "web")]
+    use_effect(move || {
+        if let Some(Some(result)) = parse_future.read_unchecked().as_ref() {
+            match result {
+                Ok(parsed) => {
+                    warnings.set(parsed.warnings.clone());
+                    parse_error.set(None);
+                    on_parsed.call(parsed.clone());
+                }
+                Err(err) => {
+                    tracing::error!("Failed to parse paste: {}", err);
+                    warnings.set(Vec::new());
+                    parse_error.set(Some(format!("Failed to parse paste: {}", err)));
+                }
+            }
+            is_parsing.set(false);
+        }
+    });
+
+    rsx! {
+        div {
+            class: "space-y-2",
+            h3 {
+                class: "text-lg font-bold",
+                "Paste Notification"
+            }
+            p {
+                class: "text-sm opacity-70",
+                "Paste a structure notification or ping to prefill the fleet, review the result before creating it."
+            }
+            textarea {
+                class: "textarea textarea-bordered h-24 w-full font-mono text-sm",
+                placeholder: "The Astrahus \"Market\" in Perimeter has lost its shields...",
+                value: "{text}",
+                disabled: is_submitting || is_parsing(),
+                oninput: move |e| text.set(e.value())
+            }
+            div {
+                class: "flex justify-end",
+                button {
+                    class: "btn btn-sm",
+                    disabled: text().trim().is_empty() || is_submitting || is_parsing(),
+                    onclick: move |_| {
+                        parse_error.set(None);
+                        warnings.set(Vec::new());
+                        is_parsing.set(true);
+                    },
+                    if is_parsing() {
+                        span { class: "loading loading-spinner loading-sm" }
+                        " Parsing..."
+                    } else {
+                        "Parse"
+                    }
+                }
+            }
+            if let Some(error) = parse_error() {
+                div {
+                    class: "alert alert-error text-sm py-2",
+                    span { "{error}" }
+                }
+            }
+            for warning in warnings() {
+                div {
+                    class: "alert alert-warning text-sm py-2",
+                    span { "{warning}" }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use dioxus::prelude::*;
+use std::collections::HashMap;
+
+use crate::client::component::SolarSystemPicker;
+
+/// Solar system ping format field with autocomplete from the bundled EVE static map
+///
+/// Stores the canonical system name in `field_values` once a suggestion is picked.
+#[component]
+pub fn SolarSystemField(
+    field_id: i32,
+    field_name: String,
+    mut field_values: Signal<HashMap<i32, String>>,
+    is_submitting: bool,
+) -> Element {
+    let value = field_values().get(&field_id).cloned();
+
+    rsx! {
+        SolarSystemPicker {
+            value,
+            placeholder: format!("Search {}...", field_name.to_lowercase()),
+            disabled: is_submitting,
+            on_change: move |name: Option<String>| match name {
+                Some(name) => {
+                    field_values.write().insert(field_id, name);
+                }
+                None => {
+                    field_values.write().remove(&field_id);
+                }
+            },
+        }
+    }
+}
@@ -0,0 +1,302 @@
+use dioxus::prelude::*;
+use dioxus_logger::tracing;
+use std::collections::HashMap;
+
+use crate::{
+    client::route::home::FleetTemplatesCache,
+    model::fleet_template::{CreateFleetTemplateDto, FleetTemplateDto},
+};
+
+#[cfg(feature = "web")]
+use crate::client::api::fleet_template::{
+    create_fleet_template, delete_fleet_template, get_fleet_templates,
+};
+
+/// Templates of the selected category for prefilling the fleet creation form
+///
+/// Lists the user's own & shared templates of the category, `on_apply` receives the
+/// picked template. The current form, everything but the fleet time, can be saved as a
+/// new template.
+#[component]
+pub fn TemplatePicker(
+    guild_id: u64,
+    category_id: i32,
+    is_submitting: bool,
+    fleet_name: Signal<String>,
+    fleet_commander_id: Signal<Option<u64>>,
+    fleet_description: Signal<String>,
+    field_values: Signal<HashMap<i32, String>>,
+    hidden: Signal<bool>,
+    disable_reminder: Signal<bool>,
+    on_apply: EventHandler<FleetTemplateDto>,
+) -> Element {
+    let mut cache = use_context::<Signal<FleetTemplatesCache>>();
+
+    let mut show_save_form = use_signal(|| false);
+    let mut template_name = use_signal(String::new);
+    let mut shared = use_signal(|| false);
+    let mut is_saving = use_signal(|| false);
+    let mut deleting_id = use_signal(|| None::<i32>);
+    let mut error = use_signal(|| None::<String>);
+
+    // Fetch templates only if not cached or guild changed
+    #[cfg(feature = "web")]
+    {
+        let mut should_fetch = use_signal(|| false);
+
+        use_effect(use_reactive!(|guild_id| {
+            if should_fetch() {
+                return;
+            }
+
+            let mut cache_state = cache.write();
+
+            let needs_fetch = (cache_state.guild_id != Some(guild_id)
+                || cache_state.data.is_none())
+                && !cache_state.is_fetching;
+
+            if needs_fetch {
+                cache_state.is_fetching = true;
+                drop(cache_state);
+                should_fetch.set(true);
+            }
+        }));
+
+        let fetch_future = use_resource(move || async move {
+            if should_fetch() {
+                Some(get_fleet_templates(guild_id).await)
+            } else {
+                None
+            }
+        });
+
+        use_effect(move || {
+            if let Some(Some(result)) = fetch_future.read_unchecked().as_ref() {
+                if let Err(err) = result {
+                    tracing::error!("Failed to fetch fleet templates: {}", err);
+                }
+                let mut cache_state = cache.write();
+                cache_state.guild_id = Some(guild_id);
+                cache_state.data = Some(result.clone());
+                cache_state.is_fetching = false;
+                drop(cache_state);
+                should_fetch.set(false);
+            }
+        });
+    }
+
+    #[cfg(feature = "web")]
+    let save_future = use_resource(move || async move {
+        if is_saving() {
+            let description = fleet_description.peek().clone();
+            let dto = CreateFleetTemplateDto {
+                category_id,
+                name: template_name.peek().clone(),
+                shared: *shared.peek(),
+                fleet_name: fleet_name.peek().clone(),
+                commander_id: *fleet_commander_id.peek(),
+                description: if description.is_empty() {
+                    None
+                } else {
+                    Some(description)
+                },
+                field_values: field_values.peek().clone(),
+                hidden: *hidden.peek(),
+                disable_reminder: *disable_reminder.peek(),
+            };
+            Some(create_fleet_template(guild_id, dto).await)
+        } else {
+            None
+        }
+    });
+
+    #[cfg(feature = "web")]
+    use_effect(move || {
+        if let Some(Some(result)) = save_future.read_unchecked().as_ref() {
+            match result {
+                Ok(template) => {
+                    let mut cache_state = cache.write();
+                    if let Some(Ok(templates)) = cache_state.data.as_mut() {
+                        templates.push(template.clone());
+                        templates.sort_by(|a, b| a.name.cmp(&b.name));
+                    }
+                    drop(cache_state);
+                    template_name.set(String::new());
+                    shared.set(false);
+                    show_save_form.set(false);
+                    error.set(None);
+                }
+                Err(err) => {
+                    tracing::error!("Failed to save fleet template: {}", err);
+                    error.set(Some(format!("Failed to save template: {}", err)));
+                }
+            }
+            is_saving.set(false);
+        }
+    });
+
+    #[cfg(feature = "web")]
+    let delete_future = use_resource(move || async move {
+        match deleting_id() {
+            Some(template_id) => Some((
+                template_id,
+                delete_fleet_template(guild_id, template_id).await,
+            )),
+            None => None,
+        }
+    });
+
+    #[cfg(feature = "web")]
+    use_effect(move || {
+        if let Some(Some((template_id, result))) = delete_future.read_unchecked().as_ref() {
+            match result {
+                Ok(()) => {
+                    if let Some(Ok(templates)) = cache.write().data.as_mut() {
+                        templates.retain(|template| template.id != *template_id);
+                    }
+                    error.set(None);
+                }
+                Err(err) => {
+                    tracing::error!("Failed to delete fleet template: {}", err);
+                    error.set(Some(format!("Failed to delete template: {}", err)));
+                }
+            }
+            deleting_id.set(None);
+        }
+    });
+
+    let templates: Vec<FleetTemplateDto> = cache
+        .read()
+        .data
+        .as_ref()
+        .and_then(|result| result.as_ref().ok())
+        .map(|templates| {
+            templates
+                .iter()
+                .filter(|template| template.category_id == category_id)
+                .cloned()
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let is_busy = is_submitting || is_saving() || deleting_id().is_some();
+
+    rsx! {
+        div {
+            class: "space-y-2",
+            div {
+                class: "flex items-center justify-between",
+                h3 {
+                    class: "text-lg font-bold",
+                    "Templates"
+                }
+                button {
+                    class: "btn btn-sm",
+                    disabled: is_busy,
+                    onclick: move |_| {
+                        error.set(None);
+                        show_save_form.set(!show_save_form());
+                    },
+                    "Save as Template"
+                }
+            }
+            if templates.is_empty() {
+                p {
+                    class: "text-sm opacity-70",
+                    "No templates for this category yet, save the form as a template to reuse it for the next fleet."
+                }
+            } else {
+                div {
+                    class: "flex flex-wrap gap-2",
+                    for template in templates {
+                        {
+                            let template_id = template.id;
+                            let template_name = template.name.clone();
+                            let is_shared = template.shared;
+                            let can_delete = template.can_delete;
+                            rsx! {
+                                div {
+                                    key: "{template_id}",
+                                    class: "join",
+                                    button {
+                                        class: "btn btn-sm btn-outline join-item",
+                                        disabled: is_busy,
+                                        onclick: move |_| on_apply.call(template.clone()),
+                                        "{template_name}"
+                                        if is_shared {
+                                            span { class: "badge badge-xs badge-primary", "Shared" }
+                                        }
+                                    }
+                                    if can_delete {
+                                        button {
+                                            class: "btn btn-sm btn-outline join-item",
+                                            title: "Delete template",
+                                            disabled: is_busy,
+                                            onclick: move |_| {
+                                                error.set(None);
+                                                deleting_id.set(Some(template_id));
+                                            },
+                                            if deleting_id() == Some(template_id) {
+                                                span { class: "loading loading-spinner loading-xs" }
+                                            } else {
+                                                "✕"
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            if show_save_form() {
+                div {
+                    class: "flex flex-col sm:flex-row sm:items-center gap-2",
+                    input {
+                        r#type: "text",
+                        class: "input input-bordered input-sm w-full sm:flex-1",
+                        placeholder: "Template name",
+                        value: "{template_name}",
+                        disabled: is_busy,
+                        oninput: move |e| template_name.set(e.value())
+                    }
+                    label {
+                        class: "label cursor-pointer gap-2",
+                        input {
+                            r#type: "checkbox",
+                            class: "checkbox checkbox-sm",
+                            checked: shared(),
+                            disabled: is_busy,
+                            onchange: move |e| shared.set(e.checked())
+                        }
+                        span {
+                            class: "label-text",
+                            title: "Requires manage permission for the category",
+                            "Share with category"
+                        }
+                    }
+                    button {
+                        class: "btn btn-sm btn-primary",
+                        disabled: template_name().trim().is_empty() || is_busy,
+                        onclick: move |_| {
+                            error.set(None);
+                            is_saving.set(true);
+                        },
+                        if is_saving() {
+                            span { class: "loading loading-spinner loading-sm" }
+                            " Saving..."
+                        } else {
+                            "Save"
+                        }
+                    }
+                }
+            }
+            if let Some(error) = error() {
+                div {
+                    class: "alert alert-error text-sm py-2",
+                    span { "{error}" }
+                }
+            }
+        }
+    }
+}
@@ -8,22 +8,25 @@ use crate::{
     client::{
         component::modal::{ConfirmationModal, FullScreenModal},
         model::error::ApiError,
+        router::Route,
         store::user::UserState,
     },
     model::{
-        category::FleetCategoryDetailsDto, fleet::UpdateFleetDto, ping_format::PingFormatFieldType,
+        category::FleetCategoryDetailsDto,
+        fleet::{FleetEventDto, UpdateFleetDto},
+        ping_format::PingFormatFieldType,
     },
 };
 
-use super::form_fields::FleetFormFields;
+use super::{clone_modal::CloneFleetModal, form_fields::FleetFormFields};
 use crate::client::route::home::{
-    CategoryDetailsCache, GuildMembersCache, ManageableCategoriesCache,
+    component::FleetCountdown, CategoryDetailsCache, GuildMembersCache, ManageableCategoriesCache,
 };
 
 #[cfg(feature = "web")]
 use crate::client::api::{
     fleet::{delete_fleet, get_category_details, get_fleet, get_guild_members, update_fleet},
-    user::get_user_manageable_categories,
+    user::{get_subscriptions, get_user_manageable_categories, set_fleet_subscription},
 };
 
 #[derive(Clone, Copy, PartialEq)]
@@ -36,12 +39,15 @@ pub enum ViewEditMode {
 #[component]
 pub fn FleetViewEditModal(
     guild_id: u64,
-    fleet_id: Signal<Option<i32>>,
+    mut fleet_id: Signal<Option<i32>>,
     mut show: Signal<bool>,
     mut refetch_trigger: Signal<u32>,
+    live_event: Signal<Option<FleetEventDto>>,
+    current_time: Signal<chrono::DateTime<Utc>>,
 ) -> Element {
     let user_store = use_context::<Store<UserState>>();
     let current_user = user_store.read().user.clone();
+    let current_user_for_clone = current_user.clone();
     let current_user_id = current_user.as_ref().map(|user| user.discord_id);
 
     let mut mode = use_signal(|| ViewEditMode::View);
@@ -75,15 +81,32 @@ pub fn FleetViewEditModal(
     let mut is_submitting = use_signal(|| false);
     let mut submission_error = use_signal(|| None::<String>);
 
+    // Changes made to the open fleet by someone else
+    let mut live_notice = use_signal(|| None::<String>);
+    let mut is_cancelled = use_signal(|| false);
+
+    // Direct message subscription state
+    let mut is_subscribed = use_signal(|| None::<bool>);
+    let mut pending_subscription = use_signal(|| None::<bool>);
+
     // Delete modal state
     let mut show_delete_modal = use_signal(|| false);
     let mut is_deleting = use_signal(|| false);
 
+    // Clone modal state
+    let mut show_clone_modal = use_signal(|| false);
+
     // Datetime validation error
     let mut datetime_error = use_signal(|| None::<String>);
 
     // Permission check: user can manage if they are admin, have manage permission, or are the fleet commander
     let can_manage = use_memo(move || {
+        // Relayed fleets are read-only, they change along with the fleet they were relayed from
+        if let Some(Ok(fleet)) = fleet_data() {
+            if fleet.relayed_from.is_some() {
+                return false;
+            }
+        }
         if let Some(user) = &current_user {
             if user.admin {
                 return true;
@@ -102,6 +125,21 @@ pub fn FleetViewEditModal(
         false
     });
 
+    // Cloning creates a fleet in the same category, requiring create permission for it
+    let can_clone = use_memo(move || {
+        if let Some(user) = &current_user_for_clone {
+            if user.admin {
+                return true;
+            }
+            if let Some(Ok(fleet)) = fleet_data() {
+                if let Some(Ok(categories)) = manageable_categories_cache.read().data.as_ref() {
+                    return categories.iter().any(|cat| cat.id == fleet.category_id);
+                }
+            }
+        }
+        false
+    });
+
     // Check if more than 1 hour has elapsed since fleet start time
     let is_fleet_edit_locked = use_memo(move || {
         if let Some(Ok(fleet)) = fleet_data() {
@@ -118,6 +156,42 @@ pub fn FleetViewEditModal(
         if show() && fleet_id().is_some() {
             mode.set(ViewEditMode::View);
             submission_error.set(None);
+            live_notice.set(None);
+            is_cancelled.set(false);
+        }
+    });
+
+    // Apply live changes to the open fleet, only the event is tracked so older events
+    // aren't replayed when the modal is reopened
+    use_effect(move || {
+        let Some(event) = live_event() else {
+            return;
+        };
+        // Changes made from this modal are reflected by its own submission handling
+        if !*show.peek() || *is_cancelled.peek() || *is_submitting.peek() || *is_deleting.peek() {
+            return;
+        }
+        let open_fleet_id = *fleet_id.peek();
+        if event.fleet_id().is_some() && event.fleet_id() != open_fleet_id {
+            return;
+        }
+
+        match (event, *mode.peek()) {
+            (FleetEventDto::Deleted { .. }, _) => {
+                is_cancelled.set(true);
+                live_notice.set(Some("This fleet has been cancelled.".to_string()));
+            }
+            (_, ViewEditMode::View) => {
+                fleet_refetch_trigger.with_mut(|trigger| *trigger += 1);
+            }
+            // Missed events may not be about this fleet, keep the form as is
+            (FleetEventDto::Resync, ViewEditMode::Edit) => {}
+            (_, ViewEditMode::Edit) => {
+                live_notice.set(Some(
+                    "This fleet was changed by someone else, saving will overwrite their changes."
+                        .to_string(),
+                ));
+            }
         }
     });
 
@@ -228,6 +302,57 @@ pub fn FleetViewEditModal(
         });
     }
 
+    // Fetch whether the user is subscribed to the fleet
+    #[cfg(feature = "web")]
+    {
+        let fetch_subscription_future = use_resource(move || async move {
+            if show() {
+                if let Some(id) = fleet_id() {
+                    Some((id, get_subscriptions(guild_id).await))
+                } else {
+                    None
+                }
+            } else {
+                None
+            }
+        });
+
+        use_effect(move || {
+            if let Some(Some((id, result))) = fetch_subscription_future.read_unchecked().as_ref() {
+                match result {
+                    Ok(subscriptions) => {
+                        is_subscribed.set(Some(subscriptions.fleet_ids.contains(id)))
+                    }
+                    Err(err) => {
+                        tracing::error!("Failed to fetch subscriptions: {}", err);
+                        is_subscribed.set(None);
+                    }
+                }
+            }
+        });
+
+        let subscription_future = use_resource(move || async move {
+            match (pending_subscription(), fleet_id.peek().as_ref()) {
+                (Some(subscribed), Some(&id)) => Some((
+                    subscribed,
+                    set_fleet_subscription(guild_id, id, subscribed).await,
+                )),
+                _ => None,
+            }
+        });
+
+        use_effect(move || {
+            if let Some(Some((subscribed, result))) = subscription_future.read_unchecked().as_ref()
+            {
+                match result {
+                    Ok(()) => is_subscribed.set(Some(*subscribed)),
+                    Err(err) => tracing::error!("Failed to update fleet subscription: {}", err),
+                }
+                pending_subscription.set(None);
+            }
+        });
+    }
+
     // Fetch category details (re-fetches when selected_category_id changes in edit mode)
     #[cfg(feature = "web")]
     {
@@ -519,6 +644,13 @@ pub fn FleetViewEditModal(
             div {
                 class: "space-y-4 overflow-y-auto max-h-[calc(100vh-200px)] sm:max-h-[calc(90vh-200px)]",
 
+                if let Some(notice) = live_notice() {
+                    div {
+                        class: "alert alert-warning",
+                        span { "{notice}" }
+                    }
+                }
+
                 match mode() {
                     ViewEditMode::View => rsx! {
                         if let Some(Ok(fleet)) = fleet_data() {
@@ -526,6 +658,13 @@ pub fn FleetViewEditModal(
                             div {
                                 class: "space-y-4",
 
+                                if let Some(source_guild) = &fleet.relayed_from {
+                                    div {
+                                        class: "alert alert-info",
+                                        span { "Relayed from {source_guild}, this fleet can only be changed in that server." }
+                                    }
+                                }
+
                                 // Category and Fleet Info
                                 div {
                                     class: "grid grid-cols-1 md:grid-cols-2 gap-4",
@@ -564,6 +703,10 @@ pub fn FleetViewEditModal(
                                             class: "input input-bordered w-full flex items-center bg-base-200 font-mono",
                                             {fleet.fleet_time.format("%Y-%m-%d %H:%M").to_string()}
                                             span { class: "ml-2 text-sm opacity-60", "(UTC)" }
+                                            span {
+                                                class: "ml-auto text-sm font-sans",
+                                                FleetCountdown { fleet_time: fleet.fleet_time, current_time }
+                                            }
                                         }
                                     }
 
@@ -598,8 +741,14 @@ pub fn FleetViewEditModal(
                                                                     "false" => "No",
                                                                     _ => field_value.as_str(),
                                                                 },
-                                                                PingFormatFieldType::Text => field_value.as_str(),
+                                                                PingFormatFieldType::Text | PingFormatFieldType::SolarSystem => field_value.as_str(),
+                                                                PingFormatFieldType::Doctrine => fleet
+                                                                    .doctrines
+                                                                    .get(&field.name)
+                                                                    .map(|doctrine| doctrine.name.as_str())
+                                                                    .unwrap_or("Unknown doctrine"),
                                                             };
+                                                            let solar_system = fleet.solar_systems.get(&field.name).cloned();
 
                                                             rsx! {
                                                                 div {
@@ -610,8 +759,20 @@ pub fn FleetViewEditModal(
                                                                         span { class: "label-text", "{field_name}" }
                                                                     }
                                                                     div {
-                                                                        class: "input input-bordered w-full flex items-center bg-base-200",
-                                                                        "{display_value}"
+                                                                        class: "input input-bordered w-full flex items-center gap-2 bg-base-200",
+                                                                        if let Some(system) = solar_system {
+                                                                            a {
+                                                                                class: "link link-primary",
+                                                                                href: "{system.dotlan_url}",
+                                                                                target: "_blank",
+                                                                                rel: "noopener noreferrer",
+                                                                                "{system.name}"
+                                                                            }
+                                                                            span { class: system.security_class(), "{system.security_label()}" }
+                                                                            span { class: "opacity-70", "{system.region}" }
+                                                                        } else {
+                                                                            "{display_value}"
+                                                                        }
                                                                     }
                                                                 }
                                                             }
@@ -623,6 +784,30 @@ pub fn FleetViewEditModal(
                                     }
                                 }
 
+                                // Route from staging to the form-up system
+                                if let Some(route) = fleet.route.clone() {
+                                    div {
+                                        class: "flex flex-col gap-2",
+                                        label {
+                                            class: "label",
+                                            span { class: "label-text", "Route from Staging ({route.staging.name})" }
+                                        }
+                                        div {
+                                            class: "input input-bordered w-full flex items-center gap-2 bg-base-200",
+                                            a {
+                                                class: "link link-primary",
+                                                href: "{route.dotlan_url}",
+                                                target: "_blank",
+                                                rel: "noopener noreferrer",
+                                                if route.jumps == 1 { "1 jump" } else { "{route.jumps} jumps" }
+                                            }
+                                            span { class: "text-success", "{route.high_sec_jumps} HS" }
+                                            span { class: "text-warning", "{route.low_sec_jumps} LS" }
+                                            span { class: "text-error", "{route.null_sec_jumps} NS" }
+                                        }
+                                    }
+                                }
+
                                 // Description
                                 div {
                                     class: "space-y-2",
@@ -658,12 +843,37 @@ pub fn FleetViewEditModal(
                                 // Action Buttons
                                 div {
                                     class: "flex gap-2 justify-end pt-4",
+                                    if let Some(subscribed) = is_subscribed().filter(|_| !is_cancelled()) {
+                                        button {
+                                            class: "btn btn-outline mr-auto",
+                                            title: "Get direct messages about changes to this fleet",
+                                            disabled: pending_subscription().is_some(),
+                                            onclick: move |_| pending_subscription.set(Some(!subscribed)),
+                                            if subscribed { "Unsubscribe" } else { "Subscribe" }
+                                        }
+                                    }
+                                    if !is_cancelled() {
+                                        Link {
+                                            class: "btn btn-ghost",
+                                            title: "Open a shareable page of this fleet",
+                                            to: Route::FleetDetail { guild_id, fleet_id: fleet.id },
+                                            "Open Page"
+                                        }
+                                    }
                                     button {
                                         class: "btn",
                                         onclick: move |_| show.set(false),
                                         "Close"
                                     }
-                                    if can_manage() {
+                                    if can_clone() && !is_cancelled() {
+                                        button {
+                                            class: "btn btn-outline",
+                                            title: "Copy this fleet to a new time",
+                                            onclick: move |_| show_clone_modal.set(true),
+                                            "Clone"
+                                        }
+                                    }
+                                    if can_manage() && !is_cancelled() {
                                         button {
                                             class: "btn btn-error",
                                             onclick: move |_| show_delete_modal.set(true),
@@ -835,5 +1045,21 @@ pub fn FleetViewEditModal(
                 is_deleting.set(true);
             },
         }
+
+        // Clone Modal
+        if let Some(Ok(fleet)) = fleet_data() {
+            CloneFleetModal {
+                guild_id,
+                fleet_id: fleet.id,
+                fleet_name: fleet.name.clone(),
+                fleet_time: fleet.fleet_time,
+                show: show_clone_modal,
+                on_cloned: move |cloned_id| {
+                    // Trigger refetch in parent & show the copy
+                    refetch_trigger.set(refetch_trigger() + 1);
+                    fleet_id.set(Some(cloned_id));
+                },
+            }
+        }
     }
 }
@@ -7,11 +7,16 @@ use crate::{
         component::{Pagination, PaginationData},
         model::error::ApiError,
     },
-    model::fleet::PaginatedFleetsDto,
+    model::fleet::{FleetEventDto, PaginatedFleetsDto},
+};
+
+use super::{
+    bulk_fleet_actions::BulkFleetActions,
+    fleet_calendar::{CalendarView, FleetCalendar},
 };
 
 #[cfg(feature = "web")]
-use crate::client::api::fleet::get_fleets;
+use crate::client::api::fleet::{get_fleets, stream_fleet_events};
 
 #[derive(Clone, Copy, PartialEq)]
 pub enum SortField {
@@ -49,14 +54,31 @@ impl Default for FleetTableCache {
 }
 
 #[component]
-pub fn FleetTable(guild_id: u64, mut refetch_trigger: Signal<u32>) -> Element {
+pub fn FleetTable(
+    guild_id: u64,
+    mut refetch_trigger: Signal<u32>,
+    on_slot_selected: EventHandler<DateTime<Utc>>,
+) -> Element {
     let cache = use_signal(FleetTableCache::default);
     let mut fleets = use_signal(|| None::<Result<PaginatedFleetsDto, ApiError>>);
 
+    // Calendar views, the paginated list is shown while no calendar is selected
+    let mut show_calendar = use_signal(|| false);
+    let mut calendar_view = use_signal(|| CalendarView::Week);
+
     // View/Edit modal state
     let mut fleet_id_to_view = use_signal(|| None::<i32>);
     let mut show_view_edit_modal = use_signal(|| false);
 
+    // Fleets selected for bulk actions, kept across pages
+    let mut selected = use_signal(Vec::<i32>::new);
+
+    // Fleets of another guild can't be changed together with the selected ones
+    use_effect(use_reactive!(|guild_id| {
+        let _ = guild_id;
+        selected.set(Vec::new());
+    }));
+
     // Shared timer for all countdown components - updates once per second
     let mut current_time = use_signal(Utc::now);
 
@@ -68,6 +90,41 @@ pub fn FleetTable(guild_id: u64, mut refetch_trigger: Signal<u32>) -> Element {
         }
     });
 
+    // Latest live fleet change, forwarded to the view/edit modal
+    let mut live_event = use_signal(|| None::<FleetEventDto>);
+    let mut is_live = use_signal(|| false);
+
+    // Refetch whenever a fleet visible to the user changes, reconnecting on guild change
+    #[cfg(feature = "web")]
+    let _live_future = use_resource(use_reactive!(|guild_id| async move {
+        let mut stream = match stream_fleet_events(guild_id) {
+            Ok(stream) => stream,
+            Err(err) => {
+                tracing::error!("Failed to open fleet event stream: {}", err);
+                is_live.set(false);
+                return;
+            }
+        };
+        is_live.set(true);
+
+        while let Some(result) = stream.next().await {
+            match result {
+                Ok(event) => {
+                    is_live.set(true);
+                    live_event.set(Some(event));
+                }
+                Err(err) => {
+                    // The browser reconnects on its own, refetch once to catch missed changes
+                    tracing::warn!("{}", err);
+                    is_live.set(false);
+                    live_event.set(Some(FleetEventDto::Resync));
+                }
+            }
+            // Written without reading so the stream isn't restarted by its own refetches
+            refetch_trigger.with_mut(|trigger| *trigger += 1);
+        }
+    }));
+
     // Fetch fleets with pagination
     #[cfg(feature = "web")]
     let fetch_future = use_resource(move || async move {
@@ -89,7 +146,60 @@ pub fn FleetTable(guild_id: u64, mut refetch_trigger: Signal<u32>) -> Element {
     });
 
     rsx! {
-        if let Some(Ok(data)) = fleets() {
+        div {
+            class: "flex items-center justify-between mb-2",
+            div {
+                class: "flex gap-1",
+                button {
+                    class: "btn btn-xs",
+                    class: if !show_calendar() { "btn-active" },
+                    onclick: move |_| show_calendar.set(false),
+                    "List"
+                }
+                for (view, label) in [
+                    (CalendarView::Day, "Day"),
+                    (CalendarView::Week, "Week"),
+                    (CalendarView::Month, "Month"),
+                ] {
+                    button {
+                        key: "{label}",
+                        class: "btn btn-xs",
+                        class: if show_calendar() && calendar_view() == view { "btn-active" },
+                        onclick: move |_| {
+                            calendar_view.set(view);
+                            show_calendar.set(true);
+                        },
+                        "{label}"
+                    }
+                }
+            }
+            if is_live() {
+                span {
+                    class: "badge badge-success badge-sm gap-1",
+                    title: "Fleet changes appear automatically",
+                    "Live"
+                }
+            } else {
+                span {
+                    class: "badge badge-ghost badge-sm gap-1",
+                    title: "Reconnecting, fleet changes may be delayed",
+                    "Offline"
+                }
+            }
+        }
+        if show_calendar() {
+            FleetCalendar {
+                guild_id,
+                view: calendar_view,
+                refetch_trigger,
+                current_time,
+                on_fleet_selected: move |fleet_id| {
+                    fleet_id_to_view.set(Some(fleet_id));
+                    show_view_edit_modal.set(true);
+                },
+                on_slot_selected,
+            }
+        } else if let Some(Ok(data)) = fleets() {
             if data.fleets.is_empty() {
                 div {
                     class: "flex items-center justify-center min-h-[400px]",
@@ -173,13 +283,47 @@ pub fn FleetTable(guild_id: u64, mut refetch_trigger: Signal<u32>) -> Element {
                         }
                     }
 
+                    let page_fleet_ids: Vec<i32> = sorted_fleets.iter().map(|fleet| fleet.id).collect();
+                    let all_selected = {
+                        let selected = selected();
+                        page_fleet_ids.iter().all(|id| selected.contains(id))
+                    };
+
                     rsx! {
+                        if !selected().is_empty() {
+                            BulkFleetActions {
+                                guild_id,
+                                selected,
+                                refetch_trigger,
+                            }
+                        }
                         div {
                             class: "overflow-x-auto",
                             table {
                                 class: "table table-zebra w-full",
                                 thead {
                                     tr {
+                                        th {
+                                            input {
+                                                r#type: "checkbox",
+                                                class: "checkbox checkbox-sm",
+                                                title: "Select all fleets on this page",
+                                                checked: all_selected,
+                                                onchange: move |_| {
+                                                    selected.with_mut(|selected| {
+                                                        if all_selected {
+                                                            selected.retain(|id| !page_fleet_ids.contains(id));
+                                                        } else {
+                                                            for id in &page_fleet_ids {
+                                                                if !selected.contains(id) {
+                                                                    selected.push(*id);
+                                                                }
+                                                            }
+                                                        }
+                                                    });
+                                                },
+                                            }
+                                        }
                                         SortableHeader {
                                             label: "Category",
                                             field: SortField::Category,
@@ -224,6 +368,22 @@ pub fn FleetTable(guild_id: u64, mut refetch_trigger: Signal<u32>) -> Element {
                                             rsx! {
                                                 tr {
                                                     key: "{fleet_id}",
+                                                    td {
+                                                        input {
+                                                            r#type: "checkbox",
+                                                            class: "checkbox checkbox-sm",
+                                                            checked: selected().contains(&fleet_id),
+                                                            onchange: move |_| {
+                                                                selected.with_mut(|selected| {
+                                                                    if let Some(index) = selected.iter().position(|id| *id == fleet_id) {
+                                                                        selected.remove(index);
+                                                                    } else {
+                                                                        selected.push(fleet_id);
+                                                                    }
+                                                                });
+                                                            },
+                                                        }
+                                                    }
                                                     td { "{fleet.category_name}" }
                                                     td {
                                                         class: "font-semibold",
@@ -292,6 +452,8 @@ pub fn FleetTable(guild_id: u64, mut refetch_trigger: Signal<u32>) -> Element {
             fleet_id: fleet_id_to_view,
             show: show_view_edit_modal,
             refetch_trigger,
+            live_event,
+            current_time,
         }
     }
 }
@@ -347,8 +509,9 @@ fn SortableHeader(label: String, field: SortField, mut cache: Signal<FleetTableC
     }
 }
 
+/// Countdown to a fleet's start, ticking with the shared current time
 #[component]
-fn FleetCountdown(fleet_time: DateTime<Utc>, current_time: Signal<DateTime<Utc>>) -> Element {
+pub fn FleetCountdown(fleet_time: DateTime<Utc>, current_time: Signal<DateTime<Utc>>) -> Element {
     let duration = fleet_time.signed_duration_since(current_time());
     let seconds = duration.num_seconds();
 
@@ -0,0 +1,265 @@
+use chrono::{DateTime, Local, Utc};
+use dioxus::prelude::*;
+use dioxus_logger::tracing;
+use std::collections::HashSet;
+
+use crate::{
+    client::{
+        component::{Pagination, PaginationData},
+        model::error::ApiError,
+    },
+    model::{
+        discord::DiscordGuildDto,
+        fleet::{FleetEventDto, FleetGuildDto, PaginatedGuildFleetsDto},
+    },
+};
+
+use super::FleetCountdown;
+
+#[cfg(feature = "web")]
+use crate::client::api::fleet::get_user_fleets;
+
+/// Timerboard merging the upcoming fleets of every server the user can view
+///
+/// Servers can be toggled off to narrow down the list, each fleet is shown with a badge
+/// of the server it was scheduled in.
+#[component]
+pub fn GuildFleetTable(guilds: Vec<DiscordGuildDto>, refetch_trigger: Signal<u32>) -> Element {
+    let mut fleets = use_signal(|| None::<Result<PaginatedGuildFleetsDto, ApiError>>);
+    let mut page = use_signal(|| 0u64);
+    let mut per_page = use_signal(|| 10u64);
+    let mut excluded_guild_ids = use_signal(HashSet::<u64>::new);
+
+    // View/Edit modal state, opened for the server of the selected fleet
+    let mut fleet_id_to_view = use_signal(|| None::<i32>);
+    let mut guild_id_to_view = use_signal(|| None::<u64>);
+    let mut show_view_edit_modal = use_signal(|| false);
+    let live_event = use_signal(|| None::<FleetEventDto>);
+
+    // Shared timer for all countdown components - updates once per second
+    let mut current_time = use_signal(Utc::now);
+
+    #[cfg(feature = "web")]
+    use_future(move || async move {
+        loop {
+            gloo_timers::future::TimeoutFuture::new(1_000).await;
+            current_time.set(Utc::now());
+        }
+    });
+
+    let all_guild_ids: Vec<u64> = guilds.iter().map(|guild| guild.guild_id).collect();
+
+    #[cfg(feature = "web")]
+    let fetch_future = use_resource(use_reactive!(|all_guild_ids| async move {
+        let _ = refetch_trigger(); // Read trigger to track changes
+        let excluded = excluded_guild_ids();
+        // Only send a filter once servers were toggled off
+        let guild_ids = (!excluded.is_empty()).then(|| {
+            all_guild_ids
+                .iter()
+                .copied()
+                .filter(|id| !excluded.contains(id))
+                .collect::<Vec<_>>()
+        });
+        get_user_fleets(page(), per_page(), guild_ids).await
+    }));
+
+    #[cfg(feature = "web")]
+    use_effect(move || match &*fetch_future.read_unchecked() {
+        Some(Ok(data)) => {
+            fleets.set(Some(Ok(data.clone())));
+        }
+        Some(Err(err)) => {
+            tracing::error!("Failed to fetch fleets: {}", err);
+            fleets.set(Some(Err(err.clone())));
+        }
+        None => (),
+    });
+
+    rsx! {
+        // Server filters
+        div {
+            class: "flex flex-wrap gap-2 mb-4",
+            for guild in guilds.clone() {
+                {
+                    let guild_id = guild.guild_id;
+                    let is_included = !excluded_guild_ids().contains(&guild_id);
+                    rsx! {
+                        button {
+                            key: "{guild_id}",
+                            class: "btn btn-sm gap-2",
+                            class: if is_included { "btn-primary" } else { "btn-ghost opacity-60" },
+                            onclick: move |_| {
+                                excluded_guild_ids.with_mut(|excluded| {
+                                    if !excluded.remove(&guild_id) {
+                                        excluded.insert(guild_id);
+                                    }
+                                });
+                                page.set(0);
+                            },
+                            "{guild.name}"
+                        }
+                    }
+                }
+            }
+        }
+
+        if all_guild_ids.iter().all(|id| excluded_guild_ids().contains(id)) {
+            div {
+                class: "flex items-center justify-center min-h-[400px]",
+                p {
+                    class: "text-lg opacity-50",
+                    "Select a server to show its fleets"
+                }
+            }
+        } else if let Some(Ok(data)) = fleets() {
+            if data.fleets.is_empty() {
+                div {
+                    class: "flex items-center justify-center min-h-[400px]",
+                    p {
+                        class: "text-lg opacity-50",
+                        "No fleets scheduled yet"
+                    }
+                }
+            } else {
+                div {
+                    class: "overflow-x-auto",
+                    table {
+                        class: "table table-zebra w-full",
+                        thead {
+                            tr {
+                                th { "Server" }
+                                th { "Category" }
+                                th { "Fleet Name" }
+                                th { "Fleet Commander" }
+                                th { "Countdown" }
+                                th { "Date & Time (UTC)" }
+                                th { "Date & Time (Local)" }
+                                th { class: "text-right", "Actions" }
+                            }
+                        }
+                        tbody {
+                            for item in data.fleets.clone() {
+                                {
+                                    let fleet = item.fleet;
+                                    let fleet_id = fleet.id;
+                                    let guild_id = item.guild.guild_id;
+                                    let fleet_time = fleet.fleet_time;
+                                    let local_time: DateTime<Local> = fleet_time.with_timezone(&Local);
+
+                                    rsx! {
+                                        tr {
+                                            key: "{fleet_id}",
+                                            td {
+                                                GuildBadge { guild: item.guild }
+                                            }
+                                            td { "{fleet.category_name}" }
+                                            td {
+                                                class: "font-semibold",
+                                                "{fleet.name}"
+                                            }
+                                            td { "{fleet.commander_name}" }
+                                            td {
+                                                FleetCountdown {
+                                                    fleet_time,
+                                                    current_time
+                                                }
+                                            }
+                                            td {
+                                                class: "font-mono text-sm",
+                                                {fleet_time.format("%Y-%m-%d %H:%M").to_string()}
+                                            }
+                                            td {
+                                                class: "font-mono text-sm",
+                                                {local_time.format("%Y-%m-%d %H:%M").to_string()}
+                                            }
+                                            td {
+                                                div {
+                                                    class: "flex gap-2 justify-end",
+                                                    button {
+                                                        class: "btn btn-sm btn-ghost",
+                                                        title: "View Details",
+                                                        onclick: move |_| {
+                                                            guild_id_to_view.set(Some(guild_id));
+                                                            fleet_id_to_view.set(Some(fleet_id));
+                                                            show_view_edit_modal.set(true);
+                                                        },
+                                                        "View"
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                Pagination {
+                    page,
+                    per_page,
+                    data: PaginationData {
+                        page: data.page,
+                        per_page: data.per_page,
+                        total: data.total,
+                        total_pages: data.total_pages,
+                    },
+                    on_page_change: move |new_page| {
+                        page.set(new_page);
+                    },
+                    on_per_page_change: move |new_per_page| {
+                        per_page.set(new_per_page);
+                        page.set(0);
+                    },
+                }
+            }
+        } else if let Some(Err(error)) = fleets() {
+            div {
+                class: "alert alert-error",
+                span { "Failed to load fleets: {error.message}" }
+            }
+        } else {
+            div {
+                class: "flex items-center justify-center min-h-[400px]",
+                span { class: "loading loading-spinner loading-lg" }
+            }
+        }
+
+        // View/Edit Modal, recreated per server so it loads that server's data
+        if let Some(guild_id) = guild_id_to_view() {
+            super::FleetViewEditModal {
+                key: "{guild_id}",
+                guild_id,
+                fleet_id: fleet_id_to_view,
+                show: show_view_edit_modal,
+                refetch_trigger,
+                live_event,
+                current_time,
+            }
+        }
+    }
+}
+
+/// Icon & name of the server a fleet was scheduled in
+#[component]
+fn GuildBadge(guild: FleetGuildDto) -> Element {
+    rsx! {
+        div {
+            class: "flex items-center gap-2 whitespace-nowrap",
+            if let Some(icon_hash) = &guild.icon_hash {
+                img {
+                    src: "https://cdn.discordapp.com/icons/{guild.guild_id}/{icon_hash}.png",
+                    alt: "{guild.name} icon",
+                    class: "w-6 h-6 rounded-full",
+                }
+            } else {
+                div {
+                    class: "w-6 h-6 rounded-full bg-base-300 flex items-center justify-center text-xs font-bold",
+                    "{guild.name.chars().next().unwrap_or('?')}"
+                }
+            }
+            span { class: "badge badge-outline badge-sm", "{guild.name}" }
+        }
+    }
+}
@@ -1,9 +1,19 @@
+mod broadcast_modal;
+mod bulk_fleet_actions;
 mod category_selection_modal;
 mod create_fleet_button;
+mod fleet_calendar;
 mod fleet_modals;
 mod fleet_table;
+mod guild_fleet_table;
+mod notification_settings_modal;
+mod stand_down_modal;
 
+pub use broadcast_modal::BroadcastButton;
 pub use category_selection_modal::CategorySelectionModal;
 pub use create_fleet_button::CreateFleetButton;
 pub use fleet_modals::{FleetCreationModal, FleetViewEditModal};
-pub use fleet_table::FleetTable;
+pub use fleet_table::{FleetCountdown, FleetTable};
+pub use guild_fleet_table::GuildFleetTable;
+pub use notification_settings_modal::NotificationSettingsModal;
+pub use stand_down_modal::StandDownButton;
@@ -0,0 +1,322 @@
+use dioxus::prelude::*;
+use dioxus_logger::tracing;
+
+use crate::{
+    client::{component::Modal, model::error::ApiError},
+    model::subscription::{NotificationPreferenceDto, SubscriptionsDto},
+};
+
+#[cfg(feature = "web")]
+use crate::client::api::user::{
+    get_notification_preferences, get_subscriptions, set_category_subscription,
+    update_notification_preferences,
+};
+
+/// Longest personal reminder lead time a user can configure, in minutes
+const MAX_REMINDER_MINUTES: i32 = 24 * 60;
+
+/// Modal for managing direct message notification preferences and category subscriptions
+#[component]
+pub fn NotificationSettingsModal(guild_id: u64, show: Signal<bool>) -> Element {
+    let mut preferences = use_signal(|| None::<Result<NotificationPreferenceDto, ApiError>>);
+    let mut subscriptions = use_signal(|| None::<Result<SubscriptionsDto, ApiError>>);
+    let mut is_saving = use_signal(|| false);
+    let mut save_result = use_signal(|| None::<Result<(), ApiError>>);
+    let mut pending_toggle = use_signal(|| None::<(i32, bool)>);
+    let mut toggle_error = use_signal(|| None::<String>);
+
+    #[cfg(feature = "web")]
+    {
+        // Reload whenever the modal opens so changes made via Discord are reflected
+        let preferences_future = use_resource(move || async move {
+            if show() {
+                Some(get_notification_preferences().await)
+            } else {
+                None
+            }
+        });
+
+        use_effect(move || {
+            if let Some(Some(result)) = preferences_future.read_unchecked().as_ref() {
+                if let Err(err) = result {
+                    tracing::error!("Failed to fetch notification preferences: {}", err);
+                }
+                preferences.set(Some(result.clone()));
+            }
+        });
+
+        let subscriptions_future = use_resource(move || async move {
+            if show() {
+                Some(get_subscriptions(guild_id).await)
+            } else {
+                None
+            }
+        });
+
+        use_effect(move || {
+            if let Some(Some(result)) = subscriptions_future.read_unchecked().as_ref() {
+                if let Err(err) = result {
+                    tracing::error!("Failed to fetch subscriptions: {}", err);
+                }
+                subscriptions.set(Some(result.clone()));
+            }
+        });
+
+        let save_future = use_resource(move || async move {
+            if !is_saving() {
+                return None;
+            }
+
+            match preferences.peek().clone() {
+                Some(Ok(payload)) => Some(update_notification_preferences(payload).await),
+                _ => None,
+            }
+        });
+
+        use_effect(move || {
+            if let Some(Some(result)) = save_future.read_unchecked().as_ref() {
+                match result {
+                    Ok(saved) => {
+                        preferences.set(Some(Ok(saved.clone())));
+                        save_result.set(Some(Ok(())));
+                    }
+                    Err(err) => save_result.set(Some(Err(err.clone()))),
+                }
+                is_saving.set(false);
+            }
+        });
+
+        let toggle_future = use_resource(move || async move {
+            match pending_toggle() {
+                Some((category_id, subscribed)) => Some((
+                    category_id,
+                    subscribed,
+                    set_category_subscription(guild_id, category_id, subscribed).await,
+                )),
+                None => None,
+            }
+        });
+
+        use_effect(move || {
+            if let Some(Some((category_id, subscribed, result))) =
+                toggle_future.read_unchecked().as_ref()
+            {
+                match result {
+                    Ok(()) => {
+                        if let Some(Ok(data)) = subscriptions.write().as_mut() {
+                            if let Some(category) = data
+                                .categories
+                                .iter_mut()
+                                .find(|c| c.category_id == *category_id)
+                            {
+                                category.subscribed = *subscribed;
+                            }
+                        }
+                    }
+                    Err(err) => toggle_error.set(Some(err.message.clone())),
+                }
+                pending_toggle.set(None);
+            }
+        });
+    }
+
+    let mut update_preferences = move |update: &dyn Fn(&mut NotificationPreferenceDto)| {
+        if let Some(Ok(prefs)) = preferences.write().as_mut() {
+            update(prefs);
+        }
+        save_result.set(None);
+    };
+
+    let reminder_valid = match preferences() {
+        Some(Ok(prefs)) => prefs
+            .reminder_minutes
+            .is_none_or(|minutes| (1..=MAX_REMINDER_MINUTES).contains(&minutes)),
+        _ => false,
+    };
+
+    rsx! {
+        Modal {
+            show,
+            title: "Notifications",
+            prevent_close: false,
+            div {
+                class: "space-y-6",
+
+                // Direct message preferences
+                div {
+                    class: "space-y-3",
+                    h3 { class: "font-semibold", "Direct Messages" }
+                    match preferences() {
+                        Some(Ok(prefs)) => rsx! {
+                            label {
+                                class: "flex items-center justify-between gap-4 cursor-pointer",
+                                span { "Receive fleet notifications by direct message" }
+                                input {
+                                    r#type: "checkbox",
+                                    class: "toggle toggle-primary",
+                                    checked: prefs.dm_enabled,
+                                    onchange: move |evt| {
+                                        let checked = evt.checked();
+                                        update_preferences(&|p| p.dm_enabled = checked);
+                                    },
+                                }
+                            }
+                            label {
+                                class: "flex items-center justify-between gap-4 cursor-pointer",
+                                span { "New fleets in subscribed categories" }
+                                input {
+                                    r#type: "checkbox",
+                                    class: "checkbox",
+                                    checked: prefs.notify_created,
+                                    disabled: !prefs.dm_enabled,
+                                    onchange: move |evt| {
+                                        let checked = evt.checked();
+                                        update_preferences(&|p| p.notify_created = checked);
+                                    },
+                                }
+                            }
+                            label {
+                                class: "flex items-center justify-between gap-4 cursor-pointer",
+                                span { "Fleet time changes" }
+                                input {
+                                    r#type: "checkbox",
+                                    class: "checkbox",
+                                    checked: prefs.notify_time_changed,
+                                    disabled: !prefs.dm_enabled,
+                                    onchange: move |evt| {
+                                        let checked = evt.checked();
+                                        update_preferences(&|p| p.notify_time_changed = checked);
+                                    },
+                                }
+                            }
+                            label {
+                                class: "flex items-center justify-between gap-4 cursor-pointer",
+                                span { "Fleet cancellations" }
+                                input {
+                                    r#type: "checkbox",
+                                    class: "checkbox",
+                                    checked: prefs.notify_cancelled,
+                                    disabled: !prefs.dm_enabled,
+                                    onchange: move |evt| {
+                                        let checked = evt.checked();
+                                        update_preferences(&|p| p.notify_cancelled = checked);
+                                    },
+                                }
+                            }
+                            div {
+                                class: "flex items-center justify-between gap-4",
+                                span { "Personal reminder (minutes before, empty to disable)" }
+                                input {
+                                    r#type: "number",
+                                    class: "input input-bordered input-sm w-24",
+                                    min: "1",
+                                    max: "{MAX_REMINDER_MINUTES}",
+                                    disabled: !prefs.dm_enabled,
+                                    value: prefs.reminder_minutes.map(|m| m.to_string()).unwrap_or_default(),
+                                    oninput: move |evt| {
+                                        let minutes = evt.value().trim().parse::<i32>().ok();
+                                        update_preferences(&|p| p.reminder_minutes = minutes);
+                                    },
+                                }
+                            }
+                            if !reminder_valid {
+                                p {
+                                    class: "text-xs text-error",
+                                    "Reminder must be between 1 and {MAX_REMINDER_MINUTES} minutes"
+                                }
+                            }
+                            div {
+                                class: "flex items-center justify-end gap-3",
+                                match save_result() {
+                                    Some(Ok(())) => rsx! {
+                                        span { class: "text-sm text-success", "Saved" }
+                                    },
+                                    Some(Err(err)) => rsx! {
+                                        span { class: "text-sm text-error", "{err.message}" }
+                                    },
+                                    None => rsx! {},
+                                }
+                                button {
+                                    r#type: "button",
+                                    class: "btn btn-primary btn-sm",
+                                    disabled: is_saving() || !reminder_valid,
+                                    onclick: move |_| {
+                                        save_result.set(None);
+                                        is_saving.set(true);
+                                    },
+                                    if is_saving() {
+                                        span { class: "loading loading-spinner loading-xs" }
+                                    } else {
+                                        "Save"
+                                    }
+                                }
+                            }
+                        },
+                        Some(Err(err)) => rsx! {
+                            p { class: "text-error", "Failed to load preferences: {err.message}" }
+                        },
+                        None => rsx! {
+                            span { class: "loading loading-spinner loading-md" }
+                        },
+                    }
+                }
+
+                // Category subscriptions
+                div {
+                    class: "space-y-3",
+                    h3 { class: "font-semibold", "Category Subscriptions" }
+                    p {
+                        class: "text-sm text-base-content/70",
+                        "Get a direct message for every fleet posted in the selected categories."
+                    }
+                    if let Some(error) = toggle_error() {
+                        p { class: "text-sm text-error", "{error}" }
+                    }
+                    match subscriptions() {
+                        Some(Ok(data)) => rsx! {
+                            if data.categories.is_empty() {
+                                p {
+                                    class: "text-base-content/70",
+                                    "No categories available."
+                                }
+                            } else {
+                                div {
+                                    class: "max-h-64 overflow-y-auto space-y-2",
+                                    for category in data.categories {
+                                        {
+                                            let category_id = category.category_id;
+                                            let subscribed = category.subscribed;
+                                            rsx! {
+                                                label {
+                                                    key: "{category_id}",
+                                                    class: "flex items-center justify-between gap-4 cursor-pointer",
+                                                    span { "{category.category_name}" }
+                                                    input {
+                                                        r#type: "checkbox",
+                                                        class: "toggle toggle-sm",
+                                                        checked: subscribed,
+                                                        disabled: pending_toggle().is_some(),
+                                                        onchange: move |_| {
+                                                            toggle_error.set(None);
+                                                            pending_toggle.set(Some((category_id, !subscribed)));
+                                                        },
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        },
+                        Some(Err(err)) => rsx! {
+                            p { class: "text-error", "Failed to load subscriptions: {err.message}" }
+                        },
+                        None => rsx! {
+                            span { class: "loading loading-spinner loading-md" }
+                        },
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,349 @@
+use chrono::{Duration, Utc};
+use dioxus::prelude::*;
+use dioxus_logger::tracing;
+
+use crate::{
+    client::{
+        component::{modal::ConfirmationModal, Modal},
+        model::error::ApiError,
+    },
+    model::stand_down::{CreateStandDownDto, StandDownOverviewDto},
+};
+
+#[cfg(feature = "web")]
+use crate::client::api::stand_down::{create_stand_down, get_stand_downs, resume_stand_down};
+
+/// Default length of a stand-down window starting now, in hours
+const DEFAULT_WINDOW_HOURS: i64 = 24;
+
+/// Button opening the stand-down modal, shown to users who can stand down any scope
+#[component]
+pub fn StandDownButton(guild_id: u64, refetch_trigger: Signal<u32>) -> Element {
+    let mut show = use_signal(|| false);
+    let mut overview = use_signal(|| None::<Result<StandDownOverviewDto, ApiError>>);
+
+    // Reload on guild change & whenever fleets were changed, as resuming restores them
+    #[cfg(feature = "web")]
+    {
+        let future = use_resource(use_reactive!(|guild_id| async move {
+            let _ = refetch_trigger();
+            get_stand_downs(guild_id).await
+        }));
+
+        use_effect(move || {
+            if let Some(result) = future.read_unchecked().as_ref() {
+                if let Err(err) = result {
+                    tracing::error!("Failed to fetch stand-downs: {}", err);
+                }
+                overview.set(Some(result.clone()));
+            }
+        });
+    }
+
+    let can_stand_down = match overview() {
+        Some(Ok(data)) => data.can_stand_down_guild || !data.ping_groups.is_empty(),
+        _ => false,
+    };
+
+    rsx! {
+        if can_stand_down {
+            button {
+                class: "btn btn-outline btn-error",
+                onclick: move |_| show.set(true),
+                "Stand Down"
+            }
+        }
+        if let Some(Ok(data)) = overview() {
+            StandDownModal {
+                guild_id,
+                overview: data,
+                show,
+                refetch_trigger
+            }
+        }
+    }
+}
+
+/// Modal for standing down the fleets of a scope & resuming recent stand-downs
+#[component]
+fn StandDownModal(
+    guild_id: u64,
+    overview: StandDownOverviewDto,
+    show: Signal<bool>,
+    mut refetch_trigger: Signal<u32>,
+) -> Element {
+    let default_end = (Utc::now() + Duration::hours(DEFAULT_WINDOW_HOURS))
+        .format("%Y-%m-%d %H:%M")
+        .to_string();
+
+    let mut ping_group_id = use_signal(|| None::<i32>);
+    let mut window_start = use_signal(|| "now".to_string());
+    let mut window_end = use_signal(move || default_end);
+    let mut reason = use_signal(String::new);
+    let mut show_confirm = use_signal(|| false);
+    let mut submit = use_signal(|| false);
+    let mut pending_resume = use_signal(|| None::<i32>);
+    let mut error = use_signal(|| None::<String>);
+    let mut success = use_signal(|| None::<String>);
+
+    // Default the scope to the first ping group when the guild can't be stood down
+    let default_group = overview.ping_groups.first().map(|group| group.id);
+    let can_stand_down_guild = overview.can_stand_down_guild;
+    use_effect(use_reactive!(|can_stand_down_guild, default_group| {
+        if !can_stand_down_guild && ping_group_id.peek().is_none() {
+            ping_group_id.set(default_group);
+        }
+    }));
+
+    #[cfg(feature = "web")]
+    {
+        let submit_future = use_resource(move || async move {
+            if !submit() {
+                return None;
+            }
+
+            let dto = CreateStandDownDto {
+                ping_group_id: *ping_group_id.peek(),
+                window_start: window_start.peek().clone(),
+                window_end: window_end.peek().clone(),
+                reason: reason.peek().clone(),
+            };
+            Some(create_stand_down(guild_id, dto).await)
+        });
+
+        use_effect(move || {
+            if let Some(Some(result)) = submit_future.read_unchecked().as_ref() {
+                match result {
+                    Ok(stand_down) => {
+                        tracing::info!("Stand-down {} called", stand_down.id);
+                        success.set(Some(format!(
+                            "Stood down {} fleet(s)",
+                            stand_down.fleet_count
+                        )));
+                        reason.set(String::new());
+                        refetch_trigger.set(refetch_trigger() + 1);
+                    }
+                    Err(err) => {
+                        tracing::error!("Failed to stand down fleets: {}", err);
+                        error.set(Some(err.message.clone()));
+                    }
+                }
+                show_confirm.set(false);
+                submit.set(false);
+            }
+        });
+
+        let resume_future = use_resource(move || async move {
+            let stand_down_id = pending_resume()?;
+            Some(resume_stand_down(guild_id, stand_down_id).await)
+        });
+
+        use_effect(move || {
+            if let Some(Some(result)) = resume_future.read_unchecked().as_ref() {
+                match result {
+                    Ok(stand_down) => {
+                        tracing::info!("Stand-down {} resumed", stand_down.id);
+                        success.set(Some("Stand-down lifted".to_string()));
+                        refetch_trigger.set(refetch_trigger() + 1);
+                    }
+                    Err(err) => {
+                        tracing::error!("Failed to resume stand-down: {}", err);
+                        error.set(Some(err.message.clone()));
+                    }
+                }
+                pending_resume.set(None);
+            }
+        });
+    }
+
+    let is_processing = submit() || pending_resume().is_some();
+    let can_submit = !is_processing
+        && !reason().trim().is_empty()
+        && (can_stand_down_guild || ping_group_id().is_some());
+    let scope_name = match ping_group_id() {
+        Some(id) => overview
+            .ping_groups
+            .iter()
+            .find(|group| group.id == id)
+            .map(|group| group.name.clone())
+            .unwrap_or_default(),
+        None => "the whole server".to_string(),
+    };
+
+    rsx! {
+        Modal {
+            show,
+            title: "Stand Down",
+            prevent_close: is_processing,
+            div {
+                class: "space-y-6",
+
+                div {
+                    class: "space-y-3",
+                    p {
+                        class: "text-sm text-base-content/70",
+                        "Cancels every fleet of the selected scope within the time window and posts the reason to every affected channel. Times are in UTC."
+                    }
+                    div {
+                        class: "form-control w-full",
+                        label { class: "label", span { class: "label-text", "Scope" } }
+                        select {
+                            class: "select select-bordered w-full",
+                            disabled: is_processing,
+                            onchange: move |evt| ping_group_id.set(evt.value().parse::<i32>().ok()),
+                            if can_stand_down_guild {
+                                option {
+                                    value: "",
+                                    selected: ping_group_id().is_none(),
+                                    "Whole server"
+                                }
+                            }
+                            for group in overview.ping_groups.clone() {
+                                option {
+                                    key: "{group.id}",
+                                    value: "{group.id}",
+                                    selected: ping_group_id() == Some(group.id),
+                                    "{group.name}"
+                                }
+                            }
+                        }
+                    }
+                    div {
+                        class: "grid grid-cols-1 sm:grid-cols-2 gap-3",
+                        div {
+                            class: "form-control w-full",
+                            label { class: "label", span { class: "label-text", "From (YYYY-MM-DD HH:MM or now)" } }
+                            input {
+                                r#type: "text",
+                                class: "input input-bordered w-full",
+                                value: "{window_start}",
+                                disabled: is_processing,
+                                oninput: move |evt| window_start.set(evt.value()),
+                            }
+                        }
+                        div {
+                            class: "form-control w-full",
+                            label { class: "label", span { class: "label-text", "Until (YYYY-MM-DD HH:MM)" } }
+                            input {
+                                r#type: "text",
+                                class: "input input-bordered w-full",
+                                value: "{window_end}",
+                                disabled: is_processing,
+                                oninput: move |evt| window_end.set(evt.value()),
+                            }
+                        }
+                    }
+                    div {
+                        class: "form-control w-full",
+                        label { class: "label", span { class: "label-text", "Reason" } }
+                        textarea {
+                            class: "textarea textarea-bordered h-24 w-full",
+                            placeholder: "Hostile capitals on grid, stand down until further notice",
+                            value: "{reason}",
+                            disabled: is_processing,
+                            oninput: move |evt| reason.set(evt.value()),
+                        }
+                    }
+                    if let Some(message) = error() {
+                        div { class: "alert alert-error", span { "{message}" } }
+                    }
+                    if let Some(message) = success() {
+                        div { class: "alert alert-success", span { "{message}" } }
+                    }
+                    div {
+                        class: "flex justify-end",
+                        button {
+                            r#type: "button",
+                            class: "btn btn-error",
+                            disabled: !can_submit,
+                            onclick: move |_| {
+                                error.set(None);
+                                success.set(None);
+                                show_confirm.set(true);
+                            },
+                            "Stand Down Fleets"
+                        }
+                    }
+                }
+
+                // Recent stand-downs
+                div {
+                    class: "space-y-3",
+                    h3 { class: "font-semibold", "Recent Stand-downs" }
+                    if overview.stand_downs.is_empty() {
+                        p { class: "text-base-content/70", "No stand-downs yet." }
+                    } else {
+                        div {
+                            class: "max-h-64 overflow-y-auto space-y-2",
+                            for stand_down in overview.stand_downs.clone() {
+                                {
+                                    let stand_down_id = stand_down.id;
+                                    let scope = stand_down
+                                        .ping_group_name
+                                        .clone()
+                                        .unwrap_or_else(|| "Whole server".to_string());
+                                    let window = format!(
+                                        "{} - {}",
+                                        stand_down.window_start.format("%Y-%m-%d %H:%M"),
+                                        stand_down.window_end.format("%Y-%m-%d %H:%M")
+                                    );
+                                    rsx! {
+                                        div {
+                                            key: "{stand_down_id}",
+                                            class: "flex items-center justify-between gap-4 p-2 rounded-box bg-base-200",
+                                            div {
+                                                class: "min-w-0",
+                                                p { class: "font-semibold truncate", "{stand_down.reason}" }
+                                                p {
+                                                    class: "text-xs text-base-content/70",
+                                                    "{scope} · {window} · {stand_down.fleet_count} fleet(s)"
+                                                }
+                                            }
+                                            if stand_down.resumed_at.is_some() {
+                                                span { class: "badge badge-ghost", "Lifted" }
+                                            } else {
+                                                button {
+                                                    r#type: "button",
+                                                    class: "btn btn-sm btn-outline",
+                                                    disabled: is_processing,
+                                                    onclick: move |_| {
+                                                        error.set(None);
+                                                        success.set(None);
+                                                        pending_resume.set(Some(stand_down_id));
+                                                    },
+                                                    if pending_resume() == Some(stand_down_id) {
+                                                        span { class: "loading loading-spinner loading-xs" }
+                                                    } else {
+                                                        "Resume"
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        ConfirmationModal {
+            show: show_confirm,
+            title: "Stand Down Fleets".to_string(),
+            message: rsx!(
+                p {
+                    class: "py-4",
+                    "Cancel every fleet of "
+                    span { class: "font-bold", "{scope_name}" }
+                    " between {window_start} and {window_end}? Resuming the stand-down restores the fleets still ahead."
+                }
+            ),
+            confirm_text: "Stand Down".to_string(),
+            confirm_class: "btn-error".to_string(),
+            is_processing: submit(),
+            processing_text: "Standing down...".to_string(),
+            on_confirm: move |_| submit.set(true),
+        }
+    }
+}
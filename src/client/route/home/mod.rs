@@ -1,5 +1,8 @@
 mod component;
 
+pub use component::FleetCountdown;
+
+use chrono::{DateTime, Utc};
 use dioxus::prelude::*;
 use dioxus_logger::tracing;
 use std::collections::{HashMap, HashSet};
@@ -10,12 +13,14 @@ use crate::{
         constant::SITE_NAME,
         model::error::ApiError,
         route::home::component::{
-            CategorySelectionModal, CreateFleetButton, FleetCreationModal, FleetTable,
+            BroadcastButton, CategorySelectionModal, CreateFleetButton, FleetCreationModal,
+            FleetTable, GuildFleetTable, NotificationSettingsModal, StandDownButton,
         },
     },
     model::{
         category::{FleetCategoryDetailsDto, FleetCategoryListItemDto},
         discord::{DiscordGuildDto, DiscordGuildMemberDto},
+        fleet_template::FleetTemplateDto,
     },
 };
 
@@ -35,6 +40,14 @@ pub struct GuildMembersCache {
     pub is_fetching: bool,
 }
 
+// Cache for the fleet templates a user can use per guild
+#[derive(Clone, Default)]
+pub struct FleetTemplatesCache {
+    pub guild_id: Option<u64>,
+    pub data: Option<Result<Vec<FleetTemplateDto>, ApiError>>,
+    pub is_fetching: bool,
+}
+
 // Cache for category details per category
 #[derive(Clone, Default)]
 pub struct CategoryDetailsCache {
@@ -49,11 +62,25 @@ use crate::client::api::user::get_user_guilds;
 pub fn Home() -> Element {
     let mut guilds = use_signal(|| None::<Result<Vec<DiscordGuildDto>, ApiError>>);
     let mut selected_guild_id = use_signal(|| None::<u64>);
+    // Merged timerboard of every server, replaces the selected server while enabled
+    let mut show_all_guilds = use_signal(|| false);
     let mut show_guild_dropdown = use_signal(|| false);
     let mut show_create_modal = use_signal(|| false);
     let mut show_fleet_creation = use_signal(|| false);
+    let mut show_notification_settings = use_signal(|| false);
     let mut selected_category_id = use_signal(|| None::<i32>);
+    // Template picked in the category selection modal to prefill the fleet with
+    let mut selected_template = use_signal(|| None::<FleetTemplateDto>);
     let mut refetch_trigger = use_signal(|| 0u32);
+    // Fleet time of the calendar slot fleet creation was started from
+    let mut prefill_fleet_time = use_signal(|| None::<DateTime<Utc>>);
+
+    // Forget the calendar slot once fleet creation is closed or finished
+    use_effect(move || {
+        if !show_create_modal() && !show_fleet_creation() {
+            prefill_fleet_time.set(None);
+        }
+    });
 
     // Provide caches for child components
     let _manageable_categories_cache =
@@ -61,6 +88,8 @@ pub fn Home() -> Element {
     let _guild_members_cache = use_context_provider(|| Signal::new(GuildMembersCache::default()));
     let _category_details_cache =
         use_context_provider(|| Signal::new(CategoryDetailsCache::default()));
+    let _fleet_templates_cache =
+        use_context_provider(|| Signal::new(FleetTemplatesCache::default()));
 
     // Fetch user's guilds on first load
     #[cfg(feature = "web")]
@@ -130,7 +159,35 @@ pub fn Home() -> Element {
                                 // Clickable guild header with dropdown
                                 div {
                                     class: "relative",
-                                    if let Some(guild) = selected_guild.clone() {
+                                    if show_all_guilds() {
+                                        button {
+                                            class: "flex items-center gap-3 hover:opacity-80 transition-opacity",
+                                            onclick: move |_| show_guild_dropdown.set(!show_guild_dropdown()),
+                                            div {
+                                                class: "w-10 h-10 rounded-full bg-base-300 flex items-center justify-center font-bold",
+                                                "*"
+                                            }
+                                            h1 {
+                                                class: "text-xl font-bold",
+                                                "All servers"
+                                            }
+                                            // Chevron icon
+                                            svg {
+                                                class: "w-5 h-5 transition-transform",
+                                                class: if show_guild_dropdown() { "rotate-180" },
+                                                xmlns: "http://www.w3.org/2000/svg",
+                                                fill: "none",
+                                                view_box: "0 0 24 24",
+                                                stroke: "currentColor",
+                                                path {
+                                                    stroke_linecap: "round",
+                                                    stroke_linejoin: "round",
+                                                    stroke_width: "2",
+                                                    d: "M19 9l-7 7-7-7"
+                                                }
+                                            }
+                                        }
+                                    } else if let Some(guild) = selected_guild.clone() {
                                         button {
                                             class: "flex items-center gap-3 hover:opacity-80 transition-opacity",
                                             onclick: move |_| show_guild_dropdown.set(!show_guild_dropdown()),
@@ -176,10 +233,31 @@ pub fn Home() -> Element {
                                                 class: "p-2",
                                                 div {
                                                     class: "max-h-96 overflow-y-auto",
+                                                    if guild_list.len() > 1 {
+                                                        button {
+                                                            class: "w-full flex items-center gap-3 p-3 rounded-box hover:bg-base-200 transition-colors",
+                                                            class: if show_all_guilds() { "bg-base-200" },
+                                                            onclick: move |_| {
+                                                                show_all_guilds.set(true);
+                                                                show_guild_dropdown.set(false);
+                                                            },
+                                                            div {
+                                                                class: "w-10 h-10 rounded-full bg-base-300 flex items-center justify-center font-bold",
+                                                                "*"
+                                                            }
+                                                            div {
+                                                                class: "flex-1 text-left",
+                                                                div {
+                                                                    class: "font-medium",
+                                                                    "All servers"
+                                                                }
+                                                            }
+                                                        }
+                                                    }
                                                     for guild in guild_list.clone() {
                                                         {
                                                             let guild_id = guild.guild_id;
-                                                            let is_selected = selected_guild_id() == Some(guild_id);
+                                                            let is_selected = !show_all_guilds() && selected_guild_id() == Some(guild_id);
                                                             rsx! {
                                                                 button {
                                                                     key: "{guild_id}",
@@ -187,6 +265,7 @@ pub fn Home() -> Element {
                                                                     class: if is_selected { "bg-base-200" },
                                                                     onclick: move |_| {
                                                                         selected_guild_id.set(Some(guild_id));
+                                                                        show_all_guilds.set(false);
                                                                         show_guild_dropdown.set(false);
                                                                     },
                                                                     if let Some(icon) = guild.icon_hash.as_ref() {
@@ -218,10 +297,26 @@ pub fn Home() -> Element {
                                     }
                                 }
 
-                                // Create Fleet Button
+                                // Notification settings, Stand Down & Create Fleet buttons
                                 div {
-                                    class: "w-full sm:w-auto",
-                                    if let Some(guild_id) = selected_guild_id() {
+                                    class: "flex w-full sm:w-auto gap-2",
+                                    if selected_guild_id().is_some() && !show_all_guilds() {
+                                        button {
+                                            class: "btn btn-outline",
+                                            onclick: move |_| show_notification_settings.set(true),
+                                            "Notifications"
+                                        }
+                                    }
+                                    if let Some(guild_id) = selected_guild_id().filter(|_| !show_all_guilds()) {
+                                        StandDownButton {
+                                            guild_id,
+                                            refetch_trigger
+                                        }
+                                    }
+                                    if let Some(guild_id) = selected_guild_id().filter(|_| !show_all_guilds()) {
+                                        BroadcastButton { guild_id }
+                                    }
+                                    if let Some(guild_id) = selected_guild_id().filter(|_| !show_all_guilds()) {
                                         CreateFleetButton {
                                             guild_id,
                                             show_create_modal
@@ -233,10 +328,19 @@ pub fn Home() -> Element {
 
                         // Fleet Timerboard
                         div {
-                            if let Some(guild_id) = selected_guild_id() {
+                            if show_all_guilds() {
+                                GuildFleetTable {
+                                    guilds: guild_list.clone(),
+                                    refetch_trigger
+                                }
+                            } else if let Some(guild_id) = selected_guild_id() {
                                 FleetTable {
                                     guild_id,
-                                    refetch_trigger
+                                    refetch_trigger,
+                                    on_slot_selected: move |fleet_time| {
+                                        prefill_fleet_time.set(Some(fleet_time));
+                                        show_create_modal.set(true);
+                                    }
                                 }
                             }
                         }
@@ -250,18 +354,35 @@ pub fn Home() -> Element {
                         show: show_create_modal,
                         on_category_selected: move |category_id| {
                             selected_category_id.set(Some(category_id));
+                            selected_template.set(None);
+                            show_create_modal.set(false);
+                            show_fleet_creation.set(true);
+                        },
+                        on_template_selected: move |template: FleetTemplateDto| {
+                            selected_category_id.set(Some(template.category_id));
+                            selected_template.set(Some(template));
                             show_create_modal.set(false);
                             show_fleet_creation.set(true);
                         }
                     }
                 }
 
+                // Notification Settings Modal
+                if let Some(guild_id) = selected_guild_id() {
+                    NotificationSettingsModal {
+                        guild_id,
+                        show: show_notification_settings
+                    }
+                }
+
                 // Fleet Creation Modal
                 if let Some(guild_id) = selected_guild_id() {
                     if let Some(category_id) = selected_category_id() {
                         FleetCreationModal {
                             guild_id,
                             category_id,
+                            fleet_time: prefill_fleet_time(),
+                            template: selected_template(),
                             show: show_fleet_creation,
                             on_success: move |_| {
                                 refetch_trigger.set(refetch_trigger() + 1);
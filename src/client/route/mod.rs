@@ -1,8 +1,10 @@
 pub mod admin;
+pub mod fleet;
 pub mod home;
 pub mod login;
 pub mod not_found;
 
+pub use fleet::FleetDetail;
 pub use home::Home;
 pub use login::Login;
 pub use not_found::NotFound;
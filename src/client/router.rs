@@ -4,12 +4,13 @@ use crate::client::component::{Layout, RequiresAdmin, RequiresLoggedIn};
 use crate::client::route::{
     admin::{
         server::{
-            ServerAdminFleetCategory, ServerAdminLayout, ServerAdminPingFormat,
-            ServerAdminPingGroup,
+            ServerAdminDoctrine, ServerAdminFleetCategory, ServerAdminLayout,
+            ServerAdminPingFormat, ServerAdminPingGroup, ServerAdminRelay, ServerAdminSettings,
+            ServerAdminWebhook,
         },
         AdminLayout, AdminServers, AdminUsers,
     },
-    Home, Login, NotFound,
+    FleetDetail, Home, Login, NotFound,
 };
 
 #[derive(Debug, Clone, Routable, PartialEq)]
@@ -23,6 +24,9 @@ pub enum Route {
     #[route("/")]
     Home {},
 
+    #[route("/guilds/:guild_id/fleets/:fleet_id")]
+    FleetDetail { guild_id: u64, fleet_id: i32 },
+
     #[end_layout]
 
     #[layout(RequiresAdmin)]
@@ -44,6 +48,18 @@ pub enum Route {
 
             #[route("/admin/server/:guild_id/ping-formats")]
             ServerAdminPingFormat { guild_id: u64 },
+
+            #[route("/admin/server/:guild_id/doctrines")]
+            ServerAdminDoctrine { guild_id: u64 },
+
+            #[route("/admin/server/:guild_id/webhooks")]
+            ServerAdminWebhook { guild_id: u64 },
+
+            #[route("/admin/server/:guild_id/relays")]
+            ServerAdminRelay { guild_id: u64 },
+
+            #[route("/admin/server/:guild_id/settings")]
+            ServerAdminSettings { guild_id: u64 },
         #[end_layout]
     #[end_nest]
     #[end_layout]
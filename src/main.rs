@@ -19,14 +19,26 @@ async fn main() {
 
     use dioxus_logger::tracing;
 
+    use clap::Parser;
+
     use crate::server::{
-        bot, config::Config, scheduler::fleet_notifications,
+        bot, cli::Cli, config::Config, scheduler::fleet_notifications,
         service::admin::code::AdminCodeService, startup, state::AppState,
     };
 
+    dotenvy::dotenv().ok();
+
+    // Administrative subcommands run to completion without starting the server
+    if let Some(command) = Cli::parse().command {
+        if let Err(e) = command.run().await {
+            eprintln!("Error: {}", e);
+            std::process::exit(1);
+        }
+        return;
+    }
+
     dioxus_logger::initialize_default();
 
-    dotenvy::dotenv().ok();
     let config = Config::from_env().expect("Failed to load configuration");
 
     let db = startup::connect_to_database(&config)
@@ -37,6 +49,7 @@ async fn main() {
         .expect("Failed to connect to session store");
     let http_client = startup::setup_reqwest_client();
     let oauth_client = startup::setup_oauth_client(&config);
+    startup::load_eve_map();
 
     // Create admin code service
     let admin_code_service = AdminCodeService::new();
@@ -65,10 +78,17 @@ async fn main() {
     let scheduler_db = db.clone();
     let scheduler_http = discord_http.clone();
     let scheduler_app_url = config.app_url.clone();
+    let scheduler_http_client = http_client.clone();
+    let scheduler_retention = config.retention;
     tokio::spawn(async move {
-        if let Err(e) =
-            fleet_notifications::start_scheduler(scheduler_db, scheduler_http, scheduler_app_url)
-                .await
+        if let Err(e) = fleet_notifications::start_scheduler(
+            scheduler_db,
+            scheduler_http,
+            scheduler_app_url,
+            scheduler_http_client,
+            scheduler_retention,
+        )
+        .await
         {
             tracing::error!("Fleet notification scheduler error: {}", e);
         }
@@ -83,7 +103,7 @@ async fn main() {
             oauth_client,
             admin_code_service,
             discord_http,
-            config.app_url.clone(),
+            &config,
         ))
         .layer(session);
     router = router.merge(server_routes);
@@ -0,0 +1,150 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "server")]
+use utoipa::ToSchema;
+
+/// Request to post an ad-hoc broadcast to chosen channels, now or at a later time
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "server", derive(ToSchema))]
+pub struct CreateBroadcastDto {
+    pub title: String,
+    pub message: String,
+    /// Channels to post the broadcast to
+    #[serde(
+        serialize_with = "serialize_u64_vec_as_strings",
+        deserialize_with = "deserialize_u64_vec_from_strings"
+    )]
+    pub channel_ids: Vec<u64>,
+    /// Roles pinged by the broadcast
+    #[serde(
+        default,
+        serialize_with = "serialize_u64_vec_as_strings",
+        deserialize_with = "deserialize_u64_vec_from_strings"
+    )]
+    pub role_ids: Vec<u64>,
+    /// Format: "YYYY-MM-DD HH:MM" in UTC, None posts the broadcast immediately
+    pub scheduled_at: Option<String>,
+}
+
+/// Channel a broadcast can be or was posted to
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "server", derive(ToSchema))]
+pub struct BroadcastChannelDto {
+    #[serde(
+        serialize_with = "serialize_u64_as_string",
+        deserialize_with = "deserialize_u64_from_string"
+    )]
+    pub channel_id: u64,
+    pub name: String,
+}
+
+/// Role a broadcast can ping or pinged
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "server", derive(ToSchema))]
+pub struct BroadcastRoleDto {
+    #[serde(
+        serialize_with = "serialize_u64_as_string",
+        deserialize_with = "deserialize_u64_from_string"
+    )]
+    pub role_id: u64,
+    pub name: String,
+    pub color: String,
+}
+
+/// Ad-hoc broadcast posted or scheduled to be posted to a guild's channels
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "server", derive(ToSchema))]
+pub struct BroadcastDto {
+    pub id: i32,
+    pub title: String,
+    pub message: String,
+    pub channels: Vec<BroadcastChannelDto>,
+    pub roles: Vec<BroadcastRoleDto>,
+    #[serde(
+        default,
+        serialize_with = "serialize_option_u64_as_string",
+        deserialize_with = "deserialize_option_u64_from_string"
+    )]
+    pub author_id: Option<u64>,
+    /// When the broadcast is due, None for broadcasts posted immediately
+    pub scheduled_at: Option<DateTime<Utc>>,
+    /// When the broadcast was posted, None while it is pending
+    pub sent_at: Option<DateTime<Utc>>,
+    /// When the scheduled broadcast was cancelled before being posted
+    pub cancelled_at: Option<DateTime<Utc>>,
+    /// Amount of channels the broadcast was posted to
+    pub posted_count: i32,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Channels & roles a user can broadcast to in a guild along with the guild's recent broadcasts
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "server", derive(ToSchema))]
+pub struct BroadcastOverviewDto {
+    /// Channels of the categories the user can broadcast to
+    pub channels: Vec<BroadcastChannelDto>,
+    /// Ping roles of the categories the user can broadcast to
+    pub roles: Vec<BroadcastRoleDto>,
+    /// Most recent broadcasts of the guild, newest first, empty if the user can't broadcast
+    pub broadcasts: Vec<BroadcastDto>,
+}
+
+fn serialize_u64_as_string<S>(value: &u64, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    serializer.serialize_str(&value.to_string())
+}
+
+fn deserialize_u64_from_string<'de, D>(deserializer: D) -> Result<u64, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    use serde::de::Error;
+    let s = String::deserialize(deserializer)?;
+    s.parse::<u64>().map_err(D::Error::custom)
+}
+
+fn serialize_option_u64_as_string<S>(value: &Option<u64>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    match value {
+        Some(value) => serializer.serialize_some(&value.to_string()),
+        None => serializer.serialize_none(),
+    }
+}
+
+fn deserialize_option_u64_from_string<'de, D>(deserializer: D) -> Result<Option<u64>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    use serde::de::Error;
+    Option::<String>::deserialize(deserializer)?
+        .map(|value| value.parse::<u64>().map_err(D::Error::custom))
+        .transpose()
+}
+
+fn serialize_u64_vec_as_strings<S>(values: &[u64], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    use serde::ser::SerializeSeq;
+    let mut seq = serializer.serialize_seq(Some(values.len()))?;
+    for value in values {
+        seq.serialize_element(&value.to_string())?;
+    }
+    seq.end()
+}
+
+fn deserialize_u64_vec_from_strings<'de, D>(deserializer: D) -> Result<Vec<u64>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    use serde::de::Error;
+    Vec::<String>::deserialize(deserializer)?
+        .into_iter()
+        .map(|value| value.parse::<u64>().map_err(D::Error::custom))
+        .collect()
+}
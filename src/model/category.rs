@@ -4,7 +4,10 @@ use serde::{Deserialize, Serialize};
 #[cfg(feature = "server")]
 use utoipa::ToSchema;
 
-use crate::model::ping_format::PingFormatFieldType;
+use crate::model::{
+    discord::DiscordChannelKind, guild_settings::DowntimeWindowDto,
+    ping_format::PingFormatFieldType,
+};
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[cfg_attr(feature = "server", derive(ToSchema))]
@@ -20,6 +23,9 @@ pub struct FleetCategoryAccessRoleDto {
     pub can_view: bool,
     pub can_create: bool,
     pub can_manage: bool,
+    /// Whether the role can send ad-hoc broadcasts to this category's channels
+    #[serde(default)]
+    pub can_broadcast: bool,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -45,6 +51,9 @@ pub struct FleetCategoryChannelDto {
     pub channel_id: u64,
     pub channel_name: String,
     pub position: i32,
+    pub kind: DiscordChannelKind,
+    /// Publish messages sent to an announcement channel to its following servers
+    pub crosspost: bool,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -63,6 +72,9 @@ pub struct FleetCategoryDto {
     pub ping_lead_time: Option<Duration>,
     pub ping_reminder: Option<Duration>,
     pub max_pre_ping: Option<Duration>,
+    /// Solar system name overriding the guild's staging system for this category
+    #[serde(default)]
+    pub staging_system: Option<String>,
     pub access_roles: Vec<FleetCategoryAccessRoleDto>,
     pub ping_roles: Vec<FleetCategoryPingRoleDto>,
     pub channels: Vec<FleetCategoryChannelDto>,
@@ -98,6 +110,9 @@ pub struct CreateFleetCategoryDto {
     pub ping_lead_time: Option<Duration>,
     pub ping_reminder: Option<Duration>,
     pub max_pre_ping: Option<Duration>,
+    /// Solar system name overriding the guild's staging system for this category
+    #[serde(default)]
+    pub staging_system: Option<String>,
     pub access_roles: Vec<FleetCategoryAccessRoleDto>,
     pub ping_roles: Vec<FleetCategoryPingRoleDto>,
     pub channels: Vec<FleetCategoryChannelDto>,
@@ -112,6 +127,9 @@ pub struct UpdateFleetCategoryDto {
     pub ping_lead_time: Option<Duration>,
     pub ping_reminder: Option<Duration>,
     pub max_pre_ping: Option<Duration>,
+    /// Solar system name overriding the guild's staging system for this category
+    #[serde(default)]
+    pub staging_system: Option<String>,
     pub access_roles: Vec<FleetCategoryAccessRoleDto>,
     pub ping_roles: Vec<FleetCategoryPingRoleDto>,
     pub channels: Vec<FleetCategoryChannelDto>,
@@ -159,6 +177,9 @@ pub struct FleetCategoryDetailsDto {
     pub ping_roles: Vec<FleetCategoryPingRoleDto>,
     pub channels: Vec<FleetCategoryChannelDto>,
     pub fields: Vec<PingFormatFieldDto>,
+    /// EVE downtime window of the guild, used to warn before scheduling into it
+    #[serde(default)]
+    pub downtime: DowntimeWindowDto,
 }
 
 fn serialize_u64_as_string<S>(value: &u64, serializer: S) -> Result<S::Ok, S::Error>
@@ -0,0 +1,59 @@
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "server")]
+use utoipa::ToSchema;
+
+/// Upcoming fleets list settings of a channel fleet categories post in
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(ToSchema))]
+pub struct ChannelListSettingsDto {
+    #[serde(
+        serialize_with = "serialize_u64_as_string",
+        deserialize_with = "deserialize_u64_from_string"
+    )]
+    pub channel_id: u64,
+    pub channel_name: String,
+    /// Only list fleets starting within this many hours, None uses the guild's lookahead
+    pub lookahead_hours: Option<i32>,
+    /// Most fleets listed, None lists every fleet within the lookahead
+    pub max_entries: Option<i32>,
+    /// Group fleets under a header per day in EVE time
+    pub group_by_day: bool,
+    /// List the fleets of each category in a separate section
+    pub split_by_category: bool,
+    /// List hidden fleets once their reminder revealed them in the channel
+    pub show_revealed_hidden: bool,
+}
+
+/// Configurable upcoming fleets list settings of a channel
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(ToSchema))]
+pub struct UpdateChannelListSettingsDto {
+    /// Only list fleets starting within this many hours, None uses the guild's lookahead
+    pub lookahead_hours: Option<i32>,
+    /// Most fleets listed, None lists every fleet within the lookahead
+    pub max_entries: Option<i32>,
+    /// Group fleets under a header per day in EVE time
+    pub group_by_day: bool,
+    /// List the fleets of each category in a separate section
+    pub split_by_category: bool,
+    /// List hidden fleets once their reminder revealed them in the channel
+    pub show_revealed_hidden: bool,
+}
+
+fn serialize_u64_as_string<S>(value: &u64, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    serializer.serialize_str(&value.to_string())
+}
+
+fn deserialize_u64_from_string<'de, D>(deserializer: D) -> Result<u64, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    use serde::de::Error;
+    String::deserialize(deserializer)?
+        .parse::<u64>()
+        .map_err(D::Error::custom)
+}
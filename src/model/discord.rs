@@ -1,3 +1,4 @@
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 #[cfg(feature = "server")]
@@ -13,6 +14,53 @@ pub struct DiscordGuildDto {
     pub guild_id: u64,
     pub name: String,
     pub icon_hash: Option<String>,
+    pub status: DiscordGuildStatus,
+    pub removed_at: Option<DateTime<Utc>>,
+}
+
+/// Whether the bot is a member of a Discord guild
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(ToSchema))]
+#[serde(rename_all = "lowercase")]
+pub enum DiscordGuildStatus {
+    /// Bot is in the guild and its timerboard is in use
+    #[default]
+    Active,
+    /// Bot was removed from the guild, its timerboard is hidden & notifications paused
+    Removed,
+    /// Bot was added back to a removed guild, awaiting an admin to restore or purge its data
+    Rejoined,
+}
+
+impl DiscordGuildStatus {
+    /// Short label describing the status to admins
+    pub fn label(&self) -> &'static str {
+        match self {
+            DiscordGuildStatus::Active => "Active",
+            DiscordGuildStatus::Removed => "Bot removed",
+            DiscordGuildStatus::Rejoined => "Awaiting restore",
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl DiscordGuildStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DiscordGuildStatus::Active => "active",
+            DiscordGuildStatus::Removed => "removed",
+            DiscordGuildStatus::Rejoined => "rejoined",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "active" => Some(DiscordGuildStatus::Active),
+            "removed" => Some(DiscordGuildStatus::Removed),
+            "rejoined" => Some(DiscordGuildStatus::Rejoined),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Clone)]
@@ -33,6 +81,51 @@ pub struct DiscordGuildRoleDto {
     pub position: i16,
 }
 
+/// Type of a Discord channel which can receive fleet notifications
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(ToSchema))]
+#[serde(rename_all = "lowercase")]
+pub enum DiscordChannelKind {
+    /// Regular text channel
+    #[default]
+    Text,
+    /// Announcement (news) channel whose messages can be crossposted to following servers
+    Announcement,
+    /// Forum channel where each fleet is posted as its own forum post
+    Forum,
+}
+
+impl DiscordChannelKind {
+    /// Short label shown next to the channel name, None for text channels
+    pub fn label(&self) -> Option<&'static str> {
+        match self {
+            DiscordChannelKind::Text => None,
+            DiscordChannelKind::Announcement => Some("Announcement"),
+            DiscordChannelKind::Forum => Some("Forum"),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl DiscordChannelKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DiscordChannelKind::Text => "text",
+            DiscordChannelKind::Announcement => "announcement",
+            DiscordChannelKind::Forum => "forum",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "text" => Some(DiscordChannelKind::Text),
+            "announcement" => Some(DiscordChannelKind::Announcement),
+            "forum" => Some(DiscordChannelKind::Forum),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Clone)]
 #[cfg_attr(feature = "server", derive(ToSchema))]
 pub struct DiscordGuildChannelDto {
@@ -48,6 +141,7 @@ pub struct DiscordGuildChannelDto {
     pub channel_id: u64,
     pub name: String,
     pub position: i32,
+    pub kind: DiscordChannelKind,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Clone)]
@@ -68,6 +162,70 @@ pub struct PaginatedDiscordGuildChannelsDto {
     pub entries: u64,
 }
 
+/// Permission the bot needs in a channel to send fleet notifications
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum ChannelPermission {
+    /// Required to see the channel at all
+    ViewChannel,
+    /// Required to post notifications
+    SendMessages,
+    /// Required for notifications to show their embed
+    EmbedLinks,
+    /// Required to ping @everyone, @here and roles which aren't mentionable
+    MentionEveryone,
+}
+
+impl ChannelPermission {
+    /// Name of the permission as shown in Discord's settings
+    pub fn label(&self) -> &'static str {
+        match self {
+            ChannelPermission::ViewChannel => "View Channel",
+            ChannelPermission::SendMessages => "Send Messages",
+            ChannelPermission::EmbedLinks => "Embed Links",
+            ChannelPermission::MentionEveryone => "Mention @everyone, @here, and All Roles",
+        }
+    }
+}
+
+/// Bot's effective permissions in a channel configured for fleet notifications
+#[derive(Serialize, Deserialize, PartialEq, Clone)]
+#[cfg_attr(feature = "server", derive(ToSchema))]
+pub struct ChannelDiagnosticsDto {
+    #[serde(
+        serialize_with = "serialize_u64_as_string",
+        deserialize_with = "deserialize_u64_from_string"
+    )]
+    pub channel_id: u64,
+    /// Permissions the bot lacks in the channel, empty when notifications can be sent
+    pub missing_permissions: Vec<ChannelPermission>,
+    /// Why the channel couldn't be checked, such as it having been deleted in Discord
+    pub error: Option<String>,
+}
+
+/// Whether the bot can ping a role configured as a ping role
+#[derive(Serialize, Deserialize, PartialEq, Clone)]
+#[cfg_attr(feature = "server", derive(ToSchema))]
+pub struct RoleDiagnosticsDto {
+    #[serde(
+        serialize_with = "serialize_u64_as_string",
+        deserialize_with = "deserialize_u64_from_string"
+    )]
+    pub role_id: u64,
+    /// Whether anyone can mention the role
+    pub mentionable: bool,
+    /// Whether the bot can mention roles which aren't mentionable
+    pub bot_can_mention_everyone: bool,
+}
+
+impl RoleDiagnosticsDto {
+    /// Whether pings will mention the role
+    pub fn can_ping(&self) -> bool {
+        self.mentionable || self.bot_can_mention_everyone
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Clone)]
 #[cfg_attr(feature = "server", derive(ToSchema))]
 pub struct DiscordGuildMemberDto {
@@ -0,0 +1,67 @@
+use serde::{Deserialize, Serialize};
+
+use crate::model::pagination::PageDto;
+
+/// Named set of ship fits flown together, referenced by doctrine ping format fields
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct DoctrineDto {
+    pub id: i32,
+    pub guild_id: u64,
+    pub name: String,
+    pub description: Option<String>,
+    pub fits: Vec<DoctrineFitDto>,
+}
+
+impl DoctrineDto {
+    /// Hulls of the doctrine's fits in order without duplicates, e.g. "Muninn, Scimitar"
+    pub fn hull_list(&self) -> String {
+        let mut hulls: Vec<&str> = Vec::new();
+        for fit in &self.fits {
+            if !hulls.contains(&fit.hull.as_str()) {
+                hulls.push(&fit.hull);
+            }
+        }
+
+        hulls.join(", ")
+    }
+
+    /// All fits in EFT format separated by blank lines, as pasted when editing
+    pub fn eft(&self) -> String {
+        self.fits
+            .iter()
+            .map(|fit| fit.eft.as_str())
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct DoctrineFitDto {
+    pub id: i32,
+    pub name: String,
+    pub hull: String,
+    /// The fit in EFT format for importing into the game client or Pyfa
+    pub eft: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct CreateDoctrineDto {
+    pub name: String,
+    pub description: Option<String>,
+    /// One or more fits in EFT format, each starting with a `[Hull, Fit name]` line
+    pub eft: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct UpdateDoctrineDto {
+    pub name: String,
+    pub description: Option<String>,
+    /// One or more fits in EFT format replacing the doctrine's current fits
+    pub eft: String,
+}
+
+pub type PaginatedDoctrinesDto = PageDto<DoctrineDto>;
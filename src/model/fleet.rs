@@ -2,6 +2,11 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+use crate::model::{
+    doctrine::DoctrineDto,
+    solar_system::{FleetRouteDto, SolarSystemDto},
+};
+
 #[cfg(feature = "server")]
 use utoipa::ToSchema;
 
@@ -41,6 +46,33 @@ pub struct UpdateFleetDto {
     pub disable_reminder: bool,
 }
 
+/// Copy of an existing fleet scheduled at a new time
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "server", derive(ToSchema))]
+pub struct CloneFleetDto {
+    pub fleet_time: String, // Format: "YYYY-MM-DD HH:MM" in UTC or "now"
+}
+
+/// Change applied to every fleet selected for a bulk operation
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "server", derive(ToSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum BulkFleetAction {
+    /// Moves the fleets by a number of minutes, negative values move them earlier
+    Shift { minutes: i64 },
+    /// Cancels & deletes the fleets
+    Cancel,
+    /// Moves the fleets to another category of the guild
+    Recategorize { category_id: i32 },
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "server", derive(ToSchema))]
+pub struct BulkFleetActionDto {
+    pub fleet_ids: Vec<i32>,
+    pub action: BulkFleetAction,
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
 #[cfg_attr(feature = "server", derive(ToSchema))]
 pub struct FleetDto {
@@ -58,10 +90,22 @@ pub struct FleetDto {
     pub fleet_time: DateTime<Utc>,
     pub description: Option<String>,
     pub field_values: HashMap<String, String>, // field_name -> value
+    /// Solar systems of solar system fields by field name, for linking & showing security
+    #[serde(default)]
+    pub solar_systems: HashMap<String, SolarSystemDto>,
+    /// Stargate route from the staging system to the fleet's form-up system
+    #[serde(default)]
+    pub route: Option<FleetRouteDto>,
+    /// Doctrines of doctrine fields by field name, for listing their fits
+    #[serde(default)]
+    pub doctrines: HashMap<String, DoctrineDto>,
     #[serde(with = "chrono::serde::ts_seconds")]
     pub created_at: DateTime<Utc>,
     pub hidden: bool,
     pub disable_reminder: bool,
+    /// Name of the server the fleet was relayed from, relayed fleets are read-only
+    #[serde(default)]
+    pub relayed_from: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
@@ -93,6 +137,101 @@ pub struct PaginatedFleetsDto {
     pub total_pages: u64,
 }
 
+/// Server a fleet of the aggregated timerboard belongs to
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "server", derive(ToSchema))]
+pub struct FleetGuildDto {
+    #[serde(
+        serialize_with = "serialize_u64_as_string",
+        deserialize_with = "deserialize_u64_from_string"
+    )]
+    pub guild_id: u64,
+    pub name: String,
+    pub icon_hash: Option<String>,
+}
+
+/// Fleet of the aggregated timerboard alongside the server it was scheduled in
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "server", derive(ToSchema))]
+pub struct GuildFleetListItemDto {
+    pub guild: FleetGuildDto,
+    pub fleet: FleetListItemDto,
+}
+
+/// Upcoming fleets of every server a user can view merged into one timerboard
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "server", derive(ToSchema))]
+pub struct PaginatedGuildFleetsDto {
+    pub fleets: Vec<GuildFleetListItemDto>,
+    pub total: u64,
+    pub page: u64,
+    pub per_page: u64,
+    pub total_pages: u64,
+}
+
+/// Fleet shown on the calendar & timeline views with the cooldown windows it blocks
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "server", derive(ToSchema))]
+pub struct FleetCalendarItemDto {
+    pub fleet: FleetListItemDto,
+    /// Seconds either side of the fleet time no other fleet of the category can be scheduled
+    pub category_cooldown: Option<i64>,
+    /// Name of the ping group sharing a cooldown with the category
+    pub ping_group_name: Option<String>,
+    /// Seconds either side of the fleet time no other fleet of the ping group can be scheduled
+    pub ping_group_cooldown: Option<i64>,
+}
+
+impl FleetCalendarItemDto {
+    /// Returns the longest cooldown blocking other fleets around this fleet, in seconds
+    pub fn cooldown(&self) -> Option<i64> {
+        self.category_cooldown.max(self.ping_group_cooldown)
+    }
+}
+
+/// Fleets of a guild within a time range for the calendar & timeline views
+///
+/// Includes fleets just outside the range whose cooldown window overlaps it.
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "server", derive(ToSchema))]
+pub struct FleetCalendarDto {
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub start: DateTime<Utc>,
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub end: DateTime<Utc>,
+    pub fleets: Vec<FleetCalendarItemDto>,
+}
+
+/// Live fleet change streamed to the timerboard over Server-Sent Events
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "server", derive(ToSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum FleetEventDto {
+    Created {
+        fleet_id: i32,
+    },
+    Updated {
+        fleet_id: i32,
+    },
+    Deleted {
+        fleet_id: i32,
+    },
+    /// Events were missed, the client should refetch its fleets
+    Resync,
+}
+
+impl FleetEventDto {
+    /// Returns the ID of the fleet the event is about, `None` for resyncs
+    pub fn fleet_id(&self) -> Option<i32> {
+        match self {
+            FleetEventDto::Created { fleet_id }
+            | FleetEventDto::Updated { fleet_id }
+            | FleetEventDto::Deleted { fleet_id } => Some(*fleet_id),
+            FleetEventDto::Resync => None,
+        }
+    }
+}
+
 fn serialize_u64_as_string<S>(value: &u64, serializer: S) -> Result<S::Ok, S::Error>
 where
     S: serde::Serializer,
@@ -0,0 +1,120 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "server")]
+use utoipa::ToSchema;
+
+/// Answer of the target guild's admins to a fleet relay request
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(ToSchema))]
+#[serde(rename_all = "lowercase")]
+pub enum FleetRelayStatus {
+    /// Waiting for the target guild to accept or decline
+    Pending,
+    /// Fleets of the source category are relayed to the target category
+    Accepted,
+    /// Target guild declined the relay, nothing is relayed
+    Declined,
+}
+
+impl FleetRelayStatus {
+    /// Short label describing the status to admins
+    pub fn label(&self) -> &'static str {
+        match self {
+            FleetRelayStatus::Pending => "Pending",
+            FleetRelayStatus::Accepted => "Accepted",
+            FleetRelayStatus::Declined => "Declined",
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl FleetRelayStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FleetRelayStatus::Pending => "pending",
+            FleetRelayStatus::Accepted => "accepted",
+            FleetRelayStatus::Declined => "declined",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "pending" => Some(FleetRelayStatus::Pending),
+            "accepted" => Some(FleetRelayStatus::Accepted),
+            "declined" => Some(FleetRelayStatus::Declined),
+            _ => None,
+        }
+    }
+}
+
+/// Relay link forwarding the fleets of a source guild's category to a target guild
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(ToSchema))]
+pub struct FleetRelayDto {
+    pub id: i32,
+    #[serde(
+        serialize_with = "serialize_u64_as_string",
+        deserialize_with = "deserialize_u64_from_string"
+    )]
+    pub source_guild_id: u64,
+    pub source_guild_name: String,
+    pub source_category_id: i32,
+    pub source_category_name: String,
+    #[serde(
+        serialize_with = "serialize_u64_as_string",
+        deserialize_with = "deserialize_u64_from_string"
+    )]
+    pub target_guild_id: u64,
+    pub target_guild_name: String,
+    /// Category of the target guild chosen when accepting, None until accepted
+    pub target_category_id: Option<i32>,
+    pub target_category_name: Option<String>,
+    pub status: FleetRelayStatus,
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub created_at: DateTime<Utc>,
+}
+
+/// Relays of a guild split by direction
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(ToSchema))]
+pub struct FleetRelaysDto {
+    /// Relays forwarding this guild's fleets to other guilds
+    pub outgoing: Vec<FleetRelayDto>,
+    /// Relays requested by other guilds to forward their fleets to this guild
+    pub incoming: Vec<FleetRelayDto>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(ToSchema))]
+pub struct CreateFleetRelayDto {
+    pub source_category_id: i32,
+    #[serde(
+        serialize_with = "serialize_u64_as_string",
+        deserialize_with = "deserialize_u64_from_string"
+    )]
+    pub target_guild_id: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(ToSchema))]
+pub struct AcceptFleetRelayDto {
+    pub target_category_id: i32,
+}
+
+fn serialize_u64_as_string<S>(value: &u64, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    serializer.serialize_str(&value.to_string())
+}
+
+fn deserialize_u64_from_string<'de, D>(deserializer: D) -> Result<u64, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    use serde::de::Error;
+    String::deserialize(deserializer)?
+        .parse::<u64>()
+        .map_err(D::Error::custom)
+}
@@ -0,0 +1,94 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[cfg(feature = "server")]
+use utoipa::ToSchema;
+
+/// Saved fleet prefilling the fleet creation form, everything of a fleet but its time
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "server", derive(ToSchema))]
+pub struct FleetTemplateDto {
+    pub id: i32,
+    pub category_id: i32,
+    /// Name the template is picked by
+    pub name: String,
+    /// Whether everyone able to create fleets in the category can use the template
+    pub shared: bool,
+    #[serde(
+        serialize_with = "serialize_u64_as_string",
+        deserialize_with = "deserialize_u64_from_string"
+    )]
+    pub owner_id: u64,
+    /// Whether the requesting user can delete the template
+    pub can_delete: bool,
+    pub fleet_name: String,
+    #[serde(
+        default,
+        serialize_with = "serialize_option_u64_as_string",
+        deserialize_with = "deserialize_option_u64_from_string"
+    )]
+    pub commander_id: Option<u64>,
+    pub description: Option<String>,
+    pub field_values: HashMap<i32, String>, // field_id -> value
+    pub hidden: bool,
+    pub disable_reminder: bool,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "server", derive(ToSchema))]
+pub struct CreateFleetTemplateDto {
+    pub category_id: i32,
+    pub name: String,
+    #[serde(default)]
+    pub shared: bool,
+    pub fleet_name: String,
+    #[serde(
+        default,
+        serialize_with = "serialize_option_u64_as_string",
+        deserialize_with = "deserialize_option_u64_from_string"
+    )]
+    pub commander_id: Option<u64>,
+    pub description: Option<String>,
+    pub field_values: HashMap<i32, String>, // field_id -> value
+    #[serde(default)]
+    pub hidden: bool,
+    #[serde(default)]
+    pub disable_reminder: bool,
+}
+
+fn serialize_u64_as_string<S>(value: &u64, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    serializer.serialize_str(&value.to_string())
+}
+
+fn deserialize_u64_from_string<'de, D>(deserializer: D) -> Result<u64, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    use serde::de::Error;
+    String::deserialize(deserializer)?
+        .parse::<u64>()
+        .map_err(D::Error::custom)
+}
+
+fn serialize_option_u64_as_string<S>(value: &Option<u64>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    match value {
+        Some(value) => serializer.serialize_some(&value.to_string()),
+        None => serializer.serialize_none(),
+    }
+}
+
+fn deserialize_option_u64_from_string<'de, D>(deserializer: D) -> Result<Option<u64>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    use serde::de::Error;
+    Option::<String>::deserialize(deserializer)?
+        .map(|value| value.parse::<u64>().map_err(D::Error::custom))
+        .transpose()
+}
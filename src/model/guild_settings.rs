@@ -0,0 +1,262 @@
+use chrono::{DateTime, Duration, Timelike, Utc};
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "server")]
+use utoipa::ToSchema;
+
+/// Display & behaviour settings of a guild used by its Discord notifications
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(ToSchema))]
+pub struct GuildSettingsDto {
+    /// Embed colour of fleet creation pings
+    pub creation_color: u32,
+    /// Embed colour of fleet messages edited after an update
+    pub update_color: u32,
+    /// Embed colour of fleet reminders
+    pub reminder_color: u32,
+    /// Embed colour of fleet form-up pings
+    pub formup_color: u32,
+    /// Embed colour of cancelled fleet messages
+    pub cancel_color: u32,
+    /// Embed colour of the upcoming fleets list
+    pub list_color: u32,
+    /// Label shown after the UTC start time of a fleet, e.g. "EVE Time"
+    pub time_label: String,
+    /// IANA timezone names the start time is also shown in, e.g. "America/New_York"
+    pub extra_timezones: Vec<String>,
+    /// Only list fleets starting within this many hours, None lists every upcoming fleet
+    pub list_lookahead_hours: Option<i32>,
+    /// Minutes after fleet time a missed form-up ping is still sent, e.g. after downtime
+    pub formup_catchup_minutes: i32,
+    /// Ping format preselected when creating a new category
+    pub default_ping_format_id: Option<i32>,
+    /// Solar system fleets are staged from, used to show jump routes to form-up systems
+    #[serde(default)]
+    pub staging_system: Option<String>,
+    /// Daily EVE downtime window & how fleets scheduled during it are handled
+    #[serde(default)]
+    pub downtime: DowntimeWindowDto,
+    /// Discord roles whose members can broadcast to every category of the guild
+    #[serde(
+        default,
+        serialize_with = "serialize_u64_vec_as_strings",
+        deserialize_with = "deserialize_u64_vec_from_strings"
+    )]
+    pub broadcast_role_ids: Vec<u64>,
+}
+
+impl Default for GuildSettingsDto {
+    fn default() -> Self {
+        Self {
+            creation_color: 0x3498db,
+            update_color: 0x3498db,
+            reminder_color: 0xf39c12,
+            formup_color: 0xe74c3c,
+            cancel_color: 0x95a5a6,
+            list_color: 0x5865f2,
+            time_label: "EVE Time".to_string(),
+            extra_timezones: Vec::new(),
+            list_lookahead_hours: None,
+            formup_catchup_minutes: 5,
+            default_ping_format_id: None,
+            staging_system: None,
+            downtime: DowntimeWindowDto::default(),
+            broadcast_role_ids: Vec::new(),
+        }
+    }
+}
+
+/// How fleets scheduled during EVE downtime are handled
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(ToSchema))]
+#[serde(rename_all = "lowercase")]
+pub enum DowntimePolicy {
+    /// Fleets may be scheduled during downtime without a warning
+    Ignore,
+    /// Fleet commanders are warned before scheduling a fleet during downtime
+    #[default]
+    Warn,
+    /// Fleets can't be scheduled during downtime
+    Block,
+}
+
+impl DowntimePolicy {
+    /// Short label describing the policy to admins
+    pub fn label(&self) -> &'static str {
+        match self {
+            DowntimePolicy::Ignore => "Ignore",
+            DowntimePolicy::Warn => "Warn",
+            DowntimePolicy::Block => "Block",
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl DowntimePolicy {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DowntimePolicy::Ignore => "ignore",
+            DowntimePolicy::Warn => "warn",
+            DowntimePolicy::Block => "block",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "ignore" => Some(DowntimePolicy::Ignore),
+            "warn" => Some(DowntimePolicy::Warn),
+            "block" => Some(DowntimePolicy::Block),
+            _ => None,
+        }
+    }
+}
+
+/// Daily EVE downtime window in UTC
+///
+/// Shared by the server, which enforces the policy & holds back form-up pings, and the
+/// fleet forms, which warn before a fleet is scheduled into the window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(ToSchema))]
+pub struct DowntimeWindowDto {
+    /// Minutes after midnight UTC the window starts, 660 for 11:00
+    pub start_minute: u32,
+    /// Length of the window in minutes
+    pub duration_minutes: u32,
+    pub policy: DowntimePolicy,
+}
+
+impl Default for DowntimeWindowDto {
+    fn default() -> Self {
+        Self {
+            start_minute: 11 * 60,
+            duration_minutes: 15,
+            policy: DowntimePolicy::Warn,
+        }
+    }
+}
+
+impl DowntimeWindowDto {
+    /// Seconds `time` lies after the start of the most recent window
+    fn seconds_since_start(&self, time: DateTime<Utc>) -> i64 {
+        let second_of_day = i64::from(time.num_seconds_from_midnight());
+        (second_of_day - i64::from(self.start_minute) * 60).rem_euclid(24 * 60 * 60)
+    }
+
+    /// Whether `time` falls within the window, windows may wrap around midnight
+    pub fn contains(&self, time: DateTime<Utc>) -> bool {
+        self.seconds_since_start(time) < i64::from(self.duration_minutes) * 60
+    }
+
+    /// End of the window `time` falls within
+    ///
+    /// # Returns
+    /// - `Some(DateTime<Utc>)` - When the window containing `time` ends
+    /// - `None` - `time` is outside of the window
+    #[cfg(feature = "server")]
+    pub fn end_of(&self, time: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        self.contains(time).then(|| {
+            time - Duration::seconds(self.seconds_since_start(time))
+                + Duration::minutes(i64::from(self.duration_minutes))
+        })
+    }
+
+    /// Whether a fleet at `time` should be flagged under the window's policy
+    pub fn flags(&self, time: DateTime<Utc>) -> bool {
+        self.policy != DowntimePolicy::Ignore && self.contains(time)
+    }
+
+    /// Window formatted for display, e.g. "11:00-11:15 UTC"
+    pub fn label(&self) -> String {
+        let end_minute = (self.start_minute + self.duration_minutes) % (24 * 60);
+        format!(
+            "{:02}:{:02}-{:02}:{:02} UTC",
+            self.start_minute / 60,
+            self.start_minute % 60,
+            end_minute / 60,
+            end_minute % 60
+        )
+    }
+}
+
+fn serialize_u64_vec_as_strings<S>(values: &[u64], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    use serde::ser::SerializeSeq;
+    let mut seq = serializer.serialize_seq(Some(values.len()))?;
+    for value in values {
+        seq.serialize_element(&value.to_string())?;
+    }
+    seq.end()
+}
+
+fn deserialize_u64_vec_from_strings<'de, D>(deserializer: D) -> Result<Vec<u64>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    use serde::de::Error;
+    Vec::<String>::deserialize(deserializer)?
+        .into_iter()
+        .map(|value| value.parse::<u64>().map_err(D::Error::custom))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(hour: u32, minute: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 1, 6, hour, minute, 0).unwrap()
+    }
+
+    /// Tests detecting fleet times within the default 11:00-11:15 UTC window.
+    ///
+    /// Expected: Start of the window is included, its end is not
+    #[test]
+    fn contains_times_within_window() {
+        let downtime = DowntimeWindowDto::default();
+
+        assert!(!downtime.contains(at(10, 59)));
+        assert!(downtime.contains(at(11, 0)));
+        assert!(downtime.contains(at(11, 14)));
+        assert!(!downtime.contains(at(11, 15)));
+        assert_eq!(downtime.label(), "11:00-11:15 UTC");
+    }
+
+    /// Tests a window wrapping around midnight UTC.
+    ///
+    /// Expected: Times on both sides of midnight are within the window and its end is
+    /// on the following day
+    #[test]
+    fn wraps_window_around_midnight() {
+        let downtime = DowntimeWindowDto {
+            start_minute: 23 * 60 + 50,
+            duration_minutes: 20,
+            policy: DowntimePolicy::Block,
+        };
+
+        assert!(downtime.contains(at(23, 55)));
+        assert!(downtime.contains(at(0, 5)));
+        assert!(!downtime.contains(at(0, 10)));
+        assert_eq!(
+            downtime.end_of(at(23, 55)),
+            Some(Utc.with_ymd_and_hms(2026, 1, 7, 0, 10, 0).unwrap())
+        );
+        assert_eq!(downtime.label(), "23:50-00:10 UTC");
+    }
+
+    /// Tests flagging fleet times depending on the downtime policy.
+    ///
+    /// Expected: Ignored downtime never flags, other policies flag times within the window
+    #[test]
+    fn flags_only_unless_ignored() {
+        let mut downtime = DowntimeWindowDto::default();
+        assert!(downtime.flags(at(11, 5)));
+        assert_eq!(downtime.end_of(at(11, 5)), Some(at(11, 15)));
+        assert_eq!(downtime.end_of(at(12, 0)), None);
+
+        downtime.policy = DowntimePolicy::Ignore;
+        assert!(!downtime.flags(at(11, 5)));
+    }
+}
@@ -0,0 +1,18 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Status of the application's dependencies returned by `/health` and `/ready`
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct HealthDto {
+    /// "ok" when the probed checks pass, otherwise "unavailable"
+    pub status: String,
+    /// Whether the database responded to a ping
+    pub database: bool,
+    /// Whether the Discord gateway connection is established
+    pub discord_gateway: bool,
+    /// Whether a scheduler job has run recently
+    pub scheduler: bool,
+    /// When a scheduler job last finished running
+    pub scheduler_last_tick: Option<DateTime<Utc>>,
+}
@@ -1,8 +1,25 @@
 pub mod api;
+pub mod broadcast;
 pub mod category;
+pub mod channel_list_settings;
 pub mod discord;
+pub mod doctrine;
 pub mod fleet;
+pub mod fleet_relay;
+pub mod fleet_template;
+pub mod guild_settings;
+// Only served by the backend for container & monitoring probes
+#[cfg(feature = "server")]
+pub mod health;
 pub mod pagination;
+pub mod paste;
 pub mod ping_format;
 pub mod ping_group;
+// Only served to admins for previewing the pruning job
+#[cfg(feature = "server")]
+pub mod retention;
+pub mod solar_system;
+pub mod stand_down;
+pub mod subscription;
 pub mod user;
+pub mod webhook;
@@ -0,0 +1,34 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "server")]
+use utoipa::ToSchema;
+
+use crate::model::fleet::CreateFleetDto;
+
+/// Text pasted into the fleet creation modal, e.g. a structure reinforcement
+/// notification copied from the game client or a ping from another Discord
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(ToSchema))]
+pub struct ParsePasteDto {
+    pub text: String,
+}
+
+/// Result of parsing a paste, returned for review in the fleet creation modal
+/// rather than creating the fleet right away
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(ToSchema))]
+pub struct ParsedPasteDto {
+    /// Fleet prefilled from the paste, `fleet_time` is empty when no time was found
+    pub fleet: CreateFleetDto,
+    /// Canonical name of the solar system found in the paste
+    pub solar_system: Option<String>,
+    /// Structure type & name found in the paste, e.g. `Astrahus "Staging Keep"`
+    pub structure: Option<String>,
+    /// When the reinforcement timer or pinged fleet ends or starts
+    #[serde(with = "chrono::serde::ts_seconds_option")]
+    pub timer_end: Option<DateTime<Utc>>,
+    /// Parts of the paste that looked meaningful but couldn't be used, e.g. an unknown
+    /// solar system given for a solar system field
+    pub warnings: Vec<String>,
+}
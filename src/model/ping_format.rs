@@ -8,6 +8,10 @@ use utoipa::ToSchema;
 pub enum PingFormatFieldType {
     Text,
     Bool,
+    /// Solar system of the bundled EVE static map, stored by its canonical name
+    SolarSystem,
+    /// Doctrine of the guild's doctrine library, stored by its ID
+    Doctrine,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+
+/// Active retention policy with the number of rows it would currently prune
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct RetentionPreviewDto {
+    /// Days after fleet time before fleets are archived, None never archives fleets
+    pub fleet_days: Option<u32>,
+    /// Hours after fleet time before fleet message records are deleted, None keeps them forever
+    pub fleet_message_hours: Option<u32>,
+    /// Days before finished webhook deliveries are deleted
+    pub webhook_delivery_days: u32,
+    /// Fleets which would be archived
+    pub fleets: u64,
+    /// Fleet message records which would be deleted
+    pub fleet_messages: u64,
+    /// Webhook deliveries which would be deleted
+    pub webhook_deliveries: u64,
+    /// Expired sessions which would be deleted
+    pub sessions: u64,
+}
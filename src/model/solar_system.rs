@@ -0,0 +1,55 @@
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "server")]
+use utoipa::ToSchema;
+
+/// Solar system of the bundled EVE static map
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(ToSchema))]
+pub struct SolarSystemDto {
+    pub id: u32,
+    pub name: String,
+    pub constellation: String,
+    pub region: String,
+    /// Security status rounded to one decimal the way the game client displays it
+    pub security: f64,
+    /// Link to the system on dotlan's EVE maps
+    pub dotlan_url: String,
+}
+
+impl SolarSystemDto {
+    /// Security status formatted for display, e.g. "0.5" or "-0.4"
+    pub fn security_label(&self) -> String {
+        format!("{:.1}", self.security)
+    }
+
+    /// Tailwind text color class for the security status, matching the in-game colors
+    /// for high, low & null security space
+    pub fn security_class(&self) -> &'static str {
+        if self.security >= 0.5 {
+            "text-success"
+        } else if self.security > 0.0 {
+            "text-warning"
+        } else {
+            "text-error"
+        }
+    }
+}
+
+/// Stargate route from a guild's staging system to a fleet's form-up system
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(ToSchema))]
+pub struct FleetRouteDto {
+    pub staging: SolarSystemDto,
+    pub destination: SolarSystemDto,
+    /// Number of stargate jumps, 0 when the fleet forms up in staging
+    pub jumps: usize,
+    /// Jumps into high security systems
+    pub high_sec_jumps: usize,
+    /// Jumps into low security systems
+    pub low_sec_jumps: usize,
+    /// Jumps into null security systems
+    pub null_sec_jumps: usize,
+    /// Link to the route on dotlan's EVE maps
+    pub dotlan_url: String,
+}
@@ -0,0 +1,81 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "server")]
+use utoipa::ToSchema;
+
+/// Request to cancel every fleet of a guild or ping group within a time window
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "server", derive(ToSchema))]
+pub struct CreateStandDownDto {
+    /// Ping group whose categories are stood down, None for every category of the guild
+    pub ping_group_id: Option<i32>,
+    pub window_start: String, // Format: "YYYY-MM-DD HH:MM" in UTC or "now"
+    pub window_end: String,   // Format: "YYYY-MM-DD HH:MM" in UTC
+    /// Reason broadcast to every affected channel
+    pub reason: String,
+}
+
+/// Stand-down which cancelled the fleets of a guild or ping group within a time window
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "server", derive(ToSchema))]
+pub struct StandDownDto {
+    pub id: i32,
+    /// Ping group whose categories were stood down, None for the whole guild
+    pub ping_group_id: Option<i32>,
+    pub ping_group_name: Option<String>,
+    pub reason: String,
+    pub window_start: DateTime<Utc>,
+    pub window_end: DateTime<Utc>,
+    #[serde(
+        default,
+        serialize_with = "serialize_option_u64_as_string",
+        deserialize_with = "deserialize_option_u64_from_string"
+    )]
+    pub created_by: Option<u64>,
+    pub created_at: DateTime<Utc>,
+    /// When the cancelled fleets were restored, None while the stand-down is active
+    pub resumed_at: Option<DateTime<Utc>>,
+    /// Amount of fleets the stand-down cancelled
+    pub fleet_count: usize,
+}
+
+/// Ping group a user can stand down
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "server", derive(ToSchema))]
+pub struct StandDownPingGroupDto {
+    pub id: i32,
+    pub name: String,
+}
+
+/// Scopes a user can stand down in a guild along with the guild's recent stand-downs
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "server", derive(ToSchema))]
+pub struct StandDownOverviewDto {
+    /// Whether the user can stand down every category of the guild
+    pub can_stand_down_guild: bool,
+    /// Ping groups the user can manage every category of
+    pub ping_groups: Vec<StandDownPingGroupDto>,
+    /// Most recent stand-downs of the guild, newest first
+    pub stand_downs: Vec<StandDownDto>,
+}
+
+fn serialize_option_u64_as_string<S>(value: &Option<u64>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    match value {
+        Some(value) => serializer.serialize_some(&value.to_string()),
+        None => serializer.serialize_none(),
+    }
+}
+
+fn deserialize_option_u64_from_string<'de, D>(deserializer: D) -> Result<Option<u64>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    use serde::de::Error;
+    Option::<String>::deserialize(deserializer)?
+        .map(|value| value.parse::<u64>().map_err(D::Error::custom))
+        .transpose()
+}
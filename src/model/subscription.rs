@@ -0,0 +1,51 @@
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "server")]
+use utoipa::ToSchema;
+
+/// Direct message notification preferences of the current user
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(ToSchema))]
+pub struct NotificationPreferenceDto {
+    /// Whether fleet notifications are sent by direct message at all
+    pub dm_enabled: bool,
+    /// Notify when a fleet is created in a subscribed category
+    pub notify_created: bool,
+    /// Notify when the time of a subscribed fleet changes
+    pub notify_time_changed: bool,
+    /// Notify when a subscribed fleet is cancelled
+    pub notify_cancelled: bool,
+    /// Minutes before fleet time to send a personal reminder, None to disable
+    pub reminder_minutes: Option<i32>,
+}
+
+impl Default for NotificationPreferenceDto {
+    fn default() -> Self {
+        Self {
+            dm_enabled: true,
+            notify_created: true,
+            notify_time_changed: true,
+            notify_cancelled: true,
+            reminder_minutes: None,
+        }
+    }
+}
+
+/// Fleet category the current user can view alongside whether they're subscribed to it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(ToSchema))]
+pub struct CategorySubscriptionDto {
+    pub category_id: i32,
+    pub category_name: String,
+    pub subscribed: bool,
+}
+
+/// Subscriptions of the current user within a guild
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(ToSchema))]
+pub struct SubscriptionsDto {
+    /// Every category of the guild the user can view, ordered by name
+    pub categories: Vec<CategorySubscriptionDto>,
+    /// IDs of the fleets the user is individually subscribed to
+    pub fleet_ids: Vec<i32>,
+}
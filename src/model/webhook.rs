@@ -0,0 +1,153 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "server")]
+use crate::model::fleet::FleetDto;
+use crate::model::pagination::PageDto;
+
+/// Fleet lifecycle events which can be delivered to outgoing webhooks
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub enum WebhookEvent {
+    #[serde(rename = "fleet.created")]
+    Created,
+    #[serde(rename = "fleet.updated")]
+    Updated,
+    #[serde(rename = "fleet.cancelled")]
+    Cancelled,
+    #[serde(rename = "fleet.reminder")]
+    Reminder,
+    #[serde(rename = "fleet.formup")]
+    Formup,
+}
+
+impl WebhookEvent {
+    /// All events a webhook can subscribe to, in display order
+    pub const ALL: [WebhookEvent; 5] = [
+        WebhookEvent::Created,
+        WebhookEvent::Updated,
+        WebhookEvent::Cancelled,
+        WebhookEvent::Reminder,
+        WebhookEvent::Formup,
+    ];
+
+    /// Returns the event name as sent in payloads & stored in the database
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WebhookEvent::Created => "fleet.created",
+            WebhookEvent::Updated => "fleet.updated",
+            WebhookEvent::Cancelled => "fleet.cancelled",
+            WebhookEvent::Reminder => "fleet.reminder",
+            WebhookEvent::Formup => "fleet.formup",
+        }
+    }
+
+    /// Parses an event from its name, returning `None` for unknown events
+    #[cfg(feature = "server")]
+    pub fn parse(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|event| event.as_str() == value)
+    }
+}
+
+/// Delivery state of a single webhook delivery attempt chain
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+#[serde(rename_all = "lowercase")]
+pub enum WebhookDeliveryStatus {
+    /// Waiting for the first attempt or a retry
+    Pending,
+    /// Endpoint responded with a 2xx status
+    Succeeded,
+    /// All retry attempts exhausted without a 2xx response
+    Failed,
+}
+
+#[cfg(feature = "server")]
+impl WebhookDeliveryStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WebhookDeliveryStatus::Pending => "pending",
+            WebhookDeliveryStatus::Succeeded => "succeeded",
+            WebhookDeliveryStatus::Failed => "failed",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "pending" => Some(WebhookDeliveryStatus::Pending),
+            "succeeded" => Some(WebhookDeliveryStatus::Succeeded),
+            "failed" => Some(WebhookDeliveryStatus::Failed),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct WebhookDto {
+    pub id: i32,
+    pub guild_id: u64,
+    pub name: String,
+    pub url: String,
+    /// Shared secret used to sign delivery payloads with HMAC-SHA256
+    pub secret: String,
+    pub events: Vec<WebhookEvent>,
+    pub enabled: bool,
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct CreateWebhookDto {
+    pub name: String,
+    pub url: String,
+    pub events: Vec<WebhookEvent>,
+    pub enabled: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct UpdateWebhookDto {
+    pub name: String,
+    pub url: String,
+    pub events: Vec<WebhookEvent>,
+    pub enabled: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct WebhookDeliveryDto {
+    pub id: i32,
+    pub webhook_id: i32,
+    pub event: WebhookEvent,
+    pub status: WebhookDeliveryStatus,
+    pub attempts: i32,
+    pub response_status: Option<i32>,
+    pub error: Option<String>,
+    #[serde(with = "chrono::serde::ts_seconds_option")]
+    pub next_attempt_at: Option<DateTime<Utc>>,
+    #[serde(with = "chrono::serde::ts_seconds_option")]
+    pub last_attempt_at: Option<DateTime<Utc>>,
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub created_at: DateTime<Utc>,
+}
+
+/// JSON body posted to webhook endpoints
+///
+/// The body is signed with the webhook's secret, the signature is sent in the
+/// `X-Timerboard-Signature` header as `sha256=<hex>` computed over `<timestamp>.<body>`
+/// where the timestamp is the value of the `X-Timerboard-Timestamp` header.
+#[cfg(feature = "server")]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct WebhookPayloadDto {
+    pub event: WebhookEvent,
+    pub guild_id: String,
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub occurred_at: DateTime<Utc>,
+    pub fleet: FleetDto,
+}
+
+pub type PaginatedWebhooksDto = PageDto<WebhookDto>;
+
+pub type PaginatedWebhookDeliveriesDto = PageDto<WebhookDeliveryDto>;
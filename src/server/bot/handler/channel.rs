@@ -1,26 +1,28 @@
 //! Channel event handlers for Discord guild channels.
 //!
-//! This module handles Discord events related to guild channels, specifically text
-//! channels that can be used for fleet notifications. The handlers keep the database
+//! This module handles Discord events related to guild channels, specifically the text,
+//! announcement and forum channels that can be used for fleet notifications. The handlers keep the database
 //! synchronized with Discord's channel state to enable:
 //! - Channel selection in notification configuration UI
 //! - Validation that configured channels still exist
 //! - Proper cleanup when channels are deleted
 //!
-//! Only text channels are tracked, as they are the only channel type that can receive
-//! fleet notification messages and embeds.
+//! Only text, announcement and forum channels are tracked, as they are the only channel
+//! types that fleet notification messages and embeds can be posted to.
 
 use dioxus_logger::tracing;
 use sea_orm::DatabaseConnection;
-use serenity::all::{ChannelType, Context, GuildChannel, Message};
+use serenity::all::{Context, GuildChannel, Message};
 
-use crate::server::data::discord::DiscordGuildChannelRepository;
+use crate::server::{
+    data::discord::DiscordGuildChannelRepository, model::discord::channel::channel_kind,
+};
 
 /// Handles the channel_create event when a channel is created in a guild.
 ///
-/// Adds the channel to the database if it's a text channel. This makes the channel
-/// available for selection in the fleet notification configuration UI. Non-text
-/// channels (voice, announcement, etc.) are ignored as they cannot receive messages.
+/// Adds the channel to the database if it's a text, announcement or forum channel. This
+/// makes the channel available for selection in the fleet notification configuration UI.
+/// Other channels (voice, stage, etc.) are ignored as fleets cannot be posted to them.
 ///
 /// # Arguments
 /// - `db` - Database connection for creating the channel record
@@ -30,10 +32,10 @@ pub async fn handle_channel_create(db: &DatabaseConnection, _ctx: Context, chann
     let guild_id = channel.guild_id.get();
     let channel_repo = DiscordGuildChannelRepository::new(db);
 
-    // Only track text channels
-    if channel.kind != ChannelType::Text {
+    // Only track channels which can receive fleet notifications
+    if channel_kind(channel.kind).is_none() {
         tracing::debug!(
-            "Ignoring non-text channel {} (type: {:?}) in guild {}",
+            "Ignoring unsupported channel {} (type: {:?}) in guild {}",
             channel.name,
             channel.kind,
             guild_id
@@ -55,9 +57,9 @@ pub async fn handle_channel_create(db: &DatabaseConnection, _ctx: Context, chann
 
 /// Handles the channel_update event when a channel is updated in a guild.
 ///
-/// Updates the channel's information (name, permissions, etc.) in the database if
-/// it's a text channel. This ensures the UI displays current channel names and that
-/// notification configurations remain valid. Non-text channels are ignored.
+/// Updates the channel's information (name, position, type) in the database if it's a
+/// text, announcement or forum channel. This ensures the UI displays current channel names
+/// and that notification configurations remain valid. Other channels are ignored.
 ///
 /// # Arguments
 /// - `db` - Database connection for updating the channel record
@@ -74,10 +76,10 @@ pub async fn handle_channel_update(
     let guild_id = channel.guild_id.get();
     let channel_repo = DiscordGuildChannelRepository::new(db);
 
-    // Only track text channels
-    if channel.kind != ChannelType::Text {
+    // Only track channels which can receive fleet notifications
+    if channel_kind(channel.kind).is_none() {
         tracing::debug!(
-            "Ignoring non-text channel {} (type: {:?}) in guild {}",
+            "Ignoring unsupported channel {} (type: {:?}) in guild {}",
             channel.name,
             channel.kind,
             guild_id
@@ -1,7 +1,9 @@
 //! Guild event handlers for Discord guild synchronization.
 //!
 //! This module handles the `guild_create` event which fires when a guild becomes
-//! available to the bot. This occurs in several scenarios:
+//! available to the bot, and the `guild_delete` event which fires when the bot is
+//! removed from a guild or a guild becomes unavailable. `guild_create` occurs in
+//! several scenarios:
 //! - On bot startup for each guild the bot is already in
 //! - When the bot joins a new guild
 //! - When a guild becomes available after a Discord outage
@@ -17,9 +19,10 @@
 //! is enforced. Full synchronization only occurs if 30+ minutes have passed since the
 //! last sync. Guild metadata (name, icon) is always updated regardless of the backoff.
 
+use chrono::Utc;
 use dioxus_logger::tracing;
 use sea_orm::DatabaseConnection;
-use serenity::all::{Context, Guild, GuildChannel, Role};
+use serenity::all::{Context, Guild, GuildChannel, Role, UnavailableGuild};
 
 use crate::server::{
     data::discord::DiscordGuildRepository,
@@ -94,9 +97,26 @@ pub async fn handle_guild_create(
         return;
     }
 
+    // A guild the bot was removed from stays hidden until an admin restores or purges it,
+    // its Discord data is synced immediately as it wasn't kept up to date while removed
+    let rejoined = match guild_repo.mark_rejoined(guild_id).await {
+        Ok(rejoined) => rejoined,
+        Err(e) => {
+            tracing::error!("Failed to mark guild {} as rejoined: {:?}", guild_id, e);
+            return;
+        }
+    };
+    if rejoined {
+        tracing::info!(
+            "Bot was added back to removed guild {} ({}), awaiting admin restore",
+            guild_id,
+            guild_name
+        );
+    }
+
     // Check if a full sync is needed (30-minute backoff)
     let needs_sync = match guild_repo.needs_sync(guild_id).await {
-        Ok(needs) => needs,
+        Ok(needs) => rejoined || needs,
         Err(e) => {
             tracing::error!("Failed to check if guild {} needs sync: {:?}", guild_id, e);
             return;
@@ -153,3 +173,41 @@ pub async fn handle_guild_create(
         tracing::info!("Successfully completed full sync for guild {}", guild_id);
     }
 }
+
+/// Handles the guild_delete event when the bot is removed from a guild or it becomes unavailable.
+///
+/// Guilds which only became unavailable due to a Discord outage are left unchanged, they
+/// become available again with a `guild_create` event. Otherwise the bot was kicked or
+/// the guild was deleted, so the guild is marked as removed: its timerboard is hidden from
+/// users & its notifications are paused while its data is kept for an admin to restore
+/// or purge.
+///
+/// # Arguments
+/// - `db` - Database connection for updating the guild status
+/// - `_ctx` - Discord context (unused, required by event handler signature)
+/// - `incomplete` - ID of the guild & whether it is only unavailable
+/// - `_full` - Cached guild data (unused, required by event handler signature)
+pub async fn handle_guild_delete(
+    db: &DatabaseConnection,
+    _ctx: Context,
+    incomplete: UnavailableGuild,
+    _full: Option<Guild>,
+) {
+    let guild_id = incomplete.id.get();
+
+    if incomplete.unavailable {
+        tracing::warn!("Guild {} became unavailable due to an outage", guild_id);
+        return;
+    }
+
+    let guild_repo = DiscordGuildRepository::new(db);
+
+    match guild_repo.mark_removed(guild_id, Utc::now()).await {
+        Ok(true) => tracing::info!(
+            "Bot was removed from guild {}, pausing its notifications",
+            guild_id
+        ),
+        Ok(false) => tracing::debug!("Bot was removed from unknown guild {}", guild_id),
+        Err(e) => tracing::error!("Failed to mark guild {} as removed: {:?}", guild_id, e),
+    }
+}
@@ -0,0 +1,84 @@
+//! Interaction event handlers for message components.
+//!
+//! This module handles Discord interactions created when users click buttons on
+//! messages sent by the bot. Currently the only component is the opt-out button
+//! attached to fleet notification direct messages, which removes the subscription
+//! the direct message was sent for.
+//!
+//! Interactions of unknown components are ignored, leaving Discord to show its
+//! default "interaction failed" notice.
+
+use dioxus_logger::tracing;
+use sea_orm::DatabaseConnection;
+use serenity::all::{
+    Context, CreateInteractionResponse, CreateInteractionResponseMessage, Interaction,
+};
+
+use crate::server::{
+    metrics::discord_call, model::subscription::SubscriptionTarget,
+    service::subscription::SubscriptionService,
+};
+
+/// Handles an interaction with a message component.
+///
+/// Unsubscribes the user who clicked a direct message opt-out button from the
+/// category or fleet the direct message was sent for and replies with the outcome.
+/// Unsubscribing when already unsubscribed succeeds so repeated clicks are harmless.
+///
+/// # Arguments
+/// - `db` - Database connection for removing the subscription
+/// - `ctx` - Discord context for responding to the interaction
+/// - `interaction` - The interaction received from Discord
+pub async fn handle_interaction_create(
+    db: &DatabaseConnection,
+    ctx: Context,
+    interaction: Interaction,
+) {
+    let Interaction::Component(component) = interaction else {
+        return;
+    };
+
+    let Some(target) = SubscriptionTarget::parse_unsubscribe_custom_id(&component.data.custom_id)
+    else {
+        tracing::debug!(
+            "Ignoring interaction with unknown component {}",
+            component.data.custom_id
+        );
+        return;
+    };
+
+    let user_id = component.user.id.get();
+    let content = match SubscriptionService::new(db)
+        .unsubscribe(user_id, target)
+        .await
+    {
+        Ok(_) => match target {
+            SubscriptionTarget::Category(_) => {
+                "You won't receive direct messages for fleets in this category anymore. You can subscribe again on the timerboard."
+            }
+            SubscriptionTarget::Fleet(_) => {
+                "You won't receive direct messages for this fleet anymore. You can subscribe again on the timerboard."
+            }
+        },
+        Err(e) => {
+            tracing::error!("Failed to unsubscribe user {} from {:?}: {}", user_id, target, e);
+            "Failed to unsubscribe, please try again or unsubscribe on the timerboard."
+        }
+    };
+
+    let response = CreateInteractionResponse::Message(
+        CreateInteractionResponseMessage::new().content(content),
+    );
+    if let Err(e) = discord_call(
+        "create_interaction_response",
+        component.create_response(&ctx.http, response),
+    )
+    .await
+    {
+        tracing::error!(
+            "Failed to respond to unsubscribe interaction of user {}: {}",
+            user_id,
+            e
+        );
+    }
+}
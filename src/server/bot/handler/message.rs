@@ -81,9 +81,14 @@ pub async fn handle_message(db: &DatabaseConnection, _ctx: Context, message: Mes
         }
     };
 
-    // Don't track the fleet list message itself
+    // Don't track the fleet list messages themselves
     // We want to track OTHER messages (including bot's fleet notifications) that bury the list
-    if message_id == existing.message_id {
+    if message_id == existing.message_id
+        || existing
+            .overflow_message_ids
+            .split(',')
+            .any(|id| id == message_id)
+    {
         tracing::debug!(
             "Ignoring fleet list message {} itself in channel {}",
             message_id,
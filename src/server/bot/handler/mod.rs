@@ -11,11 +11,12 @@
 //! # Event Categories
 //!
 //! - **Ready** (`ready`) - Bot connection and initialization events
-//! - **Guild** (`guild`) - Guild availability, joins, and full synchronization
+//! - **Guild** (`guild`) - Guild availability, joins, removals, and full synchronization
 //! - **Role** (`role`) - Role creation, updates, and deletion within guilds
 //! - **Channel** (`channel`) - Channel creation, updates, and deletion within guilds
 //! - **Member** (`member`) - Member joins, leaves, and updates (roles, nicknames)
 //! - **Message** (`message`) - Message creation for tracking fleet list visibility
+//! - **Interaction** (`interaction`) - Opt-out buttons on fleet notification direct messages
 //!
 //! # Synchronization Strategy
 //!
@@ -36,13 +37,14 @@
 
 use sea_orm::DatabaseConnection;
 use serenity::all::{
-    Context, EventHandler, Guild, GuildChannel, GuildId, GuildMemberUpdateEvent, Member, Message,
-    Ready, Role, RoleId, User,
+    Context, EventHandler, Guild, GuildChannel, GuildId, GuildMemberUpdateEvent, Interaction,
+    Member, Message, Ready, Role, RoleId, ShardStageUpdateEvent, UnavailableGuild, User,
 };
 use serenity::async_trait;
 
 pub mod channel;
 pub mod guild;
+pub mod interaction;
 pub mod member;
 pub mod message;
 pub mod ready;
@@ -83,6 +85,10 @@ impl EventHandler for Handler {
         ready::handle_ready(ctx, ready).await;
     }
 
+    async fn shard_stage_update(&self, ctx: Context, event: ShardStageUpdateEvent) {
+        ready::handle_shard_stage_update(ctx, event).await;
+    }
+
     /// Called when a guild becomes available or the bot joins a new guild.
     ///
     /// This event fires:
@@ -96,6 +102,14 @@ impl EventHandler for Handler {
         guild::handle_guild_create(&self.db, ctx, guild, is_new).await;
     }
 
+    /// Called when the bot is removed from a guild or a guild becomes unavailable.
+    ///
+    /// Marks the guild as removed when the bot was kicked or the guild deleted, hiding
+    /// its timerboard and pausing its notifications. Outages are ignored.
+    async fn guild_delete(&self, ctx: Context, incomplete: UnavailableGuild, full: Option<Guild>) {
+        guild::handle_guild_delete(&self.db, ctx, incomplete, full).await;
+    }
+
     /// Called when a role is created in a guild.
     ///
     /// Creates or updates the role in the database to keep role information
@@ -212,4 +226,12 @@ impl EventHandler for Handler {
     async fn message(&self, ctx: Context, message: Message) {
         message::handle_message(&self.db, ctx, message).await;
     }
+
+    /// Called when a user interacts with a message component sent by the bot.
+    ///
+    /// Handles the opt-out buttons of fleet notification direct messages by removing
+    /// the subscription the message was sent for.
+    async fn interaction_create(&self, ctx: Context, interaction: Interaction) {
+        interaction::handle_interaction_create(&self.db, ctx, interaction).await;
+    }
 }
@@ -8,9 +8,12 @@
 //! The ready handler is used to:
 //! - Log connection information
 //! - Perform any one-time initialization tasks
+//! - Track the gateway connection state for the readiness probe
 
 use dioxus_logger::tracing;
-use serenity::all::{Context, Ready};
+use serenity::all::{ConnectionStage, Context, Ready, ShardStageUpdateEvent};
+
+use crate::server::metrics::metrics;
 
 /// Handles the ready event when the bot connects to Discord.
 ///
@@ -23,4 +26,23 @@ use serenity::all::{Context, Ready};
 /// - `ready` - Ready event data containing bot user information
 pub async fn handle_ready(_ctx: Context, ready: Ready) {
     tracing::info!("{} is connected to Discord", ready.user.name);
+    metrics().set_gateway_connected(true);
+}
+
+/// Handles shard connection stage changes such as disconnects and reconnects.
+///
+/// Marks the gateway as connected only while the shard is in the connected stage so
+/// the readiness probe fails while the bot is disconnected or resuming.
+///
+/// # Arguments
+/// - `_ctx` - Discord context
+/// - `event` - Stage update containing the previous and new connection stage
+pub async fn handle_shard_stage_update(_ctx: Context, event: ShardStageUpdateEvent) {
+    tracing::debug!(
+        "Shard {} connection stage changed from {} to {}",
+        event.shard_id,
+        event.old,
+        event.new
+    );
+    metrics().set_gateway_connected(event.new == ConnectionStage::Connected);
 }
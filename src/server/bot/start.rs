@@ -20,6 +20,7 @@ use std::sync::Arc;
 
 use crate::server::config::Config;
 use crate::server::error::AppError;
+use crate::server::metrics::metrics;
 
 use super::handler::Handler;
 
@@ -95,6 +96,7 @@ pub async fn start_bot(mut client: Client) -> Result<(), AppError> {
     client.start().await?;
 
     tracing::warn!("Discord bot connection closed");
+    metrics().set_gateway_connected(false);
 
     Ok(())
 }
@@ -0,0 +1,100 @@
+//! `admin` command for managing admin users without the one-time login code.
+
+use clap::Subcommand;
+use sea_orm::DatabaseConnection;
+
+use crate::server::{
+    data::user::UserRepository,
+    error::AppError,
+    model::user::{SetAdminParam, UpsertUserParam},
+    service::user::UserService,
+};
+
+/// Admin user management actions.
+#[derive(Subcommand, Debug)]
+pub enum AdminCommand {
+    /// Grant admin privileges to a Discord user
+    Grant {
+        /// Discord ID of the user
+        discord_id: u64,
+    },
+    /// Revoke admin privileges from a Discord user
+    Revoke {
+        /// Discord ID of the user
+        discord_id: u64,
+    },
+    /// List all admin users
+    List,
+}
+
+impl AdminCommand {
+    /// Runs the admin management action.
+    ///
+    /// Granting admin to a user who has never logged in creates the user with their
+    /// Discord ID as a placeholder name, the name is replaced on their first login while
+    /// the admin status is preserved.
+    ///
+    /// # Arguments
+    /// - `db` - Database connection
+    ///
+    /// # Returns
+    /// - `Ok(())` - Admin status updated or admins listed successfully
+    /// - `Err(AppError::NotFound)` - Revoking admin from a user that doesn't exist
+    /// - `Err(AppError::Database)` - Database error during query or update
+    pub async fn run(self, db: &DatabaseConnection) -> Result<(), AppError> {
+        let user_service = UserService::new(db);
+
+        match self {
+            Self::Grant { discord_id } => {
+                let user_repo = UserRepository::new(db);
+                let name = match user_repo.find_by_id(discord_id).await? {
+                    Some(user) => {
+                        user_service
+                            .add_admin(SetAdminParam {
+                                discord_id,
+                                is_admin: true,
+                            })
+                            .await?;
+                        user.name
+                    }
+                    None => {
+                        user_repo
+                            .upsert(UpsertUserParam {
+                                discord_id,
+                                name: discord_id.to_string(),
+                                is_admin: Some(true),
+                            })
+                            .await?
+                            .name
+                    }
+                };
+
+                println!("Granted admin to {} ({})", name, discord_id);
+            }
+            Self::Revoke { discord_id } => {
+                user_service
+                    .remove_admin(SetAdminParam {
+                        discord_id,
+                        is_admin: false,
+                    })
+                    .await?;
+
+                println!("Revoked admin from {}", discord_id);
+                if user_service.get_all_admins().await?.is_empty() {
+                    println!("No admin users remain, a login code will be generated on startup");
+                }
+            }
+            Self::List => {
+                let admins = user_service.get_all_admins().await?;
+                if admins.is_empty() {
+                    println!("No admin users");
+                }
+                for admin in admins {
+                    println!("{:<20} {}", admin.discord_id, admin.name);
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,97 @@
+//! `config` command for validating the environment configuration.
+
+use clap::Subcommand;
+use migration::{Migrator, MigratorTrait};
+
+use crate::server::{
+    config::{Config, REQUIRED_ENV_VARS},
+    error::AppError,
+    startup,
+};
+
+/// Configuration actions.
+#[derive(Subcommand, Debug)]
+pub enum ConfigCommand {
+    /// Validate the environment variables & database connection without starting the server
+    Check,
+}
+
+impl ConfigCommand {
+    /// Runs the configuration action.
+    ///
+    /// Reports every missing environment variable rather than stopping at the first one,
+    /// validates the values the same way server startup does, and verifies the database
+    /// is reachable with the number of migrations that would be applied on startup.
+    ///
+    /// # Returns
+    /// - `Ok(())` - Configuration is valid
+    /// - `Err(AppError::BadRequest)` - One or more problems were found, each is printed
+    pub async fn run(self) -> Result<(), AppError> {
+        match self {
+            Self::Check => {
+                match dotenvy::dotenv() {
+                    Ok(path) => println!("Loaded {}", path.display()),
+                    Err(_) => println!("No .env file found, using process environment"),
+                }
+
+                let mut problems = Vec::new();
+
+                for var in REQUIRED_ENV_VARS {
+                    if std::env::var(var).is_err() {
+                        problems.push(format!("Missing required environment variable: {}", var));
+                    }
+                }
+
+                if problems.is_empty() {
+                    match Config::from_env() {
+                        Ok(config) => match url::Url::parse(&config.discord_redirect_url) {
+                            Ok(_) if !config.discord_redirect_url.starts_with(&config.app_url) => {
+                                problems.push(format!(
+                                    "DISCORD_REDIRECT_URL {} is not on the app URL {}",
+                                    config.discord_redirect_url, config.app_url
+                                ))
+                            }
+                            Ok(_) => {}
+                            Err(e) => problems.push(format!(
+                                "Invalid value for environment variable DISCORD_REDIRECT_URL: {}",
+                                e
+                            )),
+                        },
+                        Err(e) => problems.push(e.to_string()),
+                    }
+                } else if std::env::var("DATABASE_URL").is_ok() {
+                    if let Err(e) = Config::database_url_from_env() {
+                        problems.push(e.to_string());
+                    }
+                }
+
+                if let Ok(database_url) = Config::database_url_from_env() {
+                    match startup::open_database(&database_url).await {
+                        Ok(db) => match Migrator::get_pending_migrations(&db).await {
+                            Ok(pending) => println!(
+                                "Database reachable, {} pending migration(s)",
+                                pending.len()
+                            ),
+                            Err(e) => problems.push(format!("Failed to read migrations: {}", e)),
+                        },
+                        Err(e) => problems.push(format!("Failed to connect to database: {}", e)),
+                    }
+                }
+
+                if !problems.is_empty() {
+                    for problem in &problems {
+                        println!("error: {}", problem);
+                    }
+                    return Err(AppError::BadRequest(format!(
+                        "Configuration check found {} problem(s)",
+                        problems.len()
+                    )));
+                }
+
+                println!("Configuration is valid");
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,206 @@
+//! `eve-map` command for regenerating the bundled EVE static map.
+
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    path::{Path, PathBuf},
+};
+
+use clap::Subcommand;
+
+use crate::server::{
+    error::AppError,
+    eve_map::{EveMap, SOLAR_SYSTEMS_HEADER, STARGATES_HEADER},
+};
+
+/// Solar system IDs of known space, wormhole & abyssal systems have no stargates
+const KNOWN_SPACE_SYSTEM_IDS: std::ops::Range<u32> = 30_000_000..31_000_000;
+
+/// Static map actions.
+#[derive(Subcommand, Debug)]
+pub enum EveMapCommand {
+    /// Regenerate the bundled static map from the CSV dump of the static data export
+    ///
+    /// Expects `mapRegions.csv`, `mapConstellations.csv`, `mapSolarSystems.csv` &
+    /// `mapSolarSystemJumps.csv` as published by Fuzzwork. The server must be rebuilt
+    /// afterwards as the map is compiled into the binary.
+    Import {
+        /// Directory containing the static data export CSV files
+        #[arg(long)]
+        sde_dir: PathBuf,
+        /// Directory to write the static map files to
+        #[arg(long, default_value = "src/server/eve_map")]
+        out_dir: PathBuf,
+    },
+}
+
+impl EveMapCommand {
+    /// Runs the static map action.
+    ///
+    /// # Returns
+    /// - `Ok(())` - Static map files written
+    /// - `Err(AppError::BadRequest)` - An input file is missing or malformed, or writing failed
+    /// - `Err(AppError::InternalError)` - The generated files failed to parse
+    pub async fn run(self) -> Result<(), AppError> {
+        match self {
+            Self::Import { sde_dir, out_dir } => {
+                let regions: HashMap<String, String> =
+                    read_sde_csv(&sde_dir, "mapRegions.csv", &["regionID", "regionName"])?
+                        .into_iter()
+                        .map(|row| (row[0].clone(), row[1].clone()))
+                        .collect();
+                let constellations: HashMap<String, String> = read_sde_csv(
+                    &sde_dir,
+                    "mapConstellations.csv",
+                    &["constellationID", "constellationName"],
+                )?
+                .into_iter()
+                .map(|row| (row[0].clone(), row[1].clone()))
+                .collect();
+
+                let mut systems = BTreeMap::new();
+                for row in read_sde_csv(
+                    &sde_dir,
+                    "mapSolarSystems.csv",
+                    &[
+                        "solarSystemID",
+                        "solarSystemName",
+                        "constellationID",
+                        "regionID",
+                        "security",
+                    ],
+                )? {
+                    let id = parse_id(&row[0], "mapSolarSystems.csv")?;
+                    if !KNOWN_SPACE_SYSTEM_IDS.contains(&id) {
+                        continue;
+                    }
+
+                    let (Some(constellation), Some(region)) =
+                        (constellations.get(&row[2]), regions.get(&row[3]))
+                    else {
+                        return Err(AppError::BadRequest(format!(
+                            "Solar system {} references an unknown constellation or region",
+                            id
+                        )));
+                    };
+
+                    systems.insert(
+                        id,
+                        format!("{},{},{},{},{}", id, row[1], constellation, region, row[4]),
+                    );
+                }
+
+                let mut stargates = BTreeSet::new();
+                for row in read_sde_csv(
+                    &sde_dir,
+                    "mapSolarSystemJumps.csv",
+                    &["fromSolarSystemID", "toSolarSystemID"],
+                )? {
+                    let from = parse_id(&row[0], "mapSolarSystemJumps.csv")?;
+                    let to = parse_id(&row[1], "mapSolarSystemJumps.csv")?;
+                    if systems.contains_key(&from) && systems.contains_key(&to) {
+                        stargates.insert((from.min(to), from.max(to)));
+                    }
+                }
+
+                let solar_systems_csv = std::iter::once(SOLAR_SYSTEMS_HEADER.to_string())
+                    .chain(systems.into_values())
+                    .collect::<Vec<_>>()
+                    .join("\n")
+                    + "\n";
+                let stargates_csv = std::iter::once(STARGATES_HEADER.to_string())
+                    .chain(
+                        stargates
+                            .iter()
+                            .map(|(from, to)| format!("{},{}", from, to)),
+                    )
+                    .collect::<Vec<_>>()
+                    .join("\n")
+                    + "\n";
+
+                // Catch anything the server would refuse to start with before writing
+                let map = EveMap::parse(&solar_systems_csv, &stargates_csv)?;
+
+                write_file(&out_dir.join("solar_systems.csv"), &solar_systems_csv)?;
+                write_file(&out_dir.join("stargates.csv"), &stargates_csv)?;
+
+                println!(
+                    "Wrote {} solar system(s) & {} stargate connection(s) to {}",
+                    map.system_count(),
+                    map.stargate_count(),
+                    out_dir.display()
+                );
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Reads the given columns of a static data export CSV file.
+///
+/// Columns are looked up by their header name so the column order of the dump doesn't
+/// matter. Values are unquoted, names in the map tables never contain commas.
+///
+/// # Returns
+/// - `Ok(Vec<Vec<String>>)` - Rows with the requested columns in the requested order
+/// - `Err(AppError::BadRequest)` - The file can't be read or lacks a requested column
+fn read_sde_csv(dir: &Path, file: &str, columns: &[&str]) -> Result<Vec<Vec<String>>, AppError> {
+    let path = dir.join(file);
+    let contents = std::fs::read_to_string(&path)
+        .map_err(|e| AppError::BadRequest(format!("Failed to read {}: {}", path.display(), e)))?;
+
+    let mut lines = contents.lines().filter(|line| !line.trim().is_empty());
+    let header: Vec<String> = lines
+        .next()
+        .unwrap_or_default()
+        .split(',')
+        .map(unquote)
+        .collect();
+
+    let indexes = columns
+        .iter()
+        .map(|column| {
+            header
+                .iter()
+                .position(|name| name == column)
+                .ok_or_else(|| {
+                    AppError::BadRequest(format!("{} is missing the {} column", file, column))
+                })
+        })
+        .collect::<Result<Vec<usize>, AppError>>()?;
+
+    lines
+        .map(|line| {
+            let values: Vec<String> = line.split(',').map(unquote).collect();
+            indexes
+                .iter()
+                .map(|&index| {
+                    values.get(index).cloned().ok_or_else(|| {
+                        AppError::BadRequest(format!("{} has a truncated row: {}", file, line))
+                    })
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Strips surrounding whitespace & quotes from a CSV value
+fn unquote(value: &str) -> String {
+    value.trim().trim_matches('"').to_string()
+}
+
+/// Parses a solar system ID of a static data export row
+fn parse_id(value: &str, file: &str) -> Result<u32, AppError> {
+    value.parse().map_err(|e| {
+        AppError::BadRequest(format!(
+            "{} has an invalid solar system ID '{}': {}",
+            file, value, e
+        ))
+    })
+}
+
+/// Writes a generated static map file
+fn write_file(path: &Path, contents: &str) -> Result<(), AppError> {
+    std::fs::write(path, contents)
+        .map_err(|e| AppError::BadRequest(format!("Failed to write {}: {}", path.display(), e)))
+}
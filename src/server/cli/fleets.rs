@@ -0,0 +1,125 @@
+//! `fleets` command for exporting the fleets of a guild.
+
+use std::{collections::BTreeMap, path::PathBuf};
+
+use chrono::{DateTime, Utc};
+use clap::Subcommand;
+use sea_orm::DatabaseConnection;
+use serde::Serialize;
+
+use crate::server::{
+    data::{
+        category::FleetCategoryRepository, discord::guild::DiscordGuildRepository,
+        fleet::FleetRepository,
+    },
+    error::{internal::InternalError, AppError},
+};
+
+/// Fleet export actions.
+#[derive(Subcommand, Debug)]
+pub enum FleetsCommand {
+    /// Export all fleets of a guild as JSON, including past & hidden fleets
+    Export {
+        /// Discord ID of the guild to export fleets from
+        #[arg(long)]
+        guild: u64,
+        /// File to write the export to, printed to stdout when omitted
+        #[arg(long, short)]
+        output: Option<PathBuf>,
+    },
+}
+
+/// A single fleet in the JSON export.
+#[derive(Serialize)]
+struct FleetExport {
+    id: i32,
+    category_id: i32,
+    category_name: String,
+    name: String,
+    commander_id: u64,
+    fleet_time: DateTime<Utc>,
+    description: Option<String>,
+    hidden: bool,
+    disable_reminder: bool,
+    created_at: DateTime<Utc>,
+    /// Custom ping format field values keyed by field ID
+    field_values: BTreeMap<i32, String>,
+}
+
+impl FleetsCommand {
+    /// Runs the fleet export action.
+    ///
+    /// # Arguments
+    /// - `db` - Database connection
+    ///
+    /// # Returns
+    /// - `Ok(())` - Fleets exported successfully
+    /// - `Err(AppError::NotFound)` - Guild doesn't exist
+    /// - `Err(AppError::Database)` - Database error during query
+    /// - `Err(AppError::BadRequest)` - Failed to write the export file
+    pub async fn run(self, db: &DatabaseConnection) -> Result<(), AppError> {
+        match self {
+            Self::Export { guild, output } => {
+                if DiscordGuildRepository::new(db)
+                    .find_by_guild_id(guild)
+                    .await?
+                    .is_none()
+                {
+                    return Err(AppError::NotFound(format!("Guild {} not found", guild)));
+                }
+
+                let fleets = FleetRepository::new(db).get_all_by_guild(guild).await?;
+                let mut category_ids: Vec<i32> =
+                    fleets.iter().map(|(fleet, _)| fleet.category_id).collect();
+                category_ids.sort_unstable();
+                category_ids.dedup();
+                let category_names = FleetCategoryRepository::new(db)
+                    .get_names_by_ids(category_ids)
+                    .await?;
+
+                let export: Vec<FleetExport> = fleets
+                    .into_iter()
+                    .map(|(fleet, field_values)| FleetExport {
+                        id: fleet.id,
+                        category_id: fleet.category_id,
+                        category_name: category_names
+                            .get(&fleet.category_id)
+                            .cloned()
+                            .unwrap_or_default(),
+                        name: fleet.name,
+                        commander_id: fleet.commander_id,
+                        fleet_time: fleet.fleet_time,
+                        description: fleet.description,
+                        hidden: fleet.hidden,
+                        disable_reminder: fleet.disable_reminder,
+                        created_at: fleet.created_at,
+                        field_values: field_values.into_iter().collect(),
+                    })
+                    .collect();
+
+                let json = serde_json::to_string_pretty(&export).map_err(|e| {
+                    InternalError::SerializeJson {
+                        value: "fleet export",
+                        source: e,
+                    }
+                })?;
+
+                match output {
+                    Some(path) => {
+                        std::fs::write(&path, json).map_err(|e| {
+                            AppError::BadRequest(format!(
+                                "Failed to write {}: {}",
+                                path.display(),
+                                e
+                            ))
+                        })?;
+                        eprintln!("Exported {} fleet(s) to {}", export.len(), path.display());
+                    }
+                    None => println!("{}", json),
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,47 @@
+//! `guilds` command for inspecting the guilds the bot is in.
+
+use clap::Subcommand;
+use sea_orm::DatabaseConnection;
+
+use crate::server::{data::discord::guild::DiscordGuildRepository, error::AppError};
+
+/// Guild inspection actions.
+#[derive(Subcommand, Debug)]
+pub enum GuildsCommand {
+    /// List all guilds with their ID, last sync time, status and name
+    List,
+}
+
+impl GuildsCommand {
+    /// Runs the guild inspection action.
+    ///
+    /// # Arguments
+    /// - `db` - Database connection
+    ///
+    /// # Returns
+    /// - `Ok(())` - Guilds listed successfully
+    /// - `Err(AppError::Database)` - Database error during query
+    pub async fn run(self, db: &DatabaseConnection) -> Result<(), AppError> {
+        match self {
+            Self::List => {
+                let mut guilds = DiscordGuildRepository::new(db).get_all().await?;
+                guilds.sort_by(|a, b| a.name.cmp(&b.name));
+
+                if guilds.is_empty() {
+                    println!("No guilds");
+                }
+                for guild in guilds {
+                    println!(
+                        "{:<20} {}  {:<9} {}",
+                        guild.guild_id,
+                        guild.last_sync_at.format("%Y-%m-%d %H:%M UTC"),
+                        guild.status.as_str(),
+                        guild.name
+                    );
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
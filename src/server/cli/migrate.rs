@@ -0,0 +1,63 @@
+//! `migrate` command for applying & rolling back database migrations.
+
+use clap::Subcommand;
+use migration::{Migrator, MigratorTrait};
+
+use crate::server::{config::Config, error::AppError, startup};
+
+/// Database migration actions, applies all pending migrations when omitted.
+#[derive(Subcommand, Debug)]
+pub enum MigrateCommand {
+    /// Apply pending migrations
+    Up {
+        /// Number of pending migrations to apply, all when omitted
+        #[arg(long)]
+        steps: Option<u32>,
+    },
+    /// Roll back applied migrations
+    Down {
+        /// Number of applied migrations to roll back
+        #[arg(long, default_value_t = 1)]
+        steps: u32,
+    },
+    /// Show which migrations are applied or pending
+    Status,
+}
+
+impl MigrateCommand {
+    /// Runs the migration action against the database configured by `DATABASE_URL`.
+    ///
+    /// Connects without the automatic migration performed on server startup so that
+    /// rollbacks aren't immediately re-applied.
+    ///
+    /// # Returns
+    /// - `Ok(())` - Migrations applied, rolled back or listed successfully
+    /// - `Err(ConfigErr(_))` - `DATABASE_URL` is missing or uses an unsupported backend
+    /// - `Err(DbErr(_))` - Failed to connect to the database or run a migration
+    pub async fn run(self) -> Result<(), AppError> {
+        let db = startup::open_database(&Config::database_url_from_env()?).await?;
+
+        match self {
+            Self::Up { steps } => {
+                let pending = Migrator::get_pending_migrations(&db).await?.len();
+                Migrator::up(&db, steps).await?;
+
+                let applied = steps.map_or(pending, |steps| pending.min(steps as usize));
+                println!("Applied {} migration(s)", applied);
+            }
+            Self::Down { steps } => {
+                let applied = Migrator::get_applied_migrations(&db).await?.len();
+                Migrator::down(&db, Some(steps)).await?;
+
+                println!("Rolled back {} migration(s)", applied.min(steps as usize));
+            }
+            Self::Status => {
+                for migration in Migrator::get_migration_with_status(&db).await? {
+                    println!("{:<8} {}", migration.status(), migration.name());
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,112 @@
+//! Administrative command line interface of the server binary.
+//!
+//! Running the binary without a subcommand starts the full application (Discord bot,
+//! scheduler and HTTP server). The subcommands defined here instead perform a single
+//! administrative task against the database and exit, which is useful for managing an
+//! instance running in a container without relying on log output:
+//!
+//! - `migrate` - Apply, roll back or inspect database migrations
+//! - `admin` - Grant, revoke or list admin users
+//! - `guilds` - List the guilds the bot is in
+//! - `fleets` - Export the fleets of a guild
+//! - `config` - Validate the environment configuration
+//! - `eve-map` - Regenerate the bundled EVE static map from the static data export
+//!
+//! Commands reuse the existing repositories & services and never start the bot,
+//! scheduler or HTTP server.
+
+pub mod admin;
+pub mod config;
+pub mod eve_map;
+pub mod fleets;
+pub mod guilds;
+pub mod migrate;
+
+use clap::{Parser, Subcommand};
+use sea_orm::DatabaseConnection;
+
+use crate::server::{config::Config, error::AppError, startup};
+
+/// Command line arguments of the server binary.
+#[derive(Parser, Debug)]
+#[command(version, about = "EVE Online fleet timerboard for Discord")]
+pub struct Cli {
+    /// Administrative command to run, starts the server when omitted
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+/// Administrative subcommands of the server binary.
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Run or roll back database migrations
+    Migrate {
+        #[command(subcommand)]
+        action: Option<migrate::MigrateCommand>,
+    },
+    /// Manage admin users
+    Admin {
+        #[command(subcommand)]
+        action: admin::AdminCommand,
+    },
+    /// Inspect the guilds the bot is in
+    Guilds {
+        #[command(subcommand)]
+        action: guilds::GuildsCommand,
+    },
+    /// Export fleets
+    Fleets {
+        #[command(subcommand)]
+        action: fleets::FleetsCommand,
+    },
+    /// Validate the environment configuration
+    Config {
+        #[command(subcommand)]
+        action: config::ConfigCommand,
+    },
+    /// Manage the bundled EVE static map
+    EveMap {
+        #[command(subcommand)]
+        action: eve_map::EveMapCommand,
+    },
+}
+
+impl Command {
+    /// Runs the administrative command to completion.
+    ///
+    /// # Returns
+    /// - `Ok(())` - Command completed successfully
+    /// - `Err(AppError)` - Command failed, the error is printed by the caller
+    pub async fn run(self) -> Result<(), AppError> {
+        match self {
+            Self::Migrate { action } => {
+                action
+                    .unwrap_or(migrate::MigrateCommand::Up { steps: None })
+                    .run()
+                    .await
+            }
+            Self::Admin { action } => action.run(&connect().await?).await,
+            Self::Guilds { action } => action.run(&connect().await?).await,
+            Self::Fleets { action } => action.run(&connect().await?).await,
+            Self::Config { action } => action.run().await,
+            Self::EveMap { action } => action.run().await,
+        }
+    }
+}
+
+/// Connects to the database configured by `DATABASE_URL` & applies pending migrations.
+///
+/// Only `DATABASE_URL` is required so commands work without Discord credentials set.
+///
+/// # Returns
+/// - `Ok(DatabaseConnection)` - Connected database with all migrations applied
+/// - `Err(ConfigErr(_))` - `DATABASE_URL` is missing or uses an unsupported backend
+/// - `Err(DbErr(_))` - Failed to connect to the database or run migrations
+async fn connect() -> Result<DatabaseConnection, AppError> {
+    use migration::{Migrator, MigratorTrait};
+
+    let db = startup::open_database(&Config::database_url_from_env()?).await?;
+    Migrator::up(&db, None).await?;
+
+    Ok(db)
+}
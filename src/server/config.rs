@@ -9,10 +9,14 @@
 //! - Application URL for generating links
 //! - Discord OAuth2 and bot credentials
 //! - Discord API endpoint URLs
+//! - Data retention policy for the pruning job
 
 use axum::http::HeaderValue;
 
-use crate::server::error::{config::ConfigError, AppError};
+use crate::server::{
+    error::{config::ConfigError, AppError},
+    model::retention::RetentionPolicy,
+};
 
 /// Discord OAuth2 authorization endpoint URL.
 const DISCORD_AUTH_URL: &str = "https://discord.com/oauth2/authorize";
@@ -20,6 +24,20 @@ const DISCORD_AUTH_URL: &str = "https://discord.com/oauth2/authorize";
 /// Discord OAuth2 token exchange endpoint URL.
 const DISCORD_TOKEN_URL: &str = "https://discord.com/api/oauth2/token";
 
+/// Environment variables which must be set for `Config::from_env` to succeed.
+pub const REQUIRED_ENV_VARS: [&str; 7] = [
+    "DATABASE_URL",
+    "PROTOCOL",
+    "DOMAIN",
+    "DISCORD_CLIENT_ID",
+    "DISCORD_CLIENT_SECRET",
+    "DISCORD_REDIRECT_URL",
+    "DISCORD_BOT_TOKEN",
+];
+
+/// `DATABASE_URL` prefixes of the supported database backends.
+const SUPPORTED_DATABASE_SCHEMES: [&str; 3] = ["sqlite:", "postgres://", "postgresql://"];
+
 /// Application configuration loaded from environment variables.
 ///
 /// All configuration values are loaded during application startup via `from_env()`.
@@ -36,6 +54,8 @@ const DISCORD_TOKEN_URL: &str = "https://discord.com/api/oauth2/token";
 #[derive(Clone)]
 pub struct Config {
     /// Database connection URL (e.g., "sqlite://database.db" or "postgres://...").
+    ///
+    /// The URL scheme selects the database backend, only SQLite & PostgreSQL are supported.
     pub database_url: String,
 
     /// Full application URL including protocol and domain (e.g., "https://example.com").
@@ -63,6 +83,12 @@ pub struct Config {
 
     /// Discord OAuth2 token exchange endpoint URL.
     pub discord_token_url: String,
+
+    /// Bearer token required to scrape the `/metrics` endpoint, which is disabled when unset.
+    pub metrics_token: Option<String>,
+
+    /// Retention policy applied by the scheduled pruning job.
+    pub retention: RetentionPolicy,
 }
 
 impl Config {
@@ -77,7 +103,7 @@ impl Config {
     /// descriptive error message.
     ///
     /// # Required Environment Variables
-    /// - `DATABASE_URL` - Database connection string
+    /// - `DATABASE_URL` - Database connection string (`sqlite://`, `postgres://` or `postgresql://`)
     /// - `PROTOCOL` - Protocol for app URL (e.g., "http" or "https")
     /// - `DOMAIN` - Domain for app URL (e.g., "localhost:8080" or "example.com")
     /// - `DISCORD_CLIENT_ID` - Discord application client ID
@@ -85,9 +111,17 @@ impl Config {
     /// - `DISCORD_REDIRECT_URL` - Discord OAuth2 redirect URL
     /// - `DISCORD_BOT_TOKEN` - Discord bot token
     ///
+    /// # Optional Environment Variables
+    /// - `METRICS_TOKEN` - Bearer token required to scrape `/metrics`, disabled when unset
+    /// - `RETENTION_FLEET_DAYS` - Days after fleet time before fleets are archived, never archived when unset
+    /// - `RETENTION_FLEET_MESSAGE_HOURS` - Hours after fleet time before fleet message records are deleted, kept forever when unset
+    /// - `RETENTION_WEBHOOK_DELIVERY_DAYS` - Days before finished webhook deliveries are deleted (default 30)
+    ///
     /// # Returns
     /// - `Ok(Config)` - Configuration loaded successfully from environment variables
     /// - `Err(ConfigError::MissingEnvVar(_))` - Required environment variable is not set
+    /// - `Err(ConfigError::InvalidEnvValue { .. })` - `DATABASE_URL` uses an unsupported backend
+    ///   or a retention period isn't a positive whole number
     pub fn from_env() -> Result<Self, AppError> {
         let protocol = std::env::var("PROTOCOL")
             .map_err(|_| ConfigError::MissingEnvVar("PROTOCOL".to_string()))?;
@@ -103,9 +137,10 @@ impl Config {
                     reason: format!("Cannot be used as HTTP header value: {}", e),
                 })?;
 
+        let database_url = Self::database_url_from_env()?;
+
         Ok(Self {
-            database_url: std::env::var("DATABASE_URL")
-                .map_err(|_| ConfigError::MissingEnvVar("DATABASE_URL".to_string()))?,
+            database_url,
             app_url,
             cors_origin,
             discord_client_id: std::env::var("DISCORD_CLIENT_ID")
@@ -118,6 +153,80 @@ impl Config {
                 .map_err(|_| ConfigError::MissingEnvVar("DISCORD_BOT_TOKEN".to_string()))?,
             discord_auth_url: DISCORD_AUTH_URL.to_string(),
             discord_token_url: DISCORD_TOKEN_URL.to_string(),
+            metrics_token: std::env::var("METRICS_TOKEN")
+                .ok()
+                .filter(|token| !token.is_empty()),
+            retention: Self::retention_from_env()?,
         })
     }
+
+    /// Loads the retention policy from the optional `RETENTION_*` environment variables.
+    ///
+    /// Unset or empty variables fall back to the defaults of `RetentionPolicy`.
+    ///
+    /// # Returns
+    /// - `Ok(RetentionPolicy)` - Retention policy with configured or default periods
+    /// - `Err(ConfigError::InvalidEnvValue { .. })` - A period isn't a positive whole number
+    pub fn retention_from_env() -> Result<RetentionPolicy, AppError> {
+        let defaults = RetentionPolicy::default();
+
+        Ok(RetentionPolicy {
+            fleet_days: optional_positive_env("RETENTION_FLEET_DAYS")?,
+            fleet_message_hours: optional_positive_env("RETENTION_FLEET_MESSAGE_HOURS")?,
+            webhook_delivery_days: optional_positive_env("RETENTION_WEBHOOK_DELIVERY_DAYS")?
+                .unwrap_or(defaults.webhook_delivery_days),
+        })
+    }
+
+    /// Loads and validates only the `DATABASE_URL` environment variable.
+    ///
+    /// Used by the admin CLI commands which only require database access and shouldn't
+    /// fail because the Discord credentials are absent from the environment.
+    ///
+    /// # Returns
+    /// - `Ok(String)` - Database URL using a supported backend scheme
+    /// - `Err(ConfigError::MissingEnvVar(_))` - `DATABASE_URL` is not set
+    /// - `Err(ConfigError::InvalidEnvValue { .. })` - `DATABASE_URL` uses an unsupported backend
+    pub fn database_url_from_env() -> Result<String, AppError> {
+        let database_url = std::env::var("DATABASE_URL")
+            .map_err(|_| ConfigError::MissingEnvVar("DATABASE_URL".to_string()))?;
+        if !SUPPORTED_DATABASE_SCHEMES
+            .iter()
+            .any(|scheme| database_url.starts_with(scheme))
+        {
+            return Err(ConfigError::InvalidEnvValue {
+                var: "DATABASE_URL".to_string(),
+                reason:
+                    "Must be a SQLite (sqlite:) or PostgreSQL (postgres:// or postgresql://) URL"
+                        .to_string(),
+            }
+            .into());
+        }
+
+        Ok(database_url)
+    }
+}
+
+/// Reads an optional environment variable which must be a positive whole number.
+///
+/// # Arguments
+/// - `var` - Name of the environment variable
+///
+/// # Returns
+/// - `Ok(Some(u32))` - Variable is set to a positive whole number
+/// - `Ok(None)` - Variable is unset or empty
+/// - `Err(ConfigError::InvalidEnvValue { .. })` - Variable is zero or not a whole number
+fn optional_positive_env(var: &str) -> Result<Option<u32>, AppError> {
+    let Some(value) = std::env::var(var).ok().filter(|value| !value.is_empty()) else {
+        return Ok(None);
+    };
+
+    match value.parse::<u32>() {
+        Ok(parsed) if parsed > 0 => Ok(Some(parsed)),
+        _ => Err(ConfigError::InvalidEnvValue {
+            var: var.to_string(),
+            reason: format!("Must be a positive whole number, got {:?}", value),
+        }
+        .into()),
+    }
 }
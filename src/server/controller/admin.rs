@@ -3,12 +3,14 @@ use axum::{
     response::{IntoResponse, Redirect},
     Json,
 };
+use chrono::Utc;
 use serde::Deserialize;
 use tower_sessions::Session;
 
 use crate::{
     model::{
         api::{ErrorDto, SuccessDto},
+        retention::RetentionPreviewDto,
         user::{PaginatedUsersDto, UserDto},
     },
     server::{
@@ -18,7 +20,7 @@ use crate::{
             session::{AuthSession, CsrfSession, OAuthFlowSession},
         },
         model::user::{GetAllUsersParam, SetAdminParam},
-        service::{admin::bot::DiscordBotService, user::UserService},
+        service::{admin::bot::DiscordBotService, retention::RetentionService, user::UserService},
         state::AppState,
     },
 };
@@ -299,3 +301,44 @@ pub async fn remove_admin(
 
     Ok(Json(SuccessDto { success: true }))
 }
+
+/// Preview data retention pruning.
+///
+/// Returns the configured retention policy together with the number of rows the scheduled
+/// pruning job would currently remove from each table. Nothing is deleted.
+///
+/// # Access Control
+/// - `Admin` - Only admins can preview retention pruning
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection and retention policy
+/// - `session` - User's session for authentication
+///
+/// # Returns
+/// - `200 OK` - Retention policy and rows which would be pruned
+/// - `401 Unauthorized` - User not authenticated or not an admin
+/// - `500 Internal Server Error` - Database error
+#[utoipa::path(
+    get,
+    path = "/api/admin/retention/preview",
+    tag = ADMIN_TAG,
+    responses(
+        (status = 200, description = "Successfully previewed retention pruning", body = RetentionPreviewDto),
+        (status = 401, description = "User not authenticated or not an admin", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn get_retention_preview(
+    State(state): State<AppState>,
+    session: Session,
+) -> Result<impl IntoResponse, AppError> {
+    let auth_session = AuthSession::new(&session);
+    let auth_guard = AuthGuard::new(&state.db, auth_session.inner());
+    let retention_service = RetentionService::new(&state.db, state.retention);
+
+    let _ = auth_guard.require(&[Permission::Admin]).await?;
+
+    let report = retention_service.preview(Utc::now()).await?;
+
+    Ok(Json(report.into_preview_dto(&state.retention)))
+}
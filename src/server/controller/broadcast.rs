@@ -0,0 +1,156 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use tower_sessions::Session;
+
+use crate::{
+    model::{
+        api::ErrorDto,
+        broadcast::{BroadcastDto, BroadcastOverviewDto, CreateBroadcastDto},
+    },
+    server::{
+        error::AppError, middleware::auth::AuthGuard, service::broadcast::BroadcastService,
+        state::AppState,
+    },
+};
+
+/// Tag for grouping broadcast endpoints in OpenAPI documentation
+pub static BROADCAST_TAG: &str = "broadcast";
+
+/// Get the broadcast overview of a guild.
+///
+/// Lists the channels & ping roles of the categories the user can broadcast to alongside
+/// the guild's most recent broadcasts. The history is only returned to users who can
+/// broadcast.
+///
+/// # Access Control
+/// - `LoggedIn` - User must be authenticated
+///
+/// # Returns
+/// - `200 OK` - The broadcast targets & recent broadcasts
+/// - `401 Unauthorized` - User not authenticated
+/// - `500 Internal Server Error` - Database error
+#[utoipa::path(
+    get,
+    path = "/api/guilds/{guild_id}/broadcasts",
+    tag = BROADCAST_TAG,
+    params(
+        ("guild_id" = u64, Path, description = "Discord guild ID")
+    ),
+    responses(
+        (status = 200, description = "Successfully retrieved broadcasts", body = BroadcastOverviewDto),
+        (status = 401, description = "User not authenticated", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn get_broadcasts(
+    State(state): State<AppState>,
+    session: Session,
+    Path(guild_id): Path<u64>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = AuthGuard::new(&state.db, &session).require(&[]).await?;
+
+    let overview =
+        BroadcastService::new(&state.db, state.discord_http.clone(), state.app_url.clone())
+            .get_overview(guild_id, user.discord_id, user.admin)
+            .await?;
+
+    Ok((StatusCode::OK, Json(overview)))
+}
+
+/// Compose an ad-hoc broadcast.
+///
+/// Posts an embed with the title & message to the chosen channels, pinging the chosen
+/// roles. Broadcasts with a scheduled time are posted once the time is due instead.
+///
+/// # Access Control
+/// - `LoggedIn` - User must be authenticated and able to broadcast to every chosen
+///   channel & role, through a guild broadcast role or category access roles
+///
+/// # Returns
+/// - `201 Created` - The recorded broadcast
+/// - `400 Bad Request` - Invalid title, message, channels or scheduled time
+/// - `401 Unauthorized` - User not authenticated
+/// - `403 Forbidden` - User can't broadcast to a chosen channel or ping a chosen role
+/// - `500 Internal Server Error` - Database error
+#[utoipa::path(
+    post,
+    path = "/api/guilds/{guild_id}/broadcasts",
+    tag = BROADCAST_TAG,
+    params(
+        ("guild_id" = u64, Path, description = "Discord guild ID")
+    ),
+    request_body = CreateBroadcastDto,
+    responses(
+        (status = 201, description = "Successfully created broadcast", body = BroadcastDto),
+        (status = 400, description = "Invalid broadcast data", body = ErrorDto),
+        (status = 401, description = "User not authenticated", body = ErrorDto),
+        (status = 403, description = "User lacks permission to broadcast to the channels or roles", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn create_broadcast(
+    State(state): State<AppState>,
+    session: Session,
+    Path(guild_id): Path<u64>,
+    Json(dto): Json<CreateBroadcastDto>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = AuthGuard::new(&state.db, &session).require(&[]).await?;
+
+    let broadcast =
+        BroadcastService::new(&state.db, state.discord_http.clone(), state.app_url.clone())
+            .create(guild_id, user.discord_id, user.admin, dto)
+            .await?;
+
+    Ok((StatusCode::CREATED, Json(broadcast)))
+}
+
+/// Cancel a scheduled broadcast.
+///
+/// The broadcast stays in the history marked as cancelled.
+///
+/// # Access Control
+/// - `LoggedIn` - User must be authenticated and either have composed the broadcast or
+///   be able to broadcast to every one of its channels
+///
+/// # Returns
+/// - `200 OK` - The cancelled broadcast
+/// - `400 Bad Request` - Broadcast was already sent or cancelled
+/// - `401 Unauthorized` - User not authenticated
+/// - `403 Forbidden` - User lacks permission to cancel the broadcast
+/// - `404 Not Found` - Broadcast not found
+/// - `500 Internal Server Error` - Database error
+#[utoipa::path(
+    delete,
+    path = "/api/guilds/{guild_id}/broadcasts/{broadcast_id}",
+    tag = BROADCAST_TAG,
+    params(
+        ("guild_id" = u64, Path, description = "Discord guild ID"),
+        ("broadcast_id" = i32, Path, description = "Broadcast ID")
+    ),
+    responses(
+        (status = 200, description = "Successfully cancelled broadcast", body = BroadcastDto),
+        (status = 400, description = "Broadcast was already sent or cancelled", body = ErrorDto),
+        (status = 401, description = "User not authenticated", body = ErrorDto),
+        (status = 403, description = "User lacks permission to cancel the broadcast", body = ErrorDto),
+        (status = 404, description = "Broadcast not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn cancel_broadcast(
+    State(state): State<AppState>,
+    session: Session,
+    Path((guild_id, broadcast_id)): Path<(u64, i32)>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = AuthGuard::new(&state.db, &session).require(&[]).await?;
+
+    let broadcast =
+        BroadcastService::new(&state.db, state.discord_http.clone(), state.app_url.clone())
+            .cancel(guild_id, broadcast_id, user.discord_id, user.admin)
+            .await?;
+
+    Ok((StatusCode::OK, Json(broadcast)))
+}
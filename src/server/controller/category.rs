@@ -75,7 +75,7 @@ pub async fn create_fleet_category(
     let service = FleetCategoryService::new(&state.db);
 
     // Convert DTO to server model
-    let params = CreateFleetCategoryParams::from_dto(guild_id, payload);
+    let params = CreateFleetCategoryParams::from_dto(guild_id, payload)?;
 
     let category = service.create(params).await?;
 
@@ -247,7 +247,7 @@ pub async fn update_fleet_category(
     let service = FleetCategoryService::new(&state.db);
 
     // Convert DTO to server model
-    let params = UpdateFleetCategoryParams::from_dto(category_id, guild_id, payload);
+    let params = UpdateFleetCategoryParams::from_dto(category_id, guild_id, payload)?;
 
     let category = service.update(params).await?;
 
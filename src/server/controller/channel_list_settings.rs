@@ -0,0 +1,93 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use tower_sessions::Session;
+
+use crate::{
+    model::{
+        api::ErrorDto,
+        channel_list_settings::{ChannelListSettingsDto, UpdateChannelListSettingsDto},
+    },
+    server::{
+        error::AppError,
+        middleware::auth::{AuthGuard, Permission},
+        service::channel_list_settings::ChannelListSettingsService,
+        state::AppState,
+    },
+};
+
+pub static CHANNEL_LIST_SETTINGS_TAG: &str = "channel_list_settings";
+
+#[utoipa::path(
+    get,
+    path = "/api/admin/servers/{guild_id}/list-settings",
+    tag = CHANNEL_LIST_SETTINGS_TAG,
+    params(
+        ("guild_id" = u64, Path, description = "Discord guild ID")
+    ),
+    responses(
+        (status = 200, description = "Successfully retrieved upcoming fleets list settings", body = Vec<ChannelListSettingsDto>),
+        (status = 401, description = "User not authenticated or not an admin", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn get_channel_list_settings(
+    State(state): State<AppState>,
+    session: Session,
+    Path(guild_id): Path<u64>,
+) -> Result<impl IntoResponse, AppError> {
+    let _ = AuthGuard::new(&state.db, &session)
+        .require(&[Permission::Admin])
+        .await?;
+
+    let settings = ChannelListSettingsService::new(
+        &state.db,
+        state.discord_http.clone(),
+        state.app_url.clone(),
+    )
+    .list_by_guild(guild_id)
+    .await?;
+
+    Ok((StatusCode::OK, Json(settings)))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/admin/servers/{guild_id}/list-settings/{channel_id}",
+    tag = CHANNEL_LIST_SETTINGS_TAG,
+    params(
+        ("guild_id" = u64, Path, description = "Discord guild ID"),
+        ("channel_id" = u64, Path, description = "Discord channel ID"),
+    ),
+    request_body = UpdateChannelListSettingsDto,
+    responses(
+        (status = 200, description = "Successfully updated upcoming fleets list settings", body = ChannelListSettingsDto),
+        (status = 400, description = "Invalid list settings or forum channel", body = ErrorDto),
+        (status = 401, description = "User not authenticated or not an admin", body = ErrorDto),
+        (status = 404, description = "Channel not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn update_channel_list_settings(
+    State(state): State<AppState>,
+    session: Session,
+    Path((guild_id, channel_id)): Path<(u64, u64)>,
+    Json(payload): Json<UpdateChannelListSettingsDto>,
+) -> Result<impl IntoResponse, AppError> {
+    let _ = AuthGuard::new(&state.db, &session)
+        .require(&[Permission::Admin])
+        .await?;
+
+    let settings = ChannelListSettingsService::new(
+        &state.db,
+        state.discord_http.clone(),
+        state.app_url.clone(),
+    )
+    .update(guild_id, channel_id, payload)
+    .await?;
+
+    Ok((StatusCode::OK, Json(settings)))
+}
@@ -10,14 +10,18 @@ use tower_sessions::Session;
 use crate::{
     model::{
         api::ErrorDto,
-        discord::{DiscordGuildChannelDto, DiscordGuildDto, DiscordGuildRoleDto},
+        discord::{
+            ChannelDiagnosticsDto, DiscordGuildChannelDto, DiscordGuildDto, DiscordGuildRoleDto,
+            RoleDiagnosticsDto,
+        },
     },
     server::{
         controller::param::PaginationParam,
         error::AppError,
         middleware::auth::{AuthGuard, Permission},
         service::discord::{
-            DiscordGuildChannelService, DiscordGuildRoleService, DiscordGuildService,
+            DiscordDiagnosticsService, DiscordGuildChannelService, DiscordGuildRoleService,
+            DiscordGuildService,
         },
         state::AppState,
     },
@@ -122,6 +126,110 @@ pub async fn get_discord_guild_by_id(
     Ok((StatusCode::OK, Json(guild)))
 }
 
+/// Restore a Discord guild the bot was added back to.
+///
+/// Makes the guild's existing timerboard visible again and resumes its notifications
+/// after the bot was removed from the guild and later added back.
+///
+/// # Access Control
+/// - `Admin` - Only admins can restore Discord guilds
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session for authentication
+/// - `guild_id` - Discord guild ID to restore
+///
+/// # Returns
+/// - `200 OK` - The restored Discord guild
+/// - `400 Bad Request` - Guild is already active or the bot hasn't been added back
+/// - `401 Unauthorized` - User not authenticated or not an admin
+/// - `404 Not Found` - Guild with specified ID not found
+/// - `500 Internal Server Error` - Database error
+#[utoipa::path(
+    post,
+    path = "/api/admin/servers/{guild_id}/restore",
+    tag = DISCORD_TAG,
+    params(
+        ("guild_id" = u64, Path, description = "Discord guild ID")
+    ),
+    responses(
+        (status = 200, description = "Successfully restored Discord guild", body = DiscordGuildDto),
+        (status = 400, description = "Guild is active or the bot hasn't been added back", body = ErrorDto),
+        (status = 401, description = "User not authenticated or not an admin", body = ErrorDto),
+        (status = 404, description = "Guild not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn restore_discord_guild(
+    State(state): State<AppState>,
+    session: Session,
+    Path(guild_id): Path<u64>,
+) -> Result<impl IntoResponse, AppError> {
+    let _ = AuthGuard::new(&state.db, &session)
+        .require(&[Permission::Admin])
+        .await?;
+
+    let guild = DiscordGuildService::new(&state.db)
+        .restore(guild_id)
+        .await?;
+
+    Ok((StatusCode::OK, Json(guild)))
+}
+
+/// Purge the data of a Discord guild the bot was removed from.
+///
+/// Deletes the guild entirely while the bot is no longer in it. If the bot was added
+/// back, deletes the guild's categories, fleets, ping formats, ping groups and webhooks
+/// and starts the guild over as active.
+///
+/// # Access Control
+/// - `Admin` - Only admins can purge Discord guilds
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session for authentication
+/// - `guild_id` - Discord guild ID to purge
+///
+/// # Returns
+/// - `200 OK` - The guild starting over after the bot was added back
+/// - `204 No Content` - The guild was deleted entirely
+/// - `400 Bad Request` - Guild is active
+/// - `401 Unauthorized` - User not authenticated or not an admin
+/// - `404 Not Found` - Guild with specified ID not found
+/// - `500 Internal Server Error` - Database error
+#[utoipa::path(
+    post,
+    path = "/api/admin/servers/{guild_id}/purge",
+    tag = DISCORD_TAG,
+    params(
+        ("guild_id" = u64, Path, description = "Discord guild ID")
+    ),
+    responses(
+        (status = 200, description = "Successfully purged Discord guild data", body = DiscordGuildDto),
+        (status = 204, description = "Successfully deleted Discord guild"),
+        (status = 400, description = "Guild is active", body = ErrorDto),
+        (status = 401, description = "User not authenticated or not an admin", body = ErrorDto),
+        (status = 404, description = "Guild not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn purge_discord_guild(
+    State(state): State<AppState>,
+    session: Session,
+    Path(guild_id): Path<u64>,
+) -> Result<impl IntoResponse, AppError> {
+    let _ = AuthGuard::new(&state.db, &session)
+        .require(&[Permission::Admin])
+        .await?;
+
+    let guild = DiscordGuildService::new(&state.db).purge(guild_id).await?;
+
+    Ok(match guild {
+        Some(guild) => (StatusCode::OK, Json(guild)).into_response(),
+        None => StatusCode::NO_CONTENT.into_response(),
+    })
+}
+
 /// Get paginated roles for a Discord guild.
 ///
 /// Returns a paginated list of roles available in the specified Discord guild.
@@ -225,3 +333,158 @@ pub async fn get_discord_guild_channels(
 
     Ok((StatusCode::OK, Json(channels)))
 }
+
+/// Check the bot's permissions in a Discord channel.
+///
+/// Computes the bot's effective permissions in the channel from the synced role
+/// permissions and the channel's permission overwrites fetched from Discord, and
+/// reports which permissions needed for fleet notifications are missing.
+/// Only accessible by admins.
+///
+/// # Access Control
+/// - `Admin` - Only admins can diagnose Discord channels
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection and Discord client
+/// - `session` - User's session for authentication
+/// - `guild_id` - Discord guild ID the channel belongs to
+/// - `channel_id` - Discord channel ID to check
+///
+/// # Returns
+/// - `200 OK` - Missing permissions, or why the channel couldn't be checked
+/// - `401 Unauthorized` - User not authenticated or not an admin
+/// - `404 Not Found` - Channel not found in the guild
+/// - `500 Internal Server Error` - Database or Discord API error
+#[utoipa::path(
+    get,
+    path = "/api/admin/servers/{guild_id}/channels/{channel_id}/diagnostics",
+    tag = DISCORD_TAG,
+    params(
+        ("guild_id" = u64, Path, description = "Discord guild ID"),
+        ("channel_id" = u64, Path, description = "Discord channel ID")
+    ),
+    responses(
+        (status = 200, description = "Successfully checked channel permissions", body = ChannelDiagnosticsDto),
+        (status = 401, description = "User not authenticated or not an admin", body = ErrorDto),
+        (status = 404, description = "Channel not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn get_discord_channel_diagnostics(
+    State(state): State<AppState>,
+    session: Session,
+    Path((guild_id, channel_id)): Path<(u64, u64)>,
+) -> Result<impl IntoResponse, AppError> {
+    let _ = AuthGuard::new(&state.db, &session)
+        .require(&[Permission::Admin])
+        .await?;
+
+    let diagnostics = DiscordDiagnosticsService::new(&state.db, state.discord_http.clone())
+        .diagnose_channel(guild_id, channel_id)
+        .await?;
+
+    Ok((StatusCode::OK, Json(diagnostics)))
+}
+
+/// Send a test message to a Discord channel.
+///
+/// Posts a short embed without mentions to verify the bot can post in the channel.
+/// Forum channels receive a new forum post. Only accessible by admins.
+///
+/// # Access Control
+/// - `Admin` - Only admins can send test messages
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection and Discord client
+/// - `session` - User's session for authentication
+/// - `guild_id` - Discord guild ID the channel belongs to
+/// - `channel_id` - Discord channel ID to send the message to
+///
+/// # Returns
+/// - `204 No Content` - Test message sent
+/// - `400 Bad Request` - Discord rejected the message
+/// - `401 Unauthorized` - User not authenticated or not an admin
+/// - `404 Not Found` - Channel not found in the guild
+/// - `500 Internal Server Error` - Database error
+#[utoipa::path(
+    post,
+    path = "/api/admin/servers/{guild_id}/channels/{channel_id}/test-message",
+    tag = DISCORD_TAG,
+    params(
+        ("guild_id" = u64, Path, description = "Discord guild ID"),
+        ("channel_id" = u64, Path, description = "Discord channel ID")
+    ),
+    responses(
+        (status = 204, description = "Successfully sent test message"),
+        (status = 400, description = "Discord rejected the message", body = ErrorDto),
+        (status = 401, description = "User not authenticated or not an admin", body = ErrorDto),
+        (status = 404, description = "Channel not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn send_discord_test_message(
+    State(state): State<AppState>,
+    session: Session,
+    Path((guild_id, channel_id)): Path<(u64, u64)>,
+) -> Result<impl IntoResponse, AppError> {
+    let _ = AuthGuard::new(&state.db, &session)
+        .require(&[Permission::Admin])
+        .await?;
+
+    DiscordDiagnosticsService::new(&state.db, state.discord_http.clone())
+        .send_test_message(guild_id, channel_id)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Check whether the bot can ping a Discord role.
+///
+/// Reports whether the role is mentionable and whether the bot has the guild-wide
+/// Mention Everyone permission which allows pinging roles that aren't.
+/// Only accessible by admins.
+///
+/// # Access Control
+/// - `Admin` - Only admins can diagnose Discord roles
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection and Discord client
+/// - `session` - User's session for authentication
+/// - `guild_id` - Discord guild ID the role belongs to
+/// - `role_id` - Discord role ID to check
+///
+/// # Returns
+/// - `200 OK` - Whether the role can be pinged
+/// - `401 Unauthorized` - User not authenticated or not an admin
+/// - `404 Not Found` - Role not found in the guild
+/// - `500 Internal Server Error` - Database or Discord API error
+#[utoipa::path(
+    get,
+    path = "/api/admin/servers/{guild_id}/roles/{role_id}/diagnostics",
+    tag = DISCORD_TAG,
+    params(
+        ("guild_id" = u64, Path, description = "Discord guild ID"),
+        ("role_id" = u64, Path, description = "Discord role ID")
+    ),
+    responses(
+        (status = 200, description = "Successfully checked role", body = RoleDiagnosticsDto),
+        (status = 401, description = "User not authenticated or not an admin", body = ErrorDto),
+        (status = 404, description = "Role not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn get_discord_role_diagnostics(
+    State(state): State<AppState>,
+    session: Session,
+    Path((guild_id, role_id)): Path<(u64, u64)>,
+) -> Result<impl IntoResponse, AppError> {
+    let _ = AuthGuard::new(&state.db, &session)
+        .require(&[Permission::Admin])
+        .await?;
+
+    let diagnostics = DiscordDiagnosticsService::new(&state.db, state.discord_http.clone())
+        .diagnose_role(guild_id, role_id)
+        .await?;
+
+    Ok((StatusCode::OK, Json(diagnostics)))
+}
@@ -0,0 +1,191 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use tower_sessions::Session;
+
+use crate::{
+    model::{
+        api::ErrorDto,
+        doctrine::{CreateDoctrineDto, DoctrineDto, PaginatedDoctrinesDto, UpdateDoctrineDto},
+    },
+    server::{
+        controller::param::PaginationParam,
+        error::AppError,
+        middleware::auth::{AuthGuard, Permission},
+        model::doctrine::{CreateDoctrineParam, UpdateDoctrineParam},
+        service::doctrine::DoctrineService,
+        state::AppState,
+    },
+};
+
+pub static DOCTRINE_TAG: &str = "doctrine";
+
+#[utoipa::path(
+    post,
+    path = "/api/admin/servers/{guild_id}/doctrine",
+    tag = DOCTRINE_TAG,
+    params(
+        ("guild_id" = u64, Path, description = "Discord guild ID")
+    ),
+    request_body = CreateDoctrineDto,
+    responses(
+        (status = 201, description = "Successfully created doctrine", body = DoctrineDto),
+        (status = 400, description = "Invalid doctrine data or fits not in EFT format", body = ErrorDto),
+        (status = 401, description = "User not authenticated or not an admin", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn create_doctrine(
+    State(state): State<AppState>,
+    session: Session,
+    Path(guild_id): Path<u64>,
+    Json(payload): Json<CreateDoctrineDto>,
+) -> Result<impl IntoResponse, AppError> {
+    let _ = AuthGuard::new(&state.db, &session)
+        .require(&[Permission::Admin])
+        .await?;
+
+    let doctrine = DoctrineService::new(&state.db)
+        .create(guild_id, CreateDoctrineParam::from_dto(payload)?)
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(doctrine.into_dto())))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/admin/servers/{guild_id}/doctrines",
+    tag = DOCTRINE_TAG,
+    params(
+        ("guild_id" = u64, Path, description = "Discord guild ID"),
+        ("page" = Option<u64>, Query, description = "Page number (default: 0)"),
+        ("entries" = Option<u64>, Query, description = "Items per page (default: 10)")
+    ),
+    responses(
+        (status = 200, description = "Successfully retrieved doctrines", body = PaginatedDoctrinesDto),
+        (status = 401, description = "User not authenticated or not an admin", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn get_paginated_doctrines(
+    State(state): State<AppState>,
+    session: Session,
+    Path(guild_id): Path<u64>,
+    Query(pagination): Query<PaginationParam>,
+) -> Result<impl IntoResponse, AppError> {
+    let _ = AuthGuard::new(&state.db, &session)
+        .require(&[Permission::Admin])
+        .await?;
+
+    let page = DoctrineService::new(&state.db)
+        .list_by_guild(guild_id, pagination.page, pagination.entries)
+        .await?;
+
+    let dto = page.map(|doctrine| doctrine.into_dto());
+
+    Ok((StatusCode::OK, Json(dto)))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/admin/servers/{guild_id}/doctrine/{id}",
+    tag = DOCTRINE_TAG,
+    params(
+        ("guild_id" = u64, Path, description = "Discord guild ID"),
+        ("id" = i32, Path, description = "Doctrine ID"),
+    ),
+    request_body = UpdateDoctrineDto,
+    responses(
+        (status = 200, description = "Successfully updated doctrine", body = DoctrineDto),
+        (status = 400, description = "Invalid doctrine data or fits not in EFT format", body = ErrorDto),
+        (status = 401, description = "User not authenticated or not an admin", body = ErrorDto),
+        (status = 404, description = "Doctrine not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn update_doctrine(
+    State(state): State<AppState>,
+    session: Session,
+    Path((guild_id, id)): Path<(u64, i32)>,
+    Json(payload): Json<UpdateDoctrineDto>,
+) -> Result<impl IntoResponse, AppError> {
+    let _ = AuthGuard::new(&state.db, &session)
+        .require(&[Permission::Admin])
+        .await?;
+
+    let doctrine = DoctrineService::new(&state.db)
+        .update(guild_id, id, UpdateDoctrineParam::from_dto(payload)?)
+        .await?;
+
+    Ok((StatusCode::OK, Json(doctrine.into_dto())))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/admin/servers/{guild_id}/doctrine/{id}",
+    tag = DOCTRINE_TAG,
+    params(
+        ("guild_id" = u64, Path, description = "Discord guild ID"),
+        ("id" = i32, Path, description = "Doctrine ID"),
+    ),
+    responses(
+        (status = 204, description = "Successfully deleted doctrine"),
+        (status = 401, description = "User not authenticated or not an admin", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn delete_doctrine(
+    State(state): State<AppState>,
+    session: Session,
+    Path((guild_id, id)): Path<(u64, i32)>,
+) -> Result<impl IntoResponse, AppError> {
+    let _ = AuthGuard::new(&state.db, &session)
+        .require(&[Permission::Admin])
+        .await?;
+
+    DoctrineService::new(&state.db).delete(guild_id, id).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Get all doctrines of a guild.
+///
+/// Lists the guild's doctrine library with fits for the doctrine picker of the fleet
+/// form.
+///
+/// # Returns
+/// - `200 OK` - The guild's doctrines ordered by name
+/// - `401 Unauthorized` - User not authenticated
+/// - `500 Internal Server Error` - Database error
+#[utoipa::path(
+    get,
+    path = "/api/guilds/{guild_id}/doctrines",
+    tag = DOCTRINE_TAG,
+    params(
+        ("guild_id" = u64, Path, description = "Discord guild ID")
+    ),
+    responses(
+        (status = 200, description = "Successfully retrieved doctrines", body = Vec<DoctrineDto>),
+        (status = 401, description = "User not authenticated", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn get_guild_doctrines(
+    State(state): State<AppState>,
+    session: Session,
+    Path(guild_id): Path<u64>,
+) -> Result<impl IntoResponse, AppError> {
+    let _ = AuthGuard::new(&state.db, &session).require(&[]).await?;
+
+    let doctrines: Vec<DoctrineDto> = DoctrineService::new(&state.db)
+        .get_all_by_guild(guild_id)
+        .await?
+        .into_iter()
+        .map(|doctrine| doctrine.into_dto())
+        .collect();
+
+    Ok((StatusCode::OK, Json(doctrines)))
+}
@@ -1,7 +1,10 @@
 use axum::{
     extract::{Path, Query, State},
     http::StatusCode,
-    response::IntoResponse,
+    response::{
+        sse::{Event, KeepAlive, Sse},
+        IntoResponse,
+    },
     Json,
 };
 
@@ -12,19 +15,30 @@ use crate::{
     model::{
         api::ErrorDto,
         category::FleetCategoryDetailsDto,
-        discord::DiscordGuildMemberDto,
-        fleet::{CreateFleetDto, FleetDto, PaginatedFleetsDto, UpdateFleetDto},
+        discord::{DiscordChannelKind, DiscordGuildMemberDto},
+        fleet::{
+            BulkFleetActionDto, CloneFleetDto, CreateFleetDto, FleetCalendarDto, FleetDto,
+            FleetEventDto, PaginatedFleetsDto, PaginatedGuildFleetsDto, UpdateFleetDto,
+        },
+        paste::{ParsePasteDto, ParsedPasteDto},
     },
     server::{
         data::{
-            category::FleetCategoryRepository, ping_format::field::PingFormatFieldRepository,
-            ping_group::PingGroupRepository,
+            category::FleetCategoryRepository, guild_settings::GuildSettingsRepository,
+            ping_format::field::PingFormatFieldRepository, ping_group::PingGroupRepository,
             user_category_permission::UserCategoryPermissionRepository,
         },
         error::{auth::AuthError, AppError},
         middleware::auth::{AuthGuard, Permission},
-        model::fleet::{CreateFleetParam, GetPaginatedFleetsByGuildParam},
-        service::fleet::FleetService,
+        model::fleet::{
+            CreateFleetParam, GetFleetCalendarParam, GetPaginatedFleetsByGuildParam,
+            GetPaginatedFleetsForUserParam,
+        },
+        service::{
+            fleet::FleetService,
+            fleet_event::{subscribe_fleet_events, FleetEventService},
+            paste::PasteService,
+        },
         state::AppState,
     },
 };
@@ -44,6 +58,24 @@ fn default_per_page() -> u64 {
     10
 }
 
+#[derive(Deserialize)]
+pub struct GuildFleetsQuery {
+    #[serde(default)]
+    pub page: u64,
+    #[serde(default = "default_per_page")]
+    pub per_page: u64,
+    /// Comma separated guild IDs to include, every guild of the user when omitted
+    pub guild_ids: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct CalendarQuery {
+    /// Start of the range as a Unix timestamp in seconds
+    pub start: i64,
+    /// End of the range as a Unix timestamp in seconds
+    pub end: i64,
+}
+
 /// Get category details including ping format fields for fleet creation.
 ///
 /// Returns detailed information about a fleet category including its ping format fields,
@@ -130,6 +162,11 @@ pub async fn get_category_details(
         (None, None)
     };
 
+    let downtime = GuildSettingsRepository::new(&state.db)
+        .get_by_guild(guild_id)
+        .await?
+        .downtime;
+
     // Build the response DTO
     let dto = FleetCategoryDetailsDto {
         id: category_with_relations.category.id,
@@ -167,6 +204,7 @@ pub async fn get_category_details(
                     can_view: access_role.can_view,
                     can_create: access_role.can_create,
                     can_manage: access_role.can_manage,
+                    can_broadcast: access_role.can_broadcast,
                 })
             })
             .collect(),
@@ -185,20 +223,83 @@ pub async fn get_category_details(
         channels: category_with_relations
             .channels
             .into_iter()
-            .filter_map(|(_cat_channel, channel_model)| {
+            .filter_map(|(cat_channel, channel_model)| {
                 channel_model.map(|channel| crate::model::category::FleetCategoryChannelDto {
                     channel_id: channel.channel_id.parse().unwrap_or(0),
                     channel_name: channel.name,
                     position: channel.position,
+                    kind: DiscordChannelKind::parse(&channel.kind).unwrap_or_default(),
+                    crosspost: cat_channel.crosspost,
                 })
             })
             .collect(),
         fields,
+        downtime,
     };
 
     Ok((StatusCode::OK, Json(dto)))
 }
 
+/// Parse a pasted notification or ping into a fleet for the creation modal.
+///
+/// Extracts the solar system, structure, timer end and `Key: Value` lines matching the
+/// category's ping format field names from an EVE structure notification or a text ping.
+/// Nothing is created, the prefilled fleet is returned for the FC to review and submit.
+///
+/// # Access Control
+/// - `CategoryCreate` - User must have create permission for the category
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session for authentication
+/// - `guild_id` - Discord guild ID
+/// - `category_id` - Fleet category whose ping format fields are matched
+/// - `dto` - The pasted text
+///
+/// # Returns
+/// - `200 OK` - Prefilled fleet with the requesting user as FC, plus warnings for values
+///   that couldn't be used
+/// - `400 Bad Request` - Paste is empty or too long
+/// - `401 Unauthorized` - User not authenticated or lacks create permission
+/// - `404 Not Found` - Category not found
+/// - `500 Internal Server Error` - Database error
+#[utoipa::path(
+    post,
+    path = "/api/guilds/{guild_id}/categories/{category_id}/parse-paste",
+    tag = FLEET_TAG,
+    params(
+        ("guild_id" = u64, Path, description = "Discord guild ID"),
+        ("category_id" = i32, Path, description = "Fleet category ID")
+    ),
+    request_body = ParsePasteDto,
+    responses(
+        (status = 200, description = "Successfully parsed paste", body = ParsedPasteDto),
+        (status = 400, description = "Paste is empty or too long", body = ErrorDto),
+        (status = 401, description = "User not authenticated or lacks permission", body = ErrorDto),
+        (status = 404, description = "Category not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn parse_paste(
+    State(state): State<AppState>,
+    session: Session,
+    Path((guild_id, category_id)): Path<(u64, i32)>,
+    Json(dto): Json<ParsePasteDto>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = AuthGuard::new(&state.db, &session)
+        .require(&[Permission::CategoryCreate(guild_id, category_id)])
+        .await?;
+
+    let parsed = PasteService::new(&state.db)
+        .parse(guild_id, category_id, &dto.text)
+        .await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(parsed.into_dto(category_id, user.discord_id)),
+    ))
+}
+
 /// Get all members of a Discord guild for FC selection.
 ///
 /// Returns a list of all members in the specified Discord guild. Used for selecting
@@ -436,6 +537,145 @@ pub async fn get_fleets(
     Ok((StatusCode::OK, Json(fleets)))
 }
 
+/// Get paginated fleets across all of the user's guilds.
+///
+/// Returns the upcoming fleets of every guild the user is a member of merged into a
+/// single timerboard, each alongside the guild it was scheduled in. Each guild applies
+/// the same category permission and hidden fleet visibility rules as `get_fleets`,
+/// admins see the fleets of every active guild.
+///
+/// # Access Control
+/// - `LoggedIn` - User must be authenticated
+///
+/// # Arguments
+/// - `state` - Application state containing database, Discord HTTP client, and app URL
+/// - `session` - User's session for authentication
+/// - `query` - Pagination parameters and optional guild filter
+///
+/// # Returns
+/// - `200 OK` - Paginated list of visible fleets across guilds
+/// - `400 Bad Request` - Invalid guild ID in the guild filter
+/// - `401 Unauthorized` - User not authenticated
+/// - `500 Internal Server Error` - Database error
+#[utoipa::path(
+    get,
+    path = "/api/fleets",
+    tag = FLEET_TAG,
+    params(
+        ("page" = Option<u64>, Query, description = "Page number (default: 0)"),
+        ("per_page" = Option<u64>, Query, description = "Items per page (default: 10)"),
+        ("guild_ids" = Option<String>, Query, description = "Comma separated guild IDs to include (default: all)")
+    ),
+    responses(
+        (status = 200, description = "Successfully retrieved paginated fleets", body = PaginatedGuildFleetsDto),
+        (status = 400, description = "Invalid guild filter", body = ErrorDto),
+        (status = 401, description = "User not authenticated", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn get_user_fleets(
+    State(state): State<AppState>,
+    session: Session,
+    Query(query): Query<GuildFleetsQuery>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = AuthGuard::new(&state.db, &session).require(&[]).await?;
+
+    let guild_ids = query
+        .guild_ids
+        .map(|ids| {
+            ids.split(',')
+                .filter(|id| !id.trim().is_empty())
+                .map(|id| {
+                    id.trim()
+                        .parse::<u64>()
+                        .map_err(|_| AppError::BadRequest(format!("Invalid guild ID: {}", id)))
+                })
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .transpose()?;
+
+    let fleet_service =
+        FleetService::new(&state.db, state.discord_http.clone(), state.app_url.clone());
+    let fleets = fleet_service
+        .get_paginated_for_user(GetPaginatedFleetsForUserParam {
+            user_id: user.discord_id,
+            is_admin: user.admin,
+            guild_ids,
+            page: query.page,
+            per_page: query.per_page,
+        })
+        .await?;
+
+    Ok((StatusCode::OK, Json(fleets)))
+}
+
+/// Get fleets within a time range for the calendar views.
+///
+/// Returns the fleets of a guild between `start` and `end`, including past fleets, along
+/// with the cooldown windows of their category and ping group. Fleets just outside the
+/// range are included when their cooldown window overlaps it. Follows the same category
+/// permission and hidden fleet visibility rules as `get_fleets`.
+///
+/// # Access Control
+/// - `LoggedIn` - User must be authenticated
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session for authentication
+/// - `guild_id` - Discord guild ID to fetch fleets for
+/// - `range` - Start and end of the range as Unix timestamps in seconds
+///
+/// # Returns
+/// - `200 OK` - Visible fleets within the range ordered by time
+/// - `400 Bad Request` - Invalid range, or range longer than 42 days
+/// - `401 Unauthorized` - User not authenticated
+/// - `500 Internal Server Error` - Database error
+#[utoipa::path(
+    get,
+    path = "/api/guilds/{guild_id}/fleets/calendar",
+    tag = FLEET_TAG,
+    params(
+        ("guild_id" = u64, Path, description = "Discord guild ID"),
+        ("start" = i64, Query, description = "Start of the range (Unix timestamp in seconds)"),
+        ("end" = i64, Query, description = "End of the range (Unix timestamp in seconds)")
+    ),
+    responses(
+        (status = 200, description = "Successfully retrieved fleets within the range", body = FleetCalendarDto),
+        (status = 400, description = "Invalid range", body = ErrorDto),
+        (status = 401, description = "User not authenticated", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn get_fleet_calendar(
+    State(state): State<AppState>,
+    session: Session,
+    Path(guild_id): Path<u64>,
+    Query(range): Query<CalendarQuery>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = AuthGuard::new(&state.db, &session).require(&[]).await?;
+
+    let (Some(start), Some(end)) = (
+        chrono::DateTime::<chrono::Utc>::from_timestamp(range.start, 0),
+        chrono::DateTime::<chrono::Utc>::from_timestamp(range.end, 0),
+    ) else {
+        return Err(AppError::BadRequest("Invalid calendar range".to_string()));
+    };
+
+    let fleet_service =
+        FleetService::new(&state.db, state.discord_http.clone(), state.app_url.clone());
+    let calendar = fleet_service
+        .get_calendar_by_guild(GetFleetCalendarParam {
+            guild_id,
+            user_id: user.discord_id,
+            is_admin: user.admin,
+            start,
+            end,
+        })
+        .await?;
+
+    Ok((StatusCode::OK, Json(calendar)))
+}
+
 /// Update a fleet.
 ///
 /// Updates an existing fleet with new time, commander, description, hidden status,
@@ -468,7 +708,7 @@ pub async fn get_fleets(
 /// - `401 Unauthorized` - User not authenticated
 /// - `403 Forbidden` - User lacks permission to update the fleet
 /// - `404 Not Found` - Fleet not found
-/// - `400 Bad Request` - Invalid fleet data
+/// - `400 Bad Request` - Invalid fleet data or fleet was relayed from another server
 /// - `500 Internal Server Error` - Database or Discord API error
 #[utoipa::path(
     put,
@@ -553,6 +793,7 @@ pub async fn update_fleet(
 ///
 /// # Returns
 /// - `204 No Content` - Successfully deleted fleet
+/// - `400 Bad Request` - Fleet was relayed from another server and is read-only
 /// - `401 Unauthorized` - User not authenticated
 /// - `403 Forbidden` - User lacks permission to delete the fleet
 /// - `404 Not Found` - Fleet not found or user lacks permission
@@ -567,6 +808,7 @@ pub async fn update_fleet(
     ),
     responses(
         (status = 204, description = "Successfully deleted fleet"),
+        (status = 400, description = "Fleet was relayed from another server", body = ErrorDto),
         (status = 401, description = "User not authenticated", body = ErrorDto),
         (status = 403, description = "User lacks permission to delete fleet", body = ErrorDto),
         (status = 404, description = "Fleet not found", body = ErrorDto),
@@ -613,3 +855,196 @@ pub async fn delete_fleet(
         Err(AppError::NotFound("Fleet not found".to_string()))
     }
 }
+
+/// Clone a fleet with a new time.
+///
+/// Creates a copy of an existing fleet with the same category, name, commander,
+/// description, custom field values and flags, scheduled at the given time. The copy is
+/// validated and announced in Discord like a newly created fleet.
+///
+/// # Access Control
+/// - `LoggedIn` - User must be authenticated, able to see the fleet and have create
+///   permission for its category
+///
+/// # Arguments
+/// - `state` - Application state containing database, Discord HTTP client, and app URL
+/// - `session` - User's session for authentication
+/// - `guild_id` - Discord guild ID
+/// - `fleet_id` - Fleet ID to clone
+/// - `dto` - Fleet time of the copy
+///
+/// # Returns
+/// - `201 Created` - Successfully created the copy
+/// - `400 Bad Request` - Invalid fleet time
+/// - `401 Unauthorized` - User not authenticated
+/// - `403 Forbidden` - User lacks create permission for the fleet's category
+/// - `404 Not Found` - Fleet not found or user lacks permission
+/// - `500 Internal Server Error` - Database or Discord API error
+#[utoipa::path(
+    post,
+    path = "/api/guilds/{guild_id}/fleets/{fleet_id}/clone",
+    tag = FLEET_TAG,
+    params(
+        ("guild_id" = u64, Path, description = "Discord guild ID"),
+        ("fleet_id" = i32, Path, description = "Fleet ID")
+    ),
+    request_body = CloneFleetDto,
+    responses(
+        (status = 201, description = "Successfully cloned fleet", body = FleetDto),
+        (status = 400, description = "Invalid fleet time", body = ErrorDto),
+        (status = 401, description = "User not authenticated", body = ErrorDto),
+        (status = 403, description = "User lacks permission to create fleets", body = ErrorDto),
+        (status = 404, description = "Fleet not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn clone_fleet(
+    State(state): State<AppState>,
+    session: Session,
+    Path((guild_id, fleet_id)): Path<(u64, i32)>,
+    Json(dto): Json<CloneFleetDto>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = AuthGuard::new(&state.db, &session).require(&[]).await?;
+
+    // Get the fleet to check its category, the copy is created in the same one
+    let fleet_service =
+        FleetService::new(&state.db, state.discord_http.clone(), state.app_url.clone());
+    let fleet = fleet_service
+        .get_by_id(fleet_id, user.discord_id, user.admin)
+        .await?
+        .ok_or_else(|| AppError::NotFound("Fleet not found".to_string()))?;
+
+    let can_create = user.admin
+        || UserCategoryPermissionRepository::new(&state.db)
+            .user_can_create_category(user.discord_id, fleet.category_id)
+            .await?;
+
+    if !can_create {
+        return Err(AppError::AuthErr(AuthError::AccessDenied(
+            user.discord_id,
+            "You don't have permission to create fleets in this category".to_string(),
+        )));
+    }
+
+    let fleet = fleet_service
+        .clone_fleet(fleet_id, guild_id, user.admin, dto)
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(fleet)))
+}
+
+/// Shift, cancel or re-categorise several fleets at once.
+///
+/// Applies the same change to every selected fleet. The whole batch is validated against
+/// category & ping group cooldowns, including conflicts between the selected fleets,
+/// before any fleet is changed, and the changes are written in a single transaction. The
+/// upcoming fleets list of each Discord channel is updated once for the whole batch.
+///
+/// # Authorization
+/// For every selected fleet, user must be:
+/// - An admin, OR
+/// - The fleet commander, OR
+/// - Have manage permission for the fleet's category
+///
+/// Moving fleets to another category additionally requires create permission for it.
+///
+/// # Access Control
+/// - `LoggedIn` - User must be authenticated and authorized to edit every selected fleet
+///
+/// # Arguments
+/// - `state` - Application state containing database, Discord HTTP client, and app URL
+/// - `session` - User's session for authentication
+/// - `guild_id` - Discord guild ID
+/// - `dto` - Selected fleet IDs & the change to apply to them
+///
+/// # Returns
+/// - `204 No Content` - Successfully changed all fleets
+/// - `400 Bad Request` - Invalid batch, cooldown conflict or a fleet was relayed from another server
+/// - `401 Unauthorized` - User not authenticated
+/// - `403 Forbidden` - User lacks permission to edit one of the fleets
+/// - `404 Not Found` - A fleet or the new category was not found
+/// - `500 Internal Server Error` - Database or Discord API error
+#[utoipa::path(
+    post,
+    path = "/api/guilds/{guild_id}/fleets/bulk",
+    tag = FLEET_TAG,
+    params(
+        ("guild_id" = u64, Path, description = "Discord guild ID")
+    ),
+    request_body = BulkFleetActionDto,
+    responses(
+        (status = 204, description = "Successfully changed fleets"),
+        (status = 400, description = "Invalid batch or cooldown conflict", body = ErrorDto),
+        (status = 401, description = "User not authenticated", body = ErrorDto),
+        (status = 403, description = "User lacks permission to edit a fleet", body = ErrorDto),
+        (status = 404, description = "Fleet or category not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn bulk_update_fleets(
+    State(state): State<AppState>,
+    session: Session,
+    Path(guild_id): Path<u64>,
+    Json(dto): Json<BulkFleetActionDto>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = AuthGuard::new(&state.db, &session).require(&[]).await?;
+
+    let fleet_service =
+        FleetService::new(&state.db, state.discord_http.clone(), state.app_url.clone());
+    fleet_service
+        .bulk_update(guild_id, user.discord_id, user.admin, dto)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Stream live fleet changes of a guild.
+///
+/// Opens a Server-Sent Events stream sending an event whenever a fleet of the guild is
+/// created, updated or deleted. Events are filtered with the same visibility rules as
+/// GET (see get_fleet), and a `resync` event is sent when the stream fell behind so the
+/// client refetches its fleets.
+///
+/// # Access Control
+/// - `LoggedIn` - User must be authenticated
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session for authentication
+/// - `guild_id` - Discord guild ID
+///
+/// # Returns
+/// - `200 OK` - Event stream of fleet changes
+/// - `401 Unauthorized` - User not authenticated
+#[utoipa::path(
+    get,
+    path = "/api/guilds/{guild_id}/fleets/events",
+    tag = FLEET_TAG,
+    params(
+        ("guild_id" = u64, Path, description = "Discord guild ID")
+    ),
+    responses(
+        (status = 200, description = "Stream of fleet changes", body = FleetEventDto, content_type = "text/event-stream"),
+        (status = 401, description = "User not authenticated", body = ErrorDto)
+    ),
+)]
+pub async fn stream_fleet_events(
+    State(state): State<AppState>,
+    session: Session,
+    Path(guild_id): Path<u64>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = AuthGuard::new(&state.db, &session).require(&[]).await?;
+
+    // Subscribe before responding so no change made after the request is missed
+    let receiver = subscribe_fleet_events();
+    let stream =
+        futures_util::stream::unfold((receiver, state.db), move |(mut receiver, db)| async move {
+            let event = FleetEventService::new(&db)
+                .next_for_user(&mut receiver, guild_id, user.discord_id, user.admin)
+                .await?;
+
+            Some((Event::default().json_data(event), (receiver, db)))
+        });
+
+    Ok(Sse::new(stream).keep_alive(KeepAlive::default()))
+}
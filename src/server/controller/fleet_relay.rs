@@ -0,0 +1,180 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use tower_sessions::Session;
+
+use crate::{
+    model::{
+        api::ErrorDto,
+        fleet_relay::{AcceptFleetRelayDto, CreateFleetRelayDto, FleetRelayDto, FleetRelaysDto},
+    },
+    server::{
+        error::AppError,
+        middleware::auth::{AuthGuard, Permission},
+        model::fleet_relay::CreateFleetRelayParam,
+        service::fleet_relay::FleetRelayService,
+        state::AppState,
+    },
+};
+
+pub static FLEET_RELAY_TAG: &str = "fleet_relay";
+
+#[utoipa::path(
+    get,
+    path = "/api/admin/servers/{guild_id}/relays",
+    tag = FLEET_RELAY_TAG,
+    params(
+        ("guild_id" = u64, Path, description = "Discord guild ID")
+    ),
+    responses(
+        (status = 200, description = "Successfully retrieved fleet relays", body = FleetRelaysDto),
+        (status = 401, description = "User not authenticated or not an admin", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn get_fleet_relays(
+    State(state): State<AppState>,
+    session: Session,
+    Path(guild_id): Path<u64>,
+) -> Result<impl IntoResponse, AppError> {
+    let _ = AuthGuard::new(&state.db, &session)
+        .require(&[Permission::Admin])
+        .await?;
+
+    let relays = FleetRelayService::new(&state.db)
+        .list_by_guild(guild_id)
+        .await?;
+
+    Ok((StatusCode::OK, Json(relays)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/admin/servers/{guild_id}/relays",
+    tag = FLEET_RELAY_TAG,
+    params(
+        ("guild_id" = u64, Path, description = "Discord guild ID of the source category")
+    ),
+    request_body = CreateFleetRelayDto,
+    responses(
+        (status = 201, description = "Successfully requested fleet relay", body = FleetRelayDto),
+        (status = 400, description = "Invalid target or relay already exists", body = ErrorDto),
+        (status = 401, description = "User not authenticated or not an admin", body = ErrorDto),
+        (status = 404, description = "Category or target guild not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn create_fleet_relay(
+    State(state): State<AppState>,
+    session: Session,
+    Path(guild_id): Path<u64>,
+    Json(payload): Json<CreateFleetRelayDto>,
+) -> Result<impl IntoResponse, AppError> {
+    let _ = AuthGuard::new(&state.db, &session)
+        .require(&[Permission::Admin])
+        .await?;
+
+    let relay = FleetRelayService::new(&state.db)
+        .create(guild_id, CreateFleetRelayParam::from(payload))
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(relay)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/admin/servers/{guild_id}/relays/{id}/accept",
+    tag = FLEET_RELAY_TAG,
+    params(
+        ("guild_id" = u64, Path, description = "Discord guild ID of the target guild"),
+        ("id" = i32, Path, description = "Fleet relay ID"),
+    ),
+    request_body = AcceptFleetRelayDto,
+    responses(
+        (status = 200, description = "Successfully accepted fleet relay", body = FleetRelayDto),
+        (status = 401, description = "User not authenticated or not an admin", body = ErrorDto),
+        (status = 404, description = "Fleet relay or category not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn accept_fleet_relay(
+    State(state): State<AppState>,
+    session: Session,
+    Path((guild_id, id)): Path<(u64, i32)>,
+    Json(payload): Json<AcceptFleetRelayDto>,
+) -> Result<impl IntoResponse, AppError> {
+    let _ = AuthGuard::new(&state.db, &session)
+        .require(&[Permission::Admin])
+        .await?;
+
+    let relay = FleetRelayService::new(&state.db)
+        .accept(guild_id, id, payload.target_category_id)
+        .await?;
+
+    Ok((StatusCode::OK, Json(relay)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/admin/servers/{guild_id}/relays/{id}/decline",
+    tag = FLEET_RELAY_TAG,
+    params(
+        ("guild_id" = u64, Path, description = "Discord guild ID of the target guild"),
+        ("id" = i32, Path, description = "Fleet relay ID"),
+    ),
+    responses(
+        (status = 200, description = "Successfully declined fleet relay", body = FleetRelayDto),
+        (status = 401, description = "User not authenticated or not an admin", body = ErrorDto),
+        (status = 404, description = "Fleet relay not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn decline_fleet_relay(
+    State(state): State<AppState>,
+    session: Session,
+    Path((guild_id, id)): Path<(u64, i32)>,
+) -> Result<impl IntoResponse, AppError> {
+    let _ = AuthGuard::new(&state.db, &session)
+        .require(&[Permission::Admin])
+        .await?;
+
+    let relay = FleetRelayService::new(&state.db)
+        .decline(guild_id, id)
+        .await?;
+
+    Ok((StatusCode::OK, Json(relay)))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/admin/servers/{guild_id}/relays/{id}",
+    tag = FLEET_RELAY_TAG,
+    params(
+        ("guild_id" = u64, Path, description = "Discord guild ID of either guild of the relay"),
+        ("id" = i32, Path, description = "Fleet relay ID"),
+    ),
+    responses(
+        (status = 204, description = "Successfully removed fleet relay"),
+        (status = 401, description = "User not authenticated or not an admin", body = ErrorDto),
+        (status = 404, description = "Fleet relay not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn delete_fleet_relay(
+    State(state): State<AppState>,
+    session: Session,
+    Path((guild_id, id)): Path<(u64, i32)>,
+) -> Result<impl IntoResponse, AppError> {
+    let _ = AuthGuard::new(&state.db, &session)
+        .require(&[Permission::Admin])
+        .await?;
+
+    FleetRelayService::new(&state.db)
+        .delete(guild_id, id)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
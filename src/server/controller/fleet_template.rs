@@ -0,0 +1,158 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use tower_sessions::Session;
+
+use crate::{
+    model::{
+        api::ErrorDto,
+        fleet_template::{CreateFleetTemplateDto, FleetTemplateDto},
+    },
+    server::{
+        error::AppError,
+        middleware::auth::{AuthGuard, Permission},
+        model::fleet_template::CreateFleetTemplateParam,
+        service::fleet_template::FleetTemplateService,
+        state::AppState,
+    },
+};
+
+/// Tag for grouping fleet template endpoints in OpenAPI documentation
+pub static FLEET_TEMPLATE_TAG: &str = "fleet_template";
+
+/// Save a fleet template.
+///
+/// Saves everything of a fleet but its time as a template prefilling the fleet creation
+/// form. Templates are private to the user saving them unless shared with the category.
+///
+/// # Access Control
+/// - `CategoryCreate` - User must have create permission for the template's category,
+///   sharing the template also takes manage permission
+///
+/// # Returns
+/// - `201 Created` - The saved template
+/// - `400 Bad Request` - Invalid template name or template limit reached
+/// - `401 Unauthorized` - User not authenticated or lacks create permission
+/// - `403 Forbidden` - Sharing the template without manage permission
+/// - `404 Not Found` - Category not found
+/// - `500 Internal Server Error` - Database error
+#[utoipa::path(
+    post,
+    path = "/api/guilds/{guild_id}/fleet-templates",
+    tag = FLEET_TEMPLATE_TAG,
+    params(
+        ("guild_id" = u64, Path, description = "Discord guild ID")
+    ),
+    request_body = CreateFleetTemplateDto,
+    responses(
+        (status = 201, description = "Successfully saved template", body = FleetTemplateDto),
+        (status = 400, description = "Invalid template data", body = ErrorDto),
+        (status = 401, description = "User not authenticated or lacks permission", body = ErrorDto),
+        (status = 403, description = "User lacks permission to share the template", body = ErrorDto),
+        (status = 404, description = "Category not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn create_fleet_template(
+    State(state): State<AppState>,
+    session: Session,
+    Path(guild_id): Path<u64>,
+    Json(dto): Json<CreateFleetTemplateDto>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = AuthGuard::new(&state.db, &session)
+        .require(&[Permission::CategoryCreate(guild_id, dto.category_id)])
+        .await?;
+
+    let param = CreateFleetTemplateParam::from_dto(dto, user.discord_id);
+    let template = FleetTemplateService::new(&state.db)
+        .create(guild_id, param, user.admin)
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(template)))
+}
+
+/// Get the fleet templates a user can use.
+///
+/// Lists the user's own templates & the templates shared with the categories they can
+/// create fleets in, for picking one when creating a fleet.
+///
+/// # Access Control
+/// - `LoggedIn` - User must be authenticated
+///
+/// # Returns
+/// - `200 OK` - The usable templates ordered by name
+/// - `401 Unauthorized` - User not authenticated
+/// - `500 Internal Server Error` - Database error
+#[utoipa::path(
+    get,
+    path = "/api/guilds/{guild_id}/fleet-templates",
+    tag = FLEET_TEMPLATE_TAG,
+    params(
+        ("guild_id" = u64, Path, description = "Discord guild ID")
+    ),
+    responses(
+        (status = 200, description = "Successfully retrieved templates", body = Vec<FleetTemplateDto>),
+        (status = 401, description = "User not authenticated", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn get_fleet_templates(
+    State(state): State<AppState>,
+    session: Session,
+    Path(guild_id): Path<u64>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = AuthGuard::new(&state.db, &session).require(&[]).await?;
+
+    let templates = FleetTemplateService::new(&state.db)
+        .get_usable(guild_id, user.discord_id, user.admin)
+        .await?;
+
+    Ok((StatusCode::OK, Json(templates)))
+}
+
+/// Delete a fleet template.
+///
+/// Users can delete their own templates, shared templates of others take manage
+/// permission for the template's category.
+///
+/// # Access Control
+/// - `LoggedIn` - User must be authenticated and own or manage the template
+///
+/// # Returns
+/// - `204 No Content` - Successfully deleted template
+/// - `401 Unauthorized` - User not authenticated
+/// - `403 Forbidden` - User lacks permission to delete the template
+/// - `404 Not Found` - Template not found
+/// - `500 Internal Server Error` - Database error
+#[utoipa::path(
+    delete,
+    path = "/api/guilds/{guild_id}/fleet-templates/{template_id}",
+    tag = FLEET_TEMPLATE_TAG,
+    params(
+        ("guild_id" = u64, Path, description = "Discord guild ID"),
+        ("template_id" = i32, Path, description = "Fleet template ID")
+    ),
+    responses(
+        (status = 204, description = "Successfully deleted template"),
+        (status = 401, description = "User not authenticated", body = ErrorDto),
+        (status = 403, description = "User lacks permission to delete the template", body = ErrorDto),
+        (status = 404, description = "Template not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn delete_fleet_template(
+    State(state): State<AppState>,
+    session: Session,
+    Path((guild_id, template_id)): Path<(u64, i32)>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = AuthGuard::new(&state.db, &session).require(&[]).await?;
+
+    FleetTemplateService::new(&state.db)
+        .delete(guild_id, template_id, user.discord_id, user.admin)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,79 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use tower_sessions::Session;
+
+use crate::{
+    model::{api::ErrorDto, guild_settings::GuildSettingsDto},
+    server::{
+        error::AppError,
+        middleware::auth::{AuthGuard, Permission},
+        service::guild_settings::GuildSettingsService,
+        state::AppState,
+    },
+};
+
+pub static GUILD_SETTINGS_TAG: &str = "guild_settings";
+
+#[utoipa::path(
+    get,
+    path = "/api/admin/servers/{guild_id}/settings",
+    tag = GUILD_SETTINGS_TAG,
+    params(
+        ("guild_id" = u64, Path, description = "Discord guild ID")
+    ),
+    responses(
+        (status = 200, description = "Successfully retrieved guild settings", body = GuildSettingsDto),
+        (status = 401, description = "User not authenticated or not an admin", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn get_guild_settings(
+    State(state): State<AppState>,
+    session: Session,
+    Path(guild_id): Path<u64>,
+) -> Result<impl IntoResponse, AppError> {
+    let _ = AuthGuard::new(&state.db, &session)
+        .require(&[Permission::Admin])
+        .await?;
+
+    let settings = GuildSettingsService::new(&state.db).get(guild_id).await?;
+
+    Ok((StatusCode::OK, Json(settings.into_dto())))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/admin/servers/{guild_id}/settings",
+    tag = GUILD_SETTINGS_TAG,
+    params(
+        ("guild_id" = u64, Path, description = "Discord guild ID")
+    ),
+    request_body = GuildSettingsDto,
+    responses(
+        (status = 200, description = "Successfully updated guild settings", body = GuildSettingsDto),
+        (status = 400, description = "Invalid guild settings", body = ErrorDto),
+        (status = 401, description = "User not authenticated or not an admin", body = ErrorDto),
+        (status = 404, description = "Default ping format not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn update_guild_settings(
+    State(state): State<AppState>,
+    session: Session,
+    Path(guild_id): Path<u64>,
+    Json(payload): Json<GuildSettingsDto>,
+) -> Result<impl IntoResponse, AppError> {
+    let _ = AuthGuard::new(&state.db, &session)
+        .require(&[Permission::Admin])
+        .await?;
+
+    let settings = GuildSettingsService::new(&state.db)
+        .update(guild_id, payload)
+        .await?;
+
+    Ok((StatusCode::OK, Json(settings.into_dto())))
+}
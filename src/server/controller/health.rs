@@ -0,0 +1,100 @@
+use axum::{
+    extract::State,
+    http::{header, HeaderMap, StatusCode},
+    response::IntoResponse,
+    Json,
+};
+
+use crate::{
+    model::health::HealthDto,
+    server::{error::AppError, service::health::HealthService, state::AppState},
+};
+
+pub static HEALTH_TAG: &str = "health";
+
+/// Liveness probe, healthy as long as the database is reachable
+#[utoipa::path(
+    get,
+    path = "/health",
+    tag = HEALTH_TAG,
+    responses(
+        (status = 200, description = "Application is alive", body = HealthDto),
+        (status = 503, description = "Database is unreachable", body = HealthDto)
+    ),
+)]
+pub async fn health(State(state): State<AppState>) -> impl IntoResponse {
+    let health = HealthService::new(&state.db).check().await;
+    let alive = health.is_alive();
+
+    (probe_status(alive), Json(health.into_dto(alive)))
+}
+
+/// Readiness probe, requires the database, Discord gateway and a running scheduler
+#[utoipa::path(
+    get,
+    path = "/ready",
+    tag = HEALTH_TAG,
+    responses(
+        (status = 200, description = "Application is ready", body = HealthDto),
+        (status = 503, description = "A dependency is unavailable", body = HealthDto)
+    ),
+)]
+pub async fn ready(State(state): State<AppState>) -> impl IntoResponse {
+    let health = HealthService::new(&state.db).check().await;
+    let ready = health.is_ready();
+
+    (probe_status(ready), Json(health.into_dto(ready)))
+}
+
+/// Prometheus metrics, requires `Authorization: Bearer <METRICS_TOKEN>`
+///
+/// The endpoint is disabled unless `METRICS_TOKEN` is configured as the metrics expose
+/// the IDs of the guilds the bot is in.
+#[utoipa::path(
+    get,
+    path = "/metrics",
+    tag = HEALTH_TAG,
+    responses(
+        (status = 200, description = "Metrics in the Prometheus text exposition format", body = String, content_type = "text/plain"),
+        (status = 401, description = "Missing or invalid metrics token"),
+        (status = 404, description = "Metrics are disabled as no metrics token is configured"),
+        (status = 500, description = "Internal server error")
+    ),
+)]
+pub async fn metrics(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+) -> Result<impl IntoResponse, AppError> {
+    let Some(token) = &state.metrics_token else {
+        return Ok(StatusCode::NOT_FOUND.into_response());
+    };
+
+    let authorized = headers
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .is_some_and(|provided| provided == token);
+
+    if !authorized {
+        return Ok(StatusCode::UNAUTHORIZED.into_response());
+    }
+
+    let body = HealthService::new(&state.db).render_metrics().await?;
+
+    Ok((
+        [(
+            header::CONTENT_TYPE,
+            "text/plain; version=0.0.4; charset=utf-8",
+        )],
+        body,
+    )
+        .into_response())
+}
+
+fn probe_status(healthy: bool) -> StatusCode {
+    if healthy {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    }
+}
@@ -1,9 +1,19 @@
 pub mod admin;
 pub mod auth;
+pub mod broadcast;
 pub mod category;
+pub mod channel_list_settings;
 pub mod discord;
+pub mod doctrine;
 pub mod fleet;
+pub mod fleet_relay;
+pub mod fleet_template;
+pub mod guild_settings;
+pub mod health;
 pub mod param;
 pub mod ping_format;
 pub mod ping_group;
+pub mod solar_system;
+pub mod stand_down;
 pub mod user;
+pub mod webhook;
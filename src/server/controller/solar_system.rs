@@ -0,0 +1,74 @@
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use serde::Deserialize;
+use tower_sessions::Session;
+
+use crate::{
+    model::{api::ErrorDto, solar_system::SolarSystemDto},
+    server::{error::AppError, eve_map::eve_map, middleware::auth::AuthGuard, state::AppState},
+};
+
+/// Tag for grouping solar system endpoints in OpenAPI documentation
+pub static SOLAR_SYSTEM_TAG: &str = "solar_system";
+
+#[derive(Deserialize)]
+pub struct SolarSystemSearchQuery {
+    /// Part of the solar system name, matched ignoring case
+    pub search: String,
+    #[serde(default = "default_limit")]
+    pub limit: usize,
+}
+
+fn default_limit() -> usize {
+    10
+}
+
+/// Search solar systems of the bundled EVE static map by name.
+///
+/// Used to autocomplete solar system fields of fleets. An exact match is returned first,
+/// followed by systems starting with the search and then systems containing it.
+///
+/// # Access Control
+/// - `LoggedIn` - User must be authenticated
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session for authentication
+/// - `query` - Search text & maximum number of results (at most 25)
+///
+/// # Returns
+/// - `200 OK` - Matching solar systems with region, security status & dotlan link
+/// - `401 Unauthorized` - User not authenticated
+#[utoipa::path(
+    get,
+    path = "/api/eve/solar-systems",
+    tag = SOLAR_SYSTEM_TAG,
+    params(
+        ("search" = String, Query, description = "Part of the solar system name"),
+        ("limit" = Option<usize>, Query, description = "Maximum number of results, defaults to 10")
+    ),
+    responses(
+        (status = 200, description = "Successfully searched solar systems", body = Vec<SolarSystemDto>),
+        (status = 401, description = "User not authenticated", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn search_solar_systems(
+    State(state): State<AppState>,
+    session: Session,
+    Query(query): Query<SolarSystemSearchQuery>,
+) -> Result<impl IntoResponse, AppError> {
+    let _ = AuthGuard::new(&state.db, &session).require(&[]).await?;
+
+    let systems: Vec<SolarSystemDto> = eve_map()
+        .search(&query.search, query.limit)
+        .into_iter()
+        .map(|system| system.to_dto())
+        .collect();
+
+    Ok((StatusCode::OK, Json(systems)))
+}
@@ -0,0 +1,156 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use tower_sessions::Session;
+
+use crate::{
+    model::{
+        api::ErrorDto,
+        stand_down::{CreateStandDownDto, StandDownDto, StandDownOverviewDto},
+    },
+    server::{
+        error::AppError, middleware::auth::AuthGuard, service::stand_down::StandDownService,
+        state::AppState,
+    },
+};
+
+/// Tag for grouping stand-down endpoints in OpenAPI documentation
+pub static STAND_DOWN_TAG: &str = "stand_down";
+
+/// Get the stand-down overview of a guild.
+///
+/// Lists the scopes the user can stand down, being the whole guild or ping groups whose
+/// every category the user manages, alongside the guild's most recent stand-downs.
+///
+/// # Access Control
+/// - `LoggedIn` - User must be authenticated
+///
+/// # Returns
+/// - `200 OK` - The stand-down scopes & recent stand-downs
+/// - `401 Unauthorized` - User not authenticated
+/// - `500 Internal Server Error` - Database error
+#[utoipa::path(
+    get,
+    path = "/api/guilds/{guild_id}/stand-downs",
+    tag = STAND_DOWN_TAG,
+    params(
+        ("guild_id" = u64, Path, description = "Discord guild ID")
+    ),
+    responses(
+        (status = 200, description = "Successfully retrieved stand-downs", body = StandDownOverviewDto),
+        (status = 401, description = "User not authenticated", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn get_stand_downs(
+    State(state): State<AppState>,
+    session: Session,
+    Path(guild_id): Path<u64>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = AuthGuard::new(&state.db, &session).require(&[]).await?;
+
+    let overview =
+        StandDownService::new(&state.db, state.discord_http.clone(), state.app_url.clone())
+            .get_overview(guild_id, user.discord_id, user.admin)
+            .await?;
+
+    Ok((StatusCode::OK, Json(overview)))
+}
+
+/// Stand down the fleets of a guild or ping group.
+///
+/// Cancels every fleet of the scope within the time window and posts a single broadcast
+/// with the reason to every affected channel. Upcoming fleet lists are refreshed once.
+///
+/// # Access Control
+/// - `LoggedIn` - User must be authenticated and manage every category of the scope
+///
+/// # Returns
+/// - `201 Created` - The recorded stand-down
+/// - `400 Bad Request` - Invalid reason or window, or no fleets within the window
+/// - `401 Unauthorized` - User not authenticated
+/// - `403 Forbidden` - User lacks manage permission for a category of the scope
+/// - `404 Not Found` - Ping group not found
+/// - `500 Internal Server Error` - Database or Discord error
+#[utoipa::path(
+    post,
+    path = "/api/guilds/{guild_id}/stand-downs",
+    tag = STAND_DOWN_TAG,
+    params(
+        ("guild_id" = u64, Path, description = "Discord guild ID")
+    ),
+    request_body = CreateStandDownDto,
+    responses(
+        (status = 201, description = "Successfully stood down fleets", body = StandDownDto),
+        (status = 400, description = "Invalid stand-down data", body = ErrorDto),
+        (status = 401, description = "User not authenticated", body = ErrorDto),
+        (status = 403, description = "User lacks permission to stand down the scope", body = ErrorDto),
+        (status = 404, description = "Ping group not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn create_stand_down(
+    State(state): State<AppState>,
+    session: Session,
+    Path(guild_id): Path<u64>,
+    Json(dto): Json<CreateStandDownDto>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = AuthGuard::new(&state.db, &session).require(&[]).await?;
+
+    let stand_down =
+        StandDownService::new(&state.db, state.discord_http.clone(), state.app_url.clone())
+            .create(guild_id, user.discord_id, user.admin, dto)
+            .await?;
+
+    Ok((StatusCode::CREATED, Json(stand_down)))
+}
+
+/// Resume a stand-down.
+///
+/// Restores the fleets cancelled by the stand-down which are still ahead, announcing
+/// them like newly created fleets, and broadcasts that the stand-down was lifted.
+///
+/// # Access Control
+/// - `LoggedIn` - User must be authenticated and manage every category of the scope
+///
+/// # Returns
+/// - `200 OK` - The resumed stand-down
+/// - `400 Bad Request` - Stand-down was already resumed
+/// - `401 Unauthorized` - User not authenticated
+/// - `403 Forbidden` - User lacks manage permission for a category of the scope
+/// - `404 Not Found` - Stand-down not found
+/// - `500 Internal Server Error` - Database error
+#[utoipa::path(
+    post,
+    path = "/api/guilds/{guild_id}/stand-downs/{stand_down_id}/resume",
+    tag = STAND_DOWN_TAG,
+    params(
+        ("guild_id" = u64, Path, description = "Discord guild ID"),
+        ("stand_down_id" = i32, Path, description = "Stand-down ID")
+    ),
+    responses(
+        (status = 200, description = "Successfully resumed stand-down", body = StandDownDto),
+        (status = 400, description = "Stand-down was already resumed", body = ErrorDto),
+        (status = 401, description = "User not authenticated", body = ErrorDto),
+        (status = 403, description = "User lacks permission to resume the stand-down", body = ErrorDto),
+        (status = 404, description = "Stand-down not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn resume_stand_down(
+    State(state): State<AppState>,
+    session: Session,
+    Path((guild_id, stand_down_id)): Path<(u64, i32)>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = AuthGuard::new(&state.db, &session).require(&[]).await?;
+
+    let stand_down =
+        StandDownService::new(&state.db, state.discord_http.clone(), state.app_url.clone())
+            .resume(guild_id, stand_down_id, user.discord_id, user.admin)
+            .await?;
+
+    Ok((StatusCode::OK, Json(stand_down)))
+}
@@ -7,12 +7,20 @@ use axum::{
 use tower_sessions::Session;
 
 use crate::{
-    model::{api::ErrorDto, category::FleetCategoryListItemDto, discord::DiscordGuildDto},
+    model::{
+        api::ErrorDto,
+        category::FleetCategoryListItemDto,
+        discord::DiscordGuildDto,
+        subscription::{NotificationPreferenceDto, SubscriptionsDto},
+    },
     server::{
         error::AppError,
-        middleware::auth::AuthGuard,
-        model::user::GetUserParam,
-        service::{category::FleetCategoryService, user::UserService},
+        middleware::auth::{AuthGuard, Permission},
+        model::{subscription::SubscriptionTarget, user::GetUserParam},
+        service::{
+            category::FleetCategoryService, fleet::FleetService, subscription::SubscriptionService,
+            user::UserService,
+        },
         state::AppState,
     },
 };
@@ -113,3 +121,286 @@ pub async fn get_user_manageable_categories(
 
     Ok((StatusCode::OK, Json(categories_dto)))
 }
+
+/// Get the direct message notification preferences of the current user.
+///
+/// Users who never saved their preferences receive the defaults: every notification
+/// type enabled and no personal reminder.
+///
+/// # Access Control
+/// - `LoggedIn` - User must be authenticated
+///
+/// # Returns
+/// - `200 OK` - The user's notification preferences
+/// - `401 Unauthorized` - User not authenticated
+/// - `500 Internal Server Error` - Database error
+#[utoipa::path(
+    get,
+    path = "/api/user/notifications",
+    tag = USER_TAG,
+    responses(
+        (status = 200, description = "Successfully retrieved notification preferences", body = NotificationPreferenceDto),
+        (status = 401, description = "User not authenticated", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn get_notification_preferences(
+    State(state): State<AppState>,
+    session: Session,
+) -> Result<impl IntoResponse, AppError> {
+    let user = AuthGuard::new(&state.db, &session).require(&[]).await?;
+
+    let preferences = SubscriptionService::new(&state.db)
+        .get_preferences(user.discord_id)
+        .await?;
+
+    Ok((StatusCode::OK, Json(preferences.into_dto())))
+}
+
+/// Update the direct message notification preferences of the current user.
+///
+/// # Access Control
+/// - `LoggedIn` - User must be authenticated
+///
+/// # Returns
+/// - `200 OK` - The updated notification preferences
+/// - `400 Bad Request` - Reminder lead time outside of 1 to 1440 minutes
+/// - `401 Unauthorized` - User not authenticated
+/// - `500 Internal Server Error` - Database error
+#[utoipa::path(
+    put,
+    path = "/api/user/notifications",
+    tag = USER_TAG,
+    request_body = NotificationPreferenceDto,
+    responses(
+        (status = 200, description = "Successfully updated notification preferences", body = NotificationPreferenceDto),
+        (status = 400, description = "Invalid reminder lead time", body = ErrorDto),
+        (status = 401, description = "User not authenticated", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn update_notification_preferences(
+    State(state): State<AppState>,
+    session: Session,
+    Json(dto): Json<NotificationPreferenceDto>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = AuthGuard::new(&state.db, &session).require(&[]).await?;
+
+    let preferences = SubscriptionService::new(&state.db)
+        .update_preferences(user.discord_id, dto)
+        .await?;
+
+    Ok((StatusCode::OK, Json(preferences.into_dto())))
+}
+
+/// Get the subscriptions of the current user within a guild.
+///
+/// Lists every category of the guild the user can view alongside whether they're
+/// subscribed to it, as well as the fleets they're individually subscribed to.
+///
+/// # Access Control
+/// - `LoggedIn` - User must be authenticated
+///
+/// # Returns
+/// - `200 OK` - Viewable categories & subscribed fleet IDs
+/// - `401 Unauthorized` - User not authenticated
+/// - `500 Internal Server Error` - Database error
+#[utoipa::path(
+    get,
+    path = "/api/user/guilds/{guild_id}/subscriptions",
+    tag = USER_TAG,
+    params(
+        ("guild_id" = u64, Path, description = "Discord guild ID")
+    ),
+    responses(
+        (status = 200, description = "Successfully retrieved subscriptions", body = SubscriptionsDto),
+        (status = 401, description = "User not authenticated", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn get_subscriptions(
+    State(state): State<AppState>,
+    Path(guild_id): Path<u64>,
+    session: Session,
+) -> Result<impl IntoResponse, AppError> {
+    let user = AuthGuard::new(&state.db, &session).require(&[]).await?;
+
+    let subscriptions = SubscriptionService::new(&state.db)
+        .get_subscriptions(user.discord_id, guild_id, user.admin)
+        .await?;
+
+    Ok((StatusCode::OK, Json(subscriptions)))
+}
+
+/// Subscribe the current user to a fleet category.
+///
+/// The user receives direct messages for the fleets of the category as long as they
+/// can view it.
+///
+/// # Access Control
+/// - `CategoryView` - User must be able to view the category
+///
+/// # Returns
+/// - `204 No Content` - Subscribed to the category
+/// - `401 Unauthorized` - User not authenticated
+/// - `403 Forbidden` - User can't view the category
+/// - `404 Not Found` - Category doesn't exist in the guild
+/// - `500 Internal Server Error` - Database error
+#[utoipa::path(
+    put,
+    path = "/api/user/guilds/{guild_id}/categories/{category_id}/subscription",
+    tag = USER_TAG,
+    params(
+        ("guild_id" = u64, Path, description = "Discord guild ID"),
+        ("category_id" = i32, Path, description = "Fleet category ID")
+    ),
+    responses(
+        (status = 204, description = "Successfully subscribed to category"),
+        (status = 401, description = "User not authenticated", body = ErrorDto),
+        (status = 403, description = "User can't view the category", body = ErrorDto),
+        (status = 404, description = "Category not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn subscribe_category(
+    State(state): State<AppState>,
+    Path((guild_id, category_id)): Path<(u64, i32)>,
+    session: Session,
+) -> Result<impl IntoResponse, AppError> {
+    let user = AuthGuard::new(&state.db, &session)
+        .require(&[Permission::CategoryView(guild_id, category_id)])
+        .await?;
+
+    SubscriptionService::new(&state.db)
+        .subscribe(
+            user.discord_id,
+            guild_id,
+            SubscriptionTarget::Category(category_id),
+        )
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Unsubscribe the current user from a fleet category.
+///
+/// # Access Control
+/// - `LoggedIn` - User must be authenticated
+///
+/// # Returns
+/// - `204 No Content` - No longer subscribed to the category
+/// - `401 Unauthorized` - User not authenticated
+/// - `500 Internal Server Error` - Database error
+#[utoipa::path(
+    delete,
+    path = "/api/user/guilds/{guild_id}/categories/{category_id}/subscription",
+    tag = USER_TAG,
+    params(
+        ("guild_id" = u64, Path, description = "Discord guild ID"),
+        ("category_id" = i32, Path, description = "Fleet category ID")
+    ),
+    responses(
+        (status = 204, description = "Successfully unsubscribed from category"),
+        (status = 401, description = "User not authenticated", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn unsubscribe_category(
+    State(state): State<AppState>,
+    Path((_guild_id, category_id)): Path<(u64, i32)>,
+    session: Session,
+) -> Result<impl IntoResponse, AppError> {
+    let user = AuthGuard::new(&state.db, &session).require(&[]).await?;
+
+    SubscriptionService::new(&state.db)
+        .unsubscribe(user.discord_id, SubscriptionTarget::Category(category_id))
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Subscribe the current user to an individual fleet.
+///
+/// # Access Control
+/// - `LoggedIn` - User must be authenticated and able to view the fleet
+///
+/// # Returns
+/// - `204 No Content` - Subscribed to the fleet
+/// - `401 Unauthorized` - User not authenticated
+/// - `404 Not Found` - Fleet doesn't exist in the guild OR user can't view it
+/// - `500 Internal Server Error` - Database error
+#[utoipa::path(
+    put,
+    path = "/api/user/guilds/{guild_id}/fleets/{fleet_id}/subscription",
+    tag = USER_TAG,
+    params(
+        ("guild_id" = u64, Path, description = "Discord guild ID"),
+        ("fleet_id" = i32, Path, description = "Fleet ID")
+    ),
+    responses(
+        (status = 204, description = "Successfully subscribed to fleet"),
+        (status = 401, description = "User not authenticated", body = ErrorDto),
+        (status = 404, description = "Fleet not found or user lacks permission", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn subscribe_fleet(
+    State(state): State<AppState>,
+    Path((guild_id, fleet_id)): Path<(u64, i32)>,
+    session: Session,
+) -> Result<impl IntoResponse, AppError> {
+    let user = AuthGuard::new(&state.db, &session).require(&[]).await?;
+
+    // Applies category permissions & hidden fleet visibility rules
+    FleetService::new(&state.db, state.discord_http.clone(), state.app_url.clone())
+        .get_by_id(fleet_id, user.discord_id, user.admin)
+        .await?
+        .ok_or_else(|| AppError::NotFound("Fleet not found".to_string()))?;
+
+    SubscriptionService::new(&state.db)
+        .subscribe(
+            user.discord_id,
+            guild_id,
+            SubscriptionTarget::Fleet(fleet_id),
+        )
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Unsubscribe the current user from an individual fleet.
+///
+/// # Access Control
+/// - `LoggedIn` - User must be authenticated
+///
+/// # Returns
+/// - `204 No Content` - No longer subscribed to the fleet
+/// - `401 Unauthorized` - User not authenticated
+/// - `500 Internal Server Error` - Database error
+#[utoipa::path(
+    delete,
+    path = "/api/user/guilds/{guild_id}/fleets/{fleet_id}/subscription",
+    tag = USER_TAG,
+    params(
+        ("guild_id" = u64, Path, description = "Discord guild ID"),
+        ("fleet_id" = i32, Path, description = "Fleet ID")
+    ),
+    responses(
+        (status = 204, description = "Successfully unsubscribed from fleet"),
+        (status = 401, description = "User not authenticated", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn unsubscribe_fleet(
+    State(state): State<AppState>,
+    Path((_guild_id, fleet_id)): Path<(u64, i32)>,
+    session: Session,
+) -> Result<impl IntoResponse, AppError> {
+    let user = AuthGuard::new(&state.db, &session).require(&[]).await?;
+
+    SubscriptionService::new(&state.db)
+        .unsubscribe(user.discord_id, SubscriptionTarget::Fleet(fleet_id))
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,223 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use tower_sessions::Session;
+
+use crate::{
+    model::{
+        api::ErrorDto,
+        webhook::{
+            CreateWebhookDto, PaginatedWebhookDeliveriesDto, PaginatedWebhooksDto,
+            UpdateWebhookDto, WebhookDeliveryDto, WebhookDto,
+        },
+    },
+    server::{
+        controller::param::PaginationParam,
+        error::AppError,
+        middleware::auth::{AuthGuard, Permission},
+        model::webhook::{CreateWebhookParam, UpdateWebhookParam},
+        service::webhook::WebhookService,
+        state::AppState,
+    },
+};
+
+pub static WEBHOOK_TAG: &str = "webhook";
+
+#[utoipa::path(
+    post,
+    path = "/api/admin/servers/{guild_id}/webhooks",
+    tag = WEBHOOK_TAG,
+    params(
+        ("guild_id" = u64, Path, description = "Discord guild ID")
+    ),
+    request_body = CreateWebhookDto,
+    responses(
+        (status = 201, description = "Successfully created webhook", body = WebhookDto),
+        (status = 400, description = "Invalid webhook data", body = ErrorDto),
+        (status = 401, description = "User not authenticated or not an admin", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn create_webhook(
+    State(state): State<AppState>,
+    session: Session,
+    Path(guild_id): Path<u64>,
+    Json(payload): Json<CreateWebhookDto>,
+) -> Result<impl IntoResponse, AppError> {
+    let _ = AuthGuard::new(&state.db, &session)
+        .require(&[Permission::Admin])
+        .await?;
+
+    let webhook = WebhookService::new(&state.db)
+        .create(guild_id, CreateWebhookParam::from(payload))
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(webhook.into_dto())))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/admin/servers/{guild_id}/webhooks",
+    tag = WEBHOOK_TAG,
+    params(
+        ("guild_id" = u64, Path, description = "Discord guild ID"),
+        ("page" = Option<u64>, Query, description = "Page number (default: 0)"),
+        ("entries" = Option<u64>, Query, description = "Items per page (default: 10)")
+    ),
+    responses(
+        (status = 200, description = "Successfully retrieved webhooks", body = PaginatedWebhooksDto),
+        (status = 401, description = "User not authenticated or not an admin", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn get_paginated_webhooks(
+    State(state): State<AppState>,
+    session: Session,
+    Path(guild_id): Path<u64>,
+    Query(pagination): Query<PaginationParam>,
+) -> Result<impl IntoResponse, AppError> {
+    let _ = AuthGuard::new(&state.db, &session)
+        .require(&[Permission::Admin])
+        .await?;
+
+    let page = WebhookService::new(&state.db)
+        .list_by_guild(guild_id, pagination.page, pagination.entries)
+        .await?;
+
+    let dto = page.map(|webhook| webhook.into_dto());
+
+    Ok((StatusCode::OK, Json(dto)))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/admin/servers/{guild_id}/webhooks/{id}",
+    tag = WEBHOOK_TAG,
+    params(
+        ("guild_id" = u64, Path, description = "Discord guild ID"),
+        ("id" = i32, Path, description = "Webhook ID"),
+    ),
+    request_body = UpdateWebhookDto,
+    responses(
+        (status = 200, description = "Successfully updated webhook", body = WebhookDto),
+        (status = 400, description = "Invalid webhook data", body = ErrorDto),
+        (status = 401, description = "User not authenticated or not an admin", body = ErrorDto),
+        (status = 404, description = "Webhook not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn update_webhook(
+    State(state): State<AppState>,
+    session: Session,
+    Path((guild_id, id)): Path<(u64, i32)>,
+    Json(payload): Json<UpdateWebhookDto>,
+) -> Result<impl IntoResponse, AppError> {
+    let _ = AuthGuard::new(&state.db, &session)
+        .require(&[Permission::Admin])
+        .await?;
+
+    let webhook = WebhookService::new(&state.db)
+        .update(guild_id, id, UpdateWebhookParam::from(payload))
+        .await?;
+
+    Ok((StatusCode::OK, Json(webhook.into_dto())))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/admin/servers/{guild_id}/webhooks/{id}",
+    tag = WEBHOOK_TAG,
+    params(
+        ("guild_id" = u64, Path, description = "Discord guild ID"),
+        ("id" = i32, Path, description = "Webhook ID"),
+    ),
+    responses(
+        (status = 204, description = "Successfully deleted webhook"),
+        (status = 401, description = "User not authenticated or not an admin", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn delete_webhook(
+    State(state): State<AppState>,
+    session: Session,
+    Path((guild_id, id)): Path<(u64, i32)>,
+) -> Result<impl IntoResponse, AppError> {
+    let _ = AuthGuard::new(&state.db, &session)
+        .require(&[Permission::Admin])
+        .await?;
+
+    WebhookService::new(&state.db).delete(guild_id, id).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/admin/servers/{guild_id}/webhooks/{id}/deliveries",
+    tag = WEBHOOK_TAG,
+    params(
+        ("guild_id" = u64, Path, description = "Discord guild ID"),
+        ("id" = i32, Path, description = "Webhook ID"),
+        ("page" = Option<u64>, Query, description = "Page number (default: 0)"),
+        ("entries" = Option<u64>, Query, description = "Items per page (default: 10)")
+    ),
+    responses(
+        (status = 200, description = "Successfully retrieved webhook deliveries", body = PaginatedWebhookDeliveriesDto),
+        (status = 401, description = "User not authenticated or not an admin", body = ErrorDto),
+        (status = 404, description = "Webhook not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn get_paginated_webhook_deliveries(
+    State(state): State<AppState>,
+    session: Session,
+    Path((guild_id, id)): Path<(u64, i32)>,
+    Query(pagination): Query<PaginationParam>,
+) -> Result<impl IntoResponse, AppError> {
+    let _ = AuthGuard::new(&state.db, &session)
+        .require(&[Permission::Admin])
+        .await?;
+
+    let page = WebhookService::new(&state.db)
+        .list_deliveries(guild_id, id, pagination.page, pagination.entries)
+        .await?;
+
+    let dto = page.map(|delivery| delivery.into_dto());
+
+    Ok((StatusCode::OK, Json(dto)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/admin/servers/{guild_id}/webhooks/{id}/deliveries/{delivery_id}/replay",
+    tag = WEBHOOK_TAG,
+    params(
+        ("guild_id" = u64, Path, description = "Discord guild ID"),
+        ("id" = i32, Path, description = "Webhook ID"),
+        ("delivery_id" = i32, Path, description = "Webhook delivery ID"),
+    ),
+    responses(
+        (status = 200, description = "Successfully queued delivery for replay", body = WebhookDeliveryDto),
+        (status = 401, description = "User not authenticated or not an admin", body = ErrorDto),
+        (status = 404, description = "Webhook or delivery not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn replay_webhook_delivery(
+    State(state): State<AppState>,
+    session: Session,
+    Path((guild_id, id, delivery_id)): Path<(u64, i32, i32)>,
+) -> Result<impl IntoResponse, AppError> {
+    let _ = AuthGuard::new(&state.db, &session)
+        .require(&[Permission::Admin])
+        .await?;
+
+    let delivery = WebhookService::new(&state.db)
+        .replay_delivery(guild_id, id, delivery_id)
+        .await?;
+
+    Ok((StatusCode::OK, Json(delivery.into_dto())))
+}
@@ -0,0 +1,219 @@
+//! Broadcast data repository for database operations
+//!
+//! Provides the `BroadcastRepository` for recording ad-hoc broadcasts, finding scheduled
+//! broadcasts which are due and keeping track of whether they were posted or cancelled.
+//! Handles the conversion of broadcast entity models into domain models for usage within
+//! services & controllers.
+
+use chrono::{DateTime, Utc};
+use sea_orm::{
+    sea_query::Expr, ActiveModelTrait, ActiveValue, ColumnTrait, DatabaseConnection, DbErr,
+    EntityTrait, QueryFilter, QueryOrder, QuerySelect,
+};
+
+use crate::server::{
+    error::AppError,
+    model::broadcast::{Broadcast, CreateBroadcastParam},
+};
+
+/// Repository providing database operations for broadcasts.
+pub struct BroadcastRepository<'a> {
+    db: &'a DatabaseConnection,
+}
+
+impl<'a> BroadcastRepository<'a> {
+    /// Creates a new BroadcastRepository instance
+    ///
+    /// # Arguments
+    /// - `db` - Reference to the database connection
+    ///
+    /// # Returns
+    /// - `BroadcastRepository` - new repository instance
+    pub fn new(db: &'a DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// Records a new broadcast
+    ///
+    /// Broadcasts without a scheduled time are recorded as sent right away as they are
+    /// posted immediately, scheduled broadcasts stay pending until they are due.
+    ///
+    /// # Arguments
+    /// - `param` - Create parameters containing the broadcast
+    ///
+    /// # Returns
+    /// - `Ok(Broadcast)` - The recorded broadcast as a domain model
+    /// - `Err(AppError::Database)` - Database error during insert
+    pub async fn create(&self, param: CreateBroadcastParam) -> Result<Broadcast, AppError> {
+        let now = Utc::now();
+
+        let entity = entity::broadcast::ActiveModel {
+            guild_id: ActiveValue::Set(param.guild_id.to_string()),
+            author_id: ActiveValue::Set(Some(param.author_id.to_string())),
+            title: ActiveValue::Set(param.title),
+            message: ActiveValue::Set(param.message),
+            channel_ids: ActiveValue::Set(join_ids(&param.channel_ids)),
+            role_ids: ActiveValue::Set(join_ids(&param.role_ids)),
+            scheduled_at: ActiveValue::Set(param.scheduled_at),
+            sent_at: ActiveValue::Set(param.scheduled_at.is_none().then_some(now)),
+            cancelled_at: ActiveValue::Set(None),
+            posted_count: ActiveValue::Set(0),
+            created_at: ActiveValue::Set(now),
+            ..Default::default()
+        }
+        .insert(self.db)
+        .await?;
+
+        Broadcast::from_entity(entity)
+    }
+
+    /// Gets the most recent broadcasts of a guild, newest first
+    ///
+    /// # Arguments
+    /// - `guild_id` - The ID of the guild the broadcasts belong to
+    /// - `limit` - Maximum amount of broadcasts to return
+    ///
+    /// # Returns
+    /// - `Ok(Vec<Broadcast>)` - The broadcasts as domain models
+    /// - `Err(AppError::Database)` - Database error during query
+    pub async fn get_recent_by_guild(
+        &self,
+        guild_id: u64,
+        limit: u64,
+    ) -> Result<Vec<Broadcast>, AppError> {
+        entity::prelude::Broadcast::find()
+            .filter(entity::broadcast::Column::GuildId.eq(guild_id.to_string()))
+            .order_by_desc(entity::broadcast::Column::CreatedAt)
+            .order_by_desc(entity::broadcast::Column::Id)
+            .limit(limit)
+            .all(self.db)
+            .await?
+            .into_iter()
+            .map(Broadcast::from_entity)
+            .collect()
+    }
+
+    /// Finds a broadcast by ID within a specific guild
+    ///
+    /// # Arguments
+    /// - `guild_id` - The ID of the guild the broadcast belongs to
+    /// - `id` - The ID of the broadcast to retrieve
+    ///
+    /// # Returns
+    /// - `Ok(Some(Broadcast))` - The broadcast if found in the specified guild
+    /// - `Ok(None)` - If no broadcast exists with the given ID in the guild
+    /// - `Err(AppError::Database)` - Database error during fetch operation
+    pub async fn find_by_id(&self, guild_id: u64, id: i32) -> Result<Option<Broadcast>, AppError> {
+        entity::prelude::Broadcast::find_by_id(id)
+            .filter(entity::broadcast::Column::GuildId.eq(guild_id.to_string()))
+            .one(self.db)
+            .await?
+            .map(Broadcast::from_entity)
+            .transpose()
+    }
+
+    /// Gets the scheduled broadcasts which are due and neither sent nor cancelled
+    ///
+    /// # Arguments
+    /// - `now` - Current time, broadcasts scheduled at or before it are due
+    ///
+    /// # Returns
+    /// - `Ok(Vec<Broadcast>)` - The due broadcasts, oldest schedule first
+    /// - `Err(AppError::Database)` - Database error during query
+    pub async fn get_due(&self, now: DateTime<Utc>) -> Result<Vec<Broadcast>, AppError> {
+        entity::prelude::Broadcast::find()
+            .filter(entity::broadcast::Column::ScheduledAt.lte(now))
+            .filter(entity::broadcast::Column::SentAt.is_null())
+            .filter(entity::broadcast::Column::CancelledAt.is_null())
+            .order_by_asc(entity::broadcast::Column::ScheduledAt)
+            .all(self.db)
+            .await?
+            .into_iter()
+            .map(Broadcast::from_entity)
+            .collect()
+    }
+
+    /// Marks a pending broadcast as sent
+    ///
+    /// The check & update happen in a single statement so a broadcast is claimed for
+    /// posting once, even if it was cancelled concurrently or the scheduler overlaps.
+    ///
+    /// # Arguments
+    /// - `id` - The ID of the broadcast being posted
+    /// - `sent_at` - Time the broadcast is posted
+    ///
+    /// # Returns
+    /// - `Ok(true)` - The broadcast was claimed for posting
+    /// - `Ok(false)` - The broadcast doesn't exist, was already sent or was cancelled
+    /// - `Err(DbErr)` - Database error during update operation
+    pub async fn mark_sent(&self, id: i32, sent_at: DateTime<Utc>) -> Result<bool, DbErr> {
+        let result = entity::prelude::Broadcast::update_many()
+            .col_expr(entity::broadcast::Column::SentAt, Expr::value(sent_at))
+            .filter(entity::broadcast::Column::Id.eq(id))
+            .filter(entity::broadcast::Column::SentAt.is_null())
+            .filter(entity::broadcast::Column::CancelledAt.is_null())
+            .exec(self.db)
+            .await?;
+
+        Ok(result.rows_affected > 0)
+    }
+
+    /// Marks a pending broadcast as cancelled
+    ///
+    /// The check & update happen in a single statement so a broadcast which is being
+    /// posted can't be cancelled anymore.
+    ///
+    /// # Arguments
+    /// - `id` - The ID of the broadcast to cancel
+    /// - `cancelled_at` - Time the broadcast was cancelled
+    ///
+    /// # Returns
+    /// - `Ok(true)` - The broadcast was cancelled
+    /// - `Ok(false)` - The broadcast doesn't exist, was already sent or was cancelled
+    /// - `Err(DbErr)` - Database error during update operation
+    pub async fn mark_cancelled(
+        &self,
+        id: i32,
+        cancelled_at: DateTime<Utc>,
+    ) -> Result<bool, DbErr> {
+        let result = entity::prelude::Broadcast::update_many()
+            .col_expr(
+                entity::broadcast::Column::CancelledAt,
+                Expr::value(cancelled_at),
+            )
+            .filter(entity::broadcast::Column::Id.eq(id))
+            .filter(entity::broadcast::Column::SentAt.is_null())
+            .filter(entity::broadcast::Column::CancelledAt.is_null())
+            .exec(self.db)
+            .await?;
+
+        Ok(result.rows_affected > 0)
+    }
+
+    /// Records how many channels a broadcast was posted to
+    ///
+    /// # Arguments
+    /// - `id` - The ID of the posted broadcast
+    /// - `posted_count` - Amount of channels the broadcast was posted to
+    ///
+    /// # Returns
+    /// - `Ok(())` - The count was recorded
+    /// - `Err(DbErr)` - Database error during update operation
+    pub async fn set_posted_count(&self, id: i32, posted_count: i32) -> Result<(), DbErr> {
+        entity::prelude::Broadcast::update_many()
+            .col_expr(
+                entity::broadcast::Column::PostedCount,
+                Expr::value(posted_count),
+            )
+            .filter(entity::broadcast::Column::Id.eq(id))
+            .exec(self.db)
+            .await?;
+
+        Ok(())
+    }
+}
+
+/// Joins Discord IDs into the comma separated list stored in the database
+fn join_ids(ids: &[u64]) -> String {
+    ids.iter().map(u64::to_string).collect::<Vec<_>>().join(",")
+}
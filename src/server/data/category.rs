@@ -62,6 +62,7 @@ impl<'a> FleetCategoryRepository<'a> {
             ping_cooldown: ActiveValue::Set(params.ping_lead_time.map(|d| d.num_seconds() as i32)),
             ping_reminder: ActiveValue::Set(params.ping_reminder.map(|d| d.num_seconds() as i32)),
             max_pre_ping: ActiveValue::Set(params.max_pre_ping.map(|d| d.num_seconds() as i32)),
+            staging_system_id: ActiveValue::Set(params.staging_system_id.map(|id| id as i32)),
             ..Default::default()
         }
         .insert(self.db)
@@ -75,6 +76,7 @@ impl<'a> FleetCategoryRepository<'a> {
                 can_view: ActiveValue::Set(access_role.can_view),
                 can_create: ActiveValue::Set(access_role.can_create),
                 can_manage: ActiveValue::Set(access_role.can_manage),
+                can_broadcast: ActiveValue::Set(access_role.can_broadcast),
             }
             .insert(self.db)
             .await?;
@@ -91,10 +93,11 @@ impl<'a> FleetCategoryRepository<'a> {
         }
 
         // Insert channels
-        for channel_id in params.channels {
+        for channel in params.channels {
             entity::fleet_category_channel::ActiveModel {
                 fleet_category_id: ActiveValue::Set(category.id),
-                channel_id: ActiveValue::Set(channel_id.to_string()),
+                channel_id: ActiveValue::Set(channel.channel_id.to_string()),
+                crosspost: ActiveValue::Set(channel.crosspost),
             }
             .insert(self.db)
             .await?;
@@ -338,6 +341,8 @@ impl<'a> FleetCategoryRepository<'a> {
             ActiveValue::Set(params.ping_reminder.map(|d| d.num_seconds() as i32));
         active_model.max_pre_ping =
             ActiveValue::Set(params.max_pre_ping.map(|d| d.num_seconds() as i32));
+        active_model.staging_system_id =
+            ActiveValue::Set(params.staging_system_id.map(|id| id as i32));
 
         let updated_category = active_model.update(self.db).await?;
 
@@ -365,6 +370,7 @@ impl<'a> FleetCategoryRepository<'a> {
                 can_view: ActiveValue::Set(access_role.can_view),
                 can_create: ActiveValue::Set(access_role.can_create),
                 can_manage: ActiveValue::Set(access_role.can_manage),
+                can_broadcast: ActiveValue::Set(access_role.can_broadcast),
             }
             .insert(self.db)
             .await?;
@@ -381,10 +387,11 @@ impl<'a> FleetCategoryRepository<'a> {
         }
 
         // Insert new channels
-        for channel_id in params.channels {
+        for channel in params.channels {
             entity::fleet_category_channel::ActiveModel {
                 fleet_category_id: ActiveValue::Set(params.id),
-                channel_id: ActiveValue::Set(channel_id.to_string()),
+                channel_id: ActiveValue::Set(channel.channel_id.to_string()),
+                crosspost: ActiveValue::Set(channel.crosspost),
             }
             .insert(self.db)
             .await?;
@@ -484,6 +491,52 @@ impl<'a> FleetCategoryRepository<'a> {
             .collect())
     }
 
+    /// Gets the IDs of all fleet categories of a guild.
+    ///
+    /// # Arguments
+    /// - `guild_id` - Discord guild ID
+    ///
+    /// # Returns
+    /// - `Ok(Vec<i32>)` - IDs of the guild's categories
+    /// - `Err(DbErr)` - Database error during query
+    pub async fn get_ids_by_guild(&self, guild_id: u64) -> Result<Vec<i32>, DbErr> {
+        let categories = entity::prelude::FleetCategory::find()
+            .filter(entity::fleet_category::Column::GuildId.eq(guild_id.to_string()))
+            .all(self.db)
+            .await?;
+
+        Ok(categories.into_iter().map(|c| c.id).collect())
+    }
+
+    /// Gets the IDs of the fleet categories of a guild grouped by their ping group.
+    ///
+    /// # Arguments
+    /// - `guild_id` - Discord guild ID
+    ///
+    /// # Returns
+    /// - `Ok(HashMap<i32, Vec<i32>>)` - Map of ping group ID to the IDs of its categories,
+    ///   categories without a ping group are left out
+    /// - `Err(DbErr)` - Database error during query
+    pub async fn get_ids_by_ping_group(
+        &self,
+        guild_id: u64,
+    ) -> Result<HashMap<i32, Vec<i32>>, DbErr> {
+        let categories = entity::prelude::FleetCategory::find()
+            .filter(entity::fleet_category::Column::GuildId.eq(guild_id.to_string()))
+            .filter(entity::fleet_category::Column::PingGroupId.is_not_null())
+            .all(self.db)
+            .await?;
+
+        let mut groups: HashMap<i32, Vec<i32>> = HashMap::new();
+        for category in categories {
+            if let Some(ping_group_id) = category.ping_group_id {
+                groups.entry(ping_group_id).or_default().push(category.id);
+            }
+        }
+
+        Ok(groups)
+    }
+
     /// Gets category data by ID as a simple map for lookups.
     ///
     /// Retrieves multiple categories and returns them as a HashMap keyed by ID,
@@ -65,7 +65,7 @@ impl<'a> ChannelFleetListRepository<'a> {
     /// Creates or updates the fleet list message for a channel.
     ///
     /// Performs an upsert operation: if a fleet list record already exists for the channel,
-    /// updates the message ID and `updated_at` timestamp; otherwise, creates a new record.
+    /// updates the message IDs and `updated_at` timestamp; otherwise, creates a new record.
     /// The `last_message_at` field is preserved from the existing record (or set to now for
     /// new records) since it should only be updated by the message handler when OTHER messages
     /// arrive in the channel.
@@ -84,6 +84,12 @@ impl<'a> ChannelFleetListRepository<'a> {
         let existing = self.get_by_channel_id(param.channel_id).await?;
 
         let now = Utc::now();
+        let overflow_message_ids = param
+            .overflow_message_ids
+            .iter()
+            .map(|id| id.to_string())
+            .collect::<Vec<_>>()
+            .join(",");
 
         let entity = if let Some(existing) = existing {
             // Update existing record
@@ -95,6 +101,7 @@ impl<'a> ChannelFleetListRepository<'a> {
                     last_message_at: ActiveValue::Set(existing.last_message_at), // Preserve existing value
                     created_at: ActiveValue::Set(existing.created_at),
                     updated_at: ActiveValue::Set(now),
+                    overflow_message_ids: ActiveValue::Set(overflow_message_ids),
                 };
             active.update(self.db).await?
         } else {
@@ -106,6 +113,7 @@ impl<'a> ChannelFleetListRepository<'a> {
                 last_message_at: ActiveValue::Set(now),
                 created_at: ActiveValue::Set(now),
                 updated_at: ActiveValue::Set(now),
+                overflow_message_ids: ActiveValue::Set(overflow_message_ids),
             };
             new_record.insert(self.db).await?
         };
@@ -0,0 +1,91 @@
+//! Channel list settings data repository for database operations.
+//!
+//! Provides the `ChannelListSettingsRepository` for reading & storing how the upcoming
+//! fleets list of a channel is built. Channels without a stored row fall back to the
+//! default settings.
+
+use migration::OnConflict;
+use sea_orm::{ActiveValue, DatabaseConnection, EntityTrait};
+
+use crate::server::{
+    error::AppError,
+    model::channel_list_settings::{ChannelListSettings, UpsertChannelListSettingsParam},
+};
+
+/// Repository providing database operations for channel list settings.
+pub struct ChannelListSettingsRepository<'a> {
+    db: &'a DatabaseConnection,
+}
+
+impl<'a> ChannelListSettingsRepository<'a> {
+    /// Creates a new ChannelListSettingsRepository instance
+    ///
+    /// # Arguments
+    /// - `db` - Reference to the database connection
+    ///
+    /// # Returns
+    /// - `ChannelListSettingsRepository` - New repository instance
+    pub fn new(db: &'a DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// Gets the list settings of a channel, falling back to the defaults
+    ///
+    /// # Arguments
+    /// - `channel_id` - Discord ID of the channel
+    ///
+    /// # Returns
+    /// - `Ok(ChannelListSettings)` - Stored or default settings of the channel
+    /// - `Err(AppError::Database)` - Database error during fetch operation
+    pub async fn get_by_channel(&self, channel_id: u64) -> Result<ChannelListSettings, AppError> {
+        let entity = entity::prelude::ChannelListSettings::find_by_id(channel_id.to_string())
+            .one(self.db)
+            .await?;
+
+        match entity {
+            Some(entity) => ChannelListSettings::from_entity(entity),
+            None => Ok(ChannelListSettings::default_for(channel_id)),
+        }
+    }
+
+    /// Stores the list settings of a channel
+    ///
+    /// # Arguments
+    /// - `param` - The settings to store
+    ///
+    /// # Returns
+    /// - `Ok(ChannelListSettings)` - The stored settings
+    /// - `Err(AppError::Database)` - Database error during upsert operation
+    pub async fn upsert(
+        &self,
+        param: UpsertChannelListSettingsParam,
+    ) -> Result<ChannelListSettings, AppError> {
+        use entity::channel_list_settings::Column;
+
+        let entity = entity::prelude::ChannelListSettings::insert(
+            entity::channel_list_settings::ActiveModel {
+                channel_id: ActiveValue::Set(param.channel_id.to_string()),
+                lookahead_hours: ActiveValue::Set(param.lookahead_hours),
+                max_entries: ActiveValue::Set(param.max_entries),
+                group_by_day: ActiveValue::Set(param.group_by_day),
+                split_by_category: ActiveValue::Set(param.split_by_category),
+                show_revealed_hidden: ActiveValue::Set(param.show_revealed_hidden),
+            },
+        )
+        .on_conflict(
+            OnConflict::column(Column::ChannelId)
+                .update_columns([
+                    Column::LookaheadHours,
+                    Column::MaxEntries,
+                    Column::GroupByDay,
+                    Column::SplitByCategory,
+                    Column::ShowRevealedHidden,
+                ])
+                .to_owned(),
+        )
+        .exec_with_returning(self.db)
+        .await?;
+
+        ChannelListSettings::from_entity(entity)
+    }
+}
@@ -1,4 +1,7 @@
-use crate::server::model::discord::DiscordGuildChannel;
+use crate::{
+    model::discord::DiscordChannelKind,
+    server::model::discord::{channel::channel_kind, DiscordGuildChannel},
+};
 use migration::OnConflict;
 use sea_orm::{ActiveValue, ColumnTrait, DatabaseConnection, DbErr, EntityTrait, QueryFilter};
 use serenity::all::GuildChannel;
@@ -24,9 +27,10 @@ impl<'a> DiscordGuildChannelRepository<'a> {
 
     /// Upserts a single channel from Discord API data.
     ///
-    /// Creates or updates a channel record in the database. Updates the channel's name and
-    /// position if it already exists based on channel_id. Converts the entity model to a
-    /// domain model at the repository boundary.
+    /// Creates or updates a channel record in the database. Updates the channel's name,
+    /// position and kind if it already exists based on channel_id. Unsupported channel
+    /// types are stored as text channels, callers are expected to filter them beforehand.
+    /// Converts the entity model to a domain model at the repository boundary.
     ///
     /// # Arguments
     /// - `guild_id` - Discord's unique identifier for the guild
@@ -46,6 +50,12 @@ impl<'a> DiscordGuildChannelRepository<'a> {
                 channel_id: ActiveValue::Set(channel.id.get().to_string()),
                 name: ActiveValue::Set(channel.name.clone()),
                 position: ActiveValue::Set(channel.position as i32),
+                kind: ActiveValue::Set(
+                    channel_kind(channel.kind)
+                        .unwrap_or(DiscordChannelKind::Text)
+                        .as_str()
+                        .to_string(),
+                ),
             },
         )
         .on_conflict(
@@ -53,6 +63,7 @@ impl<'a> DiscordGuildChannelRepository<'a> {
                 .update_columns([
                     entity::discord_guild_channel::Column::Name,
                     entity::discord_guild_channel::Column::Position,
+                    entity::discord_guild_channel::Column::Kind,
                 ])
                 .to_owned(),
         )
@@ -81,6 +92,27 @@ impl<'a> DiscordGuildChannelRepository<'a> {
         Ok(())
     }
 
+    /// Finds a channel by its Discord channel ID.
+    ///
+    /// # Arguments
+    /// - `channel_id` - Discord's unique identifier for the channel
+    ///
+    /// # Returns
+    /// - `Ok(Some(DiscordGuildChannel))` - Channel found
+    /// - `Ok(None)` - Channel not found
+    /// - `Err(DbErr)` - Database error during query or entity conversion failure
+    pub async fn find_by_channel_id(
+        &self,
+        channel_id: u64,
+    ) -> Result<Option<DiscordGuildChannel>, DbErr> {
+        entity::prelude::DiscordGuildChannel::find()
+            .filter(entity::discord_guild_channel::Column::ChannelId.eq(channel_id.to_string()))
+            .one(self.db)
+            .await?
+            .map(DiscordGuildChannel::from_entity)
+            .transpose()
+    }
+
     /// Retrieves all channels for a specific guild.
     ///
     /// Fetches all channel records belonging to a guild, ordered by position for
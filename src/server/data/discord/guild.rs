@@ -2,7 +2,8 @@
 //!
 //! This module provides the `DiscordGuildRepository` for managing Discord guild
 //! (server) records in the database. It handles upserting guilds from Discord,
-//! tracking sync timestamps, and querying guilds by user membership. Guild data
+//! tracking sync timestamps and whether the bot is still a member of each guild, and
+//! querying guilds by user membership. Guild data
 //! is synced from Discord via Serenity and stored locally for display and to
 //! prevent excessive re-syncing.
 //!
@@ -10,11 +11,18 @@
 //! entity models internally to prevent database-specific structures from leaking
 //! into service and controller layers.
 
-use chrono::{Duration, Utc};
+use chrono::{DateTime, Duration, Utc};
 use migration::OnConflict;
-use sea_orm::{ActiveValue, ColumnTrait, DatabaseConnection, DbErr, EntityTrait, QueryFilter};
+use sea_orm::{
+    sea_query::{Expr, Query},
+    ActiveValue, ColumnTrait, DatabaseConnection, DbErr, EntityTrait, QueryFilter,
+    TransactionTrait,
+};
 
-use crate::server::model::discord::{DiscordGuild, UpsertGuildParam};
+use crate::{
+    model::discord::DiscordGuildStatus,
+    server::model::discord::{DiscordGuild, UpsertGuildParam},
+};
 
 /// Repository for Discord guild database operations.
 ///
@@ -39,7 +47,8 @@ impl<'a> DiscordGuildRepository<'a> {
     /// Creates a new guild record or updates an existing one based on guild_id.
     /// Updates name and icon_hash if the guild already exists. The last_sync_at
     /// timestamp is not updated by this method - use `update_last_sync()` after
-    /// completing a full sync.
+    /// completing a full sync. New guilds are created as active, the status of an
+    /// existing guild is left unchanged.
     ///
     /// # Arguments
     /// - `guild` - Serenity guild object containing guild data from Discord
@@ -57,6 +66,8 @@ impl<'a> DiscordGuildRepository<'a> {
             name: ActiveValue::Set(guild.name.to_string()),
             icon_hash: ActiveValue::Set(guild.icon_hash.as_ref().map(|i| i.to_string())),
             last_sync_at: ActiveValue::Set(default_last_sync),
+            status: ActiveValue::Set(DiscordGuildStatus::Active.as_str().to_string()),
+            removed_at: ActiveValue::Set(None),
         })
         .on_conflict(
             OnConflict::column(entity::discord_guild::Column::GuildId)
@@ -87,6 +98,26 @@ impl<'a> DiscordGuildRepository<'a> {
             .collect()
     }
 
+    /// Gets all guilds with an active timerboard.
+    ///
+    /// Excludes guilds the bot was removed from, including those awaiting an admin to
+    /// restore or purge their data after the bot was added back.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<DiscordGuild>)` - Vector of active guild records
+    /// - `Err(DbErr)` - Database error during query
+    pub async fn get_active(&self) -> Result<Vec<DiscordGuild>, DbErr> {
+        let entities = entity::prelude::DiscordGuild::find()
+            .filter(entity::discord_guild::Column::Status.eq(DiscordGuildStatus::Active.as_str()))
+            .all(self.db)
+            .await?;
+
+        entities
+            .into_iter()
+            .map(DiscordGuild::from_entity)
+            .collect()
+    }
+
     /// Finds a guild by its Discord guild ID.
     ///
     /// Searches for a guild in the database using the Discord-assigned guild ID.
@@ -111,9 +142,10 @@ impl<'a> DiscordGuildRepository<'a> {
 
     /// Gets all guilds for a specific user.
     ///
-    /// Retrieves all guilds that the specified user is a member of by joining
+    /// Retrieves all active guilds that the specified user is a member of by joining
     /// with the guild_member table. Used to determine which timerboards are
-    /// available to a user for display in guild selection interfaces.
+    /// available to a user for display in guild selection interfaces. Guilds the bot
+    /// was removed from are excluded.
     ///
     /// # Arguments
     /// - `user_id` - Discord user ID
@@ -125,6 +157,7 @@ impl<'a> DiscordGuildRepository<'a> {
         let entities = entity::prelude::DiscordGuild::find()
             .inner_join(entity::prelude::DiscordGuildMember)
             .filter(entity::discord_guild_member::Column::UserId.eq(user_id.to_string()))
+            .filter(entity::discord_guild::Column::Status.eq(DiscordGuildStatus::Active.as_str()))
             .all(self.db)
             .await?;
 
@@ -178,11 +211,188 @@ impl<'a> DiscordGuildRepository<'a> {
             .filter(entity::discord_guild::Column::GuildId.eq(guild_id.to_string()))
             .col_expr(
                 entity::discord_guild::Column::LastSyncAt,
-                sea_orm::sea_query::Expr::value(Utc::now().naive_utc()),
+                Expr::value(Utc::now().naive_utc()),
+            )
+            .exec(self.db)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Marks a guild as removed after the bot was kicked or the guild was deleted.
+    ///
+    /// The guild's data is kept so it can be restored if the bot is added back.
+    ///
+    /// # Arguments
+    /// - `guild_id` - Discord guild ID
+    /// - `removed_at` - When the bot was removed from the guild
+    ///
+    /// # Returns
+    /// - `Ok(true)` - Guild marked as removed
+    /// - `Ok(false)` - Guild not found
+    /// - `Err(DbErr)` - Database error during update
+    pub async fn mark_removed(
+        &self,
+        guild_id: u64,
+        removed_at: DateTime<Utc>,
+    ) -> Result<bool, DbErr> {
+        let result = entity::prelude::DiscordGuild::update_many()
+            .filter(entity::discord_guild::Column::GuildId.eq(guild_id.to_string()))
+            .col_expr(
+                entity::discord_guild::Column::Status,
+                Expr::value(DiscordGuildStatus::Removed.as_str()),
+            )
+            .col_expr(
+                entity::discord_guild::Column::RemovedAt,
+                Expr::value(removed_at),
+            )
+            .exec(self.db)
+            .await?;
+
+        Ok(result.rows_affected > 0)
+    }
+
+    /// Marks a removed guild as rejoined after the bot was added back to it.
+    ///
+    /// The guild stays hidden until an admin restores or purges its data. Guilds which
+    /// aren't marked as removed are left unchanged.
+    ///
+    /// # Arguments
+    /// - `guild_id` - Discord guild ID
+    ///
+    /// # Returns
+    /// - `Ok(true)` - Removed guild marked as rejoined
+    /// - `Ok(false)` - Guild not found or not removed
+    /// - `Err(DbErr)` - Database error during update
+    pub async fn mark_rejoined(&self, guild_id: u64) -> Result<bool, DbErr> {
+        let result = entity::prelude::DiscordGuild::update_many()
+            .filter(entity::discord_guild::Column::GuildId.eq(guild_id.to_string()))
+            .filter(entity::discord_guild::Column::Status.eq(DiscordGuildStatus::Removed.as_str()))
+            .col_expr(
+                entity::discord_guild::Column::Status,
+                Expr::value(DiscordGuildStatus::Rejoined.as_str()),
+            )
+            .exec(self.db)
+            .await?;
+
+        Ok(result.rows_affected > 0)
+    }
+
+    /// Marks a guild as active, clearing when the bot was removed from it.
+    ///
+    /// # Arguments
+    /// - `guild_id` - Discord guild ID
+    ///
+    /// # Returns
+    /// - `Ok(())` - Guild marked as active (or didn't exist)
+    /// - `Err(DbErr)` - Database error during update
+    pub async fn mark_active(&self, guild_id: u64) -> Result<(), DbErr> {
+        entity::prelude::DiscordGuild::update_many()
+            .filter(entity::discord_guild::Column::GuildId.eq(guild_id.to_string()))
+            .col_expr(
+                entity::discord_guild::Column::Status,
+                Expr::value(DiscordGuildStatus::Active.as_str()),
             )
+            .col_expr(
+                entity::discord_guild::Column::RemovedAt,
+                Expr::value(Option::<DateTime<Utc>>::None),
+            )
+            .exec(self.db)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Deletes a guild and all of its data.
+    ///
+    /// Roles, channels, members, categories, fleets, ping formats, ping groups and
+    /// webhooks are removed via database CASCADE constraints.
+    ///
+    /// # Arguments
+    /// - `guild_id` - Discord guild ID
+    ///
+    /// # Returns
+    /// - `Ok(())` - Guild deleted (or didn't exist)
+    /// - `Err(DbErr)` - Database error during deletion
+    pub async fn delete(&self, guild_id: u64) -> Result<(), DbErr> {
+        entity::prelude::DiscordGuild::delete_many()
+            .filter(entity::discord_guild::Column::GuildId.eq(guild_id.to_string()))
             .exec(self.db)
             .await?;
 
         Ok(())
     }
+
+    /// Deletes a guild's timerboard configuration while keeping the synced Discord data.
+    ///
+    /// Removes the guild's settings, stand-downs, broadcasts, doctrines, fleet relays into
+    /// the guild, fleet categories, ping groups, ping formats, webhooks and channel list
+    /// settings within a single transaction. Rows belonging to these, such as fleets,
+    /// templates, relayed fleets, doctrine fits, ping format fields & their values and
+    /// webhook deliveries, are removed via database CASCADE constraints. Roles, channels
+    /// and members are kept.
+    ///
+    /// # Arguments
+    /// - `guild_id` - Discord guild ID
+    ///
+    /// # Returns
+    /// - `Ok(())` - Timerboard configuration deleted
+    /// - `Err(DbErr)` - Database error during deletion, nothing was deleted
+    pub async fn delete_timerboard_data(&self, guild_id: u64) -> Result<(), DbErr> {
+        let guild_id = guild_id.to_string();
+        let txn = self.db.begin().await?;
+
+        entity::prelude::GuildSettings::delete_many()
+            .filter(entity::guild_settings::Column::GuildId.eq(&guild_id))
+            .exec(&txn)
+            .await?;
+        entity::prelude::StandDown::delete_many()
+            .filter(entity::stand_down::Column::GuildId.eq(&guild_id))
+            .exec(&txn)
+            .await?;
+        entity::prelude::Broadcast::delete_many()
+            .filter(entity::broadcast::Column::GuildId.eq(&guild_id))
+            .exec(&txn)
+            .await?;
+        entity::prelude::Doctrine::delete_many()
+            .filter(entity::doctrine::Column::GuildId.eq(&guild_id))
+            .exec(&txn)
+            .await?;
+        entity::prelude::FleetRelay::delete_many()
+            .filter(entity::fleet_relay::Column::TargetGuildId.eq(&guild_id))
+            .exec(&txn)
+            .await?;
+        entity::prelude::FleetCategory::delete_many()
+            .filter(entity::fleet_category::Column::GuildId.eq(&guild_id))
+            .exec(&txn)
+            .await?;
+        entity::prelude::PingGroup::delete_many()
+            .filter(entity::ping_group::Column::GuildId.eq(&guild_id))
+            .exec(&txn)
+            .await?;
+        entity::prelude::PingFormat::delete_many()
+            .filter(entity::ping_format::Column::GuildId.eq(&guild_id))
+            .exec(&txn)
+            .await?;
+        entity::prelude::Webhook::delete_many()
+            .filter(entity::webhook::Column::GuildId.eq(&guild_id))
+            .exec(&txn)
+            .await?;
+        entity::prelude::ChannelListSettings::delete_many()
+            .filter(
+                entity::channel_list_settings::Column::ChannelId.in_subquery(
+                    Query::select()
+                        .column(entity::discord_guild_channel::Column::ChannelId)
+                        .from(entity::prelude::DiscordGuildChannel)
+                        .and_where(entity::discord_guild_channel::Column::GuildId.eq(&guild_id))
+                        .to_owned(),
+                ),
+            )
+            .exec(&txn)
+            .await?;
+
+        txn.commit().await?;
+
+        Ok(())
+    }
 }
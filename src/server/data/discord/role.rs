@@ -37,8 +37,9 @@ impl<'a> DiscordGuildRoleRepository<'a> {
     /// Upserts a Discord guild role (insert or update if exists).
     ///
     /// Creates a new role record or updates an existing one based on role_id.
-    /// Updates name, color, and position if the role already exists. This is
-    /// used when syncing roles from Discord to keep local data current.
+    /// Updates name, color, position, permissions and mentionability if the role
+    /// already exists. This is used when syncing roles from Discord to keep local
+    /// data current.
     ///
     /// # Arguments
     /// - `guild_id` - Discord guild ID that owns this role
@@ -55,6 +56,8 @@ impl<'a> DiscordGuildRoleRepository<'a> {
                 name: ActiveValue::Set(role.name.clone()),
                 color: ActiveValue::Set(format!("#{:06X}", role.colour.0)),
                 position: ActiveValue::Set(role.position as i16),
+                permissions: ActiveValue::Set(role.permissions.bits().to_string()),
+                mentionable: ActiveValue::Set(role.mentionable),
             })
             .on_conflict(
                 OnConflict::column(entity::discord_guild_role::Column::RoleId)
@@ -62,6 +65,8 @@ impl<'a> DiscordGuildRoleRepository<'a> {
                         entity::discord_guild_role::Column::Name,
                         entity::discord_guild_role::Column::Color,
                         entity::discord_guild_role::Column::Position,
+                        entity::discord_guild_role::Column::Permissions,
+                        entity::discord_guild_role::Column::Mentionable,
                     ])
                     .to_owned(),
             )
@@ -102,6 +107,8 @@ impl<'a> DiscordGuildRoleRepository<'a> {
                 name: ActiveValue::Set(role.name.clone()),
                 color: ActiveValue::Set(format!("#{:06X}", role.colour.0)),
                 position: ActiveValue::Set(role.position as i16),
+                permissions: ActiveValue::Set(role.permissions.bits().to_string()),
+                mentionable: ActiveValue::Set(role.mentionable),
             })
             .collect();
 
@@ -113,6 +120,8 @@ impl<'a> DiscordGuildRoleRepository<'a> {
                         entity::discord_guild_role::Column::Name,
                         entity::discord_guild_role::Column::Color,
                         entity::discord_guild_role::Column::Position,
+                        entity::discord_guild_role::Column::Permissions,
+                        entity::discord_guild_role::Column::Mentionable,
                     ])
                     .to_owned(),
             )
@@ -0,0 +1,65 @@
+use super::*;
+use test_utils::factory;
+
+use crate::model::discord::DiscordChannelKind;
+
+/// Tests finding an existing forum channel.
+///
+/// Verifies that the repository returns the channel with its stored kind
+/// when provided with a valid channel ID.
+///
+/// Expected: Ok(Some) with forum kind
+#[tokio::test]
+async fn finds_existing_channel() -> Result<(), DbErr> {
+    let test = TestBuilder::new()
+        .with_table(entity::prelude::DiscordGuild)
+        .with_table(entity::prelude::DiscordGuildChannel)
+        .build()
+        .await
+        .unwrap();
+    let db = test.db.as_ref().unwrap();
+
+    let guild = factory::create_guild(db).await?;
+    factory::discord_guild_channel::DiscordGuildChannelFactory::new(
+        db,
+        &guild.guild_id,
+        "987654321",
+    )
+    .kind("forum")
+    .build()
+    .await?;
+
+    let repo = DiscordGuildChannelRepository::new(db);
+    let channel = repo.find_by_channel_id(987654321).await?;
+
+    assert!(channel.is_some());
+    let channel = channel.unwrap();
+    assert_eq!(channel.channel_id, 987654321);
+    assert_eq!(channel.kind, DiscordChannelKind::Forum);
+
+    Ok(())
+}
+
+/// Tests finding a nonexistent channel.
+///
+/// Verifies that the repository returns None when no channel exists
+/// with the provided channel ID.
+///
+/// Expected: Ok(None)
+#[tokio::test]
+async fn returns_none_for_nonexistent_channel() -> Result<(), DbErr> {
+    let test = TestBuilder::new()
+        .with_table(entity::prelude::DiscordGuild)
+        .with_table(entity::prelude::DiscordGuildChannel)
+        .build()
+        .await
+        .unwrap();
+    let db = test.db.as_ref().unwrap();
+
+    let repo = DiscordGuildChannelRepository::new(db);
+    let channel = repo.find_by_channel_id(999999999).await?;
+
+    assert!(channel.is_none());
+
+    Ok(())
+}
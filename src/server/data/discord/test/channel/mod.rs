@@ -3,5 +3,6 @@ use sea_orm::{ColumnTrait, DbErr, EntityTrait, QueryFilter};
 use test_utils::builder::TestBuilder;
 
 mod delete;
+mod find_by_channel_id;
 mod get_by_guild_id;
 mod upsert;
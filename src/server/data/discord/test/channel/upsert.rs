@@ -1,6 +1,8 @@
 use super::*;
 use sea_orm::EntityTrait;
-use serenity::all::{ChannelId, GuildChannel};
+use serenity::all::{ChannelId, ChannelType, GuildChannel};
+
+use crate::model::discord::DiscordChannelKind;
 use test_utils::factory;
 
 /// Tests creating a new channel.
@@ -452,3 +454,75 @@ async fn upsert_is_idempotent() -> Result<(), DbErr> {
 
     Ok(())
 }
+
+/// Tests upserting an announcement channel.
+///
+/// Verifies that the channel kind is derived from the Discord channel type
+/// when creating a new channel.
+///
+/// Expected: Ok with announcement kind stored
+#[tokio::test]
+async fn stores_announcement_kind() -> Result<(), DbErr> {
+    let test = TestBuilder::new()
+        .with_table(entity::prelude::DiscordGuild)
+        .with_table(entity::prelude::DiscordGuildChannel)
+        .build()
+        .await
+        .unwrap();
+    let db = test.db.as_ref().unwrap();
+
+    let guild = factory::create_guild(db).await?;
+    let guild_id = guild.guild_id.parse::<u64>().unwrap();
+
+    let mut channel = GuildChannel::default();
+    channel.id = ChannelId::new(987654321);
+    channel.name = "announcements".to_string();
+    channel.kind = ChannelType::News;
+
+    let repo = DiscordGuildChannelRepository::new(db);
+    let created = repo.upsert(guild_id, &channel).await?;
+
+    assert_eq!(created.kind, DiscordChannelKind::Announcement);
+
+    let db_channel = entity::prelude::DiscordGuildChannel::find()
+        .one(db)
+        .await?
+        .unwrap();
+    assert_eq!(db_channel.kind, "announcement");
+
+    Ok(())
+}
+
+/// Tests upserting updates the channel kind.
+///
+/// Verifies that the kind of an existing channel is updated when the channel
+/// type changes in Discord.
+///
+/// Expected: Ok with kind updated to forum
+#[tokio::test]
+async fn updates_kind_on_conflict() -> Result<(), DbErr> {
+    let test = TestBuilder::new()
+        .with_table(entity::prelude::DiscordGuild)
+        .with_table(entity::prelude::DiscordGuildChannel)
+        .build()
+        .await
+        .unwrap();
+    let db = test.db.as_ref().unwrap();
+
+    let guild = factory::create_guild(db).await?;
+    let guild_id = guild.guild_id.parse::<u64>().unwrap();
+
+    factory::create_guild_channel(db, &guild.guild_id, "987654321").await?;
+
+    let mut channel = GuildChannel::default();
+    channel.id = ChannelId::new(987654321);
+    channel.name = "fleets".to_string();
+    channel.kind = ChannelType::Forum;
+
+    let repo = DiscordGuildChannelRepository::new(db);
+    let updated = repo.upsert(guild_id, &channel).await?;
+
+    assert_eq!(updated.kind, DiscordChannelKind::Forum);
+
+    Ok(())
+}
@@ -0,0 +1,229 @@
+use super::*;
+
+/// Tests deleting a guild's timerboard configuration.
+///
+/// Verifies that the guild's fleet categories and ping formats are deleted while
+/// the guild itself and the configuration of other guilds are kept.
+///
+/// Expected: Ok with only the other guild's data remaining
+#[tokio::test]
+async fn deletes_configuration_of_guild_only() -> Result<(), DbErr> {
+    let test = TestBuilder::new()
+        .with_fleet_tables()
+        .with_table(entity::prelude::PingGroup)
+        .with_table(entity::prelude::Webhook)
+        .with_table(entity::prelude::GuildSettings)
+        .with_table(entity::prelude::ChannelListSettings)
+        .with_table(entity::prelude::Doctrine)
+        .with_table(entity::prelude::Broadcast)
+        .with_table(entity::prelude::StandDown)
+        .with_table(entity::prelude::FleetRelay)
+        .build()
+        .await
+        .unwrap();
+    let db = test.db.as_ref().unwrap();
+
+    let guild = factory::discord_guild::create_guild(db).await?;
+    let other_guild = factory::discord_guild::create_guild(db).await?;
+
+    let ping_format = factory::ping_format::create_ping_format(db, &guild.guild_id).await?;
+    factory::fleet_category::create_category(db, &guild.guild_id, ping_format.id).await?;
+    let other_format = factory::ping_format::create_ping_format(db, &other_guild.guild_id).await?;
+    factory::fleet_category::create_category(db, &other_guild.guild_id, other_format.id).await?;
+
+    let repo = DiscordGuildRepository::new(db);
+    repo.delete_timerboard_data(guild.guild_id.parse::<u64>().unwrap())
+        .await?;
+
+    let categories = entity::prelude::FleetCategory::find().all(db).await?;
+    assert_eq!(categories.len(), 1);
+    assert_eq!(categories[0].guild_id, other_guild.guild_id);
+
+    let formats = entity::prelude::PingFormat::find().all(db).await?;
+    assert_eq!(formats.len(), 1);
+    assert_eq!(formats[0].guild_id, other_guild.guild_id);
+
+    let guilds = entity::prelude::DiscordGuild::find().all(db).await?;
+    assert_eq!(guilds.len(), 2);
+
+    Ok(())
+}
+
+/// Tests deleting every guild-scoped table of a guild's timerboard configuration.
+///
+/// Verifies that the guild's settings, doctrines, broadcasts, stand-downs, ping groups,
+/// webhooks, ping format field values, channel list settings and relays into the guild
+/// are deleted along with the categories and ping formats.
+///
+/// Expected: Ok with no timerboard data of the guild remaining
+#[tokio::test]
+async fn deletes_every_guild_scoped_table() -> Result<(), DbErr> {
+    let test = TestBuilder::new()
+        .with_fleet_tables()
+        .with_table(entity::prelude::PingFormatFieldValue)
+        .with_table(entity::prelude::PingGroup)
+        .with_table(entity::prelude::Webhook)
+        .with_table(entity::prelude::GuildSettings)
+        .with_table(entity::prelude::ChannelListSettings)
+        .with_table(entity::prelude::Doctrine)
+        .with_table(entity::prelude::Broadcast)
+        .with_table(entity::prelude::StandDown)
+        .with_table(entity::prelude::FleetRelay)
+        .build()
+        .await
+        .unwrap();
+    let db = test.db.as_ref().unwrap();
+
+    let guild = factory::discord_guild::create_guild(db).await?;
+    let other_guild = factory::discord_guild::create_guild(db).await?;
+    let ping_format = factory::ping_format::create_ping_format(db, &guild.guild_id).await?;
+    let field =
+        factory::ping_format_field::create_ping_format_field(db, ping_format.id, "Doctrine", 1)
+            .await?;
+    let other_format = factory::ping_format::create_ping_format(db, &other_guild.guild_id).await?;
+    let other_category =
+        factory::fleet_category::create_category(db, &other_guild.guild_id, other_format.id)
+            .await?;
+    let channel =
+        factory::discord_guild_channel::create_guild_channel(db, &guild.guild_id, "555").await?;
+
+    entity::ping_format_field_value::ActiveModel {
+        ping_format_field_id: ActiveValue::Set(field.id),
+        value: ActiveValue::Set("Ferox".to_string()),
+        ..Default::default()
+    }
+    .insert(db)
+    .await?;
+    entity::ping_group::ActiveModel {
+        guild_id: ActiveValue::Set(guild.guild_id.clone()),
+        name: ActiveValue::Set("Strat Op".to_string()),
+        cooldown: ActiveValue::Set(None),
+        ..Default::default()
+    }
+    .insert(db)
+    .await?;
+    entity::webhook::ActiveModel {
+        guild_id: ActiveValue::Set(guild.guild_id.clone()),
+        name: ActiveValue::Set("Alliance Bot".to_string()),
+        url: ActiveValue::Set("https://example.com/hook".to_string()),
+        secret: ActiveValue::Set("secret".to_string()),
+        events: ActiveValue::Set("[]".to_string()),
+        enabled: ActiveValue::Set(true),
+        created_at: ActiveValue::Set(Utc::now()),
+        ..Default::default()
+    }
+    .insert(db)
+    .await?;
+    entity::guild_settings::ActiveModel {
+        guild_id: ActiveValue::Set(guild.guild_id.clone()),
+        creation_color: ActiveValue::Set(0),
+        update_color: ActiveValue::Set(0),
+        reminder_color: ActiveValue::Set(0),
+        formup_color: ActiveValue::Set(0),
+        cancel_color: ActiveValue::Set(0),
+        list_color: ActiveValue::Set(0),
+        time_label: ActiveValue::Set("EVE Time".to_string()),
+        extra_timezones: ActiveValue::Set(String::new()),
+        list_lookahead_hours: ActiveValue::Set(None),
+        formup_catchup_minutes: ActiveValue::Set(0),
+        default_ping_format_id: ActiveValue::Set(Some(ping_format.id)),
+        staging_system_id: ActiveValue::Set(None),
+        downtime_start_minute: ActiveValue::Set(0),
+        downtime_duration_minutes: ActiveValue::Set(0),
+        downtime_policy: ActiveValue::Set("none".to_string()),
+        broadcast_role_ids: ActiveValue::Set(String::new()),
+    }
+    .insert(db)
+    .await?;
+    entity::channel_list_settings::ActiveModel {
+        channel_id: ActiveValue::Set(channel.channel_id.clone()),
+        lookahead_hours: ActiveValue::Set(None),
+        max_entries: ActiveValue::Set(None),
+        group_by_day: ActiveValue::Set(false),
+        split_by_category: ActiveValue::Set(false),
+        show_revealed_hidden: ActiveValue::Set(false),
+    }
+    .insert(db)
+    .await?;
+    entity::doctrine::ActiveModel {
+        guild_id: ActiveValue::Set(guild.guild_id.clone()),
+        name: ActiveValue::Set("Ferox Fleet".to_string()),
+        description: ActiveValue::Set(None),
+        ..Default::default()
+    }
+    .insert(db)
+    .await?;
+    entity::broadcast::ActiveModel {
+        guild_id: ActiveValue::Set(guild.guild_id.clone()),
+        author_id: ActiveValue::Set(None),
+        title: ActiveValue::Set("Form up".to_string()),
+        message: ActiveValue::Set("Undock now".to_string()),
+        channel_ids: ActiveValue::Set("[]".to_string()),
+        role_ids: ActiveValue::Set("[]".to_string()),
+        scheduled_at: ActiveValue::Set(None),
+        sent_at: ActiveValue::Set(None),
+        cancelled_at: ActiveValue::Set(None),
+        posted_count: ActiveValue::Set(0),
+        created_at: ActiveValue::Set(Utc::now()),
+        ..Default::default()
+    }
+    .insert(db)
+    .await?;
+    entity::stand_down::ActiveModel {
+        guild_id: ActiveValue::Set(guild.guild_id.clone()),
+        ping_group_id: ActiveValue::Set(None),
+        reason: ActiveValue::Set("Downtime".to_string()),
+        window_start: ActiveValue::Set(Utc::now()),
+        window_end: ActiveValue::Set(Utc::now()),
+        created_by: ActiveValue::Set(None),
+        created_at: ActiveValue::Set(Utc::now()),
+        resumed_at: ActiveValue::Set(None),
+        ..Default::default()
+    }
+    .insert(db)
+    .await?;
+    entity::fleet_relay::ActiveModel {
+        source_category_id: ActiveValue::Set(other_category.id),
+        target_guild_id: ActiveValue::Set(guild.guild_id.clone()),
+        target_category_id: ActiveValue::Set(None),
+        status: ActiveValue::Set("pending".to_string()),
+        created_at: ActiveValue::Set(Utc::now()),
+        ..Default::default()
+    }
+    .insert(db)
+    .await?;
+
+    let repo = DiscordGuildRepository::new(db);
+    repo.delete_timerboard_data(guild.guild_id.parse::<u64>().unwrap())
+        .await?;
+
+    assert_eq!(entity::prelude::PingFormat::find().count(db).await?, 1);
+    assert_eq!(
+        entity::prelude::PingFormatFieldValue::find()
+            .count(db)
+            .await?,
+        0
+    );
+    assert_eq!(entity::prelude::PingGroup::find().count(db).await?, 0);
+    assert_eq!(entity::prelude::Webhook::find().count(db).await?, 0);
+    assert_eq!(entity::prelude::GuildSettings::find().count(db).await?, 0);
+    assert_eq!(
+        entity::prelude::ChannelListSettings::find()
+            .count(db)
+            .await?,
+        0
+    );
+    assert_eq!(entity::prelude::Doctrine::find().count(db).await?, 0);
+    assert_eq!(entity::prelude::Broadcast::find().count(db).await?, 0);
+    assert_eq!(entity::prelude::StandDown::find().count(db).await?, 0);
+    assert_eq!(entity::prelude::FleetRelay::find().count(db).await?, 0);
+    assert_eq!(entity::prelude::FleetCategory::find().count(db).await?, 1);
+    assert_eq!(
+        entity::prelude::DiscordGuildChannel::find()
+            .count(db)
+            .await?,
+        1
+    );
+
+    Ok(())
+}
@@ -86,6 +86,8 @@ async fn returns_complete_guild_data() -> Result<(), DbErr> {
         name: sea_orm::ActiveValue::Set("Complete Guild".to_string()),
         icon_hash: sea_orm::ActiveValue::Set(Some("icon_hash".to_string())),
         last_sync_at: sea_orm::ActiveValue::Set(sync_time),
+        status: sea_orm::ActiveValue::Set("active".to_string()),
+        removed_at: sea_orm::ActiveValue::Set(None),
     }
     .insert(db)
     .await?;
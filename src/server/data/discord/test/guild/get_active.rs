@@ -0,0 +1,37 @@
+use super::*;
+
+/// Tests getting active guilds.
+///
+/// Verifies that guilds the bot was removed from, including ones awaiting an admin
+/// after the bot was added back, are excluded.
+///
+/// Expected: Ok with only the active guild
+#[tokio::test]
+async fn excludes_removed_and_rejoined_guilds() -> Result<(), DbErr> {
+    let test = TestBuilder::new()
+        .with_table(entity::prelude::DiscordGuild)
+        .build()
+        .await
+        .unwrap();
+    let db = test.db.as_ref().unwrap();
+
+    let active = factory::discord_guild::create_guild(db).await?;
+    factory::discord_guild::DiscordGuildFactory::new(db)
+        .status("removed")
+        .removed_at(Some(Utc::now()))
+        .build()
+        .await?;
+    factory::discord_guild::DiscordGuildFactory::new(db)
+        .status("rejoined")
+        .removed_at(Some(Utc::now()))
+        .build()
+        .await?;
+
+    let repo = DiscordGuildRepository::new(db);
+    let guilds = repo.get_active().await?;
+
+    assert_eq!(guilds.len(), 1);
+    assert_eq!(guilds[0].guild_id.to_string(), active.guild_id);
+
+    Ok(())
+}
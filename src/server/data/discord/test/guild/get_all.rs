@@ -224,6 +224,8 @@ async fn gets_guilds_with_various_sync_times() -> Result<(), DbErr> {
         name: sea_orm::ActiveValue::Set("Recently synced".to_string()),
         icon_hash: sea_orm::ActiveValue::Set(None),
         last_sync_at: sea_orm::ActiveValue::Set(now - chrono::Duration::minutes(5)),
+        status: sea_orm::ActiveValue::Set("active".to_string()),
+        removed_at: sea_orm::ActiveValue::Set(None),
     }
     .insert(db)
     .await?;
@@ -233,6 +235,8 @@ async fn gets_guilds_with_various_sync_times() -> Result<(), DbErr> {
         name: sea_orm::ActiveValue::Set("Old sync".to_string()),
         icon_hash: sea_orm::ActiveValue::Set(None),
         last_sync_at: sea_orm::ActiveValue::Set(now - chrono::Duration::hours(2)),
+        status: sea_orm::ActiveValue::Set("active".to_string()),
+        removed_at: sea_orm::ActiveValue::Set(None),
     }
     .insert(db)
     .await?;
@@ -242,6 +246,8 @@ async fn gets_guilds_with_various_sync_times() -> Result<(), DbErr> {
         name: sea_orm::ActiveValue::Set("Very old sync".to_string()),
         icon_hash: sea_orm::ActiveValue::Set(None),
         last_sync_at: sea_orm::ActiveValue::Set(now - chrono::Duration::days(7)),
+        status: sea_orm::ActiveValue::Set("active".to_string()),
+        removed_at: sea_orm::ActiveValue::Set(None),
     }
     .insert(db)
     .await?;
@@ -168,3 +168,45 @@ async fn gets_single_guild_for_user() -> Result<(), DbErr> {
 
     Ok(())
 }
+
+/// Tests getting guilds for user when the bot was removed from one of them.
+///
+/// Verifies that guilds which aren't active are hidden from their members.
+///
+/// Expected: Ok with only the active guild
+#[tokio::test]
+async fn excludes_removed_guilds() -> Result<(), DbErr> {
+    let test = TestBuilder::new()
+        .with_table(entity::prelude::DiscordGuild)
+        .with_table(entity::prelude::DiscordGuildMember)
+        .build()
+        .await
+        .unwrap();
+    let db = test.db.as_ref().unwrap();
+
+    let active = factory::discord_guild::create_guild(db).await?;
+    let removed = factory::discord_guild::DiscordGuildFactory::new(db)
+        .status("removed")
+        .removed_at(Some(Utc::now()))
+        .build()
+        .await?;
+
+    for guild_id in [&active.guild_id, &removed.guild_id] {
+        entity::discord_guild_member::ActiveModel {
+            guild_id: sea_orm::ActiveValue::Set(guild_id.clone()),
+            user_id: sea_orm::ActiveValue::Set("123456789".to_string()),
+            username: sea_orm::ActiveValue::Set("User123".to_string()),
+            nickname: sea_orm::ActiveValue::Set(None),
+        }
+        .insert(db)
+        .await?;
+    }
+
+    let repo = DiscordGuildRepository::new(db);
+    let guilds = repo.get_guilds_for_user(123456789).await?;
+
+    assert_eq!(guilds.len(), 1);
+    assert_eq!(guilds[0].guild_id.to_string(), active.guild_id);
+
+    Ok(())
+}
@@ -0,0 +1,72 @@
+use super::*;
+
+/// Tests marking a removed guild as rejoined.
+///
+/// Verifies that a guild the bot was removed from moves to the rejoined status
+/// while keeping its removal timestamp.
+///
+/// Expected: Ok(true) with status "rejoined"
+#[tokio::test]
+async fn marks_removed_guild_as_rejoined() -> Result<(), DbErr> {
+    let test = TestBuilder::new()
+        .with_table(entity::prelude::DiscordGuild)
+        .build()
+        .await
+        .unwrap();
+    let db = test.db.as_ref().unwrap();
+
+    let guild = factory::discord_guild::DiscordGuildFactory::new(db)
+        .status("removed")
+        .removed_at(Some(Utc::now()))
+        .build()
+        .await?;
+    let guild_id = guild.guild_id.parse::<u64>().unwrap();
+
+    let repo = DiscordGuildRepository::new(db);
+    let marked = repo.mark_rejoined(guild_id).await?;
+
+    assert!(marked);
+
+    let db_guild = entity::prelude::DiscordGuild::find()
+        .filter(entity::discord_guild::Column::GuildId.eq(&guild.guild_id))
+        .one(db)
+        .await?
+        .unwrap();
+    assert_eq!(db_guild.status, "rejoined");
+    assert!(db_guild.removed_at.is_some());
+
+    Ok(())
+}
+
+/// Tests marking an active guild as rejoined.
+///
+/// Verifies that guilds which weren't removed are left unchanged, such as when
+/// the bot receives a guild create event on startup.
+///
+/// Expected: Ok(false) with status still "active"
+#[tokio::test]
+async fn ignores_active_guild() -> Result<(), DbErr> {
+    let test = TestBuilder::new()
+        .with_table(entity::prelude::DiscordGuild)
+        .build()
+        .await
+        .unwrap();
+    let db = test.db.as_ref().unwrap();
+
+    let guild = factory::discord_guild::create_guild(db).await?;
+    let guild_id = guild.guild_id.parse::<u64>().unwrap();
+
+    let repo = DiscordGuildRepository::new(db);
+    let marked = repo.mark_rejoined(guild_id).await?;
+
+    assert!(!marked);
+
+    let db_guild = entity::prelude::DiscordGuild::find()
+        .filter(entity::discord_guild::Column::GuildId.eq(&guild.guild_id))
+        .one(db)
+        .await?
+        .unwrap();
+    assert_eq!(db_guild.status, "active");
+
+    Ok(())
+}
@@ -0,0 +1,62 @@
+use super::*;
+use chrono::Duration;
+
+/// Tests marking a guild as removed.
+///
+/// Verifies that the guild's status is set to removed and that the removal
+/// timestamp is stored.
+///
+/// Expected: Ok(true) with status "removed" and removed_at set
+#[tokio::test]
+async fn marks_guild_as_removed() -> Result<(), DbErr> {
+    let test = TestBuilder::new()
+        .with_table(entity::prelude::DiscordGuild)
+        .build()
+        .await
+        .unwrap();
+    let db = test.db.as_ref().unwrap();
+
+    let guild = factory::discord_guild::create_guild(db).await?;
+    let guild_id = guild.guild_id.parse::<u64>().unwrap();
+    let removed_at = Utc::now() - Duration::minutes(5);
+
+    let repo = DiscordGuildRepository::new(db);
+    let marked = repo.mark_removed(guild_id, removed_at).await?;
+
+    assert!(marked);
+
+    let db_guild = entity::prelude::DiscordGuild::find()
+        .filter(entity::discord_guild::Column::GuildId.eq(&guild.guild_id))
+        .one(db)
+        .await?
+        .unwrap();
+    assert_eq!(db_guild.status, "removed");
+    assert_eq!(
+        db_guild.removed_at.map(|t| t.timestamp()),
+        Some(removed_at.timestamp())
+    );
+
+    Ok(())
+}
+
+/// Tests marking a guild which doesn't exist as removed.
+///
+/// Verifies that no rows are affected when the guild is not stored.
+///
+/// Expected: Ok(false)
+#[tokio::test]
+async fn returns_false_for_unknown_guild() -> Result<(), DbErr> {
+    let test = TestBuilder::new()
+        .with_table(entity::prelude::DiscordGuild)
+        .build()
+        .await
+        .unwrap();
+    let db = test.db.as_ref().unwrap();
+
+    let repo = DiscordGuildRepository::new(db);
+    let marked = repo.mark_removed(999999999, Utc::now()).await?;
+
+    assert!(!marked);
+
+    Ok(())
+}
@@ -1,11 +1,17 @@
 use crate::server::data::discord::guild::DiscordGuildRepository;
 use chrono::Utc;
-use sea_orm::{ColumnTrait, DbErr, EntityTrait, QueryFilter};
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, DbErr, EntityTrait, PaginatorTrait, QueryFilter,
+};
 use test_utils::{builder::TestBuilder, factory, mock::serenity::create_test_guild};
 
+mod delete_timerboard_data;
 mod find_by_guild_id;
+mod get_active;
 mod get_all;
 mod get_guilds_for_user;
+mod mark_rejoined;
+mod mark_removed;
 mod needs_sync;
 mod update_last_sync;
 mod upsert;
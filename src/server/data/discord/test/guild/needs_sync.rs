@@ -46,6 +46,8 @@ async fn no_sync_needed_for_recent_sync() -> Result<(), DbErr> {
         name: sea_orm::ActiveValue::Set("Test Guild".to_string()),
         icon_hash: sea_orm::ActiveValue::Set(None),
         last_sync_at: sea_orm::ActiveValue::Set(sync_time),
+        status: sea_orm::ActiveValue::Set("active".to_string()),
+        removed_at: sea_orm::ActiveValue::Set(None),
     }
     .insert(db)
     .await?;
@@ -80,6 +82,8 @@ async fn needs_sync_for_old_sync() -> Result<(), DbErr> {
         name: sea_orm::ActiveValue::Set("Test Guild".to_string()),
         icon_hash: sea_orm::ActiveValue::Set(None),
         last_sync_at: sea_orm::ActiveValue::Set(sync_time),
+        status: sea_orm::ActiveValue::Set("active".to_string()),
+        removed_at: sea_orm::ActiveValue::Set(None),
     }
     .insert(db)
     .await?;
@@ -114,6 +118,8 @@ async fn no_sync_needed_at_threshold() -> Result<(), DbErr> {
         name: sea_orm::ActiveValue::Set("Test Guild".to_string()),
         icon_hash: sea_orm::ActiveValue::Set(None),
         last_sync_at: sea_orm::ActiveValue::Set(sync_time),
+        status: sea_orm::ActiveValue::Set("active".to_string()),
+        removed_at: sea_orm::ActiveValue::Set(None),
     }
     .insert(db)
     .await?;
@@ -149,6 +155,8 @@ async fn needs_sync_for_very_old_sync() -> Result<(), DbErr> {
         name: sea_orm::ActiveValue::Set("Test Guild".to_string()),
         icon_hash: sea_orm::ActiveValue::Set(None),
         last_sync_at: sea_orm::ActiveValue::Set(sync_time),
+        status: sea_orm::ActiveValue::Set("active".to_string()),
+        removed_at: sea_orm::ActiveValue::Set(None),
     }
     .insert(db)
     .await?;
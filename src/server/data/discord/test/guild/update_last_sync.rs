@@ -23,6 +23,8 @@ async fn updates_last_sync_timestamp() -> Result<(), DbErr> {
         name: sea_orm::ActiveValue::Set("Test Guild".to_string()),
         icon_hash: sea_orm::ActiveValue::Set(None),
         last_sync_at: sea_orm::ActiveValue::Set(old_sync),
+        status: sea_orm::ActiveValue::Set("active".to_string()),
+        removed_at: sea_orm::ActiveValue::Set(None),
     }
     .insert(db)
     .await?;
@@ -99,6 +101,8 @@ async fn updates_only_specified_guild() -> Result<(), DbErr> {
         name: sea_orm::ActiveValue::Set("Guild 1".to_string()),
         icon_hash: sea_orm::ActiveValue::Set(None),
         last_sync_at: sea_orm::ActiveValue::Set(old_sync),
+        status: sea_orm::ActiveValue::Set("active".to_string()),
+        removed_at: sea_orm::ActiveValue::Set(None),
     }
     .insert(db)
     .await?;
@@ -108,6 +112,8 @@ async fn updates_only_specified_guild() -> Result<(), DbErr> {
         name: sea_orm::ActiveValue::Set("Guild 2".to_string()),
         icon_hash: sea_orm::ActiveValue::Set(None),
         last_sync_at: sea_orm::ActiveValue::Set(old_sync),
+        status: sea_orm::ActiveValue::Set("active".to_string()),
+        removed_at: sea_orm::ActiveValue::Set(None),
     }
     .insert(db)
     .await?;
@@ -159,6 +165,8 @@ async fn updates_multiple_times() -> Result<(), DbErr> {
         name: sea_orm::ActiveValue::Set("Test Guild".to_string()),
         icon_hash: sea_orm::ActiveValue::Set(None),
         last_sync_at: sea_orm::ActiveValue::Set(old_sync),
+        status: sea_orm::ActiveValue::Set("active".to_string()),
+        removed_at: sea_orm::ActiveValue::Set(None),
     }
     .insert(db)
     .await?;
@@ -214,6 +222,8 @@ async fn preserves_other_fields() -> Result<(), DbErr> {
         name: sea_orm::ActiveValue::Set("Test Guild".to_string()),
         icon_hash: sea_orm::ActiveValue::Set(Some("abc123".to_string())),
         last_sync_at: sea_orm::ActiveValue::Set(old_sync),
+        status: sea_orm::ActiveValue::Set("active".to_string()),
+        removed_at: sea_orm::ActiveValue::Set(None),
     }
     .insert(db)
     .await?;
@@ -294,6 +294,8 @@ async fn preserves_last_sync_at() -> Result<(), DbErr> {
         name: sea_orm::ActiveValue::Set("Test Guild".to_string()),
         icon_hash: sea_orm::ActiveValue::Set(None),
         last_sync_at: sea_orm::ActiveValue::Set(original_time),
+        status: sea_orm::ActiveValue::Set("active".to_string()),
+        removed_at: sea_orm::ActiveValue::Set(None),
     }
     .insert(db)
     .await?;
@@ -271,6 +271,7 @@ async fn cascades_to_category_access_roles() -> Result<(), DbErr> {
         can_view: sea_orm::ActiveValue::Set(true),
         can_create: sea_orm::ActiveValue::Set(true),
         can_manage: sea_orm::ActiveValue::Set(true),
+        can_broadcast: sea_orm::ActiveValue::Set(false),
     }
     .insert(db)
     .await?;
@@ -1,5 +1,6 @@
 use crate::server::data::discord::role::DiscordGuildRoleRepository;
 use sea_orm::{ActiveModelTrait, ColumnTrait, DbErr, EntityTrait, PaginatorTrait, QueryFilter};
+use serenity::all::Permissions;
 use test_utils::{builder::TestBuilder, factory, mock::serenity::create_test_role};
 
 mod delete;
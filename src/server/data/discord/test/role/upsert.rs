@@ -313,3 +313,44 @@ async fn upserts_role_with_special_characters() -> Result<(), DbErr> {
 
     Ok(())
 }
+
+/// Tests upserting a role's permissions and mentionability.
+///
+/// Verifies that the permissions bitfield and mentionable flag are stored on
+/// insert and updated when the role changes in Discord.
+///
+/// Expected: Ok with permissions and mentionable updated
+#[tokio::test]
+async fn upserts_permissions_and_mentionable() -> Result<(), DbErr> {
+    let test = TestBuilder::new()
+        .with_table(entity::prelude::DiscordGuild)
+        .with_table(entity::prelude::DiscordGuildRole)
+        .build()
+        .await
+        .unwrap();
+    let db = test.db.as_ref().unwrap();
+
+    let guild = factory::discord_guild::create_guild(db).await?;
+    let guild_id = guild.guild_id.parse::<u64>().unwrap();
+
+    let mut role = create_test_role(123456789, "Pings", 0, 1);
+    role.permissions = Permissions::SEND_MESSAGES | Permissions::EMBED_LINKS;
+
+    let repo = DiscordGuildRoleRepository::new(db);
+    let inserted = repo.upsert(guild_id, &role).await?;
+
+    assert_eq!(
+        inserted.permissions,
+        (Permissions::SEND_MESSAGES | Permissions::EMBED_LINKS).bits()
+    );
+    assert!(!inserted.mentionable);
+
+    role.permissions = Permissions::MENTION_EVERYONE;
+    role.mentionable = true;
+    let updated = repo.upsert(guild_id, &role).await?;
+
+    assert_eq!(updated.permissions, Permissions::MENTION_EVERYONE.bits());
+    assert!(updated.mentionable);
+
+    Ok(())
+}
@@ -0,0 +1,361 @@
+//! Doctrine data repository for database operations
+//!
+//! Provides the `DoctrineRepository` for managing doctrines and their ship fits in the
+//! database. Provides methods to create, get, update, and delete doctrines as well as
+//! handles the conversion of database entity models into domain models for usage within
+//! services & controllers.
+
+use std::collections::HashMap;
+
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, DatabaseConnection, DbErr, EntityTrait,
+    IntoActiveModel, PaginatorTrait, QueryFilter, QueryOrder,
+};
+
+use crate::server::{
+    error::AppError,
+    model::doctrine::{CreateDoctrineParam, Doctrine, DoctrineFit, FitParam, UpdateDoctrineParam},
+};
+
+/// Repository providing database operations for doctrine management.
+///
+/// This struct holds a reference to the database connection and provides methods
+/// for creating, reading, updating, and deleting doctrine records along with their
+/// fits & fit modules.
+pub struct DoctrineRepository<'a> {
+    db: &'a DatabaseConnection,
+}
+
+impl<'a> DoctrineRepository<'a> {
+    /// Creates a new DoctrineRepository instance
+    ///
+    /// # Arguments
+    /// - `db` - Reference to the database connection
+    ///
+    /// # Returns
+    /// - `DoctrineRepository` - new repository instance
+    pub fn new(db: &'a DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// Creates a new doctrine with its fits
+    ///
+    /// # Arguments
+    /// - `guild_id` - The ID of the guild the doctrine belongs to
+    /// - `param` - Create parameters containing the doctrine & its parsed fits
+    ///
+    /// # Returns
+    /// - `Ok(Doctrine)` - The created doctrine as a domain model
+    /// - `Err(AppError::Database)` - Database error during insert operation
+    pub async fn create(
+        &self,
+        guild_id: u64,
+        param: CreateDoctrineParam,
+    ) -> Result<Doctrine, AppError> {
+        let entity = entity::prelude::Doctrine::insert(entity::doctrine::ActiveModel {
+            guild_id: ActiveValue::Set(guild_id.to_string()),
+            name: ActiveValue::Set(param.name),
+            description: ActiveValue::Set(param.description),
+            ..Default::default()
+        })
+        .exec_with_returning(self.db)
+        .await?;
+
+        let fits = self.insert_fits(entity.id, param.fits).await?;
+
+        Doctrine::from_entity(entity, fits)
+    }
+
+    /// Gets a page of a guild's doctrines ordered by name
+    ///
+    /// # Arguments
+    /// - `guild_id` - The ID of the guild to list doctrines for
+    /// - `page` - Zero-based page number
+    /// - `per_page` - Number of doctrines per page
+    ///
+    /// # Returns
+    /// - `Ok((Vec<Doctrine>, u64))` - The doctrines of the page & total doctrine count
+    /// - `Err(AppError::Database)` - Database error during query
+    pub async fn list_by_guild(
+        &self,
+        guild_id: u64,
+        page: u64,
+        per_page: u64,
+    ) -> Result<(Vec<Doctrine>, u64), AppError> {
+        let paginator = entity::prelude::Doctrine::find()
+            .filter(entity::doctrine::Column::GuildId.eq(guild_id.to_string()))
+            .order_by_asc(entity::doctrine::Column::Name)
+            .paginate(self.db, per_page);
+
+        let total = paginator.num_items().await?;
+        let entities = paginator.fetch_page(page).await?;
+
+        Ok((self.with_fits(entities).await?, total))
+    }
+
+    /// Gets all doctrines of a guild ordered by name
+    ///
+    /// Used for the doctrine picker of the fleet form & to match doctrine names in
+    /// pasted pings.
+    ///
+    /// # Arguments
+    /// - `guild_id` - The ID of the guild to get doctrines for
+    ///
+    /// # Returns
+    /// - `Ok(Vec<Doctrine>)` - The guild's doctrines with their fits
+    /// - `Err(AppError::Database)` - Database error during query
+    pub async fn get_all_by_guild(&self, guild_id: u64) -> Result<Vec<Doctrine>, AppError> {
+        let entities = entity::prelude::Doctrine::find()
+            .filter(entity::doctrine::Column::GuildId.eq(guild_id.to_string()))
+            .order_by_asc(entity::doctrine::Column::Name)
+            .all(self.db)
+            .await?;
+
+        self.with_fits(entities).await
+    }
+
+    /// Gets the doctrines of the provided IDs within a guild
+    ///
+    /// IDs of doctrines that don't exist or belong to another guild are left out of the
+    /// result.
+    ///
+    /// # Arguments
+    /// - `guild_id` - The ID of the guild the doctrines belong to
+    /// - `ids` - IDs of the doctrines to get
+    ///
+    /// # Returns
+    /// - `Ok(HashMap<i32, Doctrine>)` - Map of doctrine ID to doctrine
+    /// - `Err(AppError::Database)` - Database error during query
+    pub async fn find_by_ids(
+        &self,
+        guild_id: u64,
+        ids: Vec<i32>,
+    ) -> Result<HashMap<i32, Doctrine>, AppError> {
+        if ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let entities = entity::prelude::Doctrine::find()
+            .filter(entity::doctrine::Column::GuildId.eq(guild_id.to_string()))
+            .filter(entity::doctrine::Column::Id.is_in(ids))
+            .all(self.db)
+            .await?;
+
+        Ok(self
+            .with_fits(entities)
+            .await?
+            .into_iter()
+            .map(|doctrine| (doctrine.id, doctrine))
+            .collect())
+    }
+
+    /// Checks whether a guild has a doctrine with the provided name
+    ///
+    /// # Arguments
+    /// - `guild_id` - The ID of the guild to check
+    /// - `name` - Doctrine name to look for
+    /// - `exclude_id` - ID of a doctrine to ignore, used when renaming it
+    ///
+    /// # Returns
+    /// - `Ok(bool)` - Whether another doctrine already uses the name
+    /// - `Err(AppError::Database)` - Database error during query
+    pub async fn name_exists(
+        &self,
+        guild_id: u64,
+        name: &str,
+        exclude_id: Option<i32>,
+    ) -> Result<bool, AppError> {
+        let mut query = entity::prelude::Doctrine::find()
+            .filter(entity::doctrine::Column::GuildId.eq(guild_id.to_string()))
+            .filter(entity::doctrine::Column::Name.eq(name));
+        if let Some(id) = exclude_id {
+            query = query.filter(entity::doctrine::Column::Id.ne(id));
+        }
+
+        Ok(query.count(self.db).await? > 0)
+    }
+
+    /// Counts the number of doctrines for a guild
+    pub async fn count_by_guild(&self, guild_id: u64) -> Result<usize, AppError> {
+        let count = entity::prelude::Doctrine::find()
+            .filter(entity::doctrine::Column::GuildId.eq(guild_id.to_string()))
+            .count(self.db)
+            .await?;
+
+        Ok(count as usize)
+    }
+
+    /// Updates the doctrine based upon provided ID & update parameters
+    ///
+    /// The doctrine's fits are replaced by the fits of the update parameters, their
+    /// modules are removed by the CASCADE foreign key constraint.
+    ///
+    /// # Arguments
+    /// - `guild_id` - The ID of the guild the doctrine belongs to
+    /// - `id` - ID of the doctrine to update
+    /// - `param` - Update parameters of the doctrine & its new fits
+    ///
+    /// # Returns
+    /// - `Ok(Doctrine)` - The updated doctrine as a domain model
+    /// - `Err(AppError::NotFound)` - No doctrine with the ID exists in the guild
+    /// - `Err(AppError::Database)` - Database error during update operation
+    pub async fn update(
+        &self,
+        guild_id: u64,
+        id: i32,
+        param: UpdateDoctrineParam,
+    ) -> Result<Doctrine, AppError> {
+        let Some(entity) = self.find_entity_by_id(guild_id, id).await? else {
+            return Err(AppError::NotFound(format!(
+                "Failed to find doctrine ID {} for guild ID {} while attempting update",
+                id, guild_id
+            )));
+        };
+
+        let mut active_model = entity.into_active_model();
+
+        active_model.name = ActiveValue::Set(param.name);
+        active_model.description = ActiveValue::Set(param.description);
+
+        let entity = active_model.update(self.db).await?;
+
+        entity::prelude::DoctrineFit::delete_many()
+            .filter(entity::doctrine_fit::Column::DoctrineId.eq(entity.id))
+            .exec(self.db)
+            .await?;
+        let fits = self.insert_fits(entity.id, param.fits).await?;
+
+        Doctrine::from_entity(entity, fits)
+    }
+
+    /// Deletes doctrine of the provided ID
+    ///
+    /// Fits & their modules are deleted due to CASCADE foreign key constraints. Fleets
+    /// keep the doctrine's ID as their field value & show it as an unknown doctrine.
+    ///
+    /// # Arguments
+    /// - `guild_id` - The ID of the guild the doctrine belongs to
+    /// - `id` - The ID of the doctrine to delete
+    ///
+    /// # Returns
+    /// - `Ok(()))` - The doctrine was successfully deleted
+    /// - `Err(AppError::Database)` - Database error during delete operation
+    pub async fn delete(&self, guild_id: u64, id: i32) -> Result<(), AppError> {
+        entity::prelude::Doctrine::delete_many()
+            .filter(entity::doctrine::Column::Id.eq(id))
+            .filter(entity::doctrine::Column::GuildId.eq(guild_id.to_string()))
+            .exec(self.db)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Helper method to find a doctrine entity by ID
+    async fn find_entity_by_id(
+        &self,
+        guild_id: u64,
+        id: i32,
+    ) -> Result<Option<entity::doctrine::Model>, DbErr> {
+        entity::prelude::Doctrine::find()
+            .filter(entity::doctrine::Column::GuildId.eq(guild_id.to_string()))
+            .filter(entity::doctrine::Column::Id.eq(id))
+            .one(self.db)
+            .await
+    }
+
+    /// Inserts fits & their modules for a doctrine in the order provided
+    async fn insert_fits(
+        &self,
+        doctrine_id: i32,
+        fits: Vec<FitParam>,
+    ) -> Result<Vec<DoctrineFit>, AppError> {
+        let mut inserted = Vec::with_capacity(fits.len());
+
+        for (position, fit) in fits.into_iter().enumerate() {
+            let entity = entity::doctrine_fit::ActiveModel {
+                doctrine_id: ActiveValue::Set(doctrine_id),
+                name: ActiveValue::Set(fit.name),
+                hull: ActiveValue::Set(fit.hull),
+                position: ActiveValue::Set(position as i32),
+                ..Default::default()
+            }
+            .insert(self.db)
+            .await?;
+
+            if !fit.modules.is_empty() {
+                entity::prelude::DoctrineFitModule::insert_many(
+                    fit.modules.iter().enumerate().map(|(position, module)| {
+                        entity::doctrine_fit_module::ActiveModel {
+                            fit_id: ActiveValue::Set(entity.id),
+                            section: ActiveValue::Set(module.section as i32),
+                            position: ActiveValue::Set(position as i32),
+                            name: ActiveValue::Set(module.name.clone()),
+                            charge: ActiveValue::Set(module.charge.clone()),
+                            quantity: ActiveValue::Set(module.quantity as i32),
+                            offline: ActiveValue::Set(module.offline),
+                            ..Default::default()
+                        }
+                    }),
+                )
+                .exec(self.db)
+                .await?;
+            }
+
+            inserted.push(DoctrineFit {
+                id: entity.id,
+                name: entity.name,
+                hull: entity.hull,
+                modules: fit.modules,
+            });
+        }
+
+        Ok(inserted)
+    }
+
+    /// Loads the fits & modules of doctrine entities & converts them to domain models
+    async fn with_fits(
+        &self,
+        entities: Vec<entity::doctrine::Model>,
+    ) -> Result<Vec<Doctrine>, AppError> {
+        if entities.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let doctrine_ids: Vec<i32> = entities.iter().map(|e| e.id).collect();
+        let fit_entities = entity::prelude::DoctrineFit::find()
+            .filter(entity::doctrine_fit::Column::DoctrineId.is_in(doctrine_ids))
+            .order_by_asc(entity::doctrine_fit::Column::Position)
+            .all(self.db)
+            .await?;
+
+        let mut modules: HashMap<i32, Vec<entity::doctrine_fit_module::Model>> = HashMap::new();
+        let fit_ids: Vec<i32> = fit_entities.iter().map(|e| e.id).collect();
+        if !fit_ids.is_empty() {
+            for module in entity::prelude::DoctrineFitModule::find()
+                .filter(entity::doctrine_fit_module::Column::FitId.is_in(fit_ids))
+                .order_by_asc(entity::doctrine_fit_module::Column::Section)
+                .order_by_asc(entity::doctrine_fit_module::Column::Position)
+                .all(self.db)
+                .await?
+            {
+                modules.entry(module.fit_id).or_default().push(module);
+            }
+        }
+
+        let mut fits: HashMap<i32, Vec<DoctrineFit>> = HashMap::new();
+        for fit in fit_entities {
+            let fit_modules = modules.remove(&fit.id).unwrap_or_default();
+            fits.entry(fit.doctrine_id)
+                .or_default()
+                .push(DoctrineFit::from_entity(fit, fit_modules));
+        }
+
+        entities
+            .into_iter()
+            .map(|entity| {
+                let doctrine_fits = fits.remove(&entity.id).unwrap_or_default();
+                Doctrine::from_entity(entity, doctrine_fits)
+            })
+            .collect()
+    }
+}
@@ -6,11 +6,12 @@
 //! with proper conversion between entity models and parameter models at the infrastructure
 //! boundary.
 
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use dioxus_logger::tracing;
 use sea_orm::{
-    ActiveModelTrait, ActiveValue, ColumnTrait, DatabaseConnection, DbErr, EntityTrait,
-    PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, RelationTrait,
+    sea_query::Expr, ActiveModelTrait, ActiveValue, ColumnTrait, ConnectionTrait,
+    DatabaseConnection, DbErr, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect,
+    RelationTrait, TransactionTrait,
 };
 use std::collections::HashMap;
 
@@ -63,6 +64,7 @@ impl<'a> FleetRepository<'a> {
             hidden: ActiveValue::Set(param.hidden),
             disable_reminder: ActiveValue::Set(param.disable_reminder),
             created_at: ActiveValue::Set(Utc::now()),
+            archived: ActiveValue::Set(false),
             ..Default::default()
         }
         .insert(self.db)
@@ -117,11 +119,11 @@ impl<'a> FleetRepository<'a> {
         }
     }
 
-    /// Gets upcoming non-hidden fleets for specific categories.
+    /// Gets upcoming fleets for specific categories.
     ///
     /// Retrieves all fleets for the specified category IDs that:
     /// - Have a fleet_time greater than the provided time
-    /// - Are not hidden
+    /// - Are not hidden, unless hidden fleets are requested
     /// - Are ordered by fleet_time in ascending order
     ///
     /// This is used for building the upcoming fleets list in Discord channels.
@@ -129,6 +131,7 @@ impl<'a> FleetRepository<'a> {
     /// # Arguments
     /// - `category_ids` - List of category IDs to filter by
     /// - `after_time` - Only include fleets with fleet_time after this time
+    /// - `include_hidden` - Whether hidden fleets are included
     ///
     /// # Returns
     /// - `Ok(Vec<Fleet>)` - Vector of upcoming fleets ordered by time
@@ -138,11 +141,16 @@ impl<'a> FleetRepository<'a> {
         &self,
         category_ids: Vec<i32>,
         after_time: chrono::DateTime<Utc>,
+        include_hidden: bool,
     ) -> Result<Vec<Fleet>, AppError> {
-        let entities = entity::prelude::Fleet::find()
+        let mut query = entity::prelude::Fleet::find()
             .filter(entity::fleet::Column::CategoryId.is_in(category_ids))
-            .filter(entity::fleet::Column::FleetTime.gt(after_time))
-            .filter(entity::fleet::Column::Hidden.eq(false))
+            .filter(entity::fleet::Column::FleetTime.gt(after_time));
+        if !include_hidden {
+            query = query.filter(entity::fleet::Column::Hidden.eq(false));
+        }
+
+        let entities = query
             .order_by_asc(entity::fleet::Column::FleetTime)
             .all(self.db)
             .await?;
@@ -222,22 +230,235 @@ impl<'a> FleetRepository<'a> {
         Ok((fleets, total))
     }
 
-    /// Deletes a fleet by ID.
+    /// Gets paginated fleets across several guilds, ordered by fleet_time (upcoming first).
     ///
-    /// Deletes the fleet with the specified ID. Associated field values and fleet messages
-    /// are automatically deleted due to CASCADE foreign key constraints.
+    /// Applies the same filtering as `get_paginated_by_guild` to the fleets of every
+    /// provided guild, used for the aggregated timerboard of users in several guilds.
     ///
     /// # Arguments
-    /// - `id` - ID of the fleet to delete
+    /// - `guild_ids` - Discord guild IDs to include fleets from
+    /// - `page` - Zero-indexed page number
+    /// - `per_page` - Number of fleets to return per page
+    /// - `viewable_category_ids` - Optional list of category IDs the user can view across
+    ///   the guilds (None means all categories - admin bypass)
     ///
     /// # Returns
-    /// - `Ok(())` - Fleet deleted successfully (or didn't exist)
-    /// - `Err(DbErr)` - Database error during delete operation
-    pub async fn delete(&self, id: i32) -> Result<(), DbErr> {
-        entity::prelude::Fleet::delete_by_id(id)
+    /// - `Ok((fleets, total))` - Vector of fleets for the page and total count
+    /// - `Err(DbErr)` - Database error during pagination query
+    pub async fn get_paginated_by_guilds(
+        &self,
+        guild_ids: &[u64],
+        page: u64,
+        per_page: u64,
+        viewable_category_ids: Option<Vec<i32>>,
+    ) -> Result<(Vec<Fleet>, u64), DbErr> {
+        use entity::fleet_category;
+        use sea_orm::JoinType;
+
+        if guild_ids.is_empty() {
+            return Ok((Vec::new(), 0));
+        }
+
+        let cutoff_time = Utc::now() - chrono::Duration::hours(1);
+
+        let mut query = entity::prelude::Fleet::find()
+            .join(
+                JoinType::InnerJoin,
+                entity::fleet::Relation::FleetCategory.def(),
+            )
+            .filter(
+                fleet_category::Column::GuildId.is_in(guild_ids.iter().map(|id| id.to_string())),
+            )
+            .filter(entity::fleet::Column::FleetTime.gte(cutoff_time))
+            .order_by_asc(entity::fleet::Column::FleetTime);
+
+        if let Some(category_ids) = viewable_category_ids {
+            if category_ids.is_empty() {
+                return Ok((Vec::new(), 0));
+            }
+            query = query.filter(entity::fleet::Column::CategoryId.is_in(category_ids));
+        }
+
+        let paginator = query.paginate(self.db, per_page);
+        let total = paginator.num_items().await?;
+        let entities = paginator.fetch_page(page).await?;
+        let fleets = entities
+            .into_iter()
+            .filter_map(|entity| match Fleet::from_entity(entity) {
+                Ok(fleet) => Some(fleet),
+                Err(e) => {
+                    tracing::error!("Failed to convert fleet entity to domain model: {}", e);
+                    None
+                }
+            })
+            .collect();
+
+        Ok((fleets, total))
+    }
+
+    /// Gets the fleets of a guild within a time range, ordered by fleet_time.
+    ///
+    /// Unlike `get_paginated_by_guild` past fleets are included, used for the calendar &
+    /// timeline views of the timerboard. Hidden fleets are included and must be filtered
+    /// by the caller.
+    ///
+    /// # Arguments
+    /// - `guild_id` - Discord guild ID as u64
+    /// - `start` - Only include fleets at or after this time
+    /// - `end` - Only include fleets before this time
+    /// - `viewable_category_ids` - Optional list of category IDs the user can view (None means all categories - admin bypass)
+    ///
+    /// # Returns
+    /// - `Ok(Vec<Fleet>)` - Fleets within the range ordered by time
+    /// - `Err(AppError::Database)` - Database error during query
+    /// - `Err(AppError::InternalError(ParseStringId))` - Failed to parse ID from String
+    pub async fn get_by_guild_in_range(
+        &self,
+        guild_id: u64,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        viewable_category_ids: Option<Vec<i32>>,
+    ) -> Result<Vec<Fleet>, AppError> {
+        use entity::fleet_category;
+        use sea_orm::JoinType;
+
+        let mut query = entity::prelude::Fleet::find()
+            .join(
+                JoinType::InnerJoin,
+                entity::fleet::Relation::FleetCategory.def(),
+            )
+            .filter(fleet_category::Column::GuildId.eq(guild_id.to_string()))
+            .filter(entity::fleet::Column::FleetTime.gte(start))
+            .filter(entity::fleet::Column::FleetTime.lt(end))
+            .filter(entity::fleet::Column::Archived.eq(false))
+            .order_by_asc(entity::fleet::Column::FleetTime);
+
+        if let Some(category_ids) = viewable_category_ids {
+            if category_ids.is_empty() {
+                return Ok(Vec::new());
+            }
+            query = query.filter(entity::fleet::Column::CategoryId.is_in(category_ids));
+        }
+
+        query
+            .all(self.db)
+            .await?
+            .into_iter()
+            .map(Fleet::from_entity)
+            .collect()
+    }
+
+    /// Gets every fleet of a guild together with its field values.
+    ///
+    /// Unlike `get_paginated_by_guild` this includes past and hidden fleets of all
+    /// categories, used for exporting a guild's fleet history. Field values are fetched
+    /// with a single query for all fleets rather than one query per fleet. Results are
+    /// ordered by fleet_time in ascending order.
+    ///
+    /// # Arguments
+    /// - `guild_id` - Discord guild ID as u64
+    ///
+    /// # Returns
+    /// - `Ok(Vec<(fleet, field_values)>)` - Fleets with their map of field_id to value
+    /// - `Err(AppError::Database)` - Database error during query
+    /// - `Err(AppError::InternalError(ParseStringId))` - Failed to parse ID from String
+    pub async fn get_all_by_guild(
+        &self,
+        guild_id: u64,
+    ) -> Result<Vec<(Fleet, HashMap<i32, String>)>, AppError> {
+        use entity::fleet_category;
+        use sea_orm::JoinType;
+
+        let entities = entity::prelude::Fleet::find()
+            .join(
+                JoinType::InnerJoin,
+                entity::fleet::Relation::FleetCategory.def(),
+            )
+            .filter(fleet_category::Column::GuildId.eq(guild_id.to_string()))
+            .order_by_asc(entity::fleet::Column::FleetTime)
+            .all(self.db)
+            .await?;
+
+        let fleet_ids: Vec<i32> = entities.iter().map(|e| e.id).collect();
+        let mut field_values = self.get_field_values_by_fleets(fleet_ids).await?;
+
+        entities
+            .into_iter()
+            .map(|entity| {
+                let values = field_values.remove(&entity.id).unwrap_or_default();
+                Ok((Fleet::from_entity(entity)?, values))
+            })
+            .collect()
+    }
+
+    /// Gets the field values of several fleets with a single query.
+    ///
+    /// # Arguments
+    /// - `fleet_ids` - IDs of the fleets to get the field values of
+    ///
+    /// # Returns
+    /// - `Ok(HashMap<i32, HashMap<i32, String>>)` - Map of fleet_id to its map of field_id
+    ///   to value, fleets without field values are left out
+    /// - `Err(DbErr)` - Database error during query
+    pub async fn get_field_values_by_fleets(
+        &self,
+        fleet_ids: Vec<i32>,
+    ) -> Result<HashMap<i32, HashMap<i32, String>>, DbErr> {
+        let mut field_values: HashMap<i32, HashMap<i32, String>> = HashMap::new();
+        if fleet_ids.is_empty() {
+            return Ok(field_values);
+        }
+
+        for fv in entity::prelude::FleetFieldValue::find()
+            .filter(entity::fleet_field_value::Column::FleetId.is_in(fleet_ids))
+            .all(self.db)
+            .await?
+        {
+            field_values
+                .entry(fv.fleet_id)
+                .or_default()
+                .insert(fv.field_id, fv.value);
+        }
+
+        Ok(field_values)
+    }
+
+    /// Counts unarchived fleets with a fleet time before the cutoff.
+    ///
+    /// # Arguments
+    /// - `cutoff` - Fleets scheduled before this time are counted
+    ///
+    /// # Returns
+    /// - `Ok(u64)` - Number of unarchived fleets scheduled before the cutoff
+    /// - `Err(DbErr)` - Database error during count query
+    pub async fn count_before(&self, cutoff: DateTime<Utc>) -> Result<u64, DbErr> {
+        entity::prelude::Fleet::find()
+            .filter(entity::fleet::Column::FleetTime.lt(cutoff))
+            .filter(entity::fleet::Column::Archived.eq(false))
+            .count(self.db)
+            .await
+    }
+
+    /// Archives fleets with a fleet time before the cutoff.
+    ///
+    /// Archived fleets keep their field values & messages but are excluded from the calendar
+    /// view. Fleets which are already archived are left untouched.
+    ///
+    /// # Arguments
+    /// - `cutoff` - Fleets scheduled before this time are archived
+    ///
+    /// # Returns
+    /// - `Ok(u64)` - Number of fleets newly archived
+    /// - `Err(DbErr)` - Database error during update operation
+    pub async fn archive_before(&self, cutoff: DateTime<Utc>) -> Result<u64, DbErr> {
+        let result = entity::prelude::Fleet::update_many()
+            .col_expr(entity::fleet::Column::Archived, Expr::value(true))
+            .filter(entity::fleet::Column::FleetTime.lt(cutoff))
+            .filter(entity::fleet::Column::Archived.eq(false))
             .exec(self.db)
             .await?;
-        Ok(())
+
+        Ok(result.rows_affected)
     }
 
     /// Updates a fleet.
@@ -255,9 +476,81 @@ impl<'a> FleetRepository<'a> {
     /// - `Err(DbErr)` - Other database error during update operation
     /// - `Err(AppError::InternalError(ParseStringId))` - Failed to parse ID from String
     pub async fn update(&self, params: UpdateFleetParam) -> Result<Fleet, AppError> {
+        Self::update_with(self.db, params).await
+    }
+
+    /// Updates multiple fleets within a single transaction.
+    ///
+    /// Applies each update the same way as `update`, either all fleets are updated or,
+    /// when any update fails, none of them are.
+    ///
+    /// # Arguments
+    /// - `params` - Update parameters for each fleet to update
+    ///
+    /// # Returns
+    /// - `Ok(Vec<Fleet>)` - The updated fleets in the order of the params
+    /// - `Err(DbErr::RecordNotFound)` - No fleet exists with one of the IDs, nothing was updated
+    /// - `Err(DbErr)` - Other database error, nothing was updated
+    /// - `Err(AppError::InternalError(ParseStringId))` - Failed to parse ID from String
+    pub async fn update_many(&self, params: Vec<UpdateFleetParam>) -> Result<Vec<Fleet>, AppError> {
+        let txn = self.db.begin().await?;
+
+        let mut fleets = Vec::with_capacity(params.len());
+        for param in params {
+            fleets.push(Self::update_with(&txn, param).await?);
+        }
+
+        txn.commit().await?;
+
+        Ok(fleets)
+    }
+
+    /// Deletes multiple fleets by ID.
+    ///
+    /// Field values and other records referencing the fleets are deleted along with them.
+    ///
+    /// # Arguments
+    /// - `ids` - IDs of the fleets to delete
+    ///
+    /// # Returns
+    /// - `Ok(u64)` - Number of fleets deleted
+    /// - `Err(DbErr)` - Database error during delete operation
+    pub async fn delete_many(&self, ids: Vec<i32>) -> Result<u64, DbErr> {
+        Self::delete_many_with(self.db, ids).await
+    }
+
+    /// Deletes multiple fleets by ID using the given connection or transaction.
+    ///
+    /// Lets other repositories delete fleets within their own transaction.
+    ///
+    /// # Arguments
+    /// - `db` - Connection or transaction to delete the fleets with
+    /// - `ids` - IDs of the fleets to delete
+    ///
+    /// # Returns
+    /// - `Ok(u64)` - Number of fleets deleted
+    /// - `Err(DbErr)` - Database error during delete operation
+    pub async fn delete_many_with<C: ConnectionTrait>(db: &C, ids: Vec<i32>) -> Result<u64, DbErr> {
+        if ids.is_empty() {
+            return Ok(0);
+        }
+
+        let result = entity::prelude::Fleet::delete_many()
+            .filter(entity::fleet::Column::Id.is_in(ids))
+            .exec(db)
+            .await?;
+
+        Ok(result.rows_affected)
+    }
+
+    /// Updates a fleet using the given connection or transaction.
+    async fn update_with<C: ConnectionTrait>(
+        db: &C,
+        params: UpdateFleetParam,
+    ) -> Result<Fleet, AppError> {
         let id = params.id;
         let fleet = entity::prelude::Fleet::find_by_id(id)
-            .one(self.db)
+            .one(db)
             .await?
             .ok_or(DbErr::RecordNotFound(format!("Fleet {} not found", id)))?;
 
@@ -282,14 +575,14 @@ impl<'a> FleetRepository<'a> {
             active_model.disable_reminder = ActiveValue::Set(disable_reminder);
         }
 
-        let updated_fleet = active_model.update(self.db).await?;
+        let updated_fleet = active_model.update(db).await?;
 
         // Update field values if provided
         if let Some(new_field_values) = params.field_values {
             // Delete existing field values
             entity::prelude::FleetFieldValue::delete_many()
                 .filter(entity::fleet_field_value::Column::FleetId.eq(id))
-                .exec(self.db)
+                .exec(db)
                 .await?;
 
             // Insert new field values
@@ -299,7 +592,7 @@ impl<'a> FleetRepository<'a> {
                     field_id: ActiveValue::Set(field_id),
                     value: ActiveValue::Set(value),
                 }
-                .insert(self.db)
+                .insert(db)
                 .await?;
             }
         }
@@ -0,0 +1,95 @@
+//! Fleet direct message reminder data repository for database operations.
+//!
+//! This module provides the `FleetDmReminderRepository` for tracking which subscribers
+//! already received their personal reminder for a fleet, preventing the scheduler from
+//! sending the same reminder again on its next run.
+
+use migration::OnConflict;
+use sea_orm::{ActiveValue, ColumnTrait, DatabaseConnection, DbErr, EntityTrait, QueryFilter};
+
+use crate::server::{error::AppError, util::parse::parse_u64_from_string};
+
+/// Repository providing database operations for sent personal reminders.
+pub struct FleetDmReminderRepository<'a> {
+    db: &'a DatabaseConnection,
+}
+
+impl<'a> FleetDmReminderRepository<'a> {
+    /// Creates a new FleetDmReminderRepository instance
+    ///
+    /// # Arguments
+    /// - `db` - Reference to the database connection
+    ///
+    /// # Returns
+    /// - `FleetDmReminderRepository` - new repository instance
+    pub fn new(db: &'a DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// Records that a user received their personal reminder for a fleet
+    ///
+    /// # Arguments
+    /// - `fleet_id` - ID of the fleet the reminder was sent for
+    /// - `user_id` - Discord ID of the user the reminder was sent to
+    ///
+    /// # Returns
+    /// - `Ok(())` - The reminder is recorded
+    /// - `Err(DbErr)` - Database error during insert operation
+    pub async fn create(&self, fleet_id: i32, user_id: u64) -> Result<(), DbErr> {
+        entity::prelude::FleetDmReminder::insert(entity::fleet_dm_reminder::ActiveModel {
+            fleet_id: ActiveValue::Set(fleet_id),
+            user_id: ActiveValue::Set(user_id.to_string()),
+            sent_at: ActiveValue::Set(chrono::Utc::now()),
+        })
+        .on_conflict(
+            OnConflict::columns([
+                entity::fleet_dm_reminder::Column::FleetId,
+                entity::fleet_dm_reminder::Column::UserId,
+            ])
+            .do_nothing()
+            .to_owned(),
+        )
+        .exec_without_returning(self.db)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Gets the users who already received their personal reminder for a fleet
+    ///
+    /// # Arguments
+    /// - `fleet_id` - ID of the fleet
+    ///
+    /// # Returns
+    /// - `Ok(Vec<u64>)` - Discord IDs of the reminded users
+    /// - `Err(AppError::Database)` - Database error during fetch operation
+    /// - `Err(AppError::InternalError(ParseStringId))` - Failed to parse a stored user ID
+    pub async fn get_user_ids_by_fleet(&self, fleet_id: i32) -> Result<Vec<u64>, AppError> {
+        entity::prelude::FleetDmReminder::find()
+            .filter(entity::fleet_dm_reminder::Column::FleetId.eq(fleet_id))
+            .all(self.db)
+            .await?
+            .into_iter()
+            .map(|r| parse_u64_from_string(r.user_id))
+            .collect()
+    }
+
+    /// Deletes the sent reminders of a fleet so they are sent again
+    ///
+    /// Used when the fleet time changes so subscribers are reminded ahead of the new time.
+    ///
+    /// # Arguments
+    /// - `fleet_id` - ID of the fleet
+    ///
+    /// # Returns
+    /// - `Ok(u64)` - Number of reminder records deleted
+    /// - `Err(DbErr)` - Database error during delete operation
+    pub async fn delete_by_fleet(&self, fleet_id: i32) -> Result<u64, DbErr> {
+        let result = entity::prelude::FleetDmReminder::delete_many()
+            .filter(entity::fleet_dm_reminder::Column::FleetId.eq(fleet_id))
+            .exec(self.db)
+            .await?;
+
+        Ok(result.rows_affected)
+    }
+}
@@ -3,12 +3,15 @@
 //! This module provides the `FleetMessageRepository` for managing fleet message records in
 //! the database. Fleet messages track Discord messages posted for fleet notifications including
 //! creation announcements, reminders, and formup calls. The repository handles creation and
-//! queries with proper conversion between entity models and parameter models at the
+//! queries, and pruning of records for completed fleets, with proper conversion between entity models and parameter models at the
 //! infrastructure boundary.
 
+use chrono::{DateTime, Utc};
 use dioxus_logger::tracing;
 use sea_orm::{
-    ActiveModelTrait, ActiveValue, ColumnTrait, DatabaseConnection, DbErr, EntityTrait, QueryFilter,
+    sea_query::{Query, SimpleExpr},
+    ActiveModelTrait, ActiveValue, ColumnTrait, DatabaseConnection, DbErr, EntityTrait,
+    PaginatorTrait, QueryFilter,
 };
 
 use crate::server::{
@@ -57,6 +60,7 @@ impl<'a> FleetMessageRepository<'a> {
             message_id: ActiveValue::Set(param.message_id.to_string()),
             message_type: ActiveValue::Set(param.message_type),
             created_at: ActiveValue::Set(chrono::Utc::now()),
+            thread_id: ActiveValue::Set(param.thread_id.map(|id| id.to_string())),
             ..Default::default()
         }
         .insert(self.db)
@@ -98,25 +102,29 @@ impl<'a> FleetMessageRepository<'a> {
             .collect())
     }
 
-    /// Gets all messages for a fleet in a specific channel.
+    /// Gets all messages of several fleets in a specific channel.
     ///
-    /// Returns all fleet message records for the specified fleet ID and channel ID.
-    /// Used to find existing messages in a channel when posting replies (reminder/formup).
+    /// Returns the fleet message records of every provided fleet in the channel with a
+    /// single query. Used to link each fleet's ping in the upcoming fleets list.
     ///
     /// # Arguments
-    /// - `fleet_id` - ID of the fleet to get messages for
+    /// - `fleet_ids` - IDs of the fleets to get messages for
     /// - `channel_id` - Discord channel ID to filter by
     ///
     /// # Returns
     /// - `Ok(Vec<FleetMessage>)` - Vector of fleet messages (empty if no messages)
     /// - `Err(DbErr)` - Database error during query
-    pub async fn get_by_fleet_id_and_channel(
+    pub async fn get_by_fleet_ids_and_channel(
         &self,
-        fleet_id: i32,
+        fleet_ids: &[i32],
         channel_id: u64,
     ) -> Result<Vec<FleetMessage>, DbErr> {
+        if fleet_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
         let entities = entity::prelude::FleetMessage::find()
-            .filter(entity::fleet_message::Column::FleetId.eq(fleet_id))
+            .filter(entity::fleet_message::Column::FleetId.is_in(fleet_ids.to_vec()))
             .filter(entity::fleet_message::Column::ChannelId.eq(channel_id.to_string()))
             .all(self.db)
             .await?;
@@ -135,4 +143,50 @@ impl<'a> FleetMessageRepository<'a> {
             })
             .collect())
     }
+
+    /// Counts messages of fleets with a fleet time before the cutoff.
+    ///
+    /// # Arguments
+    /// - `cutoff` - Messages of fleets scheduled before this time are counted
+    ///
+    /// # Returns
+    /// - `Ok(u64)` - Number of messages belonging to fleets scheduled before the cutoff
+    /// - `Err(DbErr)` - Database error during count query
+    pub async fn count_for_fleets_before(&self, cutoff: DateTime<Utc>) -> Result<u64, DbErr> {
+        entity::prelude::FleetMessage::find()
+            .filter(Self::fleet_time_before(cutoff))
+            .count(self.db)
+            .await
+    }
+
+    /// Deletes messages of fleets with a fleet time before the cutoff.
+    ///
+    /// Only the message records are removed, the Discord messages themselves are kept. Once
+    /// deleted, later edits to the fleet will no longer update those Discord messages.
+    ///
+    /// # Arguments
+    /// - `cutoff` - Messages of fleets scheduled before this time are deleted
+    ///
+    /// # Returns
+    /// - `Ok(u64)` - Number of messages deleted
+    /// - `Err(DbErr)` - Database error during delete operation
+    pub async fn delete_for_fleets_before(&self, cutoff: DateTime<Utc>) -> Result<u64, DbErr> {
+        let result = entity::prelude::FleetMessage::delete_many()
+            .filter(Self::fleet_time_before(cutoff))
+            .exec(self.db)
+            .await?;
+
+        Ok(result.rows_affected)
+    }
+
+    /// Builds a condition matching messages of fleets scheduled before the cutoff.
+    fn fleet_time_before(cutoff: DateTime<Utc>) -> SimpleExpr {
+        entity::fleet_message::Column::FleetId.in_subquery(
+            Query::select()
+                .column(entity::fleet::Column::Id)
+                .from(entity::prelude::Fleet)
+                .and_where(entity::fleet::Column::FleetTime.lt(cutoff))
+                .to_owned(),
+        )
+    }
 }
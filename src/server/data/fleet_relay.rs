@@ -0,0 +1,221 @@
+//! Fleet relay data repository for database operations
+//!
+//! Provides the `FleetRelayRepository` for managing the relay links forwarding the
+//! fleets of a category to another guild. Provides methods to create, get, answer,
+//! and delete relays as well as handles the conversion of database entity models into
+//! domain models for usage within services & controllers.
+
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, DatabaseConnection, EntityTrait, IntoActiveModel,
+    QueryFilter, QueryOrder, QuerySelect, RelationTrait,
+};
+
+use crate::{
+    model::fleet_relay::FleetRelayStatus,
+    server::{
+        error::AppError,
+        model::fleet_relay::{CreateFleetRelayParam, FleetRelay},
+    },
+};
+
+/// Repository providing database operations for fleet relay management.
+///
+/// This struct holds a reference to the database connection and provides methods
+/// for creating, reading, updating, and deleting fleet relay records.
+pub struct FleetRelayRepository<'a> {
+    db: &'a DatabaseConnection,
+}
+
+impl<'a> FleetRelayRepository<'a> {
+    /// Creates a new FleetRelayRepository instance
+    ///
+    /// # Arguments
+    /// - `db` - Reference to the database connection
+    ///
+    /// # Returns
+    /// - `FleetRelayRepository` - new repository instance
+    pub fn new(db: &'a DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// Creates a new pending fleet relay request
+    ///
+    /// # Arguments
+    /// - `param` - Source category & target guild of the relay
+    ///
+    /// # Returns
+    /// - `Ok(FleetRelay)` - The created relay as a domain model
+    /// - `Err(AppError::Database)` - Database error during insert operation
+    pub async fn create(&self, param: CreateFleetRelayParam) -> Result<FleetRelay, AppError> {
+        let entity = entity::prelude::FleetRelay::insert(entity::fleet_relay::ActiveModel {
+            source_category_id: ActiveValue::Set(param.source_category_id),
+            target_guild_id: ActiveValue::Set(param.target_guild_id.to_string()),
+            target_category_id: ActiveValue::Set(None),
+            status: ActiveValue::Set(FleetRelayStatus::Pending.as_str().to_string()),
+            created_at: ActiveValue::Set(chrono::Utc::now()),
+            ..Default::default()
+        })
+        .exec_with_returning(self.db)
+        .await?;
+
+        FleetRelay::from_entity(entity)
+    }
+
+    /// Finds a fleet relay by ID
+    ///
+    /// # Returns
+    /// - `Ok(Some(FleetRelay))` - The relay if found
+    /// - `Ok(None)` - No relay exists with the given ID
+    /// - `Err(AppError::Database)` - Database error during fetch operation
+    pub async fn find_by_id(&self, id: i32) -> Result<Option<FleetRelay>, AppError> {
+        let entity = entity::prelude::FleetRelay::find_by_id(id)
+            .one(self.db)
+            .await?;
+
+        entity.map(FleetRelay::from_entity).transpose()
+    }
+
+    /// Finds the relay of a source category to a target guild
+    ///
+    /// Only one relay may exist per source category & target guild.
+    ///
+    /// # Arguments
+    /// - `source_category_id` - ID of the category whose fleets are relayed
+    /// - `target_guild_id` - ID of the guild fleets are relayed to
+    ///
+    /// # Returns
+    /// - `Ok(Some(FleetRelay))` - The relay if one was already requested
+    /// - `Ok(None)` - No relay exists between the category & guild
+    /// - `Err(AppError::Database)` - Database error during fetch operation
+    pub async fn find_by_source_and_target(
+        &self,
+        source_category_id: i32,
+        target_guild_id: u64,
+    ) -> Result<Option<FleetRelay>, AppError> {
+        let entity = entity::prelude::FleetRelay::find()
+            .filter(entity::fleet_relay::Column::SourceCategoryId.eq(source_category_id))
+            .filter(entity::fleet_relay::Column::TargetGuildId.eq(target_guild_id.to_string()))
+            .one(self.db)
+            .await?;
+
+        entity.map(FleetRelay::from_entity).transpose()
+    }
+
+    /// Gets the relays forwarding the fleets of a guild's categories, ordered by ID
+    ///
+    /// # Arguments
+    /// - `guild_id` - ID of the guild owning the source categories
+    ///
+    /// # Returns
+    /// - `Ok(Vec<FleetRelay>)` - Outgoing relays of the guild
+    /// - `Err(AppError::Database)` - Database error during fetch operation
+    pub async fn get_outgoing_by_guild(&self, guild_id: u64) -> Result<Vec<FleetRelay>, AppError> {
+        use entity::fleet_category;
+        use sea_orm::JoinType;
+
+        let entities = entity::prelude::FleetRelay::find()
+            .join(
+                JoinType::InnerJoin,
+                entity::fleet_relay::Relation::FleetCategory2.def(),
+            )
+            .filter(fleet_category::Column::GuildId.eq(guild_id.to_string()))
+            .order_by_asc(entity::fleet_relay::Column::Id)
+            .all(self.db)
+            .await?;
+
+        entities.into_iter().map(FleetRelay::from_entity).collect()
+    }
+
+    /// Gets the relays other guilds requested to forward fleets to a guild, ordered by ID
+    ///
+    /// # Arguments
+    /// - `guild_id` - ID of the target guild
+    ///
+    /// # Returns
+    /// - `Ok(Vec<FleetRelay>)` - Incoming relays of the guild
+    /// - `Err(AppError::Database)` - Database error during fetch operation
+    pub async fn get_incoming_by_guild(&self, guild_id: u64) -> Result<Vec<FleetRelay>, AppError> {
+        let entities = entity::prelude::FleetRelay::find()
+            .filter(entity::fleet_relay::Column::TargetGuildId.eq(guild_id.to_string()))
+            .order_by_asc(entity::fleet_relay::Column::Id)
+            .all(self.db)
+            .await?;
+
+        entities.into_iter().map(FleetRelay::from_entity).collect()
+    }
+
+    /// Gets the accepted relays of a category which new fleets must be copied to
+    ///
+    /// # Arguments
+    /// - `source_category_id` - ID of the category the fleet was created in
+    ///
+    /// # Returns
+    /// - `Ok(Vec<FleetRelay>)` - Accepted relays of the category
+    /// - `Err(AppError::Database)` - Database error during fetch operation
+    pub async fn get_accepted_by_source_category(
+        &self,
+        source_category_id: i32,
+    ) -> Result<Vec<FleetRelay>, AppError> {
+        let entities = entity::prelude::FleetRelay::find()
+            .filter(entity::fleet_relay::Column::SourceCategoryId.eq(source_category_id))
+            .filter(entity::fleet_relay::Column::Status.eq(FleetRelayStatus::Accepted.as_str()))
+            .filter(entity::fleet_relay::Column::TargetCategoryId.is_not_null())
+            .all(self.db)
+            .await?;
+
+        entities.into_iter().map(FleetRelay::from_entity).collect()
+    }
+
+    /// Records the target guild's answer to a relay request
+    ///
+    /// # Arguments
+    /// - `id` - ID of the relay to answer
+    /// - `status` - Accepted or declined
+    /// - `target_category_id` - Category fleets are copied into, None when declining
+    ///
+    /// # Returns
+    /// - `Ok(FleetRelay)` - The updated relay as a domain model
+    /// - `Err(AppError::NotFound)` - No relay exists with the given ID
+    /// - `Err(AppError::Database)` - Database error during update operation
+    pub async fn update_status(
+        &self,
+        id: i32,
+        status: FleetRelayStatus,
+        target_category_id: Option<i32>,
+    ) -> Result<FleetRelay, AppError> {
+        let Some(entity) = entity::prelude::FleetRelay::find_by_id(id)
+            .one(self.db)
+            .await?
+        else {
+            return Err(AppError::NotFound(format!(
+                "Failed to find fleet relay ID {} while attempting update",
+                id
+            )));
+        };
+
+        let mut active_model = entity.into_active_model();
+
+        active_model.status = ActiveValue::Set(status.as_str().to_string());
+        active_model.target_category_id = ActiveValue::Set(target_category_id);
+
+        let entity = active_model.update(self.db).await?;
+
+        FleetRelay::from_entity(entity)
+    }
+
+    /// Deletes the relay of the provided ID
+    ///
+    /// Links of relayed fleets are deleted with the relay, the copies themselves are
+    /// kept as regular fleets of the target guild.
+    ///
+    /// # Returns
+    /// - `Ok(())` - The relay was successfully deleted
+    /// - `Err(AppError::Database)` - Database error during delete operation
+    pub async fn delete(&self, id: i32) -> Result<(), AppError> {
+        entity::prelude::FleetRelay::delete_by_id(id)
+            .exec(self.db)
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,222 @@
+//! Fleet template data repository for database operations
+//!
+//! Provides the `FleetTemplateRepository` for managing the fleet templates FCs prefill
+//! the fleet creation form with. Handles the conversion of template & field value entity
+//! models into domain models for usage within services & controllers.
+
+use std::collections::HashMap;
+
+use chrono::Utc;
+use sea_orm::{
+    sea_query::Condition, ActiveValue, ColumnTrait, DatabaseConnection, EntityTrait, JoinType,
+    PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, RelationTrait,
+};
+
+use crate::server::{
+    error::AppError,
+    model::fleet_template::{CreateFleetTemplateParam, FleetTemplate},
+};
+
+/// Repository providing database operations for fleet template management.
+pub struct FleetTemplateRepository<'a> {
+    db: &'a DatabaseConnection,
+}
+
+impl<'a> FleetTemplateRepository<'a> {
+    /// Creates a new FleetTemplateRepository instance
+    ///
+    /// # Arguments
+    /// - `db` - Reference to the database connection
+    ///
+    /// # Returns
+    /// - `FleetTemplateRepository` - new repository instance
+    pub fn new(db: &'a DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// Creates a new fleet template with its field values
+    ///
+    /// # Arguments
+    /// - `param` - Create parameters containing the template & its field values
+    ///
+    /// # Returns
+    /// - `Ok(FleetTemplate)` - The created template as a domain model
+    /// - `Err(AppError::Database)` - Database error during insert operation
+    pub async fn create(&self, param: CreateFleetTemplateParam) -> Result<FleetTemplate, AppError> {
+        let entity = entity::prelude::FleetTemplate::insert(entity::fleet_template::ActiveModel {
+            category_id: ActiveValue::Set(param.category_id),
+            owner_id: ActiveValue::Set(param.owner_id.to_string()),
+            name: ActiveValue::Set(param.name),
+            shared: ActiveValue::Set(param.shared),
+            fleet_name: ActiveValue::Set(param.fleet_name),
+            commander_id: ActiveValue::Set(param.commander_id.map(|id| id.to_string())),
+            description: ActiveValue::Set(param.description),
+            hidden: ActiveValue::Set(param.hidden),
+            disable_reminder: ActiveValue::Set(param.disable_reminder),
+            created_at: ActiveValue::Set(Utc::now()),
+            ..Default::default()
+        })
+        .exec_with_returning(self.db)
+        .await?;
+
+        let field_values: Vec<entity::fleet_template_field_value::ActiveModel> = param
+            .field_values
+            .into_iter()
+            .map(
+                |(field_id, value)| entity::fleet_template_field_value::ActiveModel {
+                    template_id: ActiveValue::Set(entity.id),
+                    field_id: ActiveValue::Set(field_id),
+                    value: ActiveValue::Set(value),
+                },
+            )
+            .collect();
+
+        if !field_values.is_empty() {
+            entity::prelude::FleetTemplateFieldValue::insert_many(field_values)
+                .exec(self.db)
+                .await?;
+        }
+
+        Ok(self.with_field_values(vec![entity]).await?.remove(0))
+    }
+
+    /// Gets the templates of a guild a user can use, ordered by name
+    ///
+    /// Users can use their own templates & the shared templates of the categories they
+    /// can create fleets in.
+    ///
+    /// # Arguments
+    /// - `guild_id` - The ID of the guild whose categories the templates belong to
+    /// - `user_id` - Discord ID of the user
+    /// - `category_ids` - Categories the user can create fleets in, None for admins who
+    ///   can use the templates of every category
+    ///
+    /// # Returns
+    /// - `Ok(Vec<FleetTemplate>)` - The templates with their field values
+    /// - `Err(AppError::Database)` - Database error during query
+    pub async fn get_usable_by_user(
+        &self,
+        guild_id: u64,
+        user_id: u64,
+        category_ids: Option<Vec<i32>>,
+    ) -> Result<Vec<FleetTemplate>, AppError> {
+        let mut query = entity::prelude::FleetTemplate::find()
+            .join(
+                JoinType::InnerJoin,
+                entity::fleet_template::Relation::FleetCategory.def(),
+            )
+            .filter(entity::fleet_category::Column::GuildId.eq(guild_id.to_string()))
+            .filter(
+                Condition::any()
+                    .add(entity::fleet_template::Column::OwnerId.eq(user_id.to_string()))
+                    .add(entity::fleet_template::Column::Shared.eq(true)),
+            )
+            .order_by_asc(entity::fleet_template::Column::Name);
+
+        if let Some(category_ids) = category_ids {
+            if category_ids.is_empty() {
+                return Ok(Vec::new());
+            }
+            query = query.filter(entity::fleet_template::Column::CategoryId.is_in(category_ids));
+        }
+
+        let entities = query.all(self.db).await?;
+
+        self.with_field_values(entities).await
+    }
+
+    /// Finds a template by ID within a specific guild
+    ///
+    /// # Arguments
+    /// - `guild_id` - The ID of the guild whose category the template belongs to
+    /// - `id` - The ID of the template to retrieve
+    ///
+    /// # Returns
+    /// - `Ok(Some(FleetTemplate))` - The template if found in the specified guild
+    /// - `Ok(None)` - If no template exists with the given ID in the guild
+    /// - `Err(AppError::Database)` - Database error during fetch operation
+    pub async fn find_by_id(
+        &self,
+        guild_id: u64,
+        id: i32,
+    ) -> Result<Option<FleetTemplate>, AppError> {
+        let Some(entity) = entity::prelude::FleetTemplate::find_by_id(id)
+            .join(
+                JoinType::InnerJoin,
+                entity::fleet_template::Relation::FleetCategory.def(),
+            )
+            .filter(entity::fleet_category::Column::GuildId.eq(guild_id.to_string()))
+            .one(self.db)
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        Ok(self.with_field_values(vec![entity]).await?.pop())
+    }
+
+    /// Counts the templates a user saved in a category
+    pub async fn count_by_owner_and_category(
+        &self,
+        owner_id: u64,
+        category_id: i32,
+    ) -> Result<usize, AppError> {
+        let count = entity::prelude::FleetTemplate::find()
+            .filter(entity::fleet_template::Column::OwnerId.eq(owner_id.to_string()))
+            .filter(entity::fleet_template::Column::CategoryId.eq(category_id))
+            .count(self.db)
+            .await?;
+
+        Ok(count as usize)
+    }
+
+    /// Deletes template of the provided ID
+    ///
+    /// Field values are deleted due to CASCADE foreign key constraints.
+    ///
+    /// # Arguments
+    /// - `id` - The ID of the template to delete
+    ///
+    /// # Returns
+    /// - `Ok(())` - The template was successfully deleted
+    /// - `Err(AppError::Database)` - Database error during delete operation
+    pub async fn delete(&self, id: i32) -> Result<(), AppError> {
+        entity::prelude::FleetTemplate::delete_by_id(id)
+            .exec(self.db)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Loads the field values of template entities & converts them to domain models
+    async fn with_field_values(
+        &self,
+        entities: Vec<entity::fleet_template::Model>,
+    ) -> Result<Vec<FleetTemplate>, AppError> {
+        if entities.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let template_ids: Vec<i32> = entities.iter().map(|e| e.id).collect();
+        let mut field_values: HashMap<i32, Vec<entity::fleet_template_field_value::Model>> =
+            HashMap::new();
+        for value in entity::prelude::FleetTemplateFieldValue::find()
+            .filter(entity::fleet_template_field_value::Column::TemplateId.is_in(template_ids))
+            .all(self.db)
+            .await?
+        {
+            field_values
+                .entry(value.template_id)
+                .or_default()
+                .push(value);
+        }
+
+        entities
+            .into_iter()
+            .map(|entity| {
+                let values = field_values.remove(&entity.id).unwrap_or_default();
+                FleetTemplate::from_entity(entity, values)
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,129 @@
+//! Guild settings data repository for database operations.
+//!
+//! This module provides the `GuildSettingsRepository` for storing the display &
+//! behaviour settings of guilds. Guilds without stored settings use the defaults of
+//! `GuildSettings::default_for`.
+
+use migration::OnConflict;
+use sea_orm::{ActiveValue, DatabaseConnection, EntityTrait};
+
+use crate::server::{
+    error::AppError,
+    model::guild_settings::{GuildSettings, UpsertGuildSettingsParam},
+};
+
+/// Repository providing database operations for guild settings.
+pub struct GuildSettingsRepository<'a> {
+    db: &'a DatabaseConnection,
+}
+
+impl<'a> GuildSettingsRepository<'a> {
+    /// Creates a new GuildSettingsRepository instance
+    ///
+    /// # Arguments
+    /// - `db` - Reference to the database connection
+    ///
+    /// # Returns
+    /// - `GuildSettingsRepository` - new repository instance
+    pub fn new(db: &'a DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// Gets the settings of a guild, falling back to the defaults
+    ///
+    /// # Arguments
+    /// - `guild_id` - Discord ID of the guild
+    ///
+    /// # Returns
+    /// - `Ok(GuildSettings)` - Stored or default settings of the guild
+    /// - `Err(AppError::Database)` - Database error during fetch operation
+    pub async fn get_by_guild(&self, guild_id: u64) -> Result<GuildSettings, AppError> {
+        let entity = entity::prelude::GuildSettings::find_by_id(guild_id.to_string())
+            .one(self.db)
+            .await?;
+
+        match entity {
+            Some(entity) => GuildSettings::from_entity(entity),
+            None => Ok(GuildSettings::default_for(guild_id)),
+        }
+    }
+
+    /// Gets the settings of every guild which stored settings
+    ///
+    /// # Returns
+    /// - `Ok(Vec<GuildSettings>)` - Stored settings, guilds using the defaults are omitted
+    /// - `Err(AppError::Database)` - Database error during fetch operation
+    pub async fn get_all(&self) -> Result<Vec<GuildSettings>, AppError> {
+        entity::prelude::GuildSettings::find()
+            .all(self.db)
+            .await?
+            .into_iter()
+            .map(GuildSettings::from_entity)
+            .collect()
+    }
+
+    /// Stores the settings of a guild
+    ///
+    /// # Arguments
+    /// - `param` - The settings to store
+    ///
+    /// # Returns
+    /// - `Ok(GuildSettings)` - The stored settings
+    /// - `Err(AppError::Database)` - Database error during upsert operation
+    pub async fn upsert(&self, param: UpsertGuildSettingsParam) -> Result<GuildSettings, AppError> {
+        use entity::guild_settings::Column;
+
+        let entity = entity::prelude::GuildSettings::insert(entity::guild_settings::ActiveModel {
+            guild_id: ActiveValue::Set(param.guild_id.to_string()),
+            creation_color: ActiveValue::Set(param.creation_color as i32),
+            update_color: ActiveValue::Set(param.update_color as i32),
+            reminder_color: ActiveValue::Set(param.reminder_color as i32),
+            formup_color: ActiveValue::Set(param.formup_color as i32),
+            cancel_color: ActiveValue::Set(param.cancel_color as i32),
+            list_color: ActiveValue::Set(param.list_color as i32),
+            time_label: ActiveValue::Set(param.time_label),
+            extra_timezones: ActiveValue::Set(param.extra_timezones.join(",")),
+            list_lookahead_hours: ActiveValue::Set(param.list_lookahead_hours),
+            formup_catchup_minutes: ActiveValue::Set(param.formup_catchup_minutes),
+            default_ping_format_id: ActiveValue::Set(param.default_ping_format_id),
+            staging_system_id: ActiveValue::Set(param.staging_system_id.map(|id| id as i32)),
+            downtime_start_minute: ActiveValue::Set(param.downtime.start_minute as i32),
+            downtime_duration_minutes: ActiveValue::Set(param.downtime.duration_minutes as i32),
+            downtime_policy: ActiveValue::Set(param.downtime.policy.as_str().to_string()),
+            broadcast_role_ids: ActiveValue::Set(
+                param
+                    .broadcast_role_ids
+                    .iter()
+                    .map(u64::to_string)
+                    .collect::<Vec<_>>()
+                    .join(","),
+            ),
+        })
+        .on_conflict(
+            OnConflict::column(Column::GuildId)
+                .update_columns([
+                    Column::CreationColor,
+                    Column::UpdateColor,
+                    Column::ReminderColor,
+                    Column::FormupColor,
+                    Column::CancelColor,
+                    Column::ListColor,
+                    Column::TimeLabel,
+                    Column::ExtraTimezones,
+                    Column::ListLookaheadHours,
+                    Column::FormupCatchupMinutes,
+                    Column::DefaultPingFormatId,
+                    Column::StagingSystemId,
+                    Column::DowntimeStartMinute,
+                    Column::DowntimeDurationMinutes,
+                    Column::DowntimePolicy,
+                    Column::BroadcastRoleIds,
+                ])
+                .to_owned(),
+        )
+        .exec_with_returning(self.db)
+        .await?;
+
+        GuildSettings::from_entity(entity)
+    }
+}
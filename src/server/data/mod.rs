@@ -5,15 +5,29 @@
 //! parameter models to maintain separation between the data layer and business logic layer.
 //! All database queries, inserts, updates, and deletes are performed through these repositories.
 
+pub mod broadcast;
 pub mod category;
 pub mod channel_fleet_list;
+pub mod channel_list_settings;
 pub mod discord;
+pub mod doctrine;
 pub mod fleet;
+pub mod fleet_dm_reminder;
 pub mod fleet_message;
+pub mod fleet_relay;
+pub mod fleet_template;
+pub mod guild_settings;
+pub mod notification_preference;
 pub mod ping_format;
 pub mod ping_group;
+pub mod relayed_fleet;
+pub mod session;
+pub mod stand_down;
+pub mod subscription;
 pub mod user;
 pub mod user_category_permission;
+pub mod webhook;
+pub mod webhook_delivery;
 
 #[cfg(test)]
 mod test;
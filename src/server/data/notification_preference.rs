@@ -0,0 +1,128 @@
+//! Notification preference data repository for database operations.
+//!
+//! This module provides the `NotificationPreferenceRepository` for storing the direct
+//! message notification preferences of users. Users without stored preferences use the
+//! defaults of `NotificationPreference::default_for`.
+
+use migration::OnConflict;
+use sea_orm::{ActiveValue, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter};
+
+use crate::server::{
+    error::AppError,
+    model::subscription::{NotificationPreference, UpsertNotificationPreferenceParam},
+};
+
+/// Repository providing database operations for notification preferences.
+pub struct NotificationPreferenceRepository<'a> {
+    db: &'a DatabaseConnection,
+}
+
+impl<'a> NotificationPreferenceRepository<'a> {
+    /// Creates a new NotificationPreferenceRepository instance
+    ///
+    /// # Arguments
+    /// - `db` - Reference to the database connection
+    ///
+    /// # Returns
+    /// - `NotificationPreferenceRepository` - new repository instance
+    pub fn new(db: &'a DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// Gets the notification preferences of a user, falling back to the defaults
+    ///
+    /// # Arguments
+    /// - `user_id` - Discord ID of the user
+    ///
+    /// # Returns
+    /// - `Ok(NotificationPreference)` - Stored or default preferences of the user
+    /// - `Err(AppError::Database)` - Database error during fetch operation
+    pub async fn get_by_user(&self, user_id: u64) -> Result<NotificationPreference, AppError> {
+        let entity = entity::prelude::NotificationPreference::find_by_id(user_id.to_string())
+            .one(self.db)
+            .await?;
+
+        match entity {
+            Some(entity) => NotificationPreference::from_entity(entity),
+            None => Ok(NotificationPreference::default_for(user_id)),
+        }
+    }
+
+    /// Gets the notification preferences of multiple users, falling back to the defaults
+    ///
+    /// # Arguments
+    /// - `user_ids` - Discord IDs of the users
+    ///
+    /// # Returns
+    /// - `Ok(Vec<NotificationPreference>)` - Preferences in the order of `user_ids`
+    /// - `Err(AppError::Database)` - Database error during fetch operation
+    pub async fn get_by_users(
+        &self,
+        user_ids: &[u64],
+    ) -> Result<Vec<NotificationPreference>, AppError> {
+        if user_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let stored = entity::prelude::NotificationPreference::find()
+            .filter(
+                entity::notification_preference::Column::UserId
+                    .is_in(user_ids.iter().map(|id| id.to_string())),
+            )
+            .all(self.db)
+            .await?
+            .into_iter()
+            .map(NotificationPreference::from_entity)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(user_ids
+            .iter()
+            .map(|user_id| {
+                stored
+                    .iter()
+                    .find(|p| p.user_id == *user_id)
+                    .cloned()
+                    .unwrap_or_else(|| NotificationPreference::default_for(*user_id))
+            })
+            .collect())
+    }
+
+    /// Stores the notification preferences of a user
+    ///
+    /// # Arguments
+    /// - `param` - The preferences to store
+    ///
+    /// # Returns
+    /// - `Ok(NotificationPreference)` - The stored preferences
+    /// - `Err(AppError::Database)` - Database error during upsert operation
+    pub async fn upsert(
+        &self,
+        param: UpsertNotificationPreferenceParam,
+    ) -> Result<NotificationPreference, AppError> {
+        let entity = entity::prelude::NotificationPreference::insert(
+            entity::notification_preference::ActiveModel {
+                user_id: ActiveValue::Set(param.user_id.to_string()),
+                dm_enabled: ActiveValue::Set(param.dm_enabled),
+                notify_created: ActiveValue::Set(param.notify_created),
+                notify_time_changed: ActiveValue::Set(param.notify_time_changed),
+                notify_cancelled: ActiveValue::Set(param.notify_cancelled),
+                reminder_minutes: ActiveValue::Set(param.reminder_minutes),
+            },
+        )
+        .on_conflict(
+            OnConflict::column(entity::notification_preference::Column::UserId)
+                .update_columns([
+                    entity::notification_preference::Column::DmEnabled,
+                    entity::notification_preference::Column::NotifyCreated,
+                    entity::notification_preference::Column::NotifyTimeChanged,
+                    entity::notification_preference::Column::NotifyCancelled,
+                    entity::notification_preference::Column::ReminderMinutes,
+                ])
+                .to_owned(),
+        )
+        .exec_with_returning(self.db)
+        .await?;
+
+        NotificationPreference::from_entity(entity)
+    }
+}
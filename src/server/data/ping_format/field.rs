@@ -77,6 +77,8 @@ impl<'a> PingFormatFieldRepository<'a> {
         let field_type_str = match data.field_type {
             PingFormatFieldType::Text => "text",
             PingFormatFieldType::Bool => "bool",
+            PingFormatFieldType::SolarSystem => "solar_system",
+            PingFormatFieldType::Doctrine => "doctrine",
         };
 
         let entity = entity::ping_format_field::ActiveModel {
@@ -93,7 +95,7 @@ impl<'a> PingFormatFieldRepository<'a> {
         if matches!(data.field_type, PingFormatFieldType::Text) {
             for value in &data.default_field_values {
                 entity::ping_format_field_value::ActiveModel {
-                    ping_format_field_id: ActiveValue::Set(entity.id.to_string()),
+                    ping_format_field_id: ActiveValue::Set(entity.id),
                     value: ActiveValue::Set(value.clone()),
                     ..Default::default()
                 }
@@ -153,8 +155,7 @@ impl<'a> PingFormatFieldRepository<'a> {
                 // Fetch default values for text fields
                 let value_entities = entity::prelude::PingFormatFieldValue::find()
                     .filter(
-                        entity::ping_format_field_value::Column::PingFormatFieldId
-                            .eq(entity.id.to_string()),
+                        entity::ping_format_field_value::Column::PingFormatFieldId.eq(entity.id),
                     )
                     .all(self.db)
                     .await?;
@@ -215,6 +216,8 @@ impl<'a> PingFormatFieldRepository<'a> {
         let field_type_str = match data.field_type {
             PingFormatFieldType::Text => "text",
             PingFormatFieldType::Bool => "bool",
+            PingFormatFieldType::SolarSystem => "solar_system",
+            PingFormatFieldType::Doctrine => "doctrine",
         };
 
         let mut active_model: entity::ping_format_field::ActiveModel = field.into();
@@ -226,10 +229,7 @@ impl<'a> PingFormatFieldRepository<'a> {
 
         // Delete all existing default values
         entity::prelude::PingFormatFieldValue::delete_many()
-            .filter(
-                entity::ping_format_field_value::Column::PingFormatFieldId
-                    .eq(entity.id.to_string()),
-            )
+            .filter(entity::ping_format_field_value::Column::PingFormatFieldId.eq(entity.id))
             .exec(self.db)
             .await?;
 
@@ -237,7 +237,7 @@ impl<'a> PingFormatFieldRepository<'a> {
         if matches!(data.field_type, PingFormatFieldType::Text) {
             for value in &data.default_field_values {
                 entity::ping_format_field_value::ActiveModel {
-                    ping_format_field_id: ActiveValue::Set(entity.id.to_string()),
+                    ping_format_field_id: ActiveValue::Set(entity.id),
                     value: ActiveValue::Set(value.clone()),
                     ..Default::default()
                 }
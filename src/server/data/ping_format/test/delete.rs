@@ -0,0 +1,81 @@
+use super::*;
+
+/// Tests deleting a ping format with fields that have default values.
+///
+/// Verifies that the repository deletes the ping format along with its fields and
+/// the default values of those fields through the cascading foreign keys.
+///
+/// Expected: Ok with ping format, fields & default values deleted
+#[tokio::test]
+async fn deletes_ping_format_with_field_values() -> Result<(), AppError> {
+    let test = TestBuilder::new()
+        .with_table(DiscordGuild)
+        .with_table(PingFormat)
+        .with_table(PingFormatField)
+        .with_table(PingFormatFieldValue)
+        .build()
+        .await
+        .unwrap();
+    let db = test.db.as_ref().unwrap();
+
+    let guild = factory::create_guild(db).await?;
+    let ping_format = factory::create_ping_format(db, &guild.guild_id).await?;
+    let other_format = factory::create_ping_format(db, &guild.guild_id).await?;
+    let field =
+        factory::ping_format_field::create_ping_format_field(db, ping_format.id, "Doctrine", 1)
+            .await?;
+    let other_field =
+        factory::ping_format_field::create_ping_format_field(db, other_format.id, "Doctrine", 1)
+            .await?;
+
+    for field_id in [field.id, other_field.id] {
+        entity::ping_format_field_value::ActiveModel {
+            ping_format_field_id: sea_orm::ActiveValue::Set(field_id),
+            value: sea_orm::ActiveValue::Set("Ferox".to_string()),
+            ..Default::default()
+        }
+        .insert(db)
+        .await?;
+    }
+
+    let repo = PingFormatRepository::new(db);
+    let result = repo.delete(ping_format.id).await;
+
+    assert!(result.is_ok());
+    assert!(entity::prelude::PingFormat::find_by_id(ping_format.id)
+        .one(db)
+        .await?
+        .is_none());
+
+    // Only the default values of the deleted format's fields are removed
+    let values = entity::prelude::PingFormatFieldValue::find()
+        .all(db)
+        .await?;
+    assert_eq!(values.len(), 1);
+    assert_eq!(values[0].ping_format_field_id, other_field.id);
+
+    Ok(())
+}
+
+/// Tests deleting a ping format that doesn't exist.
+///
+/// Expected: Ok
+#[tokio::test]
+async fn succeeds_for_nonexistent_ping_format() -> Result<(), AppError> {
+    let test = TestBuilder::new()
+        .with_table(DiscordGuild)
+        .with_table(PingFormat)
+        .with_table(PingFormatField)
+        .with_table(PingFormatFieldValue)
+        .build()
+        .await
+        .unwrap();
+    let db = test.db.as_ref().unwrap();
+
+    let repo = PingFormatRepository::new(db);
+    let result = repo.delete(999).await;
+
+    assert!(result.is_ok());
+
+    Ok(())
+}
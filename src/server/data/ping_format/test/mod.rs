@@ -1,4 +1,5 @@
 mod create;
+mod delete;
 mod exists_in_guild;
 mod get_all_by_guild_paginated;
 mod get_fleet_category_count;
@@ -0,0 +1,113 @@
+//! Relayed fleet data repository for database operations
+//!
+//! Provides the `RelayedFleetRepository` for tracking which fleets are relayed copies
+//! of a fleet in another guild, used to propagate edits & cancellations of the source
+//! fleet and to keep the copies read-only.
+
+use sea_orm::{ActiveValue, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter};
+use std::collections::HashSet;
+
+use crate::server::{error::AppError, model::fleet_relay::RelayedFleet};
+
+/// Repository providing database operations for relayed fleet links.
+pub struct RelayedFleetRepository<'a> {
+    db: &'a DatabaseConnection,
+}
+
+impl<'a> RelayedFleetRepository<'a> {
+    /// Creates a new RelayedFleetRepository instance
+    ///
+    /// # Arguments
+    /// - `db` - Reference to the database connection
+    ///
+    /// # Returns
+    /// - `RelayedFleetRepository` - new repository instance
+    pub fn new(db: &'a DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// Links a relayed copy to the fleet it was copied from
+    ///
+    /// # Arguments
+    /// - `fleet_id` - ID of the copy in the target guild
+    /// - `source_fleet_id` - ID of the fleet in the source guild
+    /// - `relay_id` - ID of the relay the copy was created by
+    ///
+    /// # Returns
+    /// - `Ok(RelayedFleet)` - The created link
+    /// - `Err(AppError::Database)` - Database error during insert operation
+    pub async fn create(
+        &self,
+        fleet_id: i32,
+        source_fleet_id: i32,
+        relay_id: i32,
+    ) -> Result<RelayedFleet, AppError> {
+        let entity = entity::prelude::RelayedFleet::insert(entity::relayed_fleet::ActiveModel {
+            fleet_id: ActiveValue::Set(fleet_id),
+            source_fleet_id: ActiveValue::Set(source_fleet_id),
+            relay_id: ActiveValue::Set(relay_id),
+        })
+        .exec_with_returning(self.db)
+        .await?;
+
+        Ok(RelayedFleet::from_entity(entity))
+    }
+
+    /// Finds the link of a fleet if it is a relayed copy
+    ///
+    /// # Returns
+    /// - `Ok(Some(RelayedFleet))` - The fleet is a copy of a fleet in another guild
+    /// - `Ok(None)` - The fleet was created in its own guild
+    /// - `Err(AppError::Database)` - Database error during fetch operation
+    pub async fn find_by_fleet_id(&self, fleet_id: i32) -> Result<Option<RelayedFleet>, AppError> {
+        let entity = entity::prelude::RelayedFleet::find_by_id(fleet_id)
+            .one(self.db)
+            .await?;
+
+        Ok(entity.map(RelayedFleet::from_entity))
+    }
+
+    /// Gets which of the provided fleets are relayed copies with a single query
+    ///
+    /// # Arguments
+    /// - `fleet_ids` - IDs of the fleets to check
+    ///
+    /// # Returns
+    /// - `Ok(HashSet<i32>)` - IDs of the fleets which are copies of a fleet in another guild
+    /// - `Err(AppError::Database)` - Database error during fetch operation
+    pub async fn get_relayed_fleet_ids(
+        &self,
+        fleet_ids: Vec<i32>,
+    ) -> Result<HashSet<i32>, AppError> {
+        if fleet_ids.is_empty() {
+            return Ok(HashSet::new());
+        }
+
+        let entities = entity::prelude::RelayedFleet::find()
+            .filter(entity::relayed_fleet::Column::FleetId.is_in(fleet_ids))
+            .all(self.db)
+            .await?;
+
+        Ok(entities.into_iter().map(|entity| entity.fleet_id).collect())
+    }
+
+    /// Gets the relayed copies of a source fleet
+    ///
+    /// # Returns
+    /// - `Ok(Vec<RelayedFleet>)` - Links of all copies of the fleet
+    /// - `Err(AppError::Database)` - Database error during fetch operation
+    pub async fn get_by_source_fleet(
+        &self,
+        source_fleet_id: i32,
+    ) -> Result<Vec<RelayedFleet>, AppError> {
+        let entities = entity::prelude::RelayedFleet::find()
+            .filter(entity::relayed_fleet::Column::SourceFleetId.eq(source_fleet_id))
+            .all(self.db)
+            .await?;
+
+        Ok(entities
+            .into_iter()
+            .map(RelayedFleet::from_entity)
+            .collect())
+    }
+}
@@ -0,0 +1,75 @@
+//! Session data repository for pruning expired login sessions.
+//!
+//! Sessions are stored by `tower-sessions-sqlx-store` in its default tables, `tower_sessions`
+//! on SQLite and `tower_sessions.session` on PostgreSQL. The store only exposes a deletion
+//! without a row count, so this repository queries those tables directly using the same
+//! expiry conditions as the store.
+
+use sea_orm::{ConnectionTrait, DatabaseBackend, DatabaseConnection, DbErr, Statement};
+
+/// Repository providing database operations for stored login sessions.
+pub struct SessionRepository<'a> {
+    db: &'a DatabaseConnection,
+}
+
+impl<'a> SessionRepository<'a> {
+    /// Creates a new SessionRepository instance.
+    ///
+    /// # Arguments
+    /// - `db` - Reference to the database connection
+    ///
+    /// # Returns
+    /// - `SessionRepository` - New repository instance
+    pub fn new(db: &'a DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// Counts sessions which have passed their expiry date.
+    ///
+    /// # Returns
+    /// - `Ok(u64)` - Number of expired sessions
+    /// - `Err(DbErr)` - Database error during count query or unsupported backend
+    pub async fn count_expired(&self) -> Result<u64, DbErr> {
+        let sql = format!("select count(*) from {}", self.expired_sessions()?);
+        let count = self
+            .db
+            .query_one_raw(Statement::from_string(self.db.get_database_backend(), sql))
+            .await?
+            .map(|row| row.try_get_by_index::<i64>(0))
+            .transpose()?
+            .unwrap_or_default();
+
+        Ok(count.max(0) as u64)
+    }
+
+    /// Deletes sessions which have passed their expiry date.
+    ///
+    /// # Returns
+    /// - `Ok(u64)` - Number of sessions deleted
+    /// - `Err(DbErr)` - Database error during delete operation or unsupported backend
+    pub async fn delete_expired(&self) -> Result<u64, DbErr> {
+        let sql = format!("delete from {}", self.expired_sessions()?);
+        let result = self
+            .db
+            .execute_raw(Statement::from_string(self.db.get_database_backend(), sql))
+            .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    /// Returns the session table and expiry condition for the active database backend.
+    fn expired_sessions(&self) -> Result<&'static str, DbErr> {
+        match self.db.get_database_backend() {
+            DatabaseBackend::Sqlite => {
+                Ok("tower_sessions where datetime(expiry_date) < datetime('now')")
+            }
+            DatabaseBackend::Postgres => {
+                Ok(r#""tower_sessions"."session" where expiry_date < (now() at time zone 'utc')"#)
+            }
+            backend => Err(DbErr::Custom(format!(
+                "Unsupported database backend for session storage: {:?}",
+                backend
+            ))),
+        }
+    }
+}
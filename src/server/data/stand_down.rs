@@ -0,0 +1,256 @@
+//! Stand-down data repository for database operations
+//!
+//! Provides the `StandDownRepository` for recording emergency stand-downs together with
+//! snapshots of the fleets they cancelled. Handles the conversion of stand-down & snapshot
+//! entity models into domain models for usage within services & controllers.
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use sea_orm::{
+    sea_query::Expr, ActiveModelTrait, ActiveValue, ColumnTrait, DatabaseConnection, DbErr,
+    EntityTrait, QueryFilter, QueryOrder, QuerySelect, TransactionTrait,
+};
+
+use crate::server::{
+    data::fleet::FleetRepository,
+    error::AppError,
+    model::stand_down::{CreateStandDownParam, StandDown, StandDownFleet},
+};
+
+/// Repository providing database operations for stand-downs.
+pub struct StandDownRepository<'a> {
+    db: &'a DatabaseConnection,
+}
+
+impl<'a> StandDownRepository<'a> {
+    /// Creates a new StandDownRepository instance
+    ///
+    /// # Arguments
+    /// - `db` - Reference to the database connection
+    ///
+    /// # Returns
+    /// - `StandDownRepository` - new repository instance
+    pub fn new(db: &'a DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// Records a new stand-down with snapshots of the fleets it cancels
+    ///
+    /// The stand-down, its fleet snapshots & their field values are inserted and the
+    /// cancelled fleets are deleted within a single transaction, so fleets are never
+    /// deleted without a snapshot to resume them from.
+    ///
+    /// # Arguments
+    /// - `param` - Create parameters containing the stand-down & the fleets it cancels
+    ///
+    /// # Returns
+    /// - `Ok(StandDown)` - The recorded stand-down as a domain model
+    /// - `Err(AppError::Database)` - Database error during insert or delete, nothing was
+    ///   recorded or deleted
+    pub async fn create(&self, param: CreateStandDownParam) -> Result<StandDown, AppError> {
+        let txn = self.db.begin().await?;
+
+        let entity = entity::stand_down::ActiveModel {
+            guild_id: ActiveValue::Set(param.guild_id.to_string()),
+            ping_group_id: ActiveValue::Set(param.ping_group_id),
+            reason: ActiveValue::Set(param.reason),
+            window_start: ActiveValue::Set(param.window_start),
+            window_end: ActiveValue::Set(param.window_end),
+            created_by: ActiveValue::Set(Some(param.created_by.to_string())),
+            created_at: ActiveValue::Set(Utc::now()),
+            resumed_at: ActiveValue::Set(None),
+            ..Default::default()
+        }
+        .insert(&txn)
+        .await?;
+
+        for (fleet, field_values) in param.fleets {
+            let snapshot = entity::stand_down_fleet::ActiveModel {
+                stand_down_id: ActiveValue::Set(entity.id),
+                category_id: ActiveValue::Set(fleet.category_id),
+                name: ActiveValue::Set(fleet.name),
+                commander_id: ActiveValue::Set(fleet.commander_id.to_string()),
+                fleet_time: ActiveValue::Set(fleet.fleet_time),
+                description: ActiveValue::Set(fleet.description),
+                hidden: ActiveValue::Set(fleet.hidden),
+                disable_reminder: ActiveValue::Set(fleet.disable_reminder),
+                ..Default::default()
+            }
+            .insert(&txn)
+            .await?;
+
+            let field_values: Vec<entity::stand_down_fleet_field_value::ActiveModel> = field_values
+                .into_iter()
+                .map(
+                    |(field_id, value)| entity::stand_down_fleet_field_value::ActiveModel {
+                        stand_down_fleet_id: ActiveValue::Set(snapshot.id),
+                        field_id: ActiveValue::Set(field_id),
+                        value: ActiveValue::Set(value),
+                    },
+                )
+                .collect();
+
+            if !field_values.is_empty() {
+                entity::prelude::StandDownFleetFieldValue::insert_many(field_values)
+                    .exec(&txn)
+                    .await?;
+            }
+        }
+
+        FleetRepository::delete_many_with(&txn, param.deleted_fleet_ids).await?;
+
+        txn.commit().await?;
+
+        StandDown::from_entity(entity)
+    }
+
+    /// Gets the most recent stand-downs of a guild, newest first
+    ///
+    /// # Arguments
+    /// - `guild_id` - The ID of the guild the stand-downs belong to
+    /// - `limit` - Maximum amount of stand-downs to return
+    ///
+    /// # Returns
+    /// - `Ok(Vec<StandDown>)` - The stand-downs as domain models
+    /// - `Err(AppError::Database)` - Database error during query
+    pub async fn get_recent_by_guild(
+        &self,
+        guild_id: u64,
+        limit: u64,
+    ) -> Result<Vec<StandDown>, AppError> {
+        entity::prelude::StandDown::find()
+            .filter(entity::stand_down::Column::GuildId.eq(guild_id.to_string()))
+            .order_by_desc(entity::stand_down::Column::CreatedAt)
+            .order_by_desc(entity::stand_down::Column::Id)
+            .limit(limit)
+            .all(self.db)
+            .await?
+            .into_iter()
+            .map(StandDown::from_entity)
+            .collect()
+    }
+
+    /// Finds a stand-down by ID within a specific guild
+    ///
+    /// # Arguments
+    /// - `guild_id` - The ID of the guild the stand-down belongs to
+    /// - `id` - The ID of the stand-down to retrieve
+    ///
+    /// # Returns
+    /// - `Ok(Some(StandDown))` - The stand-down if found in the specified guild
+    /// - `Ok(None)` - If no stand-down exists with the given ID in the guild
+    /// - `Err(AppError::Database)` - Database error during fetch operation
+    pub async fn find_by_id(&self, guild_id: u64, id: i32) -> Result<Option<StandDown>, AppError> {
+        entity::prelude::StandDown::find_by_id(id)
+            .filter(entity::stand_down::Column::GuildId.eq(guild_id.to_string()))
+            .one(self.db)
+            .await?
+            .map(StandDown::from_entity)
+            .transpose()
+    }
+
+    /// Gets the fleet snapshots of a stand-down ordered by fleet time
+    ///
+    /// # Arguments
+    /// - `stand_down_id` - The ID of the stand-down
+    ///
+    /// # Returns
+    /// - `Ok(Vec<StandDownFleet>)` - The snapshots with their field values
+    /// - `Err(AppError::Database)` - Database error during query
+    pub async fn get_fleets(&self, stand_down_id: i32) -> Result<Vec<StandDownFleet>, AppError> {
+        let entities = entity::prelude::StandDownFleet::find()
+            .filter(entity::stand_down_fleet::Column::StandDownId.eq(stand_down_id))
+            .order_by_asc(entity::stand_down_fleet::Column::FleetTime)
+            .all(self.db)
+            .await?;
+
+        if entities.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let snapshot_ids: Vec<i32> = entities.iter().map(|e| e.id).collect();
+        let mut field_values: HashMap<i32, Vec<entity::stand_down_fleet_field_value::Model>> =
+            HashMap::new();
+        for value in entity::prelude::StandDownFleetFieldValue::find()
+            .filter(
+                entity::stand_down_fleet_field_value::Column::StandDownFleetId.is_in(snapshot_ids),
+            )
+            .all(self.db)
+            .await?
+        {
+            field_values
+                .entry(value.stand_down_fleet_id)
+                .or_default()
+                .push(value);
+        }
+
+        entities
+            .into_iter()
+            .map(|entity| {
+                let values = field_values.remove(&entity.id).unwrap_or_default();
+                StandDownFleet::from_entity(entity, values)
+            })
+            .collect()
+    }
+
+    /// Counts the fleets cancelled by each of the given stand-downs
+    ///
+    /// # Arguments
+    /// - `stand_down_ids` - IDs of the stand-downs to count the fleets of
+    ///
+    /// # Returns
+    /// - `Ok(HashMap<i32, usize>)` - Map of stand-down ID to fleet count, stand-downs
+    ///   without fleets are left out
+    /// - `Err(DbErr)` - Database error during query
+    pub async fn count_fleets(
+        &self,
+        stand_down_ids: Vec<i32>,
+    ) -> Result<HashMap<i32, usize>, DbErr> {
+        let mut counts = HashMap::new();
+        if stand_down_ids.is_empty() {
+            return Ok(counts);
+        }
+
+        let snapshot_stand_down_ids: Vec<i32> = entity::prelude::StandDownFleet::find()
+            .select_only()
+            .column(entity::stand_down_fleet::Column::StandDownId)
+            .filter(entity::stand_down_fleet::Column::StandDownId.is_in(stand_down_ids))
+            .into_tuple()
+            .all(self.db)
+            .await?;
+
+        for stand_down_id in snapshot_stand_down_ids {
+            *counts.entry(stand_down_id).or_default() += 1;
+        }
+
+        Ok(counts)
+    }
+
+    /// Marks a stand-down as resumed unless it already was
+    ///
+    /// The check & update happen in a single statement so concurrent requests can't
+    /// resume the same stand-down twice.
+    ///
+    /// # Arguments
+    /// - `id` - The ID of the stand-down to resume
+    /// - `resumed_at` - Time the stand-down was resumed
+    ///
+    /// # Returns
+    /// - `Ok(true)` - The stand-down was marked as resumed
+    /// - `Ok(false)` - The stand-down doesn't exist or was already resumed
+    /// - `Err(DbErr)` - Database error during update operation
+    pub async fn mark_resumed(&self, id: i32, resumed_at: DateTime<Utc>) -> Result<bool, DbErr> {
+        let result = entity::prelude::StandDown::update_many()
+            .col_expr(
+                entity::stand_down::Column::ResumedAt,
+                Expr::value(resumed_at),
+            )
+            .filter(entity::stand_down::Column::Id.eq(id))
+            .filter(entity::stand_down::Column::ResumedAt.is_null())
+            .exec(self.db)
+            .await?;
+
+        Ok(result.rows_affected > 0)
+    }
+}
@@ -0,0 +1,263 @@
+//! Subscription data repository for database operations.
+//!
+//! This module provides the `SubscriptionRepository` for managing the fleet categories
+//! and individual fleets users subscribed to for direct message notifications. Category
+//! subscriptions cover every fleet posted in the category while fleet subscriptions
+//! cover a single fleet.
+
+use migration::OnConflict;
+use sea_orm::{
+    sea_query::Query, ActiveValue, ColumnTrait, DatabaseConnection, DbErr, EntityTrait,
+    QueryFilter, QueryOrder,
+};
+
+use crate::server::{
+    error::AppError, model::subscription::SubscriptionTarget, util::parse::parse_u64_from_string,
+};
+
+/// Repository providing database operations for fleet subscription management.
+///
+/// This struct holds a reference to the database connection and provides methods
+/// for creating, querying, and removing category & fleet subscriptions.
+pub struct SubscriptionRepository<'a> {
+    db: &'a DatabaseConnection,
+}
+
+impl<'a> SubscriptionRepository<'a> {
+    /// Creates a new SubscriptionRepository instance
+    ///
+    /// # Arguments
+    /// - `db` - Reference to the database connection
+    ///
+    /// # Returns
+    /// - `SubscriptionRepository` - new repository instance
+    pub fn new(db: &'a DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// Subscribes a user to a fleet category
+    ///
+    /// Subscribing to a category the user is already subscribed to is a no-op.
+    ///
+    /// # Arguments
+    /// - `user_id` - Discord ID of the subscribing user
+    /// - `category_id` - ID of the fleet category to subscribe to
+    ///
+    /// # Returns
+    /// - `Ok(())` - The user is subscribed to the category
+    /// - `Err(DbErr)` - Database error during insert operation
+    pub async fn subscribe_category(&self, user_id: u64, category_id: i32) -> Result<(), DbErr> {
+        entity::prelude::CategorySubscription::insert(entity::category_subscription::ActiveModel {
+            user_id: ActiveValue::Set(user_id.to_string()),
+            category_id: ActiveValue::Set(category_id),
+            created_at: ActiveValue::Set(chrono::Utc::now()),
+        })
+        .on_conflict(
+            OnConflict::columns([
+                entity::category_subscription::Column::UserId,
+                entity::category_subscription::Column::CategoryId,
+            ])
+            .do_nothing()
+            .to_owned(),
+        )
+        .exec_without_returning(self.db)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Unsubscribes a user from a fleet category
+    ///
+    /// # Arguments
+    /// - `user_id` - Discord ID of the user
+    /// - `category_id` - ID of the fleet category to unsubscribe from
+    ///
+    /// # Returns
+    /// - `Ok(true)` - The subscription was removed
+    /// - `Ok(false)` - The user wasn't subscribed to the category
+    /// - `Err(DbErr)` - Database error during delete operation
+    pub async fn unsubscribe_category(
+        &self,
+        user_id: u64,
+        category_id: i32,
+    ) -> Result<bool, DbErr> {
+        let result = entity::prelude::CategorySubscription::delete_many()
+            .filter(entity::category_subscription::Column::UserId.eq(user_id.to_string()))
+            .filter(entity::category_subscription::Column::CategoryId.eq(category_id))
+            .exec(self.db)
+            .await?;
+
+        Ok(result.rows_affected > 0)
+    }
+
+    /// Subscribes a user to an individual fleet
+    ///
+    /// Subscribing to a fleet the user is already subscribed to is a no-op.
+    ///
+    /// # Arguments
+    /// - `user_id` - Discord ID of the subscribing user
+    /// - `fleet_id` - ID of the fleet to subscribe to
+    ///
+    /// # Returns
+    /// - `Ok(())` - The user is subscribed to the fleet
+    /// - `Err(DbErr)` - Database error during insert operation
+    pub async fn subscribe_fleet(&self, user_id: u64, fleet_id: i32) -> Result<(), DbErr> {
+        entity::prelude::FleetSubscription::insert(entity::fleet_subscription::ActiveModel {
+            user_id: ActiveValue::Set(user_id.to_string()),
+            fleet_id: ActiveValue::Set(fleet_id),
+            created_at: ActiveValue::Set(chrono::Utc::now()),
+        })
+        .on_conflict(
+            OnConflict::columns([
+                entity::fleet_subscription::Column::UserId,
+                entity::fleet_subscription::Column::FleetId,
+            ])
+            .do_nothing()
+            .to_owned(),
+        )
+        .exec_without_returning(self.db)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Unsubscribes a user from an individual fleet
+    ///
+    /// # Arguments
+    /// - `user_id` - Discord ID of the user
+    /// - `fleet_id` - ID of the fleet to unsubscribe from
+    ///
+    /// # Returns
+    /// - `Ok(true)` - The subscription was removed
+    /// - `Ok(false)` - The user wasn't subscribed to the fleet
+    /// - `Err(DbErr)` - Database error during delete operation
+    pub async fn unsubscribe_fleet(&self, user_id: u64, fleet_id: i32) -> Result<bool, DbErr> {
+        let result = entity::prelude::FleetSubscription::delete_many()
+            .filter(entity::fleet_subscription::Column::UserId.eq(user_id.to_string()))
+            .filter(entity::fleet_subscription::Column::FleetId.eq(fleet_id))
+            .exec(self.db)
+            .await?;
+
+        Ok(result.rows_affected > 0)
+    }
+
+    /// Gets the IDs of the categories of a guild the user is subscribed to
+    ///
+    /// # Arguments
+    /// - `user_id` - Discord ID of the user
+    /// - `guild_id` - Discord ID of the guild
+    ///
+    /// # Returns
+    /// - `Ok(Vec<i32>)` - Subscribed category IDs in ascending order
+    /// - `Err(DbErr)` - Database error during fetch operation
+    pub async fn get_category_ids_by_user(
+        &self,
+        user_id: u64,
+        guild_id: u64,
+    ) -> Result<Vec<i32>, DbErr> {
+        let subscriptions = entity::prelude::CategorySubscription::find()
+            .filter(entity::category_subscription::Column::UserId.eq(user_id.to_string()))
+            .filter(
+                entity::category_subscription::Column::CategoryId.in_subquery(
+                    Query::select()
+                        .column(entity::fleet_category::Column::Id)
+                        .from(entity::prelude::FleetCategory)
+                        .and_where(entity::fleet_category::Column::GuildId.eq(guild_id.to_string()))
+                        .to_owned(),
+                ),
+            )
+            .order_by_asc(entity::category_subscription::Column::CategoryId)
+            .all(self.db)
+            .await?;
+
+        Ok(subscriptions.into_iter().map(|s| s.category_id).collect())
+    }
+
+    /// Gets the IDs of the fleets of a guild the user is individually subscribed to
+    ///
+    /// # Arguments
+    /// - `user_id` - Discord ID of the user
+    /// - `guild_id` - Discord ID of the guild
+    ///
+    /// # Returns
+    /// - `Ok(Vec<i32>)` - Subscribed fleet IDs in ascending order
+    /// - `Err(DbErr)` - Database error during fetch operation
+    pub async fn get_fleet_ids_by_user(
+        &self,
+        user_id: u64,
+        guild_id: u64,
+    ) -> Result<Vec<i32>, DbErr> {
+        let subscriptions = entity::prelude::FleetSubscription::find()
+            .filter(entity::fleet_subscription::Column::UserId.eq(user_id.to_string()))
+            .filter(
+                entity::fleet_subscription::Column::FleetId.in_subquery(
+                    Query::select()
+                        .column(entity::fleet::Column::Id)
+                        .from(entity::prelude::Fleet)
+                        .and_where(
+                            entity::fleet::Column::CategoryId.in_subquery(
+                                Query::select()
+                                    .column(entity::fleet_category::Column::Id)
+                                    .from(entity::prelude::FleetCategory)
+                                    .and_where(
+                                        entity::fleet_category::Column::GuildId
+                                            .eq(guild_id.to_string()),
+                                    )
+                                    .to_owned(),
+                            ),
+                        )
+                        .to_owned(),
+                ),
+            )
+            .order_by_asc(entity::fleet_subscription::Column::FleetId)
+            .all(self.db)
+            .await?;
+
+        Ok(subscriptions.into_iter().map(|s| s.fleet_id).collect())
+    }
+
+    /// Gets the users subscribed to a fleet either directly or through its category
+    ///
+    /// Users subscribed both ways are only returned once with their category subscription,
+    /// as unsubscribing from the category is what stops further notifications.
+    ///
+    /// # Arguments
+    /// - `category_id` - ID of the fleet's category
+    /// - `fleet_id` - ID of the fleet
+    ///
+    /// # Returns
+    /// - `Ok(Vec<(u64, SubscriptionTarget)>)` - Discord IDs of the subscribed users in
+    ///   ascending order alongside the subscription they are notified through
+    /// - `Err(AppError::Database)` - Database error during fetch operation
+    /// - `Err(AppError::InternalError(ParseStringId))` - Failed to parse a stored user ID
+    pub async fn get_subscribers(
+        &self,
+        category_id: i32,
+        fleet_id: i32,
+    ) -> Result<Vec<(u64, SubscriptionTarget)>, AppError> {
+        let category_subscribers = entity::prelude::CategorySubscription::find()
+            .filter(entity::category_subscription::Column::CategoryId.eq(category_id))
+            .all(self.db)
+            .await?
+            .into_iter()
+            .map(|s| (s.user_id, SubscriptionTarget::Category(category_id)));
+
+        let fleet_subscribers = entity::prelude::FleetSubscription::find()
+            .filter(entity::fleet_subscription::Column::FleetId.eq(fleet_id))
+            .all(self.db)
+            .await?
+            .into_iter()
+            .map(|s| (s.user_id, SubscriptionTarget::Fleet(fleet_id)));
+
+        let mut subscribers = category_subscribers
+            .chain(fleet_subscribers)
+            .map(|(user_id, target)| Ok((parse_u64_from_string(user_id)?, target)))
+            .collect::<Result<Vec<_>, AppError>>()?;
+
+        // Stable sort keeps category subscriptions ahead of fleet subscriptions of the same user
+        subscribers.sort_by_key(|(user_id, _)| *user_id);
+        subscribers.dedup_by_key(|(user_id, _)| *user_id);
+
+        Ok(subscribers)
+    }
+}
@@ -0,0 +1,95 @@
+use super::*;
+
+/// Tests recording a broadcast which is posted immediately.
+///
+/// Verifies that the repository stores the broadcast's channels & roles so they
+/// round-trip into the domain model, and that it is recorded as sent right away.
+///
+/// Expected: Ok with a sent broadcast which isn't due
+#[tokio::test]
+async fn creates_immediate_broadcast_as_sent() -> Result<(), AppError> {
+    let test = TestBuilder::new()
+        .with_table(User)
+        .with_table(DiscordGuild)
+        .with_table(Broadcast)
+        .build()
+        .await
+        .unwrap();
+    let db = test.db.as_ref().unwrap();
+
+    let user = factory::create_user(db).await?;
+    let guild = factory::create_guild(db).await?;
+
+    let repo = BroadcastRepository::new(db);
+    let broadcast = repo
+        .create(CreateBroadcastParam {
+            guild_id: guild.guild_id.parse().unwrap(),
+            author_id: user.discord_id.parse().unwrap(),
+            title: "Reinforcements needed".to_string(),
+            message: "Hostiles are attacking our Athanor, join comms".to_string(),
+            channel_ids: vec![111, 222],
+            role_ids: vec![333],
+            scheduled_at: None,
+        })
+        .await?;
+
+    assert!(broadcast.id > 0);
+    assert_eq!(broadcast.guild_id.to_string(), guild.guild_id);
+    assert_eq!(
+        broadcast.author_id.map(|id| id.to_string()),
+        Some(user.discord_id)
+    );
+    assert_eq!(broadcast.channel_ids, vec![111, 222]);
+    assert_eq!(broadcast.role_ids, vec![333]);
+    assert!(broadcast.sent_at.is_some());
+    assert!(!broadcast.is_pending());
+
+    assert!(repo.get_due(Utc::now()).await?.is_empty());
+
+    Ok(())
+}
+
+/// Tests recording a scheduled broadcast.
+///
+/// Verifies that a scheduled broadcast stays pending and only becomes due once its
+/// scheduled time has passed.
+///
+/// Expected: Ok with the broadcast returned by get_due after its scheduled time
+#[tokio::test]
+async fn scheduled_broadcast_is_due_after_its_time() -> Result<(), AppError> {
+    let test = TestBuilder::new()
+        .with_table(User)
+        .with_table(DiscordGuild)
+        .with_table(Broadcast)
+        .build()
+        .await
+        .unwrap();
+    let db = test.db.as_ref().unwrap();
+
+    let user = factory::create_user(db).await?;
+    let guild = factory::create_guild(db).await?;
+    let scheduled_at = Utc::now() + Duration::hours(2);
+
+    let repo = BroadcastRepository::new(db);
+    let broadcast = repo
+        .create(CreateBroadcastParam {
+            guild_id: guild.guild_id.parse().unwrap(),
+            author_id: user.discord_id.parse().unwrap(),
+            title: "Reinforcements needed".to_string(),
+            message: "Hostiles are attacking our Athanor, join comms".to_string(),
+            channel_ids: vec![111, 222],
+            role_ids: vec![333],
+            scheduled_at: Some(scheduled_at),
+        })
+        .await?;
+
+    assert!(broadcast.sent_at.is_none());
+    assert!(broadcast.is_pending());
+
+    assert!(repo.get_due(Utc::now()).await?.is_empty());
+    let due = repo.get_due(scheduled_at + Duration::minutes(1)).await?;
+    assert_eq!(due.len(), 1);
+    assert_eq!(due[0].id, broadcast.id);
+
+    Ok(())
+}
@@ -0,0 +1,93 @@
+use super::*;
+
+/// Tests claiming a scheduled broadcast for posting.
+///
+/// Verifies that a broadcast is only marked as sent once, so overlapping scheduler
+/// runs can't post it twice, and that a sent broadcast can't be cancelled anymore.
+///
+/// Expected: Ok(true) for the first claim, Ok(false) afterwards
+#[tokio::test]
+async fn marks_broadcast_sent_once() -> Result<(), AppError> {
+    let test = TestBuilder::new()
+        .with_table(User)
+        .with_table(DiscordGuild)
+        .with_table(Broadcast)
+        .build()
+        .await
+        .unwrap();
+    let db = test.db.as_ref().unwrap();
+
+    let user = factory::create_user(db).await?;
+    let guild = factory::create_guild(db).await?;
+    let guild_id = guild.guild_id.parse::<u64>().unwrap();
+
+    let repo = BroadcastRepository::new(db);
+    let broadcast = repo
+        .create(CreateBroadcastParam {
+            guild_id: guild.guild_id.parse().unwrap(),
+            author_id: user.discord_id.parse().unwrap(),
+            title: "Reinforcements needed".to_string(),
+            message: "Hostiles are attacking our Athanor, join comms".to_string(),
+            channel_ids: vec![111, 222],
+            role_ids: vec![333],
+            scheduled_at: Some(Utc::now() + Duration::hours(1)),
+        })
+        .await?;
+
+    assert!(repo.mark_sent(broadcast.id, Utc::now()).await?);
+    assert!(!repo.mark_sent(broadcast.id, Utc::now()).await?);
+    assert!(!repo.mark_cancelled(broadcast.id, Utc::now()).await?);
+
+    repo.set_posted_count(broadcast.id, 2).await?;
+
+    let found = repo.find_by_id(guild_id, broadcast.id).await?.unwrap();
+    assert!(found.sent_at.is_some());
+    assert!(found.cancelled_at.is_none());
+    assert_eq!(found.posted_count, 2);
+
+    Ok(())
+}
+
+/// Tests cancelling a scheduled broadcast.
+///
+/// Verifies that a cancelled broadcast is no longer due and can't be claimed for
+/// posting afterwards.
+///
+/// Expected: Ok(true) for the cancel, Ok(false) for the following claim
+#[tokio::test]
+async fn cancelled_broadcast_is_not_sent() -> Result<(), AppError> {
+    let test = TestBuilder::new()
+        .with_table(User)
+        .with_table(DiscordGuild)
+        .with_table(Broadcast)
+        .build()
+        .await
+        .unwrap();
+    let db = test.db.as_ref().unwrap();
+
+    let user = factory::create_user(db).await?;
+    let guild = factory::create_guild(db).await?;
+    let scheduled_at = Utc::now() + Duration::hours(1);
+
+    let repo = BroadcastRepository::new(db);
+    let broadcast = repo
+        .create(CreateBroadcastParam {
+            guild_id: guild.guild_id.parse().unwrap(),
+            author_id: user.discord_id.parse().unwrap(),
+            title: "Reinforcements needed".to_string(),
+            message: "Hostiles are attacking our Athanor, join comms".to_string(),
+            channel_ids: vec![111, 222],
+            role_ids: vec![333],
+            scheduled_at: Some(scheduled_at),
+        })
+        .await?;
+
+    assert!(repo.mark_cancelled(broadcast.id, Utc::now()).await?);
+    assert!(repo
+        .get_due(scheduled_at + Duration::minutes(1))
+        .await?
+        .is_empty());
+    assert!(!repo.mark_sent(broadcast.id, Utc::now()).await?);
+
+    Ok(())
+}
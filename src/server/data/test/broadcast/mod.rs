@@ -0,0 +1,10 @@
+use chrono::{Duration, Utc};
+
+use crate::server::{
+    data::broadcast::BroadcastRepository, error::AppError, model::broadcast::CreateBroadcastParam,
+};
+use entity::prelude::*;
+use test_utils::{builder::TestBuilder, factory};
+
+mod create;
+mod mark_sent;
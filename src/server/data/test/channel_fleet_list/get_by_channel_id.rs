@@ -23,6 +23,7 @@ async fn returns_channel_fleet_list() -> Result<(), AppError> {
     repo.upsert(UpsertChannelFleetListParam {
         channel_id,
         message_id,
+        overflow_message_ids: Vec::new(),
     })
     .await?;
 
@@ -87,12 +88,14 @@ async fn returns_only_record_for_specified_channel() -> Result<(), AppError> {
     repo.upsert(UpsertChannelFleetListParam {
         channel_id: channel1_id,
         message_id: 111111111u64,
+        overflow_message_ids: Vec::new(),
     })
     .await?;
 
     repo.upsert(UpsertChannelFleetListParam {
         channel_id: channel2_id,
         message_id: 222222222u64,
+        overflow_message_ids: Vec::new(),
     })
     .await?;
 
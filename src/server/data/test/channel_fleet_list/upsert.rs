@@ -23,6 +23,7 @@ async fn creates_new_record() -> Result<(), AppError> {
         .upsert(UpsertChannelFleetListParam {
             channel_id,
             message_id,
+            overflow_message_ids: Vec::new(),
         })
         .await;
 
@@ -71,6 +72,7 @@ async fn updates_existing_record() -> Result<(), AppError> {
         .upsert(UpsertChannelFleetListParam {
             channel_id,
             message_id: original_message_id,
+            overflow_message_ids: Vec::new(),
         })
         .await?;
 
@@ -79,6 +81,7 @@ async fn updates_existing_record() -> Result<(), AppError> {
         .upsert(UpsertChannelFleetListParam {
             channel_id,
             message_id: new_message_id,
+            overflow_message_ids: Vec::new(),
         })
         .await;
 
@@ -122,6 +125,7 @@ async fn updates_last_message_at_timestamp() -> Result<(), AppError> {
         .upsert(UpsertChannelFleetListParam {
             channel_id,
             message_id: 111111111u64,
+            overflow_message_ids: Vec::new(),
         })
         .await?;
 
@@ -135,6 +139,7 @@ async fn updates_last_message_at_timestamp() -> Result<(), AppError> {
         .upsert(UpsertChannelFleetListParam {
             channel_id,
             message_id: 222222222u64,
+            overflow_message_ids: Vec::new(),
         })
         .await?;
 
@@ -166,6 +171,7 @@ async fn preserves_created_at_on_update() -> Result<(), AppError> {
         .upsert(UpsertChannelFleetListParam {
             channel_id,
             message_id: 111111111u64,
+            overflow_message_ids: Vec::new(),
         })
         .await?;
 
@@ -177,6 +183,7 @@ async fn preserves_created_at_on_update() -> Result<(), AppError> {
         .upsert(UpsertChannelFleetListParam {
             channel_id,
             message_id: 222222222u64,
+            overflow_message_ids: Vec::new(),
         })
         .await?;
 
@@ -208,6 +215,7 @@ async fn updates_updated_at_timestamp() -> Result<(), AppError> {
         .upsert(UpsertChannelFleetListParam {
             channel_id,
             message_id: 111111111u64,
+            overflow_message_ids: Vec::new(),
         })
         .await?;
 
@@ -221,6 +229,7 @@ async fn updates_updated_at_timestamp() -> Result<(), AppError> {
         .upsert(UpsertChannelFleetListParam {
             channel_id,
             message_id: 222222222u64,
+            overflow_message_ids: Vec::new(),
         })
         .await?;
 
@@ -251,6 +260,7 @@ async fn creates_separate_records_for_different_channels() -> Result<(), AppErro
         .upsert(UpsertChannelFleetListParam {
             channel_id: 111111111u64,
             message_id: 111111111u64,
+            overflow_message_ids: Vec::new(),
         })
         .await?;
 
@@ -258,6 +268,7 @@ async fn creates_separate_records_for_different_channels() -> Result<(), AppErro
         .upsert(UpsertChannelFleetListParam {
             channel_id: 222222222u64,
             message_id: 222222222u64,
+            overflow_message_ids: Vec::new(),
         })
         .await?;
 
@@ -265,6 +276,7 @@ async fn creates_separate_records_for_different_channels() -> Result<(), AppErro
         .upsert(UpsertChannelFleetListParam {
             channel_id: 333333333u64,
             message_id: 333333333u64,
+            overflow_message_ids: Vec::new(),
         })
         .await?;
 
@@ -279,3 +291,52 @@ async fn creates_separate_records_for_different_channels() -> Result<(), AppErro
 
     Ok(())
 }
+
+/// Tests storing the overflow messages of a list spanning multiple messages.
+///
+/// Verifies that the repository persists the overflow message IDs in order and
+/// clears them when the list shrinks back to a single message.
+///
+/// Expected: Ok with overflow message IDs stored then cleared
+#[tokio::test]
+async fn stores_overflow_message_ids() -> Result<(), AppError> {
+    let test = TestBuilder::new()
+        .with_table(entity::prelude::ChannelFleetList)
+        .build()
+        .await
+        .unwrap();
+    let db = test.db.as_ref().unwrap();
+
+    let channel_id = 123456789u64;
+    let repo = ChannelFleetListRepository::new(db);
+
+    let stored = repo
+        .upsert(UpsertChannelFleetListParam {
+            channel_id,
+            message_id: 111111111u64,
+            overflow_message_ids: vec![222222222u64, 333333333u64],
+        })
+        .await?;
+    assert_eq!(
+        stored.overflow_message_ids,
+        vec![222222222u64, 333333333u64]
+    );
+    assert_eq!(
+        stored.message_ids(),
+        vec![111111111u64, 222222222u64, 333333333u64]
+    );
+
+    let stored = repo
+        .upsert(UpsertChannelFleetListParam {
+            channel_id,
+            message_id: 111111111u64,
+            overflow_message_ids: Vec::new(),
+        })
+        .await?;
+    assert!(stored.overflow_message_ids.is_empty());
+
+    let fetched = repo.get_by_channel_id(channel_id).await?.unwrap();
+    assert_eq!(fetched.message_ids(), vec![111111111u64]);
+
+    Ok(())
+}
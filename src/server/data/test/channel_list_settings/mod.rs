@@ -0,0 +1,11 @@
+use crate::{
+    model::channel_list_settings::UpdateChannelListSettingsDto,
+    server::{
+        data::channel_list_settings::ChannelListSettingsRepository, error::AppError,
+        model::channel_list_settings::UpsertChannelListSettingsParam,
+    },
+};
+use entity::prelude::*;
+use test_utils::{builder::TestBuilder, factory};
+
+mod upsert;
@@ -0,0 +1,60 @@
+use super::*;
+
+/// Tests storing list settings twice for the same channel.
+///
+/// Verifies that channels without stored settings get the defaults and that the
+/// second upsert updates the stored settings instead of failing on the primary key.
+///
+/// Expected: Ok with defaults first, then the updated settings
+#[tokio::test]
+async fn updates_existing_settings() -> Result<(), AppError> {
+    let test = TestBuilder::new()
+        .with_table(DiscordGuild)
+        .with_table(DiscordGuildChannel)
+        .with_table(ChannelListSettings)
+        .build()
+        .await
+        .unwrap();
+    let db = test.db.as_ref().unwrap();
+
+    let guild = factory::create_guild(db).await?;
+    let channel = factory::create_guild_channel(db, &guild.guild_id, "123456789").await?;
+    let channel_id = channel.channel_id.parse::<u64>().unwrap();
+
+    let repo = ChannelListSettingsRepository::new(db);
+
+    let defaults = repo.get_by_channel(channel_id).await?;
+    assert_eq!(defaults.max_entries, None);
+    assert!(!defaults.group_by_day);
+
+    repo.upsert(UpsertChannelListSettingsParam::from_dto(
+        channel_id,
+        UpdateChannelListSettingsDto {
+            max_entries: Some(10),
+            ..UpdateChannelListSettingsDto::default()
+        },
+    ))
+    .await?;
+    repo.upsert(UpsertChannelListSettingsParam::from_dto(
+        channel_id,
+        UpdateChannelListSettingsDto {
+            lookahead_hours: Some(24),
+            group_by_day: true,
+            split_by_category: true,
+            show_revealed_hidden: true,
+            ..UpdateChannelListSettingsDto::default()
+        },
+    ))
+    .await?;
+
+    let settings = repo.get_by_channel(channel_id).await?;
+
+    assert_eq!(settings.channel_id, channel_id);
+    assert_eq!(settings.lookahead_hours, Some(24));
+    assert_eq!(settings.max_entries, None);
+    assert!(settings.group_by_day);
+    assert!(settings.split_by_category);
+    assert!(settings.show_revealed_hidden);
+
+    Ok(())
+}
@@ -0,0 +1,86 @@
+use super::*;
+
+/// Tests creating a new doctrine.
+///
+/// Verifies that the repository stores the doctrine with its fits & modules so they
+/// round-trip back into the domain model in paste order.
+///
+/// Expected: Ok with doctrine created
+#[tokio::test]
+async fn creates_doctrine_with_fits() -> Result<(), AppError> {
+    let test = TestBuilder::new()
+        .with_table(DiscordGuild)
+        .with_table(Doctrine)
+        .with_table(DoctrineFit)
+        .with_table(DoctrineFitModule)
+        .build()
+        .await
+        .unwrap();
+    let db = test.db.as_ref().unwrap();
+
+    let guild = factory::create_guild(db).await?;
+    let guild_id = guild.guild_id.parse::<u64>().unwrap();
+
+    let repo = DoctrineRepository::new(db);
+    let doctrine = repo
+        .create(
+            guild_id,
+            CreateDoctrineParam {
+                name: "Muninns".to_string(),
+                description: Some("Armor kiting".to_string()),
+                fits: FitParam::parse_eft(&format!("{}\n\n{}", MUNINN_EFT, SCIMITAR_EFT)).unwrap(),
+            },
+        )
+        .await?;
+
+    assert!(doctrine.id > 0);
+    assert_eq!(doctrine.guild_id, guild_id);
+    assert_eq!(doctrine.name, "Muninns");
+    assert_eq!(doctrine.hulls(), vec!["Muninn", "Scimitar"]);
+
+    let found = repo
+        .find_by_ids(guild_id, vec![doctrine.id])
+        .await?
+        .remove(&doctrine.id)
+        .unwrap();
+    assert_eq!(found, doctrine);
+    assert_eq!(found.fits[0].to_eft(), MUNINN_EFT);
+    assert_eq!(found.fits[1].to_eft(), SCIMITAR_EFT);
+
+    Ok(())
+}
+
+/// Tests creating a doctrine for a guild that doesn't exist.
+///
+/// Verifies that the foreign key constraint prevents creating doctrines for
+/// unknown guilds.
+///
+/// Expected: Err
+#[tokio::test]
+async fn fails_for_nonexistent_guild() -> Result<(), AppError> {
+    let test = TestBuilder::new()
+        .with_table(DiscordGuild)
+        .with_table(Doctrine)
+        .with_table(DoctrineFit)
+        .with_table(DoctrineFitModule)
+        .build()
+        .await
+        .unwrap();
+    let db = test.db.as_ref().unwrap();
+
+    let repo = DoctrineRepository::new(db);
+    let result = repo
+        .create(
+            999999999,
+            CreateDoctrineParam {
+                name: "Muninns".to_string(),
+                description: Some("Armor kiting".to_string()),
+                fits: FitParam::parse_eft(MUNINN_EFT).unwrap(),
+            },
+        )
+        .await;
+
+    assert!(result.is_err());
+
+    Ok(())
+}
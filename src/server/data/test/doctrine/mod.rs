@@ -0,0 +1,15 @@
+use crate::server::{
+    data::doctrine::DoctrineRepository,
+    error::AppError,
+    model::doctrine::{CreateDoctrineParam, FitParam, UpdateDoctrineParam},
+};
+use entity::prelude::*;
+use sea_orm::{EntityTrait, PaginatorTrait};
+use test_utils::{
+    builder::TestBuilder,
+    factory,
+    fixture::doctrine::{MUNINN_EFT, SCIMITAR_EFT},
+};
+
+mod create;
+mod update;
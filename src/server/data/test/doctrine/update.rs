@@ -0,0 +1,113 @@
+use super::*;
+
+/// Tests updating a doctrine.
+///
+/// Verifies that the name & description are updated and the doctrine's fits are
+/// replaced, removing the modules of the previous fits.
+///
+/// Expected: Ok with updated doctrine
+#[tokio::test]
+async fn replaces_fits() -> Result<(), AppError> {
+    let test = TestBuilder::new()
+        .with_table(DiscordGuild)
+        .with_table(Doctrine)
+        .with_table(DoctrineFit)
+        .with_table(DoctrineFitModule)
+        .build()
+        .await
+        .unwrap();
+    let db = test.db.as_ref().unwrap();
+
+    let guild = factory::create_guild(db).await?;
+    let guild_id = guild.guild_id.parse::<u64>().unwrap();
+
+    let repo = DoctrineRepository::new(db);
+    let doctrine = repo
+        .create(
+            guild_id,
+            CreateDoctrineParam {
+                name: "Muninns".to_string(),
+                description: Some("Armor kiting".to_string()),
+                fits: FitParam::parse_eft(MUNINN_EFT).unwrap(),
+            },
+        )
+        .await?;
+
+    let updated = repo
+        .update(
+            guild_id,
+            doctrine.id,
+            UpdateDoctrineParam {
+                name: "Logi".to_string(),
+                description: None,
+                fits: FitParam::parse_eft(SCIMITAR_EFT).unwrap(),
+            },
+        )
+        .await?;
+
+    assert_eq!(updated.id, doctrine.id);
+    assert_eq!(updated.name, "Logi");
+    assert_eq!(updated.description, None);
+    assert_eq!(updated.hulls(), vec!["Scimitar"]);
+    assert_eq!(
+        repo.find_by_ids(guild_id, vec![doctrine.id])
+            .await?
+            .remove(&doctrine.id),
+        Some(updated)
+    );
+
+    // Only the modules of the new fit remain
+    let modules = entity::prelude::DoctrineFitModule::find().count(db).await?;
+    assert_eq!(modules, 2);
+
+    Ok(())
+}
+
+/// Tests updating a doctrine of another guild.
+///
+/// Verifies that doctrines can't be updated through a guild they don't belong to.
+///
+/// Expected: Err(AppError::NotFound)
+#[tokio::test]
+async fn fails_for_doctrine_of_other_guild() -> Result<(), AppError> {
+    let test = TestBuilder::new()
+        .with_table(DiscordGuild)
+        .with_table(Doctrine)
+        .with_table(DoctrineFit)
+        .with_table(DoctrineFitModule)
+        .build()
+        .await
+        .unwrap();
+    let db = test.db.as_ref().unwrap();
+
+    let guild = factory::create_guild(db).await?;
+    let other_guild = factory::create_guild(db).await?;
+
+    let repo = DoctrineRepository::new(db);
+    let doctrine = repo
+        .create(
+            guild.guild_id.parse().unwrap(),
+            CreateDoctrineParam {
+                name: "Muninns".to_string(),
+                description: Some("Armor kiting".to_string()),
+                fits: FitParam::parse_eft(MUNINN_EFT).unwrap(),
+            },
+        )
+        .await?;
+
+    let result = repo
+        .update(
+            other_guild.guild_id.parse().unwrap(),
+            doctrine.id,
+            UpdateDoctrineParam {
+                name: "Logi".to_string(),
+                description: None,
+                fits: FitParam::parse_eft(SCIMITAR_EFT).unwrap(),
+            },
+        )
+        .await;
+
+    assert!(matches!(result, Err(AppError::NotFound(_))));
+
+    Ok(())
+}
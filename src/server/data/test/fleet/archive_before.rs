@@ -0,0 +1,113 @@
+use super::*;
+
+/// Tests archiving fleets before a cutoff.
+///
+/// Verifies that fleets scheduled before the cutoff are archived rather than deleted,
+/// keeping their field values, while later fleets are left unarchived.
+///
+/// Expected: Ok(1) with both fleets & field values remaining and only the old fleet archived
+#[tokio::test]
+async fn archives_fleets_before_cutoff() -> Result<(), AppError> {
+    let test = TestBuilder::new()
+        .with_table(User)
+        .with_table(DiscordGuild)
+        .with_table(PingFormat)
+        .with_table(PingFormatField)
+        .with_table(FleetCategory)
+        .with_table(Fleet)
+        .with_table(FleetFieldValue)
+        .build()
+        .await
+        .unwrap();
+    let db = test.db.as_ref().unwrap();
+
+    let user = factory::create_user(db).await?;
+    let guild = factory::create_guild(db).await?;
+    let ping_format = factory::create_ping_format(db, &guild.guild_id).await?;
+    let category = factory::create_category(db, &guild.guild_id, ping_format.id).await?;
+    let field =
+        factory::ping_format_field::create_ping_format_field(db, ping_format.id, "Doctrine", 1)
+            .await?;
+
+    let old_fleet = factory::fleet::FleetFactory::new(db, category.id, &user.discord_id)
+        .fleet_time(Utc::now() - Duration::days(120))
+        .build()
+        .await?;
+    let recent_fleet = factory::fleet::FleetFactory::new(db, category.id, &user.discord_id)
+        .fleet_time(Utc::now() - Duration::days(1))
+        .build()
+        .await?;
+
+    for fleet_id in [old_fleet.id, recent_fleet.id] {
+        entity::fleet_field_value::ActiveModel {
+            fleet_id: ActiveValue::Set(fleet_id),
+            field_id: ActiveValue::Set(field.id),
+            value: ActiveValue::Set("Ferox".to_string()),
+        }
+        .insert(db)
+        .await?;
+    }
+
+    let repo = FleetRepository::new(db);
+    let archived = repo.archive_before(Utc::now() - Duration::days(90)).await?;
+
+    assert_eq!(archived, 1);
+
+    let old_fleet = Fleet::find_by_id(old_fleet.id).one(db).await?.unwrap();
+    let recent_fleet = Fleet::find_by_id(recent_fleet.id).one(db).await?.unwrap();
+    assert!(old_fleet.archived);
+    assert!(!recent_fleet.archived);
+    assert_eq!(FleetFieldValue::find().count(db).await?, 2);
+
+    // Already archived fleets aren't counted again
+    assert_eq!(
+        repo.archive_before(Utc::now() - Duration::days(90)).await?,
+        0
+    );
+
+    Ok(())
+}
+
+/// Tests archiving fleets when none are before the cutoff.
+///
+/// Verifies that upcoming fleets are never archived.
+///
+/// Expected: Ok(0) with the fleet left unarchived
+#[tokio::test]
+async fn keeps_fleets_after_cutoff() -> Result<(), AppError> {
+    let test = TestBuilder::new()
+        .with_table(User)
+        .with_table(DiscordGuild)
+        .with_table(PingFormat)
+        .with_table(PingFormatField)
+        .with_table(FleetCategory)
+        .with_table(Fleet)
+        .with_table(FleetFieldValue)
+        .build()
+        .await
+        .unwrap();
+    let db = test.db.as_ref().unwrap();
+
+    let user = factory::create_user(db).await?;
+    let guild = factory::create_guild(db).await?;
+    let ping_format = factory::create_ping_format(db, &guild.guild_id).await?;
+    let category = factory::create_category(db, &guild.guild_id, ping_format.id).await?;
+    factory::fleet::FleetFactory::new(db, category.id, &user.discord_id)
+        .fleet_time(Utc::now() + Duration::days(1))
+        .build()
+        .await?;
+
+    let repo = FleetRepository::new(db);
+    let archived = repo.archive_before(Utc::now() - Duration::days(90)).await?;
+
+    assert_eq!(archived, 0);
+    assert_eq!(
+        Fleet::find()
+            .filter(entity::fleet::Column::Archived.eq(true))
+            .count(db)
+            .await?,
+        0
+    );
+
+    Ok(())
+}
@@ -0,0 +1,45 @@
+use super::*;
+
+/// Tests counting fleets before a cutoff.
+///
+/// Verifies that only unarchived fleets scheduled before the cutoff are counted.
+///
+/// Expected: Ok(1) for the old unarchived fleet
+#[tokio::test]
+async fn counts_unarchived_fleets_before_cutoff() -> Result<(), AppError> {
+    let test = TestBuilder::new()
+        .with_table(User)
+        .with_table(DiscordGuild)
+        .with_table(PingFormat)
+        .with_table(FleetCategory)
+        .with_table(Fleet)
+        .build()
+        .await
+        .unwrap();
+    let db = test.db.as_ref().unwrap();
+
+    let user = factory::create_user(db).await?;
+    let guild = factory::create_guild(db).await?;
+    let ping_format = factory::create_ping_format(db, &guild.guild_id).await?;
+    let category = factory::create_category(db, &guild.guild_id, ping_format.id).await?;
+
+    factory::fleet::FleetFactory::new(db, category.id, &user.discord_id)
+        .fleet_time(Utc::now() - Duration::days(120))
+        .build()
+        .await?;
+    factory::fleet::FleetFactory::new(db, category.id, &user.discord_id)
+        .fleet_time(Utc::now() - Duration::days(150))
+        .archived(true)
+        .build()
+        .await?;
+    factory::fleet::FleetFactory::new(db, category.id, &user.discord_id)
+        .fleet_time(Utc::now() - Duration::days(1))
+        .build()
+        .await?;
+
+    let repo = FleetRepository::new(db);
+
+    assert_eq!(repo.count_before(Utc::now() - Duration::days(90)).await?, 1);
+
+    Ok(())
+}
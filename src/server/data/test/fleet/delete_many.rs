@@ -0,0 +1,61 @@
+use super::*;
+
+/// Tests deleting multiple fleets by ID.
+///
+/// Verifies that the selected fleets are deleted along with their field values, while
+/// other fleets are kept.
+///
+/// Expected: Ok(2) with only the unselected fleet remaining
+#[tokio::test]
+async fn deletes_selected_fleets_with_field_values() -> Result<(), AppError> {
+    let test = TestBuilder::new()
+        .with_table(User)
+        .with_table(DiscordGuild)
+        .with_table(PingFormat)
+        .with_table(PingFormatField)
+        .with_table(FleetCategory)
+        .with_table(Fleet)
+        .with_table(FleetFieldValue)
+        .build()
+        .await
+        .unwrap();
+    let db = test.db.as_ref().unwrap();
+
+    let user = factory::create_user(db).await?;
+    let guild = factory::create_guild(db).await?;
+    let ping_format = factory::create_ping_format(db, &guild.guild_id).await?;
+    let category = factory::create_category(db, &guild.guild_id, ping_format.id).await?;
+    let field =
+        factory::ping_format_field::create_ping_format_field(db, ping_format.id, "Doctrine", 1)
+            .await?;
+
+    let mut fleet_ids = Vec::new();
+    for hours in 1..=3 {
+        let fleet = factory::fleet::FleetFactory::new(db, category.id, &user.discord_id)
+            .fleet_time(Utc::now() + Duration::hours(hours))
+            .build()
+            .await?;
+
+        entity::fleet_field_value::ActiveModel {
+            fleet_id: ActiveValue::Set(fleet.id),
+            field_id: ActiveValue::Set(field.id),
+            value: ActiveValue::Set("Ferox".to_string()),
+        }
+        .insert(db)
+        .await?;
+
+        fleet_ids.push(fleet.id);
+    }
+
+    let repo = FleetRepository::new(db);
+    let deleted = repo.delete_many(fleet_ids[..2].to_vec()).await?;
+
+    assert_eq!(deleted, 2);
+
+    let remaining = Fleet::find().all(db).await?;
+    assert_eq!(remaining.len(), 1);
+    assert_eq!(remaining[0].id, fleet_ids[2]);
+    assert_eq!(FleetFieldValue::find().count(db).await?, 1);
+
+    Ok(())
+}
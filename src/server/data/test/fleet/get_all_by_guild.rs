@@ -0,0 +1,96 @@
+use super::*;
+
+/// Tests retrieving every fleet of a guild with field values.
+///
+/// Verifies that past and hidden fleets are included, fleets of other guilds are
+/// excluded, results are ordered by fleet time and field values belong to their fleet.
+///
+/// Expected: Ok with both fleets of the guild and their field values
+#[tokio::test]
+async fn returns_all_fleets_of_guild_with_field_values() -> Result<(), AppError> {
+    let test = TestBuilder::new()
+        .with_table(User)
+        .with_table(DiscordGuild)
+        .with_table(PingFormat)
+        .with_table(PingFormatField)
+        .with_table(FleetCategory)
+        .with_table(Fleet)
+        .with_table(FleetFieldValue)
+        .build()
+        .await
+        .unwrap();
+    let db = test.db.as_ref().unwrap();
+
+    let user = factory::create_user(db).await?;
+    let guild = factory::create_guild(db).await?;
+    let other_guild = factory::create_guild(db).await?;
+    let ping_format = factory::create_ping_format(db, &guild.guild_id).await?;
+    let other_format = factory::create_ping_format(db, &other_guild.guild_id).await?;
+    let category = factory::create_category(db, &guild.guild_id, ping_format.id).await?;
+    let other_category =
+        factory::create_category(db, &other_guild.guild_id, other_format.id).await?;
+    let field =
+        factory::ping_format_field::create_ping_format_field(db, ping_format.id, "Doctrine", 1)
+            .await?;
+
+    let past_fleet = factory::fleet::FleetFactory::new(db, category.id, &user.discord_id)
+        .fleet_time(Utc::now() - Duration::days(30))
+        .hidden(true)
+        .build()
+        .await?;
+    let upcoming_fleet = factory::fleet::FleetFactory::new(db, category.id, &user.discord_id)
+        .fleet_time(Utc::now() + Duration::days(1))
+        .build()
+        .await?;
+    factory::create_fleet(db, other_category.id, &user.discord_id).await?;
+
+    entity::fleet_field_value::ActiveModel {
+        fleet_id: ActiveValue::Set(upcoming_fleet.id),
+        field_id: ActiveValue::Set(field.id),
+        value: ActiveValue::Set("Ferox".to_string()),
+    }
+    .insert(db)
+    .await?;
+
+    let repo = FleetRepository::new(db);
+    let fleets = repo
+        .get_all_by_guild(guild.guild_id.parse::<u64>().unwrap())
+        .await?;
+
+    assert_eq!(fleets.len(), 2);
+    assert_eq!(fleets[0].0.id, past_fleet.id);
+    assert!(fleets[0].1.is_empty());
+    assert_eq!(fleets[1].0.id, upcoming_fleet.id);
+    assert_eq!(fleets[1].1.get(&field.id), Some(&"Ferox".to_string()));
+
+    Ok(())
+}
+
+/// Tests retrieving fleets of a guild without fleets.
+///
+/// Verifies that an empty list is returned rather than an error.
+///
+/// Expected: Ok with empty list
+#[tokio::test]
+async fn returns_empty_for_guild_without_fleets() -> Result<(), AppError> {
+    let test = TestBuilder::new()
+        .with_table(DiscordGuild)
+        .with_table(FleetCategory)
+        .with_table(Fleet)
+        .with_table(FleetFieldValue)
+        .build()
+        .await
+        .unwrap();
+    let db = test.db.as_ref().unwrap();
+
+    let guild = factory::create_guild(db).await?;
+
+    let repo = FleetRepository::new(db);
+    let fleets = repo
+        .get_all_by_guild(guild.guild_id.parse::<u64>().unwrap())
+        .await?;
+
+    assert!(fleets.is_empty());
+
+    Ok(())
+}
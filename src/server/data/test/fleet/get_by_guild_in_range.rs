@@ -0,0 +1,127 @@
+use super::*;
+
+/// Tests retrieving the fleets of a guild within a time range.
+///
+/// Verifies that past and hidden fleets inside the range are included, fleets outside
+/// the range, of other guilds or archived are excluded and results are ordered by fleet time.
+///
+/// Expected: Ok with the two fleets inside the range
+#[tokio::test]
+async fn returns_fleets_within_range() -> Result<(), AppError> {
+    let test = TestBuilder::new()
+        .with_table(User)
+        .with_table(DiscordGuild)
+        .with_table(PingFormat)
+        .with_table(FleetCategory)
+        .with_table(Fleet)
+        .build()
+        .await
+        .unwrap();
+    let db = test.db.as_ref().unwrap();
+
+    let user = factory::create_user(db).await?;
+    let guild = factory::create_guild(db).await?;
+    let other_guild = factory::create_guild(db).await?;
+    let ping_format = factory::create_ping_format(db, &guild.guild_id).await?;
+    let other_format = factory::create_ping_format(db, &other_guild.guild_id).await?;
+    let category = factory::create_category(db, &guild.guild_id, ping_format.id).await?;
+    let other_category =
+        factory::create_category(db, &other_guild.guild_id, other_format.id).await?;
+
+    let now = Utc::now();
+    let past_fleet = factory::fleet::FleetFactory::new(db, category.id, &user.discord_id)
+        .fleet_time(now - Duration::days(2))
+        .hidden(true)
+        .build()
+        .await?;
+    let upcoming_fleet = factory::fleet::FleetFactory::new(db, category.id, &user.discord_id)
+        .fleet_time(now + Duration::days(2))
+        .build()
+        .await?;
+    factory::fleet::FleetFactory::new(db, category.id, &user.discord_id)
+        .fleet_time(now + Duration::days(10))
+        .build()
+        .await?;
+    factory::fleet::FleetFactory::new(db, other_category.id, &user.discord_id)
+        .fleet_time(now)
+        .build()
+        .await?;
+    factory::fleet::FleetFactory::new(db, category.id, &user.discord_id)
+        .fleet_time(now - Duration::days(3))
+        .archived(true)
+        .build()
+        .await?;
+
+    let repo = FleetRepository::new(db);
+    let fleets = repo
+        .get_by_guild_in_range(
+            guild.guild_id.parse::<u64>().unwrap(),
+            now - Duration::days(7),
+            now + Duration::days(7),
+            None,
+        )
+        .await?;
+
+    assert_eq!(fleets.len(), 2);
+    assert_eq!(fleets[0].id, past_fleet.id);
+    assert_eq!(fleets[1].id, upcoming_fleet.id);
+
+    Ok(())
+}
+
+/// Tests retrieving fleets within a range limited to viewable categories.
+///
+/// Verifies that fleets of categories the user can't view are excluded and that an
+/// empty list of viewable categories returns no fleets.
+///
+/// Expected: Ok with only the fleet of the viewable category
+#[tokio::test]
+async fn filters_by_viewable_categories() -> Result<(), AppError> {
+    let test = TestBuilder::new()
+        .with_table(User)
+        .with_table(DiscordGuild)
+        .with_table(PingFormat)
+        .with_table(FleetCategory)
+        .with_table(Fleet)
+        .build()
+        .await
+        .unwrap();
+    let db = test.db.as_ref().unwrap();
+
+    let user = factory::create_user(db).await?;
+    let guild = factory::create_guild(db).await?;
+    let ping_format = factory::create_ping_format(db, &guild.guild_id).await?;
+    let category = factory::create_category(db, &guild.guild_id, ping_format.id).await?;
+    let hidden_category = factory::create_category(db, &guild.guild_id, ping_format.id).await?;
+
+    let now = Utc::now();
+    let fleet = factory::fleet::FleetFactory::new(db, category.id, &user.discord_id)
+        .fleet_time(now + Duration::hours(1))
+        .build()
+        .await?;
+    factory::fleet::FleetFactory::new(db, hidden_category.id, &user.discord_id)
+        .fleet_time(now + Duration::hours(2))
+        .build()
+        .await?;
+
+    let guild_id = guild.guild_id.parse::<u64>().unwrap();
+    let repo = FleetRepository::new(db);
+
+    let fleets = repo
+        .get_by_guild_in_range(
+            guild_id,
+            now,
+            now + Duration::days(1),
+            Some(vec![category.id]),
+        )
+        .await?;
+    assert_eq!(fleets.len(), 1);
+    assert_eq!(fleets[0].id, fleet.id);
+
+    let fleets = repo
+        .get_by_guild_in_range(guild_id, now, now + Duration::days(1), Some(Vec::new()))
+        .await?;
+    assert!(fleets.is_empty());
+
+    Ok(())
+}
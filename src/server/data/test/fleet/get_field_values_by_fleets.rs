@@ -0,0 +1,65 @@
+use super::*;
+
+/// Tests getting the field values of several fleets at once.
+///
+/// Verifies that field values are grouped by fleet, fleets without values are left
+/// out & fleets which weren't requested aren't returned.
+///
+/// Expected: Ok with the field values of the requested fleets
+#[tokio::test]
+async fn groups_field_values_by_fleet() -> Result<(), AppError> {
+    let test = TestBuilder::new()
+        .with_fleet_tables()
+        .build()
+        .await
+        .unwrap();
+    let db = test.db.as_ref().unwrap();
+
+    let (user, _guild, ping_format, category) =
+        factory::helpers::create_fleet_dependencies(db).await?;
+    let doctrine =
+        factory::ping_format_field::create_ping_format_field(db, ping_format.id, "Doctrine", 1)
+            .await?;
+    let comms =
+        factory::ping_format_field::create_ping_format_field(db, ping_format.id, "Comms", 2)
+            .await?;
+
+    let mut fleet_ids = Vec::new();
+    for _ in 0..3 {
+        let fleet = factory::fleet::FleetFactory::new(db, category.id, &user.discord_id)
+            .build()
+            .await?;
+        fleet_ids.push(fleet.id);
+    }
+
+    for (fleet_id, field_id, value) in [
+        (fleet_ids[0], doctrine.id, "Ferox"),
+        (fleet_ids[0], comms.id, "Mumble"),
+        (fleet_ids[2], doctrine.id, "Muninn"),
+    ] {
+        entity::fleet_field_value::ActiveModel {
+            fleet_id: ActiveValue::Set(fleet_id),
+            field_id: ActiveValue::Set(field_id),
+            value: ActiveValue::Set(value.to_string()),
+        }
+        .insert(db)
+        .await?;
+    }
+
+    let repo = FleetRepository::new(db);
+    let field_values = repo
+        .get_field_values_by_fleets(fleet_ids[..2].to_vec())
+        .await?;
+
+    assert_eq!(field_values.len(), 1);
+    let values = field_values.get(&fleet_ids[0]).unwrap();
+    assert_eq!(values.get(&doctrine.id).map(String::as_str), Some("Ferox"));
+    assert_eq!(values.get(&comms.id).map(String::as_str), Some("Mumble"));
+
+    assert!(repo
+        .get_field_values_by_fleets(Vec::new())
+        .await?
+        .is_empty());
+
+    Ok(())
+}
@@ -0,0 +1,123 @@
+use super::*;
+
+/// Tests retrieving upcoming fleets across several guilds.
+///
+/// Verifies that fleets of every requested guild are merged and ordered by fleet time,
+/// while fleets of other guilds and fleets older than an hour are excluded.
+///
+/// Expected: Ok with the two upcoming fleets of the requested guilds
+#[tokio::test]
+async fn merges_fleets_of_guilds() -> Result<(), AppError> {
+    let test = TestBuilder::new()
+        .with_table(User)
+        .with_table(DiscordGuild)
+        .with_table(PingFormat)
+        .with_table(FleetCategory)
+        .with_table(Fleet)
+        .build()
+        .await
+        .unwrap();
+    let db = test.db.as_ref().unwrap();
+
+    let user = factory::create_user(db).await?;
+    let guild = factory::create_guild(db).await?;
+    let second_guild = factory::create_guild(db).await?;
+    let other_guild = factory::create_guild(db).await?;
+    let ping_format = factory::create_ping_format(db, &guild.guild_id).await?;
+    let second_format = factory::create_ping_format(db, &second_guild.guild_id).await?;
+    let other_format = factory::create_ping_format(db, &other_guild.guild_id).await?;
+    let category = factory::create_category(db, &guild.guild_id, ping_format.id).await?;
+    let second_category =
+        factory::create_category(db, &second_guild.guild_id, second_format.id).await?;
+    let other_category =
+        factory::create_category(db, &other_guild.guild_id, other_format.id).await?;
+
+    let now = Utc::now();
+    let later_fleet = factory::fleet::FleetFactory::new(db, category.id, &user.discord_id)
+        .fleet_time(now + Duration::hours(3))
+        .build()
+        .await?;
+    let first_fleet = factory::fleet::FleetFactory::new(db, second_category.id, &user.discord_id)
+        .fleet_time(now + Duration::hours(1))
+        .build()
+        .await?;
+    factory::fleet::FleetFactory::new(db, category.id, &user.discord_id)
+        .fleet_time(now - Duration::hours(2))
+        .build()
+        .await?;
+    factory::fleet::FleetFactory::new(db, other_category.id, &user.discord_id)
+        .fleet_time(now + Duration::hours(2))
+        .build()
+        .await?;
+
+    let guild_ids = [
+        guild.guild_id.parse::<u64>().unwrap(),
+        second_guild.guild_id.parse::<u64>().unwrap(),
+    ];
+    let repo = FleetRepository::new(db);
+    let (fleets, total) = repo
+        .get_paginated_by_guilds(&guild_ids, 0, 10, None)
+        .await?;
+
+    assert_eq!(total, 2);
+    assert_eq!(fleets.len(), 2);
+    assert_eq!(fleets[0].id, first_fleet.id);
+    assert_eq!(fleets[1].id, later_fleet.id);
+
+    Ok(())
+}
+
+/// Tests retrieving fleets across guilds limited to viewable categories.
+///
+/// Verifies that fleets of categories the user can't view are excluded and that no
+/// guilds or an empty list of viewable categories returns no fleets.
+///
+/// Expected: Ok with only the fleet of the viewable category
+#[tokio::test]
+async fn filters_by_viewable_categories() -> Result<(), AppError> {
+    let test = TestBuilder::new()
+        .with_table(User)
+        .with_table(DiscordGuild)
+        .with_table(PingFormat)
+        .with_table(FleetCategory)
+        .with_table(Fleet)
+        .build()
+        .await
+        .unwrap();
+    let db = test.db.as_ref().unwrap();
+
+    let user = factory::create_user(db).await?;
+    let guild = factory::create_guild(db).await?;
+    let ping_format = factory::create_ping_format(db, &guild.guild_id).await?;
+    let category = factory::create_category(db, &guild.guild_id, ping_format.id).await?;
+    let hidden_category = factory::create_category(db, &guild.guild_id, ping_format.id).await?;
+
+    let now = Utc::now();
+    let fleet = factory::fleet::FleetFactory::new(db, category.id, &user.discord_id)
+        .fleet_time(now + Duration::hours(1))
+        .build()
+        .await?;
+    factory::fleet::FleetFactory::new(db, hidden_category.id, &user.discord_id)
+        .fleet_time(now + Duration::hours(2))
+        .build()
+        .await?;
+
+    let guild_ids = [guild.guild_id.parse::<u64>().unwrap()];
+    let repo = FleetRepository::new(db);
+
+    let (fleets, total) = repo
+        .get_paginated_by_guilds(&guild_ids, 0, 10, Some(vec![category.id]))
+        .await?;
+    assert_eq!(total, 1);
+    assert_eq!(fleets[0].id, fleet.id);
+
+    let (fleets, _) = repo
+        .get_paginated_by_guilds(&guild_ids, 0, 10, Some(Vec::new()))
+        .await?;
+    assert!(fleets.is_empty());
+
+    let (fleets, _) = repo.get_paginated_by_guilds(&[], 0, 10, None).await?;
+    assert!(fleets.is_empty());
+
+    Ok(())
+}
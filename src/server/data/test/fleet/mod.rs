@@ -0,0 +1,16 @@
+use crate::server::{data::fleet::FleetRepository, error::AppError};
+use chrono::{Duration, Utc};
+use entity::prelude::*;
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, EntityTrait, PaginatorTrait, QueryFilter,
+};
+use test_utils::{builder::TestBuilder, factory};
+
+mod archive_before;
+mod count_before;
+mod delete_many;
+mod get_all_by_guild;
+mod get_by_guild_in_range;
+mod get_field_values_by_fleets;
+mod get_paginated_by_guilds;
+mod update_many;
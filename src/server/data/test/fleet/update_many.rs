@@ -0,0 +1,154 @@
+use super::*;
+use crate::server::model::fleet::UpdateFleetParam;
+
+/// Tests updating multiple fleets at once.
+///
+/// Verifies that every fleet receives its own new time and the updated fleets are
+/// returned in the order of the params.
+///
+/// Expected: Ok with both fleets moved to their new times
+#[tokio::test]
+async fn updates_all_fleets() -> Result<(), AppError> {
+    let test = TestBuilder::new()
+        .with_table(User)
+        .with_table(DiscordGuild)
+        .with_table(PingFormat)
+        .with_table(PingFormatField)
+        .with_table(FleetCategory)
+        .with_table(Fleet)
+        .with_table(FleetFieldValue)
+        .build()
+        .await
+        .unwrap();
+    let db = test.db.as_ref().unwrap();
+
+    let user = factory::create_user(db).await?;
+    let guild = factory::create_guild(db).await?;
+    let ping_format = factory::create_ping_format(db, &guild.guild_id).await?;
+    let category = factory::create_category(db, &guild.guild_id, ping_format.id).await?;
+
+    let time = Utc::now()
+        .date_naive()
+        .and_hms_opt(19, 0, 0)
+        .unwrap()
+        .and_utc()
+        + Duration::days(1);
+    let first = factory::fleet::FleetFactory::new(db, category.id, &user.discord_id)
+        .fleet_time(time)
+        .build()
+        .await?;
+    let second = factory::fleet::FleetFactory::new(db, category.id, &user.discord_id)
+        .fleet_time(time + Duration::hours(1))
+        .build()
+        .await?;
+
+    let shift = Duration::minutes(30);
+    let repo = FleetRepository::new(db);
+    let updated = repo
+        .update_many(vec![
+            UpdateFleetParam {
+                id: first.id,
+                category_id: None,
+                name: None,
+                fleet_time: Some(first.fleet_time + shift),
+                description: None,
+                field_values: None,
+                hidden: None,
+                disable_reminder: None,
+            },
+            UpdateFleetParam {
+                id: second.id,
+                category_id: None,
+                name: None,
+                fleet_time: Some(second.fleet_time + shift),
+                description: None,
+                field_values: None,
+                hidden: None,
+                disable_reminder: None,
+            },
+        ])
+        .await?;
+
+    assert_eq!(updated.len(), 2);
+    assert_eq!(updated[0].id, first.id);
+    assert_eq!(updated[1].id, second.id);
+
+    let stored_first = Fleet::find_by_id(first.id).one(db).await?.unwrap();
+    let stored_second = Fleet::find_by_id(second.id).one(db).await?.unwrap();
+    assert_eq!(stored_first.fleet_time, first.fleet_time + shift);
+    assert_eq!(stored_second.fleet_time, second.fleet_time + shift);
+
+    Ok(())
+}
+
+/// Tests that a failing update rolls back the whole batch.
+///
+/// Verifies that when one of the fleets doesn't exist, the fleets updated before it
+/// keep their original values.
+///
+/// Expected: Err with the existing fleet unchanged
+#[tokio::test]
+async fn rolls_back_when_any_update_fails() -> Result<(), AppError> {
+    let test = TestBuilder::new()
+        .with_table(User)
+        .with_table(DiscordGuild)
+        .with_table(PingFormat)
+        .with_table(PingFormatField)
+        .with_table(FleetCategory)
+        .with_table(Fleet)
+        .with_table(FleetFieldValue)
+        .build()
+        .await
+        .unwrap();
+    let db = test.db.as_ref().unwrap();
+
+    let user = factory::create_user(db).await?;
+    let guild = factory::create_guild(db).await?;
+    let ping_format = factory::create_ping_format(db, &guild.guild_id).await?;
+    let category = factory::create_category(db, &guild.guild_id, ping_format.id).await?;
+
+    let fleet = factory::fleet::FleetFactory::new(db, category.id, &user.discord_id)
+        .fleet_time(
+            Utc::now()
+                .date_naive()
+                .and_hms_opt(19, 0, 0)
+                .unwrap()
+                .and_utc()
+                + Duration::days(1),
+        )
+        .build()
+        .await?;
+
+    let repo = FleetRepository::new(db);
+    let result = repo
+        .update_many(vec![
+            UpdateFleetParam {
+                id: fleet.id,
+                category_id: None,
+                name: None,
+                fleet_time: Some(fleet.fleet_time + Duration::minutes(30)),
+                description: None,
+                field_values: None,
+                hidden: None,
+                disable_reminder: None,
+            },
+            UpdateFleetParam {
+                id: fleet.id + 1000,
+                category_id: None,
+                name: None,
+                fleet_time: Some(fleet.fleet_time),
+                description: None,
+                field_values: None,
+                hidden: None,
+                disable_reminder: None,
+            },
+        ])
+        .await;
+
+    assert!(result.is_err());
+
+    let stored = Fleet::find_by_id(fleet.id).one(db).await?.unwrap();
+    assert_eq!(stored.fleet_time, fleet.fleet_time);
+
+    Ok(())
+}
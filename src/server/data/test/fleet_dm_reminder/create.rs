@@ -0,0 +1,29 @@
+use super::*;
+
+/// Tests recording the same reminder twice.
+///
+/// Verifies that recording a reminder that was already sent is ignored so the
+/// user is only listed once.
+///
+/// Expected: Ok with the user listed once for the fleet
+#[tokio::test]
+async fn ignores_duplicate_reminder() -> Result<(), AppError> {
+    let test = TestBuilder::new()
+        .with_fleet_tables()
+        .with_table(FleetDmReminder)
+        .build()
+        .await
+        .unwrap();
+    let db = test.db.as_ref().unwrap();
+
+    let (user, _, _, _, fleet) = factory::helpers::create_fleet_with_dependencies(db).await?;
+    let user_id = user.discord_id.parse::<u64>().unwrap();
+
+    let repo = FleetDmReminderRepository::new(db);
+    repo.create(fleet.id, user_id).await?;
+    repo.create(fleet.id, user_id).await?;
+
+    assert_eq!(repo.get_user_ids_by_fleet(fleet.id).await?, vec![user_id]);
+
+    Ok(())
+}
@@ -0,0 +1,37 @@
+use super::*;
+
+/// Tests resetting the reminders of a fleet.
+///
+/// Verifies that only the reminders of the provided fleet are deleted.
+///
+/// Expected: Ok with the other fleet's reminders retained
+#[tokio::test]
+async fn deletes_reminders_of_fleet_only() -> Result<(), AppError> {
+    let test = TestBuilder::new()
+        .with_fleet_tables()
+        .with_table(FleetDmReminder)
+        .build()
+        .await
+        .unwrap();
+    let db = test.db.as_ref().unwrap();
+
+    let (user, _, _, category, fleet) =
+        factory::helpers::create_fleet_with_dependencies(db).await?;
+    let other_fleet = factory::create_fleet(db, category.id, &user.discord_id).await?;
+    let user_id = user.discord_id.parse::<u64>().unwrap();
+
+    let repo = FleetDmReminderRepository::new(db);
+    repo.create(fleet.id, user_id).await?;
+    repo.create(other_fleet.id, user_id).await?;
+
+    let deleted = repo.delete_by_fleet(fleet.id).await?;
+
+    assert_eq!(deleted, 1);
+    assert!(repo.get_user_ids_by_fleet(fleet.id).await?.is_empty());
+    assert_eq!(
+        repo.get_user_ids_by_fleet(other_fleet.id).await?,
+        vec![user_id]
+    );
+
+    Ok(())
+}
@@ -0,0 +1,6 @@
+use crate::server::{data::fleet_dm_reminder::FleetDmReminderRepository, error::AppError};
+use entity::prelude::*;
+use test_utils::{builder::TestBuilder, factory};
+
+mod create;
+mod delete_by_fleet;
@@ -0,0 +1,92 @@
+use super::*;
+
+/// Tests creating a forum post message record.
+///
+/// Verifies that the forum post thread is stored alongside the forum channel and
+/// that replies and edits are directed to the thread rather than the forum.
+///
+/// Expected: Ok with thread_id stored and used as the message channel
+#[tokio::test]
+async fn creates_message_with_thread_id() -> Result<(), AppError> {
+    let test = TestBuilder::new()
+        .with_table(User)
+        .with_table(DiscordGuild)
+        .with_table(PingFormat)
+        .with_table(FleetCategory)
+        .with_table(Fleet)
+        .with_table(FleetMessage)
+        .build()
+        .await
+        .unwrap();
+    let db = test.db.as_ref().unwrap();
+
+    let user = factory::create_user(db).await?;
+    let guild = factory::create_guild(db).await?;
+    let ping_format = factory::create_ping_format(db, &guild.guild_id).await?;
+    let category = factory::create_category(db, &guild.guild_id, ping_format.id).await?;
+    let fleet = factory::create_fleet(db, category.id, &user.discord_id).await?;
+
+    let repo = FleetMessageRepository::new(db);
+    let message = repo
+        .create(CreateFleetMessageParam {
+            fleet_id: fleet.id,
+            channel_id: 111111111111111111,
+            message_id: 333333333333333333,
+            message_type: "creation".to_string(),
+            thread_id: Some(333333333333333333),
+        })
+        .await?;
+
+    assert_eq!(message.channel_id, 111111111111111111);
+    assert_eq!(message.thread_id, Some(333333333333333333));
+    assert_eq!(message.message_channel_id(), 333333333333333333);
+
+    let stored = repo.get_by_fleet_id(fleet.id).await?;
+    assert_eq!(stored.len(), 1);
+    assert_eq!(stored[0].thread_id, Some(333333333333333333));
+
+    Ok(())
+}
+
+/// Tests creating a regular channel message record.
+///
+/// Verifies that messages posted outside of forum channels have no thread and
+/// are edited in the channel they were posted to.
+///
+/// Expected: Ok with thread_id None and the channel used as the message channel
+#[tokio::test]
+async fn creates_message_without_thread_id() -> Result<(), AppError> {
+    let test = TestBuilder::new()
+        .with_table(User)
+        .with_table(DiscordGuild)
+        .with_table(PingFormat)
+        .with_table(FleetCategory)
+        .with_table(Fleet)
+        .with_table(FleetMessage)
+        .build()
+        .await
+        .unwrap();
+    let db = test.db.as_ref().unwrap();
+
+    let user = factory::create_user(db).await?;
+    let guild = factory::create_guild(db).await?;
+    let ping_format = factory::create_ping_format(db, &guild.guild_id).await?;
+    let category = factory::create_category(db, &guild.guild_id, ping_format.id).await?;
+    let fleet = factory::create_fleet(db, category.id, &user.discord_id).await?;
+
+    let repo = FleetMessageRepository::new(db);
+    let message = repo
+        .create(CreateFleetMessageParam {
+            fleet_id: fleet.id,
+            channel_id: 111111111111111111,
+            message_id: 222222222222222222,
+            message_type: "creation".to_string(),
+            thread_id: None,
+        })
+        .await?;
+
+    assert_eq!(message.thread_id, None);
+    assert_eq!(message.message_channel_id(), 111111111111111111);
+
+    Ok(())
+}
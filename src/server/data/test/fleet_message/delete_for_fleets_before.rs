@@ -0,0 +1,67 @@
+use super::*;
+
+/// Tests deleting messages of completed fleets.
+///
+/// Verifies that messages of fleets scheduled before the cutoff are counted and
+/// deleted while messages of later fleets and the fleets themselves are kept.
+///
+/// Expected: Ok(2) with only the upcoming fleet's message remaining
+#[tokio::test]
+async fn deletes_messages_of_fleets_before_cutoff() -> Result<(), AppError> {
+    let test = TestBuilder::new()
+        .with_table(User)
+        .with_table(DiscordGuild)
+        .with_table(PingFormat)
+        .with_table(FleetCategory)
+        .with_table(Fleet)
+        .with_table(FleetMessage)
+        .build()
+        .await
+        .unwrap();
+    let db = test.db.as_ref().unwrap();
+
+    let user = factory::create_user(db).await?;
+    let guild = factory::create_guild(db).await?;
+    let ping_format = factory::create_ping_format(db, &guild.guild_id).await?;
+    let category = factory::create_category(db, &guild.guild_id, ping_format.id).await?;
+
+    let completed_fleet = factory::fleet::FleetFactory::new(db, category.id, &user.discord_id)
+        .fleet_time(Utc::now() - Duration::days(2))
+        .build()
+        .await?;
+    let upcoming_fleet = factory::fleet::FleetFactory::new(db, category.id, &user.discord_id)
+        .fleet_time(Utc::now() + Duration::hours(2))
+        .build()
+        .await?;
+
+    let repo = FleetMessageRepository::new(db);
+    for (message_id, (fleet_id, message_type)) in [
+        (completed_fleet.id, "creation"),
+        (completed_fleet.id, "formup"),
+        (upcoming_fleet.id, "creation"),
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        repo.create(CreateFleetMessageParam {
+            fleet_id,
+            channel_id: 111111111111111111,
+            message_id: 222222222222222222 + message_id as u64,
+            message_type: message_type.to_string(),
+            thread_id: None,
+        })
+        .await?;
+    }
+
+    let cutoff = Utc::now() - Duration::hours(24);
+
+    assert_eq!(repo.count_for_fleets_before(cutoff).await?, 2);
+    assert_eq!(repo.delete_for_fleets_before(cutoff).await?, 2);
+
+    let remaining = FleetMessage::find().all(db).await?;
+    assert_eq!(remaining.len(), 1);
+    assert_eq!(remaining[0].fleet_id, upcoming_fleet.id);
+    assert_eq!(Fleet::find().count(db).await?, 2);
+
+    Ok(())
+}
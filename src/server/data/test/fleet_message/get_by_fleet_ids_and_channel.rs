@@ -0,0 +1,64 @@
+use super::*;
+
+/// Tests getting the messages of several fleets in one channel.
+///
+/// Verifies that messages of every requested fleet in the channel are returned while
+/// messages in other channels and of other fleets are left out.
+///
+/// Expected: Ok with only the requested fleets' messages in the channel
+#[tokio::test]
+async fn returns_messages_of_requested_fleets_in_channel() -> Result<(), AppError> {
+    let test = TestBuilder::new()
+        .with_table(User)
+        .with_table(DiscordGuild)
+        .with_table(PingFormat)
+        .with_table(FleetCategory)
+        .with_table(Fleet)
+        .with_table(FleetMessage)
+        .build()
+        .await
+        .unwrap();
+    let db = test.db.as_ref().unwrap();
+
+    let user = factory::create_user(db).await?;
+    let guild = factory::create_guild(db).await?;
+    let ping_format = factory::create_ping_format(db, &guild.guild_id).await?;
+    let category = factory::create_category(db, &guild.guild_id, ping_format.id).await?;
+    let fleet_a = factory::create_fleet(db, category.id, &user.discord_id).await?;
+    let fleet_b = factory::create_fleet(db, category.id, &user.discord_id).await?;
+    let fleet_c = factory::create_fleet(db, category.id, &user.discord_id).await?;
+
+    let channel_id = 111111111111111111;
+    let repo = FleetMessageRepository::new(db);
+    for (fleet_id, channel_id, message_id) in [
+        (fleet_a.id, channel_id, 1),
+        (fleet_b.id, channel_id, 2),
+        (fleet_b.id, 222222222222222222, 3),
+        (fleet_c.id, channel_id, 4),
+    ] {
+        repo.create(CreateFleetMessageParam {
+            fleet_id,
+            channel_id,
+            message_id,
+            message_type: "creation".to_string(),
+            thread_id: None,
+        })
+        .await?;
+    }
+
+    let mut message_ids: Vec<u64> = repo
+        .get_by_fleet_ids_and_channel(&[fleet_a.id, fleet_b.id], channel_id)
+        .await?
+        .into_iter()
+        .map(|message| message.message_id)
+        .collect();
+    message_ids.sort();
+
+    assert_eq!(message_ids, vec![1, 2]);
+    assert!(repo
+        .get_by_fleet_ids_and_channel(&[], channel_id)
+        .await?
+        .is_empty());
+
+    Ok(())
+}
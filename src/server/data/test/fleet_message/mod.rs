@@ -0,0 +1,12 @@
+use crate::server::{
+    data::fleet_message::FleetMessageRepository, error::AppError,
+    model::fleet_message::CreateFleetMessageParam,
+};
+use chrono::{Duration, Utc};
+use entity::prelude::*;
+use sea_orm::{EntityTrait, PaginatorTrait};
+use test_utils::{builder::TestBuilder, factory};
+
+mod create;
+mod delete_for_fleets_before;
+mod get_by_fleet_ids_and_channel;
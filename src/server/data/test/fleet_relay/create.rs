@@ -0,0 +1,88 @@
+use super::*;
+
+/// Tests creating a new fleet relay request.
+///
+/// Verifies that the repository creates a pending relay without a target category
+/// for the provided source category & target guild.
+///
+/// Expected: Ok with pending relay created
+#[tokio::test]
+async fn creates_pending_relay() -> Result<(), AppError> {
+    let test = TestBuilder::new()
+        .with_table(DiscordGuild)
+        .with_table(PingFormat)
+        .with_table(FleetCategory)
+        .with_table(FleetRelay)
+        .build()
+        .await
+        .unwrap();
+    let db = test.db.as_ref().unwrap();
+
+    let guild = factory::create_guild(db).await?;
+    let target_guild = factory::create_guild(db).await?;
+    let ping_format = factory::create_ping_format(db, &guild.guild_id).await?;
+    let category = factory::create_category(db, &guild.guild_id, ping_format.id).await?;
+    let target_guild_id = target_guild.guild_id.parse::<u64>().unwrap();
+
+    let repo = FleetRelayRepository::new(db);
+    let relay = repo
+        .create(CreateFleetRelayParam {
+            source_category_id: category.id,
+            target_guild_id,
+        })
+        .await?;
+
+    assert!(relay.id > 0);
+    assert_eq!(relay.source_category_id, category.id);
+    assert_eq!(relay.target_guild_id, target_guild_id);
+    assert_eq!(relay.target_category_id, None);
+    assert_eq!(relay.status, FleetRelayStatus::Pending);
+
+    Ok(())
+}
+
+/// Tests finding an existing relay by source category & target guild.
+///
+/// Verifies that the relay requested for a category is found for its target guild
+/// and not for other guilds, used to reject duplicate relay requests.
+///
+/// Expected: Ok with the relay found only for its target guild
+#[tokio::test]
+async fn finds_relay_by_source_and_target() -> Result<(), AppError> {
+    let test = TestBuilder::new()
+        .with_table(DiscordGuild)
+        .with_table(PingFormat)
+        .with_table(FleetCategory)
+        .with_table(FleetRelay)
+        .build()
+        .await
+        .unwrap();
+    let db = test.db.as_ref().unwrap();
+
+    let guild = factory::create_guild(db).await?;
+    let target_guild = factory::create_guild(db).await?;
+    let other_guild = factory::create_guild(db).await?;
+    let ping_format = factory::create_ping_format(db, &guild.guild_id).await?;
+    let category = factory::create_category(db, &guild.guild_id, ping_format.id).await?;
+    let target_guild_id = target_guild.guild_id.parse::<u64>().unwrap();
+
+    let repo = FleetRelayRepository::new(db);
+    let relay = repo
+        .create(CreateFleetRelayParam {
+            source_category_id: category.id,
+            target_guild_id,
+        })
+        .await?;
+
+    let found = repo
+        .find_by_source_and_target(category.id, target_guild_id)
+        .await?;
+    assert_eq!(found.map(|r| r.id), Some(relay.id));
+
+    let not_found = repo
+        .find_by_source_and_target(category.id, other_guild.guild_id.parse::<u64>().unwrap())
+        .await?;
+    assert!(not_found.is_none());
+
+    Ok(())
+}
@@ -0,0 +1,60 @@
+use super::*;
+
+/// Tests retrieving the relays new fleets of a category are copied along.
+///
+/// Verifies that only accepted relays are returned, pending and declined relays
+/// don't relay any fleets.
+///
+/// Expected: Ok with only the accepted relay
+#[tokio::test]
+async fn returns_only_accepted_relays() -> Result<(), AppError> {
+    let test = TestBuilder::new()
+        .with_table(DiscordGuild)
+        .with_table(PingFormat)
+        .with_table(FleetCategory)
+        .with_table(FleetRelay)
+        .build()
+        .await
+        .unwrap();
+    let db = test.db.as_ref().unwrap();
+
+    let guild = factory::create_guild(db).await?;
+    let accepting_guild = factory::create_guild(db).await?;
+    let declining_guild = factory::create_guild(db).await?;
+    let pending_guild = factory::create_guild(db).await?;
+    let ping_format = factory::create_ping_format(db, &guild.guild_id).await?;
+    let category = factory::create_category(db, &guild.guild_id, ping_format.id).await?;
+    let target_format = factory::create_ping_format(db, &accepting_guild.guild_id).await?;
+    let target_category =
+        factory::create_category(db, &accepting_guild.guild_id, target_format.id).await?;
+
+    let repo = FleetRelayRepository::new(db);
+    let mut relay_ids = Vec::new();
+    for target in [&accepting_guild, &declining_guild, &pending_guild] {
+        let relay = repo
+            .create(CreateFleetRelayParam {
+                source_category_id: category.id,
+                target_guild_id: target.guild_id.parse::<u64>().unwrap(),
+            })
+            .await?;
+        relay_ids.push(relay.id);
+    }
+
+    repo.update_status(
+        relay_ids[0],
+        FleetRelayStatus::Accepted,
+        Some(target_category.id),
+    )
+    .await?;
+    repo.update_status(relay_ids[1], FleetRelayStatus::Declined, None)
+        .await?;
+
+    let relays = repo.get_accepted_by_source_category(category.id).await?;
+
+    assert_eq!(relays.len(), 1);
+    assert_eq!(relays[0].id, relay_ids[0]);
+    assert_eq!(relays[0].status, FleetRelayStatus::Accepted);
+    assert_eq!(relays[0].target_category_id, Some(target_category.id));
+
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use super::*;
+
+/// Tests retrieving the outgoing & incoming relays of a guild.
+///
+/// Verifies that relays are outgoing for the guild of the source category and
+/// incoming for the target guild, without leaking relays of unrelated guilds.
+///
+/// Expected: Ok with the relay listed once in each direction
+#[tokio::test]
+async fn splits_relays_by_direction() -> Result<(), AppError> {
+    let test = TestBuilder::new()
+        .with_table(DiscordGuild)
+        .with_table(PingFormat)
+        .with_table(FleetCategory)
+        .with_table(FleetRelay)
+        .build()
+        .await
+        .unwrap();
+    let db = test.db.as_ref().unwrap();
+
+    let guild = factory::create_guild(db).await?;
+    let target_guild = factory::create_guild(db).await?;
+    let other_guild = factory::create_guild(db).await?;
+    let ping_format = factory::create_ping_format(db, &guild.guild_id).await?;
+    let category = factory::create_category(db, &guild.guild_id, ping_format.id).await?;
+    let guild_id = guild.guild_id.parse::<u64>().unwrap();
+    let target_guild_id = target_guild.guild_id.parse::<u64>().unwrap();
+    let other_guild_id = other_guild.guild_id.parse::<u64>().unwrap();
+
+    let repo = FleetRelayRepository::new(db);
+    let relay = repo
+        .create(CreateFleetRelayParam {
+            source_category_id: category.id,
+            target_guild_id,
+        })
+        .await?;
+
+    let outgoing = repo.get_outgoing_by_guild(guild_id).await?;
+    assert_eq!(outgoing.len(), 1);
+    assert_eq!(outgoing[0].id, relay.id);
+    assert!(repo.get_incoming_by_guild(guild_id).await?.is_empty());
+
+    let incoming = repo.get_incoming_by_guild(target_guild_id).await?;
+    assert_eq!(incoming.len(), 1);
+    assert_eq!(incoming[0].id, relay.id);
+    assert!(repo
+        .get_outgoing_by_guild(target_guild_id)
+        .await?
+        .is_empty());
+
+    assert!(repo.get_outgoing_by_guild(other_guild_id).await?.is_empty());
+    assert!(repo.get_incoming_by_guild(other_guild_id).await?.is_empty());
+
+    Ok(())
+}
@@ -0,0 +1,13 @@
+use crate::{
+    model::fleet_relay::FleetRelayStatus,
+    server::{
+        data::fleet_relay::FleetRelayRepository, error::AppError,
+        model::fleet_relay::CreateFleetRelayParam,
+    },
+};
+use entity::prelude::*;
+use test_utils::{builder::TestBuilder, factory};
+
+mod create;
+mod get_accepted_by_source_category;
+mod get_outgoing_by_guild;
@@ -0,0 +1,146 @@
+use super::*;
+
+/// Tests creating a new fleet template with field values.
+///
+/// Verifies that the repository stores the template & its field values so they
+/// round-trip back into the domain model.
+///
+/// Expected: Ok with template created
+#[tokio::test]
+async fn creates_template_with_field_values() -> Result<(), AppError> {
+    let test = TestBuilder::new()
+        .with_fleet_tables()
+        .with_table(FleetTemplate)
+        .with_table(FleetTemplateFieldValue)
+        .build()
+        .await
+        .unwrap();
+    let db = test.db.as_ref().unwrap();
+
+    let (user, guild, ping_format, category) =
+        factory::helpers::create_fleet_dependencies(db).await?;
+    let field =
+        factory::ping_format_field::create_ping_format_field(db, ping_format.id, "Comms", 1)
+            .await?;
+    let guild_id = guild.guild_id.parse::<u64>().unwrap();
+
+    let repo = FleetTemplateRepository::new(db);
+    let template = repo
+        .create(CreateFleetTemplateParam {
+            category_id: category.id,
+            owner_id: user.discord_id.parse().unwrap(),
+            name: "Sunday strat op".to_string(),
+            shared: true,
+            fleet_name: "Strat op".to_string(),
+            commander_id: Some(user.discord_id.parse().unwrap()),
+            description: Some("Bring links".to_string()),
+            field_values: HashMap::from([(field.id, "Mumble channel 3".to_string())]),
+            hidden: false,
+            disable_reminder: false,
+        })
+        .await?;
+
+    assert!(template.id > 0);
+    assert_eq!(template.category_id, category.id);
+    assert_eq!(template.owner_id.to_string(), user.discord_id);
+    assert_eq!(template.commander_id, Some(template.owner_id));
+    assert_eq!(template.name, "Sunday strat op");
+    assert!(template.shared);
+    assert_eq!(
+        template.field_values.get(&field.id).map(String::as_str),
+        Some("Mumble channel 3")
+    );
+
+    let found = repo.find_by_id(guild_id, template.id).await?.unwrap();
+    assert_eq!(found, template);
+
+    Ok(())
+}
+
+/// Tests finding a template through another guild.
+///
+/// Verifies that templates are only found within the guild of their category.
+///
+/// Expected: Ok(None) for the other guild
+#[tokio::test]
+async fn does_not_find_template_of_other_guild() -> Result<(), AppError> {
+    let test = TestBuilder::new()
+        .with_fleet_tables()
+        .with_table(FleetTemplate)
+        .with_table(FleetTemplateFieldValue)
+        .build()
+        .await
+        .unwrap();
+    let db = test.db.as_ref().unwrap();
+
+    let (user, _guild, _ping_format, category) =
+        factory::helpers::create_fleet_dependencies(db).await?;
+    let other_guild = factory::create_guild(db).await?;
+
+    let repo = FleetTemplateRepository::new(db);
+    let template = repo
+        .create(CreateFleetTemplateParam {
+            category_id: category.id,
+            owner_id: user.discord_id.parse().unwrap(),
+            name: "Strat op".to_string(),
+            shared: false,
+            fleet_name: "Strat op".to_string(),
+            commander_id: None,
+            description: None,
+            field_values: HashMap::new(),
+            hidden: false,
+            disable_reminder: false,
+        })
+        .await?;
+
+    let found = repo
+        .find_by_id(other_guild.guild_id.parse().unwrap(), template.id)
+        .await?;
+    assert!(found.is_none());
+
+    Ok(())
+}
+
+/// Tests deleting a template.
+///
+/// Verifies that the template's field values are removed by the CASCADE foreign key.
+///
+/// Expected: Ok with template & field values deleted
+#[tokio::test]
+async fn deletes_template_with_field_values() -> Result<(), AppError> {
+    let test = TestBuilder::new()
+        .with_fleet_tables()
+        .with_table(FleetTemplate)
+        .with_table(FleetTemplateFieldValue)
+        .build()
+        .await
+        .unwrap();
+    let db = test.db.as_ref().unwrap();
+
+    let (user, _guild, ping_format, category) =
+        factory::helpers::create_fleet_dependencies(db).await?;
+    let field =
+        factory::ping_format_field::create_ping_format_field(db, ping_format.id, "SRP", 1).await?;
+
+    let repo = FleetTemplateRepository::new(db);
+    let template = repo
+        .create(CreateFleetTemplateParam {
+            category_id: category.id,
+            owner_id: user.discord_id.parse().unwrap(),
+            name: "Strat op".to_string(),
+            shared: false,
+            fleet_name: "Strat op".to_string(),
+            commander_id: None,
+            description: None,
+            field_values: HashMap::from([(field.id, "Yes".to_string())]),
+            hidden: false,
+            disable_reminder: false,
+        })
+        .await?;
+    repo.delete(template.id).await?;
+
+    assert_eq!(FleetTemplate::find().count(db).await?, 0);
+    assert_eq!(FleetTemplateFieldValue::find().count(db).await?, 0);
+
+    Ok(())
+}
@@ -0,0 +1,158 @@
+use super::*;
+
+/// Tests getting the templates a user can use.
+///
+/// Verifies that users get their own templates & shared templates of other users
+/// ordered by name, but not private templates of other users.
+///
+/// Expected: Ok with own & shared templates
+#[tokio::test]
+async fn returns_own_and_shared_templates() -> Result<(), AppError> {
+    let test = TestBuilder::new()
+        .with_fleet_tables()
+        .with_table(FleetTemplate)
+        .with_table(FleetTemplateFieldValue)
+        .build()
+        .await
+        .unwrap();
+    let db = test.db.as_ref().unwrap();
+
+    let (user, guild, _ping_format, category) =
+        factory::helpers::create_fleet_dependencies(db).await?;
+    let other_user = factory::create_user(db).await?;
+    let guild_id = guild.guild_id.parse::<u64>().unwrap();
+    let user_id = user.discord_id.parse::<u64>().unwrap();
+
+    let repo = FleetTemplateRepository::new(db);
+    repo.create(CreateFleetTemplateParam {
+        category_id: category.id,
+        owner_id: user.discord_id.parse().unwrap(),
+        name: "Roam".to_string(),
+        shared: false,
+        fleet_name: "Strat op".to_string(),
+        commander_id: None,
+        description: None,
+        field_values: HashMap::new(),
+        hidden: false,
+        disable_reminder: false,
+    })
+    .await?;
+    repo.create(CreateFleetTemplateParam {
+        category_id: category.id,
+        owner_id: other_user.discord_id.parse().unwrap(),
+        name: "Defense".to_string(),
+        shared: true,
+        fleet_name: "Strat op".to_string(),
+        commander_id: None,
+        description: None,
+        field_values: HashMap::new(),
+        hidden: false,
+        disable_reminder: false,
+    })
+    .await?;
+    repo.create(CreateFleetTemplateParam {
+        category_id: category.id,
+        owner_id: other_user.discord_id.parse().unwrap(),
+        name: "Private".to_string(),
+        shared: false,
+        fleet_name: "Strat op".to_string(),
+        commander_id: None,
+        description: None,
+        field_values: HashMap::new(),
+        hidden: false,
+        disable_reminder: false,
+    })
+    .await?;
+
+    let templates = repo.get_usable_by_user(guild_id, user_id, None).await?;
+    let names: Vec<&str> = templates.iter().map(|t| t.name.as_str()).collect();
+
+    assert_eq!(names, vec!["Defense", "Roam"]);
+
+    Ok(())
+}
+
+/// Tests filtering templates by the categories a user can create fleets in.
+///
+/// Verifies that shared templates of other categories & templates of other guilds are
+/// left out.
+///
+/// Expected: Ok with only the template of the allowed category
+#[tokio::test]
+async fn filters_by_category_and_guild() -> Result<(), AppError> {
+    let test = TestBuilder::new()
+        .with_fleet_tables()
+        .with_table(FleetTemplate)
+        .with_table(FleetTemplateFieldValue)
+        .build()
+        .await
+        .unwrap();
+    let db = test.db.as_ref().unwrap();
+
+    let (user, guild, ping_format, category) =
+        factory::helpers::create_fleet_dependencies(db).await?;
+    let other_category = factory::create_category(db, &guild.guild_id, ping_format.id).await?;
+    let other_guild = factory::create_guild(db).await?;
+    let other_format = factory::create_ping_format(db, &other_guild.guild_id).await?;
+    let other_guild_category =
+        factory::create_category(db, &other_guild.guild_id, other_format.id).await?;
+    let guild_id = guild.guild_id.parse::<u64>().unwrap();
+    let user_id = user.discord_id.parse::<u64>().unwrap();
+
+    let repo = FleetTemplateRepository::new(db);
+    let allowed = repo
+        .create(CreateFleetTemplateParam {
+            category_id: category.id,
+            owner_id: user.discord_id.parse().unwrap(),
+            name: "Allowed".to_string(),
+            shared: true,
+            fleet_name: "Strat op".to_string(),
+            commander_id: None,
+            description: None,
+            field_values: HashMap::new(),
+            hidden: false,
+            disable_reminder: false,
+        })
+        .await?;
+    repo.create(CreateFleetTemplateParam {
+        category_id: other_category.id,
+        owner_id: user.discord_id.parse().unwrap(),
+        name: "Other category".to_string(),
+        shared: true,
+        fleet_name: "Strat op".to_string(),
+        commander_id: None,
+        description: None,
+        field_values: HashMap::new(),
+        hidden: false,
+        disable_reminder: false,
+    })
+    .await?;
+    repo.create(CreateFleetTemplateParam {
+        category_id: other_guild_category.id,
+        owner_id: user.discord_id.parse().unwrap(),
+        name: "Other guild".to_string(),
+        shared: true,
+        fleet_name: "Strat op".to_string(),
+        commander_id: None,
+        description: None,
+        field_values: HashMap::new(),
+        hidden: false,
+        disable_reminder: false,
+    })
+    .await?;
+
+    let templates = repo
+        .get_usable_by_user(guild_id, user_id, Some(vec![category.id]))
+        .await?;
+    assert_eq!(
+        templates.iter().map(|t| t.id).collect::<Vec<_>>(),
+        vec![allowed.id]
+    );
+
+    let none = repo
+        .get_usable_by_user(guild_id, user_id, Some(Vec::new()))
+        .await?;
+    assert!(none.is_empty());
+
+    Ok(())
+}
@@ -0,0 +1,12 @@
+use std::collections::HashMap;
+
+use crate::server::{
+    data::fleet_template::FleetTemplateRepository, error::AppError,
+    model::fleet_template::CreateFleetTemplateParam,
+};
+use entity::prelude::*;
+use sea_orm::{EntityTrait, PaginatorTrait};
+use test_utils::{builder::TestBuilder, factory};
+
+mod create;
+mod get_usable_by_user;
@@ -0,0 +1,31 @@
+use super::*;
+
+/// Tests getting the settings of a guild which never stored settings.
+///
+/// Verifies that the defaults matching the built-in notification behaviour are
+/// returned instead of an error.
+///
+/// Expected: Ok with the default settings
+#[tokio::test]
+async fn falls_back_to_defaults() -> Result<(), AppError> {
+    let test = TestBuilder::new()
+        .with_table(DiscordGuild)
+        .with_table(PingFormat)
+        .with_table(GuildSettings)
+        .build()
+        .await
+        .unwrap();
+    let db = test.db.as_ref().unwrap();
+
+    let guild = factory::create_guild(db).await?;
+    let guild_id = guild.guild_id.parse::<u64>().unwrap();
+
+    let settings = GuildSettingsRepository::new(db)
+        .get_by_guild(guild_id)
+        .await?;
+
+    assert_eq!(settings.guild_id, guild_id);
+    assert_eq!(settings.into_dto(), GuildSettingsDto::default());
+
+    Ok(())
+}
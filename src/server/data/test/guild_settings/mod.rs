@@ -0,0 +1,12 @@
+use crate::{
+    model::guild_settings::GuildSettingsDto,
+    server::{
+        data::guild_settings::GuildSettingsRepository, error::AppError,
+        model::guild_settings::UpsertGuildSettingsParam,
+    },
+};
+use entity::prelude::*;
+use test_utils::{builder::TestBuilder, factory};
+
+mod get_by_guild;
+mod upsert;
@@ -0,0 +1,60 @@
+use super::*;
+
+/// Tests storing guild settings twice for the same guild.
+///
+/// Verifies that the second upsert updates the stored settings instead of failing on
+/// the primary key, and that extra timezones round-trip in order.
+///
+/// Expected: Ok with the updated settings returned by get_by_guild
+#[tokio::test]
+async fn updates_existing_settings() -> Result<(), AppError> {
+    let test = TestBuilder::new()
+        .with_table(DiscordGuild)
+        .with_table(PingFormat)
+        .with_table(GuildSettings)
+        .build()
+        .await
+        .unwrap();
+    let db = test.db.as_ref().unwrap();
+
+    let guild = factory::create_guild(db).await?;
+    let guild_id = guild.guild_id.parse::<u64>().unwrap();
+    let ping_format = factory::create_ping_format(db, &guild.guild_id).await?;
+
+    let repo = GuildSettingsRepository::new(db);
+    repo.upsert(UpsertGuildSettingsParam::from_dto(
+        guild_id,
+        GuildSettingsDto {
+            formup_color: 0x00ff00,
+            ..GuildSettingsDto::default()
+        },
+    )?)
+    .await?;
+    repo.upsert(UpsertGuildSettingsParam::from_dto(
+        guild_id,
+        GuildSettingsDto {
+            extra_timezones: vec!["America/New_York".to_string(), "Europe/Berlin".to_string()],
+            list_lookahead_hours: Some(48),
+            default_ping_format_id: Some(ping_format.id),
+            broadcast_role_ids: vec![1234, 5678],
+            ..GuildSettingsDto::default()
+        },
+    )?)
+    .await?;
+
+    let settings = repo.get_by_guild(guild_id).await?;
+
+    assert_eq!(
+        settings.formup_color,
+        GuildSettingsDto::default().formup_color
+    );
+    assert_eq!(
+        settings.extra_timezones,
+        vec!["America/New_York".to_string(), "Europe/Berlin".to_string()]
+    );
+    assert_eq!(settings.list_lookahead_hours, Some(48));
+    assert_eq!(settings.default_ping_format_id, Some(ping_format.id));
+    assert_eq!(settings.broadcast_role_ids, vec![1234, 5678]);
+
+    Ok(())
+}
@@ -1 +1,18 @@
+mod broadcast;
 mod channel_fleet_list;
+mod channel_list_settings;
+mod doctrine;
+mod fleet;
+mod fleet_dm_reminder;
+mod fleet_message;
+mod fleet_relay;
+mod fleet_template;
+mod guild_settings;
+mod notification_preference;
+mod relayed_fleet;
+mod stand_down;
+mod subscription;
+mod user;
+mod user_category_permission;
+mod webhook;
+mod webhook_delivery;
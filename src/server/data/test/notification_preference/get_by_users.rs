@@ -0,0 +1,48 @@
+use super::*;
+use crate::server::model::subscription::NotificationPreference;
+
+/// Tests getting the preferences of several users.
+///
+/// Verifies that stored preferences are returned for users who have them and the
+/// defaults for users who don't, in the order the user IDs were provided.
+///
+/// Expected: Ok with stored and default preferences in input order
+#[tokio::test]
+async fn falls_back_to_defaults_in_input_order() -> Result<(), AppError> {
+    let test = TestBuilder::new()
+        .with_table(User)
+        .with_table(NotificationPreference)
+        .build()
+        .await
+        .unwrap();
+    let db = test.db.as_ref().unwrap();
+
+    let user = factory::create_user(db).await?;
+    let user_id = user.discord_id.parse::<u64>().unwrap();
+    let user_without_preferences = 123456789u64;
+
+    let repo = NotificationPreferenceRepository::new(db);
+    repo.upsert(UpsertNotificationPreferenceParam {
+        user_id,
+        dm_enabled: true,
+        notify_created: true,
+        notify_time_changed: true,
+        notify_cancelled: true,
+        reminder_minutes: Some(15),
+    })
+    .await?;
+
+    let preferences = repo
+        .get_by_users(&[user_without_preferences, user_id])
+        .await?;
+
+    assert_eq!(preferences.len(), 2);
+    assert_eq!(
+        preferences[0],
+        NotificationPreference::default_for(user_without_preferences)
+    );
+    assert_eq!(preferences[1].user_id, user_id);
+    assert_eq!(preferences[1].reminder_minutes, Some(15));
+
+    Ok(())
+}
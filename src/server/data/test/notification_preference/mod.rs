@@ -0,0 +1,9 @@
+use crate::server::{
+    data::notification_preference::NotificationPreferenceRepository, error::AppError,
+    model::subscription::UpsertNotificationPreferenceParam,
+};
+use entity::prelude::*;
+use test_utils::{builder::TestBuilder, factory};
+
+mod get_by_users;
+mod upsert;
@@ -0,0 +1,48 @@
+use super::*;
+
+/// Tests storing notification preferences twice for the same user.
+///
+/// Verifies that the second upsert updates the stored preferences instead of
+/// failing on the primary key.
+///
+/// Expected: Ok with the updated preferences returned by get_by_user
+#[tokio::test]
+async fn updates_existing_preferences() -> Result<(), AppError> {
+    let test = TestBuilder::new()
+        .with_table(User)
+        .with_table(NotificationPreference)
+        .build()
+        .await
+        .unwrap();
+    let db = test.db.as_ref().unwrap();
+
+    let user = factory::create_user(db).await?;
+    let user_id = user.discord_id.parse::<u64>().unwrap();
+
+    let repo = NotificationPreferenceRepository::new(db);
+    repo.upsert(UpsertNotificationPreferenceParam {
+        user_id,
+        dm_enabled: true,
+        notify_created: true,
+        notify_time_changed: true,
+        notify_cancelled: true,
+        reminder_minutes: Some(30),
+    })
+    .await?;
+    repo.upsert(UpsertNotificationPreferenceParam {
+        user_id,
+        dm_enabled: true,
+        notify_created: true,
+        notify_time_changed: true,
+        notify_cancelled: false,
+        reminder_minutes: None,
+    })
+    .await?;
+
+    let preference = repo.get_by_user(user_id).await?;
+
+    assert!(!preference.notify_cancelled);
+    assert_eq!(preference.reminder_minutes, None);
+
+    Ok(())
+}
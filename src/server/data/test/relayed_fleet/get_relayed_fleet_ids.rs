@@ -0,0 +1,56 @@
+use super::*;
+
+/// Tests checking which fleets are relayed copies.
+///
+/// Verifies that only the requested fleets linked to a source fleet are returned,
+/// while fleets created in their own guild are left out.
+///
+/// Expected: Ok with the IDs of the relayed copies
+#[tokio::test]
+async fn returns_only_relayed_copies() -> Result<(), AppError> {
+    let test = TestBuilder::new()
+        .with_fleet_tables()
+        .with_table(FleetRelay)
+        .with_table(RelayedFleet)
+        .build()
+        .await
+        .unwrap();
+    let db = test.db.as_ref().unwrap();
+
+    let (user, _guild, _ping_format, category) =
+        factory::helpers::create_fleet_dependencies(db).await?;
+    let target_guild = factory::create_guild(db).await?;
+    let target_ping_format = factory::create_ping_format(db, &target_guild.guild_id).await?;
+    let target_category =
+        factory::create_category(db, &target_guild.guild_id, target_ping_format.id).await?;
+
+    let relay = FleetRelayRepository::new(db)
+        .create(CreateFleetRelayParam {
+            source_category_id: category.id,
+            target_guild_id: target_guild.guild_id.parse().unwrap(),
+        })
+        .await?;
+
+    let source = factory::fleet::FleetFactory::new(db, category.id, &user.discord_id)
+        .build()
+        .await?;
+    let copy = factory::fleet::FleetFactory::new(db, target_category.id, &user.discord_id)
+        .build()
+        .await?;
+    let other_copy = factory::fleet::FleetFactory::new(db, target_category.id, &user.discord_id)
+        .build()
+        .await?;
+
+    let repo = RelayedFleetRepository::new(db);
+    repo.create(copy.id, source.id, relay.id).await?;
+    repo.create(other_copy.id, source.id, relay.id).await?;
+
+    let relayed = repo.get_relayed_fleet_ids(vec![source.id, copy.id]).await?;
+
+    assert_eq!(relayed.len(), 1);
+    assert!(relayed.contains(&copy.id));
+
+    assert!(repo.get_relayed_fleet_ids(Vec::new()).await?.is_empty());
+
+    Ok(())
+}
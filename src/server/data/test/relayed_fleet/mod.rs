@@ -0,0 +1,9 @@
+use crate::server::{
+    data::{fleet_relay::FleetRelayRepository, relayed_fleet::RelayedFleetRepository},
+    error::AppError,
+    model::fleet_relay::CreateFleetRelayParam,
+};
+use entity::prelude::*;
+use test_utils::{builder::TestBuilder, factory};
+
+mod get_relayed_fleet_ids;
@@ -0,0 +1,189 @@
+use super::*;
+
+/// Tests recording a stand-down with snapshots of its fleets.
+///
+/// Verifies that the repository stores the stand-down, its fleet snapshots & their
+/// field values so they round-trip back into the domain models.
+///
+/// Expected: Ok with stand-down & snapshots created
+#[tokio::test]
+async fn creates_stand_down_with_fleet_snapshots() -> Result<(), AppError> {
+    let test = TestBuilder::new()
+        .with_fleet_tables()
+        .with_table(PingGroup)
+        .with_table(StandDown)
+        .with_table(StandDownFleet)
+        .with_table(StandDownFleetFieldValue)
+        .build()
+        .await
+        .unwrap();
+    let db = test.db.as_ref().unwrap();
+
+    let (user, guild, ping_format, category) =
+        factory::helpers::create_fleet_dependencies(db).await?;
+    let field =
+        factory::ping_format_field::create_ping_format_field(db, ping_format.id, "Comms", 1)
+            .await?;
+    let later = factory::fleet::FleetFactory::new(db, category.id, &user.discord_id)
+        .fleet_time(Utc::now() + Duration::hours(6))
+        .build()
+        .await?;
+    let sooner = factory::fleet::FleetFactory::new(db, category.id, &user.discord_id)
+        .fleet_time(Utc::now() + Duration::hours(2))
+        .hidden(true)
+        .build()
+        .await?;
+
+    let field_values = HashMap::from([(field.id, "Mumble channel 3".to_string())]);
+    let fleets = vec![
+        (Fleet::from_entity(later.clone())?, field_values.clone()),
+        (Fleet::from_entity(sooner.clone())?, HashMap::new()),
+    ];
+
+    let repo = StandDownRepository::new(db);
+    let stand_down = repo
+        .create(CreateStandDownParam {
+            guild_id: guild.guild_id.parse().unwrap(),
+            ping_group_id: None,
+            reason: "Hostile capitals in staging".to_string(),
+            window_start: Utc::now(),
+            window_end: Utc::now() + Duration::hours(12),
+            created_by: user.discord_id.parse().unwrap(),
+            fleets,
+            deleted_fleet_ids: vec![later.id, sooner.id],
+        })
+        .await?;
+
+    assert!(stand_down.id > 0);
+    assert_eq!(stand_down.guild_id.to_string(), guild.guild_id);
+    assert_eq!(stand_down.reason, "Hostile capitals in staging");
+    assert_eq!(
+        stand_down.created_by.map(|id| id.to_string()),
+        Some(user.discord_id)
+    );
+    assert!(stand_down.resumed_at.is_none());
+
+    // Snapshots are ordered by fleet time
+    let snapshots = repo.get_fleets(stand_down.id).await?;
+    assert_eq!(snapshots.len(), 2);
+    assert_eq!(snapshots[0].name, sooner.name);
+    assert!(snapshots[0].hidden);
+    assert!(snapshots[0].field_values.is_empty());
+    assert_eq!(snapshots[1].name, later.name);
+    assert_eq!(snapshots[1].field_values, field_values);
+
+    let counts = repo.count_fleets(vec![stand_down.id]).await?;
+    assert_eq!(counts.get(&stand_down.id), Some(&2));
+
+    // The cancelled fleets are deleted along with recording the snapshots
+    assert_eq!(entity::prelude::Fleet::find().count(db).await?, 0);
+
+    let found = repo
+        .find_by_id(guild.guild_id.parse().unwrap(), stand_down.id)
+        .await?;
+    assert_eq!(found, Some(stand_down));
+
+    Ok(())
+}
+
+/// Tests that a failing snapshot keeps the cancelled fleets.
+///
+/// Verifies that when a snapshot can't be recorded, the fleets aren't deleted either so
+/// they are never lost without a snapshot to resume them from.
+///
+/// Expected: Err with the fleet & no stand-down remaining
+#[tokio::test]
+async fn keeps_fleets_when_snapshot_fails() -> Result<(), AppError> {
+    let test = TestBuilder::new()
+        .with_fleet_tables()
+        .with_table(PingGroup)
+        .with_table(StandDown)
+        .with_table(StandDownFleet)
+        .with_table(StandDownFleetFieldValue)
+        .build()
+        .await
+        .unwrap();
+    let db = test.db.as_ref().unwrap();
+
+    let (user, guild, _ping_format, category) =
+        factory::helpers::create_fleet_dependencies(db).await?;
+    let fleet = factory::fleet::FleetFactory::new(db, category.id, &user.discord_id)
+        .fleet_time(Utc::now() + Duration::hours(2))
+        .build()
+        .await?;
+
+    // The snapshot references a category which doesn't exist
+    let mut snapshot = Fleet::from_entity(fleet.clone())?;
+    snapshot.category_id = category.id + 1000;
+
+    let result = StandDownRepository::new(db)
+        .create(CreateStandDownParam {
+            guild_id: guild.guild_id.parse().unwrap(),
+            ping_group_id: None,
+            reason: "Hostile capitals in staging".to_string(),
+            window_start: Utc::now(),
+            window_end: Utc::now() + Duration::hours(12),
+            created_by: user.discord_id.parse().unwrap(),
+            fleets: vec![(snapshot, HashMap::new())],
+            deleted_fleet_ids: vec![fleet.id],
+        })
+        .await;
+
+    assert!(result.is_err());
+    assert!(entity::prelude::Fleet::find_by_id(fleet.id)
+        .one(db)
+        .await?
+        .is_some());
+    assert_eq!(entity::prelude::StandDown::find().count(db).await?, 0);
+
+    Ok(())
+}
+
+/// Tests finding a stand-down through another guild.
+///
+/// Verifies that stand-downs are only found & listed within their own guild.
+///
+/// Expected: Ok(None) & no recent stand-downs for the other guild
+#[tokio::test]
+async fn does_not_find_stand_down_of_other_guild() -> Result<(), AppError> {
+    let test = TestBuilder::new()
+        .with_fleet_tables()
+        .with_table(PingGroup)
+        .with_table(StandDown)
+        .with_table(StandDownFleet)
+        .with_table(StandDownFleetFieldValue)
+        .build()
+        .await
+        .unwrap();
+    let db = test.db.as_ref().unwrap();
+
+    let (user, guild, _ping_format, _category) =
+        factory::helpers::create_fleet_dependencies(db).await?;
+    let other_guild = factory::create_guild(db).await?;
+    let other_guild_id = other_guild.guild_id.parse::<u64>().unwrap();
+
+    let repo = StandDownRepository::new(db);
+    let stand_down = repo
+        .create(CreateStandDownParam {
+            guild_id: guild.guild_id.parse().unwrap(),
+            ping_group_id: None,
+            reason: "Hostile capitals in staging".to_string(),
+            window_start: Utc::now(),
+            window_end: Utc::now() + Duration::hours(12),
+            created_by: user.discord_id.parse().unwrap(),
+            fleets: Vec::new(),
+            deleted_fleet_ids: Vec::new(),
+        })
+        .await?;
+
+    assert!(repo
+        .find_by_id(other_guild_id, stand_down.id)
+        .await?
+        .is_none());
+    assert!(repo
+        .get_recent_by_guild(other_guild_id, 10)
+        .await?
+        .is_empty());
+
+    Ok(())
+}
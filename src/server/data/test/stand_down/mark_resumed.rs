@@ -0,0 +1,95 @@
+use super::*;
+
+/// Tests resuming a stand-down.
+///
+/// Verifies that a stand-down is only marked as resumed once, so concurrent resumes
+/// can't restore its fleets twice.
+///
+/// Expected: Ok(true) for the first resume, Ok(false) afterwards
+#[tokio::test]
+async fn marks_stand_down_resumed_once() -> Result<(), AppError> {
+    let test = TestBuilder::new()
+        .with_fleet_tables()
+        .with_table(PingGroup)
+        .with_table(StandDown)
+        .with_table(StandDownFleet)
+        .with_table(StandDownFleetFieldValue)
+        .build()
+        .await
+        .unwrap();
+    let db = test.db.as_ref().unwrap();
+
+    let (user, guild, _ping_format, _category) =
+        factory::helpers::create_fleet_dependencies(db).await?;
+    let guild_id = guild.guild_id.parse::<u64>().unwrap();
+
+    let repo = StandDownRepository::new(db);
+    let stand_down = repo
+        .create(CreateStandDownParam {
+            guild_id: guild.guild_id.parse().unwrap(),
+            ping_group_id: None,
+            reason: "Hostile capitals in staging".to_string(),
+            window_start: Utc::now(),
+            window_end: Utc::now() + Duration::hours(12),
+            created_by: user.discord_id.parse().unwrap(),
+            fleets: Vec::new(),
+            deleted_fleet_ids: Vec::new(),
+        })
+        .await?;
+
+    let resumed_at = Utc::now();
+    assert!(repo.mark_resumed(stand_down.id, resumed_at).await?);
+    assert!(!repo.mark_resumed(stand_down.id, Utc::now()).await?);
+
+    let found = repo.find_by_id(guild_id, stand_down.id).await?.unwrap();
+    assert!(found.resumed_at.is_some());
+
+    Ok(())
+}
+
+/// Tests listing the recent stand-downs of a guild.
+///
+/// Verifies that the newest stand-downs come first & the limit is applied.
+///
+/// Expected: Ok with the two newest stand-downs, newest first
+#[tokio::test]
+async fn lists_recent_stand_downs_newest_first() -> Result<(), AppError> {
+    let test = TestBuilder::new()
+        .with_fleet_tables()
+        .with_table(PingGroup)
+        .with_table(StandDown)
+        .with_table(StandDownFleet)
+        .with_table(StandDownFleetFieldValue)
+        .build()
+        .await
+        .unwrap();
+    let db = test.db.as_ref().unwrap();
+
+    let (user, guild, _ping_format, _category) =
+        factory::helpers::create_fleet_dependencies(db).await?;
+    let guild_id = guild.guild_id.parse::<u64>().unwrap();
+
+    let repo = StandDownRepository::new(db);
+    let mut ids = Vec::new();
+    for _ in 0..3 {
+        let stand_down = repo
+            .create(CreateStandDownParam {
+                guild_id: guild.guild_id.parse().unwrap(),
+                ping_group_id: None,
+                reason: "Hostile capitals in staging".to_string(),
+                window_start: Utc::now(),
+                window_end: Utc::now() + Duration::hours(12),
+                created_by: user.discord_id.parse().unwrap(),
+                fleets: Vec::new(),
+                deleted_fleet_ids: Vec::new(),
+            })
+            .await?;
+        ids.push(stand_down.id);
+    }
+
+    let recent = repo.get_recent_by_guild(guild_id, 2).await?;
+    let recent_ids: Vec<i32> = recent.iter().map(|s| s.id).collect();
+    assert_eq!(recent_ids, vec![ids[2], ids[1]]);
+
+    Ok(())
+}
@@ -0,0 +1,15 @@
+use std::collections::HashMap;
+
+use chrono::{Duration, Utc};
+
+use crate::server::{
+    data::stand_down::StandDownRepository,
+    error::AppError,
+    model::{fleet::Fleet, stand_down::CreateStandDownParam},
+};
+use entity::prelude::*;
+use sea_orm::{EntityTrait, PaginatorTrait};
+use test_utils::{builder::TestBuilder, factory};
+
+mod create;
+mod mark_resumed;
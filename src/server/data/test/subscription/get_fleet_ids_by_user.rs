@@ -0,0 +1,47 @@
+use super::*;
+
+/// Tests getting a user's fleet subscriptions within a guild.
+///
+/// Verifies that only subscriptions to fleets of the requested guild are returned
+/// and subscriptions of other users are excluded.
+///
+/// Expected: Ok with the fleet IDs of the requested guild only
+#[tokio::test]
+async fn returns_fleets_of_guild_only() -> Result<(), AppError> {
+    let test = TestBuilder::new()
+        .with_fleet_tables()
+        .with_table(FleetSubscription)
+        .build()
+        .await
+        .unwrap();
+    let db = test.db.as_ref().unwrap();
+
+    let user = factory::create_user(db).await?;
+    let other_user = factory::create_user(db).await?;
+    let user_id = user.discord_id.parse::<u64>().unwrap();
+    let other_user_id = other_user.discord_id.parse::<u64>().unwrap();
+
+    let guild = factory::create_guild(db).await?;
+    let ping_format = factory::create_ping_format(db, &guild.guild_id).await?;
+    let category = factory::create_category(db, &guild.guild_id, ping_format.id).await?;
+    let fleet = factory::create_fleet(db, category.id, &user.discord_id).await?;
+    let other_fleet = factory::create_fleet(db, category.id, &user.discord_id).await?;
+
+    let other_guild = factory::create_guild(db).await?;
+    let other_format = factory::create_ping_format(db, &other_guild.guild_id).await?;
+    let other_category =
+        factory::create_category(db, &other_guild.guild_id, other_format.id).await?;
+    let other_guild_fleet = factory::create_fleet(db, other_category.id, &user.discord_id).await?;
+
+    let repo = SubscriptionRepository::new(db);
+    repo.subscribe_fleet(user_id, fleet.id).await?;
+    repo.subscribe_fleet(user_id, other_guild_fleet.id).await?;
+    repo.subscribe_fleet(other_user_id, other_fleet.id).await?;
+
+    let guild_id = guild.guild_id.parse::<u64>().unwrap();
+    let fleet_ids = repo.get_fleet_ids_by_user(user_id, guild_id).await?;
+
+    assert_eq!(fleet_ids, vec![fleet.id]);
+
+    Ok(())
+}
@@ -0,0 +1,80 @@
+use super::*;
+
+/// Tests getting the subscribers of a fleet.
+///
+/// Verifies that users subscribed to the fleet's category and users subscribed to
+/// the fleet itself are both returned with the subscription they're notified through.
+///
+/// Expected: Ok with both subscribers
+#[tokio::test]
+async fn returns_category_and_fleet_subscribers() -> Result<(), AppError> {
+    let test = TestBuilder::new()
+        .with_fleet_tables()
+        .with_table(CategorySubscription)
+        .with_table(FleetSubscription)
+        .build()
+        .await
+        .unwrap();
+    let db = test.db.as_ref().unwrap();
+
+    let category_user = factory::create_user(db).await?;
+    let fleet_user = factory::create_user(db).await?;
+    let category_user_id = category_user.discord_id.parse::<u64>().unwrap();
+    let fleet_user_id = fleet_user.discord_id.parse::<u64>().unwrap();
+
+    let guild = factory::create_guild(db).await?;
+    let ping_format = factory::create_ping_format(db, &guild.guild_id).await?;
+    let category = factory::create_category(db, &guild.guild_id, ping_format.id).await?;
+    let fleet = factory::create_fleet(db, category.id, &category_user.discord_id).await?;
+
+    let repo = SubscriptionRepository::new(db);
+    repo.subscribe_category(category_user_id, category.id)
+        .await?;
+    repo.subscribe_fleet(fleet_user_id, fleet.id).await?;
+
+    let subscribers = repo.get_subscribers(category.id, fleet.id).await?;
+
+    assert_eq!(subscribers.len(), 2);
+    assert!(subscribers.contains(&(category_user_id, SubscriptionTarget::Category(category.id))));
+    assert!(subscribers.contains(&(fleet_user_id, SubscriptionTarget::Fleet(fleet.id))));
+
+    Ok(())
+}
+
+/// Tests getting the subscribers of a fleet when a user is subscribed both ways.
+///
+/// Verifies that the user is only returned once, through their category subscription.
+///
+/// Expected: Ok with a single category subscription
+#[tokio::test]
+async fn prefers_category_subscription_for_duplicates() -> Result<(), AppError> {
+    let test = TestBuilder::new()
+        .with_fleet_tables()
+        .with_table(CategorySubscription)
+        .with_table(FleetSubscription)
+        .build()
+        .await
+        .unwrap();
+    let db = test.db.as_ref().unwrap();
+
+    let user = factory::create_user(db).await?;
+    let user_id = user.discord_id.parse::<u64>().unwrap();
+
+    let guild = factory::create_guild(db).await?;
+    let ping_format = factory::create_ping_format(db, &guild.guild_id).await?;
+    let category = factory::create_category(db, &guild.guild_id, ping_format.id).await?;
+    let fleet = factory::create_fleet(db, category.id, &user.discord_id).await?;
+
+    let repo = SubscriptionRepository::new(db);
+    repo.subscribe_fleet(user_id, fleet.id).await?;
+    repo.subscribe_category(user_id, category.id).await?;
+
+    let subscribers = repo.get_subscribers(category.id, fleet.id).await?;
+
+    assert_eq!(
+        subscribers,
+        vec![(user_id, SubscriptionTarget::Category(category.id))]
+    );
+
+    Ok(())
+}
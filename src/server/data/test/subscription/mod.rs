@@ -0,0 +1,11 @@
+use crate::server::{
+    data::subscription::SubscriptionRepository, error::AppError,
+    model::subscription::SubscriptionTarget,
+};
+use entity::prelude::*;
+use sea_orm::{EntityTrait, PaginatorTrait};
+use test_utils::{builder::TestBuilder, factory};
+
+mod get_fleet_ids_by_user;
+mod get_subscribers;
+mod subscribe_category;
@@ -0,0 +1,65 @@
+use super::*;
+
+/// Tests subscribing to the same category twice.
+///
+/// Verifies that repeated subscriptions are ignored instead of failing on the
+/// composite primary key.
+///
+/// Expected: Ok with a single subscription stored
+#[tokio::test]
+async fn ignores_duplicate_subscription() -> Result<(), AppError> {
+    let test = TestBuilder::new()
+        .with_fleet_tables()
+        .with_table(CategorySubscription)
+        .build()
+        .await
+        .unwrap();
+    let db = test.db.as_ref().unwrap();
+
+    let user = factory::create_user(db).await?;
+    let guild = factory::create_guild(db).await?;
+    let ping_format = factory::create_ping_format(db, &guild.guild_id).await?;
+    let category = factory::create_category(db, &guild.guild_id, ping_format.id).await?;
+    let user_id = user.discord_id.parse::<u64>().unwrap();
+
+    let repo = SubscriptionRepository::new(db);
+    repo.subscribe_category(user_id, category.id).await?;
+    repo.subscribe_category(user_id, category.id).await?;
+
+    let count = entity::prelude::CategorySubscription::find()
+        .count(db)
+        .await?;
+    assert_eq!(count, 1);
+
+    Ok(())
+}
+
+/// Tests unsubscribing from a category.
+///
+/// Verifies that unsubscribing reports whether a subscription was removed.
+///
+/// Expected: Ok(true) for the first unsubscribe, Ok(false) afterwards
+#[tokio::test]
+async fn reports_whether_subscription_was_removed() -> Result<(), AppError> {
+    let test = TestBuilder::new()
+        .with_fleet_tables()
+        .with_table(CategorySubscription)
+        .build()
+        .await
+        .unwrap();
+    let db = test.db.as_ref().unwrap();
+
+    let user = factory::create_user(db).await?;
+    let guild = factory::create_guild(db).await?;
+    let ping_format = factory::create_ping_format(db, &guild.guild_id).await?;
+    let category = factory::create_category(db, &guild.guild_id, ping_format.id).await?;
+    let user_id = user.discord_id.parse::<u64>().unwrap();
+
+    let repo = SubscriptionRepository::new(db);
+    repo.subscribe_category(user_id, category.id).await?;
+
+    assert!(repo.unsubscribe_category(user_id, category.id).await?);
+    assert!(!repo.unsubscribe_category(user_id, category.id).await?);
+
+    Ok(())
+}
@@ -0,0 +1,37 @@
+use super::*;
+
+/// Tests getting the admins among several users.
+///
+/// Verifies that only admins of the requested users are returned, while admins which
+/// weren't requested and unknown users are left out.
+///
+/// Expected: Ok with the ID of the requested admin
+#[tokio::test]
+async fn returns_requested_admins() -> Result<(), AppError> {
+    let test = TestBuilder::new().with_table(User).build().await.unwrap();
+    let db = test.db.as_ref().unwrap();
+
+    let admin = factory::user::UserFactory::new(db)
+        .admin(true)
+        .build()
+        .await?;
+    let member = factory::create_user(db).await?;
+    let other_admin = factory::user::UserFactory::new(db)
+        .admin(true)
+        .build()
+        .await?;
+
+    let admin_id = admin.discord_id.parse::<u64>().unwrap();
+    let member_id = member.discord_id.parse::<u64>().unwrap();
+
+    let repo = UserRepository::new(db);
+    let admin_ids = repo.get_admin_ids(&[admin_id, member_id, 42]).await?;
+
+    assert_eq!(admin_ids.len(), 1);
+    assert!(admin_ids.contains(&admin_id));
+    assert!(!admin_ids.contains(&other_admin.discord_id.parse::<u64>().unwrap()));
+
+    assert!(repo.get_admin_ids(&[]).await?.is_empty());
+
+    Ok(())
+}
@@ -0,0 +1,5 @@
+use crate::server::{data::user::UserRepository, error::AppError};
+use entity::prelude::*;
+use test_utils::{builder::TestBuilder, factory};
+
+mod get_admin_ids;
@@ -0,0 +1,67 @@
+use super::*;
+
+/// Tests getting the users who can view a category.
+///
+/// Verifies that users holding a role with view access are returned, while users whose
+/// roles only grant access without viewing or who lack a role are left out.
+///
+/// Expected: Ok with the ID of the user holding the viewing role
+#[tokio::test]
+async fn returns_users_with_viewing_role() -> Result<(), AppError> {
+    let test = TestBuilder::new()
+        .with_table(User)
+        .with_table(DiscordGuild)
+        .with_table(DiscordGuildRole)
+        .with_table(UserDiscordGuildRole)
+        .with_table(PingFormat)
+        .with_table(FleetCategory)
+        .with_table(FleetCategoryAccessRole)
+        .build()
+        .await
+        .unwrap();
+    let db = test.db.as_ref().unwrap();
+
+    let guild = factory::create_guild(db).await?;
+    let ping_format = factory::create_ping_format(db, &guild.guild_id).await?;
+    let category = factory::create_category(db, &guild.guild_id, ping_format.id).await?;
+    let view_role = factory::create_guild_role(db, &guild.guild_id, "1001").await?;
+    let create_role = factory::create_guild_role(db, &guild.guild_id, "1002").await?;
+
+    for (role, can_view) in [(&view_role, true), (&create_role, false)] {
+        entity::fleet_category_access_role::ActiveModel {
+            fleet_category_id: ActiveValue::Set(category.id),
+            role_id: ActiveValue::Set(role.role_id.clone()),
+            can_view: ActiveValue::Set(can_view),
+            can_create: ActiveValue::Set(true),
+            can_manage: ActiveValue::Set(false),
+            can_broadcast: ActiveValue::Set(false),
+        }
+        .insert(db)
+        .await?;
+    }
+
+    let viewer = factory::create_user(db).await?;
+    let creator = factory::create_user(db).await?;
+    let member = factory::create_user(db).await?;
+    let viewer_id = viewer.discord_id.parse::<u64>().unwrap();
+    let creator_id = creator.discord_id.parse::<u64>().unwrap();
+    let member_id = member.discord_id.parse::<u64>().unwrap();
+
+    factory::create_user_guild_role(db, viewer_id, 1001).await?;
+    factory::create_user_guild_role(db, creator_id, 1002).await?;
+
+    let repo = UserCategoryPermissionRepository::new(db);
+    let viewer_ids = repo
+        .get_users_with_view_access(&[viewer_id, creator_id, member_id], category.id)
+        .await?;
+
+    assert_eq!(viewer_ids.len(), 1);
+    assert!(viewer_ids.contains(&viewer_id));
+
+    assert!(repo
+        .get_users_with_view_access(&[], category.id)
+        .await?
+        .is_empty());
+
+    Ok(())
+}
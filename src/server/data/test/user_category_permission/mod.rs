@@ -0,0 +1,8 @@
+use crate::server::{
+    data::user_category_permission::UserCategoryPermissionRepository, error::AppError,
+};
+use entity::prelude::*;
+use sea_orm::{ActiveModelTrait, ActiveValue};
+use test_utils::{builder::TestBuilder, factory};
+
+mod get_users_with_view_access;
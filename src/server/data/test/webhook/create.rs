@@ -0,0 +1,89 @@
+use super::*;
+
+/// Tests creating a new webhook.
+///
+/// Verifies that the repository creates a webhook with the provided secret and
+/// stores the subscribed events so they round-trip back into the domain model.
+///
+/// Expected: Ok with webhook created
+#[tokio::test]
+async fn creates_webhook() -> Result<(), AppError> {
+    let test = TestBuilder::new()
+        .with_table(DiscordGuild)
+        .with_table(Webhook)
+        .build()
+        .await
+        .unwrap();
+    let db = test.db.as_ref().unwrap();
+
+    let guild = factory::create_guild(db).await?;
+    let guild_id = guild.guild_id.parse::<u64>().unwrap();
+
+    let repo = WebhookRepository::new(db);
+    let webhook = repo
+        .create(
+            guild_id,
+            "secret".to_string(),
+            CreateWebhookParam {
+                name: "SRP".to_string(),
+                url: "https://example.com/hooks/timerboard".to_string(),
+                events: vec![WebhookEvent::Created, WebhookEvent::Cancelled],
+                enabled: true,
+            },
+        )
+        .await?;
+
+    assert!(webhook.id > 0);
+    assert_eq!(webhook.guild_id, guild_id);
+    assert_eq!(webhook.name, "SRP");
+    assert_eq!(webhook.secret, "secret");
+    assert_eq!(
+        webhook.events,
+        vec![WebhookEvent::Created, WebhookEvent::Cancelled]
+    );
+    assert!(webhook.enabled);
+
+    // Verify events are stored using their event names
+    let db_webhook = entity::prelude::Webhook::find_by_id(webhook.id)
+        .one(db)
+        .await?
+        .unwrap();
+    assert_eq!(db_webhook.events, "fleet.created,fleet.cancelled");
+
+    Ok(())
+}
+
+/// Tests creating a webhook for a guild that doesn't exist.
+///
+/// Verifies that the foreign key constraint prevents creating webhooks for
+/// unknown guilds.
+///
+/// Expected: Err
+#[tokio::test]
+async fn fails_for_nonexistent_guild() -> Result<(), AppError> {
+    let test = TestBuilder::new()
+        .with_table(DiscordGuild)
+        .with_table(Webhook)
+        .build()
+        .await
+        .unwrap();
+    let db = test.db.as_ref().unwrap();
+
+    let repo = WebhookRepository::new(db);
+    let result = repo
+        .create(
+            999999999,
+            "secret".to_string(),
+            CreateWebhookParam {
+                name: "SRP".to_string(),
+                url: "https://example.com/hooks/timerboard".to_string(),
+                events: vec![WebhookEvent::Created],
+                enabled: true,
+            },
+        )
+        .await;
+
+    assert!(result.is_err());
+
+    Ok(())
+}
@@ -0,0 +1,92 @@
+use super::*;
+
+/// Tests retrieving enabled webhooks for a guild.
+///
+/// Verifies that disabled webhooks and webhooks of other guilds are excluded.
+///
+/// Expected: Ok with only the enabled webhook of the guild
+#[tokio::test]
+async fn returns_only_enabled_webhooks_of_guild() -> Result<(), AppError> {
+    let test = TestBuilder::new()
+        .with_table(DiscordGuild)
+        .with_table(Webhook)
+        .build()
+        .await
+        .unwrap();
+    let db = test.db.as_ref().unwrap();
+
+    let guild = factory::create_guild(db).await?;
+    let other_guild = factory::create_guild(db).await?;
+    let guild_id = guild.guild_id.parse::<u64>().unwrap();
+    let other_guild_id = other_guild.guild_id.parse::<u64>().unwrap();
+
+    let repo = WebhookRepository::new(db);
+    let enabled = repo
+        .create(
+            guild_id,
+            "secret".to_string(),
+            CreateWebhookParam {
+                name: "Enabled".to_string(),
+                url: "https://example.com/hooks/timerboard".to_string(),
+                events: vec![WebhookEvent::Created],
+                enabled: true,
+            },
+        )
+        .await?;
+    repo.create(
+        guild_id,
+        "secret".to_string(),
+        CreateWebhookParam {
+            name: "Disabled".to_string(),
+            url: "https://example.com/hooks/timerboard".to_string(),
+            events: vec![WebhookEvent::Created],
+            enabled: false,
+        },
+    )
+    .await?;
+    repo.create(
+        other_guild_id,
+        "secret".to_string(),
+        CreateWebhookParam {
+            name: "Other".to_string(),
+            url: "https://example.com/hooks/timerboard".to_string(),
+            events: vec![WebhookEvent::Created],
+            enabled: true,
+        },
+    )
+    .await?;
+
+    let webhooks = repo.get_enabled_by_guild(guild_id).await?;
+
+    assert_eq!(webhooks.len(), 1);
+    assert_eq!(webhooks[0].id, enabled.id);
+
+    Ok(())
+}
+
+/// Tests retrieving enabled webhooks for a guild without webhooks.
+///
+/// Verifies that an empty list is returned rather than an error.
+///
+/// Expected: Ok with empty list
+#[tokio::test]
+async fn returns_empty_for_guild_without_webhooks() -> Result<(), AppError> {
+    let test = TestBuilder::new()
+        .with_table(DiscordGuild)
+        .with_table(Webhook)
+        .build()
+        .await
+        .unwrap();
+    let db = test.db.as_ref().unwrap();
+
+    let guild = factory::create_guild(db).await?;
+
+    let repo = WebhookRepository::new(db);
+    let webhooks = repo
+        .get_enabled_by_guild(guild.guild_id.parse().unwrap())
+        .await?;
+
+    assert!(webhooks.is_empty());
+
+    Ok(())
+}
@@ -0,0 +1,15 @@
+use crate::{
+    model::webhook::WebhookEvent,
+    server::{
+        data::webhook::WebhookRepository,
+        error::AppError,
+        model::webhook::{CreateWebhookParam, UpdateWebhookParam},
+    },
+};
+use entity::prelude::*;
+use sea_orm::EntityTrait;
+use test_utils::{builder::TestBuilder, factory};
+
+mod create;
+mod get_enabled_by_guild;
+mod update;
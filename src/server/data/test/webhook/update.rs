@@ -0,0 +1,107 @@
+use super::*;
+
+/// Tests updating a webhook.
+///
+/// Verifies that name, URL, events and enabled state are updated while the
+/// signing secret is preserved.
+///
+/// Expected: Ok with updated webhook
+#[tokio::test]
+async fn updates_webhook() -> Result<(), AppError> {
+    let test = TestBuilder::new()
+        .with_table(DiscordGuild)
+        .with_table(Webhook)
+        .build()
+        .await
+        .unwrap();
+    let db = test.db.as_ref().unwrap();
+
+    let guild = factory::create_guild(db).await?;
+    let guild_id = guild.guild_id.parse::<u64>().unwrap();
+
+    let repo = WebhookRepository::new(db);
+    let webhook = repo
+        .create(
+            guild_id,
+            "secret".to_string(),
+            CreateWebhookParam {
+                name: "SRP".to_string(),
+                url: "https://example.com/hooks/timerboard".to_string(),
+                events: vec![WebhookEvent::Created],
+                enabled: true,
+            },
+        )
+        .await?;
+
+    let updated = repo
+        .update(
+            guild_id,
+            webhook.id,
+            UpdateWebhookParam {
+                name: "Stats".to_string(),
+                url: "https://stats.example.com/hook".to_string(),
+                events: vec![WebhookEvent::Formup],
+                enabled: false,
+            },
+        )
+        .await?;
+
+    assert_eq!(updated.id, webhook.id);
+    assert_eq!(updated.name, "Stats");
+    assert_eq!(updated.url, "https://stats.example.com/hook");
+    assert_eq!(updated.events, vec![WebhookEvent::Formup]);
+    assert!(!updated.enabled);
+    assert_eq!(updated.secret, "secret");
+
+    Ok(())
+}
+
+/// Tests updating a webhook of another guild.
+///
+/// Verifies that webhooks can't be updated through a guild they don't belong to.
+///
+/// Expected: Err(AppError::NotFound)
+#[tokio::test]
+async fn fails_for_webhook_of_other_guild() -> Result<(), AppError> {
+    let test = TestBuilder::new()
+        .with_table(DiscordGuild)
+        .with_table(Webhook)
+        .build()
+        .await
+        .unwrap();
+    let db = test.db.as_ref().unwrap();
+
+    let guild = factory::create_guild(db).await?;
+    let other_guild = factory::create_guild(db).await?;
+
+    let repo = WebhookRepository::new(db);
+    let webhook = repo
+        .create(
+            guild.guild_id.parse().unwrap(),
+            "secret".to_string(),
+            CreateWebhookParam {
+                name: "SRP".to_string(),
+                url: "https://example.com/hooks/timerboard".to_string(),
+                events: vec![WebhookEvent::Created],
+                enabled: true,
+            },
+        )
+        .await?;
+
+    let result = repo
+        .update(
+            other_guild.guild_id.parse().unwrap(),
+            webhook.id,
+            UpdateWebhookParam {
+                name: "Stats".to_string(),
+                url: "https://stats.example.com/hook".to_string(),
+                events: vec![WebhookEvent::Formup],
+                enabled: true,
+            },
+        )
+        .await;
+
+    assert!(matches!(result, Err(AppError::NotFound(_))));
+
+    Ok(())
+}
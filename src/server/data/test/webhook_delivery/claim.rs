@@ -0,0 +1,57 @@
+use super::*;
+
+/// Tests claiming a due delivery for an attempt.
+///
+/// Verifies that a delivery is only claimed once, so overlapping scheduler runs which
+/// fetched the same delivery don't send it twice, and that it isn't due again until
+/// the lease ends.
+///
+/// Expected: Ok(true) for the first claim, Ok(false) afterwards
+#[tokio::test]
+async fn claims_due_delivery_once() -> Result<(), AppError> {
+    let test = TestBuilder::new()
+        .with_table(DiscordGuild)
+        .with_table(entity::prelude::Webhook)
+        .with_table(WebhookDelivery)
+        .build()
+        .await
+        .unwrap();
+    let db = test.db.as_ref().unwrap();
+
+    let guild = factory::create_guild(db).await?;
+    let webhook = WebhookRepository::new(db)
+        .create(
+            guild.guild_id.parse().unwrap(),
+            "secret".to_string(),
+            CreateWebhookParam {
+                name: "SRP".to_string(),
+                url: "https://example.com/hooks/timerboard".to_string(),
+                events: WebhookEvent::ALL.to_vec(),
+                enabled: true,
+            },
+        )
+        .await?;
+
+    let repo = WebhookDeliveryRepository::new(db);
+    let delivery = repo
+        .create(CreateWebhookDeliveryParam {
+            webhook_id: webhook.id,
+            event: WebhookEvent::Created,
+            payload: r#"{"event":"fleet.created"}"#.to_string(),
+        })
+        .await?;
+
+    let now = Utc::now() + Duration::seconds(1);
+    let lease_until = now + Duration::minutes(5);
+
+    assert!(repo.claim(delivery.id, now, lease_until).await?);
+    assert!(!repo.claim(delivery.id, now, lease_until).await?);
+    assert!(repo.get_due(now, 50).await?.is_empty());
+
+    // Once the lease ends an interrupted attempt is due again
+    let due = repo.get_due(lease_until, 50).await?;
+    assert_eq!(due.len(), 1);
+    assert_eq!(due[0].0.id, delivery.id);
+
+    Ok(())
+}
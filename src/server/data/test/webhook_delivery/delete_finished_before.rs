@@ -0,0 +1,134 @@
+use super::*;
+
+/// Tests deleting finished deliveries before a cutoff.
+///
+/// Verifies that succeeded & failed deliveries are counted and deleted while
+/// pending deliveries awaiting an attempt are kept.
+///
+/// Expected: Ok(2) with only the pending delivery remaining
+#[tokio::test]
+async fn deletes_finished_deliveries_before_cutoff() -> Result<(), AppError> {
+    let test = TestBuilder::new()
+        .with_table(DiscordGuild)
+        .with_table(entity::prelude::Webhook)
+        .with_table(WebhookDelivery)
+        .build()
+        .await
+        .unwrap();
+    let db = test.db.as_ref().unwrap();
+
+    let guild = factory::create_guild(db).await?;
+    let webhook = WebhookRepository::new(db)
+        .create(
+            guild.guild_id.parse().unwrap(),
+            "secret".to_string(),
+            CreateWebhookParam {
+                name: "SRP".to_string(),
+                url: "https://example.com/hooks/timerboard".to_string(),
+                events: WebhookEvent::ALL.to_vec(),
+                enabled: true,
+            },
+        )
+        .await?;
+
+    let repo = WebhookDeliveryRepository::new(db);
+    let pending = repo
+        .create(CreateWebhookDeliveryParam {
+            webhook_id: webhook.id,
+            event: WebhookEvent::Created,
+            payload: r#"{"event":"fleet.created"}"#.to_string(),
+        })
+        .await?;
+    for status in [
+        WebhookDeliveryStatus::Succeeded,
+        WebhookDeliveryStatus::Failed,
+    ] {
+        let delivery = repo
+            .create(CreateWebhookDeliveryParam {
+                webhook_id: webhook.id,
+                event: WebhookEvent::Created,
+                payload: r#"{"event":"fleet.created"}"#.to_string(),
+            })
+            .await?;
+        repo.record_attempt(
+            delivery.id,
+            WebhookDeliveryAttemptParam {
+                status,
+                response_status: None,
+                error: None,
+                next_attempt_at: None,
+            },
+        )
+        .await?;
+    }
+
+    let cutoff = Utc::now() + Duration::seconds(1);
+
+    assert_eq!(repo.count_finished_before(cutoff).await?, 2);
+    assert_eq!(repo.delete_finished_before(cutoff).await?, 2);
+
+    let remaining = WebhookDelivery::find().all(db).await?;
+    assert_eq!(remaining.len(), 1);
+    assert_eq!(remaining[0].id, pending.id);
+
+    Ok(())
+}
+
+/// Tests that recently finished deliveries are kept.
+///
+/// Verifies that finished deliveries created after the cutoff are not deleted.
+///
+/// Expected: Ok(0) with the delivery remaining
+#[tokio::test]
+async fn keeps_finished_deliveries_after_cutoff() -> Result<(), AppError> {
+    let test = TestBuilder::new()
+        .with_table(DiscordGuild)
+        .with_table(entity::prelude::Webhook)
+        .with_table(WebhookDelivery)
+        .build()
+        .await
+        .unwrap();
+    let db = test.db.as_ref().unwrap();
+
+    let guild = factory::create_guild(db).await?;
+    let webhook = WebhookRepository::new(db)
+        .create(
+            guild.guild_id.parse().unwrap(),
+            "secret".to_string(),
+            CreateWebhookParam {
+                name: "SRP".to_string(),
+                url: "https://example.com/hooks/timerboard".to_string(),
+                events: WebhookEvent::ALL.to_vec(),
+                enabled: true,
+            },
+        )
+        .await?;
+
+    let repo = WebhookDeliveryRepository::new(db);
+    let delivery = repo
+        .create(CreateWebhookDeliveryParam {
+            webhook_id: webhook.id,
+            event: WebhookEvent::Created,
+            payload: r#"{"event":"fleet.created"}"#.to_string(),
+        })
+        .await?;
+    repo.record_attempt(
+        delivery.id,
+        WebhookDeliveryAttemptParam {
+            status: WebhookDeliveryStatus::Succeeded,
+            response_status: Some(200),
+            error: None,
+            next_attempt_at: None,
+        },
+    )
+    .await?;
+
+    let deleted = repo
+        .delete_finished_before(Utc::now() - Duration::days(30))
+        .await?;
+
+    assert_eq!(deleted, 0);
+    assert_eq!(WebhookDelivery::find().count(db).await?, 1);
+
+    Ok(())
+}
@@ -0,0 +1,168 @@
+use super::*;
+
+/// Tests retrieving due deliveries.
+///
+/// Verifies that newly queued deliveries are due immediately and returned along
+/// with the webhook they belong to.
+///
+/// Expected: Ok with the queued delivery & its webhook
+#[tokio::test]
+async fn returns_due_deliveries_with_webhook() -> Result<(), AppError> {
+    let test = TestBuilder::new()
+        .with_table(DiscordGuild)
+        .with_table(entity::prelude::Webhook)
+        .with_table(WebhookDelivery)
+        .build()
+        .await
+        .unwrap();
+    let db = test.db.as_ref().unwrap();
+
+    let guild = factory::create_guild(db).await?;
+    let webhook = WebhookRepository::new(db)
+        .create(
+            guild.guild_id.parse().unwrap(),
+            "secret".to_string(),
+            CreateWebhookParam {
+                name: "SRP".to_string(),
+                url: "https://example.com/hooks/timerboard".to_string(),
+                events: WebhookEvent::ALL.to_vec(),
+                enabled: true,
+            },
+        )
+        .await?;
+
+    let repo = WebhookDeliveryRepository::new(db);
+    let delivery = repo
+        .create(CreateWebhookDeliveryParam {
+            webhook_id: webhook.id,
+            event: WebhookEvent::Created,
+            payload: r#"{"event":"fleet.created"}"#.to_string(),
+        })
+        .await?;
+
+    let due = repo.get_due(Utc::now() + Duration::seconds(1), 50).await?;
+
+    assert_eq!(due.len(), 1);
+    assert_eq!(due[0].0.id, delivery.id);
+    assert_eq!(due[0].0.status, WebhookDeliveryStatus::Pending);
+    assert_eq!(due[0].1.id, webhook.id);
+
+    Ok(())
+}
+
+/// Tests that deliveries scheduled for a later retry are not due yet.
+///
+/// Verifies that a delivery whose next attempt lies in the future is excluded.
+///
+/// Expected: Ok with empty list
+#[tokio::test]
+async fn excludes_deliveries_not_yet_due() -> Result<(), AppError> {
+    let test = TestBuilder::new()
+        .with_table(DiscordGuild)
+        .with_table(entity::prelude::Webhook)
+        .with_table(WebhookDelivery)
+        .build()
+        .await
+        .unwrap();
+    let db = test.db.as_ref().unwrap();
+
+    let guild = factory::create_guild(db).await?;
+    let webhook = WebhookRepository::new(db)
+        .create(
+            guild.guild_id.parse().unwrap(),
+            "secret".to_string(),
+            CreateWebhookParam {
+                name: "SRP".to_string(),
+                url: "https://example.com/hooks/timerboard".to_string(),
+                events: WebhookEvent::ALL.to_vec(),
+                enabled: true,
+            },
+        )
+        .await?;
+
+    let repo = WebhookDeliveryRepository::new(db);
+    let delivery = repo
+        .create(CreateWebhookDeliveryParam {
+            webhook_id: webhook.id,
+            event: WebhookEvent::Created,
+            payload: r#"{"event":"fleet.created"}"#.to_string(),
+        })
+        .await?;
+    repo.record_attempt(
+        delivery.id,
+        WebhookDeliveryAttemptParam {
+            status: WebhookDeliveryStatus::Pending,
+            response_status: Some(500),
+            error: Some("Internal Server Error".to_string()),
+            next_attempt_at: Some(Utc::now() + Duration::minutes(5)),
+        },
+    )
+    .await?;
+
+    let due = repo.get_due(Utc::now(), 50).await?;
+
+    assert!(due.is_empty());
+
+    Ok(())
+}
+
+/// Tests that finished deliveries are not returned.
+///
+/// Verifies that succeeded & failed deliveries are never attempted again.
+///
+/// Expected: Ok with empty list
+#[tokio::test]
+async fn excludes_finished_deliveries() -> Result<(), AppError> {
+    let test = TestBuilder::new()
+        .with_table(DiscordGuild)
+        .with_table(entity::prelude::Webhook)
+        .with_table(WebhookDelivery)
+        .build()
+        .await
+        .unwrap();
+    let db = test.db.as_ref().unwrap();
+
+    let guild = factory::create_guild(db).await?;
+    let webhook = WebhookRepository::new(db)
+        .create(
+            guild.guild_id.parse().unwrap(),
+            "secret".to_string(),
+            CreateWebhookParam {
+                name: "SRP".to_string(),
+                url: "https://example.com/hooks/timerboard".to_string(),
+                events: WebhookEvent::ALL.to_vec(),
+                enabled: true,
+            },
+        )
+        .await?;
+
+    let repo = WebhookDeliveryRepository::new(db);
+    for status in [
+        WebhookDeliveryStatus::Succeeded,
+        WebhookDeliveryStatus::Failed,
+    ] {
+        let delivery = repo
+            .create(CreateWebhookDeliveryParam {
+                webhook_id: webhook.id,
+                event: WebhookEvent::Created,
+                payload: r#"{"event":"fleet.created"}"#.to_string(),
+            })
+            .await?;
+        repo.record_attempt(
+            delivery.id,
+            WebhookDeliveryAttemptParam {
+                status,
+                response_status: None,
+                error: None,
+                next_attempt_at: None,
+            },
+        )
+        .await?;
+    }
+
+    let due = repo.get_due(Utc::now() + Duration::seconds(1), 50).await?;
+
+    assert!(due.is_empty());
+
+    Ok(())
+}
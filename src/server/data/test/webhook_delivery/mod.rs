@@ -0,0 +1,21 @@
+use chrono::{Duration, Utc};
+
+use crate::{
+    model::webhook::{WebhookDeliveryStatus, WebhookEvent},
+    server::{
+        data::{webhook::WebhookRepository, webhook_delivery::WebhookDeliveryRepository},
+        error::AppError,
+        model::webhook::{
+            CreateWebhookDeliveryParam, CreateWebhookParam, WebhookDeliveryAttemptParam,
+        },
+    },
+};
+use entity::prelude::{DiscordGuild, WebhookDelivery};
+use sea_orm::{EntityTrait, PaginatorTrait};
+use test_utils::{builder::TestBuilder, factory};
+
+mod claim;
+mod delete_finished_before;
+mod get_due;
+mod record_attempt;
+mod requeue;
@@ -0,0 +1,102 @@
+use super::*;
+
+/// Tests recording a delivery attempt.
+///
+/// Verifies that the attempt counter is incremented and the outcome of the
+/// attempt is stored on the delivery.
+///
+/// Expected: Ok with updated delivery
+#[tokio::test]
+async fn records_attempt_outcome() -> Result<(), AppError> {
+    let test = TestBuilder::new()
+        .with_table(DiscordGuild)
+        .with_table(entity::prelude::Webhook)
+        .with_table(WebhookDelivery)
+        .build()
+        .await
+        .unwrap();
+    let db = test.db.as_ref().unwrap();
+
+    let guild = factory::create_guild(db).await?;
+    let webhook = WebhookRepository::new(db)
+        .create(
+            guild.guild_id.parse().unwrap(),
+            "secret".to_string(),
+            CreateWebhookParam {
+                name: "SRP".to_string(),
+                url: "https://example.com/hooks/timerboard".to_string(),
+                events: WebhookEvent::ALL.to_vec(),
+                enabled: true,
+            },
+        )
+        .await?;
+
+    let repo = WebhookDeliveryRepository::new(db);
+    let delivery = repo
+        .create(CreateWebhookDeliveryParam {
+            webhook_id: webhook.id,
+            event: WebhookEvent::Created,
+            payload: r#"{"event":"fleet.created"}"#.to_string(),
+        })
+        .await?;
+
+    let updated = repo
+        .record_attempt(
+            delivery.id,
+            WebhookDeliveryAttemptParam {
+                status: WebhookDeliveryStatus::Failed,
+                response_status: Some(404),
+                error: Some("Not Found".to_string()),
+                next_attempt_at: None,
+            },
+        )
+        .await?;
+
+    assert_eq!(updated.attempts, 1);
+    assert_eq!(updated.status, WebhookDeliveryStatus::Failed);
+    assert_eq!(updated.response_status, Some(404));
+    assert_eq!(updated.error.as_deref(), Some("Not Found"));
+    assert!(updated.next_attempt_at.is_none());
+    assert!(updated.last_attempt_at.is_some());
+
+    // Verify the payload is left untouched for replays
+    let db_delivery = WebhookDelivery::find_by_id(delivery.id)
+        .one(db)
+        .await?
+        .unwrap();
+    assert_eq!(db_delivery.payload, r#"{"event":"fleet.created"}"#);
+
+    Ok(())
+}
+
+/// Tests recording an attempt for a delivery that doesn't exist.
+///
+/// Expected: Err(AppError::NotFound)
+#[tokio::test]
+async fn fails_for_nonexistent_delivery() -> Result<(), AppError> {
+    let test = TestBuilder::new()
+        .with_table(DiscordGuild)
+        .with_table(entity::prelude::Webhook)
+        .with_table(WebhookDelivery)
+        .build()
+        .await
+        .unwrap();
+    let db = test.db.as_ref().unwrap();
+
+    let repo = WebhookDeliveryRepository::new(db);
+    let result = repo
+        .record_attempt(
+            999,
+            WebhookDeliveryAttemptParam {
+                status: WebhookDeliveryStatus::Succeeded,
+                response_status: Some(200),
+                error: None,
+                next_attempt_at: None,
+            },
+        )
+        .await;
+
+    assert!(matches!(result, Err(AppError::NotFound(_))));
+
+    Ok(())
+}
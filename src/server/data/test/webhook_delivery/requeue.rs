@@ -0,0 +1,119 @@
+use super::*;
+
+/// Tests requeueing a failed delivery.
+///
+/// Verifies that the delivery is marked pending with a fresh retry budget and is
+/// due again.
+///
+/// Expected: Ok(Some) with pending delivery
+#[tokio::test]
+async fn requeues_failed_delivery() -> Result<(), AppError> {
+    let test = TestBuilder::new()
+        .with_table(DiscordGuild)
+        .with_table(entity::prelude::Webhook)
+        .with_table(WebhookDelivery)
+        .build()
+        .await
+        .unwrap();
+    let db = test.db.as_ref().unwrap();
+
+    let guild = factory::create_guild(db).await?;
+    let webhook = WebhookRepository::new(db)
+        .create(
+            guild.guild_id.parse().unwrap(),
+            "secret".to_string(),
+            CreateWebhookParam {
+                name: "SRP".to_string(),
+                url: "https://example.com/hooks/timerboard".to_string(),
+                events: WebhookEvent::ALL.to_vec(),
+                enabled: true,
+            },
+        )
+        .await?;
+
+    let repo = WebhookDeliveryRepository::new(db);
+    let delivery = repo
+        .create(CreateWebhookDeliveryParam {
+            webhook_id: webhook.id,
+            event: WebhookEvent::Created,
+            payload: r#"{"event":"fleet.created"}"#.to_string(),
+        })
+        .await?;
+    repo.record_attempt(
+        delivery.id,
+        WebhookDeliveryAttemptParam {
+            status: WebhookDeliveryStatus::Failed,
+            response_status: Some(500),
+            error: None,
+            next_attempt_at: None,
+        },
+    )
+    .await?;
+
+    let requeued = repo.requeue(webhook.id, delivery.id).await?.unwrap();
+
+    assert_eq!(requeued.status, WebhookDeliveryStatus::Pending);
+    assert_eq!(requeued.attempts, 0);
+
+    let due = repo.get_due(Utc::now() + Duration::seconds(1), 50).await?;
+    assert_eq!(due.len(), 1);
+
+    Ok(())
+}
+
+/// Tests requeueing a delivery through a webhook it doesn't belong to.
+///
+/// Expected: Ok(None)
+#[tokio::test]
+async fn returns_none_for_delivery_of_other_webhook() -> Result<(), AppError> {
+    let test = TestBuilder::new()
+        .with_table(DiscordGuild)
+        .with_table(entity::prelude::Webhook)
+        .with_table(WebhookDelivery)
+        .build()
+        .await
+        .unwrap();
+    let db = test.db.as_ref().unwrap();
+
+    let guild = factory::create_guild(db).await?;
+    let webhook = WebhookRepository::new(db)
+        .create(
+            guild.guild_id.parse().unwrap(),
+            "secret".to_string(),
+            CreateWebhookParam {
+                name: "SRP".to_string(),
+                url: "https://example.com/hooks/timerboard".to_string(),
+                events: WebhookEvent::ALL.to_vec(),
+                enabled: true,
+            },
+        )
+        .await?;
+    let other_guild = factory::create_guild(db).await?;
+    let other_webhook = WebhookRepository::new(db)
+        .create(
+            other_guild.guild_id.parse().unwrap(),
+            "secret".to_string(),
+            CreateWebhookParam {
+                name: "Stats".to_string(),
+                url: "https://example.com/hooks/timerboard".to_string(),
+                events: WebhookEvent::ALL.to_vec(),
+                enabled: true,
+            },
+        )
+        .await?;
+
+    let repo = WebhookDeliveryRepository::new(db);
+    let delivery = repo
+        .create(CreateWebhookDeliveryParam {
+            webhook_id: webhook.id,
+            event: WebhookEvent::Created,
+            payload: r#"{"event":"fleet.created"}"#.to_string(),
+        })
+        .await?;
+
+    let result = repo.requeue(other_webhook.id, delivery.id).await?;
+
+    assert!(result.is_none());
+
+    Ok(())
+}
@@ -15,6 +15,7 @@ use sea_orm::{
     ActiveValue, ColumnTrait, DatabaseConnection, DbErr, EntityTrait, PaginatorTrait, QueryFilter,
     QueryOrder,
 };
+use std::collections::HashSet;
 
 /// Repository providing database operations for user management.
 ///
@@ -232,6 +233,35 @@ impl<'a> UserRepository<'a> {
             .collect())
     }
 
+    /// Gets which of the given users are admins.
+    ///
+    /// Used to check many users at once, such as the subscribers of a category, instead of
+    /// looking up each user separately.
+    ///
+    /// # Arguments
+    /// - `user_ids` - Slice of Discord user IDs as u64
+    ///
+    /// # Returns
+    /// - `Ok(HashSet<u64>)` - IDs of the admins among the users (empty if slice is empty)
+    /// - `Err(DbErr)` - Database error during query
+    pub async fn get_admin_ids(&self, user_ids: &[u64]) -> Result<HashSet<u64>, DbErr> {
+        if user_ids.is_empty() {
+            return Ok(HashSet::new());
+        }
+
+        let user_id_strings: Vec<String> = user_ids.iter().map(|id| id.to_string()).collect();
+        let entities = entity::prelude::User::find()
+            .filter(entity::user::Column::DiscordId.is_in(user_id_strings))
+            .filter(entity::user::Column::Admin.eq(true))
+            .all(self.db)
+            .await?;
+
+        Ok(entities
+            .into_iter()
+            .filter_map(|entity| entity.discord_id.parse().ok())
+            .collect())
+    }
+
     /// Sets admin status for a user.
     ///
     /// Updates the admin column for the specified user to grant or revoke admin privileges.
@@ -10,6 +10,7 @@ use sea_orm::{
     sea_query::Condition, ColumnTrait, DatabaseConnection, DbErr, EntityTrait, PaginatorTrait,
     QueryFilter, QueryOrder,
 };
+use std::collections::HashSet;
 
 /// Repository for user category permission operations.
 ///
@@ -290,6 +291,94 @@ impl<'a> UserCategoryPermissionRepository<'a> {
         Ok(guild_category_ids)
     }
 
+    /// Gets fleet category IDs that a user can broadcast to.
+    ///
+    /// Returns category IDs where the user has can_broadcast permission through their
+    /// Discord roles. Admins and members of the guild's broadcast roles are not handled
+    /// here - check those before calling this method to grant access to all categories.
+    ///
+    /// # Arguments
+    /// - `user_id` - Discord user ID
+    /// - `guild_id` - Discord guild ID
+    ///
+    /// # Returns
+    /// - `Ok(Vec<i32>)` - Category IDs the user can broadcast to
+    /// - `Err(DbErr)` - Database error during query
+    pub async fn get_broadcastable_category_ids_by_user(
+        &self,
+        user_id: u64,
+        guild_id: u64,
+    ) -> Result<Vec<i32>, DbErr> {
+        // First, get all role IDs that the user has in this guild
+        let user_role_ids: Vec<String> = entity::prelude::UserDiscordGuildRole::find()
+            .filter(entity::user_discord_guild_role::Column::UserId.eq(user_id.to_string()))
+            .all(self.db)
+            .await?
+            .into_iter()
+            .map(|r| r.role_id)
+            .collect();
+
+        if user_role_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        // Find all category IDs where the user has can_broadcast permission
+        let category_ids: Vec<i32> = entity::prelude::FleetCategoryAccessRole::find()
+            .filter(entity::fleet_category_access_role::Column::RoleId.is_in(user_role_ids))
+            .filter(entity::fleet_category_access_role::Column::CanBroadcast.eq(true))
+            .all(self.db)
+            .await?
+            .into_iter()
+            .map(|r| r.fleet_category_id)
+            .collect();
+
+        if category_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        // Verify these categories belong to the specified guild
+        let guild_category_ids: Vec<i32> = entity::prelude::FleetCategory::find()
+            .filter(entity::fleet_category::Column::GuildId.eq(guild_id.to_string()))
+            .filter(entity::fleet_category::Column::Id.is_in(category_ids))
+            .all(self.db)
+            .await?
+            .into_iter()
+            .map(|c| c.id)
+            .collect();
+
+        Ok(guild_category_ids)
+    }
+
+    /// Checks if a user has any of the given Discord roles.
+    ///
+    /// Used for guild-wide permissions granted through roles configured in the guild
+    /// settings, such as the broadcast roles.
+    ///
+    /// # Arguments
+    /// - `user_id` - Discord user ID
+    /// - `role_ids` - Discord role IDs to check
+    ///
+    /// # Returns
+    /// - `Ok(true)` - User has at least one of the roles
+    /// - `Ok(false)` - User has none of the roles or no roles were given
+    /// - `Err(DbErr)` - Database error during query
+    pub async fn user_has_any_role(&self, user_id: u64, role_ids: &[u64]) -> Result<bool, DbErr> {
+        if role_ids.is_empty() {
+            return Ok(false);
+        }
+
+        let role_count = entity::prelude::UserDiscordGuildRole::find()
+            .filter(entity::user_discord_guild_role::Column::UserId.eq(user_id.to_string()))
+            .filter(
+                entity::user_discord_guild_role::Column::RoleId
+                    .is_in(role_ids.iter().map(u64::to_string)),
+            )
+            .count(self.db)
+            .await?;
+
+        Ok(role_count > 0)
+    }
+
     /// Checks if a user has view access to a specific category.
     ///
     /// Verifies that at least one of the user's Discord roles has can_view permission
@@ -334,6 +423,55 @@ impl<'a> UserCategoryPermissionRepository<'a> {
         Ok(access_count > 0)
     }
 
+    /// Gets which of the given users have view access to a specific category.
+    ///
+    /// Batched counterpart of `user_can_view_category` for checking many users at once,
+    /// such as the subscribers of a category. Admins are not included unless one of their
+    /// roles grants view access.
+    ///
+    /// # Arguments
+    /// - `user_ids` - Discord user IDs to check
+    /// - `category_id` - Fleet category ID to check access for
+    ///
+    /// # Returns
+    /// - `Ok(HashSet<u64>)` - IDs of the users with view access
+    /// - `Err(DbErr)` - Database error during query
+    pub async fn get_users_with_view_access(
+        &self,
+        user_ids: &[u64],
+        category_id: i32,
+    ) -> Result<HashSet<u64>, DbErr> {
+        if user_ids.is_empty() {
+            return Ok(HashSet::new());
+        }
+
+        // Roles with view access to this category
+        let role_ids: Vec<String> = entity::prelude::FleetCategoryAccessRole::find()
+            .filter(entity::fleet_category_access_role::Column::FleetCategoryId.eq(category_id))
+            .filter(entity::fleet_category_access_role::Column::CanView.eq(true))
+            .all(self.db)
+            .await?
+            .into_iter()
+            .map(|r| r.role_id)
+            .collect();
+
+        if role_ids.is_empty() {
+            return Ok(HashSet::new());
+        }
+
+        let user_id_strings: Vec<String> = user_ids.iter().map(|id| id.to_string()).collect();
+        let user_roles = entity::prelude::UserDiscordGuildRole::find()
+            .filter(entity::user_discord_guild_role::Column::UserId.is_in(user_id_strings))
+            .filter(entity::user_discord_guild_role::Column::RoleId.is_in(role_ids))
+            .all(self.db)
+            .await?;
+
+        Ok(user_roles
+            .into_iter()
+            .filter_map(|r| r.user_id.parse().ok())
+            .collect())
+    }
+
     /// Checks if a user has create access to a specific category.
     ///
     /// Verifies that at least one of the user's Discord roles has can_create or can_manage
@@ -0,0 +1,215 @@
+//! Webhook data repository for database operations
+//!
+//! Provides the `WebhookRepository` for managing outgoing webhook endpoints in the
+//! database. Provides methods to create, get, update, and delete webhooks as well as
+//! handles the conversion of database entity models into domain models for usage
+//! within services & controllers.
+
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, DatabaseConnection, DbErr, EntityTrait,
+    IntoActiveModel, PaginatorTrait, QueryFilter, QueryOrder,
+};
+
+use crate::server::{
+    error::AppError,
+    model::webhook::{join_events, CreateWebhookParam, UpdateWebhookParam, Webhook},
+};
+
+/// Repository providing database operations for webhook management.
+///
+/// This struct holds a reference to the database connection and provides methods
+/// for creating, reading, updating, and deleting webhook records.
+pub struct WebhookRepository<'a> {
+    db: &'a DatabaseConnection,
+}
+
+impl<'a> WebhookRepository<'a> {
+    /// Creates a new WebhookRepository instance
+    ///
+    /// # Arguments
+    /// - `db` - Reference to the database connection
+    ///
+    /// # Returns
+    /// - `WebhookRepository` - new repository instance
+    pub fn new(db: &'a DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// Creates a new webhook
+    ///
+    /// # Arguments
+    /// - `guild_id` - The ID of the guild the webhook belongs to
+    /// - `secret` - Secret used to sign delivery payloads
+    /// - `param` - Create parameters containing the webhook creation data
+    ///
+    /// # Returns
+    /// - `Ok(Webhook)` - The created webhook as a domain model
+    /// - `Err(AppError::Database)` - Database error during insert operation
+    pub async fn create(
+        &self,
+        guild_id: u64,
+        secret: String,
+        param: CreateWebhookParam,
+    ) -> Result<Webhook, AppError> {
+        let entity = entity::prelude::Webhook::insert(entity::webhook::ActiveModel {
+            guild_id: ActiveValue::Set(guild_id.to_string()),
+            name: ActiveValue::Set(param.name),
+            url: ActiveValue::Set(param.url),
+            secret: ActiveValue::Set(secret),
+            events: ActiveValue::Set(join_events(&param.events)),
+            enabled: ActiveValue::Set(param.enabled),
+            created_at: ActiveValue::Set(chrono::Utc::now()),
+            ..Default::default()
+        })
+        .exec_with_returning(self.db)
+        .await?;
+
+        Webhook::from_entity(entity)
+    }
+
+    /// Gets a page of webhooks for a guild ordered by ID
+    ///
+    /// # Arguments
+    /// - `guild_id` - The ID of the guild to list webhooks for
+    /// - `page` - Zero-indexed page number
+    /// - `per_page` - Number of webhooks per page
+    ///
+    /// # Returns
+    /// - `Ok((Vec<Webhook>, u64))` - The webhooks of the page & total webhook count
+    /// - `Err(AppError::Database)` - Database error during fetch operation
+    pub async fn list_by_guild(
+        &self,
+        guild_id: u64,
+        page: u64,
+        per_page: u64,
+    ) -> Result<(Vec<Webhook>, u64), AppError> {
+        let paginator = entity::prelude::Webhook::find()
+            .filter(entity::webhook::Column::GuildId.eq(guild_id.to_string()))
+            .order_by_asc(entity::webhook::Column::Id)
+            .paginate(self.db, per_page);
+
+        let total = paginator.num_items().await?;
+        let entities = paginator.fetch_page(page).await?;
+
+        let webhooks = entities
+            .into_iter()
+            .map(Webhook::from_entity)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok((webhooks, total))
+    }
+
+    /// Gets all enabled webhooks for a guild
+    ///
+    /// Used when emitting fleet events to determine which endpoints need a delivery.
+    ///
+    /// # Arguments
+    /// - `guild_id` - The ID of the guild to get webhooks for
+    ///
+    /// # Returns
+    /// - `Ok(Vec<Webhook>)` - All enabled webhooks of the guild
+    /// - `Err(AppError::Database)` - Database error during fetch operation
+    pub async fn get_enabled_by_guild(&self, guild_id: u64) -> Result<Vec<Webhook>, AppError> {
+        let entities = entity::prelude::Webhook::find()
+            .filter(entity::webhook::Column::GuildId.eq(guild_id.to_string()))
+            .filter(entity::webhook::Column::Enabled.eq(true))
+            .all(self.db)
+            .await?;
+
+        entities.into_iter().map(Webhook::from_entity).collect()
+    }
+
+    /// Counts the number of webhooks for a guild
+    pub async fn count_by_guild(&self, guild_id: u64) -> Result<usize, AppError> {
+        let count = entity::prelude::Webhook::find()
+            .filter(entity::webhook::Column::GuildId.eq(guild_id.to_string()))
+            .count(self.db)
+            .await?;
+
+        Ok(count as usize)
+    }
+
+    /// Updates the webhook based upon provided ID & update parameters
+    ///
+    /// # Arguments
+    /// - `guild_id` - The ID of the guild the webhook belongs to
+    /// - `id` - ID of the webhook to update
+    /// - `param` - Update parameters of the webhook fields to modify
+    ///
+    /// # Returns
+    /// - `Ok(Webhook)` - The updated webhook as a domain model
+    /// - `Err(AppError::NotFound)` - No webhook with the ID exists in the guild
+    /// - `Err(AppError::Database)` - Database error during update operation
+    pub async fn update(
+        &self,
+        guild_id: u64,
+        id: i32,
+        param: UpdateWebhookParam,
+    ) -> Result<Webhook, AppError> {
+        let Some(entity) = self.find_entity_by_id(guild_id, id).await? else {
+            return Err(AppError::NotFound(format!(
+                "Failed to find webhook ID {} for guild ID {} while attempting update",
+                id, guild_id
+            )));
+        };
+
+        let mut active_model = entity.into_active_model();
+
+        active_model.name = ActiveValue::Set(param.name);
+        active_model.url = ActiveValue::Set(param.url);
+        active_model.events = ActiveValue::Set(join_events(&param.events));
+        active_model.enabled = ActiveValue::Set(param.enabled);
+
+        let entity = active_model.update(self.db).await?;
+
+        Webhook::from_entity(entity)
+    }
+
+    /// Deletes webhook of the provided ID along with its delivery log
+    ///
+    /// # Arguments
+    /// - `guild_id` - The ID of the guild the webhook belongs to
+    /// - `id` - The ID of the webhook to delete
+    ///
+    /// # Returns
+    /// - `Ok(())` - The webhook was successfully deleted
+    /// - `Err(AppError::Database)` - Database error during delete operation
+    pub async fn delete(&self, guild_id: u64, id: i32) -> Result<(), AppError> {
+        entity::prelude::Webhook::delete_many()
+            .filter(entity::webhook::Column::Id.eq(id))
+            .filter(entity::webhook::Column::GuildId.eq(guild_id.to_string()))
+            .exec(self.db)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Finds a webhook by ID within a specific guild
+    ///
+    /// # Arguments
+    /// - `guild_id` - The ID of the guild the webhook belongs to
+    /// - `id` - The ID of the webhook to retrieve
+    ///
+    /// # Returns
+    /// - `Ok(Some(Webhook))` - The webhook if found in the specified guild
+    /// - `Ok(None)` - If no webhook exists with the given ID in the guild
+    /// - `Err(AppError::Database)` - Database error during fetch operation
+    pub async fn find_by_id(&self, guild_id: u64, id: i32) -> Result<Option<Webhook>, AppError> {
+        let entity = self.find_entity_by_id(guild_id, id).await?;
+
+        entity.map(Webhook::from_entity).transpose()
+    }
+
+    /// Helper method to find a webhook entity by ID
+    async fn find_entity_by_id(
+        &self,
+        guild_id: u64,
+        id: i32,
+    ) -> Result<Option<entity::webhook::Model>, DbErr> {
+        entity::prelude::Webhook::find()
+            .filter(entity::webhook::Column::GuildId.eq(guild_id.to_string()))
+            .filter(entity::webhook::Column::Id.eq(id))
+            .one(self.db)
+            .await
+    }
+}
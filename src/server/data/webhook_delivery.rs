@@ -0,0 +1,304 @@
+//! Webhook delivery data repository for database operations
+//!
+//! Provides the `WebhookDeliveryRepository` for managing the delivery log of outgoing
+//! webhooks. Deliveries are queued as pending records, picked up by the scheduler once
+//! due, and updated with the outcome of each attempt. Finished deliveries are pruned
+//! once older than the configured retention period.
+
+use chrono::{DateTime, Utc};
+use sea_orm::{
+    sea_query::Expr, ActiveModelTrait, ActiveValue, ColumnTrait, DatabaseConnection, DbErr,
+    EntityTrait, IntoActiveModel, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect,
+};
+
+use crate::{
+    model::webhook::WebhookDeliveryStatus,
+    server::{
+        error::AppError,
+        model::webhook::{
+            CreateWebhookDeliveryParam, Webhook, WebhookDelivery, WebhookDeliveryAttemptParam,
+        },
+    },
+};
+
+/// Repository providing database operations for the webhook delivery log.
+pub struct WebhookDeliveryRepository<'a> {
+    db: &'a DatabaseConnection,
+}
+
+impl<'a> WebhookDeliveryRepository<'a> {
+    /// Creates a new WebhookDeliveryRepository instance
+    ///
+    /// # Arguments
+    /// - `db` - Reference to the database connection
+    ///
+    /// # Returns
+    /// - `WebhookDeliveryRepository` - new repository instance
+    pub fn new(db: &'a DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// Queues a new pending delivery which is due immediately
+    ///
+    /// # Arguments
+    /// - `param` - Webhook, event & serialized payload of the delivery
+    ///
+    /// # Returns
+    /// - `Ok(WebhookDelivery)` - The queued delivery as a domain model
+    /// - `Err(AppError::Database)` - Database error during insert operation
+    pub async fn create(
+        &self,
+        param: CreateWebhookDeliveryParam,
+    ) -> Result<WebhookDelivery, AppError> {
+        let now = Utc::now();
+
+        let entity =
+            entity::prelude::WebhookDelivery::insert(entity::webhook_delivery::ActiveModel {
+                webhook_id: ActiveValue::Set(param.webhook_id),
+                event: ActiveValue::Set(param.event.as_str().to_string()),
+                payload: ActiveValue::Set(param.payload),
+                status: ActiveValue::Set(WebhookDeliveryStatus::Pending.as_str().to_string()),
+                attempts: ActiveValue::Set(0),
+                response_status: ActiveValue::Set(None),
+                error: ActiveValue::Set(None),
+                next_attempt_at: ActiveValue::Set(Some(now)),
+                last_attempt_at: ActiveValue::Set(None),
+                created_at: ActiveValue::Set(now),
+                ..Default::default()
+            })
+            .exec_with_returning(self.db)
+            .await?;
+
+        WebhookDelivery::from_entity(entity)
+    }
+
+    /// Gets a page of deliveries for a webhook, most recent first
+    ///
+    /// # Arguments
+    /// - `webhook_id` - ID of the webhook to list deliveries for
+    /// - `page` - Zero-indexed page number
+    /// - `per_page` - Number of deliveries per page
+    ///
+    /// # Returns
+    /// - `Ok((Vec<WebhookDelivery>, u64))` - The deliveries of the page & total delivery count
+    /// - `Err(AppError::Database)` - Database error during fetch operation
+    pub async fn list_by_webhook(
+        &self,
+        webhook_id: i32,
+        page: u64,
+        per_page: u64,
+    ) -> Result<(Vec<WebhookDelivery>, u64), AppError> {
+        let paginator = entity::prelude::WebhookDelivery::find()
+            .filter(entity::webhook_delivery::Column::WebhookId.eq(webhook_id))
+            .order_by_desc(entity::webhook_delivery::Column::CreatedAt)
+            .order_by_desc(entity::webhook_delivery::Column::Id)
+            .paginate(self.db, per_page);
+
+        let total = paginator.num_items().await?;
+        let entities = paginator.fetch_page(page).await?;
+
+        let deliveries = entities
+            .into_iter()
+            .map(WebhookDelivery::from_entity)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok((deliveries, total))
+    }
+
+    /// Gets pending deliveries which are due for an attempt along with their webhook
+    ///
+    /// # Arguments
+    /// - `now` - Current time, deliveries with a next attempt at or before it are due
+    /// - `limit` - Maximum number of deliveries to return
+    ///
+    /// # Returns
+    /// - `Ok(Vec<(WebhookDelivery, Webhook)>)` - Due deliveries, oldest first
+    /// - `Err(AppError::Database)` - Database error during fetch operation
+    pub async fn get_due(
+        &self,
+        now: DateTime<Utc>,
+        limit: u64,
+    ) -> Result<Vec<(WebhookDelivery, Webhook)>, AppError> {
+        let results = entity::prelude::WebhookDelivery::find()
+            .find_also_related(entity::prelude::Webhook)
+            .filter(
+                entity::webhook_delivery::Column::Status
+                    .eq(WebhookDeliveryStatus::Pending.as_str()),
+            )
+            .filter(entity::webhook_delivery::Column::NextAttemptAt.lte(now))
+            .order_by_asc(entity::webhook_delivery::Column::NextAttemptAt)
+            .limit(limit)
+            .all(self.db)
+            .await?;
+
+        results
+            .into_iter()
+            .filter_map(|(delivery, webhook)| webhook.map(|webhook| (delivery, webhook)))
+            .map(|(delivery, webhook)| {
+                Ok((
+                    WebhookDelivery::from_entity(delivery)?,
+                    Webhook::from_entity(webhook)?,
+                ))
+            })
+            .collect()
+    }
+
+    /// Claims a due pending delivery for an attempt
+    ///
+    /// Pushes the next attempt of the delivery forward to the end of the lease in a single
+    /// conditional statement, so overlapping scheduler runs which fetched the same due
+    /// delivery only attempt it once. The lease expiring lets a delivery whose attempt
+    /// was interrupted be retried.
+    ///
+    /// # Arguments
+    /// - `id` - ID of the delivery to claim
+    /// - `now` - Current time, the delivery must be due at or before it
+    /// - `lease_until` - Time the delivery becomes due again if its attempt is never recorded
+    ///
+    /// # Returns
+    /// - `Ok(true)` - The delivery was claimed for an attempt
+    /// - `Ok(false)` - The delivery is no longer pending, not due or claimed by another run
+    /// - `Err(DbErr)` - Database error during update operation
+    pub async fn claim(
+        &self,
+        id: i32,
+        now: DateTime<Utc>,
+        lease_until: DateTime<Utc>,
+    ) -> Result<bool, DbErr> {
+        let result = entity::prelude::WebhookDelivery::update_many()
+            .col_expr(
+                entity::webhook_delivery::Column::NextAttemptAt,
+                Expr::value(lease_until),
+            )
+            .filter(entity::webhook_delivery::Column::Id.eq(id))
+            .filter(
+                entity::webhook_delivery::Column::Status
+                    .eq(WebhookDeliveryStatus::Pending.as_str()),
+            )
+            .filter(entity::webhook_delivery::Column::NextAttemptAt.lte(now))
+            .exec(self.db)
+            .await?;
+
+        Ok(result.rows_affected > 0)
+    }
+
+    /// Records the outcome of a delivery attempt
+    ///
+    /// Increments the attempt counter and stores the attempt time, status, response
+    /// and when the next attempt is due if the delivery will be retried.
+    ///
+    /// # Arguments
+    /// - `id` - ID of the delivery that was attempted
+    /// - `param` - Outcome of the attempt
+    ///
+    /// # Returns
+    /// - `Ok(WebhookDelivery)` - The updated delivery
+    /// - `Err(AppError::NotFound)` - Delivery no longer exists
+    /// - `Err(AppError::Database)` - Database error during update operation
+    pub async fn record_attempt(
+        &self,
+        id: i32,
+        param: WebhookDeliveryAttemptParam,
+    ) -> Result<WebhookDelivery, AppError> {
+        let Some(entity) = entity::prelude::WebhookDelivery::find_by_id(id)
+            .one(self.db)
+            .await?
+        else {
+            return Err(AppError::NotFound(format!(
+                "Failed to find webhook delivery ID {} while recording attempt",
+                id
+            )));
+        };
+
+        let attempts = entity.attempts + 1;
+        let mut active_model = entity.into_active_model();
+
+        active_model.status = ActiveValue::Set(param.status.as_str().to_string());
+        active_model.attempts = ActiveValue::Set(attempts);
+        active_model.response_status = ActiveValue::Set(param.response_status);
+        active_model.error = ActiveValue::Set(param.error);
+        active_model.next_attempt_at = ActiveValue::Set(param.next_attempt_at);
+        active_model.last_attempt_at = ActiveValue::Set(Some(Utc::now()));
+
+        let entity = active_model.update(self.db).await?;
+
+        WebhookDelivery::from_entity(entity)
+    }
+
+    /// Requeues a delivery of a webhook so it is attempted again with a fresh retry budget
+    ///
+    /// # Arguments
+    /// - `webhook_id` - ID of the webhook the delivery belongs to
+    /// - `id` - ID of the delivery to replay
+    ///
+    /// # Returns
+    /// - `Ok(Some(WebhookDelivery))` - The requeued delivery
+    /// - `Ok(None)` - No delivery with the ID exists for the webhook
+    /// - `Err(AppError::Database)` - Database error during update operation
+    pub async fn requeue(
+        &self,
+        webhook_id: i32,
+        id: i32,
+    ) -> Result<Option<WebhookDelivery>, AppError> {
+        let Some(entity) = entity::prelude::WebhookDelivery::find()
+            .filter(entity::webhook_delivery::Column::WebhookId.eq(webhook_id))
+            .filter(entity::webhook_delivery::Column::Id.eq(id))
+            .one(self.db)
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        let mut active_model = entity.into_active_model();
+
+        active_model.status = ActiveValue::Set(WebhookDeliveryStatus::Pending.as_str().to_string());
+        active_model.attempts = ActiveValue::Set(0);
+        active_model.next_attempt_at = ActiveValue::Set(Some(Utc::now()));
+
+        let entity = active_model.update(self.db).await?;
+
+        WebhookDelivery::from_entity(entity).map(Some)
+    }
+
+    /// Counts delivered or failed deliveries created before the cutoff
+    ///
+    /// Pending deliveries are never counted as they're still awaiting an attempt.
+    ///
+    /// # Arguments
+    /// - `cutoff` - Finished deliveries created before this time are counted
+    ///
+    /// # Returns
+    /// - `Ok(u64)` - Number of finished deliveries created before the cutoff
+    /// - `Err(AppError::Database)` - Database error during count query
+    pub async fn count_finished_before(&self, cutoff: DateTime<Utc>) -> Result<u64, AppError> {
+        Ok(entity::prelude::WebhookDelivery::find()
+            .filter(
+                entity::webhook_delivery::Column::Status
+                    .ne(WebhookDeliveryStatus::Pending.as_str()),
+            )
+            .filter(entity::webhook_delivery::Column::CreatedAt.lt(cutoff))
+            .count(self.db)
+            .await?)
+    }
+
+    /// Deletes delivered or failed deliveries created before the cutoff
+    ///
+    /// # Arguments
+    /// - `cutoff` - Finished deliveries created before this time are deleted
+    ///
+    /// # Returns
+    /// - `Ok(u64)` - Number of deliveries deleted
+    /// - `Err(AppError::Database)` - Database error during delete operation
+    pub async fn delete_finished_before(&self, cutoff: DateTime<Utc>) -> Result<u64, AppError> {
+        let result = entity::prelude::WebhookDelivery::delete_many()
+            .filter(
+                entity::webhook_delivery::Column::Status
+                    .ne(WebhookDeliveryStatus::Pending.as_str()),
+            )
+            .filter(entity::webhook_delivery::Column::CreatedAt.lt(cutoff))
+            .exec(self.db)
+            .await?;
+
+        Ok(result.rows_affected)
+    }
+}
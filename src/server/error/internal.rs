@@ -51,4 +51,33 @@ pub enum InternalError {
         /// The actual invalid value found
         actual: String,
     },
+
+    /// Failure to serialize a value into JSON
+    ///
+    /// Occurs when building JSON bodies for outgoing requests such as webhook
+    /// payloads. Results in a 500 Internal Server Error with a generic message
+    /// returned to client.
+    #[error("Failed to serialize {value} to JSON: {source}")]
+    SerializeJson {
+        /// Description of the value that failed to serialize
+        value: &'static str,
+        /// The underlying serialization error
+        #[source]
+        source: serde_json::Error,
+    },
+
+    /// Malformed row in the bundled EVE static map
+    ///
+    /// Occurs when the static map files compiled into the binary don't match the
+    /// expected format, indicating a bad `eve-map import`. Prevents startup as the
+    /// map is parsed once when the server starts.
+    #[error("Invalid EVE static map data in {file} line {line}: {reason}")]
+    InvalidStaticMap {
+        /// The static map file containing the row
+        file: &'static str,
+        /// 1-based line number of the row
+        line: usize,
+        /// Why the row was rejected
+        reason: String,
+    },
 }
@@ -0,0 +1,445 @@
+//! Bundled offline copy of the EVE Online static map.
+//!
+//! The solar systems of known space (with their constellation, region & security status)
+//! and the stargates connecting them are generated from the static data export by the
+//! `eve-map import` subcommand and compiled into the binary, so no network access is
+//! needed at runtime. The map is parsed once at startup and shared through [`eve_map`].
+//!
+//! Files are plain CSV with a header row:
+//! - `eve_map/solar_systems.csv` - `solar_system_id,name,constellation,region,security`
+//! - `eve_map/stargates.csv` - `from_solar_system_id,to_solar_system_id`
+
+use std::{
+    collections::{hash_map::Entry, HashMap, VecDeque},
+    sync::LazyLock,
+};
+
+use crate::server::{error::internal::InternalError, model::solar_system::SolarSystem};
+
+/// Header of the bundled solar systems file
+pub const SOLAR_SYSTEMS_HEADER: &str = "solar_system_id,name,constellation,region,security";
+
+/// Header of the bundled stargates file
+pub const STARGATES_HEADER: &str = "from_solar_system_id,to_solar_system_id";
+
+/// Most results a solar system search returns
+pub const MAX_SEARCH_RESULTS: usize = 25;
+
+static EVE_MAP: LazyLock<EveMap> = LazyLock::new(|| {
+    EveMap::parse(
+        include_str!("eve_map/solar_systems.csv"),
+        include_str!("eve_map/stargates.csv"),
+    )
+    .expect("Failed to parse bundled EVE static map")
+});
+
+/// Returns the bundled EVE static map, parsing it on first use.
+pub fn eve_map() -> &'static EveMap {
+    &EVE_MAP
+}
+
+/// Solar systems and stargate connections of known space.
+#[derive(Debug, Default)]
+pub struct EveMap {
+    systems: Vec<SolarSystem>,
+    /// Index into `systems` by solar system ID
+    by_id: HashMap<u32, usize>,
+    /// Index into `systems` by lowercase name
+    by_name: HashMap<String, usize>,
+    /// Solar system IDs reachable through a single stargate jump, by solar system ID
+    stargates: HashMap<u32, Vec<u32>>,
+}
+
+impl EveMap {
+    /// Parses the static map from its CSV files.
+    ///
+    /// Stargates are stored in both directions regardless of whether the file lists
+    /// each connection once or twice.
+    ///
+    /// # Arguments
+    /// - `solar_systems` - Contents of `solar_systems.csv`
+    /// - `stargates` - Contents of `stargates.csv`
+    ///
+    /// # Returns
+    /// - `Ok(EveMap)` - Parsed static map
+    /// - `Err(InternalError::InvalidStaticMap)` - A row is malformed, references an unknown
+    ///   solar system or duplicates a solar system
+    pub fn parse(solar_systems: &str, stargates: &str) -> Result<Self, InternalError> {
+        let mut map = Self::default();
+
+        for (line, row) in data_rows(solar_systems, SOLAR_SYSTEMS_HEADER, "solar_systems.csv")? {
+            let invalid = |reason: String| InternalError::InvalidStaticMap {
+                file: "solar_systems.csv",
+                line,
+                reason,
+            };
+
+            let columns: Vec<&str> = row.split(',').collect();
+            let [id, name, constellation, region, security] = columns[..] else {
+                return Err(invalid(format!(
+                    "expected 5 columns, got {}",
+                    columns.len()
+                )));
+            };
+            let id = id
+                .parse::<u32>()
+                .map_err(|e| invalid(format!("invalid solar system ID '{}': {}", id, e)))?;
+            let security = security
+                .parse::<f64>()
+                .map_err(|e| invalid(format!("invalid security '{}': {}", security, e)))?;
+
+            let index = map.systems.len();
+            if map.by_id.insert(id, index).is_some() {
+                return Err(invalid(format!("duplicate solar system ID {}", id)));
+            }
+            if map.by_name.insert(name.to_lowercase(), index).is_some() {
+                return Err(invalid(format!("duplicate solar system name {}", name)));
+            }
+
+            map.systems.push(SolarSystem {
+                id,
+                name: name.to_string(),
+                constellation: constellation.to_string(),
+                region: region.to_string(),
+                security,
+            });
+        }
+
+        for (line, row) in data_rows(stargates, STARGATES_HEADER, "stargates.csv")? {
+            let invalid = |reason: String| InternalError::InvalidStaticMap {
+                file: "stargates.csv",
+                line,
+                reason,
+            };
+
+            let Some((from, to)) = row.split_once(',') else {
+                return Err(invalid("expected 2 columns".to_string()));
+            };
+
+            let mut ids = [0u32; 2];
+            for (slot, value) in ids.iter_mut().zip([from, to]) {
+                let id = value
+                    .parse::<u32>()
+                    .map_err(|e| invalid(format!("invalid solar system ID '{}': {}", value, e)))?;
+                if !map.by_id.contains_key(&id) {
+                    return Err(invalid(format!("unknown solar system ID {}", id)));
+                }
+                *slot = id;
+            }
+
+            let [from, to] = ids;
+            for (a, b) in [(from, to), (to, from)] {
+                let neighbours = map.stargates.entry(a).or_default();
+                if !neighbours.contains(&b) {
+                    neighbours.push(b);
+                }
+            }
+        }
+
+        Ok(map)
+    }
+
+    /// Number of solar systems in the map
+    pub fn system_count(&self) -> usize {
+        self.systems.len()
+    }
+
+    /// Number of stargate connections in the map, counting each pair of gates once
+    pub fn stargate_count(&self) -> usize {
+        self.stargates.values().map(Vec::len).sum::<usize>() / 2
+    }
+
+    /// Finds a solar system by its ID
+    pub fn find_by_id(&self, id: u32) -> Option<&SolarSystem> {
+        self.by_id.get(&id).map(|&index| &self.systems[index])
+    }
+
+    /// Finds a solar system by its name, ignoring case & surrounding whitespace
+    pub fn find_by_name(&self, name: &str) -> Option<&SolarSystem> {
+        self.by_name
+            .get(&name.trim().to_lowercase())
+            .map(|&index| &self.systems[index])
+    }
+
+    /// Solar system IDs reachable from a solar system with a single stargate jump
+    pub fn neighbours(&self, id: u32) -> &[u32] {
+        self.stargates.get(&id).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    /// Finds the shortest stargate route between two solar systems.
+    ///
+    /// # Arguments
+    /// - `from` - Solar system ID the route starts in
+    /// - `to` - Solar system ID the route ends in
+    ///
+    /// # Returns
+    /// - `Some(Vec<&SolarSystem>)` - Systems along the route including both ends, a
+    ///   single system when `from` and `to` are the same
+    /// - `None` - Either system is unknown or no stargate route connects them
+    pub fn route(&self, from: u32, to: u32) -> Option<Vec<&SolarSystem>> {
+        self.find_by_id(from)?;
+        self.find_by_id(to)?;
+
+        let mut previous: HashMap<u32, u32> = HashMap::new();
+        let mut queue = VecDeque::from([from]);
+        previous.insert(from, from);
+
+        while let Some(current) = queue.pop_front() {
+            if current == to {
+                break;
+            }
+            for &next in self.neighbours(current) {
+                if let Entry::Vacant(entry) = previous.entry(next) {
+                    entry.insert(current);
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        if !previous.contains_key(&to) {
+            return None;
+        }
+
+        let mut route = vec![to];
+        let mut current = to;
+        while current != from {
+            current = previous[&current];
+            route.push(current);
+        }
+        route.reverse();
+
+        route.into_iter().map(|id| self.find_by_id(id)).collect()
+    }
+
+    /// Searches solar systems by name for autocompletion.
+    ///
+    /// Matching ignores case. An exact match is returned first, followed by names
+    /// starting with the query and then names containing it, each group ordered by name.
+    ///
+    /// # Arguments
+    /// - `query` - Part of a solar system name
+    /// - `limit` - Maximum number of results, capped at `MAX_SEARCH_RESULTS`
+    ///
+    /// # Returns
+    /// - `Vec<&SolarSystem>` - Matching solar systems, empty for a blank query
+    pub fn search(&self, query: &str, limit: usize) -> Vec<&SolarSystem> {
+        let query = query.trim().to_lowercase();
+        if query.is_empty() {
+            return Vec::new();
+        }
+
+        let mut matches: Vec<(u8, &SolarSystem)> = self
+            .systems
+            .iter()
+            .filter_map(|system| {
+                let name = system.name.to_lowercase();
+                let rank = if name == query {
+                    0
+                } else if name.starts_with(&query) {
+                    1
+                } else if name.contains(&query) {
+                    2
+                } else {
+                    return None;
+                };
+                Some((rank, system))
+            })
+            .collect();
+
+        matches.sort_by(|(a_rank, a), (b_rank, b)| a_rank.cmp(b_rank).then(a.name.cmp(&b.name)));
+        matches
+            .into_iter()
+            .take(limit.min(MAX_SEARCH_RESULTS))
+            .map(|(_, system)| system)
+            .collect()
+    }
+}
+
+/// Splits a static map file into its data rows after checking the header.
+///
+/// # Returns
+/// - `Ok(Vec<(usize, &str)>)` - Non-empty data rows with their 1-based line numbers
+/// - `Err(InternalError::InvalidStaticMap)` - The header doesn't match
+fn data_rows<'a>(
+    contents: &'a str,
+    header: &str,
+    file: &'static str,
+) -> Result<Vec<(usize, &'a str)>, InternalError> {
+    let mut lines = contents.lines().enumerate();
+
+    match lines.next() {
+        Some((_, first)) if first.trim() == header => {}
+        first => {
+            return Err(InternalError::InvalidStaticMap {
+                file,
+                line: 1,
+                reason: format!(
+                    "expected header '{}', got '{}'",
+                    header,
+                    first.map(|(_, line)| line).unwrap_or_default()
+                ),
+            })
+        }
+    }
+
+    Ok(lines
+        .map(|(index, line)| (index + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty())
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SYSTEMS: &str = "solar_system_id,name,constellation,region,security
+30000142,Jita,Kimotoro,The Forge,0.9459
+30000144,Perimeter,Kimotoro,The Forge,0.9072
+30004759,1DQ1-A,O-EIMK,Delve,-0.3855
+30004760,1-7KWU,O-EIMK,Delve,0.0312
+";
+
+    const STARGATES: &str = "from_solar_system_id,to_solar_system_id
+30000142,30000144
+30000144,30000142
+";
+
+    /// Tests parsing the static map files & looking up solar systems.
+    ///
+    /// Expected: Systems are found by ID & case-insensitive name, duplicate gate rows
+    /// count once and gates are usable in both directions
+    #[test]
+    fn parses_static_map() {
+        let map = EveMap::parse(SYSTEMS, STARGATES).unwrap();
+
+        assert_eq!(map.system_count(), 4);
+        assert_eq!(map.stargate_count(), 1);
+        assert_eq!(map.find_by_name(" 1dq1-a ").unwrap().id, 30004759);
+        assert_eq!(map.find_by_id(30000142).unwrap().name, "Jita");
+        assert_eq!(map.neighbours(30000144), &[30000142]);
+        assert!(map.neighbours(30004759).is_empty());
+    }
+
+    /// Tests looking up solar systems in the bundled static map.
+    ///
+    /// Guards against shipping the map without its data, which leaves every system
+    /// lookup & route empty.
+    ///
+    /// Expected: Systems are found case-insensitively with their proper name
+    #[test]
+    fn bundled_map_finds_solar_systems() {
+        let map = eve_map();
+
+        assert_eq!(map.find_by_name("1dq1-a").unwrap().name, "1DQ1-A");
+        assert_eq!(map.find_by_name("jita").unwrap().id, 30000142);
+    }
+
+    /// Tests rejecting a stargate referencing a solar system missing from the map.
+    ///
+    /// Expected: InvalidStaticMap error pointing at the offending line
+    #[test]
+    fn rejects_unknown_stargate_system() {
+        let stargates = "from_solar_system_id,to_solar_system_id\n30000142,30000999\n";
+
+        let result = EveMap::parse(SYSTEMS, stargates);
+
+        assert!(matches!(
+            result,
+            Err(InternalError::InvalidStaticMap {
+                file: "stargates.csv",
+                line: 2,
+                ..
+            })
+        ));
+    }
+
+    /// Tests solar system search ordering.
+    ///
+    /// Expected: Exact match first, then prefix matches, then substring matches
+    #[test]
+    fn ranks_search_results() {
+        let map = EveMap::parse(SYSTEMS, STARGATES).unwrap();
+
+        let names: Vec<&str> = map
+            .search("1", 10)
+            .into_iter()
+            .map(|system| system.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["1-7KWU", "1DQ1-A"]);
+
+        let names: Vec<&str> = map
+            .search("i", 10)
+            .into_iter()
+            .map(|system| system.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["Jita", "Perimeter"]);
+
+        assert!(map.search("  ", 10).is_empty());
+        assert_eq!(map.search("jita", 1)[0].name, "Jita");
+    }
+
+    /// Tests finding the shortest stargate route between two systems.
+    ///
+    /// Expected: Route lists every system from start to end along the shortest path,
+    /// unconnected or unknown systems have no route
+    #[test]
+    fn finds_shortest_route() {
+        let stargates = "from_solar_system_id,to_solar_system_id
+30000142,30000144
+30000144,30004760
+30000142,30004760
+";
+        let map = EveMap::parse(SYSTEMS, stargates).unwrap();
+
+        let names: Vec<&str> = map
+            .route(30000144, 30004760)
+            .unwrap()
+            .into_iter()
+            .map(|system| system.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["Perimeter", "1-7KWU"]);
+
+        assert_eq!(map.route(30000142, 30000142).unwrap().len(), 1);
+        assert!(map.route(30000142, 30004759).is_none());
+        assert!(map.route(30000142, 30000999).is_none());
+    }
+
+    /// Tests routing over the stargates of the bundled static map.
+    ///
+    /// Expected: Adjacent systems route directly and a long route only steps through
+    /// stargate connections from start to end
+    #[test]
+    fn bundled_map_routes_over_stargates() {
+        let map = eve_map();
+        let jita = map.find_by_name("Jita").unwrap().id;
+        let perimeter = map.find_by_name("Perimeter").unwrap().id;
+        let amarr = map.find_by_name("Amarr").unwrap().id;
+
+        let names: Vec<&str> = map
+            .route(jita, perimeter)
+            .unwrap()
+            .into_iter()
+            .map(|system| system.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["Jita", "Perimeter"]);
+
+        let route = map.route(jita, amarr).unwrap();
+        assert_eq!(route.first().unwrap().id, jita);
+        assert_eq!(route.last().unwrap().id, amarr);
+        assert!(route
+            .windows(2)
+            .all(|pair| map.neighbours(pair[0].id).contains(&pair[1].id)));
+    }
+
+    /// Tests the security status shown for low security systems close to 0.0.
+    ///
+    /// Expected: True security between 0.0 and 0.05 displays as 0.1, others round normally
+    #[test]
+    fn rounds_display_security() {
+        let map = EveMap::parse(SYSTEMS, STARGATES).unwrap();
+
+        assert_eq!(map.find_by_name("1-7KWU").unwrap().display_security(), 0.1);
+        assert_eq!(map.find_by_name("1DQ1-A").unwrap().display_security(), -0.4);
+        assert_eq!(map.find_by_name("Jita").unwrap().display_security(), 0.9);
+    }
+}
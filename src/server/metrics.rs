@@ -0,0 +1,449 @@
+//! In-process metrics exposed in the Prometheus text exposition format.
+//!
+//! Metrics are recorded into a single global registry so that code deep within services,
+//! scheduler jobs and bot event handlers can record them without threading a handle
+//! through every constructor. The registry is rendered by the `/metrics` endpoint.
+//!
+//! Collected metrics:
+//! - `http_requests_total` & `http_request_duration_seconds` - API requests by method, route & status
+//! - `discord_api_requests_total` & `discord_api_errors_total` - Discord API calls by endpoint
+//! - `notifications_sent_total` - Discord notifications posted by type
+//! - `scheduler_job_duration_seconds` - Scheduler job run durations by job
+//! - `scheduler_last_tick_timestamp_seconds` - Unix time of the most recent scheduler job run
+//! - `discord_gateway_connected` - Whether the bot's gateway connection is established
+//! - `discord_guild_sync_lag_seconds` - Time since each guild was last synced, computed per scrape
+
+use std::{
+    collections::BTreeMap,
+    fmt::Write,
+    future::Future,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        LazyLock, Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+use axum::{
+    extract::{MatchedPath, Request},
+    middleware::Next,
+    response::Response,
+};
+use chrono::{DateTime, Utc};
+
+use crate::server::model::discord::DiscordGuild;
+
+/// Upper bounds in seconds of the latency histogram buckets.
+const DURATION_BUCKETS: [f64; 11] = [
+    0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0,
+];
+
+static METRICS: LazyLock<Metrics> = LazyLock::new(Metrics::default);
+
+/// Returns the global metrics registry.
+pub fn metrics() -> &'static Metrics {
+    &METRICS
+}
+
+/// Cumulative latency histogram using `DURATION_BUCKETS`.
+#[derive(Debug, Clone, Default)]
+struct Histogram {
+    /// Observation count per bucket, not cumulative
+    buckets: [u64; DURATION_BUCKETS.len()],
+    sum: f64,
+    count: u64,
+}
+
+impl Histogram {
+    fn observe(&mut self, duration: Duration) {
+        let seconds = duration.as_secs_f64();
+        if let Some(index) = DURATION_BUCKETS.iter().position(|le| seconds <= *le) {
+            self.buckets[index] += 1;
+        }
+        self.sum += seconds;
+        self.count += 1;
+    }
+
+    fn render(&self, out: &mut String, name: &str, labels: &str) {
+        let mut cumulative = 0;
+        for (le, count) in DURATION_BUCKETS.iter().zip(self.buckets) {
+            cumulative += count;
+            let _ = writeln!(
+                out,
+                "{}_bucket{{{},le=\"{}\"}} {}",
+                name, labels, le, cumulative
+            );
+        }
+        let _ = writeln!(
+            out,
+            "{}_bucket{{{},le=\"+Inf\"}} {}",
+            name, labels, self.count
+        );
+        let _ = writeln!(out, "{}_sum{{{}}} {}", name, labels, self.sum);
+        let _ = writeln!(out, "{}_count{{{}}} {}", name, labels, self.count);
+    }
+}
+
+/// Global registry of application metrics.
+#[derive(Debug, Default)]
+pub struct Metrics {
+    /// Request counts keyed by (method, route, status)
+    http_requests: Mutex<BTreeMap<(String, String, u16), u64>>,
+    /// Request latency keyed by (method, route)
+    http_request_duration: Mutex<BTreeMap<(String, String), Histogram>>,
+    discord_api_requests: Mutex<BTreeMap<&'static str, u64>>,
+    discord_api_errors: Mutex<BTreeMap<&'static str, u64>>,
+    notifications_sent: Mutex<BTreeMap<&'static str, u64>>,
+    scheduler_job_duration: Mutex<BTreeMap<&'static str, Histogram>>,
+    scheduler_last_tick: Mutex<Option<DateTime<Utc>>>,
+    gateway_connected: AtomicBool,
+}
+
+impl Metrics {
+    /// Records a completed API request.
+    ///
+    /// # Arguments
+    /// - `method` - HTTP method of the request
+    /// - `route` - Matched route template (e.g. `/api/guilds/{guild_id}/fleets`)
+    /// - `status` - HTTP status code of the response
+    /// - `duration` - Time taken to produce the response
+    pub fn record_request(&self, method: &str, route: &str, status: u16, duration: Duration) {
+        *self
+            .http_requests
+            .lock()
+            .unwrap()
+            .entry((method.to_string(), route.to_string(), status))
+            .or_default() += 1;
+        self.http_request_duration
+            .lock()
+            .unwrap()
+            .entry((method.to_string(), route.to_string()))
+            .or_default()
+            .observe(duration);
+    }
+
+    /// Records a Discord API call and whether it failed.
+    ///
+    /// # Arguments
+    /// - `endpoint` - Name of the Discord API operation (e.g. `send_message`)
+    /// - `success` - Whether the call succeeded
+    pub fn record_discord_call(&self, endpoint: &'static str, success: bool) {
+        *self
+            .discord_api_requests
+            .lock()
+            .unwrap()
+            .entry(endpoint)
+            .or_default() += 1;
+        if !success {
+            *self
+                .discord_api_errors
+                .lock()
+                .unwrap()
+                .entry(endpoint)
+                .or_default() += 1;
+        }
+    }
+
+    /// Records a Discord notification posted to a channel.
+    ///
+    /// # Arguments
+    /// - `notification_type` - Type of notification (creation, reminder, formup, etc.)
+    pub fn record_notification(&self, notification_type: &'static str) {
+        *self
+            .notifications_sent
+            .lock()
+            .unwrap()
+            .entry(notification_type)
+            .or_default() += 1;
+    }
+
+    /// Records a scheduler job run & marks the scheduler as having ticked.
+    ///
+    /// # Arguments
+    /// - `job` - Name of the scheduler job
+    /// - `duration` - Time taken to run the job
+    pub fn record_job(&self, job: &'static str, duration: Duration) {
+        self.scheduler_job_duration
+            .lock()
+            .unwrap()
+            .entry(job)
+            .or_default()
+            .observe(duration);
+        *self.scheduler_last_tick.lock().unwrap() = Some(Utc::now());
+    }
+
+    /// Sets whether the Discord gateway connection is established.
+    pub fn set_gateway_connected(&self, connected: bool) {
+        self.gateway_connected.store(connected, Ordering::Relaxed);
+    }
+
+    /// Returns whether the Discord gateway connection is established.
+    pub fn gateway_connected(&self) -> bool {
+        self.gateway_connected.load(Ordering::Relaxed)
+    }
+
+    /// Returns when a scheduler job last finished running, if any has run yet.
+    pub fn scheduler_last_tick(&self) -> Option<DateTime<Utc>> {
+        *self.scheduler_last_tick.lock().unwrap()
+    }
+
+    /// Renders all metrics in the Prometheus text exposition format.
+    ///
+    /// # Arguments
+    /// - `guilds` - Guilds to report the sync lag of
+    /// - `now` - Time to compute the guild sync lag against
+    ///
+    /// # Returns
+    /// - `String` - Prometheus text exposition of all metrics
+    pub fn render(&self, guilds: &[DiscordGuild], now: DateTime<Utc>) -> String {
+        let mut out = String::new();
+
+        write_header(
+            &mut out,
+            "http_requests_total",
+            "counter",
+            "API requests by method, route and status",
+        );
+        for ((method, route, status), count) in self.http_requests.lock().unwrap().iter() {
+            let _ = writeln!(
+                out,
+                "http_requests_total{{method=\"{}\",route=\"{}\",status=\"{}\"}} {}",
+                method,
+                escape(route),
+                status,
+                count
+            );
+        }
+
+        write_header(
+            &mut out,
+            "http_request_duration_seconds",
+            "histogram",
+            "API request latency by method and route",
+        );
+        for ((method, route), histogram) in self.http_request_duration.lock().unwrap().iter() {
+            let labels = format!("method=\"{}\",route=\"{}\"", method, escape(route));
+            histogram.render(&mut out, "http_request_duration_seconds", &labels);
+        }
+
+        write_header(
+            &mut out,
+            "discord_api_requests_total",
+            "counter",
+            "Discord API calls by endpoint",
+        );
+        for (endpoint, count) in self.discord_api_requests.lock().unwrap().iter() {
+            let _ = writeln!(
+                out,
+                "discord_api_requests_total{{endpoint=\"{}\"}} {}",
+                endpoint, count
+            );
+        }
+
+        write_header(
+            &mut out,
+            "discord_api_errors_total",
+            "counter",
+            "Failed Discord API calls by endpoint",
+        );
+        for (endpoint, count) in self.discord_api_errors.lock().unwrap().iter() {
+            let _ = writeln!(
+                out,
+                "discord_api_errors_total{{endpoint=\"{}\"}} {}",
+                endpoint, count
+            );
+        }
+
+        write_header(
+            &mut out,
+            "notifications_sent_total",
+            "counter",
+            "Discord notifications posted by type",
+        );
+        for (notification_type, count) in self.notifications_sent.lock().unwrap().iter() {
+            let _ = writeln!(
+                out,
+                "notifications_sent_total{{type=\"{}\"}} {}",
+                notification_type, count
+            );
+        }
+
+        write_header(
+            &mut out,
+            "scheduler_job_duration_seconds",
+            "histogram",
+            "Scheduler job run duration by job",
+        );
+        for (job, histogram) in self.scheduler_job_duration.lock().unwrap().iter() {
+            let labels = format!("job=\"{}\"", job);
+            histogram.render(&mut out, "scheduler_job_duration_seconds", &labels);
+        }
+
+        write_header(
+            &mut out,
+            "scheduler_last_tick_timestamp_seconds",
+            "gauge",
+            "Unix time of the most recent scheduler job run",
+        );
+        let last_tick = self.scheduler_last_tick().map_or(0, |t| t.timestamp());
+        let _ = writeln!(out, "scheduler_last_tick_timestamp_seconds {}", last_tick);
+
+        write_header(
+            &mut out,
+            "discord_gateway_connected",
+            "gauge",
+            "Whether the Discord gateway connection is established",
+        );
+        let _ = writeln!(
+            out,
+            "discord_gateway_connected {}",
+            u8::from(self.gateway_connected())
+        );
+
+        write_header(
+            &mut out,
+            "discord_guild_sync_lag_seconds",
+            "gauge",
+            "Seconds since each guild was last synced with Discord",
+        );
+        for guild in guilds {
+            let lag = (now - guild.last_sync_at).num_seconds().max(0);
+            let _ = writeln!(
+                out,
+                "discord_guild_sync_lag_seconds{{guild_id=\"{}\"}} {}",
+                guild.guild_id, lag
+            );
+        }
+
+        out
+    }
+}
+
+fn write_header(out: &mut String, name: &str, kind: &str, help: &str) {
+    let _ = writeln!(out, "# HELP {} {}", name, help);
+    let _ = writeln!(out, "# TYPE {} {}", name, kind);
+}
+
+/// Escapes a Prometheus label value.
+fn escape(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+/// Middleware recording the latency & status of every API request by matched route.
+///
+/// Uses the route template rather than the request path so that IDs in the path don't
+/// create a separate time series per resource.
+pub async fn track_request(request: Request, next: Next) -> Response {
+    let method = request.method().to_string();
+    let route = request
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|path| path.as_str().to_string())
+        .unwrap_or_else(|| "unmatched".to_string());
+
+    let start = Instant::now();
+    let response = next.run(request).await;
+
+    metrics().record_request(&method, &route, response.status().as_u16(), start.elapsed());
+
+    response
+}
+
+/// Awaits a Discord API call, recording it by endpoint and whether it failed.
+///
+/// # Arguments
+/// - `endpoint` - Name of the Discord API operation (e.g. `send_message`)
+/// - `call` - Future performing the Discord API call
+///
+/// # Returns
+/// - The result of the call, unchanged
+pub async fn discord_call<T, E>(
+    endpoint: &'static str,
+    call: impl Future<Output = Result<T, E>>,
+) -> Result<T, E> {
+    let result = call.await;
+    metrics().record_discord_call(endpoint, result.is_ok());
+    result
+}
+
+/// Runs a scheduler job, recording its duration and the scheduler tick.
+///
+/// # Arguments
+/// - `job` - Name of the scheduler job
+/// - `run` - Future running the job to completion
+pub async fn track_job(job: &'static str, run: impl Future<Output = ()>) {
+    let start = Instant::now();
+    run.await;
+    metrics().record_job(job, start.elapsed());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Tests rendering recorded metrics in the Prometheus text format.
+    ///
+    /// Expected: Counters, cumulative histogram buckets and guild sync lag are rendered
+    #[test]
+    fn renders_recorded_metrics() {
+        let metrics = Metrics::default();
+        metrics.record_request(
+            "GET",
+            "/api/guilds/{guild_id}/fleets",
+            200,
+            Duration::from_millis(20),
+        );
+        metrics.record_request(
+            "GET",
+            "/api/guilds/{guild_id}/fleets",
+            200,
+            Duration::from_millis(300),
+        );
+        metrics.record_discord_call("send_message", true);
+        metrics.record_discord_call("send_message", false);
+        metrics.record_notification("formup");
+        metrics.set_gateway_connected(true);
+
+        let now = Utc::now();
+        let guild = DiscordGuild {
+            guild_id: 42,
+            name: "Guild".to_string(),
+            icon_hash: None,
+            last_sync_at: now - chrono::Duration::seconds(90),
+            status: crate::model::discord::DiscordGuildStatus::Active,
+            removed_at: None,
+        };
+        let output = metrics.render(&[guild], now);
+
+        assert!(output.contains(
+            "http_requests_total{method=\"GET\",route=\"/api/guilds/{guild_id}/fleets\",status=\"200\"} 2"
+        ));
+        assert!(output.contains(
+            "http_request_duration_seconds_bucket{method=\"GET\",route=\"/api/guilds/{guild_id}/fleets\",le=\"0.025\"} 1"
+        ));
+        assert!(output.contains(
+            "http_request_duration_seconds_bucket{method=\"GET\",route=\"/api/guilds/{guild_id}/fleets\",le=\"+Inf\"} 2"
+        ));
+        assert!(output.contains("discord_api_requests_total{endpoint=\"send_message\"} 2"));
+        assert!(output.contains("discord_api_errors_total{endpoint=\"send_message\"} 1"));
+        assert!(output.contains("notifications_sent_total{type=\"formup\"} 1"));
+        assert!(output.contains("discord_gateway_connected 1"));
+        assert!(output.contains("discord_guild_sync_lag_seconds{guild_id=\"42\"} 90"));
+    }
+
+    /// Tests that scheduler job runs update the last tick.
+    ///
+    /// Expected: No tick before a job runs, a recent tick afterwards
+    #[tokio::test]
+    async fn track_job_records_scheduler_tick() {
+        assert!(Metrics::default().scheduler_last_tick().is_none());
+
+        track_job("test", async {}).await;
+
+        let last_tick = metrics().scheduler_last_tick().unwrap();
+        assert!(Utc::now() - last_tick < chrono::Duration::seconds(5));
+    }
+}
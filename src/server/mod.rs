@@ -20,10 +20,14 @@
 //!
 //! Supporting modules provide application infrastructure:
 //!
+//! - **CLI** (`cli/`) - Administrative subcommands of the server binary (migrations, admins, etc.)
 //! - **Configuration** (`config`) - Environment-based application configuration
 //! - **State** (`state`) - Shared application state (DB, HTTP clients, etc.)
 //! - **Startup** (`startup`) - Initialization of database, sessions, and services
 //! - **Router** (`router`) - Axum route configuration and API documentation
+//! - **EVE Map** (`eve_map`) - Bundled static map of solar systems & stargates
+//! - **Metrics** (`metrics`) - Prometheus metrics registry and request tracking middleware
+//! - **Session Store** (`session_store`) - Session storage for the SQLite or PostgreSQL backend
 //! - **Scheduler** (`scheduler/`) - Cron jobs for automated tasks (fleet notifications, etc.)
 //! - **Bot** (`bot/`) - Discord bot event handlers and integration
 //!
@@ -44,15 +48,19 @@
 //! This module is only available with the `server` feature flag enabled.
 
 pub mod bot;
+pub mod cli;
 pub mod config;
 pub mod controller;
 pub mod data;
 pub mod error;
+pub mod eve_map;
+pub mod metrics;
 pub mod middleware;
 pub mod model;
 pub mod router;
 pub mod scheduler;
 pub mod service;
+pub mod session_store;
 pub mod startup;
 pub mod state;
 pub mod util;
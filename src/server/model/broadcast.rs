@@ -0,0 +1,212 @@
+//! Domain & parameter models for ad-hoc broadcasts
+//!
+//! A broadcast posts an embed with a title & message to chosen channels of a guild's
+//! fleet categories, pinging chosen roles of those categories, without a scheduled
+//! fleet. Broadcasts are posted immediately or once their scheduled time is due and are
+//! kept as a history log of the guild.
+
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+
+use crate::{
+    model::broadcast::{BroadcastChannelDto, BroadcastDto, BroadcastRoleDto},
+    server::{error::AppError, util::parse::parse_u64_from_string},
+};
+
+/// Ad-hoc broadcast to channels of a guild
+#[derive(Debug, Clone, PartialEq)]
+pub struct Broadcast {
+    pub id: i32,
+    pub guild_id: u64,
+    /// Discord ID of the user who composed the broadcast, None once the user was removed
+    pub author_id: Option<u64>,
+    pub title: String,
+    pub message: String,
+    /// Discord IDs of the channels the broadcast is posted to
+    pub channel_ids: Vec<u64>,
+    /// Discord IDs of the roles pinged by the broadcast
+    pub role_ids: Vec<u64>,
+    /// When the broadcast is due, None for broadcasts posted immediately
+    pub scheduled_at: Option<DateTime<Utc>>,
+    /// When the broadcast was posted, None while it is pending
+    pub sent_at: Option<DateTime<Utc>>,
+    /// When the scheduled broadcast was cancelled before being posted
+    pub cancelled_at: Option<DateTime<Utc>>,
+    /// Amount of channels the broadcast was posted to
+    pub posted_count: i32,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Broadcast {
+    /// Converts an entity model to the broadcast domain model
+    ///
+    /// # Arguments
+    /// - `entity` - The entity model from the database
+    ///
+    /// # Returns
+    /// - `Ok(Broadcast)` - The converted broadcast
+    /// - `Err(AppError::InternalError(ParseStringId))` - Failed to parse a Discord ID to u64
+    pub fn from_entity(entity: entity::broadcast::Model) -> Result<Self, AppError> {
+        let author_id = match entity.author_id {
+            Some(author_id) => Some(parse_u64_from_string(author_id)?),
+            None => None,
+        };
+
+        Ok(Self {
+            id: entity.id,
+            guild_id: parse_u64_from_string(entity.guild_id)?,
+            author_id,
+            title: entity.title,
+            message: entity.message,
+            channel_ids: parse_id_list(&entity.channel_ids)?,
+            role_ids: parse_id_list(&entity.role_ids)?,
+            scheduled_at: entity.scheduled_at,
+            sent_at: entity.sent_at,
+            cancelled_at: entity.cancelled_at,
+            posted_count: entity.posted_count,
+            created_at: entity.created_at,
+        })
+    }
+
+    /// Whether the broadcast is still waiting to be posted
+    pub fn is_pending(&self) -> bool {
+        self.sent_at.is_none() && self.cancelled_at.is_none()
+    }
+
+    /// Converts the broadcast to a DTO
+    ///
+    /// Channels & roles which were removed from the guild since are shown by their ID.
+    ///
+    /// # Arguments
+    /// - `channels` - Synced channels of the guild by channel ID
+    /// - `roles` - Synced roles of the guild by role ID
+    pub fn into_dto(
+        self,
+        channels: &HashMap<u64, BroadcastChannel>,
+        roles: &HashMap<u64, BroadcastRole>,
+    ) -> BroadcastDto {
+        BroadcastDto {
+            id: self.id,
+            title: self.title,
+            message: self.message,
+            channels: self
+                .channel_ids
+                .iter()
+                .map(|id| {
+                    channels
+                        .get(id)
+                        .cloned()
+                        .unwrap_or_else(|| BroadcastChannel {
+                            channel_id: *id,
+                            name: format!("Unknown Channel ({})", id),
+                        })
+                        .into_dto()
+                })
+                .collect(),
+            roles: self
+                .role_ids
+                .iter()
+                .map(|id| {
+                    roles
+                        .get(id)
+                        .cloned()
+                        .unwrap_or_else(|| BroadcastRole {
+                            role_id: *id,
+                            name: format!("Unknown Role ({})", id),
+                            color: "#99aab5".to_string(),
+                        })
+                        .into_dto()
+                })
+                .collect(),
+            author_id: self.author_id,
+            scheduled_at: self.scheduled_at,
+            sent_at: self.sent_at,
+            cancelled_at: self.cancelled_at,
+            posted_count: self.posted_count,
+            created_at: self.created_at,
+        }
+    }
+}
+
+/// Channel configured for a fleet category which a broadcast can be posted to
+#[derive(Debug, Clone, PartialEq)]
+pub struct BroadcastChannel {
+    pub channel_id: u64,
+    pub name: String,
+}
+
+impl BroadcastChannel {
+    pub fn into_dto(self) -> BroadcastChannelDto {
+        BroadcastChannelDto {
+            channel_id: self.channel_id,
+            name: self.name,
+        }
+    }
+}
+
+/// Ping role of a fleet category which a broadcast can ping
+#[derive(Debug, Clone, PartialEq)]
+pub struct BroadcastRole {
+    pub role_id: u64,
+    pub name: String,
+    /// Role color in hex format (e.g., "#FF5733")
+    pub color: String,
+}
+
+impl BroadcastRole {
+    pub fn into_dto(self) -> BroadcastRoleDto {
+        BroadcastRoleDto {
+            role_id: self.role_id,
+            name: self.name,
+            color: self.color,
+        }
+    }
+}
+
+/// Channels & ping roles of a set of fleet categories a broadcast can target
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BroadcastTargets {
+    /// Channels ordered by their position in the guild
+    pub channels: Vec<BroadcastChannel>,
+    /// Ping roles ordered by their position in the guild, highest first
+    pub roles: Vec<BroadcastRole>,
+}
+
+impl BroadcastTargets {
+    /// Whether every given channel is a target
+    pub fn has_channels(&self, channel_ids: &[u64]) -> bool {
+        channel_ids
+            .iter()
+            .all(|id| self.channels.iter().any(|c| c.channel_id == *id))
+    }
+
+    /// Whether every given role is a target
+    pub fn has_roles(&self, role_ids: &[u64]) -> bool {
+        role_ids
+            .iter()
+            .all(|id| self.roles.iter().any(|r| r.role_id == *id))
+    }
+}
+
+/// Parameters for recording a new broadcast
+#[derive(Debug, Clone)]
+pub struct CreateBroadcastParam {
+    pub guild_id: u64,
+    /// Discord ID of the user composing the broadcast
+    pub author_id: u64,
+    pub title: String,
+    pub message: String,
+    pub channel_ids: Vec<u64>,
+    pub role_ids: Vec<u64>,
+    /// When the broadcast is due, None to post it immediately
+    pub scheduled_at: Option<DateTime<Utc>>,
+}
+
+/// Parses a comma separated list of Discord IDs as stored in the database
+fn parse_id_list(value: &str) -> Result<Vec<u64>, AppError> {
+    value
+        .split(',')
+        .filter(|id| !id.is_empty())
+        .map(|id| parse_u64_from_string(id.to_string()))
+        .collect()
+}
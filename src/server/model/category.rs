@@ -7,6 +7,11 @@
 use chrono::Duration;
 use sea_orm::DbErr;
 
+use crate::{
+    model::discord::DiscordChannelKind,
+    server::{error::AppError, eve_map::eve_map, model::solar_system::resolve_staging_system_id},
+};
+
 /// Access role permissions without display properties.
 ///
 /// Contains only permission flags for a role. Used when display information
@@ -21,6 +26,8 @@ pub struct AccessRoleData {
     pub can_create: bool,
     /// Whether the role can manage fleets in this category.
     pub can_manage: bool,
+    /// Whether the role can broadcast to this category's channels.
+    pub can_broadcast: bool,
 }
 
 /// Access role with permissions and display properties.
@@ -43,6 +50,8 @@ pub struct AccessRole {
     pub can_create: bool,
     /// Whether the role can manage fleets in this category.
     pub can_manage: bool,
+    /// Whether the role can broadcast to this category's channels.
+    pub can_broadcast: bool,
 }
 
 impl AccessRole {
@@ -81,6 +90,7 @@ impl AccessRole {
             can_view: entity.can_view,
             can_create: entity.can_create,
             can_manage: entity.can_manage,
+            can_broadcast: entity.can_broadcast,
         })
     }
 
@@ -97,6 +107,7 @@ impl AccessRole {
             can_view: self.can_view,
             can_create: self.can_create,
             can_manage: self.can_manage,
+            can_broadcast: self.can_broadcast,
         }
     }
 }
@@ -111,6 +122,31 @@ impl From<crate::model::category::FleetCategoryAccessRoleDto> for AccessRoleData
             can_view: dto.can_view,
             can_create: dto.can_create,
             can_manage: dto.can_manage,
+            can_broadcast: dto.can_broadcast,
+        }
+    }
+}
+
+/// Channel association without display properties.
+///
+/// Contains only the channel ID and posting options. Used when creating or updating
+/// a category's channels.
+#[derive(Debug, Clone)]
+pub struct ChannelData {
+    /// Discord channel ID as a u64.
+    pub channel_id: u64,
+    /// Whether messages sent to an announcement channel are crossposted.
+    pub crosspost: bool,
+}
+
+impl From<crate::model::category::FleetCategoryChannelDto> for ChannelData {
+    /// Converts a DTO to channel data for service layer operations.
+    ///
+    /// Extracts only the channel ID and posting options, discarding display properties.
+    fn from(dto: crate::model::category::FleetCategoryChannelDto) -> Self {
+        Self {
+            channel_id: dto.channel_id,
+            crosspost: dto.crosspost,
         }
     }
 }
@@ -193,6 +229,10 @@ pub struct Channel {
     pub channel_name: String,
     /// Channel position in guild's channel list.
     pub position: i32,
+    /// Channel type, text when the guild channel is unavailable.
+    pub kind: DiscordChannelKind,
+    /// Whether messages sent to an announcement channel are crossposted.
+    pub crosspost: bool,
 }
 
 impl Channel {
@@ -224,6 +264,11 @@ impl Channel {
                 .map(|ch| ch.name.clone())
                 .unwrap_or_else(|| format!("Unknown Channel ({})", channel_id)),
             position: channel_model.as_ref().map(|ch| ch.position).unwrap_or(0),
+            kind: channel_model
+                .as_ref()
+                .and_then(|ch| DiscordChannelKind::parse(&ch.kind))
+                .unwrap_or_default(),
+            crosspost: entity.crosspost,
         })
     }
 
@@ -236,6 +281,8 @@ impl Channel {
             channel_id: self.channel_id,
             channel_name: self.channel_name.clone(),
             position: self.position,
+            kind: self.kind,
+            crosspost: self.crosspost,
         }
     }
 }
@@ -253,14 +300,23 @@ pub struct CreateFleetCategoryParams {
     pub ping_lead_time: Option<Duration>,
     pub ping_reminder: Option<Duration>,
     pub max_pre_ping: Option<Duration>,
+    pub staging_system_id: Option<u32>,
     pub access_roles: Vec<AccessRoleData>,
     pub ping_roles: Vec<u64>,
-    pub channels: Vec<u64>,
+    pub channels: Vec<ChannelData>,
 }
 
 impl CreateFleetCategoryParams {
-    pub fn from_dto(guild_id: u64, dto: crate::model::category::CreateFleetCategoryDto) -> Self {
-        Self {
+    /// Converts the create DTO to parameters, resolving the staging system by name.
+    ///
+    /// # Returns
+    /// - `Ok(CreateFleetCategoryParams)` - The converted parameters
+    /// - `Err(AppError::BadRequest(_))` - The staging system isn't a known solar system
+    pub fn from_dto(
+        guild_id: u64,
+        dto: crate::model::category::CreateFleetCategoryDto,
+    ) -> Result<Self, AppError> {
+        Ok(Self {
             guild_id,
             ping_format_id: dto.ping_format_id,
             name: dto.name,
@@ -268,10 +324,11 @@ impl CreateFleetCategoryParams {
             ping_lead_time: dto.ping_lead_time,
             ping_reminder: dto.ping_reminder,
             max_pre_ping: dto.max_pre_ping,
+            staging_system_id: resolve_staging_system_id(dto.staging_system.as_deref())?,
             access_roles: dto.access_roles.into_iter().map(Into::into).collect(),
             ping_roles: dto.ping_roles.into_iter().map(|pr| pr.role_id).collect(),
-            channels: dto.channels.into_iter().map(|c| c.channel_id).collect(),
-        }
+            channels: dto.channels.into_iter().map(Into::into).collect(),
+        })
     }
 }
 
@@ -289,18 +346,24 @@ pub struct UpdateFleetCategoryParams {
     pub ping_lead_time: Option<Duration>,
     pub ping_reminder: Option<Duration>,
     pub max_pre_ping: Option<Duration>,
+    pub staging_system_id: Option<u32>,
     pub access_roles: Vec<AccessRoleData>,
     pub ping_roles: Vec<u64>,
-    pub channels: Vec<u64>,
+    pub channels: Vec<ChannelData>,
 }
 
 impl UpdateFleetCategoryParams {
+    /// Converts the update DTO to parameters, resolving the staging system by name.
+    ///
+    /// # Returns
+    /// - `Ok(UpdateFleetCategoryParams)` - The converted parameters
+    /// - `Err(AppError::BadRequest(_))` - The staging system isn't a known solar system
     pub fn from_dto(
         id: i32,
         guild_id: u64,
         dto: crate::model::category::UpdateFleetCategoryDto,
-    ) -> Self {
-        Self {
+    ) -> Result<Self, AppError> {
+        Ok(Self {
             id,
             guild_id,
             ping_format_id: dto.ping_format_id,
@@ -309,10 +372,11 @@ impl UpdateFleetCategoryParams {
             ping_lead_time: dto.ping_lead_time,
             ping_reminder: dto.ping_reminder,
             max_pre_ping: dto.max_pre_ping,
+            staging_system_id: resolve_staging_system_id(dto.staging_system.as_deref())?,
             access_roles: dto.access_roles.into_iter().map(Into::into).collect(),
             ping_roles: dto.ping_roles.into_iter().map(|pr| pr.role_id).collect(),
-            channels: dto.channels.into_iter().map(|c| c.channel_id).collect(),
-        }
+            channels: dto.channels.into_iter().map(Into::into).collect(),
+        })
     }
 }
 
@@ -386,6 +450,8 @@ pub struct FleetCategory {
     pub ping_lead_time: Option<Duration>,
     pub ping_reminder: Option<Duration>,
     pub max_pre_ping: Option<Duration>,
+    /// Solar system ID overriding the guild's staging system
+    pub staging_system_id: Option<u32>,
     pub access_roles: Vec<AccessRole>,
     pub ping_roles: Vec<PingRole>,
     pub channels: Vec<Channel>,
@@ -450,6 +516,7 @@ impl FleetCategory {
                 .category
                 .max_pre_ping
                 .map(|s| Duration::seconds(s as i64)),
+            staging_system_id: data.category.staging_system_id.map(|id| id as u32),
             access_roles: access_roles?,
             ping_roles: ping_roles?,
             channels: channels?,
@@ -471,6 +538,10 @@ impl FleetCategory {
             ping_lead_time: self.ping_lead_time,
             ping_reminder: self.ping_reminder,
             max_pre_ping: self.max_pre_ping,
+            staging_system: self
+                .staging_system_id
+                .and_then(|id| eve_map().find_by_id(id))
+                .map(|system| system.name.clone()),
             access_roles: self
                 .access_roles
                 .into_iter()
@@ -18,6 +18,8 @@ pub struct ChannelFleetList {
     pub channel_id: u64,
     /// Discord message ID of the fleet list message (stored as String).
     pub message_id: u64,
+    /// Discord message IDs of the messages a long list is continued in, in order.
+    pub overflow_message_ids: Vec<u64>,
     /// Timestamp of the last message posted in the channel (for edit vs. repost decision).
     pub last_message_at: DateTime<Utc>,
     /// Timestamp when the record was created.
@@ -27,6 +29,13 @@ pub struct ChannelFleetList {
 }
 
 impl ChannelFleetList {
+    /// Returns the IDs of all messages of the list, starting with the first message
+    pub fn message_ids(&self) -> Vec<u64> {
+        std::iter::once(self.message_id)
+            .chain(self.overflow_message_ids.iter().copied())
+            .collect()
+    }
+
     /// Converts an entity model to a channel fleet list domain model at the repository boundary.
     ///
     /// # Arguments
@@ -39,11 +48,18 @@ impl ChannelFleetList {
     pub fn from_entity(entity: entity::channel_fleet_list::Model) -> Result<Self, AppError> {
         let channel_id = parse_u64_from_string(entity.channel_id)?;
         let message_id = parse_u64_from_string(entity.message_id)?;
+        let overflow_message_ids = entity
+            .overflow_message_ids
+            .split(',')
+            .filter(|id| !id.is_empty())
+            .map(|id| parse_u64_from_string(id.to_string()))
+            .collect::<Result<Vec<_>, _>>()?;
 
         Ok(Self {
             id: entity.id,
             channel_id: channel_id,
             message_id: message_id,
+            overflow_message_ids,
             last_message_at: entity.last_message_at,
             created_at: entity.created_at,
             updated_at: entity.updated_at,
@@ -61,4 +77,6 @@ pub struct UpsertChannelFleetListParam {
     pub channel_id: u64,
     /// Discord message ID of the fleet list message.
     pub message_id: u64,
+    /// Discord message IDs of the messages a long list is continued in, in order.
+    pub overflow_message_ids: Vec<u64>,
 }
@@ -0,0 +1,87 @@
+//! Channel list settings domain models.
+//!
+//! Defines how the upcoming fleets list of a channel is built. Channels without stored
+//! settings list every upcoming fleet within the guild's lookahead in one flat list.
+
+use crate::{
+    model::channel_list_settings::UpdateChannelListSettingsDto,
+    server::{error::AppError, util::parse::parse_u64_from_string},
+};
+
+/// Most fleets an upcoming fleets list can be limited to
+pub const MAX_LIST_ENTRIES: i32 = 200;
+
+/// Upcoming fleets list settings of a channel
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChannelListSettings {
+    pub channel_id: u64,
+    /// Only list fleets starting within this many hours, None uses the guild's lookahead
+    pub lookahead_hours: Option<i32>,
+    /// Most fleets listed, None lists every fleet within the lookahead
+    pub max_entries: Option<i32>,
+    /// Group fleets under a header per day in EVE time
+    pub group_by_day: bool,
+    /// List the fleets of each category in a separate section
+    pub split_by_category: bool,
+    /// List hidden fleets once their reminder revealed them in the channel
+    pub show_revealed_hidden: bool,
+}
+
+impl ChannelListSettings {
+    /// Returns the default settings for a channel without stored settings
+    pub fn default_for(channel_id: u64) -> Self {
+        let defaults = UpdateChannelListSettingsDto::default();
+
+        Self {
+            channel_id,
+            lookahead_hours: defaults.lookahead_hours,
+            max_entries: defaults.max_entries,
+            group_by_day: defaults.group_by_day,
+            split_by_category: defaults.split_by_category,
+            show_revealed_hidden: defaults.show_revealed_hidden,
+        }
+    }
+
+    /// Converts an entity model to the channel list settings domain model
+    ///
+    /// # Arguments
+    /// - `entity` - The entity model from the database
+    ///
+    /// # Returns
+    /// - `Ok(ChannelListSettings)` - The converted domain model
+    /// - `Err(AppError::InternalError(ParseStringId))` - Failed to parse channel ID to u64
+    pub fn from_entity(entity: entity::channel_list_settings::Model) -> Result<Self, AppError> {
+        Ok(Self {
+            channel_id: parse_u64_from_string(entity.channel_id)?,
+            lookahead_hours: entity.lookahead_hours,
+            max_entries: entity.max_entries,
+            group_by_day: entity.group_by_day,
+            split_by_category: entity.split_by_category,
+            show_revealed_hidden: entity.show_revealed_hidden,
+        })
+    }
+}
+
+/// Parameters for storing the upcoming fleets list settings of a channel
+#[derive(Debug, Clone)]
+pub struct UpsertChannelListSettingsParam {
+    pub channel_id: u64,
+    pub lookahead_hours: Option<i32>,
+    pub max_entries: Option<i32>,
+    pub group_by_day: bool,
+    pub split_by_category: bool,
+    pub show_revealed_hidden: bool,
+}
+
+impl UpsertChannelListSettingsParam {
+    pub fn from_dto(channel_id: u64, dto: UpdateChannelListSettingsDto) -> Self {
+        Self {
+            channel_id,
+            lookahead_hours: dto.lookahead_hours,
+            max_entries: dto.max_entries,
+            group_by_day: dto.group_by_day,
+            split_by_category: dto.split_by_category,
+            show_revealed_hidden: dto.show_revealed_hidden,
+        }
+    }
+}
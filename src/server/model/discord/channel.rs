@@ -1,11 +1,30 @@
 //! Discord guild channel domain models.
 //!
 //! Provides the domain model for Discord channels within guilds, tracking channel
-//! identity, display properties, and position for ordering. Handles conversion between
-//! entity models from the database and domain models used in the service layer.
+//! identity, display properties, position for ordering, and the channel type which
+//! determines how fleet notifications are posted. Handles conversion between entity
+//! models from the database and domain models used in the service layer.
 
-use crate::model::discord::DiscordGuildChannelDto;
+use crate::model::discord::{DiscordChannelKind, DiscordGuildChannelDto};
 use sea_orm::DbErr;
+use serenity::all::ChannelType;
+
+/// Maps a Discord channel type to a supported notification channel kind.
+///
+/// # Arguments
+/// - `kind` - Channel type reported by Discord
+///
+/// # Returns
+/// - `Some(DiscordChannelKind)` - Text, announcement or forum channel
+/// - `None` - Channel type can't receive fleet notifications (voice, category, etc.)
+pub fn channel_kind(kind: ChannelType) -> Option<DiscordChannelKind> {
+    match kind {
+        ChannelType::Text => Some(DiscordChannelKind::Text),
+        ChannelType::News => Some(DiscordChannelKind::Announcement),
+        ChannelType::Forum => Some(DiscordChannelKind::Forum),
+        _ => None,
+    }
+}
 
 /// Discord channel within a guild with display properties and hierarchy position.
 ///
@@ -21,6 +40,8 @@ pub struct DiscordGuildChannel {
     pub name: String,
     /// Channel position in the guild's channel list (for display ordering).
     pub position: i32,
+    /// Channel type determining how fleet notifications are posted.
+    pub kind: DiscordChannelKind,
 }
 
 impl DiscordGuildChannel {
@@ -33,7 +54,7 @@ impl DiscordGuildChannel {
     ///
     /// # Returns
     /// - `Ok(DiscordGuildChannel)` - Successfully converted domain model
-    /// - `Err(DbErr::Custom)` - Failed to parse channel_id or guild_id as u64
+    /// - `Err(DbErr::Custom)` - Failed to parse channel_id or guild_id as u64, or unknown kind
     pub fn from_entity(entity: entity::discord_guild_channel::Model) -> Result<Self, DbErr> {
         let channel_id = entity
             .channel_id
@@ -45,11 +66,15 @@ impl DiscordGuildChannel {
             .parse::<u64>()
             .map_err(|e| DbErr::Custom(format!("Failed to parse guild_id: {}", e)))?;
 
+        let kind = DiscordChannelKind::parse(&entity.kind)
+            .ok_or_else(|| DbErr::Custom(format!("Unknown channel kind: {}", entity.kind)))?;
+
         Ok(Self {
             channel_id,
             guild_id,
             name: entity.name,
             position: entity.position,
+            kind,
         })
     }
 
@@ -63,6 +88,7 @@ impl DiscordGuildChannel {
             channel_id: self.channel_id,
             name: self.name,
             position: self.position,
+            kind: self.kind,
         }
     }
 }
@@ -8,12 +8,12 @@ use chrono::{DateTime, Utc};
 use sea_orm::DbErr;
 use serenity::all::{Guild, PartialGuild};
 
-use crate::model::discord::DiscordGuildDto;
+use crate::model::discord::{DiscordGuildDto, DiscordGuildStatus};
 
 /// Discord server (guild) with display properties and sync metadata.
 ///
-/// Tracks guild name, icon, and when the guild's roles, channels, and members
-/// were last synchronized from Discord's API.
+/// Tracks guild name, icon, whether the bot is still a member of the guild, and when
+/// the guild's roles, channels, and members were last synchronized from Discord's API.
 #[derive(Debug, Clone, PartialEq)]
 pub struct DiscordGuild {
     /// Discord guild ID as a u64.
@@ -24,6 +24,10 @@ pub struct DiscordGuild {
     pub icon_hash: Option<String>,
     /// Timestamp of the last full guild sync (roles, channels, members).
     pub last_sync_at: DateTime<Utc>,
+    /// Whether the bot is a member of the guild & its timerboard is in use.
+    pub status: DiscordGuildStatus,
+    /// When the bot was removed from the guild, None while active.
+    pub removed_at: Option<DateTime<Utc>>,
 }
 
 impl DiscordGuild {
@@ -38,25 +42,29 @@ impl DiscordGuild {
     ///
     /// # Returns
     /// - `Ok(DiscordGuild)` - Successfully converted domain model
-    /// - `Err(DbErr::Custom)` - Failed to parse guild_id as u64
+    /// - `Err(DbErr::Custom)` - Failed to parse guild_id as u64 or unknown status
     pub fn from_entity(entity: entity::discord_guild::Model) -> Result<Self, DbErr> {
         let guild_id = entity
             .guild_id
             .parse::<u64>()
             .map_err(|e| DbErr::Custom(format!("Failed to parse guild_id: {}", e)))?;
+        let status = DiscordGuildStatus::parse(&entity.status)
+            .ok_or_else(|| DbErr::Custom(format!("Unknown guild status: {}", entity.status)))?;
 
         Ok(Self {
             guild_id,
             name: entity.name,
             icon_hash: entity.icon_hash,
             last_sync_at: entity.last_sync_at,
+            status,
+            removed_at: entity.removed_at,
         })
     }
 
     /// Converts the guild domain model to a DTO for API responses.
     ///
     /// Strips synchronization metadata (last_sync_at) and preserves only the
-    /// fields needed for client display: guild ID, name, and icon hash.
+    /// fields needed for client display: guild ID, name, icon hash, and status.
     ///
     /// # Returns
     /// - `DiscordGuildDto` - The converted guild DTO
@@ -65,6 +73,8 @@ impl DiscordGuild {
             guild_id: self.guild_id,
             name: self.name,
             icon_hash: self.icon_hash,
+            status: self.status,
+            removed_at: self.removed_at,
         }
     }
 }
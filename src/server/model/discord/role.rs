@@ -1,15 +1,16 @@
 //! Discord guild role domain models.
 //!
 //! Provides the domain model for Discord roles within guilds, tracking role identity,
-//! display properties, and hierarchy position. Handles conversion between entity models
+//! display properties, hierarchy position, and permissions. Handles conversion between entity models
 //! from the database and domain models used in the service layer.
 
 use sea_orm::DbErr;
 
 /// Discord role within a guild with display properties and hierarchy position.
 ///
-/// Tracks role name, color for display styling, and position in the guild's
-/// role hierarchy where higher positions indicate greater importance.
+/// Tracks role name, color for display styling, position in the guild's
+/// role hierarchy where higher positions indicate greater importance, and the
+/// guild-wide permissions the role grants.
 #[derive(Debug, Clone, PartialEq)]
 pub struct DiscordGuildRole {
     /// Discord role ID as a u64.
@@ -22,6 +23,10 @@ pub struct DiscordGuildRole {
     pub color: String,
     /// Role position in the guild's role hierarchy (higher = more important).
     pub position: i16,
+    /// Guild-wide permissions bitfield granted by the role.
+    pub permissions: u64,
+    /// Whether anyone can mention the role.
+    pub mentionable: bool,
 }
 
 impl DiscordGuildRole {
@@ -34,7 +39,7 @@ impl DiscordGuildRole {
     ///
     /// # Returns
     /// - `Ok(DiscordGuildRole)` - Successfully converted domain model
-    /// - `Err(DbErr::Custom)` - Failed to parse role_id or guild_id as u64
+    /// - `Err(DbErr::Custom)` - Failed to parse role_id, guild_id or permissions as u64
     pub fn from_entity(entity: entity::discord_guild_role::Model) -> Result<Self, DbErr> {
         let role_id = entity
             .role_id
@@ -46,12 +51,19 @@ impl DiscordGuildRole {
             .parse::<u64>()
             .map_err(|e| DbErr::Custom(format!("Failed to parse guild_id: {}", e)))?;
 
+        let permissions = entity
+            .permissions
+            .parse::<u64>()
+            .map_err(|e| DbErr::Custom(format!("Failed to parse permissions: {}", e)))?;
+
         Ok(Self {
             role_id,
             guild_id,
             name: entity.name,
             color: entity.color,
             position: entity.position,
+            permissions,
+            mentionable: entity.mentionable,
         })
     }
 }
@@ -0,0 +1,564 @@
+//! Domain & parameter models for doctrine operations
+//!
+//! Defines the doctrine & doctrine fit domain models, the doctrine parameter models and
+//! the parser for ship fits pasted in EFT format, the text format the game client's
+//! fitting window & Pyfa import and export:
+//!
+//! ```text
+//! [Muninn, Muninn - Fleet]
+//! Damage Control II
+//! Gyrostabilizer II
+//!
+//! Large Shield Extender II
+//! [Empty Med slot]
+//!
+//! 720mm Howitzer Artillery II, Republic Fleet Phased Plasma M
+//!
+//! Medium Projectile Burst Aerator II
+//!
+//! Hammerhead II x5
+//! ```
+//!
+//! Blank lines separate the slot racks, drones & cargo. Items may have a loaded charge
+//! after a comma, a quantity as ` xN` and an `/OFFLINE` suffix. Item names aren't checked
+//! against the game's static data, only the structure of the fit is validated.
+
+use crate::{
+    model::doctrine::{CreateDoctrineDto, DoctrineDto, DoctrineFitDto, UpdateDoctrineDto},
+    server::{error::AppError, util::parse::parse_u64_from_string},
+};
+
+/// The doctrine domain model
+///
+/// A named set of ship fits of a guild, referenced by doctrine ping format fields
+#[derive(Debug, Clone, PartialEq)]
+pub struct Doctrine {
+    pub id: i32,
+    pub guild_id: u64,
+    pub name: String,
+    pub description: Option<String>,
+    /// Fits in the order they were pasted
+    pub fits: Vec<DoctrineFit>,
+}
+
+impl Doctrine {
+    /// Converts an entity model & its fits to the doctrine domain model
+    ///
+    /// # Arguments
+    /// - `entity` - The doctrine entity model from the database
+    /// - `fits` - The doctrine's fits, already ordered by position
+    ///
+    /// # Returns
+    /// - `Ok(Doctrine)` - The converted doctrine domain model
+    /// - `Err(AppError::InternalError(ParseStringId))` - Failed to parse guild ID to u64
+    pub fn from_entity(
+        entity: entity::doctrine::Model,
+        fits: Vec<DoctrineFit>,
+    ) -> Result<Self, AppError> {
+        Ok(Self {
+            id: entity.id,
+            guild_id: parse_u64_from_string(entity.guild_id)?,
+            name: entity.name,
+            description: entity.description,
+            fits,
+        })
+    }
+
+    /// Hulls of the doctrine's fits in order without duplicates
+    pub fn hulls(&self) -> Vec<&str> {
+        let mut hulls: Vec<&str> = Vec::new();
+        for fit in &self.fits {
+            if !hulls.contains(&fit.hull.as_str()) {
+                hulls.push(&fit.hull);
+            }
+        }
+
+        hulls
+    }
+
+    /// Formats the doctrine for a Discord embed field
+    ///
+    /// # Returns
+    /// - `String` - Bold doctrine name followed by its hulls, e.g.
+    ///   "**Muninns**\nMuninn, Scimitar, Sabre"
+    pub fn embed_value(&self) -> String {
+        let hulls = self.hulls();
+        if hulls.is_empty() {
+            return format!("**{}**", self.name);
+        }
+
+        format!("**{}**\n{}", self.name, hulls.join(", "))
+    }
+
+    pub fn into_dto(self) -> DoctrineDto {
+        DoctrineDto {
+            id: self.id,
+            guild_id: self.guild_id,
+            name: self.name,
+            description: self.description,
+            fits: self.fits.into_iter().map(DoctrineFit::into_dto).collect(),
+        }
+    }
+}
+
+/// A ship fit of a doctrine
+#[derive(Debug, Clone, PartialEq)]
+pub struct DoctrineFit {
+    pub id: i32,
+    pub name: String,
+    pub hull: String,
+    /// Modules, drones & cargo ordered by section & position
+    pub modules: Vec<FitModule>,
+}
+
+impl DoctrineFit {
+    /// Converts a fit entity model & its module entity models to the fit domain model
+    ///
+    /// # Arguments
+    /// - `entity` - The fit entity model from the database
+    /// - `modules` - The fit's module entity models, already ordered by section & position
+    pub fn from_entity(
+        entity: entity::doctrine_fit::Model,
+        modules: Vec<entity::doctrine_fit_module::Model>,
+    ) -> Self {
+        Self {
+            id: entity.id,
+            name: entity.name,
+            hull: entity.hull,
+            modules: modules.into_iter().map(FitModule::from_entity).collect(),
+        }
+    }
+
+    /// Exports the fit in EFT format with a blank line between sections
+    pub fn to_eft(&self) -> String {
+        let mut eft = format!("[{}, {}]", self.hull, self.name);
+        let mut section = None;
+
+        for module in &self.modules {
+            if section.is_some_and(|section| section != module.section) {
+                eft.push('\n');
+            }
+            eft.push('\n');
+            eft.push_str(&module.to_eft_line());
+            section = Some(module.section);
+        }
+
+        eft
+    }
+
+    pub fn into_dto(self) -> DoctrineFitDto {
+        DoctrineFitDto {
+            eft: self.to_eft(),
+            id: self.id,
+            name: self.name,
+            hull: self.hull,
+        }
+    }
+}
+
+/// Module, drone or cargo item of a fit
+#[derive(Debug, Clone, PartialEq)]
+pub struct FitModule {
+    /// Index of the blank line separated EFT block the item was listed in
+    pub section: u32,
+    pub name: String,
+    /// Charge loaded into the module, e.g. "Republic Fleet Phased Plasma M"
+    pub charge: Option<String>,
+    pub quantity: u32,
+    pub offline: bool,
+}
+
+impl FitModule {
+    fn from_entity(entity: entity::doctrine_fit_module::Model) -> Self {
+        Self {
+            section: entity.section as u32,
+            name: entity.name,
+            charge: entity.charge,
+            quantity: entity.quantity as u32,
+            offline: entity.offline,
+        }
+    }
+
+    /// Formats the item as an EFT line, e.g. "Hammerhead II x5"
+    fn to_eft_line(&self) -> String {
+        let mut line = self.name.clone();
+        if let Some(charge) = &self.charge {
+            line.push_str(&format!(", {}", charge));
+        }
+        if self.quantity > 1 {
+            line.push_str(&format!(" x{}", self.quantity));
+        }
+        if self.offline {
+            line.push_str(" /OFFLINE");
+        }
+
+        line
+    }
+}
+
+/// A fit parsed from EFT format, ready to be stored
+#[derive(Debug, Clone, PartialEq)]
+pub struct FitParam {
+    pub name: String,
+    pub hull: String,
+    pub modules: Vec<FitModule>,
+}
+
+impl FitParam {
+    /// Parses one or more fits pasted in EFT format
+    ///
+    /// Every fit starts with a `[Hull, Fit name]` line, the lines up to the next such
+    /// line are its items. `[Empty Low slot]` style markers are skipped.
+    ///
+    /// # Arguments
+    /// - `text` - Pasted fits
+    ///
+    /// # Returns
+    /// - `Ok(Vec<FitParam>)` - Parsed fits in the order they were pasted, empty for a
+    ///   blank paste
+    /// - `Err(AppError::BadRequest(_))` - A line isn't valid EFT, the message names the
+    ///   line number
+    pub fn parse_eft(text: &str) -> Result<Vec<Self>, AppError> {
+        let mut fits: Vec<Self> = Vec::new();
+        let mut section = 0;
+        // Whether the current section has items, so repeated blank lines count once
+        let mut section_used = false;
+        let mut after_blank = false;
+
+        for (index, line) in text.lines().enumerate() {
+            let line = line.trim();
+            let invalid =
+                |reason: String| AppError::BadRequest(format!("Line {}: {}", index + 1, reason));
+
+            if line.is_empty() {
+                after_blank = true;
+                continue;
+            }
+
+            let bracketed = line.strip_prefix('[').and_then(|l| l.strip_suffix(']'));
+            if let Some(header) = bracketed.filter(|inner| !is_empty_slot(inner)) {
+                let Some((hull, name)) = header.split_once(',') else {
+                    return Err(invalid(format!(
+                        "expected a [Hull, Fit name] line, got '{}'",
+                        line
+                    )));
+                };
+                let (hull, name) = (hull.trim(), name.trim());
+                if hull.is_empty() || name.is_empty() {
+                    return Err(invalid("a fit needs both a hull & a fit name".to_string()));
+                }
+
+                fits.push(Self {
+                    name: name.to_string(),
+                    hull: hull.to_string(),
+                    modules: Vec::new(),
+                });
+                section = 0;
+                section_used = false;
+                after_blank = false;
+                continue;
+            }
+
+            let Some(fit) = fits.last_mut() else {
+                return Err(invalid(
+                    "fits must start with a [Hull, Fit name] line".to_string(),
+                ));
+            };
+
+            if after_blank && section_used {
+                section += 1;
+            }
+            after_blank = false;
+            section_used = true;
+
+            // Empty slots only mark their section as used
+            if bracketed.is_some() {
+                continue;
+            }
+
+            fit.modules
+                .push(parse_item(line, section).map_err(invalid)?);
+        }
+
+        Ok(fits)
+    }
+}
+
+/// Whether the inside of a bracketed line is an empty slot marker, e.g. "Empty Low slot"
+fn is_empty_slot(inner: &str) -> bool {
+    let lowercase = inner.to_lowercase();
+    lowercase.starts_with("empty ") && lowercase.ends_with(" slot")
+}
+
+/// Parses an item line such as "720mm Howitzer Artillery II, Republic Fleet Phased
+/// Plasma M", "Hammerhead II x5" or "Damage Control II /OFFLINE"
+///
+/// # Returns
+/// - `Ok(FitModule)` - The parsed item
+/// - `Err(String)` - Reason the line isn't a valid item
+fn parse_item(line: &str, section: u32) -> Result<FitModule, String> {
+    let (line, offline) = match line.strip_suffix("/OFFLINE") {
+        Some(rest) => (rest.trim_end(), true),
+        None => (line, false),
+    };
+
+    let (line, quantity) = match line.rsplit_once(" x") {
+        Some((rest, amount))
+            if !amount.is_empty() && amount.bytes().all(|b| b.is_ascii_digit()) =>
+        {
+            let quantity = amount
+                .parse::<u32>()
+                .ok()
+                .filter(|quantity| *quantity > 0 && *quantity <= i32::MAX as u32)
+                .ok_or_else(|| format!("invalid quantity 'x{}'", amount))?;
+            (rest.trim_end(), quantity)
+        }
+        _ => (line, 1),
+    };
+
+    let (name, charge) = match line.split_once(',') {
+        Some((name, charge)) => (name.trim(), Some(charge.trim())),
+        None => (line.trim(), None),
+    };
+
+    if name.is_empty() {
+        return Err(format!("missing item name in '{}'", line));
+    }
+    if name.contains(['[', ']']) {
+        return Err(format!("unexpected bracket in '{}'", line));
+    }
+
+    Ok(FitModule {
+        section,
+        name: name.to_string(),
+        charge: charge
+            .filter(|charge| !charge.is_empty())
+            .map(str::to_string),
+        quantity,
+        offline,
+    })
+}
+
+/// Parameters for creating a new doctrine
+#[derive(Debug, Clone)]
+pub struct CreateDoctrineParam {
+    pub name: String,
+    pub description: Option<String>,
+    pub fits: Vec<FitParam>,
+}
+
+impl CreateDoctrineParam {
+    /// Converts the DTO to create parameters, parsing the pasted EFT fits
+    ///
+    /// # Returns
+    /// - `Ok(CreateDoctrineParam)` - Trimmed name & description with the parsed fits
+    /// - `Err(AppError::BadRequest(_))` - The fits aren't valid EFT
+    pub fn from_dto(dto: CreateDoctrineDto) -> Result<Self, AppError> {
+        Ok(Self {
+            name: dto.name.trim().to_string(),
+            description: trim_description(dto.description),
+            fits: FitParam::parse_eft(&dto.eft)?,
+        })
+    }
+}
+
+/// Parameters for updating an existing doctrine, replacing all of its fits
+#[derive(Debug, Clone)]
+pub struct UpdateDoctrineParam {
+    pub name: String,
+    pub description: Option<String>,
+    pub fits: Vec<FitParam>,
+}
+
+impl UpdateDoctrineParam {
+    /// Converts the DTO to update parameters, parsing the pasted EFT fits
+    ///
+    /// # Returns
+    /// - `Ok(UpdateDoctrineParam)` - Trimmed name & description with the parsed fits
+    /// - `Err(AppError::BadRequest(_))` - The fits aren't valid EFT
+    pub fn from_dto(dto: UpdateDoctrineDto) -> Result<Self, AppError> {
+        Ok(Self {
+            name: dto.name.trim().to_string(),
+            description: trim_description(dto.description),
+            fits: FitParam::parse_eft(&dto.eft)?,
+        })
+    }
+}
+
+/// Trims a description, treating a blank description as none
+fn trim_description(description: Option<String>) -> Option<String> {
+    description
+        .map(|description| description.trim().to_string())
+        .filter(|description| !description.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MUNINN: &str = "[Muninn, Muninn - Fleet]
+Damage Control II
+Gyrostabilizer II /OFFLINE
+[Empty Low slot]
+
+Large Shield Extender II
+[Empty Med slot]
+
+720mm Howitzer Artillery II, Republic Fleet Phased Plasma M
+720mm Howitzer Artillery II,Republic Fleet Phased Plasma M
+
+Medium Projectile Burst Aerator II
+
+
+Hammerhead II x5
+
+Nanite Repair Paste x100
+";
+
+    fn stored(fit: FitParam) -> DoctrineFit {
+        DoctrineFit {
+            id: 1,
+            name: fit.name,
+            hull: fit.hull,
+            modules: fit.modules,
+        }
+    }
+
+    /// Tests parsing a fit exported from the game client.
+    ///
+    /// Expected: Hull, name & items are read with charges, quantities & offline state,
+    /// empty slots are skipped but keep their section and repeated blank lines count once
+    #[test]
+    fn parses_eft_fit() {
+        let fits = FitParam::parse_eft(MUNINN).unwrap();
+
+        assert_eq!(fits.len(), 1);
+        let fit = &fits[0];
+        assert_eq!(fit.hull, "Muninn");
+        assert_eq!(fit.name, "Muninn - Fleet");
+        assert_eq!(fit.modules.len(), 8);
+
+        let offline = &fit.modules[1];
+        assert_eq!(offline.name, "Gyrostabilizer II");
+        assert!(offline.offline);
+
+        let gun = &fit.modules[3];
+        assert_eq!(gun.section, 2);
+        assert_eq!(gun.name, "720mm Howitzer Artillery II");
+        assert_eq!(
+            gun.charge.as_deref(),
+            Some("Republic Fleet Phased Plasma M")
+        );
+        assert_eq!(fit.modules[4], fit.modules[3]);
+
+        let drones = &fit.modules[6];
+        assert_eq!(drones.section, 4);
+        assert_eq!(drones.name, "Hammerhead II");
+        assert_eq!(drones.quantity, 5);
+        assert_eq!(fit.modules[7].section, 5);
+    }
+
+    /// Tests parsing several fits pasted at once.
+    ///
+    /// Expected: A fit per header line with its own items, in paste order
+    #[test]
+    fn parses_multiple_fits() {
+        let text = format!(
+            "{}\n[Scimitar, Scimitar - Logi]\nLarge Remote Shield Booster II\n",
+            MUNINN
+        );
+
+        let fits = FitParam::parse_eft(&text).unwrap();
+
+        assert_eq!(fits.len(), 2);
+        assert_eq!(fits[1].hull, "Scimitar");
+        assert_eq!(fits[1].modules.len(), 1);
+        assert_eq!(fits[1].modules[0].section, 0);
+    }
+
+    /// Tests exporting a parsed fit.
+    ///
+    /// Expected: Items are written back in their sections without empty slots, with
+    /// charges, quantities & offline state
+    #[test]
+    fn exports_eft() {
+        let fit = stored(FitParam::parse_eft(MUNINN).unwrap().remove(0));
+
+        assert_eq!(
+            fit.to_eft(),
+            "[Muninn, Muninn - Fleet]
+Damage Control II
+Gyrostabilizer II /OFFLINE
+
+Large Shield Extender II
+
+720mm Howitzer Artillery II, Republic Fleet Phased Plasma M
+720mm Howitzer Artillery II, Republic Fleet Phased Plasma M
+
+Medium Projectile Burst Aerator II
+
+Hammerhead II x5
+
+Nanite Repair Paste x100"
+        );
+    }
+
+    /// Tests rejecting items pasted before any fit header.
+    ///
+    /// Expected: BadRequest naming the first line
+    #[test]
+    fn rejects_items_without_header() {
+        let result = FitParam::parse_eft("Damage Control II\n[Muninn, Fleet]");
+
+        assert!(matches!(
+            result,
+            Err(AppError::BadRequest(message)) if message.starts_with("Line 1:")
+        ));
+    }
+
+    /// Tests rejecting malformed headers & quantities.
+    ///
+    /// Expected: BadRequest naming the offending line
+    #[test]
+    fn rejects_invalid_lines() {
+        for (text, line) in [
+            ("[Muninn]\nDamage Control II", "Line 1:"),
+            ("[Muninn, ]", "Line 1:"),
+            ("[Muninn, Fleet]\nHammerhead II x0", "Line 2:"),
+            (
+                "[Muninn, Fleet]\nDamage Control II\n, Phased Plasma M",
+                "Line 3:",
+            ),
+        ] {
+            let result = FitParam::parse_eft(text);
+
+            assert!(
+                matches!(&result, Err(AppError::BadRequest(message)) if message.starts_with(line)),
+                "{:?} for {:?}",
+                result,
+                text
+            );
+        }
+    }
+
+    /// Tests listing a doctrine's hulls for its embed.
+    ///
+    /// Expected: Hulls in fit order without duplicates below the doctrine's name
+    #[test]
+    fn lists_hulls_in_embed() {
+        let mut fits = FitParam::parse_eft(&format!(
+            "{}\n[Scimitar, Logi]\n\n[Muninn, Muninn - Tackle]",
+            MUNINN
+        ))
+        .unwrap();
+        let doctrine = Doctrine {
+            id: 1,
+            guild_id: 1,
+            name: "Muninns".to_string(),
+            description: None,
+            fits: fits.drain(..).map(stored).collect(),
+        };
+
+        assert_eq!(doctrine.embed_value(), "**Muninns**\nMuninn, Scimitar");
+    }
+}
@@ -146,3 +146,35 @@ pub struct GetPaginatedFleetsByGuildParam {
     /// Number of items per page.
     pub per_page: u64,
 }
+
+/// Parameters for retrieving paginated fleets across all guilds of a user.
+///
+/// Each guild applies its own category permissions, admins see every active guild.
+#[derive(Debug, Clone)]
+pub struct GetPaginatedFleetsForUserParam {
+    /// Discord user ID for guild membership & permission filtering.
+    pub user_id: u64,
+    /// Whether the user is an admin (bypasses all filtering).
+    pub is_admin: bool,
+    /// Only include fleets of these guilds, None includes every guild of the user.
+    pub guild_ids: Option<Vec<u64>>,
+    /// Page number (0-indexed).
+    pub page: u64,
+    /// Number of items per page.
+    pub per_page: u64,
+}
+
+/// Parameters for retrieving the fleets of a guild within a time range.
+#[derive(Debug, Clone)]
+pub struct GetFleetCalendarParam {
+    /// Discord guild ID to fetch fleets for.
+    pub guild_id: u64,
+    /// Discord user ID for permission filtering.
+    pub user_id: u64,
+    /// Whether the user is an admin (bypasses all filtering).
+    pub is_admin: bool,
+    /// Start of the range (inclusive).
+    pub start: DateTime<Utc>,
+    /// End of the range (exclusive).
+    pub end: DateTime<Utc>,
+}
@@ -0,0 +1,120 @@
+//! Domain models for live fleet events.
+//!
+//! Fleet changes are broadcast in-process to every open timerboard event stream. Each
+//! event carries the visibility of the fleet before & after the change so streams can
+//! filter events per user without querying the fleet, which no longer exists once deleted.
+
+use chrono::{DateTime, Duration, Utc};
+
+use crate::{model::fleet::FleetEventDto, server::model::fleet::Fleet};
+
+/// Kind of change made to a fleet
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FleetEventKind {
+    Created,
+    Updated,
+    Deleted,
+}
+
+/// Category & hidden state of a fleet used to decide who may receive an event about it
+#[derive(Debug, Clone, PartialEq)]
+pub struct FleetVisibility {
+    /// Category the fleet belongs to
+    pub category_id: i32,
+    /// Whether the fleet is hidden from viewers without create/manage access
+    pub hidden: bool,
+    /// When a hidden fleet becomes visible to every viewer of the category
+    pub revealed_at: DateTime<Utc>,
+}
+
+impl FleetVisibility {
+    /// Builds the visibility of a fleet
+    ///
+    /// Hidden fleets are revealed at the category's reminder time, or at fleet time for
+    /// categories without a reminder.
+    ///
+    /// # Arguments
+    /// - `fleet` - The fleet
+    /// - `ping_reminder` - Reminder offset of the fleet's category in seconds
+    pub fn new(fleet: &Fleet, ping_reminder: Option<i32>) -> Self {
+        let revealed_at = match ping_reminder {
+            Some(seconds) => fleet.fleet_time - Duration::seconds(seconds as i64),
+            None => fleet.fleet_time,
+        };
+
+        Self {
+            category_id: fleet.category_id,
+            hidden: fleet.hidden,
+            revealed_at,
+        }
+    }
+
+    /// Returns whether the fleet is hidden from category viewers at the provided time
+    pub fn is_hidden_at(&self, now: DateTime<Utc>) -> bool {
+        self.hidden && now < self.revealed_at
+    }
+
+    /// Returns whether a hidden fleet was revealed to category viewers after `since` and
+    /// no later than `now`
+    pub fn is_revealed_between(&self, since: DateTime<Utc>, now: DateTime<Utc>) -> bool {
+        self.hidden && since < self.revealed_at && self.revealed_at <= now
+    }
+}
+
+/// A change made to a fleet, broadcast to open timerboard event streams
+#[derive(Debug, Clone, PartialEq)]
+pub struct FleetEvent {
+    pub kind: FleetEventKind,
+    /// Discord ID of the guild the fleet belongs to
+    pub guild_id: u64,
+    pub fleet_id: i32,
+    /// Visibility of the fleet after the change, preceded by its visibility before the
+    /// change for updates. A user receives the event if any of them is visible to them.
+    pub visibility: Vec<FleetVisibility>,
+}
+
+impl FleetEvent {
+    pub fn into_dto(self) -> FleetEventDto {
+        let fleet_id = self.fleet_id;
+
+        match self.kind {
+            FleetEventKind::Created => FleetEventDto::Created { fleet_id },
+            FleetEventKind::Updated => FleetEventDto::Updated { fleet_id },
+            FleetEventKind::Deleted => FleetEventDto::Deleted { fleet_id },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn now() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2026-01-15T18:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    fn visibility(hidden: bool, revealed_at: DateTime<Utc>) -> FleetVisibility {
+        FleetVisibility {
+            category_id: 1,
+            hidden,
+            revealed_at,
+        }
+    }
+
+    /// Tests detecting hidden fleets revealed within a window.
+    ///
+    /// Expected: Only hidden fleets revealed after the window start & no later than its
+    /// end are reported, each window boundary belonging to exactly one window
+    #[test]
+    fn detects_fleets_revealed_within_window() {
+        let since = now() - Duration::minutes(1);
+
+        assert!(visibility(true, now()).is_revealed_between(since, now()));
+        assert!(visibility(true, now() - Duration::seconds(30)).is_revealed_between(since, now()));
+        assert!(!visibility(true, since).is_revealed_between(since, now()));
+        assert!(!visibility(true, now() + Duration::seconds(1)).is_revealed_between(since, now()));
+        assert!(!visibility(false, now()).is_revealed_between(since, now()));
+    }
+}
@@ -10,13 +10,15 @@ use crate::server::{error::AppError, util::parse::parse_u64_from_string};
 ///
 /// Tracks messages posted for fleet events such as creation announcements, reminders,
 /// and formup notifications. Stores channel and message IDs for message management.
+/// Messages posted to a forum channel additionally store the forum post thread they
+/// were sent in.
 #[derive(Debug, Clone, PartialEq)]
 pub struct FleetMessage {
     /// Unique identifier for the fleet message record.
     pub id: i32,
     /// ID of the fleet this message belongs to.
     pub fleet_id: i32,
-    /// Discord channel ID where the message was posted
+    /// Discord channel ID where the message was posted, the forum channel for forum posts
     pub channel_id: u64,
     /// Discord message ID
     pub message_id: u64,
@@ -24,6 +26,8 @@ pub struct FleetMessage {
     pub message_type: String,
     /// Timestamp when the message record was created.
     pub created_at: DateTime<Utc>,
+    /// Forum post thread ID the message was sent in, None outside of forum channels.
+    pub thread_id: Option<u64>,
 }
 
 impl FleetMessage {
@@ -37,6 +41,7 @@ impl FleetMessage {
     pub fn from_entity(entity: entity::fleet_message::Model) -> Result<Self, AppError> {
         let channel_id = parse_u64_from_string(entity.channel_id)?;
         let message_id = parse_u64_from_string(entity.message_id)?;
+        let thread_id = entity.thread_id.map(parse_u64_from_string).transpose()?;
 
         Ok(Self {
             id: entity.id,
@@ -45,8 +50,16 @@ impl FleetMessage {
             message_id,
             message_type: entity.message_type,
             created_at: entity.created_at,
+            thread_id,
         })
     }
+
+    /// Returns the ID of the channel the message lives in for editing or replying.
+    ///
+    /// Forum messages live in their forum post thread rather than the forum channel.
+    pub fn message_channel_id(&self) -> u64 {
+        self.thread_id.unwrap_or(self.channel_id)
+    }
 }
 
 /// Parameters for creating a new fleet message record.
@@ -62,4 +75,6 @@ pub struct CreateFleetMessageParam {
     pub message_id: u64,
     /// Type of message (e.g., "creation", "reminder", "formup").
     pub message_type: String,
+    /// Forum post thread ID the message was sent in, None outside of forum channels.
+    pub thread_id: Option<u64>,
 }
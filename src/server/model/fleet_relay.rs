@@ -0,0 +1,99 @@
+//! Domain & parameter models for cross-guild fleet relays
+//!
+//! Defines the fleet relay domain model linking a source guild's category to another
+//! guild, and the relayed fleet model linking a relayed copy to the fleet it was
+//! copied from. The source guild of a relay is the guild of its source category.
+
+use chrono::{DateTime, Utc};
+
+use crate::{
+    model::fleet_relay::{CreateFleetRelayDto, FleetRelayStatus},
+    server::{
+        error::{internal::InternalError, AppError},
+        util::parse::parse_u64_from_string,
+    },
+};
+
+/// The fleet relay domain model
+///
+/// Fleets created in the source category are copied into the target category once
+/// the target guild's admins accepted the relay.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FleetRelay {
+    pub id: i32,
+    pub source_category_id: i32,
+    pub target_guild_id: u64,
+    /// Category of the target guild fleets are copied into, chosen when accepting
+    pub target_category_id: Option<i32>,
+    pub status: FleetRelayStatus,
+    pub created_at: DateTime<Utc>,
+}
+
+impl FleetRelay {
+    /// Converts an entity model to the fleet relay domain model
+    ///
+    /// # Arguments
+    /// - `entity` - The entity model from the database
+    ///
+    /// # Returns
+    /// - `Ok(FleetRelay)` - The converted fleet relay domain model
+    /// - `Err(AppError::InternalError(ParseStringId))` - Failed to parse guild ID to u64
+    /// - `Err(AppError::InternalError(InvalidDatabaseValue))` - Unknown status stored
+    pub fn from_entity(entity: entity::fleet_relay::Model) -> Result<Self, AppError> {
+        let target_guild_id = parse_u64_from_string(entity.target_guild_id)?;
+        let status = FleetRelayStatus::parse(&entity.status).ok_or_else(|| {
+            InternalError::InvalidDatabaseValue {
+                table: "fleet_relay",
+                field: "status",
+                expected: "pending, accepted, declined",
+                actual: entity.status.clone(),
+            }
+        })?;
+
+        Ok(Self {
+            id: entity.id,
+            source_category_id: entity.source_category_id,
+            target_guild_id,
+            target_category_id: entity.target_category_id,
+            status,
+            created_at: entity.created_at,
+        })
+    }
+}
+
+/// Link between a relayed copy of a fleet and the fleet it was copied from
+#[derive(Debug, Clone, PartialEq)]
+pub struct RelayedFleet {
+    /// ID of the copy in the target guild
+    pub fleet_id: i32,
+    /// ID of the fleet in the source guild
+    pub source_fleet_id: i32,
+    /// ID of the relay the copy was created by
+    pub relay_id: i32,
+}
+
+impl RelayedFleet {
+    pub fn from_entity(entity: entity::relayed_fleet::Model) -> Self {
+        Self {
+            fleet_id: entity.fleet_id,
+            source_fleet_id: entity.source_fleet_id,
+            relay_id: entity.relay_id,
+        }
+    }
+}
+
+/// Parameters for requesting a new fleet relay
+#[derive(Debug, Clone)]
+pub struct CreateFleetRelayParam {
+    pub source_category_id: i32,
+    pub target_guild_id: u64,
+}
+
+impl From<CreateFleetRelayDto> for CreateFleetRelayParam {
+    fn from(dto: CreateFleetRelayDto) -> Self {
+        Self {
+            source_category_id: dto.source_category_id,
+            target_guild_id: dto.target_guild_id,
+        }
+    }
+}
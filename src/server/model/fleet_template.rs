@@ -0,0 +1,133 @@
+//! Domain & parameter models for fleet templates
+//!
+//! Templates hold everything of a fleet but its time so FCs don't re-enter the same
+//! name, description and field values for every fleet. A template belongs to the user
+//! who saved it and can be shared with everyone able to create fleets in its category.
+
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+
+use crate::{
+    model::fleet_template::{CreateFleetTemplateDto, FleetTemplateDto},
+    server::{error::AppError, util::parse::parse_u64_from_string},
+};
+
+/// Saved fleet prefilling the fleet creation form
+#[derive(Debug, Clone, PartialEq)]
+pub struct FleetTemplate {
+    pub id: i32,
+    /// ID of the fleet category the template creates fleets in
+    pub category_id: i32,
+    /// Discord ID of the user who saved the template
+    pub owner_id: u64,
+    /// Name the template is picked by
+    pub name: String,
+    /// Whether everyone able to create fleets in the category can use the template
+    pub shared: bool,
+    /// Name of the fleets created from the template
+    pub fleet_name: String,
+    /// Discord ID of the fleet commander, None to keep the FC picked in the form
+    pub commander_id: Option<u64>,
+    pub description: Option<String>,
+    /// Map of field_id to field value for custom ping format fields
+    pub field_values: HashMap<i32, String>,
+    pub hidden: bool,
+    pub disable_reminder: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+impl FleetTemplate {
+    /// Converts an entity model & its field values to a fleet template domain model
+    ///
+    /// # Arguments
+    /// - `entity` - The template entity model from the database
+    /// - `field_values` - The template's field value entity models
+    ///
+    /// # Returns
+    /// - `Ok(FleetTemplate)` - The converted template
+    /// - `Err(AppError::InternalError(ParseStringId))` - Failed to parse owner or commander ID to u64
+    pub fn from_entity(
+        entity: entity::fleet_template::Model,
+        field_values: Vec<entity::fleet_template_field_value::Model>,
+    ) -> Result<Self, AppError> {
+        let commander_id = match entity.commander_id {
+            Some(commander_id) => Some(parse_u64_from_string(commander_id)?),
+            None => None,
+        };
+
+        Ok(Self {
+            id: entity.id,
+            category_id: entity.category_id,
+            owner_id: parse_u64_from_string(entity.owner_id)?,
+            name: entity.name,
+            shared: entity.shared,
+            fleet_name: entity.fleet_name,
+            commander_id,
+            description: entity.description,
+            field_values: field_values
+                .into_iter()
+                .map(|value| (value.field_id, value.value))
+                .collect(),
+            hidden: entity.hidden,
+            disable_reminder: entity.disable_reminder,
+            created_at: entity.created_at,
+        })
+    }
+
+    /// Converts the template to a DTO for the user it is returned to
+    ///
+    /// # Arguments
+    /// - `can_delete` - Whether the requesting user can delete the template
+    pub fn into_dto(self, can_delete: bool) -> FleetTemplateDto {
+        FleetTemplateDto {
+            id: self.id,
+            category_id: self.category_id,
+            name: self.name,
+            shared: self.shared,
+            owner_id: self.owner_id,
+            can_delete,
+            fleet_name: self.fleet_name,
+            commander_id: self.commander_id,
+            description: self.description,
+            field_values: self.field_values,
+            hidden: self.hidden,
+            disable_reminder: self.disable_reminder,
+        }
+    }
+}
+
+/// Parameters for saving a new fleet template
+#[derive(Debug, Clone)]
+pub struct CreateFleetTemplateParam {
+    pub category_id: i32,
+    /// Discord ID of the user saving the template
+    pub owner_id: u64,
+    pub name: String,
+    pub shared: bool,
+    pub fleet_name: String,
+    pub commander_id: Option<u64>,
+    pub description: Option<String>,
+    pub field_values: HashMap<i32, String>,
+    pub hidden: bool,
+    pub disable_reminder: bool,
+}
+
+impl CreateFleetTemplateParam {
+    /// Converts the DTO into parameters for a template owned by the requesting user
+    ///
+    /// Names are trimmed and empty descriptions are stored as none.
+    pub fn from_dto(dto: CreateFleetTemplateDto, owner_id: u64) -> Self {
+        Self {
+            category_id: dto.category_id,
+            owner_id,
+            name: dto.name.trim().to_string(),
+            shared: dto.shared,
+            fleet_name: dto.fleet_name.trim().to_string(),
+            commander_id: dto.commander_id,
+            description: dto.description.filter(|d| !d.trim().is_empty()),
+            field_values: dto.field_values,
+            hidden: dto.hidden,
+            disable_reminder: dto.disable_reminder,
+        }
+    }
+}
@@ -0,0 +1,240 @@
+//! Guild settings domain models.
+//!
+//! Defines the per-guild display & behaviour settings read by the Discord notification
+//! code. Guilds without stored settings use the defaults of `GuildSettingsDto`, matching
+//! the behaviour from before settings were configurable.
+
+use sea_orm::DbErr;
+
+use crate::{
+    model::guild_settings::{DowntimePolicy, DowntimeWindowDto, GuildSettingsDto},
+    server::{
+        error::AppError, eve_map::eve_map, model::solar_system::resolve_staging_system_id,
+        util::parse::parse_u64_from_string,
+    },
+};
+
+/// Most extra timezones a guild can show fleet times in
+pub const MAX_EXTRA_TIMEZONES: usize = 5;
+
+/// Longest lookahead window of the upcoming fleets list, in hours
+pub const MAX_LIST_LOOKAHEAD_HOURS: i32 = 24 * 30;
+
+/// Longest window after fleet time a missed form-up ping is still sent, in minutes
+pub const MAX_FORMUP_CATCHUP_MINUTES: i32 = 60;
+
+/// Longest label shown after the UTC start time of a fleet
+pub const MAX_TIME_LABEL_LENGTH: usize = 32;
+
+/// Longest EVE downtime window, in minutes
+pub const MAX_DOWNTIME_MINUTES: u32 = 120;
+
+/// Largest embed colour Discord accepts
+pub const MAX_EMBED_COLOR: u32 = 0xffffff;
+
+/// Kind of Discord message whose embed colour is configured per guild
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EmbedKind {
+    /// Fleet creation ping
+    Creation,
+    /// Fleet message edited after an update
+    Update,
+    /// Fleet reminder
+    Reminder,
+    /// Fleet form-up ping
+    Formup,
+    /// Cancelled fleet message
+    Cancel,
+    /// Upcoming fleets list
+    List,
+}
+
+/// Display & behaviour settings of a guild
+#[derive(Debug, Clone, PartialEq)]
+pub struct GuildSettings {
+    /// Discord guild ID
+    pub guild_id: u64,
+    pub creation_color: u32,
+    pub update_color: u32,
+    pub reminder_color: u32,
+    pub formup_color: u32,
+    pub cancel_color: u32,
+    pub list_color: u32,
+    /// Label shown after the UTC start time of a fleet
+    pub time_label: String,
+    /// IANA timezone names the start time is also shown in
+    pub extra_timezones: Vec<String>,
+    /// Only list fleets starting within this many hours, None lists every upcoming fleet
+    pub list_lookahead_hours: Option<i32>,
+    /// Minutes after fleet time a missed form-up ping is still sent
+    pub formup_catchup_minutes: i32,
+    /// Ping format preselected when creating a new category
+    pub default_ping_format_id: Option<i32>,
+    /// Solar system ID fleets are staged from unless a category overrides it
+    pub staging_system_id: Option<u32>,
+    /// Daily EVE downtime window & how fleets scheduled during it are handled
+    pub downtime: DowntimeWindowDto,
+    /// Discord roles whose members can broadcast to every category of the guild
+    pub broadcast_role_ids: Vec<u64>,
+}
+
+impl GuildSettings {
+    /// Returns the default settings for a guild without stored settings
+    pub fn default_for(guild_id: u64) -> Self {
+        let defaults = GuildSettingsDto::default();
+
+        Self {
+            guild_id,
+            creation_color: defaults.creation_color,
+            update_color: defaults.update_color,
+            reminder_color: defaults.reminder_color,
+            formup_color: defaults.formup_color,
+            cancel_color: defaults.cancel_color,
+            list_color: defaults.list_color,
+            time_label: defaults.time_label,
+            extra_timezones: defaults.extra_timezones,
+            list_lookahead_hours: defaults.list_lookahead_hours,
+            formup_catchup_minutes: defaults.formup_catchup_minutes,
+            default_ping_format_id: defaults.default_ping_format_id,
+            staging_system_id: None,
+            downtime: defaults.downtime,
+            broadcast_role_ids: defaults.broadcast_role_ids,
+        }
+    }
+
+    /// Converts an entity model to the guild settings domain model
+    ///
+    /// # Arguments
+    /// - `entity` - The entity model from the database
+    ///
+    /// # Returns
+    /// - `Ok(GuildSettings)` - The converted domain model
+    /// - `Err(AppError::InternalError(ParseStringId))` - Failed to parse guild ID to u64
+    /// - `Err(AppError::DbErr(Custom))` - Unknown downtime policy stored
+    pub fn from_entity(entity: entity::guild_settings::Model) -> Result<Self, AppError> {
+        let downtime_policy = DowntimePolicy::parse(&entity.downtime_policy).ok_or_else(|| {
+            DbErr::Custom(format!(
+                "Unknown downtime policy: {}",
+                entity.downtime_policy
+            ))
+        })?;
+
+        Ok(Self {
+            guild_id: parse_u64_from_string(entity.guild_id)?,
+            creation_color: entity.creation_color as u32,
+            update_color: entity.update_color as u32,
+            reminder_color: entity.reminder_color as u32,
+            formup_color: entity.formup_color as u32,
+            cancel_color: entity.cancel_color as u32,
+            list_color: entity.list_color as u32,
+            time_label: entity.time_label,
+            extra_timezones: entity
+                .extra_timezones
+                .split(',')
+                .filter(|tz| !tz.is_empty())
+                .map(str::to_string)
+                .collect(),
+            list_lookahead_hours: entity.list_lookahead_hours,
+            formup_catchup_minutes: entity.formup_catchup_minutes,
+            default_ping_format_id: entity.default_ping_format_id,
+            staging_system_id: entity.staging_system_id.map(|id| id as u32),
+            downtime: DowntimeWindowDto {
+                start_minute: entity.downtime_start_minute as u32,
+                duration_minutes: entity.downtime_duration_minutes as u32,
+                policy: downtime_policy,
+            },
+            broadcast_role_ids: entity
+                .broadcast_role_ids
+                .split(',')
+                .filter(|id| !id.is_empty())
+                .map(|id| parse_u64_from_string(id.to_string()))
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
+    /// Returns the configured embed colour of a kind of message
+    pub fn embed_color(&self, kind: EmbedKind) -> u32 {
+        match kind {
+            EmbedKind::Creation => self.creation_color,
+            EmbedKind::Update => self.update_color,
+            EmbedKind::Reminder => self.reminder_color,
+            EmbedKind::Formup => self.formup_color,
+            EmbedKind::Cancel => self.cancel_color,
+            EmbedKind::List => self.list_color,
+        }
+    }
+
+    pub fn into_dto(self) -> GuildSettingsDto {
+        GuildSettingsDto {
+            creation_color: self.creation_color,
+            update_color: self.update_color,
+            reminder_color: self.reminder_color,
+            formup_color: self.formup_color,
+            cancel_color: self.cancel_color,
+            list_color: self.list_color,
+            time_label: self.time_label,
+            extra_timezones: self.extra_timezones,
+            list_lookahead_hours: self.list_lookahead_hours,
+            formup_catchup_minutes: self.formup_catchup_minutes,
+            default_ping_format_id: self.default_ping_format_id,
+            staging_system: self
+                .staging_system_id
+                .and_then(|id| eve_map().find_by_id(id))
+                .map(|system| system.name.clone()),
+            downtime: self.downtime,
+            broadcast_role_ids: self.broadcast_role_ids,
+        }
+    }
+}
+
+/// Parameters for storing the settings of a guild
+#[derive(Debug, Clone)]
+pub struct UpsertGuildSettingsParam {
+    pub guild_id: u64,
+    pub creation_color: u32,
+    pub update_color: u32,
+    pub reminder_color: u32,
+    pub formup_color: u32,
+    pub cancel_color: u32,
+    pub list_color: u32,
+    pub time_label: String,
+    pub extra_timezones: Vec<String>,
+    pub list_lookahead_hours: Option<i32>,
+    pub formup_catchup_minutes: i32,
+    pub default_ping_format_id: Option<i32>,
+    pub staging_system_id: Option<u32>,
+    pub downtime: DowntimeWindowDto,
+    pub broadcast_role_ids: Vec<u64>,
+}
+
+impl UpsertGuildSettingsParam {
+    /// Converts the settings DTO to upsert parameters
+    ///
+    /// # Returns
+    /// - `Ok(UpsertGuildSettingsParam)` - The converted parameters
+    /// - `Err(AppError::BadRequest(_))` - The staging system isn't a known solar system
+    pub fn from_dto(guild_id: u64, dto: GuildSettingsDto) -> Result<Self, AppError> {
+        Ok(Self {
+            guild_id,
+            creation_color: dto.creation_color,
+            update_color: dto.update_color,
+            reminder_color: dto.reminder_color,
+            formup_color: dto.formup_color,
+            cancel_color: dto.cancel_color,
+            list_color: dto.list_color,
+            time_label: dto.time_label.trim().to_string(),
+            extra_timezones: dto
+                .extra_timezones
+                .iter()
+                .map(|tz| tz.trim().to_string())
+                .filter(|tz| !tz.is_empty())
+                .collect(),
+            list_lookahead_hours: dto.list_lookahead_hours,
+            formup_catchup_minutes: dto.formup_catchup_minutes,
+            default_ping_format_id: dto.default_ping_format_id,
+            staging_system_id: resolve_staging_system_id(dto.staging_system.as_deref())?,
+            downtime: dto.downtime,
+            broadcast_role_ids: dto.broadcast_role_ids,
+        })
+    }
+}
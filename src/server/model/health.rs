@@ -0,0 +1,66 @@
+//! Health domain model for liveness & readiness probes.
+//!
+//! Captures the state of the application's dependencies at the time of a probe and
+//! determines whether the application is alive (able to serve requests) or ready
+//! (fully operational including the Discord bot and scheduler).
+
+use chrono::{DateTime, Duration, Utc};
+
+use crate::model::health::HealthDto;
+
+/// Maximum age of the scheduler's last tick before it's considered stalled.
+///
+/// The webhook delivery job runs every 15 seconds, so a healthy scheduler ticks well
+/// within this window.
+const SCHEDULER_TICK_MAX_AGE_SECONDS: i64 = 60;
+
+/// State of the application's dependencies at the time of a probe.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Health {
+    /// Whether the database responded to a ping.
+    pub database: bool,
+    /// Whether the Discord gateway connection is established.
+    pub discord_gateway: bool,
+    /// When a scheduler job last finished running, None if none has run yet.
+    pub scheduler_last_tick: Option<DateTime<Utc>>,
+    /// Time the probe was performed.
+    pub checked_at: DateTime<Utc>,
+}
+
+impl Health {
+    /// Returns whether a scheduler job has run within `SCHEDULER_TICK_MAX_AGE_SECONDS`.
+    pub fn scheduler_running(&self) -> bool {
+        self.scheduler_last_tick.is_some_and(|tick| {
+            self.checked_at - tick <= Duration::seconds(SCHEDULER_TICK_MAX_AGE_SECONDS)
+        })
+    }
+
+    /// Returns whether the application can serve requests, requiring only the database.
+    pub fn is_alive(&self) -> bool {
+        self.database
+    }
+
+    /// Returns whether the application is fully operational.
+    ///
+    /// Requires the database, the Discord gateway connection and a running scheduler.
+    pub fn is_ready(&self) -> bool {
+        self.database && self.discord_gateway && self.scheduler_running()
+    }
+
+    /// Converts the health state to a DTO for API responses.
+    ///
+    /// # Arguments
+    /// - `healthy` - Result of the probe (`is_alive` or `is_ready`) shown as the status
+    ///
+    /// # Returns
+    /// - `HealthDto` - The converted health DTO
+    pub fn into_dto(self, healthy: bool) -> HealthDto {
+        HealthDto {
+            status: if healthy { "ok" } else { "unavailable" }.to_string(),
+            database: self.database,
+            discord_gateway: self.discord_gateway,
+            scheduler: self.scheduler_running(),
+            scheduler_last_tick: self.scheduler_last_tick,
+        }
+    }
+}
@@ -6,12 +6,26 @@
 //! They provide type-safe representations with business logic separated from database
 //! and API concerns.
 
+pub mod broadcast;
 pub mod category;
 pub mod channel_fleet_list;
+pub mod channel_list_settings;
 pub mod discord;
+pub mod doctrine;
 pub mod fleet;
+pub mod fleet_event;
 pub mod fleet_message;
+pub mod fleet_relay;
+pub mod fleet_template;
+pub mod guild_settings;
+pub mod health;
 pub mod pagination;
+pub mod paste;
 pub mod ping_format;
 pub mod ping_group;
+pub mod retention;
+pub mod solar_system;
+pub mod stand_down;
+pub mod subscription;
 pub mod user;
+pub mod webhook;
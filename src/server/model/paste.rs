@@ -0,0 +1,70 @@
+//! Domain model for fleets parsed from pasted notifications & pings
+
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+
+use crate::model::{fleet::CreateFleetDto, paste::ParsedPasteDto};
+
+/// Fleet details parsed from a pasted notification or ping
+///
+/// Everything is optional as pastes are free form text, anything that couldn't be
+/// parsed is left for the FC to fill in the fleet creation modal.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ParsedPaste {
+    /// Fleet name given in the paste or generated from the structure & system
+    pub name: Option<String>,
+    /// Canonical name of the solar system found in the paste
+    pub solar_system: Option<String>,
+    /// Structure type found in the paste, e.g. "Astrahus"
+    pub structure_type: Option<String>,
+    /// Name of the structure if the paste quotes it
+    pub structure_name: Option<String>,
+    /// When the reinforcement timer or pinged fleet ends or starts
+    pub timer_end: Option<DateTime<Utc>>,
+    /// Map of field_id to value for ping format fields found in the paste
+    pub field_values: HashMap<i32, String>,
+    /// Lines of the paste that weren't used for a field or the name
+    pub description: Option<String>,
+    pub warnings: Vec<String>,
+}
+
+impl ParsedPaste {
+    /// Structure type followed by its quoted name if known, e.g. `Astrahus "Market"`
+    pub fn structure(&self) -> Option<String> {
+        let structure_type = self.structure_type.as_ref()?;
+
+        Some(match &self.structure_name {
+            Some(name) => format!("{} \"{}\"", structure_type, name),
+            None => structure_type.clone(),
+        })
+    }
+
+    /// Converts the parsed paste to a DTO prefilling the fleet creation modal
+    ///
+    /// # Arguments
+    /// - `category_id` - Category the paste was parsed for
+    /// - `commander_id` - Discord ID of the user who pasted, used as the default FC
+    pub fn into_dto(self, category_id: i32, commander_id: u64) -> ParsedPasteDto {
+        let structure = self.structure();
+
+        ParsedPasteDto {
+            fleet: CreateFleetDto {
+                category_id,
+                name: self.name.unwrap_or_default(),
+                commander_id,
+                fleet_time: self
+                    .timer_end
+                    .map(|time| time.format("%Y-%m-%d %H:%M").to_string())
+                    .unwrap_or_default(),
+                description: self.description,
+                field_values: self.field_values,
+                hidden: false,
+                disable_reminder: false,
+            },
+            solar_system: self.solar_system,
+            structure,
+            timer_end: self.timer_end,
+            warnings: self.warnings,
+        }
+    }
+}
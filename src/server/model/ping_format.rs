@@ -117,12 +117,14 @@ impl PingFormatField {
         let field_type = match entity.field_type.as_str() {
             "text" => PingFormatFieldType::Text,
             "bool" => PingFormatFieldType::Bool,
+            "solar_system" => PingFormatFieldType::SolarSystem,
+            "doctrine" => PingFormatFieldType::Doctrine,
             _ => {
                 return Err(AppError::InternalError(
                     InternalError::InvalidDatabaseValue {
                         table: "ping_format_field",
                         field: "field_type",
-                        expected: "text, bool",
+                        expected: "text, bool, solar_system, doctrine",
                         actual: entity.field_type,
                     },
                 ))
@@ -0,0 +1,109 @@
+//! Retention domain models for pruning historical data.
+//!
+//! Defines the configurable retention policy applied by the pruning job and the report
+//! of rows which were, or would be, removed when applying it.
+
+use chrono::{DateTime, Duration, Utc};
+use std::fmt;
+
+use crate::model::retention::RetentionPreviewDto;
+
+/// Default days before finished webhook deliveries are deleted.
+pub const DEFAULT_WEBHOOK_DELIVERY_DAYS: u32 = 30;
+
+/// Retention policy determining which historical rows are pruned.
+///
+/// Expired sessions are always pruned, every other age limit is configurable. Fleets are
+/// archived rather than deleted and fleet messages are only pruned when configured.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetentionPolicy {
+    /// Days after fleet time before a fleet is archived.
+    ///
+    /// None never archives fleets.
+    pub fleet_days: Option<u32>,
+    /// Hours after fleet time before the Discord message records of a fleet are deleted.
+    ///
+    /// None keeps fleet messages forever.
+    pub fleet_message_hours: Option<u32>,
+    /// Days after creation before delivered or failed webhook deliveries are deleted.
+    pub webhook_delivery_days: u32,
+}
+
+impl Default for RetentionPolicy {
+    fn default() -> Self {
+        Self {
+            fleet_days: None,
+            fleet_message_hours: None,
+            webhook_delivery_days: DEFAULT_WEBHOOK_DELIVERY_DAYS,
+        }
+    }
+}
+
+impl RetentionPolicy {
+    /// Returns the fleet time before which fleets are archived, None when never archived.
+    pub fn fleet_cutoff(&self, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        self.fleet_days
+            .map(|days| now - Duration::days(i64::from(days)))
+    }
+
+    /// Returns the fleet time before which fleet message records are deleted, None when
+    /// kept forever.
+    pub fn fleet_message_cutoff(&self, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        self.fleet_message_hours
+            .map(|hours| now - Duration::hours(i64::from(hours)))
+    }
+
+    /// Returns the creation time before which finished webhook deliveries are deleted.
+    pub fn webhook_delivery_cutoff(&self, now: DateTime<Utc>) -> DateTime<Utc> {
+        now - Duration::days(i64::from(self.webhook_delivery_days))
+    }
+}
+
+/// Number of rows per table pruned, or which would be pruned, by a retention policy.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RetentionReport {
+    /// Fleets archived for being older than the fleet retention period.
+    pub fleets: u64,
+    /// Discord message records of completed fleets.
+    pub fleet_messages: u64,
+    /// Finished webhook deliveries older than the delivery retention period.
+    pub webhook_deliveries: u64,
+    /// Expired login sessions.
+    pub sessions: u64,
+}
+
+impl RetentionReport {
+    /// Returns the total number of rows across all tables.
+    pub fn total(&self) -> u64 {
+        self.fleets + self.fleet_messages + self.webhook_deliveries + self.sessions
+    }
+
+    /// Converts the report to a preview DTO alongside the policy that produced it.
+    ///
+    /// # Arguments
+    /// - `policy` - Retention policy the report was calculated for
+    ///
+    /// # Returns
+    /// - `RetentionPreviewDto` - The converted preview DTO
+    pub fn into_preview_dto(self, policy: &RetentionPolicy) -> RetentionPreviewDto {
+        RetentionPreviewDto {
+            fleet_days: policy.fleet_days,
+            fleet_message_hours: policy.fleet_message_hours,
+            webhook_delivery_days: policy.webhook_delivery_days,
+            fleets: self.fleets,
+            fleet_messages: self.fleet_messages,
+            webhook_deliveries: self.webhook_deliveries,
+            sessions: self.sessions,
+        }
+    }
+}
+
+impl fmt::Display for RetentionReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} fleets archived, {} fleet messages, {} webhook deliveries, {} sessions",
+            self.fleets, self.fleet_messages, self.webhook_deliveries, self.sessions
+        )
+    }
+}
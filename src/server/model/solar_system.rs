@@ -0,0 +1,238 @@
+//! Domain model for solar systems of the bundled EVE static map
+//!
+//! Solar systems are read from the static map compiled into the binary rather than the
+//! database, see [`crate::server::eve_map`].
+
+use std::collections::HashMap;
+
+use crate::{
+    model::{
+        ping_format::PingFormatFieldType,
+        solar_system::{FleetRouteDto, SolarSystemDto},
+    },
+    server::{error::AppError, eve_map::eve_map, model::ping_format::PingFormatField},
+};
+
+/// Base URL of dotlan's solar system pages
+const DOTLAN_SYSTEM_URL: &str = "https://evemaps.dotlan.net/system";
+
+/// Base URL of dotlan's route planner
+const DOTLAN_ROUTE_URL: &str = "https://evemaps.dotlan.net/route";
+
+/// Resolves a staging system name submitted by an admin to its solar system ID
+///
+/// # Arguments
+/// - `name` - Solar system name, None or blank to clear the staging system
+///
+/// # Returns
+/// - `Ok(Some(u32))` - ID of the named solar system
+/// - `Ok(None)` - No staging system was given
+/// - `Err(AppError::BadRequest(_))` - The name isn't a known solar system
+pub fn resolve_staging_system_id(name: Option<&str>) -> Result<Option<u32>, AppError> {
+    let Some(name) = name.map(str::trim).filter(|name| !name.is_empty()) else {
+        return Ok(None);
+    };
+
+    eve_map()
+        .find_by_name(name)
+        .map(|system| Some(system.id))
+        .ok_or_else(|| AppError::BadRequest(format!("Unknown staging system: {}", name)))
+}
+
+/// The solar system domain model
+#[derive(Debug, Clone, PartialEq)]
+pub struct SolarSystem {
+    pub id: u32,
+    pub name: String,
+    pub constellation: String,
+    pub region: String,
+    /// True security status as stored in the static data export
+    pub security: f64,
+}
+
+impl SolarSystem {
+    /// Security status rounded to one decimal the way the game client displays it
+    ///
+    /// Systems with a true security status between 0.0 and 0.05 are shown as 0.1 in
+    /// game as they are low security rather than null security space.
+    pub fn display_security(&self) -> f64 {
+        if self.security > 0.0 && self.security < 0.05 {
+            0.1
+        } else {
+            (self.security * 10.0).round() / 10.0
+        }
+    }
+
+    /// Link to the system on dotlan's EVE maps
+    pub fn dotlan_url(&self) -> String {
+        format!("{}/{}", DOTLAN_SYSTEM_URL, self.name.replace(' ', "_"))
+    }
+
+    /// Formats the system for a Discord embed field
+    ///
+    /// # Returns
+    /// - `String` - Linked canonical name followed by security status & region,
+    ///   e.g. "[1DQ1-A](https://evemaps.dotlan.net/system/1DQ1-A) (-0.4) · Delve"
+    pub fn embed_value(&self) -> String {
+        format!(
+            "[{}]({}) ({:.1}) · {}",
+            self.name,
+            self.dotlan_url(),
+            self.display_security(),
+            self.region
+        )
+    }
+
+    /// Converts the solar system to a DTO for the API
+    pub fn to_dto(&self) -> SolarSystemDto {
+        SolarSystemDto {
+            id: self.id,
+            name: self.name.clone(),
+            constellation: self.constellation.clone(),
+            region: self.region.clone(),
+            security: self.display_security(),
+            dotlan_url: self.dotlan_url(),
+        }
+    }
+}
+
+/// Stargate route from a staging system to a fleet's form-up system
+///
+/// Only routes through the bundled static map exist, so the systems are borrowed from it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StagingRoute {
+    /// Systems along the route, starting with the staging system and ending with the
+    /// form-up system
+    pub systems: Vec<&'static SolarSystem>,
+}
+
+impl StagingRoute {
+    /// Finds the shortest stargate route between two systems of the static map
+    ///
+    /// # Returns
+    /// - `Some(StagingRoute)` - Route from staging to the form-up system
+    /// - `None` - Either system is unknown or they aren't connected by stargates
+    pub fn find(staging_id: u32, destination_id: u32) -> Option<Self> {
+        eve_map()
+            .route(staging_id, destination_id)
+            .map(|systems| Self { systems })
+    }
+
+    /// Finds the route from staging to a fleet's form-up system
+    ///
+    /// The form-up system is the value of the fleet's highest priority solar system field
+    /// that names a system of the static map.
+    ///
+    /// # Arguments
+    /// - `staging_id` - Staging system of the fleet's category, falling back to the guild's
+    /// - `fields` - Ping format fields of the fleet's category
+    /// - `field_values` - Map of field_id to value of the fleet
+    ///
+    /// # Returns
+    /// - `Some(StagingRoute)` - Route from staging to the form-up system
+    /// - `None` - No staging system is configured, the fleet has no known solar system
+    ///   or no stargate route connects them
+    pub fn for_fleet(
+        staging_id: Option<u32>,
+        fields: &[PingFormatField],
+        field_values: &HashMap<i32, String>,
+    ) -> Option<Self> {
+        let staging_id = staging_id?;
+        let destination = fields
+            .iter()
+            .filter(|field| field.field_type == PingFormatFieldType::SolarSystem)
+            .filter_map(|field| {
+                let value = field_values.get(&field.id)?;
+                eve_map()
+                    .find_by_name(value)
+                    .map(|system| (field.priority, system))
+            })
+            .min_by_key(|(priority, _)| *priority)
+            .map(|(_, system)| system)?;
+
+        Self::find(staging_id, destination.id)
+    }
+
+    pub fn staging(&self) -> &'static SolarSystem {
+        self.systems[0]
+    }
+
+    pub fn destination(&self) -> &'static SolarSystem {
+        self.systems[self.systems.len() - 1]
+    }
+
+    /// Number of stargate jumps along the route
+    pub fn jumps(&self) -> usize {
+        self.systems.len() - 1
+    }
+
+    /// Counts the jumps into high, low & null security systems
+    ///
+    /// The staging system itself isn't counted as no jump is made into it.
+    ///
+    /// # Returns
+    /// - `(usize, usize, usize)` - High, low & null security jumps
+    pub fn security_profile(&self) -> (usize, usize, usize) {
+        self.systems
+            .iter()
+            .skip(1)
+            .fold((0, 0, 0), |(high, low, null), system| {
+                let security = system.display_security();
+                if security >= 0.5 {
+                    (high + 1, low, null)
+                } else if security > 0.0 {
+                    (high, low + 1, null)
+                } else {
+                    (high, low, null + 1)
+                }
+            })
+    }
+
+    /// Link to the route on dotlan's EVE maps
+    pub fn dotlan_url(&self) -> String {
+        format!(
+            "{}/{}:{}",
+            DOTLAN_ROUTE_URL,
+            self.staging().name.replace(' ', "_"),
+            self.destination().name.replace(' ', "_")
+        )
+    }
+
+    /// Formats the route for a Discord embed field
+    ///
+    /// # Returns
+    /// - `String` - Jump count & security profile linked to dotlan, e.g.
+    ///   "[7 jumps](https://evemaps.dotlan.net/route/Jita:Amamake) from Jita · 3 HS / 4 LS / 0 NS"
+    pub fn embed_value(&self) -> String {
+        if self.jumps() == 0 {
+            return format!("Forms up in staging ({})", self.staging().name);
+        }
+
+        let (high, low, null) = self.security_profile();
+        format!(
+            "[{} {}]({}) from {} · {} HS / {} LS / {} NS",
+            self.jumps(),
+            if self.jumps() == 1 { "jump" } else { "jumps" },
+            self.dotlan_url(),
+            self.staging().name,
+            high,
+            low,
+            null
+        )
+    }
+
+    /// Converts the route to a DTO for the API
+    pub fn to_dto(&self) -> FleetRouteDto {
+        let (high_sec_jumps, low_sec_jumps, null_sec_jumps) = self.security_profile();
+
+        FleetRouteDto {
+            staging: self.staging().to_dto(),
+            destination: self.destination().to_dto(),
+            jumps: self.jumps(),
+            high_sec_jumps,
+            low_sec_jumps,
+            null_sec_jumps,
+            dotlan_url: self.dotlan_url(),
+        }
+    }
+}
@@ -0,0 +1,164 @@
+//! Domain & parameter models for emergency stand-downs
+//!
+//! A stand-down cancels every fleet of a guild or ping group within a time window and
+//! keeps a snapshot of each cancelled fleet so resuming the stand-down can put the
+//! fleets still ahead back on the schedule.
+
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+
+use crate::{
+    model::stand_down::StandDownDto,
+    server::{
+        error::AppError,
+        model::fleet::{CreateFleetParam, Fleet},
+        util::parse::parse_u64_from_string,
+    },
+};
+
+/// Stand-down of the fleets of a guild or ping group within a time window
+#[derive(Debug, Clone, PartialEq)]
+pub struct StandDown {
+    pub id: i32,
+    pub guild_id: u64,
+    /// Ping group whose categories were stood down, None for the whole guild
+    pub ping_group_id: Option<i32>,
+    /// Reason broadcast to the affected channels
+    pub reason: String,
+    pub window_start: DateTime<Utc>,
+    pub window_end: DateTime<Utc>,
+    /// Discord ID of the user who called the stand-down, None once the user was removed
+    pub created_by: Option<u64>,
+    pub created_at: DateTime<Utc>,
+    /// When the cancelled fleets were restored, None while the stand-down is active
+    pub resumed_at: Option<DateTime<Utc>>,
+}
+
+impl StandDown {
+    /// Converts an entity model to the stand-down domain model
+    ///
+    /// # Arguments
+    /// - `entity` - The entity model from the database
+    ///
+    /// # Returns
+    /// - `Ok(StandDown)` - The converted stand-down
+    /// - `Err(AppError::InternalError(ParseStringId))` - Failed to parse guild or user ID to u64
+    pub fn from_entity(entity: entity::stand_down::Model) -> Result<Self, AppError> {
+        let created_by = match entity.created_by {
+            Some(created_by) => Some(parse_u64_from_string(created_by)?),
+            None => None,
+        };
+
+        Ok(Self {
+            id: entity.id,
+            guild_id: parse_u64_from_string(entity.guild_id)?,
+            ping_group_id: entity.ping_group_id,
+            reason: entity.reason,
+            window_start: entity.window_start,
+            window_end: entity.window_end,
+            created_by,
+            created_at: entity.created_at,
+            resumed_at: entity.resumed_at,
+        })
+    }
+
+    /// Converts the stand-down to a DTO
+    ///
+    /// # Arguments
+    /// - `ping_group_name` - Name of the stood down ping group, if any
+    /// - `fleet_count` - Amount of fleets the stand-down cancelled
+    pub fn into_dto(self, ping_group_name: Option<String>, fleet_count: usize) -> StandDownDto {
+        StandDownDto {
+            id: self.id,
+            ping_group_id: self.ping_group_id,
+            ping_group_name,
+            reason: self.reason,
+            window_start: self.window_start,
+            window_end: self.window_end,
+            created_by: self.created_by,
+            created_at: self.created_at,
+            resumed_at: self.resumed_at,
+            fleet_count,
+        }
+    }
+}
+
+/// Snapshot of a fleet cancelled by a stand-down
+#[derive(Debug, Clone, PartialEq)]
+pub struct StandDownFleet {
+    pub id: i32,
+    pub stand_down_id: i32,
+    pub category_id: i32,
+    pub name: String,
+    /// Discord ID of the fleet commander
+    pub commander_id: u64,
+    pub fleet_time: DateTime<Utc>,
+    pub description: Option<String>,
+    /// Map of field_id to field value for custom ping format fields
+    pub field_values: HashMap<i32, String>,
+    pub hidden: bool,
+    pub disable_reminder: bool,
+}
+
+impl StandDownFleet {
+    /// Converts an entity model & its field values to a stand-down fleet domain model
+    ///
+    /// # Arguments
+    /// - `entity` - The snapshot entity model from the database
+    /// - `field_values` - The snapshot's field value entity models
+    ///
+    /// # Returns
+    /// - `Ok(StandDownFleet)` - The converted snapshot
+    /// - `Err(AppError::InternalError(ParseStringId))` - Failed to parse commander ID to u64
+    pub fn from_entity(
+        entity: entity::stand_down_fleet::Model,
+        field_values: Vec<entity::stand_down_fleet_field_value::Model>,
+    ) -> Result<Self, AppError> {
+        Ok(Self {
+            id: entity.id,
+            stand_down_id: entity.stand_down_id,
+            category_id: entity.category_id,
+            name: entity.name,
+            commander_id: parse_u64_from_string(entity.commander_id)?,
+            fleet_time: entity.fleet_time,
+            description: entity.description,
+            field_values: field_values
+                .into_iter()
+                .map(|value| (value.field_id, value.value))
+                .collect(),
+            hidden: entity.hidden,
+            disable_reminder: entity.disable_reminder,
+        })
+    }
+
+    /// Converts the snapshot into parameters recreating the cancelled fleet
+    pub fn into_create_param(self) -> CreateFleetParam {
+        CreateFleetParam {
+            category_id: self.category_id,
+            name: self.name,
+            commander_id: self.commander_id,
+            fleet_time: self.fleet_time,
+            description: self.description,
+            field_values: self.field_values,
+            hidden: self.hidden,
+            disable_reminder: self.disable_reminder,
+        }
+    }
+}
+
+/// Parameters for recording a new stand-down
+#[derive(Debug, Clone)]
+pub struct CreateStandDownParam {
+    pub guild_id: u64,
+    pub ping_group_id: Option<i32>,
+    pub reason: String,
+    pub window_start: DateTime<Utc>,
+    pub window_end: DateTime<Utc>,
+    /// Discord ID of the user calling the stand-down
+    pub created_by: u64,
+    /// Fleets cancelled by the stand-down with their field values, snapshotted for resuming
+    pub fleets: Vec<(Fleet, HashMap<i32, String>)>,
+    /// IDs of the fleets deleted along with recording the stand-down, including the
+    /// relayed copies of the cancelled fleets
+    pub deleted_fleet_ids: Vec<i32>,
+}
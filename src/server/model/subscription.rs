@@ -0,0 +1,171 @@
+//! Domain & parameter models for fleet subscriptions and direct message notifications
+//!
+//! Users subscribe to whole fleet categories or individual fleets and receive the
+//! notifications their preferences allow by Discord direct message. Defines the
+//! notification preference domain model, the kinds of direct message notifications,
+//! and the targets of the opt-out buttons attached to each direct message.
+
+use chrono::{DateTime, Utc};
+
+use crate::{
+    model::subscription::NotificationPreferenceDto,
+    server::{error::AppError, util::parse::parse_u64_from_string},
+};
+
+/// Longest personal reminder lead time a user can configure, in minutes
+pub const MAX_REMINDER_MINUTES: i32 = 24 * 60;
+
+/// Custom ID prefix of the opt-out button for category subscriptions
+const UNSUBSCRIBE_CATEGORY_PREFIX: &str = "unsubscribe_category:";
+/// Custom ID prefix of the opt-out button for fleet subscriptions
+const UNSUBSCRIBE_FLEET_PREFIX: &str = "unsubscribe_fleet:";
+
+/// Direct message notification preferences of a user
+///
+/// Users without stored preferences use the defaults: every notification type
+/// enabled and no personal reminder.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NotificationPreference {
+    /// Discord ID of the user
+    pub user_id: u64,
+    /// Whether fleet notifications are sent by direct message at all
+    pub dm_enabled: bool,
+    /// Notify when a fleet is created in a subscribed category
+    pub notify_created: bool,
+    /// Notify when the time of a subscribed fleet changes
+    pub notify_time_changed: bool,
+    /// Notify when a subscribed fleet is cancelled
+    pub notify_cancelled: bool,
+    /// Minutes before fleet time to send a personal reminder, None to disable
+    pub reminder_minutes: Option<i32>,
+}
+
+impl NotificationPreference {
+    /// Returns the default preferences for a user without stored preferences
+    pub fn default_for(user_id: u64) -> Self {
+        let defaults = NotificationPreferenceDto::default();
+
+        Self {
+            user_id,
+            dm_enabled: defaults.dm_enabled,
+            notify_created: defaults.notify_created,
+            notify_time_changed: defaults.notify_time_changed,
+            notify_cancelled: defaults.notify_cancelled,
+            reminder_minutes: defaults.reminder_minutes,
+        }
+    }
+
+    /// Converts an entity model to the notification preference domain model
+    ///
+    /// # Arguments
+    /// - `entity` - The entity model from the database
+    ///
+    /// # Returns
+    /// - `Ok(NotificationPreference)` - The converted domain model
+    /// - `Err(AppError::InternalError(ParseStringId))` - Failed to parse user ID to u64
+    pub fn from_entity(entity: entity::notification_preference::Model) -> Result<Self, AppError> {
+        Ok(Self {
+            user_id: parse_u64_from_string(entity.user_id)?,
+            dm_enabled: entity.dm_enabled,
+            notify_created: entity.notify_created,
+            notify_time_changed: entity.notify_time_changed,
+            notify_cancelled: entity.notify_cancelled,
+            reminder_minutes: entity.reminder_minutes,
+        })
+    }
+
+    /// Returns whether the user wants to receive the provided notification
+    pub fn allows(&self, notification: &DmNotification) -> bool {
+        if !self.dm_enabled {
+            return false;
+        }
+
+        match notification {
+            DmNotification::Created => self.notify_created,
+            DmNotification::Reminder => self.reminder_minutes.is_some(),
+            DmNotification::TimeChanged { .. } => self.notify_time_changed,
+            DmNotification::Cancelled => self.notify_cancelled,
+        }
+    }
+
+    pub fn into_dto(self) -> NotificationPreferenceDto {
+        NotificationPreferenceDto {
+            dm_enabled: self.dm_enabled,
+            notify_created: self.notify_created,
+            notify_time_changed: self.notify_time_changed,
+            notify_cancelled: self.notify_cancelled,
+            reminder_minutes: self.reminder_minutes,
+        }
+    }
+}
+
+/// Parameters for storing the notification preferences of a user
+#[derive(Debug, Clone)]
+pub struct UpsertNotificationPreferenceParam {
+    pub user_id: u64,
+    pub dm_enabled: bool,
+    pub notify_created: bool,
+    pub notify_time_changed: bool,
+    pub notify_cancelled: bool,
+    pub reminder_minutes: Option<i32>,
+}
+
+impl UpsertNotificationPreferenceParam {
+    pub fn from_dto(user_id: u64, dto: NotificationPreferenceDto) -> Self {
+        Self {
+            user_id,
+            dm_enabled: dto.dm_enabled,
+            notify_created: dto.notify_created,
+            notify_time_changed: dto.notify_time_changed,
+            notify_cancelled: dto.notify_cancelled,
+            reminder_minutes: dto.reminder_minutes,
+        }
+    }
+}
+
+/// Kind of fleet notification sent to subscribers by direct message
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DmNotification {
+    /// A fleet was created in a subscribed category
+    Created,
+    /// A subscribed fleet is within the user's personal reminder lead time
+    Reminder,
+    /// The time of a subscribed fleet changed
+    TimeChanged {
+        /// Fleet time before the change
+        previous_time: DateTime<Utc>,
+    },
+    /// A subscribed fleet was cancelled
+    Cancelled,
+}
+
+/// Subscription a direct message was sent for, used as the target of its opt-out button
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SubscriptionTarget {
+    Category(i32),
+    Fleet(i32),
+}
+
+impl SubscriptionTarget {
+    /// Returns the custom ID of the opt-out button for this subscription
+    pub fn unsubscribe_custom_id(&self) -> String {
+        match self {
+            SubscriptionTarget::Category(id) => format!("{UNSUBSCRIBE_CATEGORY_PREFIX}{id}"),
+            SubscriptionTarget::Fleet(id) => format!("{UNSUBSCRIBE_FLEET_PREFIX}{id}"),
+        }
+    }
+
+    /// Parses the subscription from an opt-out button custom ID
+    ///
+    /// Returns `None` for custom IDs of other components.
+    pub fn parse_unsubscribe_custom_id(custom_id: &str) -> Option<Self> {
+        if let Some(id) = custom_id.strip_prefix(UNSUBSCRIBE_CATEGORY_PREFIX) {
+            return id.parse().ok().map(SubscriptionTarget::Category);
+        }
+
+        custom_id
+            .strip_prefix(UNSUBSCRIBE_FLEET_PREFIX)
+            .and_then(|id| id.parse().ok())
+            .map(SubscriptionTarget::Fleet)
+    }
+}
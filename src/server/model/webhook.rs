@@ -0,0 +1,249 @@
+//! Domain & parameter models for outgoing webhook operations
+//!
+//! Defines the webhook & webhook delivery domain models, their parameter models,
+//! and provides methods to convert the domain models from entity and into Dtos.
+//! Subscribed events are stored as a comma separated list of event names.
+
+use chrono::{DateTime, Utc};
+
+use crate::{
+    model::webhook::{
+        CreateWebhookDto, UpdateWebhookDto, WebhookDeliveryDto, WebhookDeliveryStatus, WebhookDto,
+        WebhookEvent,
+    },
+    server::{
+        error::{internal::InternalError, AppError},
+        util::parse::parse_u64_from_string,
+    },
+};
+
+const WEBHOOK_EVENT_VALUES: &str =
+    "fleet.created, fleet.updated, fleet.cancelled, fleet.reminder, fleet.formup";
+
+/// The webhook domain model
+///
+/// Defines an outgoing webhook endpoint for a guild along with the secret used to
+/// sign payloads and the fleet events it is subscribed to
+#[derive(Debug, Clone)]
+pub struct Webhook {
+    pub id: i32,
+    pub guild_id: u64,
+    pub name: String,
+    pub url: String,
+    pub secret: String,
+    pub events: Vec<WebhookEvent>,
+    pub enabled: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Webhook {
+    /// Converts an entity model to the webhook domain model
+    ///
+    /// # Arguments
+    /// - `entity` - The entity model from the database
+    ///
+    /// # Returns
+    /// - `Ok(Webhook)` - The converted webhook domain model
+    /// - `Err(AppError::InternalError(ParseStringId))` - Failed to parse guild ID to u64
+    /// - `Err(AppError::InternalError(InvalidDatabaseValue))` - Unknown event name stored
+    pub fn from_entity(entity: entity::webhook::Model) -> Result<Self, AppError> {
+        let guild_id = parse_u64_from_string(entity.guild_id)?;
+        let events = parse_events(&entity.events)?;
+
+        Ok(Self {
+            id: entity.id,
+            guild_id,
+            name: entity.name,
+            url: entity.url,
+            secret: entity.secret,
+            events,
+            enabled: entity.enabled,
+            created_at: entity.created_at,
+        })
+    }
+
+    /// Returns whether the webhook should receive the provided event
+    pub fn is_subscribed(&self, event: WebhookEvent) -> bool {
+        self.enabled && self.events.contains(&event)
+    }
+
+    pub fn into_dto(self) -> WebhookDto {
+        WebhookDto {
+            id: self.id,
+            guild_id: self.guild_id,
+            name: self.name,
+            url: self.url,
+            secret: self.secret,
+            events: self.events,
+            enabled: self.enabled,
+            created_at: self.created_at,
+        }
+    }
+}
+
+/// The webhook delivery domain model
+///
+/// Tracks a single event payload queued for a webhook along with its retry state
+/// and the outcome of the most recent attempt
+#[derive(Debug, Clone)]
+pub struct WebhookDelivery {
+    pub id: i32,
+    pub webhook_id: i32,
+    pub event: WebhookEvent,
+    pub payload: String,
+    pub status: WebhookDeliveryStatus,
+    pub attempts: i32,
+    pub response_status: Option<i32>,
+    pub error: Option<String>,
+    pub next_attempt_at: Option<DateTime<Utc>>,
+    pub last_attempt_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl WebhookDelivery {
+    /// Converts an entity model to the webhook delivery domain model
+    ///
+    /// # Arguments
+    /// - `entity` - The entity model from the database
+    ///
+    /// # Returns
+    /// - `Ok(WebhookDelivery)` - The converted webhook delivery domain model
+    /// - `Err(AppError::InternalError(InvalidDatabaseValue))` - Unknown event or status stored
+    pub fn from_entity(entity: entity::webhook_delivery::Model) -> Result<Self, AppError> {
+        let event = WebhookEvent::parse(&entity.event).ok_or_else(|| {
+            InternalError::InvalidDatabaseValue {
+                table: "webhook_delivery",
+                field: "event",
+                expected: WEBHOOK_EVENT_VALUES,
+                actual: entity.event.clone(),
+            }
+        })?;
+        let status = WebhookDeliveryStatus::parse(&entity.status).ok_or_else(|| {
+            InternalError::InvalidDatabaseValue {
+                table: "webhook_delivery",
+                field: "status",
+                expected: "pending, succeeded, failed",
+                actual: entity.status.clone(),
+            }
+        })?;
+
+        Ok(Self {
+            id: entity.id,
+            webhook_id: entity.webhook_id,
+            event,
+            payload: entity.payload,
+            status,
+            attempts: entity.attempts,
+            response_status: entity.response_status,
+            error: entity.error,
+            next_attempt_at: entity.next_attempt_at,
+            last_attempt_at: entity.last_attempt_at,
+            created_at: entity.created_at,
+        })
+    }
+
+    pub fn into_dto(self) -> WebhookDeliveryDto {
+        WebhookDeliveryDto {
+            id: self.id,
+            webhook_id: self.webhook_id,
+            event: self.event,
+            status: self.status,
+            attempts: self.attempts,
+            response_status: self.response_status,
+            error: self.error,
+            next_attempt_at: self.next_attempt_at,
+            last_attempt_at: self.last_attempt_at,
+            created_at: self.created_at,
+        }
+    }
+}
+
+/// Parameters for creating a new webhook
+///
+/// The signing secret is generated by the service rather than provided by the client.
+#[derive(Debug, Clone)]
+pub struct CreateWebhookParam {
+    pub name: String,
+    pub url: String,
+    pub events: Vec<WebhookEvent>,
+    pub enabled: bool,
+}
+
+impl From<CreateWebhookDto> for CreateWebhookParam {
+    fn from(dto: CreateWebhookDto) -> Self {
+        Self {
+            name: dto.name,
+            url: dto.url,
+            events: dto.events,
+            enabled: dto.enabled,
+        }
+    }
+}
+
+/// Parameters for updating an existing webhook
+#[derive(Debug, Clone)]
+pub struct UpdateWebhookParam {
+    pub name: String,
+    pub url: String,
+    pub events: Vec<WebhookEvent>,
+    pub enabled: bool,
+}
+
+impl From<UpdateWebhookDto> for UpdateWebhookParam {
+    fn from(dto: UpdateWebhookDto) -> Self {
+        Self {
+            name: dto.name,
+            url: dto.url,
+            events: dto.events,
+            enabled: dto.enabled,
+        }
+    }
+}
+
+/// Parameters for queueing a delivery of an event payload to a webhook
+#[derive(Debug, Clone)]
+pub struct CreateWebhookDeliveryParam {
+    pub webhook_id: i32,
+    pub event: WebhookEvent,
+    /// Serialized JSON payload which is signed & sent as-is on every attempt
+    pub payload: String,
+}
+
+/// Result of a single delivery attempt used to update the delivery record
+#[derive(Debug, Clone)]
+pub struct WebhookDeliveryAttemptParam {
+    pub status: WebhookDeliveryStatus,
+    pub response_status: Option<i32>,
+    pub error: Option<String>,
+    /// When to retry the delivery, `None` once the delivery is finished
+    pub next_attempt_at: Option<DateTime<Utc>>,
+}
+
+/// Serializes subscribed events into the comma separated database representation
+pub fn join_events(events: &[WebhookEvent]) -> String {
+    events
+        .iter()
+        .map(|event| event.as_str())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Parses the comma separated database representation of subscribed events
+fn parse_events(value: &str) -> Result<Vec<WebhookEvent>, AppError> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|event| !event.is_empty())
+        .map(|event| {
+            WebhookEvent::parse(event).ok_or_else(|| {
+                InternalError::InvalidDatabaseValue {
+                    table: "webhook",
+                    field: "events",
+                    expected: WEBHOOK_EVENT_VALUES,
+                    actual: event.to_string(),
+                }
+                .into()
+            })
+        })
+        .collect()
+}
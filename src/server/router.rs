@@ -6,7 +6,7 @@
 
 use axum::{
     http::{header, Method},
-    Router,
+    middleware, Router,
 };
 use tower_governor::{
     governor::GovernorConfigBuilder, key_extractor::SmartIpKeyExtractor, GovernorLayer,
@@ -19,23 +19,56 @@ use utoipa_swagger_ui::SwaggerUi;
 use crate::{
     model::{
         api::{ErrorDto, SuccessDto},
+        broadcast::{
+            BroadcastChannelDto, BroadcastDto, BroadcastOverviewDto, BroadcastRoleDto,
+            CreateBroadcastDto,
+        },
         category::{
             CreateFleetCategoryDto, FleetCategoryAccessRoleDto, FleetCategoryChannelDto,
             FleetCategoryDetailsDto, FleetCategoryDto, FleetCategoryListItemDto,
             FleetCategoryPingRoleDto, PaginatedFleetCategoriesDto, UpdateFleetCategoryDto,
         },
+        channel_list_settings::{ChannelListSettingsDto, UpdateChannelListSettingsDto},
         discord::{
-            DiscordGuildChannelDto, DiscordGuildDto, DiscordGuildMemberDto, DiscordGuildRoleDto,
-            PaginatedDiscordGuildChannelsDto, PaginatedDiscordGuildRolesDto,
+            ChannelDiagnosticsDto, ChannelPermission, DiscordChannelKind, DiscordGuildChannelDto,
+            DiscordGuildDto, DiscordGuildMemberDto, DiscordGuildRoleDto, DiscordGuildStatus,
+            PaginatedDiscordGuildChannelsDto, PaginatedDiscordGuildRolesDto, RoleDiagnosticsDto,
+        },
+        doctrine::{
+            CreateDoctrineDto, DoctrineDto, DoctrineFitDto, PaginatedDoctrinesDto,
+            UpdateDoctrineDto,
+        },
+        fleet::{
+            BulkFleetAction, BulkFleetActionDto, CloneFleetDto, CreateFleetDto, FleetCalendarDto,
+            FleetCalendarItemDto, FleetDto, FleetEventDto, FleetGuildDto, FleetListItemDto,
+            GuildFleetListItemDto, PaginatedFleetsDto, PaginatedGuildFleetsDto, UpdateFleetDto,
+        },
+        fleet_relay::{
+            AcceptFleetRelayDto, CreateFleetRelayDto, FleetRelayDto, FleetRelayStatus,
+            FleetRelaysDto,
         },
-        fleet::{CreateFleetDto, FleetDto, FleetListItemDto, PaginatedFleetsDto, UpdateFleetDto},
+        fleet_template::{CreateFleetTemplateDto, FleetTemplateDto},
+        guild_settings::{DowntimePolicy, DowntimeWindowDto, GuildSettingsDto},
+        health::HealthDto,
+        paste::{ParsePasteDto, ParsedPasteDto},
         ping_format::{
             CreatePingFormatDto, CreatePingFormatFieldDto, PaginatedPingFormatsDto, PingFormatDto,
             PingFormatFieldDto, UpdatePingFormatDto, UpdatePingFormatFieldDto,
         },
+        retention::RetentionPreviewDto,
+        solar_system::{FleetRouteDto, SolarSystemDto},
+        stand_down::{
+            CreateStandDownDto, StandDownDto, StandDownOverviewDto, StandDownPingGroupDto,
+        },
+        subscription::{CategorySubscriptionDto, NotificationPreferenceDto, SubscriptionsDto},
         user::{PaginatedUsersDto, UserDto},
+        webhook::{
+            CreateWebhookDto, PaginatedWebhookDeliveriesDto, PaginatedWebhooksDto,
+            UpdateWebhookDto, WebhookDeliveryDto, WebhookDeliveryStatus, WebhookDto, WebhookEvent,
+            WebhookPayloadDto,
+        },
     },
-    server::{config::Config, controller, error::AppError, state::AppState},
+    server::{config::Config, controller, error::AppError, metrics, state::AppState},
 };
 
 /// Creates the main router with CORS configuration and OpenAPI documentation.
@@ -64,6 +97,13 @@ use crate::{
 /// ## User (`/api/user`)
 /// - `GET /api/user/guilds` - Get guilds available to current user
 /// - `GET /api/user/guilds/{guild_id}/manageable-categories` - Get manageable categories
+/// - `GET /api/user/notifications` - Get direct message notification preferences
+/// - `PUT /api/user/notifications` - Update direct message notification preferences
+/// - `GET /api/user/guilds/{guild_id}/subscriptions` - Get viewable categories & subscriptions
+/// - `PUT /api/user/guilds/{guild_id}/categories/{category_id}/subscription` - Subscribe to category
+/// - `DELETE /api/user/guilds/{guild_id}/categories/{category_id}/subscription` - Unsubscribe from category
+/// - `PUT /api/user/guilds/{guild_id}/fleets/{fleet_id}/subscription` - Subscribe to fleet
+/// - `DELETE /api/user/guilds/{guild_id}/fleets/{fleet_id}/subscription` - Unsubscribe from fleet
 ///
 /// ## Admin (`/api/admin`)
 /// - `GET /api/admin/bot/add` - Add bot to Discord server
@@ -71,10 +111,16 @@ use crate::{
 /// - `GET /api/admin/admins` - Get all admins
 /// - `POST /api/admin/admins/{user_id}` - Add admin
 /// - `DELETE /api/admin/admins/{user_id}` - Remove admin
+/// - `GET /api/admin/retention/preview` - Preview data retention pruning
 /// - `GET /api/admin/servers` - Get all Discord guilds
 /// - `GET /api/admin/servers/{guild_id}` - Get Discord guild by ID
+/// - `POST /api/admin/servers/{guild_id}/restore` - Restore guild the bot was added back to
+/// - `POST /api/admin/servers/{guild_id}/purge` - Purge data of guild the bot was removed from
 /// - `GET /api/admin/servers/{guild_id}/roles` - Get guild roles
+/// - `GET /api/admin/servers/{guild_id}/roles/{role_id}/diagnostics` - Check whether the bot can ping role
 /// - `GET /api/admin/servers/{guild_id}/channels` - Get guild channels
+/// - `GET /api/admin/servers/{guild_id}/channels/{channel_id}/diagnostics` - Check bot's channel permissions
+/// - `POST /api/admin/servers/{guild_id}/channels/{channel_id}/test-message` - Send test message
 ///
 /// ## Categories (`/api/admin/servers/{guild_id}/categories`)
 /// - `GET /api/admin/servers/{guild_id}/categories` - Get all categories
@@ -90,14 +136,72 @@ use crate::{
 /// - `DELETE /api/admin/servers/{guild_id}/formats/{format_id}` - Delete ping format
 /// - `GET /api/admin/servers/{guild_id}/formats/{format_id}/categories` - Get categories by format
 ///
-/// ## Fleets (`/api/guilds/{guild_id}`)
+/// ## Webhooks (`/api/admin/servers/{guild_id}/webhooks`)
+/// - `GET /api/admin/servers/{guild_id}/webhooks` - Get all webhooks
+/// - `POST /api/admin/servers/{guild_id}/webhooks` - Create webhook
+/// - `PUT /api/admin/servers/{guild_id}/webhooks/{id}` - Update webhook
+/// - `DELETE /api/admin/servers/{guild_id}/webhooks/{id}` - Delete webhook
+/// - `GET /api/admin/servers/{guild_id}/webhooks/{id}/deliveries` - Get webhook delivery log
+/// - `POST /api/admin/servers/{guild_id}/webhooks/{id}/deliveries/{delivery_id}/replay` - Replay delivery
+///
+/// ## Doctrines (`/api/admin/servers/{guild_id}/doctrines`)
+/// - `GET /api/admin/servers/{guild_id}/doctrines` - Get all doctrines (paginated)
+/// - `POST /api/admin/servers/{guild_id}/doctrine` - Create doctrine from EFT fits
+/// - `PUT /api/admin/servers/{guild_id}/doctrine/{id}` - Update doctrine & replace its fits
+/// - `DELETE /api/admin/servers/{guild_id}/doctrine/{id}` - Delete doctrine
+///
+/// ## Fleet Relays (`/api/admin/servers/{guild_id}/relays`)
+/// - `GET /api/admin/servers/{guild_id}/relays` - Get outgoing & incoming relays
+/// - `POST /api/admin/servers/{guild_id}/relays` - Request relay of a category to another guild
+/// - `POST /api/admin/servers/{guild_id}/relays/{id}/accept` - Accept incoming relay
+/// - `POST /api/admin/servers/{guild_id}/relays/{id}/decline` - Decline incoming relay
+/// - `DELETE /api/admin/servers/{guild_id}/relays/{id}` - Remove relay
+///
+/// ## Guild Settings (`/api/admin/servers/{guild_id}/settings`)
+/// - `GET /api/admin/servers/{guild_id}/settings` - Get display & behaviour settings
+/// - `PUT /api/admin/servers/{guild_id}/settings` - Update display & behaviour settings
+///
+/// ## Channel List Settings (`/api/admin/servers/{guild_id}/list-settings`)
+/// - `GET /api/admin/servers/{guild_id}/list-settings` - Get upcoming fleets list settings of each channel
+/// - `PUT /api/admin/servers/{guild_id}/list-settings/{channel_id}` - Update upcoming fleets list settings of a channel
+///
+/// ## Fleets (`/api/guilds/{guild_id}`, `/api/fleets`)
 /// - `GET /api/guilds/{guild_id}/members` - Get guild members
+/// - `GET /api/guilds/{guild_id}/doctrines` - Get the guild's doctrine library
 /// - `GET /api/guilds/{guild_id}/categories/{category_id}/details` - Get category details
 /// - `GET /api/guilds/{guild_id}/fleets` - Get all fleets
+/// - `GET /api/fleets` - Get fleets across all of the user's guilds
 /// - `POST /api/guilds/{guild_id}/fleets` - Create fleet
+/// - `GET /api/guilds/{guild_id}/fleets/events` - Stream live fleet changes (Server-Sent Events)
+/// - `GET /api/guilds/{guild_id}/fleets/calendar` - Get fleets within a time range
+/// - `POST /api/guilds/{guild_id}/fleets/bulk` - Shift, cancel or re-categorise several fleets
 /// - `GET /api/guilds/{guild_id}/fleets/{fleet_id}` - Get fleet by ID
 /// - `PUT /api/guilds/{guild_id}/fleets/{fleet_id}` - Update fleet
 /// - `DELETE /api/guilds/{guild_id}/fleets/{fleet_id}` - Delete fleet
+/// - `POST /api/guilds/{guild_id}/fleets/{fleet_id}/clone` - Copy fleet with a new time
+///
+/// ## Fleet Templates (`/api/guilds/{guild_id}/fleet-templates`)
+/// - `GET /api/guilds/{guild_id}/fleet-templates` - Get the user's own & shared templates
+/// - `POST /api/guilds/{guild_id}/fleet-templates` - Save fleet template
+/// - `DELETE /api/guilds/{guild_id}/fleet-templates/{template_id}` - Delete fleet template
+///
+/// ## Stand-downs (`/api/guilds/{guild_id}/stand-downs`)
+/// - `GET /api/guilds/{guild_id}/stand-downs` - Get stand-down scopes & recent stand-downs
+/// - `POST /api/guilds/{guild_id}/stand-downs` - Cancel all fleets of a scope within a window
+/// - `POST /api/guilds/{guild_id}/stand-downs/{stand_down_id}/resume` - Restore stood down fleets
+///
+/// ## Broadcasts (`/api/guilds/{guild_id}/broadcasts`)
+/// - `GET /api/guilds/{guild_id}/broadcasts` - Get broadcast targets & recent broadcasts
+/// - `POST /api/guilds/{guild_id}/broadcasts` - Post or schedule an ad-hoc broadcast
+/// - `DELETE /api/guilds/{guild_id}/broadcasts/{broadcast_id}` - Cancel a scheduled broadcast
+///
+/// ## Solar Systems (`/api/eve/solar-systems`)
+/// - `GET /api/eve/solar-systems` - Search solar systems of the bundled static map
+///
+/// ## Health
+/// - `GET /health` - Liveness probe (database)
+/// - `GET /ready` - Readiness probe (database, Discord gateway, scheduler)
+/// - `GET /metrics` - Prometheus metrics
 ///
 /// # OpenAPI Documentation
 /// The OpenAPI specification is available at `/api/docs/openapi.json` and includes:
@@ -143,7 +247,17 @@ pub fn router(config: &Config) -> Result<Router<AppState>, AppError> {
             (name = controller::category::CATEGORY_TAG, description = "Fleet category endpoints"),
             (name = controller::ping_format::PING_FORMAT_TAG, description = "Ping format endpoints"),
             (name = controller::fleet::FLEET_TAG, description = "Fleet endpoints"),
+            (name = controller::fleet_template::FLEET_TEMPLATE_TAG, description = "Fleet template endpoints"),
+            (name = controller::stand_down::STAND_DOWN_TAG, description = "Stand-down endpoints"),
+            (name = controller::broadcast::BROADCAST_TAG, description = "Broadcast endpoints"),
             (name = controller::discord::DISCORD_TAG, description = "Discord endpoints"),
+            (name = controller::webhook::WEBHOOK_TAG, description = "Webhook endpoints"),
+            (name = controller::doctrine::DOCTRINE_TAG, description = "Doctrine endpoints"),
+            (name = controller::fleet_relay::FLEET_RELAY_TAG, description = "Fleet relay endpoints"),
+            (name = controller::guild_settings::GUILD_SETTINGS_TAG, description = "Guild settings endpoints"),
+            (name = controller::channel_list_settings::CHANNEL_LIST_SETTINGS_TAG, description = "Channel list settings endpoints"),
+            (name = controller::solar_system::SOLAR_SYSTEM_TAG, description = "Solar system endpoints"),
+            (name = controller::health::HEALTH_TAG, description = "Health and metrics endpoints"),
         ),
         components(
             schemas(
@@ -152,9 +266,14 @@ pub fn router(config: &Config) -> Result<Router<AppState>, AppError> {
                 UserDto,
                 PaginatedUsersDto,
                 DiscordGuildDto,
+                DiscordGuildStatus,
                 DiscordGuildMemberDto,
                 DiscordGuildRoleDto,
+                DiscordChannelKind,
                 DiscordGuildChannelDto,
+                ChannelPermission,
+                ChannelDiagnosticsDto,
+                RoleDiagnosticsDto,
                 PaginatedDiscordGuildRolesDto,
                 PaginatedDiscordGuildChannelsDto,
                 FleetCategoryDto,
@@ -176,8 +295,61 @@ pub fn router(config: &Config) -> Result<Router<AppState>, AppError> {
                 FleetDto,
                 FleetListItemDto,
                 PaginatedFleetsDto,
+                FleetGuildDto,
+                GuildFleetListItemDto,
+                PaginatedGuildFleetsDto,
+                FleetEventDto,
+                FleetCalendarDto,
+                FleetCalendarItemDto,
                 CreateFleetDto,
                 UpdateFleetDto,
+                CloneFleetDto,
+                BulkFleetAction,
+                BulkFleetActionDto,
+                ParsePasteDto,
+                ParsedPasteDto,
+                FleetTemplateDto,
+                CreateFleetTemplateDto,
+                CreateStandDownDto,
+                StandDownDto,
+                StandDownPingGroupDto,
+                StandDownOverviewDto,
+                CreateBroadcastDto,
+                BroadcastChannelDto,
+                BroadcastRoleDto,
+                BroadcastDto,
+                BroadcastOverviewDto,
+                WebhookEvent,
+                WebhookDeliveryStatus,
+                WebhookDto,
+                CreateWebhookDto,
+                UpdateWebhookDto,
+                WebhookDeliveryDto,
+                WebhookPayloadDto,
+                PaginatedWebhooksDto,
+                PaginatedWebhookDeliveriesDto,
+                DoctrineDto,
+                DoctrineFitDto,
+                CreateDoctrineDto,
+                UpdateDoctrineDto,
+                PaginatedDoctrinesDto,
+                FleetRelayStatus,
+                FleetRelayDto,
+                FleetRelaysDto,
+                CreateFleetRelayDto,
+                AcceptFleetRelayDto,
+                GuildSettingsDto,
+                DowntimeWindowDto,
+                DowntimePolicy,
+                ChannelListSettingsDto,
+                UpdateChannelListSettingsDto,
+                SolarSystemDto,
+                FleetRouteDto,
+                HealthDto,
+                RetentionPreviewDto,
+                NotificationPreferenceDto,
+                CategorySubscriptionDto,
+                SubscriptionsDto,
             )
         )
     )]
@@ -193,7 +365,14 @@ pub fn router(config: &Config) -> Result<Router<AppState>, AppError> {
     // User routes
     let user_routes = OpenApiRouter::new()
         .routes(routes!(controller::user::get_user_guilds))
-        .routes(routes!(controller::user::get_user_manageable_categories));
+        .routes(routes!(controller::user::get_user_manageable_categories))
+        .routes(routes!(controller::user::get_notification_preferences))
+        .routes(routes!(controller::user::update_notification_preferences))
+        .routes(routes!(controller::user::get_subscriptions))
+        .routes(routes!(controller::user::subscribe_category))
+        .routes(routes!(controller::user::unsubscribe_category))
+        .routes(routes!(controller::user::subscribe_fleet))
+        .routes(routes!(controller::user::unsubscribe_fleet));
 
     // Admin routes
     let admin_routes = OpenApiRouter::new()
@@ -201,14 +380,22 @@ pub fn router(config: &Config) -> Result<Router<AppState>, AppError> {
         .routes(routes!(controller::admin::get_all_users))
         .routes(routes!(controller::admin::get_all_admins))
         .routes(routes!(controller::admin::add_admin))
-        .routes(routes!(controller::admin::remove_admin));
+        .routes(routes!(controller::admin::remove_admin))
+        .routes(routes!(controller::admin::get_retention_preview));
 
     // Discord routes
     let discord_routes = OpenApiRouter::new()
         .routes(routes!(controller::discord::get_all_discord_guilds))
         .routes(routes!(controller::discord::get_discord_guild_by_id))
+        .routes(routes!(controller::discord::restore_discord_guild))
+        .routes(routes!(controller::discord::purge_discord_guild))
         .routes(routes!(controller::discord::get_discord_guild_roles))
-        .routes(routes!(controller::discord::get_discord_guild_channels));
+        .routes(routes!(controller::discord::get_discord_guild_channels))
+        .routes(routes!(controller::discord::get_discord_role_diagnostics))
+        .routes(routes!(
+            controller::discord::get_discord_channel_diagnostics
+        ))
+        .routes(routes!(controller::discord::send_discord_test_message));
 
     // Category routes
     let category_routes = OpenApiRouter::new()
@@ -234,15 +421,90 @@ pub fn router(config: &Config) -> Result<Router<AppState>, AppError> {
         .routes(routes!(controller::ping_group::update_ping_group))
         .routes(routes!(controller::ping_group::delete_ping_group));
 
+    // Webhook routes
+    let webhook_routes = OpenApiRouter::new()
+        .routes(routes!(controller::webhook::create_webhook))
+        .routes(routes!(controller::webhook::get_paginated_webhooks))
+        .routes(routes!(controller::webhook::update_webhook))
+        .routes(routes!(controller::webhook::delete_webhook))
+        .routes(routes!(
+            controller::webhook::get_paginated_webhook_deliveries
+        ))
+        .routes(routes!(controller::webhook::replay_webhook_delivery));
+
+    // Doctrine routes
+    let doctrine_routes = OpenApiRouter::new()
+        .routes(routes!(controller::doctrine::create_doctrine))
+        .routes(routes!(controller::doctrine::get_paginated_doctrines))
+        .routes(routes!(controller::doctrine::update_doctrine))
+        .routes(routes!(controller::doctrine::delete_doctrine))
+        .routes(routes!(controller::doctrine::get_guild_doctrines));
+
+    // Fleet relay routes
+    let fleet_relay_routes = OpenApiRouter::new()
+        .routes(routes!(controller::fleet_relay::get_fleet_relays))
+        .routes(routes!(controller::fleet_relay::create_fleet_relay))
+        .routes(routes!(controller::fleet_relay::accept_fleet_relay))
+        .routes(routes!(controller::fleet_relay::decline_fleet_relay))
+        .routes(routes!(controller::fleet_relay::delete_fleet_relay));
+
+    // Guild settings routes
+    let guild_settings_routes = OpenApiRouter::new()
+        .routes(routes!(controller::guild_settings::get_guild_settings))
+        .routes(routes!(controller::guild_settings::update_guild_settings));
+
+    // Channel list settings routes
+    let channel_list_settings_routes = OpenApiRouter::new()
+        .routes(routes!(
+            controller::channel_list_settings::get_channel_list_settings
+        ))
+        .routes(routes!(
+            controller::channel_list_settings::update_channel_list_settings
+        ));
+
     // Fleet routes
     let fleet_routes = OpenApiRouter::new()
         .routes(routes!(controller::fleet::get_guild_members))
         .routes(routes!(controller::fleet::get_category_details))
+        .routes(routes!(controller::fleet::parse_paste))
         .routes(routes!(controller::fleet::get_fleets))
+        .routes(routes!(controller::fleet::get_user_fleets))
         .routes(routes!(controller::fleet::create_fleet))
+        .routes(routes!(controller::fleet::stream_fleet_events))
+        .routes(routes!(controller::fleet::get_fleet_calendar))
         .routes(routes!(controller::fleet::get_fleet))
         .routes(routes!(controller::fleet::update_fleet))
-        .routes(routes!(controller::fleet::delete_fleet));
+        .routes(routes!(controller::fleet::delete_fleet))
+        .routes(routes!(controller::fleet::clone_fleet))
+        .routes(routes!(controller::fleet::bulk_update_fleets));
+
+    // Fleet template routes
+    let fleet_template_routes = OpenApiRouter::new()
+        .routes(routes!(controller::fleet_template::create_fleet_template))
+        .routes(routes!(controller::fleet_template::get_fleet_templates))
+        .routes(routes!(controller::fleet_template::delete_fleet_template));
+
+    // Stand-down routes
+    let stand_down_routes = OpenApiRouter::new()
+        .routes(routes!(controller::stand_down::get_stand_downs))
+        .routes(routes!(controller::stand_down::create_stand_down))
+        .routes(routes!(controller::stand_down::resume_stand_down));
+
+    // Broadcast routes
+    let broadcast_routes = OpenApiRouter::new()
+        .routes(routes!(controller::broadcast::get_broadcasts))
+        .routes(routes!(controller::broadcast::create_broadcast))
+        .routes(routes!(controller::broadcast::cancel_broadcast));
+
+    // Solar system routes
+    let solar_system_routes =
+        OpenApiRouter::new().routes(routes!(controller::solar_system::search_solar_systems));
+
+    // Health & metrics routes
+    let health_routes = OpenApiRouter::new()
+        .routes(routes!(controller::health::health))
+        .routes(routes!(controller::health::ready))
+        .routes(routes!(controller::health::metrics));
 
     // Combine all routes
     let (api_router, api) = OpenApiRouter::with_openapi(ApiDoc::openapi())
@@ -253,7 +515,17 @@ pub fn router(config: &Config) -> Result<Router<AppState>, AppError> {
         .merge(category_routes)
         .merge(ping_format_routes)
         .merge(ping_group_routes)
+        .merge(webhook_routes)
+        .merge(doctrine_routes)
+        .merge(fleet_relay_routes)
+        .merge(guild_settings_routes)
+        .merge(channel_list_settings_routes)
         .merge(fleet_routes)
+        .merge(fleet_template_routes)
+        .merge(stand_down_routes)
+        .merge(broadcast_routes)
+        .merge(solar_system_routes)
+        .merge(health_routes)
         .split_for_parts();
 
     // Only serve Swagger UI in debug builds
@@ -289,8 +561,9 @@ pub fn router(config: &Config) -> Result<Router<AppState>, AppError> {
         .finish()
         .unwrap();
 
-    // Apply rate limiting and CORS layers to all routes
+    // Apply request metrics, rate limiting and CORS layers to all routes
     Ok(api_router
+        .layer(middleware::from_fn(metrics::track_request))
         .layer(GovernorLayer::new(governor_conf))
         .layer(cors))
 }
@@ -0,0 +1,32 @@
+use dioxus_logger::tracing;
+use sea_orm::DatabaseConnection;
+use serenity::http::Http;
+use std::sync::Arc;
+
+use crate::server::{error::AppError, service::broadcast::BroadcastService};
+
+/// Posts scheduled broadcasts whose time is due.
+///
+/// # Arguments
+/// - `db` - Database connection for reading & updating the broadcast log
+/// - `discord_http` - Discord HTTP client for posting the broadcasts
+/// - `app_url` - Application base URL linked in the broadcast embeds
+///
+/// # Returns
+/// - `Ok(())` - All due broadcasts attempted (failures are logged per broadcast)
+/// - `Err(DbErr(_))` - Database query failed
+pub async fn process_scheduled_broadcasts(
+    db: &DatabaseConnection,
+    discord_http: Arc<Http>,
+    app_url: String,
+) -> Result<(), AppError> {
+    let posted = BroadcastService::new(db, discord_http, app_url)
+        .process_due()
+        .await?;
+
+    if posted > 0 {
+        tracing::debug!("Posted {} scheduled broadcasts", posted);
+    }
+
+    Ok(())
+}
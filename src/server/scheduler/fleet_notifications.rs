@@ -3,37 +3,88 @@
 //! This module provides automated scheduling for fleet-related Discord notifications including:
 //! - Reminder notifications sent before fleet time based on category configuration
 //! - Form-up notifications sent when fleet time arrives
+//! - Personal reminders sent to subscribers by direct message at their configured lead time
+//! - Live updates to open timerboards when hidden fleets are revealed
 //! - Hourly updates to upcoming fleets list messages in configured channels
+//! - Delivery of queued outgoing webhook events
+//! - Daily pruning of data outside the retention policy
+//! - Posting of scheduled broadcasts once they are due
 //!
-//! The scheduler runs two primary jobs:
-//! 1. Every minute: Check for fleets needing reminders or form-up notifications
+//! The scheduler runs these primary jobs:
+//! 1. Every minute: Check for fleets needing reminders, form-up notifications or personal
+//!    reminders & publish fleets revealed since the previous run
 //! 2. Every hour: Update upcoming fleets list messages in all configured channels
+//! 3. Every 15 seconds: Deliver due webhook events, including retries
+//! 4. Every day: Prune fleets, fleet messages, webhook deliveries and sessions
+//! 5. Every minute: Post scheduled broadcasts which are due
+//!
+//! Notifications are paused for guilds the bot was removed from until an admin restores them.
 
 use chrono::{DateTime, Duration, Utc};
 use dioxus_logger::tracing;
-use sea_orm::{ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter};
+use sea_orm::{
+    sea_query::SelectStatement, ColumnTrait, Condition, DatabaseConnection, EntityTrait,
+    QueryFilter, QuerySelect, QueryTrait,
+};
 use serenity::http::Http;
-use std::{collections::HashMap, sync::Arc};
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
 use tokio_cron_scheduler::{Job, JobScheduler};
 
-use crate::server::{
-    error::AppError, model::fleet::Fleet, service::fleet_notification::FleetNotificationService,
+use crate::{
+    model::{
+        discord::DiscordGuildStatus,
+        guild_settings::{DowntimeWindowDto, GuildSettingsDto},
+        webhook::WebhookEvent,
+    },
+    server::{
+        data::guild_settings::GuildSettingsRepository,
+        error::AppError,
+        metrics::track_job,
+        model::{
+            fleet::Fleet,
+            guild_settings::{MAX_DOWNTIME_MINUTES, MAX_FORMUP_CATCHUP_MINUTES},
+            retention::RetentionPolicy,
+            subscription::MAX_REMINDER_MINUTES,
+        },
+        service::{
+            fleet::FleetService, fleet_event::FleetEventService,
+            fleet_notification::FleetNotificationService,
+        },
+    },
 };
 
-use super::sync::process_guild_sync;
+use super::{
+    broadcast::process_scheduled_broadcasts, retention::process_retention,
+    sync::process_guild_sync, webhook::process_webhook_deliveries,
+};
 
-/// Maximum age for sending form-up notifications.
+/// End of the window checked for revealed fleets by the previous run
+static LAST_REVEAL_CHECK: Mutex<Option<DateTime<Utc>>> = Mutex::new(None);
+
+/// Selects the IDs of fleet categories belonging to guilds with an active timerboard.
 ///
-/// Form-up notifications will only be sent if the fleet time is within this duration
-/// from the current time. This prevents sending form-ups for very old fleets that
-/// may have been missed during downtime.
-static FORMUP_MAX_AGE: i64 = 5;
+/// Used to pause notifications for guilds the bot was removed from.
+fn active_guild_category_ids() -> SelectStatement {
+    entity::prelude::FleetCategory::find()
+        .select_only()
+        .column(entity::fleet_category::Column::Id)
+        .inner_join(entity::prelude::DiscordGuild)
+        .filter(entity::discord_guild::Column::Status.eq(DiscordGuildStatus::Active.as_str()))
+        .into_query()
+}
 
 /// Starts the fleet notification scheduler.
 ///
-/// Initializes and starts two cron jobs:
-/// - Notifications job (every minute): Processes reminder and form-up notifications
+/// Initializes and starts the cron jobs:
+/// - Notifications job (every minute): Processes reminder, form-up and personal reminder notifications
 /// - List update job (every hour): Updates upcoming fleets list messages
+/// - Sync job (every hour): Re-syncs Discord guild data
+/// - Webhook job (every 15 seconds): Delivers due webhook events
+/// - Retention job (daily at 03:30 UTC): Prunes data outside the retention policy
+/// - Broadcast job (every minute): Posts scheduled broadcasts which are due
 ///
 /// The scheduler continues running until the application shuts down.
 ///
@@ -41,6 +92,8 @@ static FORMUP_MAX_AGE: i64 = 5;
 /// - `db` - Database connection for querying fleet and notification data
 /// - `discord_http` - Discord HTTP client for sending messages and embeds
 /// - `app_url` - Application base URL for generating fleet detail links in embeds
+/// - `http_client` - HTTP client for delivering webhook payloads
+/// - `retention` - Retention policy applied by the pruning job
 ///
 /// # Returns
 /// - `Ok(())` - Scheduler started successfully and is running
@@ -49,6 +102,8 @@ pub async fn start_scheduler(
     db: DatabaseConnection,
     discord_http: Arc<Http>,
     app_url: String,
+    http_client: reqwest::Client,
+    retention: RetentionPolicy,
 ) -> Result<(), AppError> {
     let scheduler = JobScheduler::new().await?;
 
@@ -63,12 +118,12 @@ pub async fn start_scheduler(
         let http = job_http.clone();
         let app_url = job_app_url.clone();
 
-        Box::pin(async move {
+        Box::pin(track_job("fleet_notifications", async move {
             tracing::trace!("Running fleet notifications job");
             if let Err(e) = process_fleet_notifications(&db, http, app_url).await {
                 tracing::error!("Error processing fleet notifications: {}", e);
             }
-        })
+        }))
     })?;
 
     scheduler.add(notifications_job).await?;
@@ -84,12 +139,12 @@ pub async fn start_scheduler(
         let http = list_http.clone();
         let app_url = list_app_url.clone();
 
-        Box::pin(async move {
+        Box::pin(track_job("upcoming_fleets_lists", async move {
             tracing::trace!("Running upcoming fleets list update job");
             if let Err(e) = process_upcoming_fleets_lists(&db, http, app_url).await {
                 tracing::error!("Error processing upcoming fleets lists: {}", e);
             }
-        })
+        }))
     })?;
 
     scheduler.add(list_job).await?;
@@ -101,15 +156,68 @@ pub async fn start_scheduler(
         let db = sync_db.clone();
         let http = sync_http.clone();
 
-        Box::pin(async move {
+        Box::pin(track_job("guild_sync", async move {
             tracing::trace!("Running periodic Discord sync update job");
             if let Err(e) = process_guild_sync(&db, http).await {
                 tracing::error!("Error processing periodic Discord guild sync: {}", e)
             }
-        })
+        }))
     })?;
 
     scheduler.add(sync_job).await?;
+
+    let webhook_db = db.clone();
+
+    // Schedule job to run every 15 seconds for webhook deliveries and retries
+    let webhook_job = Job::new_async("0/15 * * * * *", move |_uuid, _lock| {
+        let db = webhook_db.clone();
+        let http_client = http_client.clone();
+
+        Box::pin(track_job("webhook_deliveries", async move {
+            tracing::trace!("Running webhook delivery job");
+            if let Err(e) = process_webhook_deliveries(&db, &http_client).await {
+                tracing::error!("Error processing webhook deliveries: {}", e)
+            }
+        }))
+    })?;
+
+    scheduler.add(webhook_job).await?;
+
+    let retention_db = db.clone();
+
+    // Schedule job to run daily outside of peak hours for pruning historical data
+    let retention_job = Job::new_async("0 30 3 * * *", move |_uuid, _lock| {
+        let db = retention_db.clone();
+
+        Box::pin(track_job("retention", async move {
+            tracing::trace!("Running retention pruning job");
+            if let Err(e) = process_retention(&db, retention).await {
+                tracing::error!("Error processing retention pruning: {}", e)
+            }
+        }))
+    })?;
+
+    scheduler.add(retention_job).await?;
+
+    let broadcast_db = db.clone();
+    let broadcast_http = discord_http.clone();
+    let broadcast_app_url = app_url.clone();
+
+    // Schedule job to run every minute for scheduled broadcasts
+    let broadcast_job = Job::new_async("0 * * * * *", move |_uuid, _lock| {
+        let db = broadcast_db.clone();
+        let http = broadcast_http.clone();
+        let app_url = broadcast_app_url.clone();
+
+        Box::pin(track_job("broadcasts", async move {
+            tracing::trace!("Running scheduled broadcast job");
+            if let Err(e) = process_scheduled_broadcasts(&db, http, app_url).await {
+                tracing::error!("Error processing scheduled broadcasts: {}", e)
+            }
+        }))
+    })?;
+
+    scheduler.add(broadcast_job).await?;
     scheduler.start().await?;
 
     tracing::info!("Fleet notification scheduler started successfully");
@@ -122,6 +230,8 @@ pub async fn start_scheduler(
 /// This function is called every minute by the scheduler and delegates to:
 /// - `process_reminders` - Sends reminder notifications for fleets approaching their fleet time
 /// - `process_formups` - Sends form-up notifications for fleets at their fleet time
+/// - `process_dm_reminders` - Sends personal reminders to subscribers by direct message
+/// - `process_reveals` - Publishes live updates for hidden fleets revealed since the last run
 ///
 /// Errors from individual notification types are logged but don't prevent processing
 /// of other notification types.
@@ -146,10 +256,20 @@ async fn process_fleet_notifications(
     }
 
     // Process form-ups
-    if let Err(e) = process_formups(db, discord_http, app_url, now).await {
+    if let Err(e) = process_formups(db, discord_http.clone(), app_url.clone(), now).await {
         tracing::error!("Error processing form-ups: {}", e);
     }
 
+    // Process personal reminders of subscribers
+    if let Err(e) = process_dm_reminders(db, discord_http, app_url, now).await {
+        tracing::error!("Error processing direct message reminders: {}", e);
+    }
+
+    // Process hidden fleets revealed to category viewers
+    if let Err(e) = process_reveals(db, now).await {
+        tracing::error!("Error processing revealed fleets: {}", e);
+    }
+
     Ok(())
 }
 
@@ -184,6 +304,8 @@ async fn process_reminders(
     let fleets = entity::prelude::Fleet::find()
         .filter(entity::fleet::Column::Hidden.eq(false))
         .filter(entity::fleet::Column::DisableReminder.eq(false))
+        .filter(entity::fleet::Column::FleetTime.gt(now))
+        .filter(entity::fleet::Column::CategoryId.in_subquery(active_guild_category_ids()))
         .all(db)
         .await?;
 
@@ -253,6 +375,17 @@ async fn process_reminders(
                                 fleet.name,
                                 e
                             );
+                        } else if let Err(e) =
+                            FleetService::new(db, discord_http.clone(), app_url.clone())
+                                .emit_webhook_event(fleet.id, WebhookEvent::Reminder)
+                                .await
+                        {
+                            tracing::error!(
+                                "Failed to queue reminder webhooks for fleet {} ({}): {}",
+                                fleet.id,
+                                fleet.name,
+                                e
+                            );
                         }
                     }
                 }
@@ -268,7 +401,13 @@ async fn process_reminders(
 /// Queries the database for fleets that meet form-up criteria:
 /// - Fleet time has passed
 /// - No form-up notification has been sent yet
-/// - Fleet time is within the maximum age window (prevents very old fleets)
+/// - Fleet time is within the form-up catch-up window of the fleet's guild (prevents
+///   sending form-ups for very old fleets that may have been missed during downtime)
+///
+/// Form-ups are held back while the EVE downtime window of the fleet's guild is in
+/// progress as nobody can log in to form up. The catch-up window of fleets scheduled
+/// into downtime starts once downtime ends, so their form-up is sent right after it.
+/// Guilds ignoring downtime get their form-ups on time.
 ///
 /// For each qualifying fleet, sends a form-up notification via the notification service.
 ///
@@ -287,15 +426,80 @@ async fn process_formups(
     app_url: String,
     now: DateTime<Utc>,
 ) -> Result<(), AppError> {
+    // Only fleets within the widest catch-up window, extended by the longest downtime
+    // form-ups may be held back for, can need a form-up. This prevents sending form-ups
+    // for very old fleets and rescanning every past fleet on each run
+    let max_age = now
+        - Duration::minutes(i64::from(MAX_FORMUP_CATCHUP_MINUTES))
+        - Duration::minutes(i64::from(MAX_DOWNTIME_MINUTES));
+
     // Query fleets that might need form-up notifications
     let fleets = entity::prelude::Fleet::find()
         .filter(entity::fleet::Column::FleetTime.lte(now))
+        .filter(entity::fleet::Column::FleetTime.gte(max_age))
+        .filter(entity::fleet::Column::CategoryId.in_subquery(active_guild_category_ids()))
         .all(db)
         .await?;
 
     tracing::debug!("Checking {} fleets for form-ups", fleets.len());
 
+    // Resolve the catch-up & downtime windows configured by the guild of each fleet's
+    // category
+    let defaults = GuildSettingsDto::default();
+    let windows_by_guild: HashMap<String, (i32, DowntimeWindowDto)> =
+        GuildSettingsRepository::new(db)
+            .get_all()
+            .await?
+            .into_iter()
+            .map(|settings| {
+                (
+                    settings.guild_id.to_string(),
+                    (settings.formup_catchup_minutes, settings.downtime),
+                )
+            })
+            .collect();
+    let guild_by_category: HashMap<i32, String> = entity::prelude::FleetCategory::find()
+        .filter(
+            entity::fleet_category::Column::Id.is_in(
+                fleets
+                    .iter()
+                    .map(|fleet| fleet.category_id)
+                    .collect::<Vec<_>>(),
+            ),
+        )
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|category| (category.id, category.guild_id))
+        .collect();
+
     for fleet in fleets {
+        let (catchup_minutes, downtime) = guild_by_category
+            .get(&fleet.category_id)
+            .and_then(|guild_id| windows_by_guild.get(guild_id))
+            .copied()
+            .unwrap_or((defaults.formup_catchup_minutes, defaults.downtime));
+
+        if downtime.flags(now) {
+            tracing::debug!(
+                "Holding form-up for fleet {} ({}) until downtime ends",
+                fleet.id,
+                fleet.name
+            );
+            continue;
+        }
+
+        let due_from = if downtime.flags(fleet.fleet_time) {
+            downtime
+                .end_of(fleet.fleet_time)
+                .unwrap_or(fleet.fleet_time)
+        } else {
+            fleet.fleet_time
+        };
+        if due_from < now - Duration::minutes(i64::from(catchup_minutes)) {
+            continue;
+        }
+
         // Check if form-up already sent
         let existing_formup = entity::prelude::FleetMessage::find()
             .filter(entity::fleet_message::Column::FleetId.eq(fleet.id))
@@ -304,57 +508,54 @@ async fn process_formups(
             .await?;
 
         if existing_formup.is_none() {
-            // Only send form-up if fleet time is within the maximum age
-            // This prevents sending form-ups for very old fleets
-            let max_age = now - Duration::minutes(FORMUP_MAX_AGE);
+            tracing::debug!(
+                "Sending form-up for fleet {} ({}) scheduled for {}",
+                fleet.id,
+                fleet.name,
+                fleet.fleet_time
+            );
+
+            let notification_service =
+                FleetNotificationService::new(db, discord_http.clone(), app_url.clone());
+
+            // Get field values for the fleet
+            let field_values = entity::prelude::FleetFieldValue::find()
+                .filter(entity::fleet_field_value::Column::FleetId.eq(fleet.id))
+                .all(db)
+                .await?;
+
+            let field_values_map: HashMap<i32, String> = field_values
+                .into_iter()
+                .map(|fv| (fv.field_id, fv.value))
+                .collect();
 
-            if fleet.fleet_time >= max_age {
-                tracing::debug!(
-                    "Sending form-up for fleet {} ({}) scheduled for {}",
+            let Ok(fleet_param) = Fleet::from_entity(fleet.clone()) else {
+                tracing::error!(
+                    "Failed to convert fleet entity to Fleet domain model, skipping form-up notification for fleet ID: {}",
+                    fleet.id
+                );
+                continue;
+            };
+
+            if let Err(e) = notification_service
+                .post_fleet_formup(&fleet_param, &field_values_map)
+                .await
+            {
+                tracing::error!(
+                    "Failed to send form-up for fleet {} ({}): {}",
                     fleet.id,
                     fleet.name,
-                    fleet.fleet_time
+                    e
                 );
-
-                let notification_service =
-                    FleetNotificationService::new(db, discord_http.clone(), app_url.clone());
-
-                // Get field values for the fleet
-                let field_values = entity::prelude::FleetFieldValue::find()
-                    .filter(entity::fleet_field_value::Column::FleetId.eq(fleet.id))
-                    .all(db)
-                    .await?;
-
-                let field_values_map: HashMap<i32, String> = field_values
-                    .into_iter()
-                    .map(|fv| (fv.field_id, fv.value))
-                    .collect();
-
-                let Ok(fleet_param) = Fleet::from_entity(fleet.clone()) else {
-                    tracing::error!(
-                        "Failed to convert fleet entity to Fleet domain model, skipping form-up notification for fleet ID: {}",
-                        fleet.id
-                    );
-                    continue;
-                };
-
-                if let Err(e) = notification_service
-                    .post_fleet_formup(&fleet_param, &field_values_map)
-                    .await
-                {
-                    tracing::error!(
-                        "Failed to send form-up for fleet {} ({}): {}",
-                        fleet.id,
-                        fleet.name,
-                        e
-                    );
-                }
-            } else {
-                tracing::debug!(
-                    "Skipping form-up for old fleet {} ({}) from {}",
+            } else if let Err(e) = FleetService::new(db, discord_http.clone(), app_url.clone())
+                .emit_webhook_event(fleet.id, WebhookEvent::Formup)
+                .await
+            {
+                tracing::error!(
+                    "Failed to queue form-up webhooks for fleet {} ({}): {}",
                     fleet.id,
                     fleet.name,
-                    fleet.fleet_time
+                    e
                 );
             }
         }
@@ -388,8 +589,12 @@ async fn process_upcoming_fleets_lists(
 ) -> Result<(), AppError> {
     tracing::trace!("Processing upcoming fleets lists update");
 
-    // Get all unique channels that have fleet categories configured
+    // Get all unique channels that have fleet categories of active guilds configured
     let channels = entity::prelude::FleetCategoryChannel::find()
+        .filter(
+            entity::fleet_category_channel::Column::FleetCategoryId
+                .in_subquery(active_guild_category_ids()),
+        )
         .all(db)
         .await?;
 
@@ -440,3 +645,125 @@ async fn process_upcoming_fleets_lists(
 
     Ok(())
 }
+
+/// Processes personal reminders of fleet subscribers.
+///
+/// Queries upcoming fleets within the longest configurable reminder lead time that have
+/// at least one subscriber, either directly or through their category. For each fleet,
+/// the notification service sends the reminders which are due based on each subscriber's
+/// lead time and records them so they're only sent once.
+///
+/// # Arguments
+/// - `db` - Database connection for querying fleet and subscription data
+/// - `discord_http` - Discord HTTP client for sending direct messages
+/// - `app_url` - Application URL for embed links
+/// - `now` - Current UTC timestamp for calculating reminder times
+///
+/// # Returns
+/// - `Ok(())` - All reminders processed (individual send failures are logged)
+/// - `Err(DbErr(_))` - Database query failed
+async fn process_dm_reminders(
+    db: &DatabaseConnection,
+    discord_http: Arc<Http>,
+    app_url: String,
+    now: DateTime<Utc>,
+) -> Result<(), AppError> {
+    let max_lead = now + Duration::minutes(MAX_REMINDER_MINUTES as i64);
+
+    let fleets = entity::prelude::Fleet::find()
+        .filter(entity::fleet::Column::FleetTime.gt(now))
+        .filter(entity::fleet::Column::FleetTime.lte(max_lead))
+        .filter(entity::fleet::Column::CategoryId.in_subquery(active_guild_category_ids()))
+        .filter(
+            Condition::any()
+                .add(
+                    entity::fleet::Column::CategoryId.in_subquery(
+                        entity::prelude::CategorySubscription::find()
+                            .select_only()
+                            .column(entity::category_subscription::Column::CategoryId)
+                            .into_query(),
+                    ),
+                )
+                .add(
+                    entity::fleet::Column::Id.in_subquery(
+                        entity::prelude::FleetSubscription::find()
+                            .select_only()
+                            .column(entity::fleet_subscription::Column::FleetId)
+                            .into_query(),
+                    ),
+                ),
+        )
+        .all(db)
+        .await?;
+
+    tracing::debug!(
+        "Checking {} subscribed fleets for direct message reminders",
+        fleets.len()
+    );
+
+    let notification_service = FleetNotificationService::new(db, discord_http, app_url);
+
+    for fleet in fleets {
+        let field_values_map: HashMap<i32, String> = entity::prelude::FleetFieldValue::find()
+            .filter(entity::fleet_field_value::Column::FleetId.eq(fleet.id))
+            .all(db)
+            .await?
+            .into_iter()
+            .map(|fv| (fv.field_id, fv.value))
+            .collect();
+
+        let Ok(fleet_param) = Fleet::from_entity(fleet.clone()) else {
+            tracing::error!(
+                "Failed to convert fleet entity to Fleet domain model, skipping direct message reminders for fleet ID: {}",
+                fleet.id
+            );
+            continue;
+        };
+
+        if let Err(e) = notification_service
+            .post_due_dm_reminders(&fleet_param, &field_values_map, now)
+            .await
+        {
+            tracing::error!(
+                "Failed to send direct message reminders for fleet {} ({}): {}",
+                fleet.id,
+                fleet.name,
+                e
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Publishes live updates for hidden fleets revealed since the previous run.
+///
+/// Hidden fleets become visible to category viewers at their reveal time without being
+/// changed, so open timerboards are told to fetch them once it passes. The first run
+/// after startup checks the preceding minute.
+///
+/// # Arguments
+/// - `db` - Database connection for querying hidden fleets
+/// - `now` - Current UTC timestamp ending the checked window
+///
+/// # Returns
+/// - `Ok(())` - Updates published for every revealed fleet
+/// - `Err(AppError::Database(_))` - Database query failed, the window is retried next run
+async fn process_reveals(db: &DatabaseConnection, now: DateTime<Utc>) -> Result<(), AppError> {
+    let since = LAST_REVEAL_CHECK
+        .lock()
+        .map(|last| last.unwrap_or(now - Duration::minutes(1)))
+        .unwrap_or(now - Duration::minutes(1));
+
+    let revealed = FleetEventService::new(db)
+        .publish_reveals(since, now)
+        .await?;
+
+    tracing::debug!("Published {} revealed fleets", revealed);
+
+    if let Ok(mut last) = LAST_REVEAL_CHECK.lock() {
+        *last = Some(now);
+    }
+
+    Ok(())
+}
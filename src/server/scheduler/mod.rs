@@ -7,6 +7,12 @@
 //! Current schedulers include:
 //! - Fleet notifications - Sends automated Discord notifications for fleet reminders,
 //!   form-ups, and maintains upcoming fleets lists in configured channels
+//! - Webhook deliveries - Sends queued fleet lifecycle events to guild webhooks
+//! - Retention - Prunes historical data outside of the configured retention policy
+//! - Broadcasts - Posts scheduled ad-hoc broadcasts once they are due
 
+pub mod broadcast;
 pub mod fleet_notifications;
+pub mod retention;
 pub mod sync;
+pub mod webhook;
@@ -0,0 +1,32 @@
+use chrono::Utc;
+use dioxus_logger::tracing;
+use sea_orm::DatabaseConnection;
+
+use crate::server::{
+    error::AppError, model::retention::RetentionPolicy, service::retention::RetentionService,
+};
+
+/// Archives fleets and prunes fleet messages, webhook deliveries and sessions outside the
+/// retention policy.
+///
+/// # Arguments
+/// - `db` - Database connection to prune
+/// - `policy` - Retention policy determining which rows are archived or removed
+///
+/// # Returns
+/// - `Ok(())` - Pruning completed, the affected row counts are logged
+/// - `Err(DbErr(_))` - Database error during update or delete operations
+pub async fn process_retention(
+    db: &DatabaseConnection,
+    policy: RetentionPolicy,
+) -> Result<(), AppError> {
+    let report = RetentionService::new(db, policy).prune(Utc::now()).await?;
+
+    if report.total() > 0 {
+        tracing::info!("Retention pruning affected {}", report);
+    } else {
+        tracing::debug!("Retention pruning found nothing to remove");
+    }
+
+    Ok(())
+}
@@ -6,12 +6,16 @@ use sea_orm::{
 use serenity::http::Http;
 use std::sync::Arc;
 
-use crate::server::{
-    data::discord::DiscordGuildRepository,
-    error::AppError,
-    model::discord::guild::UpsertGuildParam,
-    service::discord::{
-        DiscordGuildChannelService, DiscordGuildMemberService, DiscordGuildRoleService,
+use crate::{
+    model::discord::DiscordGuildStatus,
+    server::{
+        data::discord::DiscordGuildRepository,
+        error::AppError,
+        metrics::discord_call,
+        model::discord::guild::UpsertGuildParam,
+        service::discord::{
+            DiscordGuildChannelService, DiscordGuildMemberService, DiscordGuildRoleService,
+        },
     },
 };
 
@@ -22,7 +26,12 @@ pub async fn process_guild_sync(
     db: &DatabaseConnection,
     discord_http: Arc<Http>,
 ) -> Result<(), AppError> {
-    let guild_count = entity::prelude::DiscordGuild::find().count(db).await?;
+    // Guilds the bot was removed from can't be fetched from Discord
+    let removed = DiscordGuildStatus::Removed.as_str();
+    let guild_count = entity::prelude::DiscordGuild::find()
+        .filter(entity::discord_guild::Column::Status.ne(removed))
+        .count(db)
+        .await?;
 
     // Space out sync evenly across the threshold window
     let limit = guild_count * (GUILD_SYNC_CHECK_INTERVAL / GUILD_SYNC_THRESHOLD) as u64;
@@ -31,6 +40,7 @@ pub async fn process_guild_sync(
     let sync_threshold = Utc::now() - Duration::minutes(GUILD_SYNC_THRESHOLD as i64);
     let guilds: Vec<String> = entity::prelude::DiscordGuild::find()
         .filter(entity::discord_guild::Column::LastSyncAt.lt(sync_threshold))
+        .filter(entity::discord_guild::Column::Status.ne(removed))
         .select_only()
         .column(entity::discord_guild::Column::GuildId)
         .limit(limit)
@@ -54,7 +64,7 @@ pub async fn process_guild_sync(
         // Fetch guild data from Discord API
         let guild_id_obj = serenity::model::id::GuildId::new(guild_id);
 
-        let guild = match discord_http.get_guild(guild_id_obj).await {
+        let guild = match discord_call("get_guild", discord_http.get_guild(guild_id_obj)).await {
             Ok(guild) => guild,
             Err(e) => {
                 tracing::error!("Failed to fetch guild information {}: {}", guild_id, e);
@@ -81,7 +91,12 @@ pub async fn process_guild_sync(
         tracing::trace!("Syncing guild {} ({})", guild.name, guild_id);
 
         // Fetch roles and channels
-        let guild_roles = match discord_http.get_guild_roles(guild_id_obj).await {
+        let guild_roles = match discord_call(
+            "get_guild_roles",
+            discord_http.get_guild_roles(guild_id_obj),
+        )
+        .await
+        {
             Ok(roles) => roles,
             Err(e) => {
                 tracing::error!("Failed to fetch roles for guild {}: {}", guild_id, e);
@@ -96,13 +111,14 @@ pub async fn process_guild_sync(
             tracing::error!("Failed to update guild {} roles: {:?}", guild_id, e);
         }
 
-        let guild_channels = match discord_http.get_channels(guild_id_obj).await {
-            Ok(channels) => channels,
-            Err(e) => {
-                tracing::error!("Failed to fetch channels for guild {}: {}", guild_id, e);
-                continue;
-            }
-        };
+        let guild_channels =
+            match discord_call("get_channels", discord_http.get_channels(guild_id_obj)).await {
+                Ok(channels) => channels,
+                Err(e) => {
+                    tracing::error!("Failed to fetch channels for guild {}: {}", guild_id, e);
+                    continue;
+                }
+            };
 
         // Sync all text channels in the guild
         let channel_service = DiscordGuildChannelService::new(db);
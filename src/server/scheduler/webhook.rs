@@ -0,0 +1,28 @@
+use dioxus_logger::tracing;
+use sea_orm::DatabaseConnection;
+
+use crate::server::{error::AppError, service::webhook::WebhookService};
+
+/// Delivers queued webhook events which are due, including retries of failed attempts.
+///
+/// # Arguments
+/// - `db` - Database connection for reading & updating the delivery log
+/// - `http_client` - HTTP client used to post payloads to webhook endpoints
+///
+/// # Returns
+/// - `Ok(())` - All due deliveries attempted (failures are recorded per delivery)
+/// - `Err(DbErr(_))` - Database query failed
+pub async fn process_webhook_deliveries(
+    db: &DatabaseConnection,
+    http_client: &reqwest::Client,
+) -> Result<(), AppError> {
+    let attempted = WebhookService::new(db)
+        .process_due_deliveries(http_client)
+        .await?;
+
+    if attempted > 0 {
+        tracing::debug!("Attempted {} webhook deliveries", attempted);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,506 @@
+//! Broadcast service for ad-hoc pings without a scheduled fleet.
+//!
+//! This module provides the `BroadcastService` for composing broadcasts, e.g. a call for
+//! reinforcements or a reminder about a structure timer. A broadcast posts an embed to
+//! chosen channels of the guild's fleet categories, pinging chosen ping roles of those
+//! categories, either immediately or once its scheduled time is due. Every broadcast is
+//! kept as a history log of the guild.
+//!
+//! Admins & members of the guild's broadcast roles can broadcast to every category,
+//! other users only to categories whose access roles allow them to broadcast.
+
+use chrono::{DateTime, Duration, NaiveDateTime, Utc};
+use dioxus_logger::tracing;
+use sea_orm::DatabaseConnection;
+use serenity::http::Http;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::{
+    model::broadcast::{BroadcastDto, BroadcastOverviewDto, CreateBroadcastDto},
+    server::{
+        data::{
+            broadcast::BroadcastRepository,
+            category::FleetCategoryRepository,
+            discord::{DiscordGuildChannelRepository, DiscordGuildRoleRepository},
+            guild_settings::GuildSettingsRepository,
+            user_category_permission::UserCategoryPermissionRepository,
+        },
+        error::{auth::AuthError, AppError},
+        model::broadcast::{
+            Broadcast, BroadcastChannel, BroadcastRole, BroadcastTargets, CreateBroadcastParam,
+        },
+        service::fleet_notification::FleetNotificationService,
+    },
+};
+
+/// Maximum length of a broadcast title, the limit of Discord embed titles
+const MAX_TITLE_LENGTH: usize = 256;
+
+/// Maximum length of a broadcast message, leaving room in the embed for its author
+const MAX_MESSAGE_LENGTH: usize = 4000;
+
+/// Furthest ahead a broadcast can be scheduled
+pub const MAX_SCHEDULE_DAYS: i64 = 30;
+
+/// Amount of recent broadcasts listed in the overview
+const RECENT_BROADCAST_LIMIT: u64 = 20;
+
+pub struct BroadcastService<'a> {
+    db: &'a DatabaseConnection,
+    discord_http: Arc<Http>,
+    app_url: String,
+}
+
+impl<'a> BroadcastService<'a> {
+    pub fn new(db: &'a DatabaseConnection, discord_http: Arc<Http>, app_url: String) -> Self {
+        Self {
+            db,
+            discord_http,
+            app_url,
+        }
+    }
+
+    /// Gets the channels & roles a user can broadcast to & the guild's recent broadcasts
+    ///
+    /// # Arguments
+    /// - `guild_id` - Discord ID of the guild
+    /// - `user_id` - Discord ID of the user
+    /// - `is_admin` - Whether the user is an admin, admins can broadcast to every category
+    ///
+    /// # Returns
+    /// - `Ok(BroadcastOverviewDto)` - The channels & ping roles of the categories the user
+    ///   can broadcast to, and the most recent broadcasts if the user can broadcast at all
+    /// - `Err(AppError::Database(_))` - Database operation failed
+    pub async fn get_overview(
+        &self,
+        guild_id: u64,
+        user_id: u64,
+        is_admin: bool,
+    ) -> Result<BroadcastOverviewDto, AppError> {
+        let targets = self.get_targets(guild_id, user_id, is_admin).await?;
+
+        if targets.channels.is_empty() {
+            return Ok(BroadcastOverviewDto {
+                channels: Vec::new(),
+                roles: Vec::new(),
+                broadcasts: Vec::new(),
+            });
+        }
+
+        let broadcasts = BroadcastRepository::new(self.db)
+            .get_recent_by_guild(guild_id, RECENT_BROADCAST_LIMIT)
+            .await?;
+        let (channels, roles) = self.get_display_names(guild_id).await?;
+
+        Ok(BroadcastOverviewDto {
+            channels: targets
+                .channels
+                .into_iter()
+                .map(BroadcastChannel::into_dto)
+                .collect(),
+            roles: targets
+                .roles
+                .into_iter()
+                .map(BroadcastRole::into_dto)
+                .collect(),
+            broadcasts: broadcasts
+                .into_iter()
+                .map(|broadcast| broadcast.into_dto(&channels, &roles))
+                .collect(),
+        })
+    }
+
+    /// Composes a broadcast, posting it immediately unless it is scheduled
+    ///
+    /// # Arguments
+    /// - `guild_id` - Discord ID of the guild
+    /// - `user_id` - Discord ID of the user composing the broadcast
+    /// - `is_admin` - Whether the user is an admin
+    /// - `dto` - Title, message, channels, roles & optional schedule of the broadcast
+    ///
+    /// # Returns
+    /// - `Ok(BroadcastDto)` - The recorded broadcast
+    /// - `Err(AppError::BadRequest(_))` - Invalid title, message, channels or schedule
+    /// - `Err(AppError::AuthErr(AccessDenied))` - User can't broadcast to a chosen channel
+    ///   or ping a chosen role
+    /// - `Err(AppError::Database(_))` - Database operation failed
+    pub async fn create(
+        &self,
+        guild_id: u64,
+        user_id: u64,
+        is_admin: bool,
+        dto: CreateBroadcastDto,
+    ) -> Result<BroadcastDto, AppError> {
+        let title = validate_text(&dto.title, "title", MAX_TITLE_LENGTH)?;
+        let message = validate_text(&dto.message, "message", MAX_MESSAGE_LENGTH)?;
+        let channel_ids = dedup_ids(dto.channel_ids);
+        let role_ids = dedup_ids(dto.role_ids);
+        let scheduled_at = parse_scheduled_at(dto.scheduled_at.as_deref(), Utc::now())?;
+
+        if channel_ids.is_empty() {
+            return Err(AppError::BadRequest(
+                "Choose at least one channel to broadcast to".to_string(),
+            ));
+        }
+
+        let targets = self.get_targets(guild_id, user_id, is_admin).await?;
+        if !targets.has_channels(&channel_ids) {
+            return Err(AppError::AuthErr(AuthError::AccessDenied(
+                user_id,
+                "User can't broadcast to every chosen channel".to_string(),
+            )));
+        }
+        if !targets.has_roles(&role_ids) {
+            return Err(AppError::AuthErr(AuthError::AccessDenied(
+                user_id,
+                "User can't ping every chosen role".to_string(),
+            )));
+        }
+
+        let repo = BroadcastRepository::new(self.db);
+        let mut broadcast = repo
+            .create(CreateBroadcastParam {
+                guild_id,
+                author_id: user_id,
+                title,
+                message,
+                channel_ids,
+                role_ids,
+                scheduled_at,
+            })
+            .await?;
+
+        if broadcast.scheduled_at.is_none() {
+            broadcast.posted_count = self.post(&broadcast).await?;
+        }
+
+        let (channels, roles) = self.get_display_names(guild_id).await?;
+        Ok(broadcast.into_dto(&channels, &roles))
+    }
+
+    /// Cancels a scheduled broadcast before it is posted
+    ///
+    /// # Arguments
+    /// - `guild_id` - Discord ID of the guild
+    /// - `id` - ID of the broadcast to cancel
+    /// - `user_id` - Discord ID of the user cancelling the broadcast
+    /// - `is_admin` - Whether the user is an admin
+    ///
+    /// # Returns
+    /// - `Ok(BroadcastDto)` - The cancelled broadcast
+    /// - `Err(AppError::BadRequest(_))` - The broadcast was already sent or cancelled
+    /// - `Err(AppError::NotFound(_))` - Broadcast not found in the guild
+    /// - `Err(AppError::AuthErr(AccessDenied))` - User neither composed the broadcast nor
+    ///   can broadcast to every one of its channels
+    /// - `Err(AppError::Database(_))` - Database operation failed
+    pub async fn cancel(
+        &self,
+        guild_id: u64,
+        id: i32,
+        user_id: u64,
+        is_admin: bool,
+    ) -> Result<BroadcastDto, AppError> {
+        let repo = BroadcastRepository::new(self.db);
+
+        let mut broadcast = repo
+            .find_by_id(guild_id, id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Broadcast not found".to_string()))?;
+
+        if !is_admin && broadcast.author_id != Some(user_id) {
+            let targets = self.get_targets(guild_id, user_id, is_admin).await?;
+            if !targets.has_channels(&broadcast.channel_ids) {
+                return Err(AppError::AuthErr(AuthError::AccessDenied(
+                    user_id,
+                    "User can't broadcast to every channel of the broadcast".to_string(),
+                )));
+            }
+        }
+
+        let now = Utc::now();
+        if !broadcast.is_pending() || !repo.mark_cancelled(id, now).await? {
+            return Err(AppError::BadRequest(
+                "Broadcast was already sent or cancelled".to_string(),
+            ));
+        }
+        broadcast.cancelled_at = Some(now);
+
+        let (channels, roles) = self.get_display_names(guild_id).await?;
+        Ok(broadcast.into_dto(&channels, &roles))
+    }
+
+    /// Posts every scheduled broadcast which is due
+    ///
+    /// Each broadcast is claimed before posting so it is posted once even if it was
+    /// cancelled concurrently. A broadcast failing to post doesn't stop the others.
+    ///
+    /// # Returns
+    /// - `Ok(usize)` - Amount of broadcasts posted
+    /// - `Err(AppError::Database(_))` - Failed to fetch or claim the due broadcasts
+    pub async fn process_due(&self) -> Result<usize, AppError> {
+        let repo = BroadcastRepository::new(self.db);
+        let now = Utc::now();
+
+        let mut processed = 0;
+        for mut broadcast in repo.get_due(now).await? {
+            if !repo.mark_sent(broadcast.id, now).await? {
+                continue;
+            }
+            broadcast.sent_at = Some(now);
+
+            match self.post(&broadcast).await {
+                Ok(_) => processed += 1,
+                Err(e) => {
+                    tracing::error!("Failed to post scheduled broadcast {}: {}", broadcast.id, e)
+                }
+            }
+        }
+
+        Ok(processed)
+    }
+
+    /// Posts a broadcast & records the amount of channels it was posted to
+    async fn post(&self, broadcast: &Broadcast) -> Result<i32, AppError> {
+        let posted =
+            FleetNotificationService::new(self.db, self.discord_http.clone(), self.app_url.clone())
+                .post_broadcast(broadcast)
+                .await? as i32;
+
+        BroadcastRepository::new(self.db)
+            .set_posted_count(broadcast.id, posted)
+            .await?;
+
+        Ok(posted)
+    }
+
+    /// Resolves the channels & ping roles a user can broadcast to
+    ///
+    /// # Arguments
+    /// - `guild_id` - Discord ID of the guild
+    /// - `user_id` - Discord ID of the user
+    /// - `is_admin` - Whether the user is an admin (bypasses permission checks)
+    ///
+    /// # Returns
+    /// - `Ok(BroadcastTargets)` - Channels & ping roles of every category of the guild for
+    ///   admins & members of a broadcast role, otherwise of the categories whose access
+    ///   roles allow the user to broadcast
+    /// - `Err(AppError::Database(_))` - Database operation failed
+    async fn get_targets(
+        &self,
+        guild_id: u64,
+        user_id: u64,
+        is_admin: bool,
+    ) -> Result<BroadcastTargets, AppError> {
+        let permission_repo = UserCategoryPermissionRepository::new(self.db);
+
+        let broadcast_role_ids = GuildSettingsRepository::new(self.db)
+            .get_by_guild(guild_id)
+            .await?
+            .broadcast_role_ids;
+        let can_broadcast_guild = is_admin
+            || permission_repo
+                .user_has_any_role(user_id, &broadcast_role_ids)
+                .await?;
+
+        let category_ids = if can_broadcast_guild {
+            FleetCategoryRepository::new(self.db)
+                .get_ids_by_guild(guild_id)
+                .await?
+        } else {
+            permission_repo
+                .get_broadcastable_category_ids_by_user(user_id, guild_id)
+                .await?
+        };
+
+        FleetNotificationService::new(self.db, self.discord_http.clone(), self.app_url.clone())
+            .get_broadcast_targets(&category_ids)
+            .await
+    }
+
+    /// Gets the names of the guild's channels & roles for listing broadcasts
+    async fn get_display_names(
+        &self,
+        guild_id: u64,
+    ) -> Result<(HashMap<u64, BroadcastChannel>, HashMap<u64, BroadcastRole>), AppError> {
+        let channels = DiscordGuildChannelRepository::new(self.db)
+            .get_by_guild_id(guild_id)
+            .await?
+            .into_iter()
+            .map(|channel| {
+                (
+                    channel.channel_id,
+                    BroadcastChannel {
+                        channel_id: channel.channel_id,
+                        name: channel.name,
+                    },
+                )
+            })
+            .collect();
+
+        let roles = DiscordGuildRoleRepository::new(self.db)
+            .get_by_guild_id(guild_id)
+            .await?
+            .into_iter()
+            .map(|role| {
+                (
+                    role.role_id,
+                    BroadcastRole {
+                        role_id: role.role_id,
+                        name: role.name,
+                        color: role.color,
+                    },
+                )
+            })
+            .collect();
+
+        Ok((channels, roles))
+    }
+}
+
+/// Trims a text of a broadcast & verifies it is given and not too long
+fn validate_text(value: &str, label: &str, max_length: usize) -> Result<String, AppError> {
+    let value = value.trim();
+
+    if value.is_empty() {
+        return Err(AppError::BadRequest(format!(
+            "A broadcast {} is required",
+            label
+        )));
+    }
+
+    if value.chars().count() > max_length {
+        return Err(AppError::BadRequest(format!(
+            "Broadcast {} can't be longer than {} characters",
+            label, max_length
+        )));
+    }
+
+    Ok(value.to_string())
+}
+
+/// Removes repeated IDs while keeping the order they were chosen in
+fn dedup_ids(ids: Vec<u64>) -> Vec<u64> {
+    let mut unique = Vec::with_capacity(ids.len());
+    for id in ids {
+        if !unique.contains(&id) {
+            unique.push(id);
+        }
+    }
+    unique
+}
+
+/// Parses & validates the scheduled time of a broadcast
+///
+/// # Arguments
+/// - `value` - Scheduled time in format "YYYY-MM-DD HH:MM", None or empty to post now
+/// - `now` - Current time
+///
+/// # Returns
+/// - `Ok(Some(DateTime<Utc>))` - The scheduled time
+/// - `Ok(None)` - The broadcast is posted immediately
+/// - `Err(AppError::BadRequest(_))` - Invalid format, the time isn't in the future or is
+///   more than `MAX_SCHEDULE_DAYS` ahead
+fn parse_scheduled_at(
+    value: Option<&str>,
+    now: DateTime<Utc>,
+) -> Result<Option<DateTime<Utc>>, AppError> {
+    let Some(value) = value.map(str::trim).filter(|value| !value.is_empty()) else {
+        return Ok(None);
+    };
+
+    let scheduled_at = NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M")
+        .map(|naive| naive.and_utc())
+        .map_err(|e| {
+            AppError::BadRequest(format!(
+                "Invalid scheduled time. Expected 'YYYY-MM-DD HH:MM', got '{}': {}",
+                value, e
+            ))
+        })?;
+
+    if scheduled_at <= now {
+        return Err(AppError::BadRequest(
+            "Scheduled time must be in the future".to_string(),
+        ));
+    }
+    if scheduled_at - now > Duration::days(MAX_SCHEDULE_DAYS) {
+        return Err(AppError::BadRequest(format!(
+            "Broadcasts can be scheduled at most {} days ahead",
+            MAX_SCHEDULE_DAYS
+        )));
+    }
+
+    Ok(Some(scheduled_at))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn now() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2026-01-15T18:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    /// Tests parsing the scheduled time of a broadcast.
+    ///
+    /// Expected: Ok(None) without a time, Ok with the parsed time for a future time
+    #[test]
+    fn parses_scheduled_at() {
+        assert_eq!(parse_scheduled_at(None, now()).unwrap(), None);
+        assert_eq!(parse_scheduled_at(Some("  "), now()).unwrap(), None);
+        assert_eq!(
+            parse_scheduled_at(Some("2026-01-15 20:30"), now()).unwrap(),
+            Some(now() + Duration::minutes(150))
+        );
+    }
+
+    /// Tests rejecting invalid scheduled times.
+    ///
+    /// Expected: Err(AppError::BadRequest) for a past time, one too far ahead & an
+    /// invalid format
+    #[test]
+    fn rejects_invalid_scheduled_at() {
+        let invalid = ["2026-01-15 17:59", "2026-03-01 12:00", "tomorrow"];
+
+        for value in invalid {
+            assert!(
+                matches!(
+                    parse_scheduled_at(Some(value), now()),
+                    Err(AppError::BadRequest(_))
+                ),
+                "scheduled time {} should be rejected",
+                value
+            );
+        }
+    }
+
+    /// Tests validating broadcast titles & messages.
+    ///
+    /// Expected: Ok with the trimmed text, Err(AppError::BadRequest) for an empty & an
+    /// overly long text
+    #[test]
+    fn validates_text() {
+        assert_eq!(
+            validate_text("  Reinforce the keepstar  ", "title", MAX_TITLE_LENGTH).unwrap(),
+            "Reinforce the keepstar"
+        );
+        assert!(matches!(
+            validate_text("   ", "title", MAX_TITLE_LENGTH),
+            Err(AppError::BadRequest(_))
+        ));
+        assert!(matches!(
+            validate_text(&"a".repeat(MAX_TITLE_LENGTH + 1), "title", MAX_TITLE_LENGTH),
+            Err(AppError::BadRequest(_))
+        ));
+    }
+
+    /// Tests removing repeated channel & role IDs.
+    ///
+    /// Expected: Each ID once in the order first chosen
+    #[test]
+    fn dedups_ids_in_order() {
+        assert_eq!(dedup_ids(vec![3, 1, 3, 2, 1]), vec![3, 1, 2]);
+    }
+}
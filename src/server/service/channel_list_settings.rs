@@ -0,0 +1,176 @@
+//! Channel list settings service for configuring upcoming fleets lists.
+//!
+//! This module provides the `ChannelListSettingsService` for reading and validating
+//! how admins configured the upcoming fleets list of each channel fleet categories post
+//! in. Saved settings are applied to the channel's list right away.
+
+use dioxus_logger::tracing;
+use sea_orm::DatabaseConnection;
+use serenity::http::Http;
+use std::sync::Arc;
+
+use crate::{
+    model::{
+        channel_list_settings::{ChannelListSettingsDto, UpdateChannelListSettingsDto},
+        discord::DiscordChannelKind,
+    },
+    server::{
+        data::{
+            category::FleetCategoryRepository,
+            channel_list_settings::ChannelListSettingsRepository,
+            discord::DiscordGuildChannelRepository,
+        },
+        error::AppError,
+        model::{
+            channel_list_settings::{
+                ChannelListSettings, UpsertChannelListSettingsParam, MAX_LIST_ENTRIES,
+            },
+            discord::DiscordGuildChannel,
+            guild_settings::MAX_LIST_LOOKAHEAD_HOURS,
+        },
+        service::fleet_notification::FleetNotificationService,
+    },
+};
+
+pub struct ChannelListSettingsService<'a> {
+    db: &'a DatabaseConnection,
+    discord_http: Arc<Http>,
+    app_url: String,
+}
+
+impl<'a> ChannelListSettingsService<'a> {
+    pub fn new(db: &'a DatabaseConnection, discord_http: Arc<Http>, app_url: String) -> Self {
+        Self {
+            db,
+            discord_http,
+            app_url,
+        }
+    }
+
+    /// Gets the list settings of every channel of a guild showing an upcoming fleets list
+    ///
+    /// Only text & announcement channels at least one category posts in are included,
+    /// ordered by their position in the guild.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<ChannelListSettingsDto>)` - Stored or default settings of each channel
+    /// - `Err(AppError::Database(_))` - Database operation failed
+    pub async fn list_by_guild(
+        &self,
+        guild_id: u64,
+    ) -> Result<Vec<ChannelListSettingsDto>, AppError> {
+        let category_repo = FleetCategoryRepository::new(self.db);
+        let settings_repo = ChannelListSettingsRepository::new(self.db);
+
+        let channels = DiscordGuildChannelRepository::new(self.db)
+            .get_by_guild_id(guild_id)
+            .await?;
+
+        let mut list = Vec::new();
+        for channel in channels {
+            if channel.kind == DiscordChannelKind::Forum
+                || category_repo
+                    .get_category_ids_by_channel(channel.channel_id)
+                    .await?
+                    .is_empty()
+            {
+                continue;
+            }
+
+            let settings = settings_repo.get_by_channel(channel.channel_id).await?;
+            list.push(build_dto(&channel, settings));
+        }
+
+        Ok(list)
+    }
+
+    /// Updates the list settings of a channel & refreshes its upcoming fleets list
+    ///
+    /// # Arguments
+    /// - `guild_id` - Discord ID of the guild the channel belongs to
+    /// - `channel_id` - Discord ID of the channel
+    /// - `dto` - The new settings
+    ///
+    /// # Returns
+    /// - `Ok(ChannelListSettingsDto)` - The stored settings
+    /// - `Err(AppError::NotFound(_))` - Channel not found in the guild
+    /// - `Err(AppError::BadRequest(_))` - Forum channel or a setting is outside of its
+    ///   allowed range
+    /// - `Err(AppError::Database(_))` - Database operation failed
+    pub async fn update(
+        &self,
+        guild_id: u64,
+        channel_id: u64,
+        dto: UpdateChannelListSettingsDto,
+    ) -> Result<ChannelListSettingsDto, AppError> {
+        let Some(channel) = DiscordGuildChannelRepository::new(self.db)
+            .find_by_channel_id(channel_id)
+            .await?
+            .filter(|channel| channel.guild_id == guild_id)
+        else {
+            return Err(AppError::NotFound("Channel not found".to_string()));
+        };
+
+        if channel.kind == DiscordChannelKind::Forum {
+            return Err(AppError::BadRequest(
+                "Forum channels don't show an upcoming fleets list".to_string(),
+            ));
+        }
+
+        let param = UpsertChannelListSettingsParam::from_dto(channel_id, dto);
+
+        if param
+            .lookahead_hours
+            .is_some_and(|hours| !(1..=MAX_LIST_LOOKAHEAD_HOURS).contains(&hours))
+        {
+            return Err(AppError::BadRequest(format!(
+                "List lookahead must be between 1 and {} hours",
+                MAX_LIST_LOOKAHEAD_HOURS
+            )));
+        }
+
+        if param
+            .max_entries
+            .is_some_and(|max| !(1..=MAX_LIST_ENTRIES).contains(&max))
+        {
+            return Err(AppError::BadRequest(format!(
+                "Maximum listed fleets must be between 1 and {}",
+                MAX_LIST_ENTRIES
+            )));
+        }
+
+        let settings = ChannelListSettingsRepository::new(self.db)
+            .upsert(param)
+            .await?;
+
+        if let Err(e) =
+            FleetNotificationService::new(self.db, self.discord_http.clone(), self.app_url.clone())
+                .update_upcoming_fleets_list(channel_id)
+                .await
+        {
+            tracing::warn!(
+                "Failed to update upcoming fleets list for channel {}: {}",
+                channel_id,
+                e
+            );
+        }
+
+        Ok(build_dto(&channel, settings))
+    }
+}
+
+/// Builds the list settings DTO of a channel with its name
+fn build_dto(
+    channel: &DiscordGuildChannel,
+    settings: ChannelListSettings,
+) -> ChannelListSettingsDto {
+    ChannelListSettingsDto {
+        channel_id: channel.channel_id,
+        channel_name: channel.name.clone(),
+        lookahead_hours: settings.lookahead_hours,
+        max_entries: settings.max_entries,
+        group_by_day: settings.group_by_day,
+        split_by_category: settings.split_by_category,
+        show_revealed_hidden: settings.show_revealed_hidden,
+    }
+}
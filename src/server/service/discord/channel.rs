@@ -1,23 +1,27 @@
 //! Discord guild channel service for managing guild channel synchronization.
 //!
 //! This module provides the `DiscordGuildChannelService` for synchronizing Discord guild
-//! text channels with the database. It filters for text channels only, handles bulk channel
-//! updates during bot startup, and provides paginated queries for channel data used in the UI.
+//! channels with the database. It filters for channels which can receive fleet notifications
+//! (text, announcement and forum channels), handles bulk channel updates during bot startup,
+//! and provides paginated queries for channel data used in the UI.
 
 use dioxus_logger::tracing;
 use sea_orm::DatabaseConnection;
-use serenity::all::{ChannelType, GuildChannel};
+use serenity::all::GuildChannel;
 
 use crate::{
     model::discord::{DiscordGuildChannelDto, PaginatedDiscordGuildChannelsDto},
-    server::{data::discord::DiscordGuildChannelRepository, error::AppError},
+    server::{
+        data::discord::DiscordGuildChannelRepository, error::AppError,
+        model::discord::channel::channel_kind,
+    },
 };
 
 /// Service for managing Discord guild channels.
 ///
-/// Provides methods for synchronizing text channel data from Discord's API to the database
-/// and querying channel information for display in the UI. Only tracks text channels,
-/// excluding voice channels, categories, forums, and other channel types. Acts as the
+/// Provides methods for synchronizing channel data from Discord's API to the database
+/// and querying channel information for display in the UI. Only tracks text, announcement
+/// and forum channels, excluding voice channels, categories, threads, and other channel types. Acts as the
 /// orchestration layer between Discord bot events and the channel repository.
 pub struct DiscordGuildChannelService<'a> {
     /// Database connection for repository operations.
@@ -38,9 +42,9 @@ impl<'a> DiscordGuildChannelService<'a> {
 
     /// Updates channels for a guild by syncing with Discord's current state.
     ///
-    /// Performs a complete sync of guild text channels by filtering for text channels only
-    /// (excluding voice, category, forum, etc.), deleting channels that no longer exist in
-    /// Discord, and upserting all current text channels. This ensures the database accurately
+    /// Performs a complete sync of guild channels by filtering for text, announcement and
+    /// forum channels (excluding voice, category, etc.), deleting channels that no longer
+    /// exist in Discord, and upserting all current supported channels. This ensures the database accurately
     /// reflects Discord's channel structure. Used during bot startup and when significant
     /// channel changes occur in the guild.
     ///
@@ -58,10 +62,10 @@ impl<'a> DiscordGuildChannelService<'a> {
     ) -> Result<(), AppError> {
         let channel_repo = DiscordGuildChannelRepository::new(self.db);
 
-        // Filter for text channels only
+        // Filter for channels which can receive fleet notifications
         let text_channels: Vec<&GuildChannel> = guild_channels
             .iter()
-            .filter(|channel| channel.kind == ChannelType::Text)
+            .filter(|channel| channel_kind(channel.kind).is_some())
             .collect();
 
         // Get existing channels from database
@@ -83,13 +87,13 @@ impl<'a> DiscordGuildChannelService<'a> {
             }
         }
 
-        // Upsert all current text channels
+        // Upsert all current supported channels
         for channel in &text_channels {
             channel_repo.upsert(guild_id, channel).await?;
         }
 
         tracing::info!(
-            "Updated {} text channels for guild {}",
+            "Updated {} channels for guild {}",
             text_channels.len(),
             guild_id
         );
@@ -99,7 +103,7 @@ impl<'a> DiscordGuildChannelService<'a> {
 
     /// Gets paginated channels for a guild.
     ///
-    /// Retrieves a paginated list of text channels for the specified guild, ordered by
+    /// Retrieves a paginated list of channels for the specified guild, ordered by
     /// position (Discord's channel order). Converts domain models to DTOs for API responses
     /// using offset-based pagination. Used for displaying channel lists in the UI and
     /// channel selection interfaces.
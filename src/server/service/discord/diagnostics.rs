@@ -0,0 +1,608 @@
+//! Discord permission diagnostics for configured ping channels and roles.
+//!
+//! This module provides the `DiscordDiagnosticsService` for checking whether the bot can
+//! actually deliver fleet notifications. Effective channel permissions are computed from
+//! the synced role permissions combined with the bot's roles and the channel's permission
+//! overwrites fetched from Discord, following Discord's permission hierarchy. Missing
+//! permissions otherwise only show up as failed sends in the logs.
+//!
+//! The admin panel diagnoses every configured channel and role of a category at once, so
+//! the bot's member record is cached per guild for a short time instead of being fetched
+//! from Discord for each of them.
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, LazyLock, Mutex},
+    time::{Duration, Instant},
+};
+
+use sea_orm::DatabaseConnection;
+use serenity::{
+    all::{
+        ChannelId, CreateEmbed, CreateEmbedFooter, CreateForumPost, CreateMessage, GuildId,
+        PermissionOverwrite, PermissionOverwriteType, Permissions,
+    },
+    http::Http,
+};
+
+use crate::{
+    model::discord::{
+        ChannelDiagnosticsDto, ChannelPermission, DiscordChannelKind, RoleDiagnosticsDto,
+    },
+    server::{
+        data::discord::{DiscordGuildChannelRepository, DiscordGuildRoleRepository},
+        error::AppError,
+        metrics::discord_call,
+        model::discord::DiscordGuildChannel,
+    },
+};
+
+/// Embed color of the test message.
+const TEST_MESSAGE_COLOR: u32 = 0x57F287;
+
+/// Permissions required in a channel to send fleet notifications, in the order reported.
+const REQUIRED_PERMISSIONS: [(ChannelPermission, Permissions); 4] = [
+    (ChannelPermission::ViewChannel, Permissions::VIEW_CHANNEL),
+    (ChannelPermission::SendMessages, Permissions::SEND_MESSAGES),
+    (ChannelPermission::EmbedLinks, Permissions::EMBED_LINKS),
+    (
+        ChannelPermission::MentionEveryone,
+        Permissions::MENTION_EVERYONE,
+    ),
+];
+
+/// Seconds the bot's member record fetched from Discord is reused for a guild.
+const BOT_MEMBER_CACHE_SECONDS: u64 = 30;
+
+/// Bot member records fetched from Discord, keyed by guild ID.
+static BOT_MEMBERS: LazyLock<Mutex<HashMap<u64, CachedBotMember>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// The bot's member record of a guild as fetched from Discord.
+#[derive(Clone)]
+struct CachedBotMember {
+    /// Discord user ID of the bot.
+    user_id: u64,
+    /// Role IDs assigned to the bot, excluding @everyone.
+    role_ids: Vec<u64>,
+    /// When the record was fetched.
+    fetched_at: Instant,
+}
+
+impl CachedBotMember {
+    /// Returns whether the record can still be used at the provided time.
+    fn is_fresh(&self, now: Instant) -> bool {
+        now.duration_since(self.fetched_at) < Duration::from_secs(BOT_MEMBER_CACHE_SECONDS)
+    }
+}
+
+/// The bot's identity within a guild.
+struct BotMember {
+    /// Discord user ID of the bot.
+    user_id: u64,
+    /// Guild-wide permissions granted by @everyone and the bot's roles.
+    base_permissions: Permissions,
+    /// Role IDs assigned to the bot, excluding @everyone.
+    role_ids: Vec<u64>,
+}
+
+/// Service for diagnosing whether the bot can deliver fleet notifications.
+///
+/// Combines synced role data from the database with the bot's member record and channel
+/// overwrites fetched from Discord to report missing permissions per channel and whether
+/// ping roles can be mentioned.
+pub struct DiscordDiagnosticsService<'a> {
+    /// Database connection for repository operations.
+    db: &'a DatabaseConnection,
+    /// Discord HTTP client for fetching the bot member and channel overwrites.
+    http: Arc<Http>,
+}
+
+impl<'a> DiscordDiagnosticsService<'a> {
+    /// Creates a new DiscordDiagnosticsService instance.
+    ///
+    /// # Arguments
+    /// - `db` - Reference to the database connection
+    /// - `http` - Arc-wrapped Discord HTTP client for API requests
+    ///
+    /// # Returns
+    /// - `DiscordDiagnosticsService` - New service instance
+    pub fn new(db: &'a DatabaseConnection, http: Arc<Http>) -> Self {
+        Self { db, http }
+    }
+
+    /// Checks the bot's effective permissions in a channel.
+    ///
+    /// Reports which of View Channel, Send Messages, Embed Links and Mention Everyone
+    /// the bot is missing. If the channel can't be fetched from Discord, such as when it
+    /// was deleted or the bot can't see it, the reason is reported instead.
+    ///
+    /// # Arguments
+    /// - `guild_id` - Discord guild ID the channel belongs to
+    /// - `channel_id` - Discord channel ID to check
+    ///
+    /// # Returns
+    /// - `Ok(ChannelDiagnosticsDto)` - Missing permissions or why they couldn't be checked
+    /// - `Err(AppError::NotFound)` - Channel isn't synced for the guild
+    /// - `Err(AppError::DiscordErr)` - Failed to fetch the bot's member record
+    /// - `Err(AppError::DbErr)` - Database error
+    pub async fn diagnose_channel(
+        &self,
+        guild_id: u64,
+        channel_id: u64,
+    ) -> Result<ChannelDiagnosticsDto, AppError> {
+        self.find_channel(guild_id, channel_id).await?;
+        let bot = self.bot_member(guild_id).await?;
+
+        let channel = match discord_call(
+            "get_channel",
+            self.http.get_channel(ChannelId::new(channel_id)),
+        )
+        .await
+        {
+            Ok(channel) => channel,
+            Err(e) => {
+                return Ok(ChannelDiagnosticsDto {
+                    channel_id,
+                    missing_permissions: Vec::new(),
+                    error: Some(format!("Failed to fetch the channel from Discord: {}", e)),
+                });
+            }
+        };
+        let overwrites = channel
+            .guild()
+            .map(|channel| channel.permission_overwrites)
+            .unwrap_or_default();
+
+        let permissions = channel_permissions(
+            bot.base_permissions,
+            guild_id,
+            bot.user_id,
+            &bot.role_ids,
+            &overwrites,
+        );
+
+        Ok(ChannelDiagnosticsDto {
+            channel_id,
+            missing_permissions: missing_permissions(permissions),
+            error: None,
+        })
+    }
+
+    /// Checks whether the bot can ping a role.
+    ///
+    /// A role can be pinged if it's mentionable or if the bot has the guild-wide
+    /// Mention Everyone permission. Channel overwrites are reported per channel by
+    /// `diagnose_channel`.
+    ///
+    /// # Arguments
+    /// - `guild_id` - Discord guild ID the role belongs to
+    /// - `role_id` - Discord role ID to check
+    ///
+    /// # Returns
+    /// - `Ok(RoleDiagnosticsDto)` - Whether the role is mentionable and the bot can bypass it
+    /// - `Err(AppError::NotFound)` - Role isn't synced for the guild
+    /// - `Err(AppError::DiscordErr)` - Failed to fetch the bot's member record
+    /// - `Err(AppError::DbErr)` - Database error
+    pub async fn diagnose_role(
+        &self,
+        guild_id: u64,
+        role_id: u64,
+    ) -> Result<RoleDiagnosticsDto, AppError> {
+        let role = DiscordGuildRoleRepository::new(self.db)
+            .get_by_guild_id(guild_id)
+            .await?
+            .into_iter()
+            .find(|role| role.role_id == role_id)
+            .ok_or_else(|| AppError::NotFound("Role not found".to_string()))?;
+
+        let bot = self.bot_member(guild_id).await?;
+
+        Ok(RoleDiagnosticsDto {
+            role_id,
+            mentionable: role.mentionable,
+            bot_can_mention_everyone: bot.base_permissions.contains(Permissions::MENTION_EVERYONE),
+        })
+    }
+
+    /// Sends a test message to a channel.
+    ///
+    /// Posts a short embed without any mentions so admins can verify the bot can post
+    /// in the channel. Forum channels receive a new forum post.
+    ///
+    /// # Arguments
+    /// - `guild_id` - Discord guild ID the channel belongs to
+    /// - `channel_id` - Discord channel ID to send the message to
+    ///
+    /// # Returns
+    /// - `Ok(())` - Test message sent
+    /// - `Err(AppError::NotFound)` - Channel isn't synced for the guild
+    /// - `Err(AppError::BadRequest)` - Discord rejected the message
+    /// - `Err(AppError::DbErr)` - Database error
+    pub async fn send_test_message(&self, guild_id: u64, channel_id: u64) -> Result<(), AppError> {
+        let channel = self.find_channel(guild_id, channel_id).await?;
+
+        let embed = CreateEmbed::new()
+            .title("Test message")
+            .description(
+                "The timerboard can post fleet notifications in this channel. \
+                 You can delete this message.",
+            )
+            .color(TEST_MESSAGE_COLOR)
+            .footer(CreateEmbedFooter::new(
+                "Sent from the timerboard admin panel",
+            ));
+        let message = CreateMessage::new().embed(embed);
+        let discord_channel = ChannelId::new(channel_id);
+
+        let result = match channel.kind {
+            DiscordChannelKind::Forum => discord_call(
+                "create_forum_post",
+                discord_channel.create_forum_post(
+                    &self.http,
+                    CreateForumPost::new("Timerboard test message", message),
+                ),
+            )
+            .await
+            .map(|_| ()),
+            DiscordChannelKind::Text | DiscordChannelKind::Announcement => discord_call(
+                "send_message",
+                discord_channel.send_message(&self.http, message),
+            )
+            .await
+            .map(|_| ()),
+        };
+
+        result
+            .map_err(|e| AppError::BadRequest(format!("Discord rejected the test message: {}", e)))
+    }
+
+    /// Finds a synced channel, verifying it belongs to the guild.
+    async fn find_channel(
+        &self,
+        guild_id: u64,
+        channel_id: u64,
+    ) -> Result<DiscordGuildChannel, AppError> {
+        DiscordGuildChannelRepository::new(self.db)
+            .find_by_channel_id(channel_id)
+            .await?
+            .filter(|channel| channel.guild_id == guild_id)
+            .ok_or_else(|| AppError::NotFound("Channel not found".to_string()))
+    }
+
+    /// Resolves the bot's roles and computes its guild-wide permissions from the synced
+    /// role permissions.
+    ///
+    /// The bot's roles are fetched from Discord at most once per guild within
+    /// `BOT_MEMBER_CACHE_SECONDS`, permissions are always computed from the current sync.
+    async fn bot_member(&self, guild_id: u64) -> Result<BotMember, AppError> {
+        let member = self.cached_bot_member(guild_id).await?;
+
+        let role_permissions: HashMap<u64, u64> = DiscordGuildRoleRepository::new(self.db)
+            .get_by_guild_id(guild_id)
+            .await?
+            .into_iter()
+            .map(|role| (role.role_id, role.permissions))
+            .collect();
+
+        Ok(BotMember {
+            user_id: member.user_id,
+            base_permissions: base_permissions(guild_id, &role_permissions, &member.role_ids),
+            role_ids: member.role_ids,
+        })
+    }
+
+    /// Returns the bot's cached member record of a guild, fetching it from Discord when
+    /// missing or expired.
+    async fn cached_bot_member(&self, guild_id: u64) -> Result<CachedBotMember, AppError> {
+        if let Some(member) = BOT_MEMBERS
+            .lock()
+            .ok()
+            .and_then(|members| members.get(&guild_id).cloned())
+            .filter(|member| member.is_fresh(Instant::now()))
+        {
+            return Ok(member);
+        }
+
+        let current_user = discord_call("get_current_user", self.http.get_current_user()).await?;
+        let member = discord_call(
+            "get_member",
+            self.http
+                .get_member(GuildId::new(guild_id), current_user.id),
+        )
+        .await?;
+
+        let member = CachedBotMember {
+            user_id: current_user.id.get(),
+            role_ids: member.roles.iter().map(|role| role.get()).collect(),
+            fetched_at: Instant::now(),
+        };
+
+        if let Ok(mut members) = BOT_MEMBERS.lock() {
+            members.retain(|_, cached| cached.is_fresh(member.fetched_at));
+            members.insert(guild_id, member.clone());
+        }
+
+        Ok(member)
+    }
+}
+
+/// Computes guild-wide permissions from @everyone and the member's roles.
+///
+/// The @everyone role shares its ID with the guild. Administrator grants every
+/// permission.
+fn base_permissions(
+    guild_id: u64,
+    role_permissions: &HashMap<u64, u64>,
+    role_ids: &[u64],
+) -> Permissions {
+    let bits = std::iter::once(&guild_id)
+        .chain(role_ids)
+        .filter_map(|role_id| role_permissions.get(role_id))
+        .fold(0, |bits, permissions| bits | permissions);
+    let permissions = Permissions::from_bits_truncate(bits);
+
+    if permissions.contains(Permissions::ADMINISTRATOR) {
+        Permissions::all()
+    } else {
+        permissions
+    }
+}
+
+/// Applies a channel's permission overwrites to a member's guild-wide permissions.
+///
+/// Overwrites are applied in Discord's order: the @everyone overwrite, then the
+/// combined overwrites of the member's roles, then the member-specific overwrite.
+/// Administrators bypass overwrites entirely.
+fn channel_permissions(
+    base: Permissions,
+    guild_id: u64,
+    user_id: u64,
+    role_ids: &[u64],
+    overwrites: &[PermissionOverwrite],
+) -> Permissions {
+    if base.contains(Permissions::ADMINISTRATOR) {
+        return Permissions::all();
+    }
+
+    let mut permissions = base;
+
+    if let Some(everyone) = overwrites
+        .iter()
+        .find(|o| matches!(o.kind, PermissionOverwriteType::Role(id) if id.get() == guild_id))
+    {
+        permissions = (permissions - everyone.deny) | everyone.allow;
+    }
+
+    let (allow, deny) = overwrites
+        .iter()
+        .filter(|o| {
+            matches!(o.kind, PermissionOverwriteType::Role(id)
+                if id.get() != guild_id && role_ids.contains(&id.get()))
+        })
+        .fold(
+            (Permissions::empty(), Permissions::empty()),
+            |(allow, deny), o| (allow | o.allow, deny | o.deny),
+        );
+    permissions = (permissions - deny) | allow;
+
+    if let Some(member) = overwrites
+        .iter()
+        .find(|o| matches!(o.kind, PermissionOverwriteType::Member(id) if id.get() == user_id))
+    {
+        permissions = (permissions - member.deny) | member.allow;
+    }
+
+    permissions
+}
+
+/// Lists the required notification permissions missing from a permission set.
+///
+/// Without View Channel no other permission applies, so it's reported on its own.
+fn missing_permissions(permissions: Permissions) -> Vec<ChannelPermission> {
+    if !permissions.contains(Permissions::VIEW_CHANNEL) {
+        return vec![ChannelPermission::ViewChannel];
+    }
+
+    REQUIRED_PERMISSIONS
+        .iter()
+        .filter(|(_, required)| !permissions.contains(*required))
+        .map(|(permission, _)| *permission)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serenity::all::{RoleId, UserId};
+
+    const GUILD_ID: u64 = 100;
+    const BOT_ID: u64 = 200;
+    const BOT_ROLE_ID: u64 = 300;
+    const OTHER_ROLE_ID: u64 = 400;
+
+    fn overwrite(
+        kind: PermissionOverwriteType,
+        allow: Permissions,
+        deny: Permissions,
+    ) -> PermissionOverwrite {
+        PermissionOverwrite { allow, deny, kind }
+    }
+
+    fn notification_permissions() -> Permissions {
+        Permissions::VIEW_CHANNEL
+            | Permissions::SEND_MESSAGES
+            | Permissions::EMBED_LINKS
+            | Permissions::MENTION_EVERYONE
+    }
+
+    /// Tests combining @everyone with the bot's roles.
+    ///
+    /// Verifies permissions of roles the bot doesn't have are ignored.
+    ///
+    /// Expected: Ok with @everyone and bot role permissions only
+    #[test]
+    fn combines_everyone_and_member_roles() {
+        let roles = HashMap::from([
+            (GUILD_ID, Permissions::VIEW_CHANNEL.bits()),
+            (BOT_ROLE_ID, Permissions::SEND_MESSAGES.bits()),
+            (OTHER_ROLE_ID, Permissions::EMBED_LINKS.bits()),
+        ]);
+
+        let permissions = base_permissions(GUILD_ID, &roles, &[BOT_ROLE_ID]);
+
+        assert_eq!(
+            permissions,
+            Permissions::VIEW_CHANNEL | Permissions::SEND_MESSAGES
+        );
+    }
+
+    /// Tests base permissions of an administrator.
+    ///
+    /// Verifies Administrator grants every permission.
+    ///
+    /// Expected: Ok with all permissions
+    #[test]
+    fn administrator_grants_all_permissions() {
+        let roles = HashMap::from([(BOT_ROLE_ID, Permissions::ADMINISTRATOR.bits())]);
+
+        let permissions = base_permissions(GUILD_ID, &roles, &[BOT_ROLE_ID]);
+
+        assert_eq!(permissions, Permissions::all());
+    }
+
+    /// Tests applying overwrites in Discord's order.
+    ///
+    /// Verifies a role overwrite allowing a permission takes precedence over the
+    /// @everyone overwrite denying it, and a member overwrite over both.
+    ///
+    /// Expected: Ok with role allow overriding @everyone deny and member deny applied last
+    #[test]
+    fn applies_overwrites_in_order() {
+        let overwrites = vec![
+            overwrite(
+                PermissionOverwriteType::Role(RoleId::new(GUILD_ID)),
+                Permissions::empty(),
+                Permissions::SEND_MESSAGES | Permissions::EMBED_LINKS,
+            ),
+            overwrite(
+                PermissionOverwriteType::Role(RoleId::new(BOT_ROLE_ID)),
+                Permissions::SEND_MESSAGES | Permissions::EMBED_LINKS,
+                Permissions::empty(),
+            ),
+            overwrite(
+                PermissionOverwriteType::Member(UserId::new(BOT_ID)),
+                Permissions::empty(),
+                Permissions::EMBED_LINKS,
+            ),
+        ];
+
+        let permissions = channel_permissions(
+            notification_permissions(),
+            GUILD_ID,
+            BOT_ID,
+            &[BOT_ROLE_ID],
+            &overwrites,
+        );
+
+        assert!(permissions.contains(Permissions::SEND_MESSAGES));
+        assert!(!permissions.contains(Permissions::EMBED_LINKS));
+    }
+
+    /// Tests overwrites for roles and members other than the bot.
+    ///
+    /// Verifies overwrites which don't target the bot leave its permissions unchanged.
+    ///
+    /// Expected: Ok with base permissions
+    #[test]
+    fn ignores_overwrites_for_others() {
+        let overwrites = vec![
+            overwrite(
+                PermissionOverwriteType::Role(RoleId::new(OTHER_ROLE_ID)),
+                Permissions::empty(),
+                Permissions::SEND_MESSAGES,
+            ),
+            overwrite(
+                PermissionOverwriteType::Member(UserId::new(BOT_ID + 1)),
+                Permissions::empty(),
+                Permissions::VIEW_CHANNEL,
+            ),
+        ];
+
+        let permissions = channel_permissions(
+            notification_permissions(),
+            GUILD_ID,
+            BOT_ID,
+            &[BOT_ROLE_ID],
+            &overwrites,
+        );
+
+        assert_eq!(permissions, notification_permissions());
+    }
+
+    /// Tests an administrator in a channel denying it permissions.
+    ///
+    /// Verifies administrators bypass channel overwrites.
+    ///
+    /// Expected: Ok with all permissions
+    #[test]
+    fn administrator_bypasses_overwrites() {
+        let overwrites = vec![overwrite(
+            PermissionOverwriteType::Member(UserId::new(BOT_ID)),
+            Permissions::empty(),
+            Permissions::SEND_MESSAGES,
+        )];
+
+        let permissions =
+            channel_permissions(Permissions::all(), GUILD_ID, BOT_ID, &[], &overwrites);
+
+        assert_eq!(permissions, Permissions::all());
+    }
+
+    /// Tests listing missing notification permissions.
+    ///
+    /// Verifies every missing required permission is reported in order.
+    ///
+    /// Expected: Ok with Embed Links and Mention Everyone missing
+    #[test]
+    fn reports_missing_permissions() {
+        let missing = missing_permissions(Permissions::VIEW_CHANNEL | Permissions::SEND_MESSAGES);
+
+        assert_eq!(
+            missing,
+            vec![
+                ChannelPermission::EmbedLinks,
+                ChannelPermission::MentionEveryone
+            ]
+        );
+    }
+
+    /// Tests listing missing permissions for a channel the bot can't see.
+    ///
+    /// Verifies only View Channel is reported since other permissions don't apply.
+    ///
+    /// Expected: Ok with only View Channel missing
+    #[test]
+    fn reports_only_view_channel_when_hidden() {
+        let missing = missing_permissions(Permissions::SEND_MESSAGES);
+
+        assert_eq!(missing, vec![ChannelPermission::ViewChannel]);
+    }
+
+    /// Tests expiring cached bot member records.
+    ///
+    /// Expected: A record is reused until `BOT_MEMBER_CACHE_SECONDS` have passed
+    #[test]
+    fn expires_cached_bot_member() {
+        let fetched_at = Instant::now();
+        let member = CachedBotMember {
+            user_id: BOT_ID,
+            role_ids: vec![BOT_ROLE_ID],
+            fetched_at,
+        };
+
+        assert!(member.is_fresh(fetched_at));
+        assert!(member.is_fresh(fetched_at + Duration::from_secs(BOT_MEMBER_CACHE_SECONDS - 1)));
+        assert!(!member.is_fresh(fetched_at + Duration::from_secs(BOT_MEMBER_CACHE_SECONDS)));
+    }
+}
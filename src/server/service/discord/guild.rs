@@ -2,13 +2,17 @@
 //!
 //! This module provides the `DiscordGuildService` for retrieving Discord guild
 //! information from the database. It handles queries for guild data including
-//! names and icons, providing this information to controllers for API responses.
+//! names, icons and status, providing this information to controllers for API
+//! responses, and lets admins restore or purge the data of guilds the bot was
+//! removed from.
 
 use sea_orm::DatabaseConnection;
 
 use crate::{
-    model::discord::DiscordGuildDto,
-    server::{data::discord::DiscordGuildRepository, error::AppError},
+    model::discord::{DiscordGuildDto, DiscordGuildStatus},
+    server::{
+        data::discord::DiscordGuildRepository, error::AppError, model::discord::DiscordGuild,
+    },
 };
 
 /// Service for managing Discord guild data.
@@ -49,11 +53,7 @@ impl<'a> DiscordGuildService<'a> {
             .get_all()
             .await?
             .into_iter()
-            .map(|g| DiscordGuildDto {
-                guild_id: g.guild_id,
-                name: g.name,
-                icon_hash: g.icon_hash,
-            })
+            .map(DiscordGuild::into_dto)
             .collect();
 
         Ok(guilds)
@@ -80,10 +80,86 @@ impl<'a> DiscordGuildService<'a> {
 
         let guild = guild_repo.find_by_guild_id(guild_id).await?;
 
-        Ok(guild.map(|g| DiscordGuildDto {
-            guild_id: g.guild_id,
-            name: g.name,
-            icon_hash: g.icon_hash,
-        }))
+        Ok(guild.map(DiscordGuild::into_dto))
+    }
+
+    /// Restores the timerboard of a guild the bot was added back to.
+    ///
+    /// Makes the guild's existing categories & fleets visible again and resumes its
+    /// notifications.
+    ///
+    /// # Arguments
+    /// - `guild_id` - Discord guild ID to restore
+    ///
+    /// # Returns
+    /// - `Ok(DiscordGuildDto)` - The restored guild
+    /// - `Err(AppError::NotFound)` - Guild not found in database
+    /// - `Err(AppError::BadRequest)` - Guild is already active or the bot hasn't been added back
+    /// - `Err(AppError::Database)` - Database error during update
+    pub async fn restore(&self, guild_id: u64) -> Result<DiscordGuildDto, AppError> {
+        let guild_repo = DiscordGuildRepository::new(self.db);
+
+        let guild = self.find_guild(guild_id).await?;
+        match guild.status {
+            DiscordGuildStatus::Active => {
+                return Err(AppError::BadRequest("Server is already active".to_string()));
+            }
+            DiscordGuildStatus::Removed => {
+                return Err(AppError::BadRequest(
+                    "The bot must be added back to the server before it can be restored"
+                        .to_string(),
+                ));
+            }
+            DiscordGuildStatus::Rejoined => {}
+        }
+
+        guild_repo.mark_active(guild_id).await?;
+
+        Ok(self.find_guild(guild_id).await?.into_dto())
+    }
+
+    /// Purges the data of a guild the bot was removed from.
+    ///
+    /// Guilds the bot is no longer in are deleted entirely. Guilds the bot was added
+    /// back to keep their synced roles, channels & members while all of their timerboard
+    /// configuration & fleets are deleted, after which the guild starts over as active.
+    ///
+    /// # Arguments
+    /// - `guild_id` - Discord guild ID to purge
+    ///
+    /// # Returns
+    /// - `Ok(Some(DiscordGuildDto))` - The guild starting over after the bot was added back
+    /// - `Ok(None)` - The guild was deleted entirely
+    /// - `Err(AppError::NotFound)` - Guild not found in database
+    /// - `Err(AppError::BadRequest)` - Guild is active
+    /// - `Err(AppError::Database)` - Database error during deletion
+    pub async fn purge(&self, guild_id: u64) -> Result<Option<DiscordGuildDto>, AppError> {
+        let guild_repo = DiscordGuildRepository::new(self.db);
+
+        let guild = self.find_guild(guild_id).await?;
+        match guild.status {
+            DiscordGuildStatus::Active => Err(AppError::BadRequest(
+                "Only servers the bot was removed from can be purged".to_string(),
+            )),
+            DiscordGuildStatus::Removed => {
+                guild_repo.delete(guild_id).await?;
+
+                Ok(None)
+            }
+            DiscordGuildStatus::Rejoined => {
+                guild_repo.delete_timerboard_data(guild_id).await?;
+                guild_repo.mark_active(guild_id).await?;
+
+                Ok(Some(self.find_guild(guild_id).await?.into_dto()))
+            }
+        }
+    }
+
+    /// Finds a guild by ID, returning NotFound if it doesn't exist.
+    async fn find_guild(&self, guild_id: u64) -> Result<DiscordGuild, AppError> {
+        DiscordGuildRepository::new(self.db)
+            .find_by_guild_id(guild_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Guild with ID {} not found", guild_id)))
     }
 }
@@ -11,7 +11,7 @@ use sea_orm::DatabaseConnection;
 use serenity::all::Http;
 
 use crate::server::{
-    data::discord::DiscordGuildMemberRepository, error::AppError,
+    data::discord::DiscordGuildMemberRepository, error::AppError, metrics::discord_call,
     service::discord::UserDiscordGuildRoleService,
 };
 
@@ -75,10 +75,15 @@ impl<'a> DiscordGuildMemberService<'a> {
         // Fetch ALL members from Discord API with pagination
         // This requires the GUILD_MEMBERS privileged intent
         loop {
-            match self
-                .discord_http
-                .get_guild_members(guild_id.into(), Some(MEMBERS_PER_REQUEST), after)
-                .await
+            match discord_call(
+                "get_guild_members",
+                self.discord_http.get_guild_members(
+                    guild_id.into(),
+                    Some(MEMBERS_PER_REQUEST),
+                    after,
+                ),
+            )
+            .await
             {
                 Ok(members) => {
                     if members.is_empty() {
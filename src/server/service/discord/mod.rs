@@ -2,15 +2,18 @@
 //!
 //! This module provides service abstractions for interacting with the Discord API through
 //! the Serenity library. These services handle Discord-specific operations such as fetching
-//! guild information, managing channels and roles, and synchronizing user access permissions.
+//! guild information, managing channels and roles, synchronizing user access permissions, and
+//! diagnosing whether the bot can deliver fleet notifications.
 
 pub mod channel;
+pub mod diagnostics;
 pub mod guild;
 pub mod guild_member;
 pub mod role;
 pub mod user_guild_role;
 
 pub use channel::DiscordGuildChannelService;
+pub use diagnostics::DiscordDiagnosticsService;
 pub use guild::DiscordGuildService;
 pub use guild_member::DiscordGuildMemberService;
 pub use role::DiscordGuildRoleService;
@@ -0,0 +1,215 @@
+//! Doctrine service for the per-guild library of ship fits.
+//!
+//! This module provides the `DoctrineService` for managing a guild's doctrines. Fits are
+//! pasted in EFT format and parsed into hull & modules when the parameters are built,
+//! the service validates the doctrine as a whole before it is stored.
+
+use sea_orm::DatabaseConnection;
+
+use crate::server::{
+    data::doctrine::DoctrineRepository,
+    error::AppError,
+    model::{
+        doctrine::{CreateDoctrineParam, Doctrine, FitParam, UpdateDoctrineParam},
+        pagination::Page,
+    },
+};
+
+/// Maximum amount of doctrines that can be created per Discord guild
+const DOCTRINE_LIMIT_PER_GUILD: usize = 50;
+
+/// Maximum amount of fits per doctrine
+const MAX_FITS_PER_DOCTRINE: usize = 20;
+
+/// Maximum amount of modules, drones & cargo items per fit
+const MAX_MODULES_PER_FIT: usize = 100;
+
+/// Maximum length of a doctrine name
+const MAX_NAME_LENGTH: usize = 100;
+
+pub struct DoctrineService<'a> {
+    db: &'a DatabaseConnection,
+}
+
+impl<'a> DoctrineService<'a> {
+    pub fn new(db: &'a DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// Creates a new doctrine for a guild
+    ///
+    /// # Arguments
+    /// - `guild_id` - ID of the guild to create the doctrine for
+    /// - `param` - Name, description & parsed fits of the doctrine
+    ///
+    /// # Returns
+    /// - `Ok(Doctrine)` - The created doctrine
+    /// - `Err(AppError::BadRequest)` - Invalid doctrine, name already used or guild
+    ///   doctrine limit reached
+    /// - `Err(AppError::Database)` - Database error during insert operation
+    pub async fn create(
+        &self,
+        guild_id: u64,
+        param: CreateDoctrineParam,
+    ) -> Result<Doctrine, AppError> {
+        validate_doctrine(&param.name, &param.fits)?;
+
+        let repo = DoctrineRepository::new(self.db);
+
+        let count = repo.count_by_guild(guild_id).await?;
+        if count >= DOCTRINE_LIMIT_PER_GUILD {
+            return Err(AppError::BadRequest(format!(
+                "Maximum doctrine limit ({}) reached for this Discord server",
+                DOCTRINE_LIMIT_PER_GUILD
+            )));
+        }
+
+        if repo.name_exists(guild_id, &param.name, None).await? {
+            return Err(name_taken(&param.name));
+        }
+
+        repo.create(guild_id, param).await
+    }
+
+    pub async fn list_by_guild(
+        &self,
+        guild_id: u64,
+        page: u64,
+        per_page: u64,
+    ) -> Result<Page<Doctrine>, AppError> {
+        let (doctrines, total) = DoctrineRepository::new(self.db)
+            .list_by_guild(guild_id, page, per_page)
+            .await?;
+
+        Ok(Page::new(doctrines, total, page, per_page))
+    }
+
+    /// Gets all doctrines of a guild for the doctrine picker of the fleet form
+    pub async fn get_all_by_guild(&self, guild_id: u64) -> Result<Vec<Doctrine>, AppError> {
+        DoctrineRepository::new(self.db)
+            .get_all_by_guild(guild_id)
+            .await
+    }
+
+    /// Updates a doctrine, replacing its fits
+    ///
+    /// # Returns
+    /// - `Ok(Doctrine)` - The updated doctrine
+    /// - `Err(AppError::BadRequest)` - Invalid doctrine or name used by another doctrine
+    /// - `Err(AppError::NotFound)` - Doctrine does not exist in the guild
+    /// - `Err(AppError::Database)` - Database error during update operation
+    pub async fn update(
+        &self,
+        guild_id: u64,
+        id: i32,
+        param: UpdateDoctrineParam,
+    ) -> Result<Doctrine, AppError> {
+        validate_doctrine(&param.name, &param.fits)?;
+
+        let repo = DoctrineRepository::new(self.db);
+
+        if repo.name_exists(guild_id, &param.name, Some(id)).await? {
+            return Err(name_taken(&param.name));
+        }
+
+        repo.update(guild_id, id, param).await
+    }
+
+    pub async fn delete(&self, guild_id: u64, id: i32) -> Result<(), AppError> {
+        DoctrineRepository::new(self.db).delete(guild_id, id).await
+    }
+}
+
+fn name_taken(name: &str) -> AppError {
+    AppError::BadRequest(format!(
+        "A doctrine named '{}' already exists in this Discord server",
+        name
+    ))
+}
+
+fn validate_doctrine(name: &str, fits: &[FitParam]) -> Result<(), AppError> {
+    if name.trim().is_empty() {
+        return Err(AppError::BadRequest(
+            "Doctrine name is required".to_string(),
+        ));
+    }
+
+    if name.chars().count() > MAX_NAME_LENGTH {
+        return Err(AppError::BadRequest(format!(
+            "Doctrine name can't be longer than {} characters",
+            MAX_NAME_LENGTH
+        )));
+    }
+
+    if fits.is_empty() {
+        return Err(AppError::BadRequest(
+            "Doctrine needs at least one fit in EFT format, starting with a [Hull, Fit name] line"
+                .to_string(),
+        ));
+    }
+
+    if fits.len() > MAX_FITS_PER_DOCTRINE {
+        return Err(AppError::BadRequest(format!(
+            "Doctrine can't have more than {} fits",
+            MAX_FITS_PER_DOCTRINE
+        )));
+    }
+
+    if let Some(fit) = fits
+        .iter()
+        .find(|fit| fit.modules.len() > MAX_MODULES_PER_FIT)
+    {
+        return Err(AppError::BadRequest(format!(
+            "Fit '{}' can't have more than {} items",
+            fit.name, MAX_MODULES_PER_FIT
+        )));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fits(eft: &str) -> Vec<FitParam> {
+        FitParam::parse_eft(eft).unwrap()
+    }
+
+    /// Tests validating a doctrine with a named fit.
+    ///
+    /// Expected: Ok
+    #[test]
+    fn accepts_doctrine_with_fit() {
+        let result = validate_doctrine("Muninns", &fits("[Muninn, Fleet]\nDamage Control II"));
+
+        assert!(result.is_ok());
+    }
+
+    /// Tests validating doctrines without a name or fits.
+    ///
+    /// Expected: Err(AppError::BadRequest) for a blank name & a blank paste
+    #[test]
+    fn rejects_missing_name_or_fits() {
+        let blank_name = validate_doctrine(" ", &fits("[Muninn, Fleet]"));
+        let no_fits = validate_doctrine("Muninns", &fits("\n\n"));
+
+        assert!(matches!(blank_name, Err(AppError::BadRequest(_))));
+        assert!(matches!(no_fits, Err(AppError::BadRequest(_))));
+    }
+
+    /// Tests validating a doctrine exceeding the fit limit.
+    ///
+    /// Expected: Err(AppError::BadRequest)
+    #[test]
+    fn rejects_too_many_fits() {
+        let eft = (0..=MAX_FITS_PER_DOCTRINE)
+            .map(|i| format!("[Muninn, Fleet {}]", i))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let result = validate_doctrine("Muninns", &fits(&eft));
+
+        assert!(matches!(result, Err(AppError::BadRequest(_))));
+    }
+}
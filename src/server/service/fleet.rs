@@ -13,25 +13,70 @@
 use chrono::{DateTime, NaiveDateTime, Utc};
 use sea_orm::{ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter};
 use serenity::http::Http;
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
 use std::sync::Arc;
 
 use crate::{
-    model::fleet::{FleetDto, FleetListItemDto, PaginatedFleetsDto, UpdateFleetDto},
+    constant::PING_GROUP_LIMIT_PER_GUILD,
+    model::{
+        doctrine::DoctrineDto,
+        fleet::{
+            BulkFleetAction, BulkFleetActionDto, CloneFleetDto, FleetCalendarDto,
+            FleetCalendarItemDto, FleetDto, FleetGuildDto, FleetListItemDto, GuildFleetListItemDto,
+            PaginatedFleetsDto, PaginatedGuildFleetsDto, UpdateFleetDto,
+        },
+        guild_settings::DowntimePolicy,
+        ping_format::PingFormatFieldType,
+        solar_system::SolarSystemDto,
+        webhook::WebhookEvent,
+    },
     server::{
         data::{
-            category::FleetCategoryRepository, discord::DiscordGuildMemberRepository,
-            fleet::FleetRepository, ping_format::field::PingFormatFieldRepository,
-            ping_group::PingGroupRepository, user::UserRepository,
+            category::FleetCategoryRepository,
+            discord::{DiscordGuildMemberRepository, DiscordGuildRepository},
+            doctrine::DoctrineRepository,
+            fleet::FleetRepository,
+            fleet_dm_reminder::FleetDmReminderRepository,
+            fleet_relay::FleetRelayRepository,
+            guild_settings::GuildSettingsRepository,
+            ping_format::field::PingFormatFieldRepository,
+            ping_group::PingGroupRepository,
+            relayed_fleet::RelayedFleetRepository,
+            user::UserRepository,
             user_category_permission::UserCategoryPermissionRepository,
         },
-        error::AppError,
-        model::fleet::{CreateFleetParam, GetPaginatedFleetsByGuildParam, UpdateFleetParam},
-        service::fleet_notification::FleetNotificationService,
+        error::{auth::AuthError, AppError},
+        eve_map::eve_map,
+        model::{
+            fleet::{
+                CreateFleetParam, Fleet, GetFleetCalendarParam, GetPaginatedFleetsByGuildParam,
+                GetPaginatedFleetsForUserParam, UpdateFleetParam,
+            },
+            fleet_event::FleetEventKind,
+            fleet_relay::FleetRelay,
+            solar_system::StagingRoute,
+            subscription::DmNotification,
+        },
+        service::{
+            fleet_event::FleetEventService,
+            fleet_notification::{
+                builder::BulkChange, cancel::FleetCancellation, FleetNotificationService,
+            },
+            webhook::WebhookService,
+        },
         util::parse::parse_u64_from_string,
     },
 };
 
+/// Longest time range the calendar views can request, covering a six week month grid
+pub const MAX_CALENDAR_RANGE_DAYS: i64 = 42;
+
+/// Most fleets a single bulk operation can change
+pub const MAX_BULK_FLEETS: usize = 50;
+
+/// Furthest a bulk operation can shift fleets in either direction, one week
+pub const MAX_BULK_SHIFT_MINUTES: i64 = 7 * 24 * 60;
+
 /// Service for managing fleet operations.
 ///
 /// Handles fleet creation, retrieval, updates, and deletion with integrated
@@ -59,6 +104,7 @@ impl<'a> FleetService<'a> {
     /// Creates a fleet after validating the time is not in the past (with 2-minute grace
     /// period) and checking for conflicts with category cooldown settings. Posts a creation
     /// notification to Discord and returns the enriched fleet data.
+    /// The fleet is then relayed to the guilds which accepted a relay of its category.
     ///
     /// # Arguments
     /// - `dto` - Fleet creation data including category, time, commander, and field values
@@ -66,7 +112,8 @@ impl<'a> FleetService<'a> {
     ///
     /// # Returns
     /// - `Ok(FleetDto)` - Created fleet with enriched data (category name, commander name)
-    /// - `Err(AppError::BadRequest(_))` - Time validation failed or conflict with cooldown
+    /// - `Err(AppError::BadRequest(_))` - Time validation failed, conflict with cooldown or
+    ///   fleet time within downtime of a guild blocking it
     /// - `Err(AppError::NotFound(_))` - Category not found
     /// - `Err(AppError::InternalError(_))` - Discord notification or data fetch failed
     /// - `Err(AppError::Database(_))` - Database operation failed
@@ -78,22 +125,22 @@ impl<'a> FleetService<'a> {
         let fleet_repo = FleetRepository::new(self.db);
 
         // Validate fleet time doesn't conflict with existing fleets in the same category
-        self.validate_fleet_time_conflict(param.category_id, param.fleet_time, None)
+        self.validate_fleet_time_conflict(param.category_id, param.fleet_time, None, &[])
+            .await?;
+        self.validate_downtime(param.category_id, param.fleet_time)
+            .await?;
+
+        let mut param = param;
+        param.field_values = self
+            .resolve_field_values(param.category_id, param.field_values)
             .await?;
 
         let field_values = param.field_values.clone();
         let fleet = fleet_repo.create(param).await?;
 
-        // Post fleet creation notification to Discord
-        let notification_service =
-            FleetNotificationService::new(self.db, self.discord_http.clone(), self.app_url.clone());
-        notification_service
-            .post_fleet_creation(&fleet, &field_values)
-            .await?;
+        self.notify_created(&fleet, &field_values).await?;
 
-        // Update upcoming fleets lists for all channels in this category
-        self.update_upcoming_fleets_lists_for_category(fleet.category_id)
-            .await?;
+        self.relay_created(&fleet, &field_values).await;
 
         // Fetch the full fleet data with enriched information
         // Get guild_id from the category
@@ -199,6 +246,50 @@ impl<'a> FleetService<'a> {
                 .get_by_ping_format_id(guild_id, category.ping_format_id)
                 .await?;
 
+            // Resolve solar system fields against the static map for links & security
+            let solar_systems: HashMap<String, SolarSystemDto> = fields
+                .iter()
+                .filter(|f| f.field_type == PingFormatFieldType::SolarSystem)
+                .filter_map(|f| {
+                    let value = field_values_by_id.get(&f.id)?;
+                    let system = eve_map().find_by_name(value)?;
+                    Some((f.name.clone(), system.to_dto()))
+                })
+                .collect();
+
+            // Route from the category's staging system, falling back to the guild's
+            let staging_id = match category.staging_system_id {
+                Some(id) => Some(id as u32),
+                None => {
+                    GuildSettingsRepository::new(self.db)
+                        .get_by_guild(guild_id)
+                        .await?
+                        .staging_system_id
+                }
+            };
+            let route = StagingRoute::for_fleet(staging_id, &fields, &field_values_by_id)
+                .map(|route| route.to_dto());
+
+            // Resolve doctrine fields to the guild's doctrines for listing their fits
+            let doctrine_fields: Vec<(String, i32)> = fields
+                .iter()
+                .filter(|f| f.field_type == PingFormatFieldType::Doctrine)
+                .filter_map(|f| {
+                    let id = field_values_by_id.get(&f.id)?.trim().parse::<i32>().ok()?;
+                    Some((f.name.clone(), id))
+                })
+                .collect();
+            let guild_doctrines = DoctrineRepository::new(self.db)
+                .find_by_ids(
+                    guild_id,
+                    doctrine_fields.iter().map(|(_, id)| *id).collect(),
+                )
+                .await?;
+            let doctrines: HashMap<String, DoctrineDto> = doctrine_fields
+                .into_iter()
+                .filter_map(|(name, id)| Some((name, guild_doctrines.get(&id)?.clone().into_dto())))
+                .collect();
+
             let field_name_map: HashMap<i32, String> =
                 fields.into_iter().map(|f| (f.id, f.name)).collect();
 
@@ -224,6 +315,8 @@ impl<'a> FleetService<'a> {
                 commander.name.clone()
             };
 
+            let relayed_from = self.get_relayed_from(fleet.id).await?;
+
             Ok(Some(FleetDto {
                 id: fleet.id,
                 category_id: fleet.category_id,
@@ -234,9 +327,13 @@ impl<'a> FleetService<'a> {
                 fleet_time: fleet.fleet_time,
                 description: fleet.description,
                 field_values,
+                solar_systems,
+                route,
+                doctrines,
                 created_at: fleet.created_at,
                 hidden: fleet.hidden,
                 disable_reminder: fleet.disable_reminder,
+                relayed_from,
             }))
         } else {
             Ok(None)
@@ -259,38 +356,11 @@ impl<'a> FleetService<'a> {
         &self,
         params: GetPaginatedFleetsByGuildParam,
     ) -> Result<PaginatedFleetsDto, AppError> {
-        let user_repo = UserRepository::new(self.db);
-        let category_repo = FleetCategoryRepository::new(self.db);
         let fleet_repo = FleetRepository::new(self.db);
-        let permission_repo = UserCategoryPermissionRepository::new(self.db);
 
-        // Get viewable category IDs for non-admin users
-        let viewable_category_ids = if params.is_admin {
-            None // Admins can view all categories
-        } else {
-            Some(
-                permission_repo
-                    .get_viewable_category_ids_by_user(params.user_id, params.guild_id)
-                    .await?,
-            )
-        };
-
-        // Get categories where user has create or manage permissions (can see hidden fleets)
-        let manageable_category_ids = if params.is_admin {
-            None // Admins can see all hidden fleets
-        } else {
-            let create_ids = permission_repo
-                .get_creatable_category_ids_by_user(params.user_id, params.guild_id)
-                .await?;
-            let manage_ids = permission_repo
-                .get_manageable_category_ids_by_user(params.user_id, params.guild_id)
-                .await?;
-
-            // Combine create and manage IDs
-            let mut combined: std::collections::HashSet<i32> = create_ids.into_iter().collect();
-            combined.extend(manage_ids);
-            Some(combined.into_iter().collect::<Vec<i32>>())
-        };
+        let (viewable_category_ids, manageable_category_ids) = self
+            .get_category_visibility(params.guild_id, params.user_id, params.is_admin)
+            .await?;
 
         let (fleets, total) = fleet_repo
             .get_paginated_by_guild(
@@ -312,76 +382,130 @@ impl<'a> FleetService<'a> {
         let now = chrono::Utc::now();
 
         for fleet in fleets {
-            // Filter hidden fleets based on permissions
-            if fleet.hidden {
-                // Check if user can see hidden fleets in this category
-                let can_see_hidden = params.is_admin
-                    || manageable_category_ids
-                        .as_ref()
-                        .map(|ids| ids.contains(&fleet.category_id))
-                        .unwrap_or(false);
-
-                if !can_see_hidden {
-                    // User can only see hidden fleet if reminder time has passed
-                    // Get the category to check reminder time
-                    if let Ok(Some(category)) =
-                        entity::prelude::FleetCategory::find_by_id(fleet.category_id)
-                            .one(self.db)
-                            .await
-                    {
-                        if let Some(reminder_seconds) = category.ping_reminder {
-                            let reminder_time = fleet.fleet_time
-                                - chrono::Duration::seconds(reminder_seconds as i64);
-                            if now < reminder_time {
-                                // Skip this fleet - not yet visible
-                                continue;
-                            }
-                        } else {
-                            // No reminder time configured, show at fleet start time
-                            if now < fleet.fleet_time {
-                                // Skip this fleet - not yet visible
-                                continue;
-                            }
-                        }
-                    } else {
-                        // If category not found, skip the fleet for safety
-                        continue;
-                    }
-                }
+            if !self
+                .can_see_hidden_fleet(&fleet, params.is_admin, &manageable_category_ids, now)
+                .await
+            {
+                continue;
             }
-            // Fetch category
-            let category = category_repo.find_by_id(fleet.category_id).await?;
 
-            // Fetch commander
-            let commander = user_repo.find_by_id(fleet.commander_id).await?;
+            if let Some((item, _)) = self.build_list_item(fleet, params.guild_id).await? {
+                fleet_list.push(item);
+            }
+        }
 
-            if let (Some(category), Some(commander)) = (category, commander) {
-                // Fetch commander nickname from guild
-                let member_repo = DiscordGuildMemberRepository::new(self.db);
-                let commander_display_name = if let Ok(Some(member)) = member_repo
-                    .get_member(commander.discord_id, params.guild_id)
-                    .await
-                {
-                    member.nickname.unwrap_or(member.username)
-                } else {
-                    commander.name.clone()
-                };
+        Ok(PaginatedFleetsDto {
+            fleets: fleet_list,
+            total,
+            page: params.page,
+            per_page: params.per_page,
+            total_pages,
+        })
+    }
+
+    /// Retrieves paginated fleets across every guild of a user for the aggregated timerboard.
+    ///
+    /// Applies the category permissions & hidden fleet rules of each guild the user is a
+    /// member of, admins see the fleets of every active guild. Each fleet is returned with
+    /// the guild it was scheduled in.
+    ///
+    /// # Arguments
+    /// - `params` - User ID, admin status, optional guild filter, and pagination configuration
+    ///
+    /// # Returns
+    /// - `Ok(PaginatedGuildFleetsDto)` - Paginated list of fleets with total count and page info
+    /// - `Err(AppError::InternalError(_))` - Failed to parse IDs
+    /// - `Err(AppError::Database(_))` - Database operation failed
+    pub async fn get_paginated_for_user(
+        &self,
+        params: GetPaginatedFleetsForUserParam,
+    ) -> Result<PaginatedGuildFleetsDto, AppError> {
+        let guild_repo = DiscordGuildRepository::new(self.db);
+
+        let mut guilds = if params.is_admin {
+            guild_repo.get_active().await?
+        } else {
+            guild_repo.get_guilds_for_user(params.user_id).await?
+        };
+        if let Some(guild_ids) = &params.guild_ids {
+            guilds.retain(|guild| guild_ids.contains(&guild.guild_id));
+        }
+        let guild_ids: Vec<u64> = guilds.iter().map(|guild| guild.guild_id).collect();
+
+        // Category IDs are unique across guilds so each guild's permissions can be merged
+        let mut viewable_category_ids: Option<Vec<i32>> = None;
+        let mut manageable_category_ids: Option<Vec<i32>> = None;
+        if !params.is_admin {
+            let mut viewable = Vec::new();
+            let mut manageable = Vec::new();
+            for guild_id in &guild_ids {
+                let (viewable_ids, manageable_ids) = self
+                    .get_category_visibility(*guild_id, params.user_id, params.is_admin)
+                    .await?;
+                viewable.extend(viewable_ids.unwrap_or_default());
+                manageable.extend(manageable_ids.unwrap_or_default());
+            }
+            viewable_category_ids = Some(viewable);
+            manageable_category_ids = Some(manageable);
+        }
+
+        let (fleets, total) = FleetRepository::new(self.db)
+            .get_paginated_by_guilds(
+                &guild_ids,
+                params.page,
+                params.per_page,
+                viewable_category_ids,
+            )
+            .await?;
+
+        let total_pages = if params.per_page > 0 {
+            (total as f64 / params.per_page as f64).ceil() as u64
+        } else {
+            0
+        };
+
+        let category_guilds: HashMap<i32, u64> = entity::prelude::FleetCategory::find()
+            .filter(
+                entity::fleet_category::Column::GuildId
+                    .is_in(guild_ids.iter().map(|id| id.to_string())),
+            )
+            .all(self.db)
+            .await?
+            .into_iter()
+            .map(|category| Ok((category.id, parse_u64_from_string(category.guild_id)?)))
+            .collect::<Result<_, AppError>>()?;
+
+        let mut fleet_list = Vec::new();
+        let now = chrono::Utc::now();
+
+        for fleet in fleets {
+            if !self
+                .can_see_hidden_fleet(&fleet, params.is_admin, &manageable_category_ids, now)
+                .await
+            {
+                continue;
+            }
+
+            let Some(guild) = category_guilds
+                .get(&fleet.category_id)
+                .and_then(|guild_id| guilds.iter().find(|guild| guild.guild_id == *guild_id))
+            else {
+                continue;
+            };
 
-                fleet_list.push(FleetListItemDto {
-                    id: fleet.id,
-                    category_id: fleet.category_id,
-                    category_name: category.category.name,
-                    name: fleet.name,
-                    commander_id: commander.discord_id,
-                    commander_name: commander_display_name,
-                    fleet_time: fleet.fleet_time,
-                    hidden: fleet.hidden,
-                    disable_reminder: fleet.disable_reminder,
+            if let Some((item, _)) = self.build_list_item(fleet, guild.guild_id).await? {
+                fleet_list.push(GuildFleetListItemDto {
+                    guild: FleetGuildDto {
+                        guild_id: guild.guild_id,
+                        name: guild.name.clone(),
+                        icon_hash: guild.icon_hash.clone(),
+                    },
+                    fleet: item,
                 });
             }
         }
 
-        Ok(PaginatedFleetsDto {
+        Ok(PaginatedGuildFleetsDto {
             fleets: fleet_list,
             total,
             page: params.page,
@@ -390,12 +514,269 @@ impl<'a> FleetService<'a> {
         })
     }
 
+    /// Retrieves the fleets of a guild within a time range for the calendar views.
+    ///
+    /// Applies the same visibility rules as `get_paginated_by_guild` but includes past
+    /// fleets. Each fleet carries the cooldown windows of its category & ping group, and
+    /// fleets just outside the range are included when their cooldown window overlaps it
+    /// so the views can shade blocked time at the edges.
+    ///
+    /// # Arguments
+    /// - `params` - Guild ID, user ID, admin status, and the time range
+    ///
+    /// # Returns
+    /// - `Ok(FleetCalendarDto)` - Fleets within the range ordered by time
+    /// - `Err(AppError::BadRequest(_))` - Range is empty or longer than `MAX_CALENDAR_RANGE_DAYS`
+    /// - `Err(AppError::InternalError(_))` - Failed to parse IDs
+    /// - `Err(AppError::Database(_))` - Database operation failed
+    pub async fn get_calendar_by_guild(
+        &self,
+        params: GetFleetCalendarParam,
+    ) -> Result<FleetCalendarDto, AppError> {
+        if params.end <= params.start {
+            return Err(AppError::BadRequest(
+                "Calendar range end must be after its start".to_string(),
+            ));
+        }
+        if params.end - params.start > chrono::Duration::days(MAX_CALENDAR_RANGE_DAYS) {
+            return Err(AppError::BadRequest(format!(
+                "Calendar range can't be longer than {} days",
+                MAX_CALENDAR_RANGE_DAYS
+            )));
+        }
+
+        let fleet_repo = FleetRepository::new(self.db);
+        let ping_group_repo = PingGroupRepository::new(self.db);
+
+        let (viewable_category_ids, manageable_category_ids) = self
+            .get_category_visibility(params.guild_id, params.user_id, params.is_admin)
+            .await?;
+
+        let (ping_groups, _) = ping_group_repo
+            .list_by_guild(params.guild_id, 0, PING_GROUP_LIMIT_PER_GUILD as u64)
+            .await?;
+        let categories = entity::prelude::FleetCategory::find()
+            .filter(entity::fleet_category::Column::GuildId.eq(params.guild_id.to_string()))
+            .all(self.db)
+            .await?;
+
+        // Widen the range by the longest cooldown so overlapping windows are included
+        let padding = categories
+            .iter()
+            .filter_map(|c| c.ping_cooldown.map(|seconds| seconds as i64))
+            .chain(
+                ping_groups
+                    .iter()
+                    .filter_map(|g| g.cooldown.map(|d| d.num_seconds())),
+            )
+            .max()
+            .map(chrono::Duration::seconds)
+            .unwrap_or_default();
+
+        let fleets = fleet_repo
+            .get_by_guild_in_range(
+                params.guild_id,
+                params.start - padding,
+                params.end + padding,
+                viewable_category_ids,
+            )
+            .await?;
+
+        let now = chrono::Utc::now();
+        let mut items = Vec::new();
+
+        for fleet in fleets {
+            if !self
+                .can_see_hidden_fleet(&fleet, params.is_admin, &manageable_category_ids, now)
+                .await
+            {
+                continue;
+            }
+
+            let Some((item, category)) = self.build_list_item(fleet, params.guild_id).await? else {
+                continue;
+            };
+
+            let category_cooldown = category.ping_cooldown.map(|seconds| seconds as i64);
+            let ping_group = category
+                .ping_group_id
+                .and_then(|id| ping_groups.iter().find(|g| g.id == id));
+            let ping_group_cooldown = ping_group.and_then(|g| g.cooldown.map(|d| d.num_seconds()));
+
+            let calendar_item = FleetCalendarItemDto {
+                fleet: item,
+                category_cooldown,
+                ping_group_name: ping_group.map(|g| g.name.clone()),
+                ping_group_cooldown,
+            };
+
+            // Skip fleets from the padding whose cooldown doesn't reach into the range
+            let window = chrono::Duration::seconds(calendar_item.cooldown().unwrap_or(0));
+            let fleet_time = calendar_item.fleet.fleet_time;
+            if fleet_time + window < params.start || fleet_time - window >= params.end {
+                continue;
+            }
+
+            items.push(calendar_item);
+        }
+
+        Ok(FleetCalendarDto {
+            start: params.start,
+            end: params.end,
+            fleets: items,
+        })
+    }
+
+    /// Gets the categories of a guild whose fleets a user can view & whose hidden fleets
+    /// they can always see.
+    ///
+    /// # Arguments
+    /// - `guild_id` - Discord guild ID
+    /// - `user_id` - Discord user ID
+    /// - `is_admin` - Whether the user is an admin
+    ///
+    /// # Returns
+    /// - `Ok((viewable, manageable))` - Viewable category IDs and the category IDs the user
+    ///   can create or manage fleets in, both None for admins who bypass all filtering
+    /// - `Err(AppError::Database(_))` - Database operation failed
+    async fn get_category_visibility(
+        &self,
+        guild_id: u64,
+        user_id: u64,
+        is_admin: bool,
+    ) -> Result<(Option<Vec<i32>>, Option<Vec<i32>>), AppError> {
+        if is_admin {
+            return Ok((None, None));
+        }
+
+        let permission_repo = UserCategoryPermissionRepository::new(self.db);
+
+        let viewable_ids = permission_repo
+            .get_viewable_category_ids_by_user(user_id, guild_id)
+            .await?;
+
+        // Users with create or manage permissions can see hidden fleets
+        let create_ids = permission_repo
+            .get_creatable_category_ids_by_user(user_id, guild_id)
+            .await?;
+        let manage_ids = permission_repo
+            .get_manageable_category_ids_by_user(user_id, guild_id)
+            .await?;
+
+        let mut combined: std::collections::HashSet<i32> = create_ids.into_iter().collect();
+        combined.extend(manage_ids);
+
+        Ok((Some(viewable_ids), Some(combined.into_iter().collect())))
+    }
+
+    /// Returns whether a fleet passes the hidden fleet rules for a user.
+    ///
+    /// Hidden fleets are visible to admins and users who can create or manage fleets in the
+    /// category, otherwise only once the category's reminder time has passed (or the fleet
+    /// has started if no reminder is configured).
+    ///
+    /// # Arguments
+    /// - `fleet` - The fleet to check
+    /// - `is_admin` - Whether the user is an admin
+    /// - `manageable_category_ids` - Categories the user can create or manage fleets in
+    /// - `now` - Current time
+    async fn can_see_hidden_fleet(
+        &self,
+        fleet: &Fleet,
+        is_admin: bool,
+        manageable_category_ids: &Option<Vec<i32>>,
+        now: DateTime<Utc>,
+    ) -> bool {
+        if !fleet.hidden {
+            return true;
+        }
+
+        let can_see_hidden = is_admin
+            || manageable_category_ids
+                .as_ref()
+                .map(|ids| ids.contains(&fleet.category_id))
+                .unwrap_or(false);
+        if can_see_hidden {
+            return true;
+        }
+
+        // User can only see hidden fleet if reminder time has passed
+        match entity::prelude::FleetCategory::find_by_id(fleet.category_id)
+            .one(self.db)
+            .await
+        {
+            Ok(Some(category)) => {
+                let reveal_time = match category.ping_reminder {
+                    Some(reminder_seconds) => {
+                        fleet.fleet_time - chrono::Duration::seconds(reminder_seconds as i64)
+                    }
+                    // No reminder time configured, show at fleet start time
+                    None => fleet.fleet_time,
+                };
+                now >= reveal_time
+            }
+            // If category not found, skip the fleet for safety
+            _ => false,
+        }
+    }
+
+    /// Enriches a fleet with its category and commander names for fleet lists.
+    ///
+    /// # Arguments
+    /// - `fleet` - The fleet to enrich
+    /// - `guild_id` - Discord guild ID for looking up the commander's nickname
+    ///
+    /// # Returns
+    /// - `Ok(Some((item, category)))` - List item alongside the fleet's category
+    /// - `Ok(None)` - Category or commander no longer exists
+    /// - `Err(AppError::Database(_))` - Database operation failed
+    async fn build_list_item(
+        &self,
+        fleet: Fleet,
+        guild_id: u64,
+    ) -> Result<Option<(FleetListItemDto, entity::fleet_category::Model)>, AppError> {
+        let user_repo = UserRepository::new(self.db);
+        let category_repo = FleetCategoryRepository::new(self.db);
+        let member_repo = DiscordGuildMemberRepository::new(self.db);
+
+        let category = category_repo.find_by_id(fleet.category_id).await?;
+        let commander = user_repo.find_by_id(fleet.commander_id).await?;
+
+        let (Some(category), Some(commander)) = (category, commander) else {
+            return Ok(None);
+        };
+
+        // Fetch commander nickname from guild
+        let commander_display_name = if let Ok(Some(member)) =
+            member_repo.get_member(commander.discord_id, guild_id).await
+        {
+            member.nickname.unwrap_or(member.username)
+        } else {
+            commander.name.clone()
+        };
+
+        let item = FleetListItemDto {
+            id: fleet.id,
+            category_id: fleet.category_id,
+            category_name: category.category.name.clone(),
+            name: fleet.name,
+            commander_id: commander.discord_id,
+            commander_name: commander_display_name,
+            fleet_time: fleet.fleet_time,
+            hidden: fleet.hidden,
+            disable_reminder: fleet.disable_reminder,
+        };
+
+        Ok(Some((item, category.category)))
+    }
+
     /// Updates a fleet with time validation and Discord notification updates.
     ///
     /// Updates fleet properties after validating time constraints and checking for cooldown
     /// conflicts. For fleets not yet started, validates new time is not too far in past.
     /// For started fleets, ensures new time is not earlier than original. Updates Discord
     /// messages with new information.
+    /// Changes are propagated to relayed copies of the fleet.
     ///
     /// # Arguments
     /// - `id` - Fleet ID to update
@@ -407,7 +788,9 @@ impl<'a> FleetService<'a> {
     /// # Returns
     /// - `Ok(FleetDto)` - Updated fleet with enriched data
     /// - `Err(AppError::NotFound(_))` - Fleet, category, or commander not found
-    /// - `Err(AppError::BadRequest(_))` - Time validation failed or conflict with cooldown
+    /// - `Err(AppError::BadRequest(_))` - Time validation failed, conflict with cooldown,
+    ///   a new fleet time within downtime of a guild blocking it, or the fleet was relayed
+    ///   from another guild
     /// - `Err(AppError::InternalError(_))` - Discord notification or ID parsing failed
     /// - `Err(AppError::Database(_))` - Database operation failed
     pub async fn update(
@@ -431,8 +814,13 @@ impl<'a> FleetService<'a> {
                     .map_err(|e| *e)?;
 
             // Validate fleet time doesn't conflict with existing fleets (excluding this fleet)
-            self.validate_fleet_time_conflict(dto.category_id, new_fleet_time, Some(id))
+            self.validate_fleet_time_conflict(dto.category_id, new_fleet_time, Some(id), &[])
                 .await?;
+            // Fleets already scheduled into downtime may still be edited otherwise
+            if new_fleet_time != original_time {
+                self.validate_downtime(dto.category_id, new_fleet_time)
+                    .await?;
+            }
             // Fetch old category to verify guild
             let old_category = category_repo.find_by_id(fleet.category_id).await?;
 
@@ -443,6 +831,8 @@ impl<'a> FleetService<'a> {
                     return Err(AppError::NotFound("Fleet not found".to_string()));
                 }
 
+                self.require_not_relayed(id).await?;
+
                 // If category is being changed, validate the new category belongs to the same guild
                 if dto.category_id != fleet.category_id {
                     let Some(new_category) = category_repo.find_by_id(dto.category_id).await?
@@ -460,6 +850,10 @@ impl<'a> FleetService<'a> {
                     }
                 }
 
+                let field_values = self
+                    .resolve_field_values(dto.category_id, dto.field_values.clone())
+                    .await?;
+
                 // Update the fleet
                 let params = UpdateFleetParam {
                     id,
@@ -467,31 +861,16 @@ impl<'a> FleetService<'a> {
                     name: Some(dto.name.clone()),
                     fleet_time: Some(new_fleet_time),
                     description: Some(dto.description.clone()),
-                    field_values: Some(dto.field_values.clone()),
+                    field_values: Some(field_values.clone()),
                     hidden: Some(dto.hidden),
                     disable_reminder: Some(dto.disable_reminder),
                 };
                 let updated_fleet = fleet_repo.update(params).await?;
 
-                // Update Discord messages with new fleet information
-                let notification_service = FleetNotificationService::new(
-                    self.db,
-                    self.discord_http.clone(),
-                    self.app_url.clone(),
-                );
-                notification_service
-                    .update_fleet_messages(&updated_fleet, &dto.field_values)
-                    .await?;
-
-                // Update upcoming fleets lists for all channels in this category
-                self.update_upcoming_fleets_lists_for_category(dto.category_id)
+                self.notify_updated(&fleet, &updated_fleet, &field_values)
                     .await?;
 
-                // If category was changed, also update the old category's channels
-                if dto.category_id != fleet.category_id {
-                    self.update_upcoming_fleets_lists_for_category(fleet.category_id)
-                        .await?;
-                }
+                self.relay_updated(&updated_fleet, &field_values).await;
 
                 // Fetch the updated fleet data with enriched information
                 return self
@@ -508,6 +887,7 @@ impl<'a> FleetService<'a> {
     ///
     /// Verifies the fleet belongs to the specified guild before deletion and cancels
     /// all associated Discord messages (creation and reminder notifications).
+    /// Relayed copies of the fleet in other guilds are cancelled along with it.
     ///
     /// # Arguments
     /// - `id` - Fleet ID to delete
@@ -516,6 +896,7 @@ impl<'a> FleetService<'a> {
     /// # Returns
     /// - `Ok(true)` - Fleet was deleted successfully
     /// - `Ok(false)` - Fleet not found or doesn't belong to guild
+    /// - `Err(AppError::BadRequest(_))` - Fleet was relayed from another guild
     /// - `Err(AppError::InternalError(_))` - Discord notification cancellation failed
     /// - `Err(AppError::Database(_))` - Database operation failed
     pub async fn delete(&self, id: i32, guild_id: u64) -> Result<bool, AppError> {
@@ -525,7 +906,7 @@ impl<'a> FleetService<'a> {
         // Check if fleet exists and belongs to the guild
         let result = fleet_repo.get_by_id(id).await?;
 
-        if let Some((fleet, _)) = result {
+        if let Some((fleet, field_values)) = result {
             // Fetch category to verify guild
             let category = category_repo.find_by_id(fleet.category_id).await?;
 
@@ -533,21 +914,14 @@ impl<'a> FleetService<'a> {
                 let category_guild_id = parse_u64_from_string(category.category.guild_id)?;
 
                 if category_guild_id == guild_id {
-                    // Cancel Discord messages before deleting
-                    let notification_service = FleetNotificationService::new(
-                        self.db,
-                        self.discord_http.clone(),
-                        self.app_url.clone(),
-                    );
-                    notification_service
-                        .cancel_fleet_messages(&fleet, self.app_url.as_str())
-                        .await?;
+                    self.require_not_relayed(id).await?;
 
-                    fleet_repo.delete(id).await?;
-
-                    // Update upcoming fleets lists for all channels in this category
-                    self.update_upcoming_fleets_lists_for_category(fleet.category_id)
-                        .await?;
+                    self.bulk_cancel(
+                        vec![(fleet, field_values)],
+                        guild_id,
+                        CancellationNotice::Messages,
+                    )
+                    .await?;
 
                     return Ok(true);
                 }
@@ -557,51 +931,1328 @@ impl<'a> FleetService<'a> {
         Ok(false)
     }
 
-    /// Updates upcoming fleets lists for all channels configured for a category.
+    /// Creates a copy of a fleet scheduled at a new time.
     ///
-    /// Called after fleet updates or deletions to reflect changes in the upcoming
-    /// fleets lists without bumping them to the most recent message.
+    /// Copies the category, name, commander, description, field values and flags of the
+    /// fleet, then creates the copy with the same validation & notifications as `create`.
     ///
     /// # Arguments
-    /// - `category_id` - Category ID whose channels should be updated
+    /// - `id` - ID of the fleet to copy
+    /// - `guild_id` - Discord guild ID for authorization verification
+    /// - `is_admin` - Whether the user is an admin for permission checks on result
+    /// - `dto` - New fleet time of the copy
     ///
     /// # Returns
-    /// - `Ok(())` - Successfully updated all channel lists
-    /// - `Err(AppError)` - Database or Discord error
-    async fn update_upcoming_fleets_lists_for_category(
+    /// - `Ok(FleetDto)` - The created copy with enriched data
+    /// - `Err(AppError::NotFound(_))` - Fleet not found or doesn't belong to the guild
+    /// - `Err(AppError::BadRequest(_))` - Time validation failed, conflict with cooldown or
+    ///   fleet time within downtime of a guild blocking it
+    /// - `Err(AppError::InternalError(_))` - Discord notification or data fetch failed
+    /// - `Err(AppError::Database(_))` - Database operation failed
+    pub async fn clone_fleet(
         &self,
-        category_id: i32,
-    ) -> Result<(), AppError> {
-        let category_repo = FleetCategoryRepository::new(self.db);
-
-        // Get the category to extract its channels
-        let Some(category_data) = category_repo.find_by_id(category_id).await? else {
-            return Ok(()); // Category not found, nothing to update
+        id: i32,
+        guild_id: u64,
+        is_admin: bool,
+        dto: CloneFleetDto,
+    ) -> Result<FleetDto, AppError> {
+        let Some((fleet, field_values)) = FleetRepository::new(self.db).get_by_id(id).await? else {
+            return Err(AppError::NotFound("Fleet not found".to_string()));
         };
 
-        // Extract channel IDs from the category's channels
-        let channel_ids: Vec<u64> = category_data
-            .channels
-            .iter()
-            .filter_map(|(channel_model, _)| channel_model.channel_id.parse::<u64>().ok())
-            .collect();
-
-        if channel_ids.is_empty() {
-            return Ok(()); // No channels configured
+        let Some(category) = FleetCategoryRepository::new(self.db)
+            .find_by_id(fleet.category_id)
+            .await?
+        else {
+            return Err(AppError::NotFound("Fleet not found".to_string()));
+        };
+        if parse_u64_from_string(category.category.guild_id)? != guild_id {
+            return Err(AppError::NotFound("Fleet not found".to_string()));
         }
 
-        // Update the upcoming fleets list for each channel
-        let notification_service =
-            FleetNotificationService::new(self.db, self.discord_http.clone(), self.app_url.clone());
+        let fleet_time = Self::parse_fleet_time_with_min(&dto.fleet_time, None).map_err(|e| *e)?;
+
+        let param = CreateFleetParam {
+            category_id: fleet.category_id,
+            name: fleet.name,
+            commander_id: fleet.commander_id,
+            fleet_time,
+            description: fleet.description,
+            field_values,
+            hidden: fleet.hidden,
+            disable_reminder: fleet.disable_reminder,
+        };
 
-        for channel_id in channel_ids {
-            if let Err(e) = notification_service
-                .update_upcoming_fleets_list(channel_id)
-                .await
-            {
-                dioxus_logger::tracing::warn!(
-                    "Failed to update upcoming fleets list for channel {}: {}",
-                    channel_id,
+        self.create(param, is_admin).await
+    }
+
+    /// Shifts, cancels or re-categorises a set of fleets at once.
+    ///
+    /// Every fleet must belong to the guild and be editable by the user, being its
+    /// commander or having manage permission for its category. The whole batch is
+    /// validated before any fleet is changed: new times are checked against the cooldowns
+    /// of existing fleets and of the other fleets of the batch, and the changes are written
+    /// in a single transaction. Rather than editing each fleet's Discord messages, one
+    /// summary of the change is posted to each affected channel and its upcoming fleets
+    /// list is updated once for the whole batch.
+    ///
+    /// # Arguments
+    /// - `guild_id` - Discord guild ID the fleets belong to
+    /// - `user_id` - Discord user ID for permission checks
+    /// - `is_admin` - Whether the user is an admin (bypasses permission checks)
+    /// - `dto` - Selected fleet IDs & the change to apply to them
+    ///
+    /// # Returns
+    /// - `Ok(())` - All fleets were changed
+    /// - `Err(AppError::BadRequest(_))` - Empty or too large batch, invalid shift, a time
+    ///   validation failed, a conflict with cooldowns, a new fleet time within downtime of a
+    ///   guild blocking it, or a fleet was relayed from another guild
+    /// - `Err(AppError::NotFound(_))` - A fleet or the new category was not found
+    /// - `Err(AppError::AuthErr(AccessDenied))` - User can't edit one of the fleets or create
+    ///   fleets in the new category
+    /// - `Err(AppError::InternalError(_))` - Discord notification or ID parsing failed
+    /// - `Err(AppError::Database(_))` - Database operation failed
+    pub async fn bulk_update(
+        &self,
+        guild_id: u64,
+        user_id: u64,
+        is_admin: bool,
+        dto: BulkFleetActionDto,
+    ) -> Result<(), AppError> {
+        let mut fleet_ids = dto.fleet_ids;
+        fleet_ids.sort_unstable();
+        fleet_ids.dedup();
+
+        if fleet_ids.is_empty() {
+            return Err(AppError::BadRequest("No fleets selected".to_string()));
+        }
+        if fleet_ids.len() > MAX_BULK_FLEETS {
+            return Err(AppError::BadRequest(format!(
+                "At most {} fleets can be changed at once",
+                MAX_BULK_FLEETS
+            )));
+        }
+
+        let fleets = self
+            .get_editable_fleets(&fleet_ids, guild_id, user_id, is_admin)
+            .await?;
+
+        match dto.action {
+            BulkFleetAction::Cancel => {
+                self.bulk_cancel(fleets, guild_id, CancellationNotice::Summary { user_id })
+                    .await
+            }
+            BulkFleetAction::Shift { minutes } => {
+                if minutes == 0 || minutes.abs() > MAX_BULK_SHIFT_MINUTES {
+                    return Err(AppError::BadRequest(format!(
+                        "Fleets can be shifted by up to {} minutes",
+                        MAX_BULK_SHIFT_MINUTES
+                    )));
+                }
+
+                let shift = chrono::Duration::minutes(minutes);
+                let mut planned = Vec::with_capacity(fleets.len());
+                for (fleet, _) in &fleets {
+                    let fleet_time = fleet.fleet_time + shift;
+
+                    Self::validate_fleet_time_with_min(fleet_time, Some(fleet.fleet_time))
+                        .map_err(|e| batch_error(fleet, *e))?;
+                    self.validate_downtime(fleet.category_id, fleet_time)
+                        .await
+                        .map_err(|e| batch_error(fleet, e))?;
+
+                    planned.push(PlannedFleet {
+                        fleet_id: fleet.id,
+                        category_id: fleet.category_id,
+                        fleet_time,
+                    });
+                }
+
+                let summary = if minutes > 0 {
+                    format!("Rescheduled {} minutes later", minutes)
+                } else {
+                    format!("Rescheduled {} minutes earlier", -minutes)
+                };
+
+                self.apply_bulk_update(fleets, planned, HashMap::new(), &summary, user_id)
+                    .await
+            }
+            BulkFleetAction::Recategorize { category_id } => {
+                let Some(category) = FleetCategoryRepository::new(self.db)
+                    .find_by_id(category_id)
+                    .await?
+                else {
+                    return Err(AppError::NotFound("New category not found".to_string()));
+                };
+                if parse_u64_from_string(category.category.guild_id)? != guild_id {
+                    return Err(AppError::BadRequest(
+                        "New category does not belong to this guild".to_string(),
+                    ));
+                }
+
+                if !is_admin
+                    && !UserCategoryPermissionRepository::new(self.db)
+                        .user_can_create_category(user_id, category_id)
+                        .await?
+                {
+                    return Err(AppError::AuthErr(AuthError::AccessDenied(
+                        user_id,
+                        "You don't have permission to create fleets in the new category"
+                            .to_string(),
+                    )));
+                }
+
+                let mut planned = Vec::with_capacity(fleets.len());
+                let mut mapped_field_values = HashMap::new();
+                for (fleet, field_values) in &fleets {
+                    // Ping formats may differ between categories, fields are matched by name
+                    if fleet.category_id != category_id {
+                        let mapped = self
+                            .map_relayed_field_values(fleet.category_id, category_id, field_values)
+                            .await?;
+                        mapped_field_values.insert(fleet.id, mapped);
+                    }
+
+                    planned.push(PlannedFleet {
+                        fleet_id: fleet.id,
+                        category_id,
+                        fleet_time: fleet.fleet_time,
+                    });
+                }
+
+                let summary = format!("Moved to {}", category.category.name);
+
+                self.apply_bulk_update(fleets, planned, mapped_field_values, &summary, user_id)
+                    .await
+            }
+        }
+    }
+
+    /// Gets the fleets of a batch operation, verifying the user may edit each of them.
+    ///
+    /// # Arguments
+    /// - `fleet_ids` - IDs of the selected fleets
+    /// - `guild_id` - Discord guild ID the fleets must belong to
+    /// - `user_id` - Discord user ID for permission checks
+    /// - `is_admin` - Whether the user is an admin (bypasses permission checks)
+    ///
+    /// # Returns
+    /// - `Ok(Vec<(Fleet, HashMap<i32, String>)>)` - The fleets with their field values
+    /// - `Err(AppError::NotFound(_))` - A fleet was not found or doesn't belong to the guild
+    /// - `Err(AppError::AuthErr(AccessDenied))` - User can't edit one of the fleets
+    /// - `Err(AppError::BadRequest(_))` - A fleet was relayed from another guild
+    /// - `Err(AppError::Database(_))` - Database operation failed
+    async fn get_editable_fleets(
+        &self,
+        fleet_ids: &[i32],
+        guild_id: u64,
+        user_id: u64,
+        is_admin: bool,
+    ) -> Result<Vec<(Fleet, HashMap<i32, String>)>, AppError> {
+        let fleet_repo = FleetRepository::new(self.db);
+        let category_repo = FleetCategoryRepository::new(self.db);
+        let permission_repo = UserCategoryPermissionRepository::new(self.db);
+
+        // Whether the user can manage each category already verified to be in the guild
+        let mut manageable: HashMap<i32, bool> = HashMap::new();
+        let mut fleets = Vec::with_capacity(fleet_ids.len());
+
+        for &id in fleet_ids {
+            let Some((fleet, field_values)) = fleet_repo.get_by_id(id).await? else {
+                return Err(AppError::NotFound(format!("Fleet {} not found", id)));
+            };
+
+            let can_manage = match manageable.get(&fleet.category_id) {
+                Some(can_manage) => *can_manage,
+                None => {
+                    let category = category_repo.find_by_id(fleet.category_id).await?;
+                    let in_guild = match category {
+                        Some(category) => {
+                            parse_u64_from_string(category.category.guild_id)? == guild_id
+                        }
+                        None => false,
+                    };
+                    if !in_guild {
+                        return Err(AppError::NotFound(format!("Fleet {} not found", id)));
+                    }
+
+                    let can_manage = is_admin
+                        || permission_repo
+                            .user_can_manage_category(user_id, fleet.category_id)
+                            .await?;
+                    manageable.insert(fleet.category_id, can_manage);
+                    can_manage
+                }
+            };
+
+            if !can_manage && fleet.commander_id != user_id {
+                return Err(AppError::AuthErr(AuthError::AccessDenied(
+                    user_id,
+                    format!("You don't have permission to edit fleet '{}'", fleet.name),
+                )));
+            }
+
+            self.require_not_relayed(id).await?;
+
+            fleets.push((fleet, field_values));
+        }
+
+        Ok(fleets)
+    }
+
+    /// Cancels & deletes fleets along with their relayed copies.
+    ///
+    /// Everything the Discord notifications & webhooks need is read first, then all
+    /// fleets are deleted at once so either every fleet or none is cancelled. Discord
+    /// messages, direct messages, webhooks and the upcoming fleets lists are updated
+    /// afterwards, failures are logged per fleet without failing the cancellation as the
+    /// fleets were already deleted.
+    ///
+    /// # Arguments
+    /// - `fleets` - The fleets to cancel with their field values
+    /// - `guild_id` - Discord guild ID the fleets belong to
+    /// - `notice` - How the Discord channels of the fleets are told about the cancellation
+    ///
+    /// # Returns
+    /// - `Ok(())` - All fleets were deleted
+    /// - `Err(AppError)` - Reading the fleets or deleting them failed, nothing was deleted
+    pub async fn bulk_cancel(
+        &self,
+        fleets: Vec<(Fleet, HashMap<i32, String>)>,
+        guild_id: u64,
+        notice: CancellationNotice,
+    ) -> Result<(), AppError> {
+        let prepared = self.prepare_bulk_cancel(fleets, guild_id).await?;
+
+        FleetRepository::new(self.db)
+            .delete_many(prepared.fleet_ids())
+            .await?;
+
+        self.finish_bulk_cancel(prepared, notice).await;
+
+        Ok(())
+    }
+
+    /// Reads everything needed to announce the cancellation of fleets before deleting them.
+    ///
+    /// Relayed copies of the fleets are included so they are cancelled along with them.
+    /// Nothing is deleted, the caller deletes `PreparedCancellation::fleet_ids` and then
+    /// passes the result to `finish_bulk_cancel`.
+    ///
+    /// # Arguments
+    /// - `fleets` - The fleets to cancel with their field values
+    /// - `guild_id` - Discord guild ID the fleets belong to
+    ///
+    /// # Returns
+    /// - `Ok(PreparedCancellation)` - The fleets & relayed copies to delete
+    /// - `Err(AppError)` - Reading the fleets, their messages or subscribers failed
+    pub async fn prepare_bulk_cancel(
+        &self,
+        fleets: Vec<(Fleet, HashMap<i32, String>)>,
+        guild_id: u64,
+    ) -> Result<PreparedCancellation, AppError> {
+        let notification_service =
+            FleetNotificationService::new(self.db, self.discord_http.clone(), self.app_url.clone());
+
+        // Relayed copies are linked to their source fleet and cancelled along with it
+        let mut cancelled = Vec::with_capacity(fleets.len());
+        for (fleet, field_values) in fleets {
+            cancelled.extend(self.get_relayed_copies(fleet.id).await?);
+            cancelled.push((fleet, field_values, guild_id));
+        }
+
+        // Messages, subscriptions & the webhook payload are deleted with the fleet
+        let mut prepared = Vec::with_capacity(cancelled.len());
+        for (fleet, field_values, guild_id) in cancelled {
+            let fleet_dto = self.get_by_id(fleet.id, fleet.commander_id, true).await?;
+            let cancellation = notification_service.prepare_cancellation(&fleet).await?;
+            prepared.push(CancelledFleet {
+                fleet,
+                field_values,
+                guild_id,
+                fleet_dto,
+                cancellation,
+            });
+        }
+
+        Ok(PreparedCancellation { fleets: prepared })
+    }
+
+    /// Announces the cancellation of fleets deleted after `prepare_bulk_cancel`.
+    ///
+    /// Failures are logged per fleet without failing the cancellation as the fleets were
+    /// already deleted.
+    ///
+    /// # Arguments
+    /// - `prepared` - The deleted fleets as prepared before the deletion
+    /// - `notice` - How the Discord channels of the fleets are told about the cancellation
+    pub async fn finish_bulk_cancel(
+        &self,
+        prepared: PreparedCancellation,
+        notice: CancellationNotice,
+    ) {
+        let notification_service =
+            FleetNotificationService::new(self.db, self.discord_http.clone(), self.app_url.clone());
+
+        let mut category_ids = Vec::with_capacity(prepared.fleets.len());
+        let mut cancelled_fleets = Vec::with_capacity(prepared.fleets.len());
+        for CancelledFleet {
+            fleet,
+            field_values,
+            guild_id,
+            fleet_dto,
+            cancellation,
+        } in prepared.fleets
+        {
+            match notice {
+                CancellationNotice::Messages => {
+                    if let Err(e) = notification_service
+                        .send_cancellation(&fleet, &field_values, cancellation)
+                        .await
+                    {
+                        dioxus_logger::tracing::warn!(
+                            "Failed to cancel Discord messages of fleet {}: {}",
+                            fleet.id,
+                            e
+                        );
+                    }
+                }
+                CancellationNotice::Summary { .. } | CancellationNotice::Silent => {
+                    notification_service.send_cancellation_dms(&fleet, &field_values, cancellation);
+                }
+            }
+
+            self.notify_cancelled(&fleet, fleet_dto, guild_id).await;
+
+            category_ids.push(fleet.category_id);
+            cancelled_fleets.push(fleet);
+        }
+
+        if let CancellationNotice::Summary { user_id } = notice {
+            let changes: Vec<(&Fleet, &Fleet)> = cancelled_fleets
+                .iter()
+                .map(|fleet| (fleet, fleet))
+                .collect();
+            if let Err(e) = notification_service
+                .post_bulk_update(
+                    BulkChange::Cancelled,
+                    "The listed fleets were cancelled and won't take place.",
+                    &changes,
+                    user_id,
+                )
+                .await
+            {
+                dioxus_logger::tracing::warn!("Failed to post bulk cancellation summary: {}", e);
+            }
+        }
+
+        // One update of the upcoming fleets list per channel for the whole batch
+        if let Err(e) = self.update_upcoming_fleets_lists(&category_ids).await {
+            dioxus_logger::tracing::warn!(
+                "Failed to update upcoming fleets lists after cancelling fleets: {}",
+                e
+            );
+        }
+    }
+
+    /// Recreates the fleets cancelled by a stand-down.
+    ///
+    /// Skips the time, cooldown & downtime validation of `create` as the fleets return to
+    /// the schedule they were cancelled from. Each fleet is announced in Discord & relayed
+    /// like a newly created fleet, the upcoming fleets list of each Discord channel is
+    /// updated once for all of them. Failures are logged per fleet without failing the
+    /// remaining fleets.
+    ///
+    /// # Arguments
+    /// - `params` - The cancelled fleets with their field values
+    ///
+    /// # Returns
+    /// - `Ok(Vec<Fleet>)` - The recreated fleets
+    /// - `Err(AppError)` - Upcoming fleets list update failed
+    pub async fn bulk_restore(
+        &self,
+        params: Vec<CreateFleetParam>,
+    ) -> Result<Vec<Fleet>, AppError> {
+        let fleet_repo = FleetRepository::new(self.db);
+
+        let mut fleets = Vec::with_capacity(params.len());
+        for param in params {
+            let field_values = param.field_values.clone();
+            let fleet = match fleet_repo.create(param).await {
+                Ok(fleet) => fleet,
+                Err(e) => {
+                    dioxus_logger::tracing::warn!("Failed to restore cancelled fleet: {}", e);
+                    continue;
+                }
+            };
+
+            if let Err(e) = self.notify_created_fleet(&fleet, &field_values).await {
+                dioxus_logger::tracing::warn!(
+                    "Failed to announce restored fleet {}: {}",
+                    fleet.id,
+                    e
+                );
+            }
+
+            self.relay_created(&fleet, &field_values).await;
+
+            fleets.push(fleet);
+        }
+
+        // One update of the upcoming fleets list per channel for the whole batch
+        let category_ids: Vec<i32> = fleets.iter().map(|fleet| fleet.category_id).collect();
+        self.update_upcoming_fleets_lists(&category_ids).await?;
+
+        Ok(fleets)
+    }
+
+    /// Validates & applies the planned category and time of the fleets of a batch operation.
+    ///
+    /// Instead of editing the Discord messages of every fleet, a single summary of the
+    /// change is posted to each affected channel alongside one update of its upcoming
+    /// fleets list. Subscribers of rescheduled fleets are messaged about the new time, and
+    /// webhooks, live events & relayed copies are still updated per fleet. Failures after the update are logged without failing the
+    /// batch as the fleets were already updated.
+    ///
+    /// # Arguments
+    /// - `fleets` - The fleets before the change with their field values
+    /// - `planned` - Planned category & time of each fleet, in the same order
+    /// - `mapped_field_values` - Replacement field values by fleet ID for fleets moved to
+    ///   a category with another ping format
+    /// - `summary` - Description of the change posted to the affected channels
+    /// - `user_id` - Discord ID of the user who made the change
+    ///
+    /// # Returns
+    /// - `Ok(())` - All fleets were updated
+    /// - `Err(AppError::BadRequest(_))` - Conflict with cooldowns, nothing was updated
+    /// - `Err(AppError::Database(_))` - Database operation failed, nothing was updated
+    async fn apply_bulk_update(
+        &self,
+        fleets: Vec<(Fleet, HashMap<i32, String>)>,
+        planned: Vec<PlannedFleet>,
+        mapped_field_values: HashMap<i32, HashMap<i32, String>>,
+        summary: &str,
+        user_id: u64,
+    ) -> Result<(), AppError> {
+        // Validate the whole batch against existing fleets & each other
+        for ((fleet, _), planned_fleet) in fleets.iter().zip(&planned) {
+            self.validate_fleet_time_conflict(
+                planned_fleet.category_id,
+                planned_fleet.fleet_time,
+                Some(fleet.id),
+                &planned,
+            )
+            .await
+            .map_err(|e| batch_error(fleet, e))?;
+        }
+
+        let params = planned
+            .iter()
+            .map(|planned_fleet| UpdateFleetParam {
+                id: planned_fleet.fleet_id,
+                category_id: Some(planned_fleet.category_id),
+                name: None,
+                fleet_time: Some(planned_fleet.fleet_time),
+                description: None,
+                field_values: mapped_field_values.get(&planned_fleet.fleet_id).cloned(),
+                hidden: None,
+                disable_reminder: None,
+            })
+            .collect();
+        let updated_fleets = FleetRepository::new(self.db).update_many(params).await?;
+
+        let notification_service =
+            FleetNotificationService::new(self.db, self.discord_http.clone(), self.app_url.clone());
+
+        let mut category_ids = Vec::with_capacity(fleets.len() * 2);
+        for ((previous, field_values), fleet) in fleets.iter().zip(&updated_fleets) {
+            let field_values = mapped_field_values.get(&fleet.id).unwrap_or(field_values);
+
+            if fleet.fleet_time != previous.fleet_time {
+                self.notify_time_changed(
+                    &notification_service,
+                    fleet,
+                    field_values,
+                    previous.fleet_time,
+                )
+                .await;
+            }
+
+            if let Err(e) = self
+                .emit_webhook_event(fleet.id, WebhookEvent::Updated)
+                .await
+            {
+                dioxus_logger::tracing::warn!(
+                    "Failed to queue webhooks for updated fleet {}: {}",
+                    fleet.id,
+                    e
+                );
+            }
+
+            self.publish_fleet_event(FleetEventKind::Updated, fleet, Some(previous))
+                .await;
+
+            self.relay_updated(fleet, field_values).await;
+
+            category_ids.extend([previous.category_id, fleet.category_id]);
+        }
+
+        let changes: Vec<(&Fleet, &Fleet)> = fleets
+            .iter()
+            .map(|(previous, _)| previous)
+            .zip(&updated_fleets)
+            .collect();
+        if let Err(e) = notification_service
+            .post_bulk_update(BulkChange::Updated, summary, &changes, user_id)
+            .await
+        {
+            dioxus_logger::tracing::warn!("Failed to post bulk update summary: {}", e);
+        }
+
+        // One update of the upcoming fleets list per channel for the whole batch
+        self.update_upcoming_fleets_lists(&category_ids).await
+    }
+
+    /// Posts the Discord notifications & events for a newly created fleet.
+    ///
+    /// # Arguments
+    /// - `fleet` - The created fleet
+    /// - `field_values` - Map of field_id to value for custom ping format fields
+    ///
+    /// # Returns
+    /// - `Ok(())` - Creation notification posted & lists updated
+    /// - `Err(AppError)` - Discord notification or upcoming fleets list update failed
+    async fn notify_created(
+        &self,
+        fleet: &Fleet,
+        field_values: &HashMap<i32, String>,
+    ) -> Result<(), AppError> {
+        self.notify_created_fleet(fleet, field_values).await?;
+
+        // Update upcoming fleets lists for all channels in this category
+        self.update_upcoming_fleets_lists(&[fleet.category_id])
+            .await
+    }
+
+    /// Posts the Discord notifications & events for a newly created fleet.
+    ///
+    /// Leaves the upcoming fleets lists to the caller so a batch of fleets updates
+    /// each list once.
+    ///
+    /// # Arguments
+    /// - `fleet` - The created fleet
+    /// - `field_values` - Map of field_id to value for custom ping format fields
+    ///
+    /// # Returns
+    /// - `Ok(())` - Creation notification posted
+    /// - `Err(AppError)` - Discord notification failed
+    async fn notify_created_fleet(
+        &self,
+        fleet: &Fleet,
+        field_values: &HashMap<i32, String>,
+    ) -> Result<(), AppError> {
+        // Post fleet creation notification to Discord
+        let notification_service =
+            FleetNotificationService::new(self.db, self.discord_http.clone(), self.app_url.clone());
+        notification_service
+            .post_fleet_creation(fleet, field_values)
+            .await?;
+
+        notification_service.spawn_subscriber_dms(
+            fleet.clone(),
+            field_values.clone(),
+            DmNotification::Created,
+        );
+
+        if let Err(e) = self
+            .emit_webhook_event(fleet.id, WebhookEvent::Created)
+            .await
+        {
+            dioxus_logger::tracing::warn!(
+                "Failed to queue webhooks for created fleet {}: {}",
+                fleet.id,
+                e
+            );
+        }
+
+        self.publish_fleet_event(FleetEventKind::Created, fleet, None)
+            .await;
+
+        Ok(())
+    }
+
+    /// Updates the Discord notifications & posts events for an updated fleet.
+    ///
+    /// # Arguments
+    /// - `previous` - The fleet before the update
+    /// - `fleet` - The updated fleet
+    /// - `field_values` - Map of field_id to value for custom ping format fields
+    ///
+    /// # Returns
+    /// - `Ok(())` - Discord messages & lists updated
+    /// - `Err(AppError)` - Discord notification or upcoming fleets list update failed
+    async fn notify_updated(
+        &self,
+        previous: &Fleet,
+        fleet: &Fleet,
+        field_values: &HashMap<i32, String>,
+    ) -> Result<(), AppError> {
+        self.notify_updated_fleet(previous, fleet, field_values)
+            .await?;
+
+        // Update upcoming fleets lists for all channels in the new & the old category
+        self.update_upcoming_fleets_lists(&[fleet.category_id, previous.category_id])
+            .await
+    }
+
+    /// Updates the Discord messages & posts events of an updated fleet.
+    ///
+    /// Leaves the upcoming fleets lists to the caller so a batch of fleets updates
+    /// each list once.
+    ///
+    /// # Arguments
+    /// - `previous` - The fleet before the update
+    /// - `fleet` - The updated fleet
+    /// - `field_values` - Map of field_id to value for custom ping format fields
+    ///
+    /// # Returns
+    /// - `Ok(())` - Discord messages updated
+    /// - `Err(AppError)` - Discord notification update failed
+    async fn notify_updated_fleet(
+        &self,
+        previous: &Fleet,
+        fleet: &Fleet,
+        field_values: &HashMap<i32, String>,
+    ) -> Result<(), AppError> {
+        // Update Discord messages with new fleet information
+        let notification_service =
+            FleetNotificationService::new(self.db, self.discord_http.clone(), self.app_url.clone());
+        notification_service
+            .update_fleet_messages(fleet, field_values)
+            .await?;
+
+        if fleet.fleet_time != previous.fleet_time {
+            self.notify_time_changed(
+                &notification_service,
+                fleet,
+                field_values,
+                previous.fleet_time,
+            )
+            .await;
+        }
+
+        if let Err(e) = self
+            .emit_webhook_event(fleet.id, WebhookEvent::Updated)
+            .await
+        {
+            dioxus_logger::tracing::warn!(
+                "Failed to queue webhooks for updated fleet {}: {}",
+                fleet.id,
+                e
+            );
+        }
+
+        self.publish_fleet_event(FleetEventKind::Updated, fleet, Some(previous))
+            .await;
+
+        Ok(())
+    }
+
+    /// Posts the events & queues the webhooks of a deleted fleet.
+    ///
+    /// # Arguments
+    /// - `fleet` - The deleted fleet
+    /// - `fleet_dto` - Fleet payload for webhooks captured before the deletion
+    /// - `guild_id` - Discord guild ID the fleet belonged to
+    async fn notify_cancelled(&self, fleet: &Fleet, fleet_dto: Option<FleetDto>, guild_id: u64) {
+        self.publish_fleet_event(FleetEventKind::Deleted, fleet, None)
+            .await;
+
+        if let Some(fleet_dto) = fleet_dto {
+            if let Err(e) = WebhookService::new(self.db)
+                .emit(guild_id, WebhookEvent::Cancelled, fleet_dto)
+                .await
+            {
+                dioxus_logger::tracing::warn!(
+                    "Failed to queue webhooks for cancelled fleet {}: {}",
+                    fleet.id,
+                    e
+                );
+            }
+        }
+    }
+
+    /// Rejects changes to a fleet relayed from another guild.
+    ///
+    /// Relayed copies are owned by the source guild and only change along with the
+    /// fleet they were copied from.
+    ///
+    /// # Returns
+    /// - `Ok(())` - The fleet was created in its own guild
+    /// - `Err(AppError::BadRequest(_))` - The fleet is a relayed copy
+    /// - `Err(AppError::Database(_))` - Database operation failed
+    async fn require_not_relayed(&self, id: i32) -> Result<(), AppError> {
+        if RelayedFleetRepository::new(self.db)
+            .find_by_fleet_id(id)
+            .await?
+            .is_some()
+        {
+            return Err(AppError::BadRequest(
+                "Relayed fleets can only be changed in the server they were relayed from"
+                    .to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Copies a newly created fleet into the target categories of accepted relays.
+    ///
+    /// Copies skip the cooldown validation of the target category as the fleet time is
+    /// owned by the source guild, and are never relayed further. Failures are logged per
+    /// relay without failing the creation as the source fleet was created successfully.
+    ///
+    /// # Arguments
+    /// - `fleet` - The created source fleet
+    /// - `field_values` - Map of field_id to value of the source fleet
+    async fn relay_created(&self, fleet: &Fleet, field_values: &HashMap<i32, String>) {
+        let relays = match FleetRelayRepository::new(self.db)
+            .get_accepted_by_source_category(fleet.category_id)
+            .await
+        {
+            Ok(relays) => relays,
+            Err(e) => {
+                dioxus_logger::tracing::warn!(
+                    "Failed to get relays for created fleet {}: {}",
+                    fleet.id,
+                    e
+                );
+                return;
+            }
+        };
+
+        for relay in relays {
+            if let Err(e) = self.relay_fleet(&relay, fleet, field_values).await {
+                dioxus_logger::tracing::warn!(
+                    "Failed to relay fleet {} along relay {}: {}",
+                    fleet.id,
+                    relay.id,
+                    e
+                );
+            }
+        }
+    }
+
+    /// Creates the relayed copy of a fleet in the target category of a relay.
+    async fn relay_fleet(
+        &self,
+        relay: &FleetRelay,
+        fleet: &Fleet,
+        field_values: &HashMap<i32, String>,
+    ) -> Result<(), AppError> {
+        let Some(target_category_id) = relay.target_category_id else {
+            return Ok(());
+        };
+
+        let copy_field_values = self
+            .map_relayed_field_values(fleet.category_id, target_category_id, field_values)
+            .await?;
+
+        let copy = FleetRepository::new(self.db)
+            .create(CreateFleetParam {
+                category_id: target_category_id,
+                name: fleet.name.clone(),
+                commander_id: fleet.commander_id,
+                fleet_time: fleet.fleet_time,
+                description: fleet.description.clone(),
+                field_values: copy_field_values.clone(),
+                hidden: fleet.hidden,
+                disable_reminder: fleet.disable_reminder,
+            })
+            .await?;
+
+        RelayedFleetRepository::new(self.db)
+            .create(copy.id, fleet.id, relay.id)
+            .await?;
+
+        self.notify_created(&copy, &copy_field_values).await
+    }
+
+    /// Propagates an update of a source fleet to its relayed copies.
+    ///
+    /// Copies keep the category they were relayed into. Failures are logged per copy
+    /// without failing the update as the source fleet was updated successfully.
+    ///
+    /// # Arguments
+    /// - `fleet` - The updated source fleet
+    /// - `field_values` - Map of field_id to value of the source fleet
+    async fn relay_updated(&self, fleet: &Fleet, field_values: &HashMap<i32, String>) {
+        let copies = match RelayedFleetRepository::new(self.db)
+            .get_by_source_fleet(fleet.id)
+            .await
+        {
+            Ok(copies) => copies,
+            Err(e) => {
+                dioxus_logger::tracing::warn!(
+                    "Failed to get relayed copies of updated fleet {}: {}",
+                    fleet.id,
+                    e
+                );
+                return;
+            }
+        };
+
+        for copy in copies {
+            if let Err(e) = self
+                .update_relayed_fleet(copy.fleet_id, fleet, field_values)
+                .await
+            {
+                dioxus_logger::tracing::warn!(
+                    "Failed to update relayed copy {} of fleet {}: {}",
+                    copy.fleet_id,
+                    fleet.id,
+                    e
+                );
+            }
+        }
+    }
+
+    /// Updates a relayed copy to match its source fleet.
+    async fn update_relayed_fleet(
+        &self,
+        copy_id: i32,
+        fleet: &Fleet,
+        field_values: &HashMap<i32, String>,
+    ) -> Result<(), AppError> {
+        let fleet_repo = FleetRepository::new(self.db);
+
+        let Some((copy, _)) = fleet_repo.get_by_id(copy_id).await? else {
+            return Ok(());
+        };
+
+        let copy_field_values = self
+            .map_relayed_field_values(fleet.category_id, copy.category_id, field_values)
+            .await?;
+
+        let updated_copy = fleet_repo
+            .update(UpdateFleetParam {
+                id: copy.id,
+                category_id: None,
+                name: Some(fleet.name.clone()),
+                fleet_time: Some(fleet.fleet_time),
+                description: Some(fleet.description.clone()),
+                field_values: Some(copy_field_values.clone()),
+                hidden: Some(fleet.hidden),
+                disable_reminder: Some(fleet.disable_reminder),
+            })
+            .await?;
+
+        self.notify_updated(&copy, &updated_copy, &copy_field_values)
+            .await
+    }
+
+    /// Gets the relayed copies of a source fleet with the guild each was relayed into.
+    ///
+    /// # Arguments
+    /// - `source_fleet_id` - ID of the source fleet
+    ///
+    /// # Returns
+    /// - `Ok(Vec<(Fleet, HashMap<i32, String>, u64)>)` - Each copy with its field values
+    ///   and Discord guild ID
+    /// - `Err(AppError::NotFound(_))` - The category of a copy was not found
+    /// - `Err(AppError::Database(_))` - Database operation failed
+    async fn get_relayed_copies(
+        &self,
+        source_fleet_id: i32,
+    ) -> Result<Vec<(Fleet, HashMap<i32, String>, u64)>, AppError> {
+        let fleet_repo = FleetRepository::new(self.db);
+        let category_repo = FleetCategoryRepository::new(self.db);
+
+        let mut copies = Vec::new();
+        for relayed in RelayedFleetRepository::new(self.db)
+            .get_by_source_fleet(source_fleet_id)
+            .await?
+        {
+            let Some((copy, field_values)) = fleet_repo.get_by_id(relayed.fleet_id).await? else {
+                continue;
+            };
+
+            let Some(category) = category_repo.find_by_id(copy.category_id).await? else {
+                return Err(AppError::NotFound("Category not found".to_string()));
+            };
+            let guild_id = parse_u64_from_string(category.category.guild_id)?;
+
+            copies.push((copy, field_values, guild_id));
+        }
+
+        Ok(copies)
+    }
+
+    /// Validates typed field values & replaces solar system values with the canonical
+    /// name of the system.
+    ///
+    /// Values of solar system fields are matched against the bundled EVE static map
+    /// ignoring case, so "1dq1-a" is stored as "1DQ1-A". Values of doctrine fields must
+    /// be the ID of a doctrine of the category's guild. Empty values are left as is as
+    /// fields are optional.
+    ///
+    /// # Arguments
+    /// - `category_id` - Category whose ping format defines the field types
+    /// - `field_values` - Map of field_id to value submitted for the fleet
+    ///
+    /// # Returns
+    /// - `Ok(HashMap<i32, String>)` - Field values with canonical solar system names
+    /// - `Err(AppError::BadRequest(_))` - A value isn't a known solar system or doctrine
+    /// - `Err(AppError::NotFound(_))` - Category not found
+    /// - `Err(AppError::Database(_))` - Database operation failed
+    async fn resolve_field_values(
+        &self,
+        category_id: i32,
+        mut field_values: HashMap<i32, String>,
+    ) -> Result<HashMap<i32, String>, AppError> {
+        let Some(category) = FleetCategoryRepository::new(self.db)
+            .find_by_id(category_id)
+            .await?
+        else {
+            return Err(AppError::NotFound("Category not found".to_string()));
+        };
+
+        let guild_id = parse_u64_from_string(category.category.guild_id)?;
+        let fields = PingFormatFieldRepository::new(self.db)
+            .get_by_ping_format_id(guild_id, category.category.ping_format_id)
+            .await?;
+
+        for field in fields
+            .iter()
+            .filter(|f| f.field_type == PingFormatFieldType::SolarSystem)
+        {
+            let Some(value) = field_values.get_mut(&field.id) else {
+                continue;
+            };
+            if value.trim().is_empty() {
+                continue;
+            }
+
+            let Some(system) = eve_map().find_by_name(value) else {
+                return Err(AppError::BadRequest(format!(
+                    "Unknown solar system '{}' for {}",
+                    value.trim(),
+                    field.name
+                )));
+            };
+            *value = system.name.clone();
+        }
+
+        let mut doctrine_ids: Vec<(&str, i32)> = Vec::new();
+        for field in fields
+            .iter()
+            .filter(|f| f.field_type == PingFormatFieldType::Doctrine)
+        {
+            let Some(value) = field_values.get_mut(&field.id) else {
+                continue;
+            };
+            *value = value.trim().to_string();
+            if value.is_empty() {
+                continue;
+            }
+
+            let Ok(id) = value.parse::<i32>() else {
+                return Err(AppError::BadRequest(format!(
+                    "Invalid doctrine '{}' for {}",
+                    value, field.name
+                )));
+            };
+            doctrine_ids.push((&field.name, id));
+        }
+
+        if !doctrine_ids.is_empty() {
+            let doctrines = DoctrineRepository::new(self.db)
+                .find_by_ids(guild_id, doctrine_ids.iter().map(|(_, id)| *id).collect())
+                .await?;
+            if let Some((name, id)) = doctrine_ids
+                .iter()
+                .find(|(_, id)| !doctrines.contains_key(id))
+            {
+                return Err(AppError::BadRequest(format!(
+                    "Unknown doctrine ID {} for {}",
+                    id, name
+                )));
+            }
+        }
+
+        Ok(field_values)
+    }
+
+    /// Maps the field values of a source fleet onto the ping format of a target category.
+    ///
+    /// Fields are matched by name as each guild has its own ping formats, values of
+    /// fields the target ping format doesn't have are dropped. Doctrines are matched by
+    /// name as well as each guild has its own doctrine library, doctrine values without
+    /// a doctrine of the same name in the target guild are dropped.
+    ///
+    /// # Arguments
+    /// - `source_category_id` - Category of the source fleet
+    /// - `target_category_id` - Category of the relayed copy
+    /// - `field_values` - Map of field_id to value of the source fleet
+    ///
+    /// # Returns
+    /// - `Ok(HashMap<i32, String>)` - Map of target field_id to value
+    /// - `Err(AppError::NotFound(_))` - Category or ping format not found
+    /// - `Err(AppError::Database(_))` - Database operation failed
+    async fn map_relayed_field_values(
+        &self,
+        source_category_id: i32,
+        target_category_id: i32,
+        field_values: &HashMap<i32, String>,
+    ) -> Result<HashMap<i32, String>, AppError> {
+        let category_repo = FleetCategoryRepository::new(self.db);
+        let field_repo = PingFormatFieldRepository::new(self.db);
+        let doctrine_repo = DoctrineRepository::new(self.db);
+
+        let (Some(source), Some(target)) = (
+            category_repo.find_by_id(source_category_id).await?,
+            category_repo.find_by_id(target_category_id).await?,
+        ) else {
+            return Err(AppError::NotFound("Category not found".to_string()));
+        };
+
+        let source_guild_id = parse_u64_from_string(source.category.guild_id)?;
+        let target_guild_id = parse_u64_from_string(target.category.guild_id)?;
+        let source_fields = field_repo
+            .get_by_ping_format_id(source_guild_id, source.category.ping_format_id)
+            .await?;
+        let target_fields = field_repo
+            .get_by_ping_format_id(target_guild_id, target.category.ping_format_id)
+            .await?;
+
+        let has_doctrines = source_fields
+            .iter()
+            .chain(target_fields.iter())
+            .any(|f| f.field_type == PingFormatFieldType::Doctrine);
+        let (source_doctrines, target_doctrines) = if has_doctrines {
+            (
+                doctrine_repo.get_all_by_guild(source_guild_id).await?,
+                doctrine_repo.get_all_by_guild(target_guild_id).await?,
+            )
+        } else {
+            (Vec::new(), Vec::new())
+        };
+
+        let target_by_name: HashMap<&str, _> =
+            target_fields.iter().map(|f| (f.name.as_str(), f)).collect();
+
+        Ok(source_fields
+            .iter()
+            .filter_map(|source_field| {
+                let value = field_values.get(&source_field.id)?;
+                let target_field = target_by_name.get(source_field.name.as_str())?;
+
+                // Doctrine IDs only exist in their own guild, relay the doctrine's name
+                let value = if source_field.field_type == PingFormatFieldType::Doctrine {
+                    let id = value.parse::<i32>().ok()?;
+                    source_doctrines.iter().find(|d| d.id == id)?.name.clone()
+                } else {
+                    value.clone()
+                };
+                let value = if target_field.field_type == PingFormatFieldType::Doctrine {
+                    target_doctrines
+                        .iter()
+                        .find(|d| d.name.eq_ignore_ascii_case(value.trim()))?
+                        .id
+                        .to_string()
+                } else {
+                    value
+                };
+
+                Some((target_field.id, value))
+            })
+            .collect())
+    }
+
+    /// Gets the name of the guild a relayed copy was relayed from.
+    ///
+    /// # Returns
+    /// - `Ok(Some(String))` - Name of the source guild if the fleet is a relayed copy
+    /// - `Ok(None)` - The fleet was created in its own guild
+    /// - `Err(AppError::Database(_))` - Database operation failed
+    async fn get_relayed_from(&self, id: i32) -> Result<Option<String>, AppError> {
+        let Some(relayed) = RelayedFleetRepository::new(self.db)
+            .find_by_fleet_id(id)
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        let Some(relay) = FleetRelayRepository::new(self.db)
+            .find_by_id(relayed.relay_id)
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        let Some(source_category) = FleetCategoryRepository::new(self.db)
+            .find_by_id(relay.source_category_id)
+            .await?
+        else {
+            return Ok(None);
+        };
+        let source_guild_id = parse_u64_from_string(source_category.category.guild_id)?;
+
+        let source_guild = DiscordGuildRepository::new(self.db)
+            .find_by_guild_id(source_guild_id)
+            .await?;
+
+        Ok(Some(
+            source_guild
+                .map(|guild| guild.name)
+                .unwrap_or_else(|| "another server".to_string()),
+        ))
+    }
+
+    /// Notifies subscribers that the time of a fleet changed.
+    ///
+    /// Clears the personal reminders already sent for the fleet so subscribers are
+    /// reminded again ahead of the new time, the direct messages are sent in the
+    /// background. Failures are logged without failing the update as the fleet itself was
+    /// updated successfully.
+    ///
+    /// # Arguments
+    /// - `notification_service` - Notification service to send the direct messages with
+    /// - `fleet` - The updated fleet
+    /// - `field_values` - Map of field_id to value for custom ping format fields
+    /// - `previous_time` - Fleet time before the update
+    async fn notify_time_changed(
+        &self,
+        notification_service: &FleetNotificationService<'_>,
+        fleet: &Fleet,
+        field_values: &HashMap<i32, String>,
+        previous_time: DateTime<Utc>,
+    ) {
+        if let Err(e) = FleetDmReminderRepository::new(self.db)
+            .delete_by_fleet(fleet.id)
+            .await
+        {
+            dioxus_logger::tracing::warn!(
+                "Failed to reset direct message reminders of fleet {}: {}",
+                fleet.id,
+                e
+            );
+        }
+
+        notification_service.spawn_subscriber_dms(
+            fleet.clone(),
+            field_values.clone(),
+            DmNotification::TimeChanged { previous_time },
+        );
+    }
+
+    /// Publishes a fleet change to open timerboards.
+    ///
+    /// Failures are logged without failing the operation as the fleet itself was
+    /// changed successfully.
+    ///
+    /// # Arguments
+    /// - `kind` - Kind of change made to the fleet
+    /// - `fleet` - The fleet after the change, or before it was deleted
+    /// - `previous` - The fleet before an update
+    async fn publish_fleet_event(
+        &self,
+        kind: FleetEventKind,
+        fleet: &Fleet,
+        previous: Option<&Fleet>,
+    ) {
+        if let Err(e) = FleetEventService::new(self.db)
+            .publish(kind, fleet, previous)
+            .await
+        {
+            dioxus_logger::tracing::warn!(
+                "Failed to publish live event for fleet {}: {}",
+                fleet.id,
+                e
+            );
+        }
+    }
+
+    /// Queues outgoing webhook deliveries for a fleet lifecycle event.
+    ///
+    /// Builds the unfiltered fleet payload and queues it for every webhook of the fleet's
+    /// guild subscribed to the event. Deliveries are sent by the scheduler, so this does
+    /// not wait on any webhook endpoint.
+    ///
+    /// # Arguments
+    /// - `id` - ID of the fleet the event occurred for
+    /// - `event` - The fleet lifecycle event
+    ///
+    /// # Returns
+    /// - `Ok(())` - Deliveries queued (or fleet no longer exists)
+    /// - `Err(AppError::NotFound(_))` - Fleet category or commander not found
+    /// - `Err(AppError::Database(_))` - Database operation failed
+    pub async fn emit_webhook_event(&self, id: i32, event: WebhookEvent) -> Result<(), AppError> {
+        let category_repo = FleetCategoryRepository::new(self.db);
+
+        // Admin view so hidden fleets are included in the payload
+        let Some(fleet) = self.get_by_id(id, 0, true).await? else {
+            return Ok(());
+        };
+
+        let Some(category) = category_repo.find_by_id(fleet.category_id).await? else {
+            return Err(AppError::NotFound("Category not found".to_string()));
+        };
+        let guild_id = parse_u64_from_string(category.category.guild_id)?;
+
+        WebhookService::new(self.db)
+            .emit(guild_id, event, fleet)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Updates upcoming fleets lists for all channels configured for the categories.
+    ///
+    /// Called after fleet updates or deletions to reflect changes in the upcoming
+    /// fleets lists without bumping them to the most recent message. Channels shared by
+    /// several categories are only updated once.
+    ///
+    /// # Arguments
+    /// - `category_ids` - Category IDs whose channels should be updated
+    ///
+    /// # Returns
+    /// - `Ok(())` - Successfully updated all channel lists
+    /// - `Err(AppError)` - Database or Discord error
+    async fn update_upcoming_fleets_lists(&self, category_ids: &[i32]) -> Result<(), AppError> {
+        let category_repo = FleetCategoryRepository::new(self.db);
+
+        // Extract channel IDs from the channels of each category
+        let mut channel_ids: BTreeSet<u64> = BTreeSet::new();
+        for category_id in category_ids.iter().collect::<BTreeSet<_>>() {
+            let Some(category_data) = category_repo.find_by_id(*category_id).await? else {
+                continue; // Category not found, nothing to update
+            };
+
+            channel_ids.extend(
+                category_data
+                    .channels
+                    .iter()
+                    .filter_map(|(channel_model, _)| channel_model.channel_id.parse::<u64>().ok()),
+            );
+        }
+
+        if channel_ids.is_empty() {
+            return Ok(()); // No channels configured
+        }
+
+        // Update the upcoming fleets list for each channel
+        let notification_service =
+            FleetNotificationService::new(self.db, self.discord_http.clone(), self.app_url.clone());
+
+        for channel_id in channel_ids {
+            if let Err(e) = notification_service
+                .update_upcoming_fleets_list(channel_id)
+                .await
+            {
+                dioxus_logger::tracing::warn!(
+                    "Failed to update upcoming fleets list for channel {}: {}",
+                    channel_id,
                     e
                 );
                 // Continue updating other channels even if one fails
@@ -644,6 +2295,29 @@ impl<'a> FleetService<'a> {
                 })?
         };
 
+        Self::validate_fleet_time_with_min(fleet_time, min_time)?;
+
+        Ok(fleet_time)
+    }
+
+    /// Validates a fleet time with optional minimum time constraint for updates.
+    ///
+    /// Applies the same rules as `parse_fleet_time_with_min` to an already computed time,
+    /// such as a fleet time shifted by a batch operation.
+    ///
+    /// # Arguments
+    /// - `fleet_time` - Proposed fleet time
+    /// - `min_time` - Optional minimum time for updates (original fleet time)
+    ///
+    /// # Returns
+    /// - `Ok(())` - Fleet time is valid
+    /// - `Err(AppError::BadRequest(_))` - Time validation failed
+    fn validate_fleet_time_with_min(
+        fleet_time: DateTime<Utc>,
+        min_time: Option<DateTime<Utc>>,
+    ) -> Result<(), Box<AppError>> {
+        let now = Utc::now();
+
         // If min_time is provided and is in the past, validate against min_time
         if let Some(min_time) = min_time {
             if min_time < now && fleet_time < min_time {
@@ -669,7 +2343,50 @@ impl<'a> FleetService<'a> {
             }
         }
 
-        Ok(fleet_time)
+        Ok(())
+    }
+
+    /// Validates fleet time against the EVE downtime window of the category's guild.
+    ///
+    /// Fleets within the window are only rejected when the guild blocks them, guilds
+    /// warning about downtime are warned by the fleet forms before submitting.
+    ///
+    /// # Arguments
+    /// - `category_id` - Category the fleet is scheduled in
+    /// - `fleet_time` - Proposed fleet time
+    ///
+    /// # Returns
+    /// - `Ok(())` - Fleet time is allowed
+    /// - `Err(AppError::NotFound(_))` - Category not found
+    /// - `Err(AppError::BadRequest(_))` - Fleet time within downtime of a guild blocking it
+    /// - `Err(AppError::Database(_))` - Database operation failed
+    async fn validate_downtime(
+        &self,
+        category_id: i32,
+        fleet_time: DateTime<Utc>,
+    ) -> Result<(), AppError> {
+        let Some(category) = FleetCategoryRepository::new(self.db)
+            .find_by_id(category_id)
+            .await?
+        else {
+            return Err(AppError::NotFound("Category not found".to_string()));
+        };
+
+        let guild_id = parse_u64_from_string(category.category.guild_id)?;
+        let downtime = GuildSettingsRepository::new(self.db)
+            .get_by_guild(guild_id)
+            .await?
+            .downtime;
+
+        if downtime.policy == DowntimePolicy::Block && downtime.contains(fleet_time) {
+            return Err(AppError::BadRequest(format!(
+                "Fleet time {} falls within EVE downtime ({}), pick a time outside of it",
+                fleet_time.format("%Y-%m-%d %H:%M UTC"),
+                downtime.label()
+            )));
+        }
+
+        Ok(())
     }
 
     /// Validates fleet time doesn't conflict with category cooldown settings.
@@ -678,10 +2395,15 @@ impl<'a> FleetService<'a> {
     /// in the same category is scheduled within the cooldown window (before or after the
     /// proposed time).
     ///
+    /// Fleets of a batch operation are checked at their planned time instead of the time
+    /// currently stored, so fleets moved together don't conflict with where the others
+    /// used to be but do conflict with where they are going.
+    ///
     /// # Arguments
     /// - `category_id` - Category ID to check for conflicts
     /// - `fleet_time` - Proposed fleet time
     /// - `exclude_fleet_id` - Optional fleet ID to exclude from check (for updates)
+    /// - `batch` - Planned category & time of the fleets changed by the same operation
     ///
     /// # Returns
     /// - `Ok(())` - No conflicts found
@@ -693,9 +2415,16 @@ impl<'a> FleetService<'a> {
         category_id: i32,
         fleet_time: DateTime<Utc>,
         exclude_fleet_id: Option<i32>,
+        batch: &[PlannedFleet],
     ) -> Result<(), AppError> {
         let fleet_category_repo = FleetCategoryRepository::new(self.db);
 
+        // Fleets of the batch are checked at their planned time below
+        let excluded_ids: Vec<i32> = exclude_fleet_id
+            .into_iter()
+            .chain(batch.iter().map(|planned| planned.fleet_id))
+            .collect();
+
         // Get the category to check ping_cooldown and ping_group settings
         let Some(category) = fleet_category_repo.find_by_id(category_id).await? else {
             return Err(AppError::NotFound("Category not found".to_string()));
@@ -723,18 +2452,28 @@ impl<'a> FleetService<'a> {
 
                     // Query for conflicting fleets across ALL categories in the ping group
                     let mut query = entity::prelude::Fleet::find()
-                        .filter(entity::fleet::Column::CategoryId.is_in(category_ids))
+                        .filter(entity::fleet::Column::CategoryId.is_in(category_ids.clone()))
                         .filter(entity::fleet::Column::FleetTime.gte(time_window_start))
                         .filter(entity::fleet::Column::FleetTime.lte(time_window_end));
 
                     // Exclude the current fleet if updating
-                    if let Some(exclude_id) = exclude_fleet_id {
-                        query = query.filter(entity::fleet::Column::Id.ne(exclude_id));
+                    if !excluded_ids.is_empty() {
+                        query =
+                            query.filter(entity::fleet::Column::Id.is_not_in(excluded_ids.clone()));
                     }
 
-                    let conflicting_fleet = query.one(self.db).await?;
+                    let conflict_time = match query.one(self.db).await? {
+                        Some(conflict) => Some(conflict.fleet_time),
+                        None => find_batch_conflict(
+                            batch,
+                            exclude_fleet_id,
+                            &category_ids,
+                            time_window_start,
+                            time_window_end,
+                        ),
+                    };
 
-                    if let Some(conflict) = conflicting_fleet {
+                    if let Some(conflict_time) = conflict_time {
                         let cooldown_minutes = cooldown_seconds / 60;
                         let hours = cooldown_minutes / 60;
                         let minutes = cooldown_minutes % 60;
@@ -751,7 +2490,7 @@ impl<'a> FleetService<'a> {
                             Conflicting fleet at {}",
                             ping_group.name,
                             cooldown_display,
-                            conflict.fleet_time.format("%Y-%m-%d %H:%M UTC")
+                            conflict_time.format("%Y-%m-%d %H:%M UTC")
                         )));
                     }
                 }
@@ -772,13 +2511,22 @@ impl<'a> FleetService<'a> {
                 .filter(entity::fleet::Column::FleetTime.lte(time_window_end));
 
             // Exclude the current fleet if updating
-            if let Some(exclude_id) = exclude_fleet_id {
-                query = query.filter(entity::fleet::Column::Id.ne(exclude_id));
+            if !excluded_ids.is_empty() {
+                query = query.filter(entity::fleet::Column::Id.is_not_in(excluded_ids));
             }
 
-            let conflicting_fleet = query.one(self.db).await?;
+            let conflict_time = match query.one(self.db).await? {
+                Some(conflict) => Some(conflict.fleet_time),
+                None => find_batch_conflict(
+                    batch,
+                    exclude_fleet_id,
+                    &[category_id],
+                    time_window_start,
+                    time_window_end,
+                ),
+            };
 
-            if let Some(conflict) = conflicting_fleet {
+            if let Some(conflict_time) = conflict_time {
                 let cooldown_minutes = cooldown_seconds / 60;
                 let hours = cooldown_minutes / 60;
                 let minutes = cooldown_minutes % 60;
@@ -794,7 +2542,7 @@ impl<'a> FleetService<'a> {
                     Category requires a minimum spacing of {} between fleets. \
                     Conflicting fleet at {}",
                     cooldown_display,
-                    conflict.fleet_time.format("%Y-%m-%d %H:%M UTC")
+                    conflict_time.format("%Y-%m-%d %H:%M UTC")
                 )));
             }
         }
@@ -802,3 +2550,147 @@ impl<'a> FleetService<'a> {
         Ok(())
     }
 }
+
+/// Prefixes a validation error of a batch operation with the fleet it is about.
+fn batch_error(fleet: &Fleet, error: AppError) -> AppError {
+    match error {
+        AppError::BadRequest(message) => {
+            AppError::BadRequest(format!("Fleet '{}': {}", fleet.name, message))
+        }
+        error => error,
+    }
+}
+
+/// How the Discord channels of cancelled fleets are told about the cancellation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CancellationNotice {
+    /// The messages of each fleet are edited into a cancellation notice
+    Messages,
+    /// One summary per channel lists the cancelled fleets
+    Summary {
+        /// Discord ID of the user who cancelled the fleets
+        user_id: u64,
+    },
+    /// The caller announces the cancellation itself, such as a stand-down broadcast
+    Silent,
+}
+
+/// Fleets about to be cancelled along with everything needed to announce the cancellation.
+pub struct PreparedCancellation {
+    fleets: Vec<CancelledFleet>,
+}
+
+impl PreparedCancellation {
+    /// IDs of the fleets to delete, including relayed copies
+    pub fn fleet_ids(&self) -> Vec<i32> {
+        self.fleets
+            .iter()
+            .map(|cancelled| cancelled.fleet.id)
+            .collect()
+    }
+}
+
+/// Fleet about to be cancelled, captured before it is deleted.
+struct CancelledFleet {
+    fleet: Fleet,
+    field_values: HashMap<i32, String>,
+    /// Discord guild ID the fleet belongs to
+    guild_id: u64,
+    /// Fleet payload for webhooks
+    fleet_dto: Option<FleetDto>,
+    /// Discord messages & subscribers of the fleet
+    cancellation: FleetCancellation,
+}
+
+/// Category & time a batch operation is about to give one of its fleets.
+struct PlannedFleet {
+    fleet_id: i32,
+    category_id: i32,
+    fleet_time: DateTime<Utc>,
+}
+
+/// Finds a fleet of a batch planned within a cooldown window.
+///
+/// # Arguments
+/// - `batch` - Planned category & time of the fleets of the batch
+/// - `exclude_fleet_id` - Fleet being checked, which can't conflict with itself
+/// - `category_ids` - Categories sharing the cooldown
+/// - `window_start` - Start of the cooldown window (inclusive)
+/// - `window_end` - End of the cooldown window (inclusive)
+///
+/// # Returns
+/// - `Some(DateTime<Utc>)` - Planned time of the first conflicting fleet
+/// - `None` - No fleet of the batch is planned within the window
+fn find_batch_conflict(
+    batch: &[PlannedFleet],
+    exclude_fleet_id: Option<i32>,
+    category_ids: &[i32],
+    window_start: DateTime<Utc>,
+    window_end: DateTime<Utc>,
+) -> Option<DateTime<Utc>> {
+    batch
+        .iter()
+        .filter(|planned| Some(planned.fleet_id) != exclude_fleet_id)
+        .filter(|planned| category_ids.contains(&planned.category_id))
+        .find(|planned| planned.fleet_time >= window_start && planned.fleet_time <= window_end)
+        .map(|planned| planned.fleet_time)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone};
+
+    fn planned(fleet_id: i32, category_id: i32, fleet_time: DateTime<Utc>) -> PlannedFleet {
+        PlannedFleet {
+            fleet_id,
+            category_id,
+            fleet_time,
+        }
+    }
+
+    /// Tests that fleets of a batch conflict with each other at their planned times.
+    ///
+    /// Expected: The planned time of the other fleet within the window of the same category
+    #[test]
+    fn finds_conflict_within_batch() {
+        let time = Utc.with_ymd_and_hms(2026, 1, 1, 19, 0, 0).unwrap();
+        let batch = [
+            planned(1, 10, time),
+            planned(2, 10, time + Duration::minutes(30)),
+        ];
+
+        let conflict = find_batch_conflict(
+            &batch,
+            Some(1),
+            &[10],
+            time - Duration::hours(1),
+            time + Duration::hours(1),
+        );
+
+        assert_eq!(conflict, Some(time + Duration::minutes(30)));
+    }
+
+    /// Tests that the fleet being checked and fleets of other categories are ignored.
+    ///
+    /// Expected: None as the only fleet in the window is the checked fleet itself
+    #[test]
+    fn ignores_checked_fleet_and_other_categories() {
+        let time = Utc.with_ymd_and_hms(2026, 1, 1, 19, 0, 0).unwrap();
+        let batch = [
+            planned(1, 10, time),
+            planned(2, 20, time + Duration::minutes(30)),
+            planned(3, 10, time + Duration::hours(3)),
+        ];
+
+        let conflict = find_batch_conflict(
+            &batch,
+            Some(1),
+            &[10],
+            time - Duration::hours(1),
+            time + Duration::hours(1),
+        );
+
+        assert_eq!(conflict, None);
+    }
+}
@@ -0,0 +1,256 @@
+//! Live fleet event service.
+//!
+//! This module provides the in-process broadcast of fleet changes used to update open
+//! timerboards live, and the `FleetEventService` which publishes fleet changes and decides
+//! which users may receive them. Events follow the same visibility rules as fleet
+//! retrieval:
+//! - Users must have view, create or manage permission for the fleet's category
+//! - Hidden fleets are only sent to users with create/manage permissions until revealed
+//! - Admins receive every event of the guild
+//!
+//! Revealing a hidden fleet doesn't change it, so the scheduler publishes an update for
+//! each fleet once its reveal time passes to show it to viewers.
+
+use chrono::{DateTime, Utc};
+use sea_orm::{ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter};
+use std::sync::LazyLock;
+use tokio::sync::broadcast::{self, error::RecvError};
+
+use crate::{
+    model::fleet::FleetEventDto,
+    server::{
+        data::{
+            category::FleetCategoryRepository,
+            user_category_permission::UserCategoryPermissionRepository,
+        },
+        error::AppError,
+        model::{
+            fleet::Fleet,
+            fleet_event::{FleetEvent, FleetEventKind, FleetVisibility},
+        },
+        util::parse::parse_u64_from_string,
+    },
+};
+
+/// Number of events buffered per stream before a slow stream is told to resync
+const FLEET_EVENT_CAPACITY: usize = 256;
+
+static FLEET_EVENTS: LazyLock<broadcast::Sender<FleetEvent>> =
+    LazyLock::new(|| broadcast::channel(FLEET_EVENT_CAPACITY).0);
+
+/// Subscribes to the fleet events published from now on.
+pub fn subscribe_fleet_events() -> broadcast::Receiver<FleetEvent> {
+    FLEET_EVENTS.subscribe()
+}
+
+/// Service for publishing live fleet events & filtering them per user.
+pub struct FleetEventService<'a> {
+    db: &'a DatabaseConnection,
+}
+
+impl<'a> FleetEventService<'a> {
+    pub fn new(db: &'a DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// Publishes a fleet change to every open event stream.
+    ///
+    /// # Arguments
+    /// - `kind` - Kind of change made to the fleet
+    /// - `fleet` - The fleet after the change, or before it was deleted
+    /// - `previous` - The fleet before an update, so viewers who lost access to it are
+    ///   still told to remove it
+    ///
+    /// # Returns
+    /// - `Ok(())` - Event published (published events without open streams are dropped)
+    /// - `Err(AppError::NotFound(_))` - Fleet category not found
+    /// - `Err(AppError::Database(_))` - Database error retrieving the category
+    pub async fn publish(
+        &self,
+        kind: FleetEventKind,
+        fleet: &Fleet,
+        previous: Option<&Fleet>,
+    ) -> Result<(), AppError> {
+        let category_repo = FleetCategoryRepository::new(self.db);
+
+        let mut visibility = Vec::new();
+        let mut guild_id = None;
+        for state in previous.into_iter().chain([fleet]) {
+            let Some(category) = category_repo.find_by_id(state.category_id).await? else {
+                return Err(AppError::NotFound("Category not found".to_string()));
+            };
+
+            guild_id = Some(parse_u64_from_string(category.category.guild_id)?);
+            visibility.push(FleetVisibility::new(state, category.category.ping_reminder));
+        }
+
+        let Some(guild_id) = guild_id else {
+            return Ok(());
+        };
+
+        // Sending only fails when no stream is open
+        let _ = FLEET_EVENTS.send(FleetEvent {
+            kind,
+            guild_id,
+            fleet_id: fleet.id,
+            visibility,
+        });
+
+        Ok(())
+    }
+
+    /// Publishes an update for every hidden fleet revealed to category viewers within a
+    /// time window.
+    ///
+    /// Each fleet's reveal time is derived the same way as for event visibility, so the
+    /// update reaches viewers who couldn't see the fleet before.
+    ///
+    /// # Arguments
+    /// - `since` - End of the previously checked window (exclusive)
+    /// - `now` - Current time (inclusive)
+    ///
+    /// # Returns
+    /// - `Ok(usize)` - Number of revealed fleets an update was published for
+    /// - `Err(AppError::Database(_))` - Database error retrieving fleets or categories
+    pub async fn publish_reveals(
+        &self,
+        since: DateTime<Utc>,
+        now: DateTime<Utc>,
+    ) -> Result<usize, AppError> {
+        // Fleets are revealed at or before fleet time, so fleets before the window are
+        // already visible
+        let fleets = entity::prelude::Fleet::find()
+            .filter(entity::fleet::Column::Hidden.eq(true))
+            .filter(entity::fleet::Column::FleetTime.gt(since))
+            .find_also_related(entity::prelude::FleetCategory)
+            .all(self.db)
+            .await?;
+
+        let mut revealed = 0;
+        for (fleet, category) in fleets {
+            let Some(category) = category else {
+                continue;
+            };
+
+            let fleet = Fleet::from_entity(fleet)?;
+            let visibility = FleetVisibility::new(&fleet, category.ping_reminder);
+            if !visibility.is_revealed_between(since, now) {
+                continue;
+            }
+
+            // Sending only fails when no stream is open
+            let _ = FLEET_EVENTS.send(FleetEvent {
+                kind: FleetEventKind::Updated,
+                guild_id: parse_u64_from_string(category.guild_id)?,
+                fleet_id: fleet.id,
+                visibility: vec![visibility],
+            });
+            revealed += 1;
+        }
+
+        Ok(revealed)
+    }
+
+    /// Waits for the next fleet event of a guild the user may receive.
+    ///
+    /// Events of other guilds and events the user can't see are skipped. When the stream
+    /// fell behind and events were dropped, a resync is returned so the client refetches.
+    ///
+    /// # Arguments
+    /// - `receiver` - Receiver from `subscribe_fleet_events` owned by the user's stream
+    /// - `guild_id` - Discord ID of the guild the stream is for
+    /// - `user_id` - Discord ID of the user the stream belongs to
+    /// - `is_admin` - Whether the user is an admin
+    ///
+    /// # Returns
+    /// - `Some(FleetEventDto)` - The next event to send to the user
+    /// - `None` - The event channel was closed
+    pub async fn next_for_user(
+        &self,
+        receiver: &mut broadcast::Receiver<FleetEvent>,
+        guild_id: u64,
+        user_id: u64,
+        is_admin: bool,
+    ) -> Option<FleetEventDto> {
+        loop {
+            let event = match receiver.recv().await {
+                Ok(event) => event,
+                Err(RecvError::Lagged(_)) => return Some(FleetEventDto::Resync),
+                Err(RecvError::Closed) => return None,
+            };
+
+            if event.guild_id != guild_id {
+                continue;
+            }
+
+            match self
+                .can_receive(user_id, is_admin, &event, Utc::now())
+                .await
+            {
+                Ok(true) => return Some(event.into_dto()),
+                Ok(false) => continue,
+                Err(e) => {
+                    dioxus_logger::tracing::warn!(
+                        "Failed to check access of user {} to fleet {} event: {}",
+                        user_id,
+                        event.fleet_id,
+                        e
+                    );
+                }
+            }
+        }
+    }
+
+    /// Returns whether a user may receive a fleet event.
+    ///
+    /// # Arguments
+    /// - `user_id` - Discord ID of the user the stream belongs to
+    /// - `is_admin` - Whether the user is an admin (receives every event)
+    /// - `event` - The fleet event
+    /// - `now` - Current time for revealing hidden fleets
+    ///
+    /// # Returns
+    /// - `Ok(true)` - The fleet is visible to the user before or after the change
+    /// - `Ok(false)` - The user can't see the fleet
+    /// - `Err(AppError::Database(_))` - Database error checking permissions
+    pub async fn can_receive(
+        &self,
+        user_id: u64,
+        is_admin: bool,
+        event: &FleetEvent,
+        now: DateTime<Utc>,
+    ) -> Result<bool, AppError> {
+        if is_admin {
+            return Ok(true);
+        }
+
+        let permission_repo = UserCategoryPermissionRepository::new(self.db);
+
+        for visibility in &event.visibility {
+            let can_create = permission_repo
+                .user_can_create_category(user_id, visibility.category_id)
+                .await?;
+            let can_manage = permission_repo
+                .user_can_manage_category(user_id, visibility.category_id)
+                .await?;
+
+            // Users with create or manage permission can always see hidden fleets
+            if can_create || can_manage {
+                return Ok(true);
+            }
+
+            if visibility.is_hidden_at(now) {
+                continue;
+            }
+
+            if permission_repo
+                .user_can_view_category(user_id, visibility.category_id)
+                .await?
+            {
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
+}
@@ -0,0 +1,209 @@
+//! Ad-hoc broadcast operations.
+//!
+//! This module provides functionality for posting broadcasts which aren't tied to a
+//! scheduled fleet. Broadcasts target channels configured for a guild's fleet categories
+//! and ping roles configured for those categories, resolved the same way the ping roles
+//! of fleet notifications are.
+
+use dioxus_logger::tracing;
+use serenity::all::CreateMessage;
+use std::collections::BTreeMap;
+
+use crate::server::{
+    data::category::FleetCategoryRepository,
+    error::AppError,
+    metrics::metrics,
+    model::broadcast::{Broadcast, BroadcastChannel, BroadcastRole, BroadcastTargets},
+    util::parse::parse_u64_from_string,
+};
+
+use super::{build_role_ping_content, builder, category_ping_role_ids, FleetNotificationService};
+
+/// Category channel configuration alongside the synced guild channel
+type CategoryChannel = (
+    entity::fleet_category_channel::Model,
+    Option<entity::discord_guild_channel::Model>,
+);
+
+/// Channels & ping roles of a set of categories keyed by their Discord IDs
+struct CategoryTargets {
+    channels: BTreeMap<u64, CategoryChannel>,
+    roles: BTreeMap<u64, Option<entity::discord_guild_role::Model>>,
+}
+
+impl<'a> FleetNotificationService<'a> {
+    /// Collects the channels & ping roles configured for the given categories.
+    ///
+    /// Channels shared by several categories are included once, using the configuration
+    /// of the first category they were found in.
+    ///
+    /// # Arguments
+    /// - `category_ids` - IDs of the categories to collect the targets of
+    ///
+    /// # Returns
+    /// - `Ok(CategoryTargets)` - Channels & roles keyed by their Discord IDs
+    /// - `Err(AppError::NotFound)` - A fleet category was not found
+    /// - `Err(AppError::InternalError)` - Invalid ID format
+    /// - `Err(AppError::Database)` - Database error retrieving categories
+    async fn collect_category_targets(
+        &self,
+        category_ids: &[i32],
+    ) -> Result<CategoryTargets, AppError> {
+        let mut targets = CategoryTargets {
+            channels: BTreeMap::new(),
+            roles: BTreeMap::new(),
+        };
+
+        for &category_id in category_ids {
+            let (category_data, _) = self.get_category_data_with_guild_id(category_id).await?;
+
+            let role_ids = category_ping_role_ids(&category_data)?;
+            for (role_id, (_, role_model)) in role_ids.into_iter().zip(category_data.ping_roles) {
+                targets.roles.entry(role_id).or_insert(role_model);
+            }
+
+            for channel in category_data.channels {
+                let channel_id = parse_u64_from_string(channel.0.channel_id.clone())?;
+                targets.channels.entry(channel_id).or_insert(channel);
+            }
+        }
+
+        Ok(targets)
+    }
+
+    /// Gets the channels & ping roles a broadcast to the given categories can target.
+    ///
+    /// # Arguments
+    /// - `category_ids` - IDs of the categories the user can broadcast to
+    ///
+    /// # Returns
+    /// - `Ok(BroadcastTargets)` - Channels ordered by guild position, roles highest first
+    /// - `Err(AppError::NotFound)` - A fleet category was not found
+    /// - `Err(AppError::InternalError)` - Invalid ID format
+    /// - `Err(AppError::Database)` - Database error retrieving categories
+    pub async fn get_broadcast_targets(
+        &self,
+        category_ids: &[i32],
+    ) -> Result<BroadcastTargets, AppError> {
+        let targets = self.collect_category_targets(category_ids).await?;
+
+        let mut channels: Vec<(i32, BroadcastChannel)> = targets
+            .channels
+            .into_iter()
+            .map(|(channel_id, (_, guild_channel))| {
+                let position = guild_channel.as_ref().map(|c| c.position).unwrap_or(0);
+                let name = guild_channel
+                    .map(|c| c.name)
+                    .unwrap_or_else(|| format!("Unknown Channel ({})", channel_id));
+                (position, BroadcastChannel { channel_id, name })
+            })
+            .collect();
+        channels.sort_by_key(|(position, _)| *position);
+
+        let mut roles: Vec<(i16, BroadcastRole)> = targets
+            .roles
+            .into_iter()
+            .map(|(role_id, role_model)| match role_model {
+                Some(role) => (
+                    role.position,
+                    BroadcastRole {
+                        role_id,
+                        name: role.name,
+                        color: role.color,
+                    },
+                ),
+                None => (
+                    0,
+                    BroadcastRole {
+                        role_id,
+                        name: format!("Unknown Role ({})", role_id),
+                        color: "#99aab5".to_string(),
+                    },
+                ),
+            })
+            .collect();
+        roles.sort_by_key(|(position, _)| std::cmp::Reverse(*position));
+
+        Ok(BroadcastTargets {
+            channels: channels.into_iter().map(|(_, channel)| channel).collect(),
+            roles: roles.into_iter().map(|(_, role)| role).collect(),
+        })
+    }
+
+    /// Posts a broadcast to its channels, pinging its roles.
+    ///
+    /// Channels which are no longer configured for any category of the guild are
+    /// skipped. Continues posting to remaining channels even if individual posts fail.
+    ///
+    /// # Arguments
+    /// - `broadcast` - The broadcast to post
+    ///
+    /// # Returns
+    /// - `Ok(usize)` - Amount of channels the broadcast was posted to
+    /// - `Err(AppError::InternalError)` - Invalid ID format or timestamp
+    /// - `Err(AppError::Database)` - Database error retrieving categories or settings
+    pub async fn post_broadcast(&self, broadcast: &Broadcast) -> Result<usize, AppError> {
+        let guild_id = broadcast.guild_id;
+        let settings = self.get_guild_settings(guild_id).await?;
+
+        let category_ids = FleetCategoryRepository::new(self.db)
+            .get_ids_by_guild(guild_id)
+            .await?;
+        let targets = self.collect_category_targets(&category_ids).await?;
+
+        let embed = builder::build_broadcast_embed(
+            &broadcast.title,
+            &broadcast.message,
+            broadcast.author_id,
+            &settings,
+            &self.app_url,
+        )?;
+        let content = build_role_ping_content(
+            &format!("**{}**", broadcast.title),
+            &broadcast.role_ids,
+            guild_id,
+        );
+
+        let mut posted = 0;
+        for channel_id in &broadcast.channel_ids {
+            let Some(channel) = targets.channels.get(channel_id) else {
+                tracing::warn!(
+                    "Skipping broadcast {} to channel {} which is no longer configured",
+                    broadcast.id,
+                    channel_id
+                );
+                continue;
+            };
+
+            let message = CreateMessage::new()
+                .content(content.clone())
+                .embed(embed.clone());
+            match self
+                .send_channel_message(&broadcast.title, *channel_id, channel, message, None)
+                .await
+            {
+                Ok(_) => {
+                    posted += 1;
+                    metrics().record_notification("broadcast");
+
+                    tracing::info!(
+                        "Posted broadcast {} to channel {}",
+                        broadcast.id,
+                        channel_id
+                    );
+                }
+                Err(e) => {
+                    tracing::error!(
+                        "Failed to post broadcast {} to channel {}: {}",
+                        broadcast.id,
+                        channel_id,
+                        e
+                    );
+                    // Continue posting to other channels even if one fails
+                }
+            }
+        }
+
+        Ok(posted)
+    }
+}
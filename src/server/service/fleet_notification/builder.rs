@@ -4,18 +4,28 @@
 //! display names for fleet notifications. These utilities are shared across posting
 //! and update operations to ensure consistent formatting.
 
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
 use dioxus_logger::tracing;
 use serenity::{
     all::{CreateEmbed, GuildId, Timestamp},
     http::Http,
 };
-use std::sync::Arc;
+use std::{collections::HashMap, str::FromStr, sync::Arc};
 
 use crate::{
     model::ping_format::PingFormatFieldType,
     server::{
         error::{internal::InternalError, AppError},
-        model::{fleet::Fleet, ping_format::PingFormatField},
+        eve_map::eve_map,
+        metrics::discord_call,
+        model::{
+            doctrine::Doctrine,
+            fleet::Fleet,
+            guild_settings::{EmbedKind, GuildSettings},
+            ping_format::PingFormatField,
+            solar_system::StagingRoute,
+        },
     },
 };
 
@@ -42,7 +52,12 @@ pub async fn get_commander_name(
     let guild_id = GuildId::new(guild_id);
 
     // Try to fetch member from guild to get nickname
-    match http.get_member(guild_id, fleet.commander_id.into()).await {
+    match discord_call(
+        "get_member",
+        http.get_member(guild_id, fleet.commander_id.into()),
+    )
+    .await
+    {
         Ok(member) => {
             // Use nickname if available, otherwise use Discord username
             Ok(member.nick.unwrap_or_else(|| member.user.name.clone()))
@@ -60,18 +75,48 @@ pub async fn get_commander_name(
     }
 }
 
+/// Builds the link to a fleet's detail page on the timerboard.
+///
+/// # Arguments
+/// - `app_url` - Base application URL, with or without a trailing slash
+/// - `guild_id` - Discord guild ID the fleet belongs to
+/// - `fleet_id` - ID of the fleet
+///
+/// # Returns
+/// - `String` - URL of the fleet detail page
+pub fn fleet_url(app_url: &str, guild_id: u64, fleet_id: i32) -> String {
+    format!(
+        "{}/guilds/{}/fleets/{}",
+        app_url.trim_end_matches('/'),
+        guild_id,
+        fleet_id
+    )
+}
+
+/// Ping format fields of a fleet along with the data resolved from their values.
+pub struct EmbedFields<'a> {
+    /// Ping format field definitions from the database
+    pub fields: &'a [PingFormatField],
+    /// Map of field_id to value for custom fields
+    pub field_values: &'a HashMap<i32, String>,
+    /// Stargate route from staging to the fleet's form-up system
+    pub route: Option<&'a StagingRoute>,
+    /// Doctrines referenced by doctrine fields, by doctrine ID
+    pub doctrines: &'a HashMap<i32, Doctrine>,
+}
+
 /// Builds a Discord embed for a fleet notification.
 ///
 /// Creates a rich embed with fleet details including FC mention, fleet time in both
 /// UTC and local formats, custom ping format fields, and optional description. The
-/// embed includes the fleet name as title, application URL as clickable link, and
+/// embed includes the fleet name as title linking to the fleet's detail page, and
 /// a footer with the commander's name and current timestamp.
 ///
 /// # Arguments
 /// - `fleet` - Fleet domain model containing event details
-/// - `fields` - Ping format field definitions from the database
-/// - `field_values` - Map of field_id to value for custom fields
-/// - `color` - Embed color as hex integer
+/// - `embed_fields` - Ping format fields, their values, route & referenced doctrines
+/// - `settings` - Settings of the guild the fleet belongs to
+/// - `kind` - Kind of message, selects the guild's embed color
 /// - `commander_name` - Display name of the fleet commander
 /// - `app_url` - Base application URL for embed link
 ///
@@ -80,16 +125,16 @@ pub async fn get_commander_name(
 /// - `Err(AppError::InternalError)` - Invalid commander ID or timestamp format
 pub async fn build_fleet_embed(
     fleet: &Fleet,
-    fields: &[PingFormatField],
-    field_values: &std::collections::HashMap<i32, String>,
-    color: u32,
+    embed_fields: &EmbedFields<'_>,
+    settings: &GuildSettings,
+    kind: EmbedKind,
     commander_name: &str,
     app_url: &str,
 ) -> Result<CreateEmbed, AppError> {
     let mut embed = CreateEmbed::new()
         .title(&fleet.name)
-        .url(app_url)
-        .color(color)
+        .url(fleet_url(app_url, settings.guild_id, fleet.id))
+        .color(settings.embed_color(kind))
         .field("FC", format!("<@{}>", fleet.commander_id), false);
 
     // Use current time for "sent at" timestamp
@@ -105,9 +150,12 @@ pub async fn build_fleet_embed(
         .field(
             "Start Time (UTC)",
             format!(
-                "{} EVE Time",
-                fleet.fleet_time.format("%Y-%m-%d %H:%M").to_string()
-            ),
+                "{} {}",
+                fleet.fleet_time.format("%Y-%m-%d %H:%M"),
+                settings.time_label
+            )
+            .trim_end()
+            .to_string(),
             false,
         )
         .field(
@@ -120,24 +168,45 @@ pub async fn build_fleet_embed(
             false,
         );
 
+    if let Some(extra_times) = format_extra_times(fleet.fleet_time, &settings.extra_timezones) {
+        embed = embed.field("Start Time (Other Timezones)", extra_times, false);
+    }
+
     // Add custom fields from ping format
-    for field in fields {
-        if let Some(value) = field_values.get(&field.id) {
+    for field in embed_fields.fields {
+        if let Some(value) = embed_fields.field_values.get(&field.id) {
             if !value.is_empty() {
                 // Format boolean values as "Yes"/"No" for better readability
                 let display_value = match field.field_type {
                     PingFormatFieldType::Bool => match value.as_str() {
-                        "true" => "Yes",
-                        "false" => "No",
-                        _ => value.as_str(),
+                        "true" => "Yes".to_string(),
+                        "false" => "No".to_string(),
+                        _ => value.clone(),
                     },
-                    PingFormatFieldType::Text => value.as_str(),
+                    PingFormatFieldType::Text => value.clone(),
+                    // Link solar systems to dotlan, falling back to the stored name for
+                    // systems no longer in the bundled map
+                    PingFormatFieldType::SolarSystem => eve_map()
+                        .find_by_name(value)
+                        .map(|system| system.embed_value())
+                        .unwrap_or_else(|| value.clone()),
+                    // Show the doctrine's hulls, the doctrine may have been deleted since
+                    PingFormatFieldType::Doctrine => value
+                        .parse::<i32>()
+                        .ok()
+                        .and_then(|id| embed_fields.doctrines.get(&id))
+                        .map(|doctrine| doctrine.embed_value())
+                        .unwrap_or_else(|| "Unknown doctrine".to_string()),
                 };
                 embed = embed.field(&field.name, display_value, false);
             }
         }
     }
 
+    if let Some(route) = embed_fields.route {
+        embed = embed.field("Route from Staging", route.embed_value(), false);
+    }
+
     // Add description if present
     if let Some(description) = &fleet.description {
         if !description.is_empty() {
@@ -155,3 +224,442 @@ pub async fn build_fleet_embed(
 
     Ok(embed)
 }
+
+/// Formats a fleet time in each of a guild's extra timezones, one per line.
+///
+/// Timezones which fail to parse are skipped, they are validated when saving the
+/// guild's settings.
+///
+/// # Arguments
+/// - `fleet_time` - Start time of the fleet
+/// - `timezones` - IANA timezone names, e.g. "America/New_York"
+///
+/// # Returns
+/// - `Some(String)` - Fleet time in each timezone
+/// - `None` - No valid extra timezones configured
+pub fn format_extra_times(fleet_time: DateTime<Utc>, timezones: &[String]) -> Option<String> {
+    let lines: Vec<String> = timezones
+        .iter()
+        .filter_map(|name| {
+            let timezone = Tz::from_str(name).ok()?;
+            let local_time = fleet_time.with_timezone(&timezone);
+            Some(format!(
+                "{} ({})",
+                local_time.format("%Y-%m-%d %H:%M %Z"),
+                name
+            ))
+        })
+        .collect();
+
+    (!lines.is_empty()).then(|| lines.join("\n"))
+}
+
+/// Builds a Discord embed announcing that a fleet was cancelled.
+///
+/// Used to replace the fleet's channel messages and for the cancellation direct messages
+/// sent to subscribers. Uses the guild's cancellation embed color and includes the
+/// cancellation timestamp and cancelled-by information.
+///
+/// # Arguments
+/// - `fleet` - Fleet domain model being cancelled
+/// - `category_name` - Name of the fleet's category
+/// - `commander_name` - Display name of the fleet commander
+/// - `settings` - Settings of the guild the fleet belongs to
+/// - `app_url` - Base application URL for embed link
+///
+/// # Returns
+/// - `Ok(CreateEmbed)` - Discord embed ready for posting
+/// - `Err(AppError::InternalError)` - Invalid timestamp format
+pub fn build_cancellation_embed(
+    fleet: &Fleet,
+    category_name: &str,
+    commander_name: &str,
+    settings: &GuildSettings,
+    app_url: &str,
+) -> Result<CreateEmbed, AppError> {
+    let now = chrono::Utc::now();
+    let timestamp = Timestamp::from_unix_timestamp(now.timestamp()).map_err(|e| {
+        AppError::InternalError(InternalError::InvalidDiscordTimestamp {
+            timestamp: now.timestamp(),
+            reason: e.to_string(),
+        })
+    })?;
+
+    Ok(CreateEmbed::new()
+        .title(format!(".:{} Cancelled:.", category_name))
+        .url(app_url)
+        .color(settings.embed_color(EmbedKind::Cancel))
+        .description(format!(
+            "{} posted by <@{}>, **{}**, scheduled for **{} UTC** (<t:{}:F>) was cancelled.",
+            category_name,
+            fleet.commander_id,
+            fleet.name,
+            fleet.fleet_time.format("%Y-%m-%d %H:%M"),
+            fleet.fleet_time.timestamp()
+        ))
+        .footer(serenity::all::CreateEmbedFooter::new(format!(
+            "Cancelled by: {}",
+            commander_name
+        )))
+        .timestamp(timestamp))
+}
+
+/// Maximum length of a Discord embed field value.
+const EMBED_FIELD_VALUE_LIMIT: usize = 1024;
+
+/// Notice broadcast about a stand-down
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StandDownNotice {
+    /// Fleets were cancelled by the stand-down
+    Called,
+    /// The stand-down was resumed and its fleets restored
+    Lifted,
+}
+
+/// Builds the Discord embed broadcast to a channel when leadership stands down fleets
+/// or lifts a stand-down.
+///
+/// Stand-downs use the guild's cancellation embed color, lifted stand-downs its creation
+/// embed color. The reason is shown as description and the fleets of the channel are
+/// listed so members know which pings no longer or once again apply.
+///
+/// # Arguments
+/// - `notice` - Whether the stand-down was called or lifted
+/// - `reason` - Reason given for the stand-down
+/// - `fleets` - Cancelled or restored fleets posted to the channel, hidden fleets are left out
+/// - `user_id` - Discord ID of the user who called or lifted the stand-down
+/// - `settings` - Settings of the guild the fleets belong to
+/// - `app_url` - Base application URL for embed link
+///
+/// # Returns
+/// - `Ok(CreateEmbed)` - Discord embed ready for posting
+/// - `Err(AppError::InternalError)` - Invalid timestamp format
+pub fn build_stand_down_embed(
+    notice: StandDownNotice,
+    reason: &str,
+    fleets: &[&Fleet],
+    user_id: u64,
+    settings: &GuildSettings,
+    app_url: &str,
+) -> Result<CreateEmbed, AppError> {
+    let now = chrono::Utc::now();
+    let timestamp = Timestamp::from_unix_timestamp(now.timestamp()).map_err(|e| {
+        AppError::InternalError(InternalError::InvalidDiscordTimestamp {
+            timestamp: now.timestamp(),
+            reason: e.to_string(),
+        })
+    })?;
+
+    let (title, kind, description, field_name) = match notice {
+        StandDownNotice::Called => (
+            ".:STAND DOWN:.",
+            EmbedKind::Cancel,
+            format!("{}\n\nCalled by <@{}>", reason, user_id),
+            "Cancelled fleets",
+        ),
+        StandDownNotice::Lifted => (
+            ".:STAND DOWN LIFTED:.",
+            EmbedKind::Creation,
+            format!(
+                "The stand-down for \"{}\" was lifted by <@{}>, fleets still ahead are back on the schedule.",
+                reason, user_id
+            ),
+            "Restored fleets",
+        ),
+    };
+
+    let mut embed = CreateEmbed::new()
+        .title(title)
+        .url(app_url)
+        .color(settings.embed_color(kind))
+        .description(description)
+        .timestamp(timestamp);
+
+    if !fleets.is_empty() {
+        embed = embed.field(
+            field_name,
+            format_fleet_list(fleets, EMBED_FIELD_VALUE_LIMIT),
+            false,
+        );
+    }
+
+    Ok(embed)
+}
+
+/// Lists fleets one per line with their Discord timestamp.
+///
+/// Lines which would exceed `limit` are replaced by a count of the remaining fleets.
+///
+/// # Arguments
+/// - `fleets` - Fleets to list, in display order
+/// - `limit` - Maximum length of the text in characters
+pub fn format_fleet_list(fleets: &[&Fleet], limit: usize) -> String {
+    let mut text = String::new();
+    for (index, fleet) in fleets.iter().enumerate() {
+        let line = format!(
+            "**{}** - <t:{}:F>\n",
+            fleet.name,
+            fleet.fleet_time.timestamp()
+        );
+        let remaining = fleets.len() - index;
+        let more = format!("...and {} more", remaining);
+
+        // Keep room to mention the fleets which don't fit anymore
+        let reserved = if remaining > 1 {
+            more.chars().count()
+        } else {
+            0
+        };
+        if text.chars().count() + line.chars().count() + reserved > limit {
+            text.push_str(&more);
+            return text;
+        }
+        text.push_str(&line);
+    }
+
+    text.trim_end().to_string()
+}
+
+/// Change announced by a bulk summary
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BulkChange {
+    /// Fleets were shifted or moved to another category
+    Updated,
+    /// Fleets were cancelled
+    Cancelled,
+}
+
+/// Builds the Discord embed summarizing a bulk change of fleets to a channel.
+///
+/// Updates use the guild's update embed color, cancellations its cancellation embed
+/// color. The summary of the change is shown as description, followed by who made it,
+/// and the changed fleets of the channel are listed with their current time.
+///
+/// # Arguments
+/// - `change` - Whether the fleets were updated or cancelled
+/// - `summary` - Description of the change applied to every fleet
+/// - `fleets` - Changed fleets posted to the channel, hidden fleets are left out
+/// - `user_id` - Discord ID of the user who changed the fleets
+/// - `settings` - Settings of the guild the fleets belong to
+/// - `app_url` - Base application URL for embed link
+///
+/// # Returns
+/// - `Ok(CreateEmbed)` - Discord embed ready for posting
+/// - `Err(AppError::InternalError)` - Invalid timestamp format
+pub fn build_bulk_update_embed(
+    change: BulkChange,
+    summary: &str,
+    fleets: &[&Fleet],
+    user_id: u64,
+    settings: &GuildSettings,
+    app_url: &str,
+) -> Result<CreateEmbed, AppError> {
+    let now = chrono::Utc::now();
+    let timestamp = Timestamp::from_unix_timestamp(now.timestamp()).map_err(|e| {
+        AppError::InternalError(InternalError::InvalidDiscordTimestamp {
+            timestamp: now.timestamp(),
+            reason: e.to_string(),
+        })
+    })?;
+
+    let (title, kind, description, field_name) = match change {
+        BulkChange::Updated => (
+            ".:Fleets Updated:.",
+            EmbedKind::Update,
+            format!("{}\n\nChanged by <@{}>", summary, user_id),
+            "Updated fleets",
+        ),
+        BulkChange::Cancelled => (
+            ".:Fleets Cancelled:.",
+            EmbedKind::Cancel,
+            format!("{}\n\nCancelled by <@{}>", summary, user_id),
+            "Cancelled fleets",
+        ),
+    };
+
+    let mut embed = CreateEmbed::new()
+        .title(title)
+        .url(app_url)
+        .color(settings.embed_color(kind))
+        .description(description)
+        .timestamp(timestamp);
+
+    if !fleets.is_empty() {
+        embed = embed.field(
+            field_name,
+            format_fleet_list(fleets, EMBED_FIELD_VALUE_LIMIT),
+            false,
+        );
+    }
+
+    Ok(embed)
+}
+
+/// Builds the Discord embed of an ad-hoc broadcast.
+///
+/// Broadcasts use the guild's form-up embed color as they call members to action much
+/// like a form-up ping. The message is shown as description, followed by who sent it.
+///
+/// # Arguments
+/// - `title` - Title of the broadcast
+/// - `message` - Message of the broadcast
+/// - `author_id` - Discord ID of the user who composed the broadcast, if still known
+/// - `settings` - Settings of the guild the broadcast belongs to
+/// - `app_url` - Base application URL for embed link
+///
+/// # Returns
+/// - `Ok(CreateEmbed)` - Discord embed ready for posting
+/// - `Err(AppError::InternalError)` - Invalid timestamp format
+pub fn build_broadcast_embed(
+    title: &str,
+    message: &str,
+    author_id: Option<u64>,
+    settings: &GuildSettings,
+    app_url: &str,
+) -> Result<CreateEmbed, AppError> {
+    let now = chrono::Utc::now();
+    let timestamp = Timestamp::from_unix_timestamp(now.timestamp()).map_err(|e| {
+        AppError::InternalError(InternalError::InvalidDiscordTimestamp {
+            timestamp: now.timestamp(),
+            reason: e.to_string(),
+        })
+    })?;
+
+    let description = match author_id {
+        Some(author_id) => format!("{}\n\nSent by <@{}>", message, author_id),
+        None => message.to_string(),
+    };
+
+    Ok(CreateEmbed::new()
+        .title(title)
+        .url(app_url)
+        .color(settings.embed_color(EmbedKind::Formup))
+        .description(description)
+        .timestamp(timestamp))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Tests building the link to a fleet's detail page.
+    ///
+    /// Verifies that a trailing slash on the application URL doesn't produce a double
+    /// slash in the link.
+    ///
+    /// Expected: Both URLs build the same link
+    #[test]
+    fn builds_fleet_url_without_double_slash() {
+        let expected = "https://timerboard.example/guilds/123/fleets/7";
+
+        assert_eq!(fleet_url("https://timerboard.example", 123, 7), expected);
+        assert_eq!(fleet_url("https://timerboard.example/", 123, 7), expected);
+    }
+
+    /// Tests formatting a fleet time in a guild's extra timezones.
+    ///
+    /// Verifies that each valid timezone gets its own line with the converted time and
+    /// that unknown timezones are skipped.
+    ///
+    /// Expected: One line per valid timezone, None without valid timezones
+    #[test]
+    fn formats_extra_timezones() {
+        let fleet_time = DateTime::parse_from_rfc3339("2026-01-15T18:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let timezones = vec![
+            "America/New_York".to_string(),
+            "Not/AZone".to_string(),
+            "Australia/Sydney".to_string(),
+        ];
+
+        assert_eq!(
+            format_extra_times(fleet_time, &timezones).as_deref(),
+            Some(
+                "2026-01-15 13:00 EST (America/New_York)\n2026-01-16 05:00 AEDT (Australia/Sydney)"
+            )
+        );
+        assert_eq!(
+            format_extra_times(fleet_time, &["Not/AZone".to_string()]),
+            None
+        );
+    }
+
+    /// Tests listing the fleets cancelled by a stand-down.
+    ///
+    /// Verifies that fleets are listed one per line and that fleets exceeding the
+    /// length limit are summarized by a count.
+    ///
+    /// Expected: All fleets within the limit, a count of the remaining fleets beyond it
+    #[test]
+    fn formats_stand_down_fleets_within_limit() {
+        let fleet_time = DateTime::parse_from_rfc3339("2026-01-15T18:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let fleets: Vec<Fleet> = (1..=3)
+            .map(|id| Fleet {
+                id,
+                category_id: 1,
+                name: format!("Op {}", id),
+                commander_id: 1,
+                fleet_time,
+                description: None,
+                hidden: false,
+                disable_reminder: false,
+                created_at: fleet_time,
+            })
+            .collect();
+        let fleets: Vec<&Fleet> = fleets.iter().collect();
+
+        assert_eq!(
+            format_fleet_list(&fleets, 1024),
+            "**Op 1** - <t:1768500000:F>\n**Op 2** - <t:1768500000:F>\n**Op 3** - <t:1768500000:F>"
+        );
+        assert_eq!(
+            format_fleet_list(&fleets, 50),
+            "**Op 1** - <t:1768500000:F>\n...and 2 more"
+        );
+    }
+
+    /// Tests building the summary of fleets cancelled together.
+    ///
+    /// Verifies that a bulk cancellation uses the cancellation title & embed color and
+    /// lists the cancelled fleets.
+    ///
+    /// Expected: Cancellation embed listing the fleet
+    #[test]
+    fn builds_bulk_cancellation_embed() {
+        let fleet_time = DateTime::parse_from_rfc3339("2026-01-15T18:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let fleet = Fleet {
+            id: 1,
+            category_id: 1,
+            name: "Op 1".to_string(),
+            commander_id: 1,
+            fleet_time,
+            description: None,
+            hidden: false,
+            disable_reminder: false,
+            created_at: fleet_time,
+        };
+        let settings = GuildSettings::default_for(123);
+
+        let embed = build_bulk_update_embed(
+            BulkChange::Cancelled,
+            "Timer moved",
+            &[&fleet],
+            42,
+            &settings,
+            "https://timerboard.example",
+        )
+        .unwrap();
+        let embed = serde_json::to_value(&embed).unwrap();
+
+        assert_eq!(embed["title"], ".:Fleets Cancelled:.");
+        assert_eq!(embed["color"], settings.embed_color(EmbedKind::Cancel));
+        assert_eq!(embed["description"], "Timer moved\n\nCancelled by <@42>");
+        assert_eq!(embed["fields"][0]["name"], "Cancelled fleets");
+        assert_eq!(embed["fields"][0]["value"], "**Op 1** - <t:1768500000:F>");
+    }
+}
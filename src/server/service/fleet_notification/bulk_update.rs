@@ -0,0 +1,155 @@
+//! Bulk update summary operations.
+//!
+//! This module provides functionality for announcing a change applied to many fleets at
+//! once. A single message is posted to each channel of the affected categories listing
+//! the channel's changed fleets, rather than editing the messages of every fleet.
+//! Summaries are posted without pings as the fleets were already announced.
+
+use dioxus_logger::tracing;
+use serenity::all::CreateMessage;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use crate::server::{
+    error::AppError,
+    metrics::metrics,
+    model::{fleet::Fleet, guild_settings::GuildSettings},
+    util::parse::parse_u64_from_string,
+};
+
+use super::{
+    builder::{self, BulkChange},
+    FleetNotificationService,
+};
+
+/// Summary of a bulk update for a single channel
+struct ChannelSummary<'f> {
+    /// Discord guild ID the channel belongs to
+    guild_id: u64,
+    /// Category channel configuration alongside the synced guild channel
+    channel: (
+        entity::fleet_category_channel::Model,
+        Option<entity::discord_guild_channel::Model>,
+    ),
+    /// Changed fleets listed in the summary
+    fleets: Vec<&'f Fleet>,
+}
+
+impl<'a> FleetNotificationService<'a> {
+    /// Posts a summary of a bulk update to the channels of the changed fleets.
+    ///
+    /// Posts one message to every channel configured for the previous or new category of
+    /// the changed fleets. Channels shared by several categories receive a single message
+    /// listing the fleets of all of them. Fleets may belong to several guilds, such as
+    /// relayed copies of cancelled fleets, each channel uses the settings of its own guild.
+    /// Continues posting to remaining channels even if individual posts fail.
+    ///
+    /// # Arguments
+    /// - `change` - Whether the fleets were updated or cancelled
+    /// - `summary` - Description of the change applied to every fleet
+    /// - `changes` - Each changed fleet before & after the change
+    /// - `user_id` - Discord ID of the user who changed the fleets
+    ///
+    /// # Returns
+    /// - `Ok(())` - Successfully posted to all channels (or failed gracefully)
+    /// - `Err(AppError::NotFound)` - A fleet category was not found
+    /// - `Err(AppError::InternalError)` - Invalid ID format or timestamp
+    /// - `Err(AppError::Database)` - Database error retrieving categories or settings
+    pub async fn post_bulk_update(
+        &self,
+        change: BulkChange,
+        summary: &str,
+        changes: &[(&Fleet, &Fleet)],
+        user_id: u64,
+    ) -> Result<(), AppError> {
+        let mut summaries: BTreeMap<u64, ChannelSummary> = BTreeMap::new();
+        let category_ids: BTreeSet<i32> = changes
+            .iter()
+            .flat_map(|(previous, fleet)| [previous.category_id, fleet.category_id])
+            .collect();
+        for category_id in category_ids {
+            let (category_data, guild_id) =
+                self.get_category_data_with_guild_id(category_id).await?;
+
+            for channel in &category_data.channels {
+                let channel_id = parse_u64_from_string(channel.0.channel_id.clone())?;
+                let channel_summary =
+                    summaries
+                        .entry(channel_id)
+                        .or_insert_with(|| ChannelSummary {
+                            guild_id,
+                            channel: channel.clone(),
+                            fleets: Vec::new(),
+                        });
+
+                for (previous, fleet) in changes {
+                    let in_category =
+                        previous.category_id == category_id || fleet.category_id == category_id;
+                    if in_category
+                        && !fleet.hidden
+                        && !channel_summary
+                            .fleets
+                            .iter()
+                            .any(|listed| listed.id == fleet.id)
+                    {
+                        channel_summary.fleets.push(fleet);
+                    }
+                }
+            }
+        }
+
+        let (content, forum_title) = match change {
+            BulkChange::Updated => ("**.:Fleets Updated:.**", "Fleets updated"),
+            BulkChange::Cancelled => ("**.:Fleets Cancelled:.**", "Fleets cancelled"),
+        };
+
+        let guild_ids: BTreeSet<u64> = summaries.values().map(|summary| summary.guild_id).collect();
+        let mut settings: HashMap<u64, GuildSettings> = HashMap::new();
+        for guild_id in guild_ids {
+            settings.insert(guild_id, self.get_guild_settings(guild_id).await?);
+        }
+
+        for (channel_id, mut channel_summary) in summaries {
+            if channel_summary.fleets.is_empty() {
+                continue;
+            }
+            channel_summary.fleets.sort_by_key(|fleet| fleet.fleet_time);
+
+            let embed = builder::build_bulk_update_embed(
+                change,
+                summary,
+                &channel_summary.fleets,
+                user_id,
+                &settings[&channel_summary.guild_id],
+                &self.app_url,
+            )?;
+            let message = CreateMessage::new().content(content).embed(embed);
+
+            match self
+                .send_channel_message(
+                    forum_title,
+                    channel_id,
+                    &channel_summary.channel,
+                    message,
+                    None,
+                )
+                .await
+            {
+                Ok(_) => {
+                    metrics().record_notification("bulk_update");
+
+                    tracing::info!("Posted bulk update summary to channel {}", channel_id);
+                }
+                Err(e) => {
+                    tracing::error!(
+                        "Failed to post bulk update summary to channel {}: {}",
+                        channel_id,
+                        e
+                    );
+                    // Continue posting to other channels even if one fails
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -2,87 +2,145 @@
 //!
 //! This module provides functionality for cancelling fleet messages by editing them with cancellation notices.
 //! Cancellation messages display that the fleet has been cancelled with relevant metadata.
+//! The messages & subscribers of a fleet are captured before it is deleted and the
+//! cancellation is only sent once the deletion succeeded.
 
 use dioxus_logger::tracing;
-use serenity::all::{ChannelId, CreateEmbed, EditMessage, MessageId, Timestamp};
+use serenity::all::{ChannelId, CreateEmbed, EditMessage, MessageId};
+use std::collections::HashMap;
 
 use crate::server::{
     data::fleet_message::FleetMessageRepository,
-    error::{internal::InternalError, AppError},
-    model::{fleet::Fleet, fleet_message::FleetMessage},
+    error::AppError,
+    metrics::{discord_call, metrics},
+    model::{
+        fleet::Fleet,
+        fleet_message::FleetMessage,
+        subscription::{DmNotification, SubscriptionTarget},
+    },
 };
 
 use super::{builder, FleetNotificationService};
 
+/// Discord messages & subscribers of a fleet captured before the fleet is deleted.
+///
+/// Both are deleted along with the fleet, so they are read beforehand and the
+/// cancellation is sent once the deletion succeeded.
+pub struct FleetCancellation {
+    /// Discord messages posted for the fleet
+    messages: Vec<FleetMessage>,
+    /// Subscribers to notify by direct message alongside their subscription
+    recipients: Vec<(u64, SubscriptionTarget)>,
+}
+
 impl<'a> FleetNotificationService<'a> {
-    /// Cancels all existing fleet messages by editing them with cancellation notice.
+    /// Captures the Discord messages & subscribers of a fleet about to be deleted.
+    ///
+    /// Only reads from the database, nothing is sent to Discord until
+    /// `send_cancellation` is called with the result.
+    ///
+    /// # Arguments
+    /// - `fleet` - Fleet domain model about to be deleted
+    ///
+    /// # Returns
+    /// - `Ok(FleetCancellation)` - Messages & subscribers of the fleet
+    /// - `Err(AppError::NotFound)` - Fleet category not found
+    /// - `Err(AppError::Database)` - Database error retrieving messages or subscribers
+    pub async fn prepare_cancellation(&self, fleet: &Fleet) -> Result<FleetCancellation, AppError> {
+        let messages = FleetMessageRepository::new(self.db)
+            .get_by_fleet_id(fleet.id)
+            .await?;
+        let recipients = self
+            .get_dm_recipients(fleet, &DmNotification::Cancelled)
+            .await?;
+
+        Ok(FleetCancellation {
+            messages,
+            recipients,
+        })
+    }
+
+    /// Cancels the Discord messages of a deleted fleet & notifies its subscribers.
     ///
-    /// Edits all Discord messages associated with the fleet to display cancellation
-    /// information. Uses gray embed color (0x95a5a6) and includes cancellation timestamp
-    /// and cancelled-by information. Continues cancelling remaining messages even if
-    /// individual edits fail.
+    /// Edits all Discord messages captured for the fleet to display cancellation
+    /// information. Uses the guild's cancellation embed color and includes cancellation
+    /// timestamp and cancelled-by information. Continues cancelling remaining messages
+    /// even if individual edits fail. Subscribers are messaged in the background.
     ///
     /// # Arguments
-    /// - `fleet` - Fleet domain model being cancelled
+    /// - `fleet` - Fleet domain model which was cancelled
+    /// - `field_values` - Map of field_id to value for custom ping format fields
+    /// - `cancellation` - Messages & subscribers captured before the fleet was deleted
     ///
     /// # Returns
     /// - `Ok(())` - Successfully cancelled all messages (or no messages exist)
     /// - `Err(AppError::NotFound)` - Fleet category not found
     /// - `Err(AppError::InternalError)` - Invalid ID format or timestamp
-    /// - `Err(AppError::Database)` - Database error retrieving messages
-    pub async fn cancel_fleet_messages(
+    /// - `Err(AppError::Database)` - Database error retrieving category or settings
+    pub async fn send_cancellation(
         &self,
         fleet: &Fleet,
-        app_url: &str,
+        field_values: &HashMap<i32, String>,
+        cancellation: FleetCancellation,
     ) -> Result<(), AppError> {
-        let message_repo = FleetMessageRepository::new(self.db);
+        if cancellation.messages.is_empty() {
+            tracing::debug!("No messages found for fleet {}, skipping cancel", fleet.id);
+        } else {
+            // Get category data and guild_id
+            let (category_data, guild_id) = self
+                .get_category_data_with_guild_id(fleet.category_id)
+                .await?;
 
-        // Get all existing messages for this fleet
-        let messages = message_repo.get_by_fleet_id(fleet.id).await?;
+            // Fetch commander name from Discord
+            let commander_name =
+                builder::get_commander_name(self.http.clone(), fleet, guild_id).await?;
+            let settings = self.get_guild_settings(guild_id).await?;
 
-        if messages.is_empty() {
-            tracing::debug!("No messages found for fleet {}, skipping cancel", fleet.id);
-            return Ok(());
+            // Build cancellation embed
+            let embed = builder::build_cancellation_embed(
+                fleet,
+                &category_data.category.name,
+                &commander_name,
+                &settings,
+                &self.app_url,
+            )?;
+
+            // Update each message with cancellation notice
+            self.cancel_existing_messages(&cancellation.messages, &embed)
+                .await?;
         }
 
-        // Get category data and guild_id
-        let (category_data, guild_id) = self
-            .get_category_data_with_guild_id(fleet.category_id)
-            .await?;
+        self.spawn_dms(
+            fleet.clone(),
+            field_values.clone(),
+            DmNotification::Cancelled,
+            cancellation.recipients,
+        );
 
-        // Fetch commander name from Discord
-        let commander_name =
-            builder::get_commander_name(self.http.clone(), fleet, guild_id).await?;
-
-        // Build cancellation embed
-        let now = chrono::Utc::now();
-        let timestamp = Timestamp::from_unix_timestamp(now.timestamp()).map_err(|e| {
-            AppError::InternalError(InternalError::InvalidDiscordTimestamp {
-                timestamp: now.timestamp(),
-                reason: e.to_string(),
-            })
-        })?;
-
-        let embed = CreateEmbed::new()
-            .title(format!(".:{} Cancelled:.", category_data.category.name))
-            .url(app_url)
-            .color(0x95a5a6) // Gray color for cancellation
-            .description(format!(
-                "{} posted by <@{}>, **{}**, scheduled for **{} UTC** (<t:{}:F>) was cancelled.",
-                category_data.category.name,
-                fleet.commander_id,
-                fleet.name,
-                fleet.fleet_time.format("%Y-%m-%d %H:%M"),
-                fleet.fleet_time.timestamp()
-            ))
-            .footer(serenity::all::CreateEmbedFooter::new(format!(
-                "Cancelled by: {}",
-                commander_name
-            )))
-            .timestamp(timestamp);
-
-        // Update each message with cancellation notice
-        self.cancel_existing_messages(&messages, &embed).await
+        Ok(())
+    }
+
+    /// Notifies the subscribers of a deleted fleet without editing its Discord messages.
+    ///
+    /// Used when the channels are told about the cancellation otherwise, such as a single
+    /// summary for a batch of fleets. Subscribers are messaged in the background.
+    ///
+    /// # Arguments
+    /// - `fleet` - Fleet domain model which was cancelled
+    /// - `field_values` - Map of field_id to value for custom ping format fields
+    /// - `cancellation` - Messages & subscribers captured before the fleet was deleted
+    pub fn send_cancellation_dms(
+        &self,
+        fleet: &Fleet,
+        field_values: &HashMap<i32, String>,
+        cancellation: FleetCancellation,
+    ) {
+        self.spawn_dms(
+            fleet.clone(),
+            field_values.clone(),
+            DmNotification::Cancelled,
+            cancellation.recipients,
+        );
     }
 
     /// Cancels existing fleet messages by editing them with cancellation embed.
@@ -99,18 +157,22 @@ impl<'a> FleetNotificationService<'a> {
         embed: &CreateEmbed,
     ) -> Result<(), AppError> {
         for message in messages {
-            let channel_id = ChannelId::new(message.channel_id);
+            let channel_id = ChannelId::new(message.message_channel_id());
             let msg_id = MessageId::new(message.message_id);
 
             // Clear content and set cancellation embed
             let edit_builder = EditMessage::new().content("").embed(embed.clone());
 
-            match self
-                .http
-                .edit_message(channel_id, msg_id, &edit_builder, vec![])
-                .await
+            match discord_call(
+                "edit_message",
+                self.http
+                    .edit_message(channel_id, msg_id, &edit_builder, vec![]),
+            )
+            .await
             {
                 Ok(_) => {
+                    metrics().record_notification("cancel");
+
                     tracing::info!(
                         "Cancelled fleet message {} in channel {}",
                         msg_id,
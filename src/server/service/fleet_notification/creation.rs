@@ -4,13 +4,15 @@
 //! Creation messages are posted to all configured channels with role pings and fleet details.
 
 use dioxus_logger::tracing;
-use serenity::all::{ChannelId, CreateEmbed, CreateMessage};
+use serenity::all::{CreateEmbed, CreateMessage};
 
 use crate::server::{
     data::fleet_message::FleetMessageRepository,
     error::AppError,
+    metrics::metrics,
     model::{
         category::FleetCategoryWithRelations, fleet::Fleet, fleet_message::CreateFleetMessageParam,
+        guild_settings::EmbedKind,
     },
     util::parse::parse_u64_from_string,
 };
@@ -22,7 +24,7 @@ impl<'a> FleetNotificationService<'a> {
     ///
     /// Creates Discord messages with fleet details in all channels configured for the
     /// fleet's category. Only posts if the fleet is not hidden. Message IDs are stored
-    /// in the database for later updates or cancellations. Uses blue embed color (0x3498db).
+    /// in the database for later updates or cancellations. Uses the guild's creation embed color.
     ///
     /// # Arguments
     /// - `fleet` - Fleet domain model containing event details
@@ -59,9 +61,10 @@ impl<'a> FleetNotificationService<'a> {
         let embed = self
             .build_fleet_embed_with_commander(
                 fleet,
+                &category_data,
                 &fields[..],
                 field_values,
-                0x3498db, // Blue color for creation
+                EmbedKind::Creation,
                 guild_id,
             )
             .await?;
@@ -97,21 +100,26 @@ impl<'a> FleetNotificationService<'a> {
         content: &str,
         embed: &CreateEmbed,
     ) -> Result<(), AppError> {
-        for (channel, _) in &category_data.channels {
-            let channel_id_u64 = parse_u64_from_string(channel.channel_id.clone())?;
-            let channel_id = ChannelId::new(channel_id_u64);
+        for channel in &category_data.channels {
+            let channel_id_u64 = parse_u64_from_string(channel.0.channel_id.clone())?;
 
             let message = CreateMessage::new().content(content).embed(embed.clone());
 
-            match channel_id.send_message(&self.http, message).await {
-                Ok(msg) => {
+            match self
+                .send_channel_message(&fleet.name, channel_id_u64, channel, message, None)
+                .await
+            {
+                Ok(posted) => {
+                    metrics().record_notification("creation");
+
                     // Store message in database
                     message_repo
                         .create(CreateFleetMessageParam {
                             fleet_id: fleet.id,
                             channel_id: channel_id_u64,
-                            message_id: msg.id.get(),
+                            message_id: posted.message_id,
                             message_type: "creation".to_string(),
+                            thread_id: posted.thread_id,
                         })
                         .await?;
 
@@ -0,0 +1,492 @@
+//! Fleet direct message notification operations.
+//!
+//! This module provides functionality for notifying the subscribers of a fleet by Discord
+//! direct message. Users subscribe to whole categories or individual fleets and receive
+//! the notifications their preferences allow. Each direct message carries a button to
+//! unsubscribe from the subscription it was sent for. Fleet events are fanned out to the
+//! subscribers from a spawned task so requests changing a fleet don't wait on Discord.
+//!
+//! Hidden fleets are only sent to subscribers once they are revealed to the category's
+//! viewers, and recipients must still be able to view the fleet's category when the
+//! message is sent.
+
+use chrono::{DateTime, Duration, Utc};
+use dioxus_logger::tracing;
+use serenity::all::{
+    ButtonStyle, CreateActionRow, CreateButton, CreateEmbed, CreateMessage, UserId,
+};
+use std::collections::HashMap;
+
+use crate::server::{
+    data::{
+        fleet_dm_reminder::FleetDmReminderRepository,
+        notification_preference::NotificationPreferenceRepository,
+        subscription::SubscriptionRepository, user::UserRepository,
+        user_category_permission::UserCategoryPermissionRepository,
+    },
+    error::AppError,
+    metrics::{discord_call, metrics},
+    model::{
+        category::FleetCategoryWithRelations,
+        fleet::Fleet,
+        guild_settings::EmbedKind,
+        subscription::{DmNotification, NotificationPreference, SubscriptionTarget},
+    },
+};
+
+use super::{builder, FleetNotificationService};
+
+impl<'a> FleetNotificationService<'a> {
+    /// Notifies the subscribers of a fleet about a fleet event by direct message.
+    ///
+    /// Sends the notification to every user subscribed to the fleet or its category whose
+    /// preferences allow it and who can view the category. Failing to message a user
+    /// (e.g. direct messages from server members are disabled) is logged without failing
+    /// the remaining messages. Personal reminders are sent by `post_due_dm_reminders`.
+    ///
+    /// # Arguments
+    /// - `fleet` - Fleet domain model the notification is about
+    /// - `field_values` - Map of field_id to value for custom ping format fields
+    /// - `notification` - Kind of notification to send
+    ///
+    /// # Returns
+    /// - `Ok(())` - Notified all subscribers (or failed gracefully)
+    /// - `Err(AppError::NotFound)` - Fleet category or ping format not found
+    /// - `Err(AppError::Database)` - Database error retrieving subscribers
+    pub async fn send_subscriber_dms(
+        &self,
+        fleet: &Fleet,
+        field_values: &HashMap<i32, String>,
+        notification: DmNotification,
+    ) -> Result<(), AppError> {
+        let recipients = self.get_dm_recipients(fleet, &notification).await?;
+
+        self.send_dms(fleet, field_values, &notification, recipients)
+            .await
+    }
+
+    /// Notifies the subscribers of a fleet about a fleet event in the background.
+    ///
+    /// Looks up & messages the subscribers from a spawned task, so the request creating or
+    /// changing the fleet doesn't wait on the Discord calls for every subscriber. Failures
+    /// are logged.
+    ///
+    /// # Arguments
+    /// - `fleet` - Fleet domain model the notification is about
+    /// - `field_values` - Map of field_id to value for custom ping format fields
+    /// - `notification` - Kind of notification to send
+    pub fn spawn_subscriber_dms(
+        &self,
+        fleet: Fleet,
+        field_values: HashMap<i32, String>,
+        notification: DmNotification,
+    ) {
+        let db = self.db.clone();
+        let http = self.http.clone();
+        let app_url = self.app_url.clone();
+
+        tokio::spawn(async move {
+            let service = FleetNotificationService::new(&db, http, app_url);
+            if let Err(e) = service
+                .send_subscriber_dms(&fleet, &field_values, notification)
+                .await
+            {
+                tracing::warn!(
+                    "Failed to notify subscribers of fleet {} by direct message: {}",
+                    fleet.id,
+                    e
+                );
+            }
+        });
+    }
+
+    /// Gets the users to notify about a fleet event by direct message.
+    ///
+    /// Used directly to capture the subscribers of a fleet before it is deleted, as its
+    /// fleet subscriptions are deleted along with it.
+    ///
+    /// # Arguments
+    /// - `fleet` - Fleet domain model the notification is about
+    /// - `notification` - Kind of notification to send
+    ///
+    /// # Returns
+    /// - `Ok(Vec<(u64, SubscriptionTarget)>)` - Discord ID of each recipient alongside the
+    ///   subscription they are notified through, empty for hidden fleets
+    /// - `Err(AppError::NotFound)` - Fleet category not found
+    /// - `Err(AppError::Database)` - Database error retrieving subscribers
+    pub(super) async fn get_dm_recipients(
+        &self,
+        fleet: &Fleet,
+        notification: &DmNotification,
+    ) -> Result<Vec<(u64, SubscriptionTarget)>, AppError> {
+        let (category_data, _) = self
+            .get_category_data_with_guild_id(fleet.category_id)
+            .await?;
+
+        if !is_revealed(fleet, &category_data, Utc::now()) {
+            tracing::debug!(
+                "Fleet {} is hidden, skipping subscriber direct messages",
+                fleet.id
+            );
+            return Ok(Vec::new());
+        }
+
+        Ok(self
+            .get_recipients(fleet, &category_data, notification)
+            .await?
+            .into_iter()
+            .map(|(preference, target)| (preference.user_id, target))
+            .collect())
+    }
+
+    /// Sends a direct message notification about a fleet event to the given recipients.
+    ///
+    /// Failing to message a user is logged without failing the remaining messages. Sent
+    /// from the background through `spawn_dms`.
+    ///
+    /// # Arguments
+    /// - `fleet` - Fleet domain model the notification is about
+    /// - `field_values` - Map of field_id to value for custom ping format fields
+    /// - `notification` - Kind of notification to send
+    /// - `recipients` - Discord ID of each recipient alongside their subscription
+    ///
+    /// # Returns
+    /// - `Ok(())` - Notified all recipients (or failed gracefully)
+    /// - `Err(AppError::NotFound)` - Fleet category or ping format not found
+    /// - `Err(AppError::Database)` - Database error building the embed
+    async fn send_dms(
+        &self,
+        fleet: &Fleet,
+        field_values: &HashMap<i32, String>,
+        notification: &DmNotification,
+        recipients: Vec<(u64, SubscriptionTarget)>,
+    ) -> Result<(), AppError> {
+        if recipients.is_empty() {
+            return Ok(());
+        }
+
+        let (category_data, guild_id) = self
+            .get_category_data_with_guild_id(fleet.category_id)
+            .await?;
+
+        let embed = self
+            .build_dm_embed(fleet, field_values, &category_data, guild_id, notification)
+            .await?;
+        let content = dm_content(fleet, &category_data, notification);
+
+        for (user_id, target) in recipients {
+            if let Err(e) = self
+                .send_dm(user_id, &content, &embed, &category_data, target)
+                .await
+            {
+                tracing::warn!(
+                    "Failed to send direct message for fleet {} to user {}: {}",
+                    fleet.id,
+                    user_id,
+                    e
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Sends a direct message notification to the given recipients in the background.
+    ///
+    /// Counterpart of `spawn_subscriber_dms` for recipients captured before the fleet
+    /// was deleted.
+    ///
+    /// # Arguments
+    /// - `fleet` - Fleet domain model the notification is about
+    /// - `field_values` - Map of field_id to value for custom ping format fields
+    /// - `notification` - Kind of notification to send
+    /// - `recipients` - Discord ID of each recipient alongside their subscription
+    pub(super) fn spawn_dms(
+        &self,
+        fleet: Fleet,
+        field_values: HashMap<i32, String>,
+        notification: DmNotification,
+        recipients: Vec<(u64, SubscriptionTarget)>,
+    ) {
+        if recipients.is_empty() {
+            return;
+        }
+
+        let db = self.db.clone();
+        let http = self.http.clone();
+        let app_url = self.app_url.clone();
+
+        tokio::spawn(async move {
+            let service = FleetNotificationService::new(&db, http, app_url);
+            if let Err(e) = service
+                .send_dms(&fleet, &field_values, &notification, recipients)
+                .await
+            {
+                tracing::warn!(
+                    "Failed to notify subscribers of fleet {} by direct message: {}",
+                    fleet.id,
+                    e
+                );
+            }
+        });
+    }
+
+    /// Sends the personal reminders of a fleet that are due.
+    ///
+    /// A subscriber's reminder is due once the current time is within their configured
+    /// lead time of the fleet time. Sent reminders are recorded so each subscriber is only
+    /// reminded once, unless the fleet time changes.
+    ///
+    /// # Arguments
+    /// - `fleet` - Fleet domain model to send reminders for
+    /// - `field_values` - Map of field_id to value for custom ping format fields
+    /// - `now` - Current UTC timestamp for calculating reminder times
+    ///
+    /// # Returns
+    /// - `Ok(())` - Sent all due reminders (or failed gracefully)
+    /// - `Err(AppError::NotFound)` - Fleet category or ping format not found
+    /// - `Err(AppError::Database)` - Database error retrieving or storing reminders
+    pub async fn post_due_dm_reminders(
+        &self,
+        fleet: &Fleet,
+        field_values: &HashMap<i32, String>,
+        now: DateTime<Utc>,
+    ) -> Result<(), AppError> {
+        let reminder_repo = FleetDmReminderRepository::new(self.db);
+
+        let (category_data, guild_id) = self
+            .get_category_data_with_guild_id(fleet.category_id)
+            .await?;
+
+        if !is_revealed(fleet, &category_data, now) {
+            return Ok(());
+        }
+
+        let reminded = reminder_repo.get_user_ids_by_fleet(fleet.id).await?;
+        let recipients = self
+            .get_recipients(fleet, &category_data, &DmNotification::Reminder)
+            .await?
+            .into_iter()
+            .filter(|(preference, _)| !reminded.contains(&preference.user_id))
+            .filter(|(preference, _)| {
+                preference.reminder_minutes.is_some_and(|minutes| {
+                    now >= fleet.fleet_time - Duration::minutes(minutes as i64)
+                })
+            })
+            .collect::<Vec<_>>();
+
+        if recipients.is_empty() {
+            return Ok(());
+        }
+
+        let notification = DmNotification::Reminder;
+        let embed = self
+            .build_dm_embed(fleet, field_values, &category_data, guild_id, &notification)
+            .await?;
+        let content = dm_content(fleet, &category_data, &notification);
+
+        for (preference, target) in recipients {
+            // Record the reminder even if the message fails, users with closed direct
+            // messages would otherwise be retried every minute until the fleet starts
+            reminder_repo.create(fleet.id, preference.user_id).await?;
+
+            if let Err(e) = self
+                .send_dm(preference.user_id, &content, &embed, &category_data, target)
+                .await
+            {
+                tracing::warn!(
+                    "Failed to send direct message reminder for fleet {} to user {}: {}",
+                    fleet.id,
+                    preference.user_id,
+                    e
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Gets the subscribers of a fleet who should receive a notification.
+    ///
+    /// # Arguments
+    /// - `fleet` - Fleet the notification is about
+    /// - `category_data` - Category of the fleet
+    /// - `notification` - Kind of notification to send
+    ///
+    /// # Returns
+    /// - `Ok(Vec<(NotificationPreference, SubscriptionTarget)>)` - Preferences of each
+    ///   recipient alongside the subscription they are notified through
+    /// - `Err(AppError::Database)` - Database error retrieving subscribers or permissions
+    async fn get_recipients(
+        &self,
+        fleet: &Fleet,
+        category_data: &FleetCategoryWithRelations,
+        notification: &DmNotification,
+    ) -> Result<Vec<(NotificationPreference, SubscriptionTarget)>, AppError> {
+        let subscribers = SubscriptionRepository::new(self.db)
+            .get_subscribers(category_data.category.id, fleet.id)
+            .await?;
+        if subscribers.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let user_ids = subscribers.iter().map(|(id, _)| *id).collect::<Vec<_>>();
+        let preferences = NotificationPreferenceRepository::new(self.db)
+            .get_by_users(&user_ids)
+            .await?;
+
+        // Access may have been revoked since subscribing
+        let admin_ids = UserRepository::new(self.db)
+            .get_admin_ids(&user_ids)
+            .await?;
+        let viewer_ids = UserCategoryPermissionRepository::new(self.db)
+            .get_users_with_view_access(&user_ids, category_data.category.id)
+            .await?;
+
+        Ok(preferences
+            .into_iter()
+            .zip(subscribers)
+            .filter(|(preference, _)| preference.allows(notification))
+            .filter(|(preference, _)| {
+                admin_ids.contains(&preference.user_id) || viewer_ids.contains(&preference.user_id)
+            })
+            .map(|(preference, (_, target))| (preference, target))
+            .collect())
+    }
+
+    /// Builds the embed of a direct message notification.
+    ///
+    /// Cancellations use the cancellation embed, every other notification uses the
+    /// fleet embed colored like its channel counterpart.
+    async fn build_dm_embed(
+        &self,
+        fleet: &Fleet,
+        field_values: &HashMap<i32, String>,
+        category_data: &FleetCategoryWithRelations,
+        guild_id: u64,
+        notification: &DmNotification,
+    ) -> Result<CreateEmbed, AppError> {
+        if *notification == DmNotification::Cancelled {
+            let commander_name =
+                builder::get_commander_name(self.http.clone(), fleet, guild_id).await?;
+            let settings = self.get_guild_settings(guild_id).await?;
+
+            return builder::build_cancellation_embed(
+                fleet,
+                &category_data.category.name,
+                &commander_name,
+                &settings,
+                &self.app_url,
+            );
+        }
+
+        let kind = match notification {
+            DmNotification::Reminder => EmbedKind::Reminder,
+            _ => EmbedKind::Creation,
+        };
+        let fields = self.get_ping_format_fields(category_data, guild_id).await?;
+
+        self.build_fleet_embed_with_commander(
+            fleet,
+            category_data,
+            &fields,
+            field_values,
+            kind,
+            guild_id,
+        )
+        .await
+    }
+
+    /// Sends a direct message notification with an opt-out button to a user.
+    ///
+    /// # Arguments
+    /// - `user_id` - Discord ID of the recipient
+    /// - `content` - Message content
+    /// - `embed` - Fleet embed of the notification
+    /// - `category_data` - Category of the fleet for the opt-out button label
+    /// - `target` - Subscription the message is sent for
+    ///
+    /// # Returns
+    /// - `Ok(())` - The direct message was sent
+    /// - `Err(serenity::Error)` - Discord API error opening the channel or sending
+    async fn send_dm(
+        &self,
+        user_id: u64,
+        content: &str,
+        embed: &CreateEmbed,
+        category_data: &FleetCategoryWithRelations,
+        target: SubscriptionTarget,
+    ) -> Result<(), serenity::Error> {
+        let label = match target {
+            SubscriptionTarget::Category(_) => {
+                let label = format!("Unsubscribe from {}", category_data.category.name);
+                // Discord limits button labels to 80 characters
+                label.chars().take(80).collect()
+            }
+            SubscriptionTarget::Fleet(_) => "Unsubscribe from this fleet".to_string(),
+        };
+        let button = CreateButton::new(target.unsubscribe_custom_id())
+            .label(label)
+            .style(ButtonStyle::Secondary);
+
+        let message = CreateMessage::new()
+            .content(content)
+            .embed(embed.clone())
+            .components(vec![CreateActionRow::Buttons(vec![button])]);
+
+        let channel = discord_call(
+            "create_dm_channel",
+            UserId::new(user_id).create_dm_channel(&self.http),
+        )
+        .await?;
+        discord_call("send_message", channel.id.send_message(&self.http, message)).await?;
+
+        metrics().record_notification("dm");
+
+        Ok(())
+    }
+}
+
+/// Returns whether a fleet is visible to every viewer of its category.
+///
+/// Hidden fleets are revealed once the category's reminder time has passed, or at fleet
+/// time for categories without a reminder.
+fn is_revealed(
+    fleet: &Fleet,
+    category_data: &FleetCategoryWithRelations,
+    now: DateTime<Utc>,
+) -> bool {
+    if !fleet.hidden {
+        return true;
+    }
+
+    let reveal_time = match category_data.category.ping_reminder {
+        Some(reminder_seconds) => fleet.fleet_time - Duration::seconds(reminder_seconds as i64),
+        None => fleet.fleet_time,
+    };
+
+    now >= reveal_time
+}
+
+/// Builds the content of a direct message notification.
+fn dm_content(
+    fleet: &Fleet,
+    category_data: &FleetCategoryWithRelations,
+    notification: &DmNotification,
+) -> String {
+    let category_name = &category_data.category.name;
+
+    match notification {
+        DmNotification::Created => format!("**.:New Upcoming {}:.**", category_name),
+        DmNotification::Reminder => format!("**.:Reminder - Upcoming {}:.**", category_name),
+        DmNotification::TimeChanged { previous_time } => format!(
+            "**.:Time Changed - {}:.**\n\n**{}** moved from **{} UTC** to **{} UTC** (<t:{}:R>).",
+            category_name,
+            fleet.name,
+            previous_time.format("%Y-%m-%d %H:%M"),
+            fleet.fleet_time.format("%Y-%m-%d %H:%M"),
+            fleet.fleet_time.timestamp()
+        ),
+        DmNotification::Cancelled => format!("**.:Cancelled - {}:.**", category_name),
+    }
+}
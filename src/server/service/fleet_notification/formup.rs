@@ -4,15 +4,17 @@
 //! Formup messages are posted as replies to the most recent existing messages (reminder or creation).
 
 use dioxus_logger::tracing;
-use serenity::all::{ChannelId, CreateEmbed, CreateMessage, MessageId, MessageReference};
+use serenity::all::{CreateEmbed, CreateMessage};
 
 use crate::server::{
     data::fleet_message::FleetMessageRepository,
     error::AppError,
+    metrics::metrics,
     model::{
         category::FleetCategoryWithRelations,
         fleet::Fleet,
         fleet_message::{CreateFleetMessageParam, FleetMessage},
+        guild_settings::EmbedKind,
     },
     util::parse::parse_u64_from_string,
 };
@@ -24,7 +26,7 @@ impl<'a> FleetNotificationService<'a> {
     ///
     /// Creates formup notifications at fleet time to signal immediate gathering. Replies
     /// to the most recent existing message (reminder or creation) for each channel.
-    /// Uses red embed color (0xe74c3c) to indicate urgency.
+    /// Uses the guild's form-up embed color, red by default to indicate urgency.
     ///
     /// # Arguments
     /// - `fleet` - Fleet domain model containing event details
@@ -67,9 +69,10 @@ impl<'a> FleetNotificationService<'a> {
         let embed = self
             .build_fleet_embed_with_commander(
                 fleet,
+                &category_data,
                 &fields[..],
                 field_values,
-                0xe74c3c, // Red color for formup
+                EmbedKind::Formup,
                 guild_id,
             )
             .await?;
@@ -114,9 +117,8 @@ impl<'a> FleetNotificationService<'a> {
         content: &str,
         embed: &CreateEmbed,
     ) -> Result<(), AppError> {
-        for (channel, _) in &category_data.channels {
-            let channel_id_u64 = parse_u64_from_string(channel.channel_id.clone())?;
-            let channel_id = ChannelId::new(channel_id_u64);
+        for channel in &category_data.channels {
+            let channel_id_u64 = parse_u64_from_string(channel.0.channel_id.clone())?;
 
             // Find the most recent message for this channel (prefer reminder over creation)
             let reference_msg = existing_messages
@@ -124,25 +126,24 @@ impl<'a> FleetNotificationService<'a> {
                 .filter(|m| m.channel_id == channel_id_u64)
                 .max_by_key(|m| &m.created_at);
 
-            let mut message = CreateMessage::new().content(content).embed(embed.clone());
+            let message = CreateMessage::new().content(content).embed(embed.clone());
 
             // Reply to the most recent message if it exists
-            if let Some(ref_msg) = reference_msg {
-                message = message.reference_message(MessageReference::from((
-                    channel_id,
-                    MessageId::new(ref_msg.message_id),
-                )));
-            }
+            match self
+                .send_channel_message(&fleet.name, channel_id_u64, channel, message, reference_msg)
+                .await
+            {
+                Ok(posted) => {
+                    metrics().record_notification("formup");
 
-            match channel_id.send_message(&self.http, message).await {
-                Ok(msg) => {
                     // Store message in database
                     message_repo
                         .create(CreateFleetMessageParam {
                             fleet_id: fleet.id,
                             channel_id: channel_id_u64,
-                            message_id: msg.id.get(),
+                            message_id: posted.message_id,
                             message_type: "formup".to_string(),
+                            thread_id: posted.thread_id,
                         })
                         .await?;
 
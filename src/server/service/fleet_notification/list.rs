@@ -1,31 +1,80 @@
 //! Upcoming fleets list management operations.
 //!
-//! This module provides functionality for posting and updating the "upcoming fleets" list message in Discord channels.
-//! The list displays all upcoming non-hidden fleets for categories configured to post in a channel,
-//! with links to fleet messages and relative timestamps.
+//! This module provides functionality for posting and updating the "upcoming fleets" list in Discord channels.
+//! The list displays the upcoming non-hidden fleets for categories configured to post in a channel,
+//! with links to each fleet's detail page, its ping message and relative timestamps. How the list
+//! is built is configured per channel. Lists exceeding Discord's embed limits are split across
+//! multiple embeds and, when needed, multiple messages.
 
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
 use dioxus_logger::tracing;
 use serenity::all::{ChannelId, CreateEmbed, CreateMessage, EditMessage, MessageId, Timestamp};
 
-use crate::server::{
-    data::{
-        category::FleetCategoryRepository, channel_fleet_list::ChannelFleetListRepository,
-        fleet::FleetRepository, fleet_message::FleetMessageRepository,
+use crate::{
+    model::discord::DiscordChannelKind,
+    server::{
+        data::{
+            category::FleetCategoryRepository, channel_fleet_list::ChannelFleetListRepository,
+            channel_list_settings::ChannelListSettingsRepository,
+            discord::DiscordGuildChannelRepository, fleet::FleetRepository,
+            fleet_message::FleetMessageRepository,
+        },
+        error::AppError,
+        metrics::{discord_call, metrics},
+        model::{
+            channel_fleet_list::UpsertChannelFleetListParam,
+            channel_list_settings::ChannelListSettings, guild_settings::EmbedKind,
+        },
+        util::parse::parse_u64_from_string,
     },
-    error::AppError,
-    model::channel_fleet_list::UpsertChannelFleetListParam,
 };
 
-use super::FleetNotificationService;
+use super::{builder, FleetNotificationService};
+
+/// Title of the upcoming fleets list when it isn't split by category
+const LIST_TITLE: &str = ".:Upcoming Events:.";
+
+/// Discord's limit of characters in an embed description
+const EMBED_DESCRIPTION_LIMIT: usize = 4096;
+
+/// Discord's limit of embeds in a single message
+const EMBEDS_PER_MESSAGE: usize = 10;
+
+/// Discord's limit of characters across all embeds of a single message
+const MESSAGE_EMBED_CHARACTER_LIMIT: usize = 6000;
+
+/// Single fleet shown in the upcoming fleets list
+#[derive(Debug, Clone, PartialEq)]
+struct ListEntry {
+    category_name: String,
+    fleet_time: DateTime<Utc>,
+    /// Formatted bullet line linking the fleet & its ping
+    line: String,
+}
+
+/// Embed of the upcoming fleets list before conversion to a Discord embed
+#[derive(Debug, Clone, PartialEq)]
+struct ListPage {
+    title: String,
+    description: String,
+}
+
+impl ListPage {
+    /// Characters counted against Discord's per message embed limit
+    fn len(&self) -> usize {
+        self.title.chars().count() + self.description.chars().count()
+    }
+}
 
 impl<'a> FleetNotificationService<'a> {
     /// Posts or updates the upcoming fleets list for a channel.
     ///
-    /// Creates or updates a single message displaying all upcoming non-hidden fleets
-    /// for categories configured to post in the channel. The list includes links to
-    /// fleet messages and relative timestamps. Only posts a new message if there have
-    /// been no messages in the channel for the last 10 minutes. Otherwise, edits the
-    /// existing message. Uses Discord blurple color (0x5865F2).
+    /// Creates or updates the messages displaying the upcoming fleets for categories
+    /// configured to post in the channel, built according to the channel's list settings.
+    /// Only reposts the list if there have been no messages in the channel for the last
+    /// 10 minutes. Otherwise, edits the existing messages. Uses the guild's list embed color.
     ///
     /// # Arguments
     /// - `channel_id` - Discord channel ID
@@ -39,16 +88,16 @@ impl<'a> FleetNotificationService<'a> {
         let list_repo = ChannelFleetListRepository::new(self.db);
         let now = chrono::Utc::now();
 
-        // Build the fleet list embed (returns None if no fleets and allow_empty=false)
-        let Some(embed) = self.build_fleet_list_embed(channel_id, false).await? else {
+        // Build the fleet list messages (returns None if no fleets and allow_empty=false)
+        let Some(messages) = self.build_fleet_list_messages(channel_id, false).await? else {
             tracing::debug!("No upcoming fleets for channel {}", channel_id);
             return Ok(());
         };
 
-        // Get or create the list message
+        // Get or create the list messages
         let existing_list = list_repo.get_by_channel_id(channel_id).await?;
 
-        // Check if we should edit or post new message
+        // Check if we should edit or post new messages
         if let Some(existing) = existing_list {
             // Check if the fleet list is still the most recent message
             let is_most_recent = existing.updated_at >= existing.last_message_at;
@@ -57,10 +106,10 @@ impl<'a> FleetNotificationService<'a> {
             let time_since_last_message = now - existing.last_message_at;
             let ten_minutes = chrono::Duration::minutes(10);
 
-            // Only post new message if:
+            // Only post new messages if:
             // 1. Fleet list is NOT the most recent message, AND
             // 2. No messages in channel for last 10 minutes
-            // Otherwise, always edit the existing message
+            // Otherwise, always edit the existing messages
             let should_post_new = !is_most_recent && time_since_last_message >= ten_minutes;
 
             tracing::debug!(
@@ -74,39 +123,35 @@ impl<'a> FleetNotificationService<'a> {
 
             if should_post_new {
                 // Channel has been quiet for 10+ minutes AND list is buried, delete old and post new
-                self.delete_and_repost_fleet_list(
-                    channel_id_obj,
-                    existing.message_id,
-                    embed,
-                    &list_repo,
-                    channel_id,
-                )
-                .await?;
+                self.delete_fleet_list_messages(channel_id_obj, &existing.message_ids())
+                    .await;
+                self.sync_fleet_list_messages(channel_id_obj, &[], messages, &list_repo)
+                    .await?;
             } else {
-                // Fleet list is still most recent OR channel is active, just edit the existing message
-                self.edit_fleet_list_message(
+                // Fleet list is still most recent OR channel is active, just edit the existing messages
+                self.sync_fleet_list_messages(
                     channel_id_obj,
-                    existing.message_id,
-                    embed,
+                    &existing.message_ids(),
+                    messages,
                     &list_repo,
-                    channel_id,
                 )
                 .await?;
             }
         } else {
-            // No existing list, post new message
-            self.post_new_fleet_list_message(channel_id_obj, embed, &list_repo, channel_id)
+            // No existing list, post new messages
+            self.sync_fleet_list_messages(channel_id_obj, &[], messages, &list_repo)
                 .await?;
         }
 
         Ok(())
     }
 
-    /// Updates the upcoming fleets list by only editing the existing message.
+    /// Updates the upcoming fleets list by only editing the existing messages.
     ///
     /// This method is used when a fleet is updated or deleted to reflect changes
     /// in the upcoming fleets list without bumping it to the most recent message.
     /// If there are no upcoming fleets, it displays "No upcoming fleets..." message.
+    /// Messages are only posted when the list grew beyond its existing messages.
     ///
     /// # Arguments
     /// - `channel_id` - Discord channel ID
@@ -119,8 +164,8 @@ impl<'a> FleetNotificationService<'a> {
         let channel_id_obj = ChannelId::new(channel_id);
         let list_repo = ChannelFleetListRepository::new(self.db);
 
-        // Build the fleet list embed (allow_empty=true to show "No upcoming fleets...")
-        let Some(embed) = self.build_fleet_list_embed(channel_id, true).await? else {
+        // Build the fleet list messages (allow_empty=true to show "No upcoming fleets...")
+        let Some(messages) = self.build_fleet_list_messages(channel_id, true).await? else {
             tracing::debug!(
                 "No categories configured for channel {}, skipping list update",
                 channel_id
@@ -128,35 +173,27 @@ impl<'a> FleetNotificationService<'a> {
             return Ok(());
         };
 
-        // Get the existing list message
-        let existing_list = list_repo.get_by_channel_id(channel_id).await?;
+        // Always edit the existing messages, never bump to most recent
+        let existing_ids = list_repo
+            .get_by_channel_id(channel_id)
+            .await?
+            .map(|existing| existing.message_ids())
+            .unwrap_or_default();
 
-        if let Some(existing) = existing_list {
-            // Always edit the existing message, never bump to most recent
-            self.edit_fleet_list_message(
-                channel_id_obj,
-                existing.message_id,
-                embed,
-                &list_repo,
-                channel_id,
-            )
+        self.sync_fleet_list_messages(channel_id_obj, &existing_ids, messages, &list_repo)
             .await?;
-            tracing::debug!(
-                "Updated upcoming fleets list in channel {} (edit only, no bump)",
-                channel_id
-            );
-        } else {
-            // No existing list, post new message
-            self.post_new_fleet_list_message(channel_id_obj, embed, &list_repo, channel_id)
-                .await?;
-        }
+        tracing::debug!(
+            "Updated upcoming fleets list in channel {} (edit only, no bump)",
+            channel_id
+        );
 
         Ok(())
     }
 
-    /// Builds the fleet list embed with all upcoming fleets for a channel.
+    /// Builds the fleet list embeds with the upcoming fleets of a channel.
     ///
-    /// Fetches categories, fleets, and builds the embed description with fleet links.
+    /// Fetches categories, fleets & their messages in the channel, then builds the list
+    /// according to the channel's list settings, split into the embeds of each message.
     ///
     /// # Arguments
     /// - `channel_id` - Discord channel ID
@@ -164,20 +201,28 @@ impl<'a> FleetNotificationService<'a> {
     ///                   If false, returns None when no fleets.
     ///
     /// # Returns
-    /// - `Ok(Some(CreateEmbed))` - Successfully built embed
-    /// - `Ok(None)` - No categories configured OR (no fleets and !allow_empty)
+    /// - `Ok(Some(messages))` - Embeds of each list message, in order
+    /// - `Ok(None)` - No categories configured, forum channel OR (no fleets and !allow_empty)
     /// - `Err(AppError)` - Database error
-    async fn build_fleet_list_embed(
+    async fn build_fleet_list_messages(
         &self,
         channel_id: u64,
         allow_empty: bool,
-    ) -> Result<Option<CreateEmbed>, AppError> {
+    ) -> Result<Option<Vec<Vec<CreateEmbed>>>, AppError> {
         let now = chrono::Utc::now();
 
         let category_repo = FleetCategoryRepository::new(self.db);
         let fleet_repo = FleetRepository::new(self.db);
         let message_repo = FleetMessageRepository::new(self.db);
 
+        // Forum channels only hold fleet posts, there is no place for a list message
+        let channel = DiscordGuildChannelRepository::new(self.db)
+            .find_by_channel_id(channel_id)
+            .await?;
+        if channel.is_some_and(|c| c.kind == DiscordChannelKind::Forum) {
+            return Ok(None);
+        }
+
         // Get all categories that post to this channel
         let category_ids = category_repo
             .get_category_ids_by_channel(channel_id)
@@ -187,227 +232,567 @@ impl<'a> FleetNotificationService<'a> {
             return Ok(None);
         }
 
-        // Get all upcoming fleets for these categories
-        let fleets = fleet_repo
-            .get_upcoming_by_categories(category_ids.clone(), now)
-            .await?;
-
-        // Get guild_id from the first category for building message links
+        // Get guild_id from the first category for building message & fleet page links
         let guild_id =
             if let Some(category_data) = category_repo.find_by_id(category_ids[0]).await? {
-                category_data.category.guild_id
+                parse_u64_from_string(category_data.category.guild_id)?
             } else {
                 return Ok(None);
             };
 
-        // Build description with bullet list of fleets
-        let description = if fleets.is_empty() {
-            if !allow_empty {
-                return Ok(None);
-            }
-            "No upcoming events scheduled.".to_string()
-        } else {
-            // Get categories data for names
-            let category_map = category_repo.get_names_by_ids(category_ids.clone()).await?;
+        let settings = self.get_guild_settings(guild_id).await?;
+        let list_settings = ChannelListSettingsRepository::new(self.db)
+            .get_by_channel(channel_id)
+            .await?;
+
+        // Get all upcoming fleets for these categories, hidden fleets are only kept once revealed
+        let mut fleets = fleet_repo
+            .get_upcoming_by_categories(
+                category_ids.clone(),
+                now,
+                list_settings.show_revealed_hidden,
+            )
+            .await?;
+
+        // Only list fleets within the channel's or else the guild's lookahead window
+        if let Some(hours) = list_settings
+            .lookahead_hours
+            .or(settings.list_lookahead_hours)
+        {
+            let lookahead_end = now + chrono::Duration::hours(i64::from(hours));
+            fleets.retain(|fleet| fleet.fleet_time <= lookahead_end);
+        }
+
+        // Get the messages of all fleets in this channel at once
+        let fleet_ids: Vec<i32> = fleets.iter().map(|fleet| fleet.id).collect();
+        let mut messages_by_fleet: HashMap<i32, Vec<_>> = HashMap::new();
+        for message in message_repo
+            .get_by_fleet_ids_and_channel(&fleet_ids, channel_id)
+            .await?
+        {
+            messages_by_fleet
+                .entry(message.fleet_id)
+                .or_default()
+                .push(message);
+        }
 
-            let mut desc = String::new();
+        // Hidden fleets are revealed in the channel once their reminder was posted
+        fleets.retain(|fleet| {
+            !fleet.hidden
+                || messages_by_fleet
+                    .get(&fleet.id)
+                    .is_some_and(|messages| messages.iter().any(|m| m.message_type == "reminder"))
+        });
 
-            for fleet in fleets {
+        if fleets.is_empty() && !allow_empty {
+            return Ok(None);
+        }
+
+        // Limit the list to the configured amount of fleets
+        let max_entries = list_settings
+            .max_entries
+            .map(|max| max.max(0) as usize)
+            .unwrap_or(usize::MAX);
+        let omitted = fleets.len().saturating_sub(max_entries);
+        fleets.truncate(max_entries);
+
+        // Get categories data for names
+        let category_map = category_repo.get_names_by_ids(category_ids.clone()).await?;
+
+        let entries: Vec<ListEntry> = fleets
+            .iter()
+            .map(|fleet| {
                 let category_name = category_map
                     .get(&fleet.category_id)
                     .map(|s| s.as_str())
                     .unwrap_or("Unknown");
 
-                // Get the most recent message for this fleet in this channel (prefer reminder over creation)
-                let messages = message_repo
-                    .get_by_fleet_id_and_channel(fleet.id, channel_id)
-                    .await?;
-
-                // Find reminder or creation message (not formup)
-                let message_link = messages
-                    .iter()
+                // Find the most recent reminder or creation message (not formup)
+                let message_link = messages_by_fleet
+                    .get(&fleet.id)
+                    .into_iter()
+                    .flatten()
                     .filter(|m| m.message_type == "reminder" || m.message_type == "creation")
                     .max_by_key(|m| &m.created_at)
                     .map(|m| {
                         format!(
                             "https://discord.com/channels/{}/{}/{}",
-                            guild_id, channel_id, m.message_id
+                            guild_id,
+                            m.message_channel_id(),
+                            m.message_id
                         )
                     });
 
+                // Format: • Category - [Fleet Name](fleet page) - relative time - [Ping](message)
+                // The category is left out when each category has its own section
+                let mut line = if list_settings.split_by_category {
+                    "• ".to_string()
+                } else {
+                    format!("• {} - ", category_name)
+                };
+                line.push_str(&format!(
+                    "[{}]({}) - <t:{}:R>",
+                    fleet.name,
+                    builder::fleet_url(&self.app_url, guild_id, fleet.id),
+                    fleet.fleet_time.timestamp()
+                ));
                 if let Some(link) = message_link {
-                    // Format: • Category - [Fleet Name](link) - relative time
-                    let line = format!(
-                        "• {} - [{}]({}) - <t:{}:R>\n",
-                        category_name,
-                        fleet.name,
-                        link,
-                        fleet.fleet_time.timestamp()
-                    );
-                    desc.push_str(&line);
+                    line.push_str(&format!(" - [Ping]({})", link));
                 }
-            }
 
-            desc
-        };
-
-        // Build embed with description containing the fleet list
-        let embed = CreateEmbed::new()
-            .title(".:Upcoming Events:.")
-            .url(&self.app_url)
-            .description(description)
-            .color(0x5865F2) // Discord blurple color
-            .timestamp(Timestamp::from_unix_timestamp(now.timestamp()).unwrap());
+                ListEntry {
+                    category_name: category_name.to_string(),
+                    fleet_time: fleet.fleet_time,
+                    line,
+                }
+            })
+            .collect();
+
+        let more_line = (omitted > 0)
+            .then(|| format!("[+{} more on the timerboard]({})", omitted, self.app_url));
+
+        let pages = build_list_pages(&entries, &list_settings, more_line);
+        let total_pages = pages.iter().map(Vec::len).sum::<usize>();
+        let color = settings.embed_color(EmbedKind::List);
+
+        let mut page_number = 0;
+        let messages = pages
+            .into_iter()
+            .map(|message| {
+                message
+                    .into_iter()
+                    .map(|page| {
+                        page_number += 1;
+
+                        let mut embed = CreateEmbed::new()
+                            .title(page.title)
+                            .description(page.description)
+                            .color(color);
+                        if page_number == 1 {
+                            embed = embed.url(&self.app_url);
+                        }
+                        if page_number == total_pages {
+                            embed = embed.timestamp(
+                                Timestamp::from_unix_timestamp(now.timestamp()).unwrap(),
+                            );
+                        }
+                        embed
+                    })
+                    .collect()
+            })
+            .collect();
 
-        Ok(Some(embed))
+        Ok(Some(messages))
     }
 
-    /// Edits an existing fleet list message.
+    /// Brings the list messages of a channel in line with the newly built list.
+    ///
+    /// Edits the existing messages in order, posts additional messages when the list
+    /// grew & deletes messages no longer needed when it shrank. The resulting message
+    /// IDs are stored for the channel.
     ///
     /// # Arguments
     /// - `channel_id` - Discord channel ID object
-    /// - `message_id` - Existing message ID to edit
-    /// - `embed` - New embed to set
+    /// - `existing_ids` - IDs of the current list messages, in order
+    /// - `messages` - Embeds of each list message, in order
     /// - `list_repo` - Channel fleet list repository
-    /// - `channel_id_u64` - Channel ID as u64 for repository operations
     ///
     /// # Returns
-    /// - `Ok(())` - Successfully edited message
+    /// - `Ok(())` - Messages synced, individual Discord failures are logged
     /// - `Err(AppError)` - Database error
-    async fn edit_fleet_list_message(
+    async fn sync_fleet_list_messages(
         &self,
         channel_id: ChannelId,
-        message_id: u64,
-        embed: CreateEmbed,
+        existing_ids: &[u64],
+        messages: Vec<Vec<CreateEmbed>>,
         list_repo: &ChannelFleetListRepository<'_>,
-        channel_id_u64: u64,
     ) -> Result<(), AppError> {
-        let edit_message = EditMessage::new().embed(embed);
+        let mut message_ids = Vec::new();
+
+        for (index, embeds) in messages.into_iter().enumerate() {
+            let message_id = match existing_ids.get(index) {
+                Some(&message_id) => {
+                    self.edit_fleet_list_message(channel_id, message_id, embeds)
+                        .await;
+                    Some(message_id)
+                }
+                None => self.post_new_fleet_list_message(channel_id, embeds).await,
+            };
+            message_ids.extend(message_id);
+        }
+
+        // Delete messages of a previously longer list
+        if existing_ids.len() > message_ids.len() {
+            self.delete_fleet_list_messages(channel_id, &existing_ids[message_ids.len()..])
+                .await;
+        }
+
+        let Some((&message_id, overflow_message_ids)) = message_ids.split_first() else {
+            return Ok(());
+        };
+
+        metrics().record_notification("list");
+
+        // Update the message IDs & updated_at timestamp
+        list_repo
+            .upsert(UpsertChannelFleetListParam {
+                channel_id: channel_id.get(),
+                message_id,
+                overflow_message_ids: overflow_message_ids.to_vec(),
+            })
+            .await?;
+        tracing::info!(
+            "Synced upcoming fleets list in channel {} across {} message(s)",
+            channel_id,
+            message_ids.len()
+        );
+
+        Ok(())
+    }
+
+    /// Edits an existing fleet list message.
+    ///
+    /// # Arguments
+    /// - `channel_id` - Discord channel ID object
+    /// - `message_id` - Existing message ID to edit
+    /// - `embeds` - New embeds to set
+    async fn edit_fleet_list_message(
+        &self,
+        channel_id: ChannelId,
+        message_id: u64,
+        embeds: Vec<CreateEmbed>,
+    ) {
+        let edit_message = EditMessage::new().embeds(embeds);
 
-        match self
-            .http
-            .edit_message(
+        if let Err(e) = discord_call(
+            "edit_message",
+            self.http.edit_message(
                 channel_id,
                 MessageId::new(message_id),
                 &edit_message,
                 vec![],
-            )
-            .await
+            ),
+        )
+        .await
         {
-            Ok(_) => {
-                // Update the updated_at timestamp
-                list_repo
-                    .upsert(UpsertChannelFleetListParam {
-                        channel_id: channel_id_u64,
-                        message_id,
-                    })
-                    .await?;
-                tracing::info!(
-                    "Edited existing upcoming fleets list in channel {}",
-                    channel_id_u64
-                );
-            }
-            Err(e) => {
-                tracing::error!(
-                    "Failed to edit upcoming fleets list in channel {}: {}",
-                    channel_id_u64,
-                    e
-                );
-            }
+            tracing::error!(
+                "Failed to edit upcoming fleets list message {} in channel {}: {}",
+                message_id,
+                channel_id,
+                e
+            );
         }
-
-        Ok(())
     }
 
     /// Posts a new fleet list message.
     ///
     /// # Arguments
     /// - `channel_id` - Discord channel ID object
-    /// - `embed` - Embed to post
-    /// - `list_repo` - Channel fleet list repository
-    /// - `channel_id_u64` - Channel ID as u64 for repository operations
+    /// - `embeds` - Embeds to post
     ///
     /// # Returns
-    /// - `Ok(())` - Successfully posted message
-    /// - `Err(AppError)` - Database error
+    /// - `Some(message_id)` - ID of the posted message
+    /// - `None` - Posting failed, the error is logged
     async fn post_new_fleet_list_message(
         &self,
         channel_id: ChannelId,
-        embed: CreateEmbed,
-        list_repo: &ChannelFleetListRepository<'_>,
-        channel_id_u64: u64,
-    ) -> Result<(), AppError> {
-        let new_message = CreateMessage::new().embed(embed);
-
-        match channel_id.send_message(&self.http, new_message).await {
-            Ok(msg) => {
-                list_repo
-                    .upsert(UpsertChannelFleetListParam {
-                        channel_id: channel_id_u64,
-                        message_id: msg.id.get(),
-                    })
-                    .await?;
-                tracing::info!(
-                    "Posted new upcoming fleets list in channel {}",
-                    channel_id_u64
-                );
-            }
+        embeds: Vec<CreateEmbed>,
+    ) -> Option<u64> {
+        let new_message = CreateMessage::new().embeds(embeds);
+
+        match discord_call(
+            "send_message",
+            channel_id.send_message(&self.http, new_message),
+        )
+        .await
+        {
+            Ok(msg) => Some(msg.id.get()),
             Err(e) => {
                 tracing::error!(
                     "Failed to post upcoming fleets list in channel {}: {}",
-                    channel_id_u64,
+                    channel_id,
                     e
                 );
+                None
             }
         }
-
-        Ok(())
     }
 
-    /// Deletes an old fleet list message and posts a new one.
+    /// Deletes fleet list messages, failures are logged and otherwise ignored.
     ///
     /// # Arguments
     /// - `channel_id` - Discord channel ID object
-    /// - `old_message_id` - Old message ID to delete
-    /// - `embed` - New embed to post
-    /// - `list_repo` - Channel fleet list repository
-    /// - `channel_id_u64` - Channel ID as u64 for repository operations
-    ///
-    /// # Returns
-    /// - `Ok(())` - Successfully deleted and posted
-    /// - `Err(AppError)` - Database error
-    async fn delete_and_repost_fleet_list(
-        &self,
-        channel_id: ChannelId,
-        old_message_id: u64,
-        embed: CreateEmbed,
-        list_repo: &ChannelFleetListRepository<'_>,
-        channel_id_u64: u64,
-    ) -> Result<(), AppError> {
-        // Delete old message
-        match self
-            .http
-            .delete_message(channel_id, MessageId::new(old_message_id), None)
+    /// - `message_ids` - IDs of the messages to delete
+    async fn delete_fleet_list_messages(&self, channel_id: ChannelId, message_ids: &[u64]) {
+        for &message_id in message_ids {
+            match discord_call(
+                "delete_message",
+                self.http
+                    .delete_message(channel_id, MessageId::new(message_id), None),
+            )
             .await
+            {
+                Ok(_) => {
+                    tracing::debug!(
+                        "Deleted upcoming fleets list message {} in channel {}",
+                        message_id,
+                        channel_id
+                    );
+                }
+                Err(e) => {
+                    tracing::warn!(
+                        "Failed to delete upcoming fleets list message {} in channel {}: {}",
+                        message_id,
+                        channel_id,
+                        e
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Builds the pages of the upcoming fleets list, grouped into the messages posting them.
+///
+/// Fleets are optionally split into a section per category & grouped under a header per
+/// day in EVE time. Each section is paginated to fit Discord's embed description limit,
+/// and pages are grouped into messages within Discord's per message embed limits.
+///
+/// # Arguments
+/// - `entries` - Fleets to list, ordered by fleet time
+/// - `settings` - List settings of the channel
+/// - `more_line` - Line appended to the list when fleets were left out
+///
+/// # Returns
+/// - Pages of each message, always at least one message with one page
+fn build_list_pages(
+    entries: &[ListEntry],
+    settings: &ChannelListSettings,
+    more_line: Option<String>,
+) -> Vec<Vec<ListPage>> {
+    // Split entries into sections, keeping the order of each category's first fleet
+    let mut sections: Vec<(String, Vec<&ListEntry>)> = Vec::new();
+    for entry in entries {
+        let title = if settings.split_by_category {
+            entry.category_name.clone()
+        } else {
+            LIST_TITLE.to_string()
+        };
+        match sections.iter_mut().find(|(t, _)| *t == title) {
+            Some((_, section)) => section.push(entry),
+            None => sections.push((title, vec![entry])),
+        }
+    }
+
+    let mut pages = Vec::new();
+    for (title, section) in sections {
+        let mut lines = Vec::new();
+        let mut current_day = None;
+        for entry in section {
+            let day = entry.fleet_time.date_naive();
+            if settings.group_by_day && current_day != Some(day) {
+                lines.push(format!("**{}**", day.format("%A, %d %B (EVE)")));
+                current_day = Some(day);
+            }
+            lines.push(entry.line.clone());
+        }
+
+        for (index, description) in paginate_lines(&lines).into_iter().enumerate() {
+            let title = if index == 0 {
+                title.clone()
+            } else {
+                format!("{} (continued)", title)
+            };
+            pages.push(ListPage { title, description });
+        }
+    }
+
+    if pages.is_empty() {
+        pages.push(ListPage {
+            title: LIST_TITLE.to_string(),
+            description: "No upcoming events scheduled.".to_string(),
+        });
+    }
+
+    if let Some(more_line) = more_line {
+        let last = pages.last_mut().expect("list has at least one page");
+        if last.description.chars().count() + more_line.chars().count() < EMBED_DESCRIPTION_LIMIT {
+            last.description.push('\n');
+            last.description.push_str(&more_line);
+        } else {
+            let title = format!("{} (continued)", last.title);
+            pages.push(ListPage {
+                title,
+                description: more_line,
+            });
+        }
+    }
+
+    // Group pages into messages within the embed count & character limits
+    let mut messages: Vec<Vec<ListPage>> = Vec::new();
+    let mut current: Vec<ListPage> = Vec::new();
+    let mut current_len = 0;
+    for page in pages {
+        if !current.is_empty()
+            && (current.len() == EMBEDS_PER_MESSAGE
+                || current_len + page.len() > MESSAGE_EMBED_CHARACTER_LIMIT)
         {
-            Ok(_) => {
-                tracing::debug!(
-                    "Deleted old upcoming fleets list in channel {} (not most recent)",
-                    channel_id_u64
-                );
+            messages.push(std::mem::take(&mut current));
+            current_len = 0;
+        }
+        current_len += page.len();
+        current.push(page);
+    }
+    messages.push(current);
+
+    messages
+}
+
+/// Joins lines into embed descriptions within Discord's description limit.
+///
+/// Day headers are moved to the next description rather than ending one, lines too long
+/// on their own are truncated.
+fn paginate_lines(lines: &[String]) -> Vec<String> {
+    let mut descriptions = Vec::new();
+    let mut current: Vec<String> = Vec::new();
+    let mut current_len = 0;
+
+    for line in lines {
+        let line: String = line.chars().take(EMBED_DESCRIPTION_LIMIT).collect();
+        let line_len = line.chars().count();
+
+        // Account for the newline joining this line to the previous one
+        if !current.is_empty() && current_len + 1 + line_len > EMBED_DESCRIPTION_LIMIT {
+            // Carry a trailing day header over to the next description
+            let carried = current
+                .last()
+                .filter(|last| last.starts_with("**"))
+                .cloned();
+            if carried.is_some() {
+                current.pop();
             }
-            Err(e) => {
-                tracing::warn!(
-                    "Failed to delete old upcoming fleets list in channel {}: {}",
-                    channel_id_u64,
-                    e
-                );
-                // Continue anyway to post new message
+            descriptions.push(current.join("\n"));
+            current = carried.into_iter().collect();
+            current_len = current.iter().map(|l| l.chars().count()).sum();
+        }
+
+        if !current.is_empty() {
+            current_len += 1;
+        }
+        current_len += line_len;
+        current.push(line);
+    }
+
+    if !current.is_empty() {
+        descriptions.push(current.join("\n"));
+    }
+
+    descriptions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(category_name: &str, fleet_time: &str, line_len: usize) -> ListEntry {
+        ListEntry {
+            category_name: category_name.to_string(),
+            fleet_time: DateTime::parse_from_rfc3339(fleet_time)
+                .unwrap()
+                .with_timezone(&Utc),
+            line: "x".repeat(line_len),
+        }
+    }
+
+    /// Tests building the pages of a list too long for a single embed.
+    ///
+    /// Verifies that every description stays within Discord's description limit, that
+    /// messages stay within the per message limits and no fleet is lost.
+    ///
+    /// Expected: Multiple pages across multiple messages containing every line
+    #[test]
+    fn paginates_long_lists_within_discord_limits() {
+        let entries: Vec<ListEntry> = (0..100)
+            .map(|_| entry("Strategic", "2026-01-15T18:00:00Z", 150))
+            .collect();
+        let settings = ChannelListSettings::default_for(1);
+
+        let messages = build_list_pages(&entries, &settings, None);
+
+        assert!(messages.len() > 1);
+        for message in &messages {
+            assert!(message.len() <= EMBEDS_PER_MESSAGE);
+            assert!(
+                message.iter().map(ListPage::len).sum::<usize>() <= MESSAGE_EMBED_CHARACTER_LIMIT
+            );
+            for page in message {
+                assert!(page.description.chars().count() <= EMBED_DESCRIPTION_LIMIT);
             }
         }
+        let lines: usize = messages
+            .iter()
+            .flatten()
+            .map(|page| page.description.lines().count())
+            .sum();
+        assert_eq!(lines, 100);
+        let titles: Vec<&str> = messages
+            .iter()
+            .flatten()
+            .map(|page| page.title.as_str())
+            .collect();
+        assert_eq!(titles[0], LIST_TITLE);
+        assert_eq!(titles[1], format!("{} (continued)", LIST_TITLE));
+    }
 
-        // Post new message
-        self.post_new_fleet_list_message(channel_id, embed, list_repo, channel_id_u64)
-            .await
+    /// Tests grouping fleets by day & splitting them by category.
+    ///
+    /// Verifies that each category gets its own page and that a day header precedes the
+    /// first fleet of each day within a category.
+    ///
+    /// Expected: One page per category with day headers
+    #[test]
+    fn groups_by_day_and_splits_by_category() {
+        let entries = vec![
+            entry("Strategic", "2026-01-15T18:00:00Z", 10),
+            entry("Roams", "2026-01-15T19:00:00Z", 10),
+            entry("Strategic", "2026-01-16T01:00:00Z", 10),
+        ];
+        let settings = ChannelListSettings {
+            group_by_day: true,
+            split_by_category: true,
+            ..ChannelListSettings::default_for(1)
+        };
+
+        let messages = build_list_pages(&entries, &settings, Some("+1 more".to_string()));
+
+        assert_eq!(messages.len(), 1);
+        let pages = &messages[0];
+        assert_eq!(pages.len(), 2);
+        assert_eq!(pages[0].title, "Strategic");
+        assert_eq!(
+            pages[0].description,
+            format!(
+                "**Thursday, 15 January (EVE)**\n{0}\n**Friday, 16 January (EVE)**\n{0}",
+                "x".repeat(10)
+            )
+        );
+        assert_eq!(pages[1].title, "Roams");
+        assert!(pages[1].description.ends_with("\n+1 more"));
+    }
+
+    /// Tests building the list without any fleets.
+    ///
+    /// Expected: A single page stating no events are scheduled
+    #[test]
+    fn builds_empty_list() {
+        let messages = build_list_pages(&[], &ChannelListSettings::default_for(1), None);
+
+        assert_eq!(
+            messages,
+            vec![vec![ListPage {
+                title: LIST_TITLE.to_string(),
+                description: "No upcoming events scheduled.".to_string(),
+            }]]
+        );
     }
 }
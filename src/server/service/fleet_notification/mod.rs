@@ -10,26 +10,63 @@
 //! - `reminder` - Fleet reminder notifications
 //! - `formup` - Fleet formup (start) notifications
 //! - `list` - Upcoming fleets list management
+//! - `dm` - Direct message notifications for subscribers
+//! - `stand_down` - Stand-down broadcasts to every affected channel
+//! - `broadcast` - Ad-hoc broadcasts to chosen channels of a guild's categories
+//! - `bulk_update` - One summary per channel of fleets changed together
 
+pub mod broadcast;
 pub mod builder;
+pub mod bulk_update;
 pub mod cancel;
 pub mod creation;
+pub mod dm;
 pub mod formup;
 pub mod list;
 pub mod reminder;
+pub mod stand_down;
 pub mod update;
 
+use dioxus_logger::tracing;
 use sea_orm::DatabaseConnection;
-use serenity::{all::CreateEmbed, http::Http};
+use serenity::{
+    all::{ChannelId, CreateEmbed, CreateForumPost, CreateMessage, MessageId, MessageReference},
+    http::Http,
+};
 use std::sync::Arc;
 
-use crate::server::{
-    data::{category::FleetCategoryRepository, ping_format::field::PingFormatFieldRepository},
-    error::AppError,
-    model::{category::FleetCategoryWithRelations, fleet::Fleet, ping_format::PingFormatField},
-    util::parse::parse_u64_from_string,
+use crate::{
+    model::{discord::DiscordChannelKind, ping_format::PingFormatFieldType},
+    server::{
+        data::{
+            category::FleetCategoryRepository, doctrine::DoctrineRepository,
+            guild_settings::GuildSettingsRepository, ping_format::field::PingFormatFieldRepository,
+        },
+        error::AppError,
+        metrics::discord_call,
+        model::{
+            category::FleetCategoryWithRelations,
+            fleet::Fleet,
+            fleet_message::FleetMessage,
+            guild_settings::{EmbedKind, GuildSettings},
+            ping_format::PingFormatField,
+            solar_system::StagingRoute,
+        },
+        util::parse::parse_u64_from_string,
+    },
 };
 
+/// Maximum length of a Discord forum post title.
+const FORUM_POST_TITLE_LIMIT: usize = 100;
+
+/// Discord message posted to a configured channel.
+struct PostedMessage {
+    /// Discord message ID
+    message_id: u64,
+    /// Forum post thread ID the message was sent in, None outside of forum channels
+    thread_id: Option<u64>,
+}
+
 /// Service providing Discord notification operations for fleet events.
 ///
 /// This struct holds references to the database connection, Discord HTTP client, and
@@ -113,14 +150,26 @@ impl<'a> FleetNotificationService<'a> {
         Ok(fields)
     }
 
+    /// Fetches the display & behaviour settings of a guild
+    ///
+    /// # Returns
+    /// - `Ok(settings)` - Stored or default settings of the guild
+    /// - `Err(AppError::Database)` - Database error retrieving settings
+    async fn get_guild_settings(&self, guild_id: u64) -> Result<GuildSettings, AppError> {
+        GuildSettingsRepository::new(self.db)
+            .get_by_guild(guild_id)
+            .await
+    }
+
     /// Builds fleet embed with commander name fetching
     ///
     /// # Arguments
     /// - `fleet` - Fleet data
+    /// - `category_data` - Category of the fleet, may override the guild's staging system
     /// - `fields` - Ping format fields
     /// - `field_values` - Field values map
-    /// - `color` - Embed color
-    /// - `guild_id` - Guild ID for fetching commander name
+    /// - `kind` - Kind of message, selects the guild's embed color
+    /// - `guild_id` - Guild ID for fetching commander name & settings
     ///
     /// # Returns
     /// - `Ok(embed)` - Built embed
@@ -128,19 +177,43 @@ impl<'a> FleetNotificationService<'a> {
     async fn build_fleet_embed_with_commander(
         &self,
         fleet: &Fleet,
+        category_data: &FleetCategoryWithRelations,
         fields: &[PingFormatField],
         field_values: &std::collections::HashMap<i32, String>,
-        color: u32,
+        kind: EmbedKind,
         guild_id: u64,
     ) -> Result<CreateEmbed, AppError> {
         let commander_name =
             builder::get_commander_name(self.http.clone(), fleet, guild_id).await?;
+        let settings = self.get_guild_settings(guild_id).await?;
 
-        let embed = builder::build_fleet_embed(
-            fleet,
+        let staging_id = category_data
+            .category
+            .staging_system_id
+            .map(|id| id as u32)
+            .or(settings.staging_system_id);
+        let route = StagingRoute::for_fleet(staging_id, fields, field_values);
+
+        let doctrine_ids = fields
+            .iter()
+            .filter(|f| f.field_type == PingFormatFieldType::Doctrine)
+            .filter_map(|f| field_values.get(&f.id)?.parse::<i32>().ok())
+            .collect();
+        let doctrines = DoctrineRepository::new(self.db)
+            .find_by_ids(guild_id, doctrine_ids)
+            .await?;
+
+        let embed_fields = builder::EmbedFields {
             fields,
             field_values,
-            color,
+            route: route.as_ref(),
+            doctrines: &doctrines,
+        };
+        let embed = builder::build_fleet_embed(
+            fleet,
+            &embed_fields,
+            &settings,
+            kind,
             &commander_name,
             &self.app_url,
         )
@@ -165,18 +238,144 @@ impl<'a> FleetNotificationService<'a> {
         category_data: &FleetCategoryWithRelations,
         guild_id: u64,
     ) -> Result<String, AppError> {
-        let mut content = format!("{}\n\n", title);
-        for (ping_role, _) in &category_data.ping_roles {
-            let role_id = parse_u64_from_string(ping_role.role_id.clone())?;
-
-            // @everyone role has the same ID as the guild - use @everyone instead of <@&guild_id>
-            if role_id == guild_id {
-                content.push_str("@everyone ");
-            } else {
-                content.push_str(&format!("<@&{}> ", role_id));
+        let role_ids = category_ping_role_ids(category_data)?;
+
+        Ok(build_role_ping_content(title, &role_ids, guild_id))
+    }
+
+    /// Posts a message to a channel configured for the fleet's category.
+    ///
+    /// Text and announcement channels receive a regular message which replies to
+    /// `reference` when provided. Forum channels receive a new forum post titled `title`,
+    /// or a reply within the fleet's existing forum post when `reference` was sent in one.
+    /// Messages in announcement channels are crossposted to following servers when
+    /// crossposting is enabled for the category channel; a failed crosspost is logged
+    /// without failing the post.
+    ///
+    /// # Arguments
+    /// - `title` - Title of the forum post created in forum channels, usually the fleet name
+    /// - `channel_id` - Discord channel ID of the category channel
+    /// - `channel` - Category channel configuration alongside the synced guild channel
+    /// - `message` - Message to post
+    /// - `reference` - Earlier fleet message in the same channel to reply to
+    ///
+    /// # Returns
+    /// - `Ok(PostedMessage)` - The posted message and the forum post thread it was sent in
+    /// - `Err(serenity::Error)` - Discord API error posting the message
+    async fn send_channel_message(
+        &self,
+        title: &str,
+        channel_id: u64,
+        channel: &(
+            entity::fleet_category_channel::Model,
+            Option<entity::discord_guild_channel::Model>,
+        ),
+        message: CreateMessage,
+        reference: Option<&FleetMessage>,
+    ) -> Result<PostedMessage, serenity::Error> {
+        let (category_channel, guild_channel) = channel;
+        let channel_id = ChannelId::new(channel_id);
+        let kind = guild_channel
+            .as_ref()
+            .and_then(|c| DiscordChannelKind::parse(&c.kind))
+            .unwrap_or_default();
+
+        if kind == DiscordChannelKind::Forum {
+            if let Some((reference, thread_id)) =
+                reference.and_then(|r| r.thread_id.map(|thread_id| (r, thread_id)))
+            {
+                let thread = ChannelId::new(thread_id);
+                let message = message.reference_message(MessageReference::from((
+                    thread,
+                    MessageId::new(reference.message_id),
+                )));
+                let msg =
+                    discord_call("send_message", thread.send_message(&self.http, message)).await?;
+
+                return Ok(PostedMessage {
+                    message_id: msg.id.get(),
+                    thread_id: Some(thread_id),
+                });
+            }
+
+            let title: String = title.chars().take(FORUM_POST_TITLE_LIMIT).collect();
+            let post = discord_call(
+                "create_forum_post",
+                channel_id.create_forum_post(&self.http, CreateForumPost::new(title, message)),
+            )
+            .await?;
+
+            // The starter message of a forum post shares the ID of its thread
+            return Ok(PostedMessage {
+                message_id: post.id.get(),
+                thread_id: Some(post.id.get()),
+            });
+        }
+
+        let message = match reference {
+            Some(reference) => message.reference_message(MessageReference::from((
+                channel_id,
+                MessageId::new(reference.message_id),
+            ))),
+            None => message,
+        };
+        let msg =
+            discord_call("send_message", channel_id.send_message(&self.http, message)).await?;
+
+        if kind == DiscordChannelKind::Announcement && category_channel.crosspost {
+            if let Err(e) = discord_call(
+                "crosspost_message",
+                self.http.crosspost_message(channel_id, msg.id),
+            )
+            .await
+            {
+                tracing::warn!(
+                    "Failed to crosspost message {} in channel {}: {}",
+                    msg.id,
+                    channel_id,
+                    e
+                );
             }
         }
 
-        Ok(content)
+        Ok(PostedMessage {
+            message_id: msg.id.get(),
+            thread_id: None,
+        })
     }
 }
+
+/// Parses the Discord IDs of the roles pinged for a category
+///
+/// # Returns
+/// - `Ok(Vec<u64>)` - Role IDs in the order they are configured
+/// - `Err(AppError::InternalError)` - Failed to parse role ID
+fn category_ping_role_ids(
+    category_data: &FleetCategoryWithRelations,
+) -> Result<Vec<u64>, AppError> {
+    category_data
+        .ping_roles
+        .iter()
+        .map(|(ping_role, _)| parse_u64_from_string(ping_role.role_id.clone()))
+        .collect()
+}
+
+/// Builds message content of a title followed by mentions of the given roles
+///
+/// # Arguments
+/// - `title` - Title to prepend to the content
+/// - `role_ids` - Discord IDs of the roles to ping
+/// - `guild_id` - Guild ID for @everyone detection
+fn build_role_ping_content(title: &str, role_ids: &[u64], guild_id: u64) -> String {
+    let mut content = format!("{}\n\n", title);
+    for &role_id in role_ids {
+        // @everyone role has the same ID as the guild - use @everyone instead of <@&guild_id>
+        if role_id == guild_id {
+            content.push_str("@everyone ");
+        } else {
+            content.push_str(&format!("<@&{}> ", role_id));
+        }
+    }
+
+    content
+}
@@ -5,15 +5,17 @@
 //! or as standalone messages if the fleet was initially hidden.
 
 use dioxus_logger::tracing;
-use serenity::all::{ChannelId, CreateEmbed, CreateMessage, MessageId, MessageReference};
+use serenity::all::{CreateEmbed, CreateMessage};
 
 use crate::server::{
     data::fleet_message::FleetMessageRepository,
     error::AppError,
+    metrics::metrics,
     model::{
         category::FleetCategoryWithRelations,
         fleet::Fleet,
         fleet_message::{CreateFleetMessageParam, FleetMessage},
+        guild_settings::EmbedKind,
     },
     util::parse::parse_u64_from_string,
 };
@@ -26,7 +28,7 @@ impl<'a> FleetNotificationService<'a> {
     /// Creates reminder notifications before fleet time to alert participants. If creation
     /// messages exist, replies to them. If the fleet was initially hidden (no creation
     /// messages), posts as new messages. Skips posting if `disable_reminder` is true.
-    /// Uses orange embed color (0xf39c12).
+    /// Uses the guild's reminder embed color.
     ///
     /// # Arguments
     /// - `fleet` - Fleet domain model containing event details
@@ -67,9 +69,10 @@ impl<'a> FleetNotificationService<'a> {
         let embed = self
             .build_fleet_embed_with_commander(
                 fleet,
+                &category_data,
                 &fields[..],
                 field_values,
-                0xf39c12, // Orange color for reminder
+                EmbedKind::Reminder,
                 guild_id,
             )
             .await?;
@@ -121,9 +124,8 @@ impl<'a> FleetNotificationService<'a> {
         content: &str,
         embed: &CreateEmbed,
     ) -> Result<(), AppError> {
-        for (channel, _) in &category_data.channels {
-            let channel_id_u64 = parse_u64_from_string(channel.channel_id.clone())?;
-            let channel_id = ChannelId::new(channel_id_u64);
+        for channel in &category_data.channels {
+            let channel_id_u64 = parse_u64_from_string(channel.0.channel_id.clone())?;
 
             // Find creation message for this channel if it exists
             let reference_msg = creation_messages
@@ -131,25 +133,24 @@ impl<'a> FleetNotificationService<'a> {
                 .filter(|m| m.channel_id == channel_id_u64)
                 .max_by_key(|m| &m.created_at);
 
-            let mut message = CreateMessage::new().content(content).embed(embed.clone());
+            let message = CreateMessage::new().content(content).embed(embed.clone());
 
             // If reference message exists, reply to it
-            if let Some(ref_msg) = reference_msg {
-                message = message.reference_message(MessageReference::from((
-                    channel_id,
-                    MessageId::new(ref_msg.message_id),
-                )));
-            }
+            match self
+                .send_channel_message(&fleet.name, channel_id_u64, channel, message, reference_msg)
+                .await
+            {
+                Ok(posted) => {
+                    metrics().record_notification("reminder");
 
-            match channel_id.send_message(&self.http, message).await {
-                Ok(msg) => {
                     // Store message in database
                     message_repo
                         .create(CreateFleetMessageParam {
                             fleet_id: fleet.id,
                             channel_id: channel_id_u64,
-                            message_id: msg.id.get(),
+                            message_id: posted.message_id,
                             message_type: "reminder".to_string(),
+                            thread_id: posted.thread_id,
                         })
                         .await?;
 
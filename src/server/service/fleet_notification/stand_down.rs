@@ -0,0 +1,154 @@
+//! Stand-down broadcast operations.
+//!
+//! This module provides functionality for broadcasting emergency stand-downs. A single
+//! message is posted to each channel of the affected categories listing the channel's
+//! cancelled fleets, rather than one message per fleet. Stand-downs ping the roles of
+//! the channel's categories, the notice that a stand-down was lifted is posted without
+//! pings as the restored fleets are announced with their own creation pings.
+
+use dioxus_logger::tracing;
+use serenity::all::CreateMessage;
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::server::{
+    error::AppError,
+    metrics::metrics,
+    model::{fleet::Fleet, stand_down::StandDown},
+    util::parse::parse_u64_from_string,
+};
+
+use super::{
+    build_role_ping_content,
+    builder::{self, StandDownNotice},
+    category_ping_role_ids, FleetNotificationService,
+};
+
+/// Broadcast of a stand-down to a single channel
+struct ChannelBroadcast<'f> {
+    /// Category channel configuration alongside the synced guild channel
+    channel: (
+        entity::fleet_category_channel::Model,
+        Option<entity::discord_guild_channel::Model>,
+    ),
+    /// Fleets of the channel's categories listed in the broadcast
+    fleets: Vec<&'f Fleet>,
+    /// Discord IDs of the ping roles of the channel's categories
+    role_ids: Vec<u64>,
+}
+
+impl<'a> FleetNotificationService<'a> {
+    /// Broadcasts a called or lifted stand-down to the channels of the affected fleets.
+    ///
+    /// Posts one message to every channel configured for the categories of the given
+    /// fleets. Channels shared by several categories receive a single message listing the
+    /// fleets of all of them. Continues posting to remaining channels even if individual
+    /// posts fail.
+    ///
+    /// # Arguments
+    /// - `notice` - Whether the stand-down was called or lifted
+    /// - `stand_down` - The stand-down being broadcast
+    /// - `fleets` - Fleets cancelled or restored by the stand-down
+    /// - `user_id` - Discord ID of the user who called or lifted the stand-down
+    ///
+    /// # Returns
+    /// - `Ok(())` - Successfully posted to all channels (or failed gracefully)
+    /// - `Err(AppError::NotFound)` - A fleet category was not found
+    /// - `Err(AppError::InternalError)` - Invalid ID format or timestamp
+    /// - `Err(AppError::Database)` - Database error retrieving categories or settings
+    pub async fn post_stand_down(
+        &self,
+        notice: StandDownNotice,
+        stand_down: &StandDown,
+        fleets: &[Fleet],
+        user_id: u64,
+    ) -> Result<(), AppError> {
+        let guild_id = stand_down.guild_id;
+        let settings = self.get_guild_settings(guild_id).await?;
+
+        let mut broadcasts: BTreeMap<u64, ChannelBroadcast> = BTreeMap::new();
+        let category_ids: BTreeSet<i32> = fleets.iter().map(|fleet| fleet.category_id).collect();
+        for category_id in category_ids {
+            let (category_data, _) = self.get_category_data_with_guild_id(category_id).await?;
+
+            let role_ids = category_ping_role_ids(&category_data)?;
+
+            for channel in &category_data.channels {
+                let channel_id = parse_u64_from_string(channel.0.channel_id.clone())?;
+                let broadcast = broadcasts
+                    .entry(channel_id)
+                    .or_insert_with(|| ChannelBroadcast {
+                        channel: channel.clone(),
+                        fleets: Vec::new(),
+                        role_ids: Vec::new(),
+                    });
+
+                broadcast.fleets.extend(
+                    fleets
+                        .iter()
+                        .filter(|fleet| fleet.category_id == category_id && !fleet.hidden),
+                );
+                for role_id in &role_ids {
+                    if !broadcast.role_ids.contains(role_id) {
+                        broadcast.role_ids.push(*role_id);
+                    }
+                }
+            }
+        }
+
+        let (title, forum_title) = match notice {
+            StandDownNotice::Called => (
+                "**.:STAND DOWN:.**",
+                format!("Stand down: {}", stand_down.reason),
+            ),
+            StandDownNotice::Lifted => (
+                "**.:Stand Down Lifted:.**",
+                format!("Stand down lifted: {}", stand_down.reason),
+            ),
+        };
+
+        for (channel_id, mut broadcast) in broadcasts {
+            broadcast.fleets.sort_by_key(|fleet| fleet.fleet_time);
+
+            let embed = builder::build_stand_down_embed(
+                notice,
+                &stand_down.reason,
+                &broadcast.fleets,
+                user_id,
+                &settings,
+                &self.app_url,
+            )?;
+            let role_ids = match notice {
+                StandDownNotice::Called => broadcast.role_ids.as_slice(),
+                StandDownNotice::Lifted => &[],
+            };
+            let content = build_role_ping_content(title, role_ids, guild_id);
+            let message = CreateMessage::new().content(content).embed(embed);
+
+            match self
+                .send_channel_message(&forum_title, channel_id, &broadcast.channel, message, None)
+                .await
+            {
+                Ok(_) => {
+                    metrics().record_notification("stand_down");
+
+                    tracing::info!(
+                        "Posted stand-down {} to channel {}",
+                        stand_down.id,
+                        channel_id
+                    );
+                }
+                Err(e) => {
+                    tracing::error!(
+                        "Failed to post stand-down {} to channel {}: {}",
+                        stand_down.id,
+                        channel_id,
+                        e
+                    );
+                    // Continue posting to other channels even if one fails
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -4,22 +4,23 @@
 //! It handles editing Discord messages with new fleet information.
 
 use dioxus_logger::tracing;
-use serenity::all::{ChannelId, CreateEmbed, EditMessage, MessageId};
+use serenity::all::{ChannelId, CreateEmbed, EditMessage, EditThread, MessageId};
 
 use crate::server::{
     data::fleet_message::FleetMessageRepository,
     error::AppError,
-    model::{fleet::Fleet, fleet_message::FleetMessage},
+    metrics::{discord_call, metrics},
+    model::{fleet::Fleet, fleet_message::FleetMessage, guild_settings::EmbedKind},
 };
 
-use super::FleetNotificationService;
+use super::{FleetNotificationService, FORUM_POST_TITLE_LIMIT};
 
 impl<'a> FleetNotificationService<'a> {
     /// Updates all existing fleet messages with new fleet information.
     ///
     /// Edits all Discord messages associated with the fleet to reflect updated details.
-    /// Continues updating remaining messages even if individual updates fail. Uses blue
-    /// embed color (0x3498db) for updates. Logs errors for failed updates but doesn't
+    /// Continues updating remaining messages even if individual updates fail. Uses the
+    /// guild's update embed color. Logs errors for failed updates but doesn't
     /// propagate them to allow partial success.
     ///
     /// # Arguments
@@ -56,18 +57,29 @@ impl<'a> FleetNotificationService<'a> {
             .get_ping_format_fields(&category_data, guild_id)
             .await?;
 
-        // Build updated embed with commander name (use blue color for updates)
+        // Build updated embed with commander name
         let embed = self
-            .build_fleet_embed_with_commander(fleet, &fields[..], field_values, 0x3498db, guild_id)
+            .build_fleet_embed_with_commander(
+                fleet,
+                &category_data,
+                &fields[..],
+                field_values,
+                EmbedKind::Update,
+                guild_id,
+            )
             .await?;
 
         // Update each message
-        self.update_existing_messages(&messages, &embed).await
+        self.update_existing_messages(fleet, &messages, &embed)
+            .await
     }
 
     /// Updates existing fleet messages with new embed.
     ///
+    /// Forum posts started by a fleet message are renamed to the current fleet name.
+    ///
     /// # Arguments
+    /// - `fleet` - Updated fleet data
     /// - `messages` - Existing fleet messages to update
     /// - `embed` - New fleet embed to set
     ///
@@ -75,22 +87,32 @@ impl<'a> FleetNotificationService<'a> {
     /// - `Ok(())` - Successfully updated all messages (or failed gracefully)
     async fn update_existing_messages(
         &self,
+        fleet: &Fleet,
         messages: &[FleetMessage],
         embed: &CreateEmbed,
     ) -> Result<(), AppError> {
         for message in messages {
-            let channel_id = ChannelId::new(message.channel_id);
+            let channel_id = ChannelId::new(message.message_channel_id());
             let msg_id = MessageId::new(message.message_id);
 
             let edit_builder = EditMessage::new().embed(embed.clone());
 
-            match self
-                .http
-                .edit_message(channel_id, msg_id, &edit_builder, vec![])
-                .await
+            match discord_call(
+                "edit_message",
+                self.http
+                    .edit_message(channel_id, msg_id, &edit_builder, vec![]),
+            )
+            .await
             {
                 Ok(_) => {
+                    metrics().record_notification("update");
+
                     tracing::info!("Updated fleet message {} in channel {}", msg_id, channel_id);
+
+                    // The starter message of a forum post shares the ID of its thread
+                    if message.thread_id == Some(message.message_id) {
+                        self.rename_forum_post(fleet, channel_id).await;
+                    }
                 }
                 Err(e) => {
                     tracing::error!(
@@ -106,4 +128,24 @@ impl<'a> FleetNotificationService<'a> {
 
         Ok(())
     }
+
+    /// Renames a fleet's forum post to the current fleet name.
+    ///
+    /// Failures are logged without failing the update.
+    ///
+    /// # Arguments
+    /// - `fleet` - Updated fleet data
+    /// - `thread_id` - Forum post thread to rename
+    async fn rename_forum_post(&self, fleet: &Fleet, thread_id: ChannelId) {
+        let title: String = fleet.name.chars().take(FORUM_POST_TITLE_LIMIT).collect();
+
+        if let Err(e) = discord_call(
+            "edit_thread",
+            thread_id.edit_thread(&self.http, EditThread::new().name(title)),
+        )
+        .await
+        {
+            tracing::error!("Failed to rename forum post {}: {}", thread_id, e);
+        }
+    }
 }
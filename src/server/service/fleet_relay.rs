@@ -0,0 +1,269 @@
+//! Fleet relay service for linking categories across guilds.
+//!
+//! This module provides the `FleetRelayService` for managing relay links between a
+//! source guild's category and another guild hosted on the same instance. Relays are
+//! requested by the source guild's admins and only forward fleets once the target
+//! guild's admins accepted them, choosing the category relayed fleets are posted in.
+//! Copying fleets along a relay is handled by the `FleetService`.
+
+use sea_orm::DatabaseConnection;
+
+use crate::{
+    model::{
+        discord::DiscordGuildStatus,
+        fleet_relay::{FleetRelayDto, FleetRelayStatus, FleetRelaysDto},
+    },
+    server::{
+        data::{
+            category::FleetCategoryRepository, discord::DiscordGuildRepository,
+            fleet_relay::FleetRelayRepository,
+        },
+        error::AppError,
+        model::fleet_relay::{CreateFleetRelayParam, FleetRelay},
+        util::parse::parse_u64_from_string,
+    },
+};
+
+/// Maximum amount of relays a single category can forward fleets to
+const RELAY_LIMIT_PER_CATEGORY: usize = 10;
+
+pub struct FleetRelayService<'a> {
+    db: &'a DatabaseConnection,
+}
+
+impl<'a> FleetRelayService<'a> {
+    pub fn new(db: &'a DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// Gets the outgoing & incoming relays of a guild
+    ///
+    /// # Returns
+    /// - `Ok(FleetRelaysDto)` - Relays of the guild with guild & category names
+    /// - `Err(AppError::Database)` - Database error during fetch operation
+    pub async fn list_by_guild(&self, guild_id: u64) -> Result<FleetRelaysDto, AppError> {
+        let repo = FleetRelayRepository::new(self.db);
+
+        let mut outgoing = Vec::new();
+        for relay in repo.get_outgoing_by_guild(guild_id).await? {
+            outgoing.push(self.build_dto(relay).await?);
+        }
+
+        let mut incoming = Vec::new();
+        for relay in repo.get_incoming_by_guild(guild_id).await? {
+            incoming.push(self.build_dto(relay).await?);
+        }
+
+        Ok(FleetRelaysDto { outgoing, incoming })
+    }
+
+    /// Requests a relay of a category's fleets to another guild
+    ///
+    /// The relay stays pending until the target guild's admins accept it.
+    ///
+    /// # Arguments
+    /// - `guild_id` - ID of the guild requesting the relay
+    /// - `param` - Category of the guild to relay & the target guild
+    ///
+    /// # Returns
+    /// - `Ok(FleetRelayDto)` - The pending relay
+    /// - `Err(AppError::NotFound)` - Category not in guild or target guild not found
+    /// - `Err(AppError::BadRequest)` - Target is the same or an inactive guild, relay
+    ///   already exists or category relay limit reached
+    /// - `Err(AppError::Database)` - Database error during insert operation
+    pub async fn create(
+        &self,
+        guild_id: u64,
+        param: CreateFleetRelayParam,
+    ) -> Result<FleetRelayDto, AppError> {
+        let repo = FleetRelayRepository::new(self.db);
+
+        if !FleetCategoryRepository::new(self.db)
+            .exists_in_guild(param.source_category_id, guild_id)
+            .await?
+        {
+            return Err(AppError::NotFound("Category not found".to_string()));
+        }
+
+        if param.target_guild_id == guild_id {
+            return Err(AppError::BadRequest(
+                "Fleets can't be relayed to the same Discord server".to_string(),
+            ));
+        }
+
+        let Some(target_guild) = DiscordGuildRepository::new(self.db)
+            .find_by_guild_id(param.target_guild_id)
+            .await?
+        else {
+            return Err(AppError::NotFound(
+                "Target Discord server not found".to_string(),
+            ));
+        };
+
+        if target_guild.status != DiscordGuildStatus::Active {
+            return Err(AppError::BadRequest(
+                "Fleets can only be relayed to Discord servers the bot is active in".to_string(),
+            ));
+        }
+
+        if repo
+            .find_by_source_and_target(param.source_category_id, param.target_guild_id)
+            .await?
+            .is_some()
+        {
+            return Err(AppError::BadRequest(
+                "This category is already relayed to that Discord server".to_string(),
+            ));
+        }
+
+        let count = repo
+            .get_outgoing_by_guild(guild_id)
+            .await?
+            .into_iter()
+            .filter(|relay| relay.source_category_id == param.source_category_id)
+            .count();
+        if count >= RELAY_LIMIT_PER_CATEGORY {
+            return Err(AppError::BadRequest(format!(
+                "Maximum relay limit ({}) reached for this category",
+                RELAY_LIMIT_PER_CATEGORY
+            )));
+        }
+
+        let relay = repo.create(param).await?;
+
+        self.build_dto(relay).await
+    }
+
+    /// Accepts an incoming relay, posting relayed fleets in a category of the guild
+    ///
+    /// Accepting an already accepted relay moves future relayed fleets to the new
+    /// category. Only fleets created after accepting are relayed.
+    ///
+    /// # Arguments
+    /// - `guild_id` - ID of the target guild answering the request
+    /// - `id` - ID of the relay
+    /// - `target_category_id` - Category of the guild relayed fleets are posted in
+    ///
+    /// # Returns
+    /// - `Ok(FleetRelayDto)` - The accepted relay
+    /// - `Err(AppError::NotFound)` - Relay not incoming to guild or category not in guild
+    /// - `Err(AppError::Database)` - Database error during update operation
+    pub async fn accept(
+        &self,
+        guild_id: u64,
+        id: i32,
+        target_category_id: i32,
+    ) -> Result<FleetRelayDto, AppError> {
+        self.require_incoming(guild_id, id).await?;
+
+        if !FleetCategoryRepository::new(self.db)
+            .exists_in_guild(target_category_id, guild_id)
+            .await?
+        {
+            return Err(AppError::NotFound("Category not found".to_string()));
+        }
+
+        let relay = FleetRelayRepository::new(self.db)
+            .update_status(id, FleetRelayStatus::Accepted, Some(target_category_id))
+            .await?;
+
+        self.build_dto(relay).await
+    }
+
+    /// Declines an incoming relay, stopping any further fleets from being relayed
+    ///
+    /// # Returns
+    /// - `Ok(FleetRelayDto)` - The declined relay
+    /// - `Err(AppError::NotFound)` - Relay not incoming to guild
+    /// - `Err(AppError::Database)` - Database error during update operation
+    pub async fn decline(&self, guild_id: u64, id: i32) -> Result<FleetRelayDto, AppError> {
+        self.require_incoming(guild_id, id).await?;
+
+        let relay = FleetRelayRepository::new(self.db)
+            .update_status(id, FleetRelayStatus::Declined, None)
+            .await?;
+
+        self.build_dto(relay).await
+    }
+
+    /// Removes a relay, either guild of the relay can remove it
+    ///
+    /// Fleets already relayed are kept in the target guild as regular fleets.
+    ///
+    /// # Returns
+    /// - `Ok(())` - Relay was removed
+    /// - `Err(AppError::NotFound)` - Relay doesn't involve the guild
+    /// - `Err(AppError::Database)` - Database error during delete operation
+    pub async fn delete(&self, guild_id: u64, id: i32) -> Result<(), AppError> {
+        let repo = FleetRelayRepository::new(self.db);
+
+        let Some(relay) = repo.find_by_id(id).await? else {
+            return Err(AppError::NotFound("Fleet relay not found".to_string()));
+        };
+
+        let is_source = FleetCategoryRepository::new(self.db)
+            .exists_in_guild(relay.source_category_id, guild_id)
+            .await?;
+        if !is_source && relay.target_guild_id != guild_id {
+            return Err(AppError::NotFound("Fleet relay not found".to_string()));
+        }
+
+        repo.delete(id).await
+    }
+
+    /// Ensures a relay exists & was requested to forward fleets to the guild
+    async fn require_incoming(&self, guild_id: u64, id: i32) -> Result<FleetRelay, AppError> {
+        match FleetRelayRepository::new(self.db).find_by_id(id).await? {
+            Some(relay) if relay.target_guild_id == guild_id => Ok(relay),
+            _ => Err(AppError::NotFound("Fleet relay not found".to_string())),
+        }
+    }
+
+    /// Builds the relay DTO with the names of both guilds & categories
+    async fn build_dto(&self, relay: FleetRelay) -> Result<FleetRelayDto, AppError> {
+        let category_repo = FleetCategoryRepository::new(self.db);
+        let guild_repo = DiscordGuildRepository::new(self.db);
+
+        let Some(source_category) = category_repo
+            .find_by_id(relay.source_category_id)
+            .await?
+            .map(|c| c.category)
+        else {
+            return Err(AppError::NotFound("Category not found".to_string()));
+        };
+        let source_guild_id = parse_u64_from_string(source_category.guild_id)?;
+
+        let source_guild_name = guild_repo
+            .find_by_guild_id(source_guild_id)
+            .await?
+            .map(|guild| guild.name)
+            .unwrap_or_default();
+        let target_guild_name = guild_repo
+            .find_by_guild_id(relay.target_guild_id)
+            .await?
+            .map(|guild| guild.name)
+            .unwrap_or_default();
+
+        let target_category_name = match relay.target_category_id {
+            Some(category_id) => category_repo
+                .find_by_id(category_id)
+                .await?
+                .map(|c| c.category.name),
+            None => None,
+        };
+
+        Ok(FleetRelayDto {
+            id: relay.id,
+            source_guild_id,
+            source_guild_name,
+            source_category_id: relay.source_category_id,
+            source_category_name: source_category.name,
+            target_guild_id: relay.target_guild_id,
+            target_guild_name,
+            target_category_id: relay.target_category_id,
+            target_category_name,
+            status: relay.status,
+            created_at: relay.created_at,
+        })
+    }
+}
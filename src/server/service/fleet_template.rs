@@ -0,0 +1,289 @@
+//! Fleet template service for prefilling the fleet creation form.
+//!
+//! This module provides the `FleetTemplateService` for saving, listing & deleting fleet
+//! templates. Users can use their own templates & the shared templates of the categories
+//! they can create fleets in. Sharing a template with a category takes manage permission
+//! for it, the same permission needed to delete a template saved by someone else.
+
+use std::collections::HashSet;
+
+use sea_orm::DatabaseConnection;
+
+use crate::{
+    model::fleet_template::FleetTemplateDto,
+    server::{
+        data::{
+            category::FleetCategoryRepository, fleet_template::FleetTemplateRepository,
+            ping_format::field::PingFormatFieldRepository,
+            user_category_permission::UserCategoryPermissionRepository,
+        },
+        error::{auth::AuthError, AppError},
+        model::fleet_template::{CreateFleetTemplateParam, FleetTemplate},
+        util::parse::parse_u64_from_string,
+    },
+};
+
+/// Maximum amount of templates a user can save per fleet category
+const TEMPLATE_LIMIT_PER_CATEGORY: usize = 25;
+
+/// Maximum length of a template name
+const MAX_NAME_LENGTH: usize = 100;
+
+pub struct FleetTemplateService<'a> {
+    db: &'a DatabaseConnection,
+}
+
+impl<'a> FleetTemplateService<'a> {
+    pub fn new(db: &'a DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// Saves a new fleet template
+    ///
+    /// Field values of fields outside the category's ping format & empty values are
+    /// dropped, as the form's values are saved as they are when switching categories.
+    ///
+    /// # Arguments
+    /// - `guild_id` - Discord ID of the guild the template's category belongs to
+    /// - `param` - The template to save
+    /// - `is_admin` - Whether the owner is an admin
+    ///
+    /// # Returns
+    /// - `Ok(FleetTemplateDto)` - The saved template
+    /// - `Err(AppError::BadRequest(_))` - Invalid name or template limit reached
+    /// - `Err(AppError::NotFound(_))` - Category not found in the guild
+    /// - `Err(AppError::AuthErr(AccessDenied))` - Sharing without manage permission
+    /// - `Err(AppError::Database(_))` - Database operation failed
+    pub async fn create(
+        &self,
+        guild_id: u64,
+        mut param: CreateFleetTemplateParam,
+        is_admin: bool,
+    ) -> Result<FleetTemplateDto, AppError> {
+        validate_name(&param.name)?;
+
+        let category = FleetCategoryRepository::new(self.db)
+            .find_by_id(param.category_id)
+            .await?
+            .filter(|category| {
+                parse_u64_from_string(category.category.guild_id.clone()).ok() == Some(guild_id)
+            })
+            .ok_or_else(|| AppError::NotFound("Category not found".to_string()))?;
+
+        if param.shared && !is_admin {
+            let can_manage = UserCategoryPermissionRepository::new(self.db)
+                .user_can_manage_category(param.owner_id, param.category_id)
+                .await?;
+            if !can_manage {
+                return Err(AppError::AuthErr(AuthError::AccessDenied(
+                    param.owner_id,
+                    "Sharing a template requires manage permission for the category".to_string(),
+                )));
+            }
+        }
+
+        let repo = FleetTemplateRepository::new(self.db);
+
+        let count = repo
+            .count_by_owner_and_category(param.owner_id, param.category_id)
+            .await?;
+        if count >= TEMPLATE_LIMIT_PER_CATEGORY {
+            return Err(AppError::BadRequest(format!(
+                "Maximum template limit ({}) reached for this category",
+                TEMPLATE_LIMIT_PER_CATEGORY
+            )));
+        }
+
+        let field_ids: HashSet<i32> = PingFormatFieldRepository::new(self.db)
+            .get_by_ping_format_id(guild_id, category.category.ping_format_id)
+            .await?
+            .into_iter()
+            .map(|field| field.id)
+            .collect();
+        param
+            .field_values
+            .retain(|field_id, value| field_ids.contains(field_id) && !value.trim().is_empty());
+
+        // Whoever saves a template can delete it
+        Ok(repo.create(param).await?.into_dto(true))
+    }
+
+    /// Gets the templates of a guild a user can use, ordered by name
+    ///
+    /// # Arguments
+    /// - `guild_id` - Discord ID of the guild
+    /// - `user_id` - Discord ID of the user
+    /// - `is_admin` - Whether the user is an admin, admins can use & delete every
+    ///   shared template
+    ///
+    /// # Returns
+    /// - `Ok(Vec<FleetTemplateDto>)` - The user's own templates & the shared templates of
+    ///   categories they can create fleets in
+    /// - `Err(AppError::Database(_))` - Database operation failed
+    pub async fn get_usable(
+        &self,
+        guild_id: u64,
+        user_id: u64,
+        is_admin: bool,
+    ) -> Result<Vec<FleetTemplateDto>, AppError> {
+        let repo = FleetTemplateRepository::new(self.db);
+
+        if is_admin {
+            return Ok(repo
+                .get_usable_by_user(guild_id, user_id, None)
+                .await?
+                .into_iter()
+                .map(|template| template.into_dto(true))
+                .collect());
+        }
+
+        let permission_repo = UserCategoryPermissionRepository::new(self.db);
+        let manage_ids: HashSet<i32> = permission_repo
+            .get_manageable_category_ids_by_user(user_id, guild_id)
+            .await?
+            .into_iter()
+            .collect();
+        let mut usable_ids: HashSet<i32> = permission_repo
+            .get_creatable_category_ids_by_user(user_id, guild_id)
+            .await?
+            .into_iter()
+            .collect();
+        usable_ids.extend(manage_ids.iter().copied());
+
+        Ok(repo
+            .get_usable_by_user(guild_id, user_id, Some(usable_ids.into_iter().collect()))
+            .await?
+            .into_iter()
+            .map(|template| {
+                let can_delete = can_delete(&template, user_id, &manage_ids);
+                template.into_dto(can_delete)
+            })
+            .collect())
+    }
+
+    /// Deletes a template
+    ///
+    /// # Arguments
+    /// - `guild_id` - Discord ID of the guild the template's category belongs to
+    /// - `id` - ID of the template to delete
+    /// - `user_id` - Discord ID of the user deleting the template
+    /// - `is_admin` - Whether the user is an admin
+    ///
+    /// # Returns
+    /// - `Ok(())` - The template was deleted
+    /// - `Err(AppError::NotFound(_))` - Template not found in the guild or not usable by
+    ///   the user
+    /// - `Err(AppError::AuthErr(AccessDenied))` - Shared template of another user without
+    ///   manage permission for its category
+    /// - `Err(AppError::Database(_))` - Database operation failed
+    pub async fn delete(
+        &self,
+        guild_id: u64,
+        id: i32,
+        user_id: u64,
+        is_admin: bool,
+    ) -> Result<(), AppError> {
+        let repo = FleetTemplateRepository::new(self.db);
+
+        // Private templates of other users aren't revealed to exist
+        let template = repo
+            .find_by_id(guild_id, id)
+            .await?
+            .filter(|template| is_admin || template.shared || template.owner_id == user_id)
+            .ok_or_else(|| AppError::NotFound("Template not found".to_string()))?;
+
+        if !is_admin && template.owner_id != user_id {
+            let can_manage = UserCategoryPermissionRepository::new(self.db)
+                .user_can_manage_category(user_id, template.category_id)
+                .await?;
+            if !can_manage {
+                return Err(AppError::AuthErr(AuthError::AccessDenied(
+                    user_id,
+                    "Deleting another user's template requires manage permission for the category"
+                        .to_string(),
+                )));
+            }
+        }
+
+        repo.delete(id).await
+    }
+}
+
+/// Returns whether a non-admin user can delete a template they can use
+///
+/// # Arguments
+/// - `template` - The template
+/// - `user_id` - Discord ID of the user
+/// - `manage_ids` - Categories the user can manage
+fn can_delete(template: &FleetTemplate, user_id: u64, manage_ids: &HashSet<i32>) -> bool {
+    template.owner_id == user_id || (template.shared && manage_ids.contains(&template.category_id))
+}
+
+fn validate_name(name: &str) -> Result<(), AppError> {
+    if name.is_empty() {
+        return Err(AppError::BadRequest(
+            "Template name is required".to_string(),
+        ));
+    }
+
+    if name.chars().count() > MAX_NAME_LENGTH {
+        return Err(AppError::BadRequest(format!(
+            "Template name can't be longer than {} characters",
+            MAX_NAME_LENGTH
+        )));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use std::collections::HashMap;
+
+    fn template(owner_id: u64, shared: bool) -> FleetTemplate {
+        FleetTemplate {
+            id: 1,
+            category_id: 7,
+            owner_id,
+            name: "Sunday strat op".to_string(),
+            shared,
+            fleet_name: "Strat op".to_string(),
+            commander_id: None,
+            description: None,
+            field_values: HashMap::new(),
+            hidden: false,
+            disable_reminder: false,
+            created_at: Utc::now(),
+        }
+    }
+
+    /// Tests who can delete a template.
+    ///
+    /// Expected: the owner always, other users only for shared templates of categories
+    /// they manage
+    #[test]
+    fn only_owner_or_manager_can_delete() {
+        let managed = HashSet::from([7]);
+        let unmanaged = HashSet::new();
+
+        assert!(can_delete(&template(1, false), 1, &unmanaged));
+        assert!(can_delete(&template(1, true), 2, &managed));
+        assert!(!can_delete(&template(1, true), 2, &unmanaged));
+        assert!(!can_delete(&template(1, false), 2, &managed));
+    }
+
+    /// Tests validating template names.
+    ///
+    /// Expected: Err(AppError::BadRequest) for an empty & an overly long name
+    #[test]
+    fn rejects_empty_or_long_name() {
+        assert!(validate_name("Sunday strat op").is_ok());
+        assert!(matches!(validate_name(""), Err(AppError::BadRequest(_))));
+        assert!(matches!(
+            validate_name(&"a".repeat(MAX_NAME_LENGTH + 1)),
+            Err(AppError::BadRequest(_))
+        ));
+    }
+}
@@ -0,0 +1,160 @@
+//! Guild settings service for per-guild display & behaviour defaults.
+//!
+//! This module provides the `GuildSettingsService` for reading and validating the
+//! settings admins configure for a guild. The settings are read by the Discord
+//! notification code through the `GuildSettingsRepository`.
+
+use sea_orm::DatabaseConnection;
+use std::str::FromStr;
+
+use crate::{
+    model::guild_settings::GuildSettingsDto,
+    server::{
+        data::{
+            discord::DiscordGuildRoleRepository, guild_settings::GuildSettingsRepository,
+            ping_format::PingFormatRepository,
+        },
+        error::AppError,
+        model::guild_settings::{
+            GuildSettings, UpsertGuildSettingsParam, MAX_DOWNTIME_MINUTES, MAX_EMBED_COLOR,
+            MAX_EXTRA_TIMEZONES, MAX_FORMUP_CATCHUP_MINUTES, MAX_LIST_LOOKAHEAD_HOURS,
+            MAX_TIME_LABEL_LENGTH,
+        },
+    },
+};
+
+pub struct GuildSettingsService<'a> {
+    db: &'a DatabaseConnection,
+}
+
+impl<'a> GuildSettingsService<'a> {
+    pub fn new(db: &'a DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// Gets the settings of a guild, falling back to the defaults
+    pub async fn get(&self, guild_id: u64) -> Result<GuildSettings, AppError> {
+        GuildSettingsRepository::new(self.db)
+            .get_by_guild(guild_id)
+            .await
+    }
+
+    /// Updates the settings of a guild
+    ///
+    /// # Arguments
+    /// - `guild_id` - Discord ID of the guild
+    /// - `dto` - The new settings
+    ///
+    /// # Returns
+    /// - `Ok(GuildSettings)` - The stored settings
+    /// - `Err(AppError::BadRequest(_))` - A setting is outside of its allowed range or an
+    ///   extra timezone or the staging system is unknown
+    /// - `Err(AppError::NotFound(_))` - Default ping format or a broadcast role not found
+    ///   in the guild
+    /// - `Err(AppError::Database(_))` - Database operation failed
+    pub async fn update(
+        &self,
+        guild_id: u64,
+        dto: GuildSettingsDto,
+    ) -> Result<GuildSettings, AppError> {
+        let param = UpsertGuildSettingsParam::from_dto(guild_id, dto)?;
+
+        let colors = [
+            param.creation_color,
+            param.update_color,
+            param.reminder_color,
+            param.formup_color,
+            param.cancel_color,
+            param.list_color,
+        ];
+        if colors.iter().any(|color| *color > MAX_EMBED_COLOR) {
+            return Err(AppError::BadRequest(
+                "Embed colours must be between #000000 and #FFFFFF".to_string(),
+            ));
+        }
+
+        if param.time_label.chars().count() > MAX_TIME_LABEL_LENGTH {
+            return Err(AppError::BadRequest(format!(
+                "Time label can't be longer than {} characters",
+                MAX_TIME_LABEL_LENGTH
+            )));
+        }
+
+        if param.extra_timezones.len() > MAX_EXTRA_TIMEZONES {
+            return Err(AppError::BadRequest(format!(
+                "At most {} extra timezones can be shown",
+                MAX_EXTRA_TIMEZONES
+            )));
+        }
+        for (index, timezone) in param.extra_timezones.iter().enumerate() {
+            if chrono_tz::Tz::from_str(timezone).is_err() {
+                return Err(AppError::BadRequest(format!(
+                    "Unknown timezone: {}",
+                    timezone
+                )));
+            }
+            if param.extra_timezones[..index].contains(timezone) {
+                return Err(AppError::BadRequest(format!(
+                    "Timezone {} is listed more than once",
+                    timezone
+                )));
+            }
+        }
+
+        if let Some(hours) = param.list_lookahead_hours {
+            if !(1..=MAX_LIST_LOOKAHEAD_HOURS).contains(&hours) {
+                return Err(AppError::BadRequest(format!(
+                    "List lookahead must be between 1 and {} hours",
+                    MAX_LIST_LOOKAHEAD_HOURS
+                )));
+            }
+        }
+
+        if !(1..=MAX_FORMUP_CATCHUP_MINUTES).contains(&param.formup_catchup_minutes) {
+            return Err(AppError::BadRequest(format!(
+                "Form-up catch-up window must be between 1 and {} minutes",
+                MAX_FORMUP_CATCHUP_MINUTES
+            )));
+        }
+
+        if param.downtime.start_minute >= 24 * 60 {
+            return Err(AppError::BadRequest(
+                "Downtime must start between 00:00 and 23:59 UTC".to_string(),
+            ));
+        }
+        if !(1..=MAX_DOWNTIME_MINUTES).contains(&param.downtime.duration_minutes) {
+            return Err(AppError::BadRequest(format!(
+                "Downtime must last between 1 and {} minutes",
+                MAX_DOWNTIME_MINUTES
+            )));
+        }
+
+        if let Some(ping_format_id) = param.default_ping_format_id {
+            if !PingFormatRepository::new(self.db)
+                .exists_in_guild(ping_format_id, guild_id)
+                .await?
+            {
+                return Err(AppError::NotFound("Ping format not found".to_string()));
+            }
+        }
+
+        if !param.broadcast_role_ids.is_empty() {
+            let guild_roles = DiscordGuildRoleRepository::new(self.db)
+                .get_by_guild_id(guild_id)
+                .await?;
+            for (index, role_id) in param.broadcast_role_ids.iter().enumerate() {
+                if !guild_roles.iter().any(|role| role.role_id == *role_id) {
+                    return Err(AppError::NotFound(format!("Role {} not found", role_id)));
+                }
+                if param.broadcast_role_ids[..index].contains(role_id) {
+                    return Err(AppError::BadRequest(format!(
+                        "Role {} is listed more than once",
+                        role_id
+                    )));
+                }
+            }
+        }
+
+        GuildSettingsRepository::new(self.db).upsert(param).await
+    }
+}
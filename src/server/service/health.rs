@@ -0,0 +1,69 @@
+//! Health service for probes and metrics.
+//!
+//! This module provides the `HealthService` which checks the state of the application's
+//! dependencies for the `/health` and `/ready` probes, and renders the global metrics
+//! registry together with guild sync lag read from the database for `/metrics`.
+
+use chrono::Utc;
+use sea_orm::DatabaseConnection;
+
+use crate::{
+    model::discord::DiscordGuildStatus,
+    server::{
+        data::discord::guild::DiscordGuildRepository, error::AppError, metrics::metrics,
+        model::health::Health,
+    },
+};
+
+/// Service providing probe checks and metrics rendering.
+pub struct HealthService<'a> {
+    db: &'a DatabaseConnection,
+}
+
+impl<'a> HealthService<'a> {
+    /// Creates a new HealthService instance.
+    ///
+    /// # Arguments
+    /// - `db` - Reference to the database connection
+    ///
+    /// # Returns
+    /// - `HealthService` - New service instance
+    pub fn new(db: &'a DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// Checks the database, Discord gateway & scheduler.
+    ///
+    /// A failed database ping is reported in the result rather than returned as an
+    /// error so probes can respond with the state of every dependency.
+    ///
+    /// # Returns
+    /// - `Health` - State of the application's dependencies
+    pub async fn check(&self) -> Health {
+        let metrics = metrics();
+
+        Health {
+            database: self.db.ping().await.is_ok(),
+            discord_gateway: metrics.gateway_connected(),
+            scheduler_last_tick: metrics.scheduler_last_tick(),
+            checked_at: Utc::now(),
+        }
+    }
+
+    /// Renders all metrics in the Prometheus text exposition format.
+    ///
+    /// # Returns
+    /// - `Ok(String)` - Prometheus text exposition including guild sync lag of synced guilds
+    /// - `Err(AppError::Database)` - Database error while fetching guilds
+    pub async fn render_metrics(&self) -> Result<String, AppError> {
+        // Guilds the bot was removed from are no longer synced
+        let guilds: Vec<_> = DiscordGuildRepository::new(self.db)
+            .get_all()
+            .await?
+            .into_iter()
+            .filter(|g| g.status != DiscordGuildStatus::Removed)
+            .collect();
+
+        Ok(metrics().render(&guilds, Utc::now()))
+    }
+}
@@ -10,10 +10,23 @@
 
 pub mod admin;
 pub mod auth;
+pub mod broadcast;
 pub mod category;
+pub mod channel_list_settings;
 pub mod discord;
+pub mod doctrine;
 pub mod fleet;
+pub mod fleet_event;
 pub mod fleet_notification;
+pub mod fleet_relay;
+pub mod fleet_template;
+pub mod guild_settings;
+pub mod health;
+pub mod paste;
 pub mod ping_format;
 pub mod ping_group;
+pub mod retention;
+pub mod stand_down;
+pub mod subscription;
 pub mod user;
+pub mod webhook;
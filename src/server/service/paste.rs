@@ -0,0 +1,950 @@
+//! Paste-to-parse ingestion of EVE notifications & Discord text pings.
+//!
+//! FCs paste a structure notification copied from the game client, or a ping written
+//! for another Discord, into the fleet creation modal. The paste is parsed into a
+//! prefilled fleet that is returned for review rather than created right away, as the
+//! text is free form and only parsed on a best effort basis:
+//!
+//! - **Solar system** - the value of a `System:` line or the first known solar system
+//!   named in the text, looked up in the bundled EVE static map
+//! - **Structure** - the first known structure type named in the text, followed by its
+//!   quoted name if any
+//! - **Timer end** - an EVE time such as `2026.10.18 14:32` or a countdown such as
+//!   `1d 4h 22m` counted from the time of parsing
+//! - **Field values** - `Key: Value` lines whose key matches a ping format field name,
+//!   doctrine fields take the name of a doctrine in the guild's doctrine library
+//!
+//! Pingbot broadcast footers & mention-only lines are ignored, remaining lines are kept
+//! as the fleet description.
+
+use chrono::{DateTime, Duration, NaiveDate, NaiveTime, Utc};
+use sea_orm::DatabaseConnection;
+
+use crate::{
+    model::ping_format::PingFormatFieldType,
+    server::{
+        data::{
+            category::FleetCategoryRepository, doctrine::DoctrineRepository,
+            ping_format::field::PingFormatFieldRepository,
+        },
+        error::AppError,
+        eve_map::{eve_map, EveMap},
+        model::{
+            doctrine::Doctrine, paste::ParsedPaste, ping_format::PingFormatField,
+            solar_system::SolarSystem,
+        },
+        util::parse::parse_u64_from_string,
+    },
+};
+
+/// Longest paste accepted, in characters
+pub const MAX_PASTE_LENGTH: usize = 10_000;
+
+/// Structure types recognised in pastes
+///
+/// Names containing another name must come first so the longest match wins.
+const STRUCTURE_TYPES: &[&str] = &[
+    "Ansiblex Jump Gate",
+    "Pharolux Cyno Beacon",
+    "Tenebrex Cyno Jammer",
+    "Infrastructure Hub",
+    "Territorial Claim Unit",
+    "Customs Office",
+    "Orbital Skyhook",
+    "Mercenary Den",
+    "Control Tower",
+    "Keepstar",
+    "Fortizar",
+    "Astrahus",
+    "Sotiyo",
+    "Azbel",
+    "Raitaru",
+    "Tatara",
+    "Athanor",
+    "Metenox",
+];
+
+/// Keys naming the fleet when no ping format field has the same name
+const NAME_KEYS: &[&str] = &[
+    "name",
+    "fleet",
+    "fleetname",
+    "op",
+    "opname",
+    "operation",
+    "title",
+];
+
+/// Keys giving the solar system when no ping format field has the same name
+const SYSTEM_KEYS: &[&str] = &["system", "solarsystem", "location"];
+
+/// Keys giving the structure when no ping format field has the same name
+const STRUCTURE_KEYS: &[&str] = &["structure", "target"];
+
+/// Keys giving the fleet time when no ping format field has the same name
+const TIME_KEYS: &[&str] = &[
+    "time",
+    "when",
+    "date",
+    "start",
+    "starttime",
+    "fleettime",
+    "formuptime",
+    "evetime",
+    "timer",
+    "timerend",
+];
+
+/// Words marking a line as describing a timer, whose time is preferred over other times
+const TIMER_CUES: &[&str] = &[
+    "reinforce",
+    "timer",
+    "until",
+    "exit",
+    "vulnerable",
+    "comes out",
+];
+
+pub struct PasteService<'a> {
+    db: &'a DatabaseConnection,
+}
+
+impl<'a> PasteService<'a> {
+    pub fn new(db: &'a DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// Parses a paste into a fleet of a category for review in the creation modal
+    ///
+    /// # Arguments
+    /// - `guild_id` - Discord ID of the guild the category belongs to
+    /// - `category_id` - Category whose ping format fields are matched against the paste
+    /// - `text` - The pasted text
+    ///
+    /// # Returns
+    /// - `Ok(ParsedPaste)` - Whatever could be parsed from the paste
+    /// - `Err(AppError::BadRequest(_))` - Paste is empty or too long
+    /// - `Err(AppError::NotFound(_))` - Category not found in the guild
+    /// - `Err(AppError::Database(_))` - Database operation failed
+    pub async fn parse(
+        &self,
+        guild_id: u64,
+        category_id: i32,
+        text: &str,
+    ) -> Result<ParsedPaste, AppError> {
+        if text.trim().is_empty() {
+            return Err(AppError::BadRequest("Paste is empty".to_string()));
+        }
+        if text.chars().count() > MAX_PASTE_LENGTH {
+            return Err(AppError::BadRequest(format!(
+                "Paste can't be longer than {} characters",
+                MAX_PASTE_LENGTH
+            )));
+        }
+
+        let category = FleetCategoryRepository::new(self.db)
+            .find_by_id(category_id)
+            .await?
+            .filter(|category| {
+                parse_u64_from_string(category.category.guild_id.clone()).ok() == Some(guild_id)
+            })
+            .ok_or_else(|| AppError::NotFound("Category not found".to_string()))?;
+
+        let fields = PingFormatFieldRepository::new(self.db)
+            .get_by_ping_format_id(guild_id, category.category.ping_format_id)
+            .await?;
+
+        let doctrines = if fields
+            .iter()
+            .any(|f| f.field_type == PingFormatFieldType::Doctrine)
+        {
+            DoctrineRepository::new(self.db)
+                .get_all_by_guild(guild_id)
+                .await?
+        } else {
+            Vec::new()
+        };
+
+        Ok(parse_paste(
+            text,
+            &fields,
+            &doctrines,
+            eve_map(),
+            Utc::now(),
+        ))
+    }
+}
+
+/// Parses a pasted notification or ping
+///
+/// # Arguments
+/// - `text` - The pasted text
+/// - `fields` - Ping format fields of the category the fleet is created in
+/// - `doctrines` - Doctrines of the guild, matched by name for doctrine fields
+/// - `map` - Static map to look up solar systems in
+/// - `now` - Time countdowns & times without a date are counted from
+///
+/// # Returns
+/// - `ParsedPaste` - Whatever could be parsed, with warnings for values that were found
+///   but couldn't be used
+pub fn parse_paste(
+    text: &str,
+    fields: &[PingFormatField],
+    doctrines: &[Doctrine],
+    map: &EveMap,
+    now: DateTime<Utc>,
+) -> ParsedPaste {
+    let mut parsed = ParsedPaste::default();
+    let mut description = Vec::new();
+    let mut stage = None;
+    // Times given by a key are preferred over times of timer lines over any other time
+    let mut key_time = None;
+    let mut timer_time = None;
+    let mut other_time = None;
+
+    for line in text.lines().map(str::trim) {
+        if line.is_empty() || is_noise(line) {
+            continue;
+        }
+
+        if let Some((key, value)) = split_key_value(line) {
+            let key = normalise_key(key);
+
+            if let Some(field) = fields.iter().find(|f| normalise_key(&f.name) == key) {
+                if let Some(value) = field_value(field, value, doctrines, map, &mut parsed) {
+                    parsed.field_values.insert(field.id, value);
+                }
+                continue;
+            }
+
+            if NAME_KEYS.contains(&key.as_str()) {
+                parsed.name.get_or_insert_with(|| value.to_string());
+                continue;
+            }
+            if SYSTEM_KEYS.contains(&key.as_str()) {
+                match map.find_by_name(value) {
+                    Some(system) => parsed.solar_system = Some(system.name.clone()),
+                    None => parsed
+                        .warnings
+                        .push(format!("Unknown solar system '{}'", value)),
+                }
+                continue;
+            }
+            if STRUCTURE_KEYS.contains(&key.as_str()) {
+                match find_structure(value) {
+                    Some((structure_type, name)) => {
+                        parsed.structure_type = Some(structure_type.to_string());
+                        parsed.structure_name = name;
+                    }
+                    None => parsed.structure_name = Some(value.to_string()),
+                }
+                continue;
+            }
+            if TIME_KEYS.contains(&key.as_str()) {
+                match find_time(value, now, true) {
+                    Some(time) => key_time = key_time.or(Some(time)),
+                    None => parsed
+                        .warnings
+                        .push(format!("Couldn't read '{}' as a time", value)),
+                }
+                continue;
+            }
+        }
+
+        let lowercase = line.to_lowercase();
+
+        if let Some(time) = find_time(line, now, false) {
+            if TIMER_CUES.iter().any(|cue| lowercase.contains(cue)) {
+                timer_time = timer_time.or(Some(time));
+            } else {
+                other_time = other_time.or(Some(time));
+            }
+        }
+        if parsed.structure_type.is_none() {
+            if let Some((structure_type, name)) = find_structure(line) {
+                parsed.structure_type = Some(structure_type.to_string());
+                parsed.structure_name = parsed.structure_name.take().or(name);
+            }
+        }
+        if parsed.solar_system.is_none() {
+            parsed.solar_system = find_solar_system(line, map).map(|system| system.name.clone());
+        }
+        stage = stage.or_else(|| timer_stage(&lowercase));
+
+        description.push(line);
+    }
+
+    parsed.timer_end = key_time.or(timer_time).or(other_time);
+    if parsed.timer_end.is_none() {
+        parsed
+            .warnings
+            .push("No fleet time found in the paste".to_string());
+    }
+
+    // Prefill the main solar system field, the one listed first in the ping
+    if let Some(solar_system) = &parsed.solar_system {
+        let main_field = fields
+            .iter()
+            .filter(|f| f.field_type == PingFormatFieldType::SolarSystem)
+            .min_by_key(|f| f.priority);
+        if let Some(field) = main_field {
+            parsed
+                .field_values
+                .entry(field.id)
+                .or_insert_with(|| solar_system.clone());
+        }
+    }
+
+    if parsed.name.is_none() {
+        parsed.name = default_name(
+            stage,
+            parsed.structure_type.as_deref(),
+            parsed.solar_system.as_deref(),
+        );
+    }
+    if !description.is_empty() {
+        parsed.description = Some(description.join("\n"));
+    }
+
+    parsed
+}
+
+/// Whether a line carries nothing to parse, i.e. pingbot broadcast footers such as
+/// `~~~ This was a broadcast from x to all at 2026-10-18 17:02:11 EVE ~~~` and lines
+/// only mentioning roles or users
+fn is_noise(line: &str) -> bool {
+    line.starts_with("~~~")
+        || line.to_lowercase().contains("this was a broadcast from")
+        || line
+            .split_whitespace()
+            .all(|word| word.starts_with('@') || word.starts_with("<@"))
+}
+
+/// Splits a `Key: Value` line, allowing Discord markdown around the key such as
+/// `**FC:** Name` or `- Doctrine: Muninns`
+///
+/// Lines whose key ends in a digit are times like `14:32` rather than keys, and values
+/// starting with `//` are links.
+fn split_key_value(line: &str) -> Option<(&str, &str)> {
+    let (key, value) = line.split_once(':')?;
+
+    let key = key.trim_matches(|c: char| c.is_whitespace() || "*_-•>".contains(c));
+    let value = value.trim_matches(|c: char| c.is_whitespace() || "*_".contains(c));
+
+    let valid = !key.is_empty()
+        && key.chars().count() <= 32
+        && !key.ends_with(|c: char| c.is_ascii_digit())
+        && !value.is_empty()
+        && !value.starts_with("//");
+
+    valid.then_some((key, value))
+}
+
+/// Lowercases a key & drops everything but letters and digits, so "Form-up" and
+/// "formup" match the same field
+fn normalise_key(key: &str) -> String {
+    key.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Converts a value given for a ping format field to the value stored for its type
+///
+/// # Returns
+/// - `Some(String)` - Value to prefill the field with
+/// - `None` - The value isn't valid for the field's type, a warning is added instead
+fn field_value(
+    field: &PingFormatField,
+    value: &str,
+    doctrines: &[Doctrine],
+    map: &EveMap,
+    parsed: &mut ParsedPaste,
+) -> Option<String> {
+    match field.field_type {
+        PingFormatFieldType::Text => Some(value.to_string()),
+        PingFormatFieldType::Bool => match value.to_lowercase().as_str() {
+            "yes" | "y" | "true" | "on" => Some("true".to_string()),
+            "no" | "n" | "false" | "off" => Some("false".to_string()),
+            _ => {
+                parsed.warnings.push(format!(
+                    "Couldn't read '{}' as yes or no for {}",
+                    value, field.name
+                ));
+                None
+            }
+        },
+        PingFormatFieldType::SolarSystem => match map.find_by_name(value) {
+            Some(system) => {
+                parsed
+                    .solar_system
+                    .get_or_insert_with(|| system.name.clone());
+                Some(system.name.clone())
+            }
+            None => {
+                parsed.warnings.push(format!(
+                    "Unknown solar system '{}' for {}",
+                    value, field.name
+                ));
+                None
+            }
+        },
+        PingFormatFieldType::Doctrine => {
+            match doctrines
+                .iter()
+                .find(|doctrine| doctrine.name.eq_ignore_ascii_case(value))
+            {
+                Some(doctrine) => Some(doctrine.id.to_string()),
+                None => {
+                    parsed
+                        .warnings
+                        .push(format!("Unknown doctrine '{}' for {}", value, field.name));
+                    None
+                }
+            }
+        }
+    }
+}
+
+/// Finds the first time in a text
+///
+/// Absolute EVE times are preferred over countdowns, times of day without a date are only
+/// read when `time_of_day` is set as on their own they're too ambiguous.
+///
+/// # Returns
+/// - `Some(DateTime<Utc>)` - The time, countdowns & times of day are resolved against `now`
+/// - `None` - No time found
+fn find_time(text: &str, now: DateTime<Utc>, time_of_day: bool) -> Option<DateTime<Utc>> {
+    if let Some(time) = starts(text).find_map(parse_date_time) {
+        return Some(time);
+    }
+    if let Some(countdown) = find_countdown(text) {
+        return Some(now + countdown);
+    }
+    if !time_of_day {
+        return None;
+    }
+
+    let time = starts(text).find_map(|mut rest| take_time(&mut rest))?;
+    let today = now.date_naive().and_time(time).and_utc();
+
+    Some(if today < now {
+        today + Duration::days(1)
+    } else {
+        today
+    })
+}
+
+/// Suffixes of a text starting at a digit not preceded by another digit
+fn starts(text: &str) -> impl Iterator<Item = &str> {
+    text.char_indices()
+        .filter(move |(i, c)| {
+            c.is_ascii_digit() && !text[..*i].ends_with(|p: char| p.is_ascii_digit())
+        })
+        .map(move |(i, _)| &text[i..])
+}
+
+/// Parses a date & time at the start of a text, such as the EVE format
+/// `2026.10.18 14:32:05` or `2026-10-18T14:32`
+fn parse_date_time(mut rest: &str) -> Option<DateTime<Utc>> {
+    let year = take_digits(&mut rest, 4, 4)?;
+    let separator = take_char(&mut rest, &['.', '-', '/'])?;
+    let month = take_digits(&mut rest, 1, 2)?;
+    take_char(&mut rest, &[separator])?;
+    let day = take_digits(&mut rest, 1, 2)?;
+    let date = NaiveDate::from_ymd_opt(year as i32, month, day)?;
+
+    rest = rest.trim_start_matches([' ', '\t', ',', 'T']);
+    rest = rest.strip_prefix("at ").unwrap_or(rest);
+    let time = take_time(&mut rest)?;
+
+    Some(date.and_time(time).and_utc())
+}
+
+/// Takes a time of day such as `14:32` or `14:32:05` from the start of a text
+fn take_time(rest: &mut &str) -> Option<NaiveTime> {
+    let hour = take_digits(rest, 1, 2)?;
+    take_char(rest, &[':'])?;
+    let minute = take_digits(rest, 2, 2)?;
+    let second = match take_char(rest, &[':']) {
+        Some(_) => take_digits(rest, 2, 2)?,
+        None => 0,
+    };
+
+    NaiveTime::from_hms_opt(hour, minute, second)
+}
+
+/// Takes a number of `min` to `max` digits from the start of a text
+fn take_digits(rest: &mut &str, min: usize, max: usize) -> Option<u32> {
+    let len = rest.bytes().take_while(u8::is_ascii_digit).count();
+    if len < min || len > max {
+        return None;
+    }
+
+    let value = rest[..len].parse().ok()?;
+    *rest = &rest[len..];
+    Some(value)
+}
+
+/// Takes one of the given characters from the start of a text
+fn take_char(rest: &mut &str, chars: &[char]) -> Option<char> {
+    let c = rest.chars().next().filter(|c| chars.contains(c))?;
+    *rest = &rest[c.len_utf8()..];
+    Some(c)
+}
+
+/// Finds the first countdown in a text, such as `1d 4h 22m`, `1d4h22m` or
+/// `2 hours and 5 minutes`
+fn find_countdown(text: &str) -> Option<Duration> {
+    let words: Vec<&str> = text
+        .split(|c: char| c.is_whitespace() || ",()[]".contains(c))
+        .map(|word| word.trim_end_matches(['.', ';', '!']))
+        .filter(|word| !word.is_empty())
+        .collect();
+
+    (0..words.len()).find_map(|start| {
+        let mut total = Duration::zero();
+        let mut parts = 0;
+        let mut i = start;
+
+        while i < words.len() {
+            if parts > 0 && words[i].eq_ignore_ascii_case("and") {
+                i += 1;
+                continue;
+            }
+
+            // Either a compact word like "4h22m" or a number followed by a unit word
+            if let Some(duration) = parse_compact_countdown(words[i]) {
+                total += duration;
+                i += 1;
+            } else if let (Ok(amount), Some(unit)) = (
+                words[i].parse::<i64>(),
+                words.get(i + 1).and_then(|unit| unit_seconds(unit)),
+            ) {
+                total += Duration::seconds(amount * unit);
+                i += 2;
+            } else {
+                break;
+            }
+            parts += 1;
+        }
+
+        (parts > 0).then_some(total)
+    })
+}
+
+/// Parses a countdown without spaces such as `4h22m`
+fn parse_compact_countdown(word: &str) -> Option<Duration> {
+    let mut rest = word;
+    let mut total = Duration::zero();
+
+    while !rest.is_empty() {
+        let digits = rest.bytes().take_while(u8::is_ascii_digit).count();
+        let letters = rest[digits..]
+            .bytes()
+            .take_while(u8::is_ascii_alphabetic)
+            .count();
+        if digits == 0 || letters == 0 {
+            return None;
+        }
+
+        let amount: i64 = rest[..digits].parse().ok()?;
+        let unit = unit_seconds(&rest[digits..digits + letters])?;
+        total += Duration::seconds(amount * unit);
+        rest = &rest[digits + letters..];
+    }
+
+    Some(total)
+}
+
+/// Length of a countdown unit in seconds
+fn unit_seconds(unit: &str) -> Option<i64> {
+    match unit.to_lowercase().as_str() {
+        "d" | "day" | "days" => Some(86_400),
+        "h" | "hr" | "hrs" | "hour" | "hours" => Some(3_600),
+        "m" | "min" | "mins" | "minute" | "minutes" => Some(60),
+        "s" | "sec" | "secs" | "second" | "seconds" => Some(1),
+        _ => None,
+    }
+}
+
+/// Finds the first known structure type in a text
+///
+/// # Returns
+/// - `Some((&str, Option<String>))` - Structure type & the quoted name following it, if any
+/// - `None` - No known structure type in the text
+fn find_structure(text: &str) -> Option<(&'static str, Option<String>)> {
+    let (index, structure_type) = STRUCTURE_TYPES
+        .iter()
+        .filter_map(|structure_type| {
+            find_word_ignore_case(text, structure_type).map(|index| (index, *structure_type))
+        })
+        .min_by_key(|(index, _)| *index)?;
+
+    let rest = text[index + structure_type.len()..].trim_start();
+    let name = rest
+        .strip_prefix(['"', '“'])
+        .and_then(|quoted| quoted.split_once(['"', '”']))
+        .map(|(name, _)| name.trim().to_string())
+        .filter(|name| !name.is_empty());
+
+    Some((structure_type, name))
+}
+
+/// Finds a word in a text ignoring ASCII case
+///
+/// # Returns
+/// - `Some(usize)` - Byte index of the word in the text
+/// - `None` - The word doesn't appear on its own in the text
+fn find_word_ignore_case(text: &str, word: &str) -> Option<usize> {
+    let bytes = text.as_bytes();
+    let is_word_byte = |i: usize| bytes.get(i).is_some_and(u8::is_ascii_alphanumeric);
+
+    (0..=bytes.len().checked_sub(word.len())?).find(|&i| {
+        bytes[i..i + word.len()].eq_ignore_ascii_case(word.as_bytes())
+            && (i == 0 || !is_word_byte(i - 1))
+            && !is_word_byte(i + word.len())
+    })
+}
+
+/// Finds the first solar system named in a text
+///
+/// Systems following "in", "at" or "system" are preferred as other words, such as the
+/// structure's name, may happen to be system names too. Quoted text is skipped as it
+/// usually is a structure's name.
+fn find_solar_system<'m>(text: &str, map: &'m EveMap) -> Option<&'m SolarSystem> {
+    let mut unquoted = String::with_capacity(text.len());
+    let mut quoted = false;
+    for c in text.chars() {
+        if matches!(c, '"' | '“' | '”') {
+            quoted = !quoted;
+            unquoted.push(' ');
+        } else if !quoted {
+            unquoted.push(c);
+        }
+    }
+
+    let words: Vec<&str> = unquoted
+        .split_whitespace()
+        .map(|word| word.trim_matches(|c: char| !c.is_alphanumeric() && c != '-'))
+        .filter(|word| !word.is_empty())
+        .collect();
+
+    // Longest names first so "Old Man Star" wins over a system named "Old"
+    let system_at = |start: usize| {
+        (1..=3).rev().find_map(|len| {
+            let name = words.get(start..start + len)?.join(" ");
+            map.find_by_name(&name)
+        })
+    };
+
+    let after_cue = (1..words.len())
+        .filter(|&i| {
+            ["in", "at", "system"]
+                .iter()
+                .any(|cue| words[i - 1].eq_ignore_ascii_case(cue))
+        })
+        .find_map(system_at);
+
+    after_cue.or_else(|| (0..words.len()).find_map(system_at))
+}
+
+/// Stage of the next timer of a structure, read from the wording of EVE's notifications
+/// or a ping naming it
+fn timer_stage(lowercase: &str) -> Option<&'static str> {
+    if lowercase.contains("lost its shield") {
+        return Some("Armor");
+    }
+    if lowercase.contains("lost its armor") {
+        return Some("Hull");
+    }
+
+    [
+        ("shield timer", "Shield"),
+        ("armor timer", "Armor"),
+        ("hull timer", "Hull"),
+        ("final timer", "Final"),
+    ]
+    .into_iter()
+    .find(|(phrase, _)| lowercase.contains(phrase))
+    .map(|(_, stage)| stage)
+}
+
+/// Generates a fleet name from the timer found in the paste, e.g.
+/// "Armor timer: Astrahus in 1DQ1-A"
+fn default_name(
+    stage: Option<&str>,
+    structure_type: Option<&str>,
+    solar_system: Option<&str>,
+) -> Option<String> {
+    let timer = match stage {
+        Some(stage) => format!("{} timer", stage),
+        None => "Timer".to_string(),
+    };
+
+    match (structure_type, solar_system) {
+        (Some(structure_type), Some(system)) => {
+            Some(format!("{}: {} in {}", timer, structure_type, system))
+        }
+        (Some(structure_type), None) => Some(format!("{}: {}", timer, structure_type)),
+        (None, Some(system)) => Some(format!("{} in {}", timer, system)),
+        (None, None) => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    const SYSTEMS: &str = "solar_system_id,name,constellation,region,security
+30000142,Jita,Kimotoro,The Forge,0.9459
+30000144,Perimeter,Kimotoro,The Forge,0.9072
+30004759,1DQ1-A,O-EIMK,Delve,-0.3855
+30004760,1-7KWU,O-EIMK,Delve,0.0312
+";
+
+    const STARGATES: &str = "from_solar_system_id,to_solar_system_id\n";
+
+    const FORMUP: i32 = 1;
+    const DOCTRINE: i32 = 2;
+    const CAPITALS: i32 = 3;
+    const TARGET: i32 = 4;
+
+    fn field(
+        id: i32,
+        name: &str,
+        priority: i32,
+        field_type: PingFormatFieldType,
+    ) -> PingFormatField {
+        PingFormatField {
+            id,
+            ping_format_id: 1,
+            name: name.to_string(),
+            priority,
+            field_type,
+            default_field_values: vec![],
+        }
+    }
+
+    fn fields() -> Vec<PingFormatField> {
+        vec![
+            field(FORMUP, "Formup", 1, PingFormatFieldType::SolarSystem),
+            field(DOCTRINE, "Doctrine", 2, PingFormatFieldType::Text),
+            field(CAPITALS, "Capitals", 3, PingFormatFieldType::Bool),
+            field(TARGET, "Target", 4, PingFormatFieldType::SolarSystem),
+        ]
+    }
+
+    fn parse(text: &str) -> ParsedPaste {
+        let map = EveMap::parse(SYSTEMS, STARGATES).unwrap();
+        let now = Utc.with_ymd_and_hms(2026, 10, 18, 12, 0, 0).unwrap();
+
+        parse_paste(text, &fields(), &[], &map, now)
+    }
+
+    fn utc(year: i32, month: u32, day: u32, hour: u32, minute: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(year, month, day, hour, minute, 0)
+            .unwrap()
+    }
+
+    /// Tests parsing the notification of a structure losing its shields.
+    ///
+    /// Expected: Structure with its quoted name, system & reinforcement exit time are
+    /// found, the name is generated for the armor timer and the solar system field is
+    /// prefilled
+    #[test]
+    fn parses_shield_notification() {
+        let parsed = parse(include_str!("paste/structure_lost_shields.txt"));
+
+        assert_eq!(
+            parsed.structure(),
+            Some("Astrahus \"Jita Market\"".to_string())
+        );
+        assert_eq!(parsed.solar_system.as_deref(), Some("Perimeter"));
+        assert_eq!(parsed.timer_end, Some(utc(2026, 10, 19, 17, 45)));
+        assert_eq!(
+            parsed.name.as_deref(),
+            Some("Armor timer: Astrahus in Perimeter")
+        );
+        assert_eq!(
+            parsed.field_values.get(&FORMUP).map(String::as_str),
+            Some("Perimeter")
+        );
+        assert!(parsed.warnings.is_empty());
+    }
+
+    /// Tests parsing the notification of a structure losing its armor.
+    ///
+    /// Expected: The system after "in" is preferred over the one in the structure's name
+    /// and the time with seconds is read
+    #[test]
+    fn parses_armor_notification() {
+        let parsed = parse(include_str!("paste/structure_lost_armor.txt"));
+
+        assert_eq!(parsed.structure(), Some("Fortizar".to_string()));
+        assert_eq!(parsed.solar_system.as_deref(), Some("1DQ1-A"));
+        assert_eq!(parsed.timer_end, Some(utc(2026, 10, 20, 3, 10)));
+        assert_eq!(
+            parsed.name.as_deref(),
+            Some("Hull timer: Fortizar in 1DQ1-A")
+        );
+    }
+
+    /// Tests parsing a pingbot broadcast with key-value lines.
+    ///
+    /// Expected: Keys matching fields fill them, the time key sets the fleet time rather
+    /// than the broadcast footer, unmatched lines are kept as the description & mentions
+    /// and the footer are dropped
+    #[test]
+    fn parses_pingbot_broadcast() {
+        let parsed = parse(include_str!("paste/pingbot_broadcast.txt"));
+
+        assert_eq!(parsed.timer_end, Some(utc(2026, 10, 18, 20, 0)));
+        assert_eq!(
+            parsed.field_values.get(&FORMUP).map(String::as_str),
+            Some("1-7KWU")
+        );
+        assert_eq!(
+            parsed.field_values.get(&DOCTRINE).map(String::as_str),
+            Some("Muninns")
+        );
+        assert_eq!(
+            parsed.field_values.get(&CAPITALS).map(String::as_str),
+            Some("false")
+        );
+        assert_eq!(
+            parsed.field_values.get(&TARGET).map(String::as_str),
+            Some("1DQ1-A")
+        );
+        assert_eq!(parsed.structure(), Some("Raitaru".to_string()));
+        assert_eq!(
+            parsed.name.as_deref(),
+            Some("Shield timer: Raitaru in 1-7KWU")
+        );
+        assert_eq!(
+            parsed.description.as_deref(),
+            Some("**FC:** Lord Vader\nShield timer on the Raitaru in 1DQ1-A, bring nanite paste.")
+        );
+        assert!(parsed.warnings.is_empty());
+    }
+
+    /// Tests parsing a ping giving the timer as a countdown.
+    ///
+    /// Expected: Timer end is counted from now, the bool field is read from "yes"
+    #[test]
+    fn parses_countdown() {
+        let parsed = parse(include_str!("paste/countdown.txt"));
+
+        assert_eq!(parsed.timer_end, Some(utc(2026, 10, 19, 16, 22)));
+        assert_eq!(parsed.structure(), Some("Keepstar".to_string()));
+        assert_eq!(parsed.solar_system.as_deref(), Some("Jita"));
+        assert_eq!(
+            parsed.field_values.get(&CAPITALS).map(String::as_str),
+            Some("true")
+        );
+        assert_eq!(
+            parsed.field_values.get(&DOCTRINE).map(String::as_str),
+            Some("Ravens")
+        );
+    }
+
+    /// Tests parsing a ping with values that aren't valid for their fields.
+    ///
+    /// Expected: Invalid values are left out with a warning each, the name key names the
+    /// fleet and a time of day resolves to its next occurrence
+    #[test]
+    fn warns_about_invalid_values() {
+        let parsed = parse(include_str!("paste/invalid_values.txt"));
+
+        assert_eq!(parsed.name.as_deref(), Some("Home defense"));
+        assert_eq!(parsed.timer_end, Some(utc(2026, 10, 19, 9, 30)));
+        assert!(!parsed.field_values.contains_key(&FORMUP));
+        assert!(!parsed.field_values.contains_key(&CAPITALS));
+        assert_eq!(
+            parsed.warnings,
+            vec![
+                "Unknown solar system 'Nowhere' for Formup".to_string(),
+                "Couldn't read 'maybe' as yes or no for Capitals".to_string(),
+            ]
+        );
+    }
+
+    /// Tests parsing a paste without anything recognisable.
+    ///
+    /// Expected: Only the description is filled & a warning notes the missing time
+    #[test]
+    fn parses_plain_text() {
+        let parsed = parse(include_str!("paste/plain_text.txt"));
+
+        assert_eq!(parsed.name, None);
+        assert_eq!(parsed.solar_system, None);
+        assert_eq!(parsed.structure(), None);
+        assert_eq!(parsed.timer_end, None);
+        assert!(parsed.field_values.is_empty());
+        assert_eq!(
+            parsed.description.as_deref(),
+            Some("Anyone up for some mining later? Bring your own ore holds.")
+        );
+        assert_eq!(
+            parsed.warnings,
+            vec!["No fleet time found in the paste".to_string()]
+        );
+    }
+
+    /// Tests reading countdowns in their different spellings.
+    ///
+    /// Expected: Compact, spaced & spelled out countdowns are read, numbers followed by
+    /// other words aren't
+    #[test]
+    fn finds_countdowns() {
+        assert_eq!(
+            find_countdown("exits in 1d 4h 22m"),
+            Some(Duration::minutes(1702))
+        );
+        assert_eq!(find_countdown("(1d4h22m)"), Some(Duration::minutes(1702)));
+        assert_eq!(
+            find_countdown("in 2 hours and 5 minutes"),
+            Some(Duration::minutes(125))
+        );
+        assert_eq!(find_countdown("10 man fleet in 1DQ1-A"), None);
+    }
+
+    /// Tests filling doctrine fields from the guild's doctrine library.
+    ///
+    /// Expected: A doctrine named in any case is stored by its ID, unknown doctrines
+    /// leave the field empty with a warning
+    #[test]
+    fn matches_doctrines_by_name() {
+        let map = EveMap::parse(SYSTEMS, STARGATES).unwrap();
+        let now = Utc.with_ymd_and_hms(2026, 10, 18, 12, 0, 0).unwrap();
+        let fields = vec![
+            field(DOCTRINE, "Doctrine", 1, PingFormatFieldType::Doctrine),
+            field(TARGET, "Support", 2, PingFormatFieldType::Doctrine),
+        ];
+        let doctrines = vec![Doctrine {
+            id: 7,
+            guild_id: 1,
+            name: "Muninns".to_string(),
+            description: None,
+            fits: vec![],
+        }];
+
+        let parsed = parse_paste(
+            "Doctrine: muninns\nSupport: Ravens",
+            &fields,
+            &doctrines,
+            &map,
+            now,
+        );
+
+        assert_eq!(
+            parsed.field_values.get(&DOCTRINE).map(String::as_str),
+            Some("7")
+        );
+        assert!(!parsed.field_values.contains_key(&TARGET));
+        assert!(parsed
+            .warnings
+            .contains(&"Unknown doctrine 'Ravens' for Support".to_string()));
+    }
+}
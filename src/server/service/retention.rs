@@ -0,0 +1,107 @@
+//! Retention service for pruning historical data.
+//!
+//! This module provides the `RetentionService` which applies the configured retention policy
+//! to fleets, fleet messages, webhook deliveries and sessions. Fleets are archived while rows
+//! of the other tables are deleted. The same calculation backs both the admin preview, which
+//! only counts matching rows, and the scheduled pruning job.
+
+use chrono::{DateTime, Utc};
+use sea_orm::DatabaseConnection;
+
+use crate::server::{
+    data::{
+        fleet::FleetRepository, fleet_message::FleetMessageRepository, session::SessionRepository,
+        webhook_delivery::WebhookDeliveryRepository,
+    },
+    error::AppError,
+    model::retention::{RetentionPolicy, RetentionReport},
+};
+
+/// Service applying a retention policy to the database.
+pub struct RetentionService<'a> {
+    db: &'a DatabaseConnection,
+    policy: RetentionPolicy,
+}
+
+impl<'a> RetentionService<'a> {
+    /// Creates a new RetentionService instance.
+    ///
+    /// # Arguments
+    /// - `db` - Reference to the database connection
+    /// - `policy` - Retention policy to preview or apply
+    ///
+    /// # Returns
+    /// - `RetentionService` - New service instance
+    pub fn new(db: &'a DatabaseConnection, policy: RetentionPolicy) -> Self {
+        Self { db, policy }
+    }
+
+    /// Counts the rows which pruning would currently affect without changing anything.
+    ///
+    /// # Arguments
+    /// - `now` - Time the retention periods are measured from
+    ///
+    /// # Returns
+    /// - `Ok(RetentionReport)` - Number of rows per table which would be pruned
+    /// - `Err(DbErr(_))` - Database error during count queries
+    pub async fn preview(&self, now: DateTime<Utc>) -> Result<RetentionReport, AppError> {
+        let fleets = match self.policy.fleet_cutoff(now) {
+            Some(cutoff) => FleetRepository::new(self.db).count_before(cutoff).await?,
+            None => 0,
+        };
+
+        let fleet_messages = match self.policy.fleet_message_cutoff(now) {
+            Some(cutoff) => {
+                FleetMessageRepository::new(self.db)
+                    .count_for_fleets_before(cutoff)
+                    .await?
+            }
+            None => 0,
+        };
+
+        Ok(RetentionReport {
+            fleets,
+            fleet_messages,
+            webhook_deliveries: WebhookDeliveryRepository::new(self.db)
+                .count_finished_before(self.policy.webhook_delivery_cutoff(now))
+                .await?,
+            sessions: SessionRepository::new(self.db).count_expired().await?,
+        })
+    }
+
+    /// Applies the retention policy.
+    ///
+    /// Fleets are archived rather than deleted so their history remains available. Fleet
+    /// messages, finished webhook deliveries and expired sessions are deleted.
+    ///
+    /// # Arguments
+    /// - `now` - Time the retention periods are measured from
+    ///
+    /// # Returns
+    /// - `Ok(RetentionReport)` - Number of rows per table which were pruned
+    /// - `Err(DbErr(_))` - Database error during update or delete operations
+    pub async fn prune(&self, now: DateTime<Utc>) -> Result<RetentionReport, AppError> {
+        let fleets = match self.policy.fleet_cutoff(now) {
+            Some(cutoff) => FleetRepository::new(self.db).archive_before(cutoff).await?,
+            None => 0,
+        };
+
+        let fleet_messages = match self.policy.fleet_message_cutoff(now) {
+            Some(cutoff) => {
+                FleetMessageRepository::new(self.db)
+                    .delete_for_fleets_before(cutoff)
+                    .await?
+            }
+            None => 0,
+        };
+
+        Ok(RetentionReport {
+            fleets,
+            fleet_messages,
+            webhook_deliveries: WebhookDeliveryRepository::new(self.db)
+                .delete_finished_before(self.policy.webhook_delivery_cutoff(now))
+                .await?,
+            sessions: SessionRepository::new(self.db).delete_expired().await?,
+        })
+    }
+}
@@ -0,0 +1,558 @@
+//! Stand-down service for cancelling every scheduled fleet of a scope at once.
+//!
+//! This module provides the `StandDownService` for emergency stand-downs, e.g. when a
+//! hostile capital fleet drops. A stand-down cancels every fleet of a guild or ping group
+//! within a time window, broadcasts the reason to every affected channel and keeps a
+//! snapshot of the cancelled fleets so resuming it restores the fleets still ahead.
+//!
+//! Standing down or resuming a scope takes manage permission for every category in it.
+
+use chrono::{DateTime, Duration, NaiveDateTime, Utc};
+use sea_orm::DatabaseConnection;
+use serenity::http::Http;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use crate::{
+    constant::PING_GROUP_LIMIT_PER_GUILD,
+    model::stand_down::{
+        CreateStandDownDto, StandDownDto, StandDownOverviewDto, StandDownPingGroupDto,
+    },
+    server::{
+        data::{
+            category::FleetCategoryRepository, fleet::FleetRepository,
+            ping_group::PingGroupRepository, relayed_fleet::RelayedFleetRepository,
+            stand_down::StandDownRepository,
+            user_category_permission::UserCategoryPermissionRepository,
+        },
+        error::{auth::AuthError, AppError},
+        model::{ping_group::PingGroup, stand_down::CreateStandDownParam},
+        service::{
+            fleet::{CancellationNotice, FleetService},
+            fleet_notification::{builder::StandDownNotice, FleetNotificationService},
+        },
+    },
+};
+
+/// Maximum length of the reason broadcast with a stand-down
+const MAX_REASON_LENGTH: usize = 1000;
+
+/// Longest time window a single stand-down can cover
+pub const MAX_STAND_DOWN_WINDOW_DAYS: i64 = 7;
+
+/// Amount of recent stand-downs listed in the overview
+const RECENT_STAND_DOWN_LIMIT: u64 = 10;
+
+pub struct StandDownService<'a> {
+    db: &'a DatabaseConnection,
+    discord_http: Arc<Http>,
+    app_url: String,
+}
+
+impl<'a> StandDownService<'a> {
+    pub fn new(db: &'a DatabaseConnection, discord_http: Arc<Http>, app_url: String) -> Self {
+        Self {
+            db,
+            discord_http,
+            app_url,
+        }
+    }
+
+    /// Gets the scopes a user can stand down & the guild's recent stand-downs
+    ///
+    /// # Arguments
+    /// - `guild_id` - Discord ID of the guild
+    /// - `user_id` - Discord ID of the user
+    /// - `is_admin` - Whether the user is an admin, admins can stand down every scope
+    ///
+    /// # Returns
+    /// - `Ok(StandDownOverviewDto)` - The guild & ping groups the user manages every
+    ///   category of, and the most recent stand-downs of the guild
+    /// - `Err(AppError::Database(_))` - Database operation failed
+    pub async fn get_overview(
+        &self,
+        guild_id: u64,
+        user_id: u64,
+        is_admin: bool,
+    ) -> Result<StandDownOverviewDto, AppError> {
+        let category_repo = FleetCategoryRepository::new(self.db);
+
+        let manageable: Option<HashSet<i32>> = if is_admin {
+            None
+        } else {
+            Some(
+                UserCategoryPermissionRepository::new(self.db)
+                    .get_manageable_category_ids_by_user(user_id, guild_id)
+                    .await?
+                    .into_iter()
+                    .collect(),
+            )
+        };
+
+        let guild_category_ids = category_repo.get_ids_by_guild(guild_id).await?;
+        let can_stand_down_guild = can_manage_all(&guild_category_ids, manageable.as_ref());
+
+        let group_category_ids = category_repo.get_ids_by_ping_group(guild_id).await?;
+        let (groups, _) = PingGroupRepository::new(self.db)
+            .list_by_guild(guild_id, 0, PING_GROUP_LIMIT_PER_GUILD as u64)
+            .await?;
+        let group_names: HashMap<i32, String> = groups
+            .iter()
+            .map(|group| (group.id, group.name.clone()))
+            .collect();
+
+        let mut ping_groups: Vec<StandDownPingGroupDto> = groups
+            .into_iter()
+            .filter(|group| {
+                group_category_ids
+                    .get(&group.id)
+                    .is_some_and(|ids| can_manage_all(ids, manageable.as_ref()))
+            })
+            .map(|group| StandDownPingGroupDto {
+                id: group.id,
+                name: group.name,
+            })
+            .collect();
+        ping_groups.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let repo = StandDownRepository::new(self.db);
+        let stand_downs = repo
+            .get_recent_by_guild(guild_id, RECENT_STAND_DOWN_LIMIT)
+            .await?;
+        let fleet_counts = repo
+            .count_fleets(stand_downs.iter().map(|s| s.id).collect())
+            .await?;
+
+        let stand_downs = stand_downs
+            .into_iter()
+            .map(|stand_down| {
+                let ping_group_name = stand_down
+                    .ping_group_id
+                    .and_then(|id| group_names.get(&id).cloned());
+                let fleet_count = fleet_counts.get(&stand_down.id).copied().unwrap_or(0);
+                stand_down.into_dto(ping_group_name, fleet_count)
+            })
+            .collect();
+
+        Ok(StandDownOverviewDto {
+            can_stand_down_guild,
+            ping_groups,
+            stand_downs,
+        })
+    }
+
+    /// Stands down every fleet of a guild or ping group within a time window
+    ///
+    /// Snapshots & deletes the fleets within a single transaction, then posts a single
+    /// broadcast with the reason to every channel of the affected categories instead of
+    /// editing the messages of each fleet. Fleets relayed from another guild are
+    /// left to the guild they were relayed from, relayed copies of the cancelled fleets
+    /// are cancelled along with them.
+    ///
+    /// # Arguments
+    /// - `guild_id` - Discord ID of the guild
+    /// - `user_id` - Discord ID of the user calling the stand-down
+    /// - `is_admin` - Whether the user is an admin
+    /// - `dto` - Scope, time window & reason of the stand-down
+    ///
+    /// # Returns
+    /// - `Ok(StandDownDto)` - The recorded stand-down
+    /// - `Err(AppError::BadRequest(_))` - Invalid reason or window, or no fleets to cancel
+    /// - `Err(AppError::NotFound(_))` - Ping group not found in the guild
+    /// - `Err(AppError::AuthErr(AccessDenied))` - User can't manage every category of the scope
+    /// - `Err(AppError::Database(_))` - Database operation failed
+    pub async fn create(
+        &self,
+        guild_id: u64,
+        user_id: u64,
+        is_admin: bool,
+        dto: CreateStandDownDto,
+    ) -> Result<StandDownDto, AppError> {
+        let reason = validate_reason(&dto.reason)?;
+        let (window_start, window_end) =
+            parse_window(&dto.window_start, &dto.window_end, Utc::now())?;
+
+        let (category_ids, ping_group) = self
+            .authorize_scope(guild_id, dto.ping_group_id, user_id, is_admin)
+            .await?;
+
+        let fleet_repo = FleetRepository::new(self.db);
+
+        let fleets = fleet_repo
+            .get_by_guild_in_range(guild_id, window_start, window_end, Some(category_ids))
+            .await?;
+        let fleet_ids: Vec<i32> = fleets.iter().map(|fleet| fleet.id).collect();
+        let relayed_ids = RelayedFleetRepository::new(self.db)
+            .get_relayed_fleet_ids(fleet_ids.clone())
+            .await?;
+        let mut field_values = fleet_repo.get_field_values_by_fleets(fleet_ids).await?;
+
+        // Relayed copies are cancelled along with their source fleet in the other guild
+        let cancelled: Vec<_> = fleets
+            .into_iter()
+            .filter(|fleet| !relayed_ids.contains(&fleet.id))
+            .map(|fleet| {
+                let values = field_values.remove(&fleet.id).unwrap_or_default();
+                (fleet, values)
+            })
+            .collect();
+
+        if cancelled.is_empty() {
+            return Err(AppError::BadRequest(
+                "No fleets are scheduled within the stand-down window".to_string(),
+            ));
+        }
+
+        let fleet_count = cancelled.len();
+        let fleets: Vec<_> = cancelled.iter().map(|(fleet, _)| fleet.clone()).collect();
+
+        let fleet_service =
+            FleetService::new(self.db, self.discord_http.clone(), self.app_url.clone());
+        let prepared = fleet_service
+            .prepare_bulk_cancel(cancelled.clone(), guild_id)
+            .await?;
+
+        // The fleets are deleted in the transaction recording their snapshots, so they are
+        // only gone once they can be resumed
+        let stand_down = StandDownRepository::new(self.db)
+            .create(CreateStandDownParam {
+                guild_id,
+                ping_group_id: ping_group.as_ref().map(|group| group.id),
+                reason,
+                window_start,
+                window_end,
+                created_by: user_id,
+                fleets: cancelled,
+                deleted_fleet_ids: prepared.fleet_ids(),
+            })
+            .await?;
+
+        // The stand-down broadcast announces the cancellation to every affected channel
+        fleet_service
+            .finish_bulk_cancel(prepared, CancellationNotice::Silent)
+            .await;
+
+        if let Err(e) = self
+            .notification_service()
+            .post_stand_down(StandDownNotice::Called, &stand_down, &fleets, user_id)
+            .await
+        {
+            dioxus_logger::tracing::warn!(
+                "Failed to broadcast stand-down {}: {}",
+                stand_down.id,
+                e
+            );
+        }
+
+        Ok(stand_down.into_dto(ping_group.map(|group| group.name), fleet_count))
+    }
+
+    /// Resumes a stand-down, restoring its cancelled fleets which are still ahead
+    ///
+    /// Restored fleets are announced like newly created fleets and the lifted stand-down
+    /// is broadcast to the channels of their categories. Fleets whose time passed while
+    /// the stand-down was active stay cancelled.
+    ///
+    /// # Arguments
+    /// - `guild_id` - Discord ID of the guild
+    /// - `id` - ID of the stand-down to resume
+    /// - `user_id` - Discord ID of the user resuming the stand-down
+    /// - `is_admin` - Whether the user is an admin
+    ///
+    /// # Returns
+    /// - `Ok(StandDownDto)` - The resumed stand-down
+    /// - `Err(AppError::BadRequest(_))` - The stand-down was already resumed
+    /// - `Err(AppError::NotFound(_))` - Stand-down not found in the guild
+    /// - `Err(AppError::AuthErr(AccessDenied))` - User can't manage every category of the scope
+    /// - `Err(AppError::Database(_))` - Database operation failed
+    pub async fn resume(
+        &self,
+        guild_id: u64,
+        id: i32,
+        user_id: u64,
+        is_admin: bool,
+    ) -> Result<StandDownDto, AppError> {
+        let repo = StandDownRepository::new(self.db);
+
+        let mut stand_down = repo
+            .find_by_id(guild_id, id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Stand-down not found".to_string()))?;
+
+        // A removed ping group leaves the stand-down scoped to the whole guild
+        let (_, ping_group) = self
+            .authorize_scope(guild_id, stand_down.ping_group_id, user_id, is_admin)
+            .await?;
+
+        let now = Utc::now();
+        if stand_down.resumed_at.is_some() || !repo.mark_resumed(id, now).await? {
+            return Err(AppError::BadRequest(
+                "Stand-down was already resumed".to_string(),
+            ));
+        }
+        stand_down.resumed_at = Some(now);
+
+        let snapshots = repo.get_fleets(id).await?;
+        let fleet_count = snapshots.len();
+        let params = snapshots
+            .into_iter()
+            .filter(|snapshot| snapshot.fleet_time > now)
+            .map(|snapshot| snapshot.into_create_param())
+            .collect();
+
+        let restored = FleetService::new(self.db, self.discord_http.clone(), self.app_url.clone())
+            .bulk_restore(params)
+            .await?;
+
+        if !restored.is_empty() {
+            if let Err(e) = self
+                .notification_service()
+                .post_stand_down(StandDownNotice::Lifted, &stand_down, &restored, user_id)
+                .await
+            {
+                dioxus_logger::tracing::warn!(
+                    "Failed to broadcast lifted stand-down {}: {}",
+                    stand_down.id,
+                    e
+                );
+            }
+        }
+
+        Ok(stand_down.into_dto(ping_group.map(|group| group.name), fleet_count))
+    }
+
+    /// Resolves the categories of a stand-down scope & verifies the user manages all of them
+    ///
+    /// # Arguments
+    /// - `guild_id` - Discord ID of the guild
+    /// - `ping_group_id` - Ping group to stand down, None for the whole guild
+    /// - `user_id` - Discord ID of the user
+    /// - `is_admin` - Whether the user is an admin (bypasses permission checks)
+    ///
+    /// # Returns
+    /// - `Ok((Vec<i32>, Option<PingGroup>))` - IDs of the scope's categories & its ping group
+    /// - `Err(AppError::BadRequest(_))` - The scope has no categories
+    /// - `Err(AppError::NotFound(_))` - Ping group not found in the guild
+    /// - `Err(AppError::AuthErr(AccessDenied))` - User can't manage every category of the scope
+    /// - `Err(AppError::Database(_))` - Database operation failed
+    async fn authorize_scope(
+        &self,
+        guild_id: u64,
+        ping_group_id: Option<i32>,
+        user_id: u64,
+        is_admin: bool,
+    ) -> Result<(Vec<i32>, Option<PingGroup>), AppError> {
+        let category_repo = FleetCategoryRepository::new(self.db);
+
+        let (category_ids, ping_group) = match ping_group_id {
+            Some(ping_group_id) => {
+                let ping_group = PingGroupRepository::new(self.db)
+                    .find_by_id(guild_id, ping_group_id)
+                    .await?
+                    .ok_or_else(|| AppError::NotFound("Ping group not found".to_string()))?;
+                let category_ids = category_repo
+                    .get_ids_by_ping_group(guild_id)
+                    .await?
+                    .remove(&ping_group_id)
+                    .unwrap_or_default();
+
+                (category_ids, Some(ping_group))
+            }
+            None => (category_repo.get_ids_by_guild(guild_id).await?, None),
+        };
+
+        if category_ids.is_empty() {
+            return Err(AppError::BadRequest(
+                "There are no fleet categories to stand down".to_string(),
+            ));
+        }
+
+        if !is_admin {
+            let manageable: HashSet<i32> = UserCategoryPermissionRepository::new(self.db)
+                .get_manageable_category_ids_by_user(user_id, guild_id)
+                .await?
+                .into_iter()
+                .collect();
+
+            if !can_manage_all(&category_ids, Some(&manageable)) {
+                return Err(AppError::AuthErr(AuthError::AccessDenied(
+                    user_id,
+                    "Standing down requires manage permission for every category of the scope"
+                        .to_string(),
+                )));
+            }
+        }
+
+        Ok((category_ids, ping_group))
+    }
+
+    fn notification_service(&self) -> FleetNotificationService<'a> {
+        FleetNotificationService::new(self.db, self.discord_http.clone(), self.app_url.clone())
+    }
+}
+
+/// Returns whether a user can manage every one of the given categories
+///
+/// # Arguments
+/// - `category_ids` - Categories of the scope, an empty scope can't be managed
+/// - `manageable` - Categories the user can manage, None for admins who manage all
+fn can_manage_all(category_ids: &[i32], manageable: Option<&HashSet<i32>>) -> bool {
+    !category_ids.is_empty()
+        && manageable.is_none_or(|manageable| category_ids.iter().all(|id| manageable.contains(id)))
+}
+
+/// Trims the reason of a stand-down & verifies its length
+fn validate_reason(reason: &str) -> Result<String, AppError> {
+    let reason = reason.trim();
+
+    if reason.is_empty() {
+        return Err(AppError::BadRequest(
+            "A reason for the stand-down is required".to_string(),
+        ));
+    }
+
+    if reason.chars().count() > MAX_REASON_LENGTH {
+        return Err(AppError::BadRequest(format!(
+            "Stand-down reason can't be longer than {} characters",
+            MAX_REASON_LENGTH
+        )));
+    }
+
+    Ok(reason.to_string())
+}
+
+/// Parses & validates the time window of a stand-down
+///
+/// # Arguments
+/// - `start` - Window start in format "YYYY-MM-DD HH:MM" or "now" (case-insensitive)
+/// - `end` - Window end in format "YYYY-MM-DD HH:MM"
+/// - `now` - Current time
+///
+/// # Returns
+/// - `Ok((start, end))` - The parsed window
+/// - `Err(AppError::BadRequest(_))` - Invalid format, the window doesn't end in the future
+///   or spans more than `MAX_STAND_DOWN_WINDOW_DAYS`
+fn parse_window(
+    start: &str,
+    end: &str,
+    now: DateTime<Utc>,
+) -> Result<(DateTime<Utc>, DateTime<Utc>), AppError> {
+    let parse = |value: &str, label: &str| -> Result<DateTime<Utc>, AppError> {
+        if value.trim().eq_ignore_ascii_case("now") {
+            return Ok(now);
+        }
+
+        NaiveDateTime::parse_from_str(value.trim(), "%Y-%m-%d %H:%M")
+            .map(|naive| naive.and_utc())
+            .map_err(|e| {
+                AppError::BadRequest(format!(
+                    "Invalid window {}. Expected 'YYYY-MM-DD HH:MM' or 'now', got '{}': {}",
+                    label, value, e
+                ))
+            })
+    };
+
+    let start = parse(start, "start")?;
+    let end = parse(end, "end")?;
+
+    if end <= start {
+        return Err(AppError::BadRequest(
+            "Stand-down window must end after it starts".to_string(),
+        ));
+    }
+    if end <= now {
+        return Err(AppError::BadRequest(
+            "Stand-down window must end in the future".to_string(),
+        ));
+    }
+    if end - start > Duration::days(MAX_STAND_DOWN_WINDOW_DAYS) {
+        return Err(AppError::BadRequest(format!(
+            "Stand-down window can span at most {} days",
+            MAX_STAND_DOWN_WINDOW_DAYS
+        )));
+    }
+
+    Ok((start, end))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn now() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2026-01-15T18:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    /// Tests parsing a stand-down window starting now.
+    ///
+    /// Expected: Ok with the window starting at the current time
+    #[test]
+    fn parses_window_starting_now() {
+        let (start, end) = parse_window("now", "2026-01-16 02:00", now()).unwrap();
+
+        assert_eq!(start, now());
+        assert_eq!(end, now() + Duration::hours(8));
+    }
+
+    /// Tests rejecting invalid stand-down windows.
+    ///
+    /// Expected: Err(AppError::BadRequest) for a window ending before it starts, one
+    /// ending in the past, one spanning too long & an invalid format
+    #[test]
+    fn rejects_invalid_window() {
+        let invalid = [
+            ("2026-01-16 02:00", "2026-01-16 01:00"),
+            ("2026-01-14 12:00", "2026-01-15 12:00"),
+            ("now", "2026-01-30 18:00"),
+            ("now", "tomorrow"),
+        ];
+
+        for (start, end) in invalid {
+            assert!(
+                matches!(
+                    parse_window(start, end, now()),
+                    Err(AppError::BadRequest(_))
+                ),
+                "window {} - {} should be rejected",
+                start,
+                end
+            );
+        }
+    }
+
+    /// Tests which scopes a user can stand down.
+    ///
+    /// Expected: admins any non-empty scope, other users only scopes whose every
+    /// category they manage
+    #[test]
+    fn requires_manage_permission_for_every_category() {
+        let manageable = HashSet::from([1, 2]);
+
+        assert!(can_manage_all(&[1, 2, 3], None));
+        assert!(can_manage_all(&[1, 2], Some(&manageable)));
+        assert!(!can_manage_all(&[1, 3], Some(&manageable)));
+        assert!(!can_manage_all(&[], None));
+    }
+
+    /// Tests validating stand-down reasons.
+    ///
+    /// Expected: Ok with the trimmed reason, Err(AppError::BadRequest) for an empty &
+    /// an overly long reason
+    #[test]
+    fn validates_reason() {
+        assert_eq!(
+            validate_reason("  Hostile titans on grid  ").unwrap(),
+            "Hostile titans on grid"
+        );
+        assert!(matches!(
+            validate_reason("   "),
+            Err(AppError::BadRequest(_))
+        ));
+        assert!(matches!(
+            validate_reason(&"a".repeat(MAX_REASON_LENGTH + 1)),
+            Err(AppError::BadRequest(_))
+        ));
+    }
+}
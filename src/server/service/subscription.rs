@@ -0,0 +1,198 @@
+//! Subscription service for direct message fleet notifications.
+//!
+//! This module provides the `SubscriptionService` for managing the categories and fleets
+//! users subscribed to and their notification preferences. Permission checks for viewing
+//! a category or fleet are performed by the caller before subscribing; the service only
+//! verifies that the subscribed category or fleet belongs to the provided guild.
+
+use sea_orm::DatabaseConnection;
+
+use crate::{
+    model::subscription::{CategorySubscriptionDto, NotificationPreferenceDto, SubscriptionsDto},
+    server::{
+        data::{
+            category::FleetCategoryRepository, fleet::FleetRepository,
+            notification_preference::NotificationPreferenceRepository,
+            subscription::SubscriptionRepository,
+            user_category_permission::UserCategoryPermissionRepository,
+        },
+        error::AppError,
+        model::subscription::{
+            NotificationPreference, SubscriptionTarget, UpsertNotificationPreferenceParam,
+            MAX_REMINDER_MINUTES,
+        },
+        util::parse::parse_u64_from_string,
+    },
+};
+
+pub struct SubscriptionService<'a> {
+    db: &'a DatabaseConnection,
+}
+
+impl<'a> SubscriptionService<'a> {
+    pub fn new(db: &'a DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// Gets the notification preferences of a user
+    pub async fn get_preferences(&self, user_id: u64) -> Result<NotificationPreference, AppError> {
+        NotificationPreferenceRepository::new(self.db)
+            .get_by_user(user_id)
+            .await
+    }
+
+    /// Updates the notification preferences of a user
+    ///
+    /// # Arguments
+    /// - `user_id` - Discord ID of the user
+    /// - `dto` - The new preferences
+    ///
+    /// # Returns
+    /// - `Ok(NotificationPreference)` - The stored preferences
+    /// - `Err(AppError::BadRequest(_))` - Reminder lead time outside of the allowed range
+    /// - `Err(AppError::Database(_))` - Database operation failed
+    pub async fn update_preferences(
+        &self,
+        user_id: u64,
+        dto: NotificationPreferenceDto,
+    ) -> Result<NotificationPreference, AppError> {
+        if let Some(minutes) = dto.reminder_minutes {
+            if !(1..=MAX_REMINDER_MINUTES).contains(&minutes) {
+                return Err(AppError::BadRequest(format!(
+                    "Reminder must be between 1 and {} minutes before the fleet",
+                    MAX_REMINDER_MINUTES
+                )));
+            }
+        }
+
+        NotificationPreferenceRepository::new(self.db)
+            .upsert(UpsertNotificationPreferenceParam::from_dto(user_id, dto))
+            .await
+    }
+
+    /// Gets the subscriptions of a user within a guild
+    ///
+    /// Lists every category of the guild the user can view so the user can toggle their
+    /// subscription to each of them. Admins can view all categories.
+    ///
+    /// # Arguments
+    /// - `user_id` - Discord ID of the user
+    /// - `guild_id` - Discord ID of the guild
+    /// - `is_admin` - Whether the user is an admin
+    ///
+    /// # Returns
+    /// - `Ok(SubscriptionsDto)` - Viewable categories & subscribed fleet IDs
+    /// - `Err(AppError::Database(_))` - Database operation failed
+    pub async fn get_subscriptions(
+        &self,
+        user_id: u64,
+        guild_id: u64,
+        is_admin: bool,
+    ) -> Result<SubscriptionsDto, AppError> {
+        let repo = SubscriptionRepository::new(self.db);
+        let category_repo = FleetCategoryRepository::new(self.db);
+
+        let viewable_ids = if is_admin {
+            category_repo.get_ids_by_guild(guild_id).await?
+        } else {
+            UserCategoryPermissionRepository::new(self.db)
+                .get_viewable_category_ids_by_user(user_id, guild_id)
+                .await?
+        };
+        let names = category_repo.get_names_by_ids(viewable_ids).await?;
+        let subscribed_ids = repo.get_category_ids_by_user(user_id, guild_id).await?;
+
+        let mut categories = names
+            .into_iter()
+            .map(|(category_id, category_name)| CategorySubscriptionDto {
+                category_id,
+                category_name,
+                subscribed: subscribed_ids.contains(&category_id),
+            })
+            .collect::<Vec<_>>();
+        categories.sort_by(|a, b| a.category_name.cmp(&b.category_name));
+
+        Ok(SubscriptionsDto {
+            categories,
+            fleet_ids: repo.get_fleet_ids_by_user(user_id, guild_id).await?,
+        })
+    }
+
+    /// Subscribes a user to a category or fleet of a guild
+    ///
+    /// # Arguments
+    /// - `user_id` - Discord ID of the subscribing user
+    /// - `guild_id` - Discord ID of the guild the category or fleet belongs to
+    /// - `target` - The category or fleet to subscribe to
+    ///
+    /// # Returns
+    /// - `Ok(())` - The user is subscribed
+    /// - `Err(AppError::NotFound(_))` - Category or fleet doesn't exist in the guild
+    /// - `Err(AppError::Database(_))` - Database operation failed
+    pub async fn subscribe(
+        &self,
+        user_id: u64,
+        guild_id: u64,
+        target: SubscriptionTarget,
+    ) -> Result<(), AppError> {
+        let repo = SubscriptionRepository::new(self.db);
+
+        match target {
+            SubscriptionTarget::Category(category_id) => {
+                self.verify_category_guild(category_id, guild_id).await?;
+                repo.subscribe_category(user_id, category_id).await?;
+            }
+            SubscriptionTarget::Fleet(fleet_id) => {
+                let Some((fleet, _)) = FleetRepository::new(self.db).get_by_id(fleet_id).await?
+                else {
+                    return Err(AppError::NotFound("Fleet not found".to_string()));
+                };
+                self.verify_category_guild(fleet.category_id, guild_id)
+                    .await
+                    .map_err(|_| AppError::NotFound("Fleet not found".to_string()))?;
+                repo.subscribe_fleet(user_id, fleet_id).await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Unsubscribes a user from a category or fleet
+    ///
+    /// # Returns
+    /// - `Ok(true)` - The subscription was removed
+    /// - `Ok(false)` - The user wasn't subscribed
+    /// - `Err(AppError::Database(_))` - Database operation failed
+    pub async fn unsubscribe(
+        &self,
+        user_id: u64,
+        target: SubscriptionTarget,
+    ) -> Result<bool, AppError> {
+        let repo = SubscriptionRepository::new(self.db);
+
+        let removed = match target {
+            SubscriptionTarget::Category(category_id) => {
+                repo.unsubscribe_category(user_id, category_id).await?
+            }
+            SubscriptionTarget::Fleet(fleet_id) => {
+                repo.unsubscribe_fleet(user_id, fleet_id).await?
+            }
+        };
+
+        Ok(removed)
+    }
+
+    /// Verifies that a category exists and belongs to the guild
+    async fn verify_category_guild(&self, category_id: i32, guild_id: u64) -> Result<(), AppError> {
+        let category = FleetCategoryRepository::new(self.db)
+            .find_by_id(category_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Category not found".to_string()))?;
+
+        if parse_u64_from_string(category.category.guild_id)? != guild_id {
+            return Err(AppError::NotFound("Category not found".to_string()));
+        }
+
+        Ok(())
+    }
+}
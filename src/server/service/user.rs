@@ -155,8 +155,9 @@ impl<'a> UserService<'a> {
     /// Retrieves all guilds accessible to a user.
     ///
     /// Returns all Discord guilds (timerboards) that the user has access to based on their
-    /// permissions. Admin users receive all guilds in the system, while regular users only
-    /// receive guilds they are members of.
+    /// permissions. Admin users receive all active guilds in the system, while regular users
+    /// only receive active guilds they are members of. Guilds the bot was removed from are
+    /// managed from the admin servers list instead.
     ///
     /// # Arguments
     /// - `param` - Parameters containing the Discord user ID to check access for
@@ -176,9 +177,9 @@ impl<'a> UserService<'a> {
         let user = user_repo.find_by_id(param.discord_id).await?;
         let is_admin = user.map(|u| u.admin).unwrap_or(false);
 
-        // If admin, return all guilds; otherwise return only user's guilds
+        // If admin, return all active guilds; otherwise return only user's guilds
         let guilds = if is_admin {
-            guild_repo.get_all().await?
+            guild_repo.get_active().await?
         } else {
             guild_repo.get_guilds_for_user(param.discord_id).await?
         };
@@ -0,0 +1,599 @@
+//! Webhook service for outgoing fleet lifecycle event delivery.
+//!
+//! This module provides the `WebhookService` for managing per-guild webhook endpoints,
+//! queueing fleet events for delivery and delivering queued events. Emitting an event
+//! only records a pending delivery per subscribed webhook; the scheduler picks up due
+//! deliveries, signs the payload with the webhook's secret and posts it, retrying with
+//! a backoff until the endpoint responds with a 2xx status or attempts are exhausted.
+
+use chrono::{DateTime, Duration, Utc};
+use dioxus_logger::tracing;
+use hmac::{Hmac, Mac};
+use rand::Rng;
+use sea_orm::DatabaseConnection;
+use sha2::Sha256;
+use std::net::IpAddr;
+
+use crate::{
+    model::{
+        fleet::FleetDto,
+        webhook::{WebhookDeliveryStatus, WebhookEvent, WebhookPayloadDto},
+    },
+    server::{
+        data::{webhook::WebhookRepository, webhook_delivery::WebhookDeliveryRepository},
+        error::{internal::InternalError, AppError},
+        model::{
+            pagination::Page,
+            webhook::{
+                CreateWebhookDeliveryParam, CreateWebhookParam, UpdateWebhookParam, Webhook,
+                WebhookDelivery, WebhookDeliveryAttemptParam,
+            },
+        },
+    },
+};
+
+/// Maximum amount of webhooks that can be created per Discord guild
+const WEBHOOK_LIMIT_PER_GUILD: usize = 10;
+
+/// Maximum delivery attempts before a delivery is marked as failed
+const MAX_DELIVERY_ATTEMPTS: i32 = 5;
+
+/// Maximum amount of due deliveries processed per scheduler run
+const DELIVERY_BATCH_SIZE: u64 = 50;
+
+/// Timeout for a single delivery request
+const DELIVERY_TIMEOUT_SECONDS: u64 = 10;
+
+/// Time a claimed delivery stays reserved for its attempt before it is due again
+const DELIVERY_LEASE_SECONDS: i64 = 300;
+
+/// Maximum length of the stored error message for a failed attempt
+const MAX_ERROR_LENGTH: usize = 500;
+
+/// Length of generated webhook signing secrets
+const SECRET_LENGTH: usize = 40;
+
+pub struct WebhookService<'a> {
+    db: &'a DatabaseConnection,
+}
+
+impl<'a> WebhookService<'a> {
+    pub fn new(db: &'a DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// Creates a new webhook for a guild with a generated signing secret
+    ///
+    /// # Arguments
+    /// - `guild_id` - ID of the guild to create the webhook for
+    /// - `param` - Name, URL, subscribed events & enabled state of the webhook
+    ///
+    /// # Returns
+    /// - `Ok(Webhook)` - The created webhook including its secret
+    /// - `Err(AppError::BadRequest)` - Invalid webhook data or guild webhook limit reached
+    /// - `Err(AppError::Database)` - Database error during insert operation
+    pub async fn create(
+        &self,
+        guild_id: u64,
+        param: CreateWebhookParam,
+    ) -> Result<Webhook, AppError> {
+        validate_webhook(&param.name, &param.url, &param.events)?;
+
+        let repo = WebhookRepository::new(self.db);
+
+        let count = repo.count_by_guild(guild_id).await?;
+        if count >= WEBHOOK_LIMIT_PER_GUILD {
+            return Err(AppError::BadRequest(format!(
+                "Maximum webhook limit ({}) reached for this Discord server",
+                WEBHOOK_LIMIT_PER_GUILD
+            )));
+        }
+
+        repo.create(guild_id, generate_secret(), param).await
+    }
+
+    pub async fn list_by_guild(
+        &self,
+        guild_id: u64,
+        page: u64,
+        per_page: u64,
+    ) -> Result<Page<Webhook>, AppError> {
+        let (webhooks, total) = WebhookRepository::new(self.db)
+            .list_by_guild(guild_id, page, per_page)
+            .await?;
+
+        Ok(Page::new(webhooks, total, page, per_page))
+    }
+
+    pub async fn update(
+        &self,
+        guild_id: u64,
+        id: i32,
+        param: UpdateWebhookParam,
+    ) -> Result<Webhook, AppError> {
+        validate_webhook(&param.name, &param.url, &param.events)?;
+
+        WebhookRepository::new(self.db)
+            .update(guild_id, id, param)
+            .await
+    }
+
+    pub async fn delete(&self, guild_id: u64, id: i32) -> Result<(), AppError> {
+        WebhookRepository::new(self.db).delete(guild_id, id).await
+    }
+
+    /// Gets a page of the delivery log of a guild's webhook
+    ///
+    /// # Returns
+    /// - `Ok(Page<WebhookDelivery>)` - Deliveries of the webhook, most recent first
+    /// - `Err(AppError::NotFound)` - Webhook does not exist in the guild
+    /// - `Err(AppError::Database)` - Database error during fetch operation
+    pub async fn list_deliveries(
+        &self,
+        guild_id: u64,
+        webhook_id: i32,
+        page: u64,
+        per_page: u64,
+    ) -> Result<Page<WebhookDelivery>, AppError> {
+        self.require_webhook(guild_id, webhook_id).await?;
+
+        let (deliveries, total) = WebhookDeliveryRepository::new(self.db)
+            .list_by_webhook(webhook_id, page, per_page)
+            .await?;
+
+        Ok(Page::new(deliveries, total, page, per_page))
+    }
+
+    /// Requeues a delivery so the scheduler attempts it again with a fresh retry budget
+    ///
+    /// # Returns
+    /// - `Ok(WebhookDelivery)` - The requeued delivery
+    /// - `Err(AppError::NotFound)` - Webhook or delivery does not exist in the guild
+    /// - `Err(AppError::Database)` - Database error during update operation
+    pub async fn replay_delivery(
+        &self,
+        guild_id: u64,
+        webhook_id: i32,
+        delivery_id: i32,
+    ) -> Result<WebhookDelivery, AppError> {
+        self.require_webhook(guild_id, webhook_id).await?;
+
+        WebhookDeliveryRepository::new(self.db)
+            .requeue(webhook_id, delivery_id)
+            .await?
+            .ok_or_else(|| {
+                AppError::NotFound(format!(
+                    "Webhook delivery ID {} not found for webhook ID {}",
+                    delivery_id, webhook_id
+                ))
+            })
+    }
+
+    /// Queues a fleet event for delivery to every enabled webhook of the guild subscribed to it
+    ///
+    /// # Arguments
+    /// - `guild_id` - ID of the guild the fleet belongs to
+    /// - `event` - The fleet lifecycle event which occurred
+    /// - `fleet` - The fleet the event occurred for, sent as the payload body
+    ///
+    /// # Returns
+    /// - `Ok(usize)` - Number of deliveries queued
+    /// - `Err(AppError::Database)` - Database error while fetching webhooks or queueing
+    pub async fn emit(
+        &self,
+        guild_id: u64,
+        event: WebhookEvent,
+        fleet: FleetDto,
+    ) -> Result<usize, AppError> {
+        let webhooks: Vec<Webhook> = WebhookRepository::new(self.db)
+            .get_enabled_by_guild(guild_id)
+            .await?
+            .into_iter()
+            .filter(|webhook| webhook.is_subscribed(event))
+            .collect();
+
+        if webhooks.is_empty() {
+            return Ok(0);
+        }
+
+        let payload = serde_json::to_string(&WebhookPayloadDto {
+            event,
+            guild_id: guild_id.to_string(),
+            occurred_at: Utc::now(),
+            fleet,
+        })
+        .map_err(|e| InternalError::SerializeJson {
+            value: "webhook payload",
+            source: e,
+        })?;
+
+        let delivery_repo = WebhookDeliveryRepository::new(self.db);
+        for webhook in &webhooks {
+            delivery_repo
+                .create(CreateWebhookDeliveryParam {
+                    webhook_id: webhook.id,
+                    event,
+                    payload: payload.clone(),
+                })
+                .await?;
+        }
+
+        Ok(webhooks.len())
+    }
+
+    /// Attempts all pending deliveries which are due
+    ///
+    /// Each delivery is claimed before it is attempted so overlapping runs don't send it
+    /// twice. Failures of individual deliveries are recorded on the delivery and scheduled
+    /// for retry rather than returned.
+    ///
+    /// # Arguments
+    /// - `http_client` - HTTP client used to post payloads, should not follow redirects
+    ///
+    /// # Returns
+    /// - `Ok(usize)` - Number of deliveries attempted
+    /// - `Err(AppError::Database)` - Database error while fetching or updating deliveries
+    pub async fn process_due_deliveries(
+        &self,
+        http_client: &reqwest::Client,
+    ) -> Result<usize, AppError> {
+        let now = Utc::now();
+        let delivery_repo = WebhookDeliveryRepository::new(self.db);
+
+        let due = delivery_repo.get_due(now, DELIVERY_BATCH_SIZE).await?;
+        let lease_until = now + Duration::seconds(DELIVERY_LEASE_SECONDS);
+        let mut count = 0;
+
+        for (delivery, webhook) in due {
+            // Another run may have fetched the same delivery, only attempt it if claimed
+            if !delivery_repo.claim(delivery.id, now, lease_until).await? {
+                continue;
+            }
+            count += 1;
+
+            let attempt = if webhook.enabled {
+                attempt_delivery(http_client, &webhook, &delivery).await
+            } else {
+                WebhookDeliveryAttemptParam {
+                    status: WebhookDeliveryStatus::Failed,
+                    response_status: None,
+                    error: Some("Webhook is disabled".to_string()),
+                    next_attempt_at: None,
+                }
+            };
+
+            if attempt.status == WebhookDeliveryStatus::Failed {
+                tracing::warn!(
+                    "Webhook delivery {} of {} to webhook {} failed permanently: {}",
+                    delivery.id,
+                    delivery.event.as_str(),
+                    webhook.id,
+                    attempt.error.as_deref().unwrap_or("unknown error")
+                );
+            }
+
+            delivery_repo.record_attempt(delivery.id, attempt).await?;
+        }
+
+        Ok(count)
+    }
+
+    async fn require_webhook(&self, guild_id: u64, webhook_id: i32) -> Result<Webhook, AppError> {
+        WebhookRepository::new(self.db)
+            .find_by_id(guild_id, webhook_id)
+            .await?
+            .ok_or_else(|| {
+                AppError::NotFound(format!(
+                    "Webhook ID {} not found for guild ID {}",
+                    webhook_id, guild_id
+                ))
+            })
+    }
+}
+
+/// Computes the hex encoded HMAC-SHA256 signature of a payload
+///
+/// The signed message is `<timestamp>.<body>` so receivers can reject replayed
+/// requests by checking the timestamp header.
+///
+/// # Arguments
+/// - `secret` - The webhook's signing secret
+/// - `timestamp` - Unix timestamp sent in the `X-Timerboard-Timestamp` header
+/// - `body` - The exact request body
+///
+/// # Returns
+/// - `String` - Lowercase hex encoded signature
+pub fn sign_payload(secret: &str, timestamp: i64, body: &str) -> String {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes())
+        .expect("HMAC should accept keys of any length");
+    mac.update(timestamp.to_string().as_bytes());
+    mac.update(b".");
+    mac.update(body.as_bytes());
+
+    hex::encode(mac.finalize().into_bytes())
+}
+
+/// Posts a delivery's payload to its webhook & determines the resulting delivery state
+async fn attempt_delivery(
+    http_client: &reqwest::Client,
+    webhook: &Webhook,
+    delivery: &WebhookDelivery,
+) -> WebhookDeliveryAttemptParam {
+    let now = Utc::now();
+    let timestamp = now.timestamp();
+    let signature = sign_payload(&webhook.secret, timestamp, &delivery.payload);
+
+    if let Err(error) = check_resolved_host(&webhook.url).await {
+        return WebhookDeliveryAttemptParam {
+            status: WebhookDeliveryStatus::Failed,
+            response_status: None,
+            error: Some(error.chars().take(MAX_ERROR_LENGTH).collect()),
+            next_attempt_at: None,
+        };
+    }
+
+    let result = http_client
+        .post(&webhook.url)
+        .header(reqwest::header::CONTENT_TYPE, "application/json")
+        .header("X-Timerboard-Event", delivery.event.as_str())
+        .header("X-Timerboard-Delivery", delivery.id.to_string())
+        .header("X-Timerboard-Timestamp", timestamp.to_string())
+        .header("X-Timerboard-Signature", format!("sha256={}", signature))
+        .timeout(std::time::Duration::from_secs(DELIVERY_TIMEOUT_SECONDS))
+        .body(delivery.payload.clone())
+        .send()
+        .await;
+
+    let (response_status, error) = match result {
+        Ok(response) if response.status().is_success() => {
+            return WebhookDeliveryAttemptParam {
+                status: WebhookDeliveryStatus::Succeeded,
+                response_status: Some(response.status().as_u16() as i32),
+                error: None,
+                next_attempt_at: None,
+            };
+        }
+        Ok(response) => (
+            Some(response.status().as_u16() as i32),
+            format!("Endpoint responded with {}", response.status()),
+        ),
+        Err(e) => (None, e.to_string()),
+    };
+
+    let attempts = delivery.attempts + 1;
+    let next_attempt_at = next_attempt_at(now, attempts);
+
+    WebhookDeliveryAttemptParam {
+        status: if next_attempt_at.is_some() {
+            WebhookDeliveryStatus::Pending
+        } else {
+            WebhookDeliveryStatus::Failed
+        },
+        response_status,
+        error: Some(error.chars().take(MAX_ERROR_LENGTH).collect()),
+        next_attempt_at,
+    }
+}
+
+/// Determines when to retry a delivery after the given number of failed attempts
+///
+/// Uses a backoff of 1, 5, 30 & 120 minutes, returning `None` once the maximum
+/// amount of attempts has been reached.
+fn next_attempt_at(now: DateTime<Utc>, attempts: i32) -> Option<DateTime<Utc>> {
+    if attempts >= MAX_DELIVERY_ATTEMPTS {
+        return None;
+    }
+
+    let delay = match attempts {
+        1 => Duration::minutes(1),
+        2 => Duration::minutes(5),
+        3 => Duration::minutes(30),
+        _ => Duration::minutes(120),
+    };
+
+    Some(now + delay)
+}
+
+fn validate_webhook(name: &str, url: &str, events: &[WebhookEvent]) -> Result<(), AppError> {
+    if name.trim().is_empty() {
+        return Err(AppError::BadRequest("Webhook name is required".to_string()));
+    }
+
+    let parsed = url::Url::parse(url)
+        .map_err(|e| AppError::BadRequest(format!("Invalid webhook URL '{}': {}", url, e)))?;
+    if parsed.scheme() != "https" {
+        return Err(AppError::BadRequest(
+            "Webhook URL must use https".to_string(),
+        ));
+    }
+
+    let is_public_host = match parsed.host() {
+        Some(url::Host::Domain(domain)) => {
+            let domain = domain.trim_end_matches('.').to_ascii_lowercase();
+            domain != "localhost" && !domain.ends_with(".localhost")
+        }
+        Some(url::Host::Ipv4(ip)) => is_public_ip(IpAddr::V4(ip)),
+        Some(url::Host::Ipv6(ip)) => is_public_ip(IpAddr::V6(ip)),
+        None => false,
+    };
+    if !is_public_host {
+        return Err(AppError::BadRequest(
+            "Webhook URL must point to a public host".to_string(),
+        ));
+    }
+
+    if events.is_empty() {
+        return Err(AppError::BadRequest(
+            "Webhook must subscribe to at least one event".to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Checks whether an IP address is publicly routable
+///
+/// Webhooks must not reach loopback, private, link-local or other internal addresses
+/// of the network the server runs in.
+fn is_public_ip(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => {
+            let [a, b, ..] = ip.octets();
+            !(ip.is_private()
+                || ip.is_loopback()
+                || ip.is_link_local()
+                || ip.is_unspecified()
+                || ip.is_broadcast()
+                || ip.is_documentation()
+                || a == 0
+                // Carrier-grade NAT shared address space 100.64.0.0/10
+                || (a == 100 && (b & 0b1100_0000) == 64))
+        }
+        IpAddr::V6(ip) => {
+            if let Some(mapped) = ip.to_ipv4_mapped() {
+                return is_public_ip(IpAddr::V4(mapped));
+            }
+            let first = ip.segments()[0];
+            !(ip.is_loopback()
+                || ip.is_unspecified()
+                // Unique local fc00::/7 & link-local fe80::/10 addresses
+                || (first & 0xfe00) == 0xfc00
+                || (first & 0xffc0) == 0xfe80)
+        }
+    }
+}
+
+/// Resolves the host of a webhook URL & checks every address it resolves to is public
+///
+/// Guards against hostnames which resolve to internal addresses, which can't be caught
+/// when the webhook is saved.
+async fn check_resolved_host(url: &str) -> Result<(), String> {
+    let parsed = url::Url::parse(url).map_err(|e| format!("Invalid webhook URL: {}", e))?;
+    let host = parsed
+        .host_str()
+        .ok_or_else(|| "Webhook URL has no host".to_string())?
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .to_string();
+    let port = parsed.port_or_known_default().unwrap_or(443);
+
+    let addrs = tokio::net::lookup_host((host.as_str(), port))
+        .await
+        .map_err(|e| format!("Failed to resolve webhook host '{}': {}", host, e))?;
+
+    for addr in addrs {
+        if !is_public_ip(addr.ip()) {
+            return Err(format!(
+                "Webhook host '{}' resolves to non-public address {}",
+                host,
+                addr.ip()
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+fn generate_secret() -> String {
+    const CHARSET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ\
+                             abcdefghijklmnopqrstuvwxyz\
+                             0123456789";
+
+    let mut rng = rand::rng();
+
+    (0..SECRET_LENGTH)
+        .map(|_| {
+            let idx = rng.random_range(0..CHARSET.len());
+            CHARSET[idx] as char
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Tests signing a payload with a known secret.
+    ///
+    /// Verifies the signature is the HMAC-SHA256 of `<timestamp>.<body>` so
+    /// receivers can reproduce it with standard HMAC libraries.
+    ///
+    /// Expected: Ok with matching hex signature
+    #[test]
+    fn signs_timestamp_and_body() {
+        let signature = sign_payload("secret", 1700000000, r#"{"event":"fleet.created"}"#);
+
+        assert_eq!(
+            signature,
+            "86ec94debc63b2e2da04b5ea74b60d36b31fcc7f8b19431422f4732d5f082ed4"
+        );
+    }
+
+    /// Tests the retry backoff schedule.
+    ///
+    /// Verifies retries are spaced increasingly further apart and stop once the
+    /// maximum amount of attempts is reached.
+    ///
+    /// Expected: Ok with increasing delays then None
+    #[test]
+    fn backs_off_until_max_attempts() {
+        let now = Utc::now();
+
+        assert_eq!(next_attempt_at(now, 1), Some(now + Duration::minutes(1)));
+        assert_eq!(next_attempt_at(now, 2), Some(now + Duration::minutes(5)));
+        assert_eq!(next_attempt_at(now, 4), Some(now + Duration::minutes(120)));
+        assert_eq!(next_attempt_at(now, MAX_DELIVERY_ATTEMPTS), None);
+    }
+
+    /// Tests validating webhook URLs.
+    ///
+    /// Verifies only https URLs pointing at public hosts are accepted, so webhooks
+    /// can't be used to reach services on the server's internal network.
+    ///
+    /// Expected: Ok for public https URLs, BadRequest otherwise
+    #[test]
+    fn rejects_non_https_and_internal_urls() {
+        let events = WebhookEvent::ALL.to_vec();
+
+        assert!(validate_webhook("SRP", "https://example.com/hook", &events).is_ok());
+        assert!(validate_webhook("SRP", "https://1.1.1.1/hook", &events).is_ok());
+
+        for url in [
+            "http://example.com/hook",
+            "https://localhost/hook",
+            "https://api.localhost/hook",
+            "https://127.0.0.1/hook",
+            "https://10.0.0.5/hook",
+            "https://192.168.1.1/hook",
+            "https://169.254.169.254/latest/meta-data",
+            "https://100.64.0.1/hook",
+            "https://[::1]/hook",
+            "https://[fd00::1]/hook",
+            "https://[::ffff:127.0.0.1]/hook",
+        ] {
+            assert!(
+                matches!(
+                    validate_webhook("SRP", url, &events),
+                    Err(AppError::BadRequest(_))
+                ),
+                "{} should be rejected",
+                url
+            );
+        }
+    }
+
+    /// Tests generated secrets.
+    ///
+    /// Verifies secrets have the expected length and differ between calls.
+    ///
+    /// Expected: Ok with unique alphanumeric secrets
+    #[test]
+    fn generates_unique_secrets() {
+        let first = generate_secret();
+        let second = generate_secret();
+
+        assert_eq!(first.len(), SECRET_LENGTH);
+        assert!(first.chars().all(|c| c.is_ascii_alphanumeric()));
+        assert_ne!(first, second);
+    }
+}
@@ -0,0 +1,96 @@
+//! Session storage backed by the application database.
+//!
+//! The server runs on either SQLite or PostgreSQL depending on the scheme of the
+//! configured `DATABASE_URL`. This module provides `DatabaseSessionStore`, which wraps
+//! the matching `tower-sessions-sqlx-store` implementation so the session layer has a
+//! single concrete store type regardless of which backend was selected at runtime.
+
+use async_trait::async_trait;
+use sea_orm::{DatabaseBackend, DatabaseConnection};
+use tower_sessions::{
+    session::{Id, Record},
+    session_store, SessionStore,
+};
+use tower_sessions_sqlx_store::{PostgresStore, SqliteStore};
+
+use crate::server::error::AppError;
+
+/// Session store using the same database as the rest of the application.
+///
+/// Delegates every operation to the SQLx store of the active database backend.
+#[derive(Debug, Clone)]
+pub enum DatabaseSessionStore {
+    Sqlite(SqliteStore),
+    Postgres(PostgresStore),
+}
+
+impl DatabaseSessionStore {
+    /// Creates a session store for the backend of the provided connection.
+    ///
+    /// # Arguments
+    /// - `db` - Database connection whose underlying SQLx pool stores the sessions
+    ///
+    /// # Returns
+    /// - `Ok(DatabaseSessionStore)` - Store for the SQLite or PostgreSQL backend
+    /// - `Err(DbErr(_))` - Connection uses a backend without session store support
+    pub fn new(db: &DatabaseConnection) -> Result<Self, AppError> {
+        match db.get_database_backend() {
+            DatabaseBackend::Sqlite => Ok(Self::Sqlite(SqliteStore::new(
+                db.get_sqlite_connection_pool().clone(),
+            ))),
+            DatabaseBackend::Postgres => Ok(Self::Postgres(PostgresStore::new(
+                db.get_postgres_connection_pool().clone(),
+            ))),
+            backend => Err(sea_orm::DbErr::Custom(format!(
+                "Unsupported database backend for session storage: {:?}",
+                backend
+            ))
+            .into()),
+        }
+    }
+
+    /// Creates the session table if it doesn't exist yet.
+    ///
+    /// # Returns
+    /// - `Ok(())` - Session table is ready for use
+    /// - `Err(SqlxErr(_))` - Failed to create the session table
+    pub async fn migrate(&self) -> Result<(), AppError> {
+        match self {
+            Self::Sqlite(store) => store.migrate().await?,
+            Self::Postgres(store) => store.migrate().await?,
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl SessionStore for DatabaseSessionStore {
+    async fn create(&self, session_record: &mut Record) -> session_store::Result<()> {
+        match self {
+            Self::Sqlite(store) => store.create(session_record).await,
+            Self::Postgres(store) => store.create(session_record).await,
+        }
+    }
+
+    async fn save(&self, session_record: &Record) -> session_store::Result<()> {
+        match self {
+            Self::Sqlite(store) => store.save(session_record).await,
+            Self::Postgres(store) => store.save(session_record).await,
+        }
+    }
+
+    async fn load(&self, session_id: &Id) -> session_store::Result<Option<Record>> {
+        match self {
+            Self::Sqlite(store) => store.load(session_id).await,
+            Self::Postgres(store) => store.load(session_id).await,
+        }
+    }
+
+    async fn delete(&self, session_id: &Id) -> session_store::Result<()> {
+        match self {
+            Self::Sqlite(store) => store.delete(session_id).await,
+            Self::Postgres(store) => store.delete(session_id).await,
+        }
+    }
+}
@@ -6,6 +6,7 @@
 //! - Session storage configuration
 //! - HTTP client setup
 //! - OAuth2 client configuration
+//! - EVE static map loading
 //! - Admin user initialization
 //!
 //! All setup functions are called during server startup and must complete successfully
@@ -17,10 +18,11 @@ use oauth2::{AuthUrl, ClientId, ClientSecret, RedirectUrl, TokenUrl};
 use sea_orm::DatabaseConnection;
 use time::Duration;
 use tower_sessions::{cookie::SameSite, Expiry, SessionManagerLayer};
-use tower_sessions_sqlx_store::SqliteStore;
 
 use crate::server::data::user::UserRepository;
+use crate::server::eve_map::eve_map;
 use crate::server::service::admin::code::AdminCodeService;
+use crate::server::session_store::DatabaseSessionStore;
 use crate::server::state::OAuth2Client;
 use crate::server::{config::Config, error::AppError};
 
@@ -37,12 +39,15 @@ static SESSION_EXPIRY_DAYS: i64 = 7;
 /// within the time window will be granted admin privileges.
 static ADMIN_CODE_VALIDITY_SECONDS: u64 = 60;
 
-/// Connects to the SQLite database and runs pending migrations.
+/// Connects to the database and runs pending migrations.
 ///
-/// Establishes a connection pool to the SQLite database using the connection string
-/// from configuration, then automatically runs all pending SeaORM migrations to ensure
+/// Establishes a connection pool to the database using the connection string from
+/// configuration, then automatically runs all pending SeaORM migrations to ensure
 /// the database schema is up-to-date. SQL query logging is disabled to reduce noise.
 ///
+/// The backend is selected from the URL scheme: `sqlite://` connects to SQLite while
+/// `postgres://` or `postgresql://` connects to PostgreSQL.
+///
 /// This function must complete successfully before the application can access the
 /// database. Migration failures will prevent the application from starting.
 ///
@@ -55,14 +60,8 @@ static ADMIN_CODE_VALIDITY_SECONDS: u64 = 60;
 /// - `Err(DbErr(_))` - Failed to run migrations
 pub async fn connect_to_database(config: &Config) -> Result<DatabaseConnection, AppError> {
     use migration::{Migrator, MigratorTrait};
-    use sea_orm::{ConnectOptions, Database};
-
-    tracing::info!("Connecting to database: {}", config.database_url);
-
-    let mut opt = ConnectOptions::new(&config.database_url);
-    opt.sqlx_logging(false);
 
-    let db = Database::connect(opt).await?;
+    let db = open_database(&config.database_url).await?;
 
     tracing::info!("Running database migrations");
     Migrator::up(&db, None).await?;
@@ -72,9 +71,32 @@ pub async fn connect_to_database(config: &Config) -> Result<DatabaseConnection,
     Ok(db)
 }
 
-/// Creates a session manager using SQLite for session storage.
+/// Connects to the database without running migrations.
+///
+/// Used directly by the `migrate` CLI command, which has to control which migrations
+/// are applied or rolled back, and by `connect_to_database` before migrating.
+///
+/// # Arguments
+/// - `database_url` - SQLite or PostgreSQL connection URL
 ///
-/// Sets up session handling with SQLite as the backing store. Sessions expire after
+/// # Returns
+/// - `Ok(DatabaseConnection)` - Connected database
+/// - `Err(DbErr(_))` - Failed to connect to database
+pub async fn open_database(database_url: &str) -> Result<DatabaseConnection, AppError> {
+    use sea_orm::{ConnectOptions, Database};
+
+    tracing::info!("Connecting to database: {}", database_url);
+
+    let mut opt = ConnectOptions::new(database_url);
+    opt.sqlx_logging(false);
+
+    Ok(Database::connect(opt).await?)
+}
+
+/// Creates a session manager using the application database for session storage.
+///
+/// Sets up session handling with the SQLite or PostgreSQL database the application is
+/// connected to as the backing store. Sessions expire after
 /// 7 days of inactivity, requiring users to log in again. Cookie security settings
 /// are automatically configured based on build mode:
 /// - Release builds: Secure cookies enabled (HTTPS only)
@@ -87,16 +109,15 @@ pub async fn connect_to_database(config: &Config) -> Result<DatabaseConnection,
 ///
 /// # Returns
 /// - `Ok(SessionManagerLayer)` - Configured session manager ready for use
-/// - `Err(SqlxError(_))` - Failed to initialize session store
+/// - `Err(DbErr(_))` - Database backend doesn't support session storage
 /// - `Err(SqlxError(_))` - Failed to migrate session table
 pub async fn connect_to_session(
     db: &DatabaseConnection,
-) -> Result<SessionManagerLayer<SqliteStore>, AppError> {
+) -> Result<SessionManagerLayer<DatabaseSessionStore>, AppError> {
     tracing::info!("Initializing session storage");
 
-    // Get the underlying SQLx pool from SeaORM connection
-    let pool = db.get_sqlite_connection_pool();
-    let session_store = SqliteStore::new(pool.clone());
+    // Use the SQLx store matching the backend of the SeaORM connection
+    let session_store = DatabaseSessionStore::new(db)?;
 
     // Initialize the session table in the database
     session_store.migrate().await?;
@@ -146,6 +167,30 @@ pub fn setup_reqwest_client() -> reqwest::Client {
         .expect("HTTP client should build successfully")
 }
 
+/// Loads the bundled EVE static map.
+///
+/// Parses the solar systems & stargates compiled into the binary so malformed map data
+/// prevents startup rather than failing the first fleet using a solar system field.
+/// An empty map is allowed but logged as solar system fields can't be filled in.
+///
+/// # Panics
+/// - Panics if the bundled map fails to parse (would indicate a bad `eve-map import`)
+pub fn load_eve_map() {
+    let map = eve_map();
+
+    if map.system_count() == 0 {
+        tracing::warn!(
+            "Bundled EVE static map is empty, run `eve-map import` to enable solar system fields"
+        );
+    } else {
+        tracing::info!(
+            "Loaded EVE static map with {} solar systems & {} stargate connections",
+            map.system_count(),
+            map.stargate_count()
+        );
+    }
+}
+
 /// Sets up the OAuth2 client for Discord authentication.
 ///
 /// Creates a configured OAuth2 client for handling Discord login flow. The client
@@ -11,6 +11,8 @@
 //! - Admin code service for temporary admin access
 //! - Discord HTTP client for bot operations
 //! - Application URL for generating links
+//! - Metrics endpoint token
+//! - Data retention policy
 
 use oauth2::basic::{BasicErrorResponseType, BasicTokenType};
 use oauth2::{
@@ -22,7 +24,9 @@ use sea_orm::DatabaseConnection;
 use serenity::http::Http;
 use std::sync::Arc;
 
-use super::service::admin::code::AdminCodeService;
+use super::{
+    config::Config, model::retention::RetentionPolicy, service::admin::code::AdminCodeService,
+};
 
 /// Type alias for the OAuth2 client configured for Discord authentication.
 pub(crate) type OAuth2Client = Client<
@@ -89,6 +93,14 @@ pub struct AppState {
     /// Used to construct full URLs for OAuth2 callbacks, embed links, and
     /// other resources that need to reference the application.
     pub app_url: String,
+
+    /// Bearer token required to scrape `/metrics`.
+    ///
+    /// When None the metrics endpoint is disabled.
+    pub metrics_token: Option<String>,
+
+    /// Retention policy previewed by the admin retention endpoint.
+    pub retention: RetentionPolicy,
 }
 
 impl AppState {
@@ -104,7 +116,8 @@ impl AppState {
     /// - `oauth_client` - OAuth2 client for Discord authentication
     /// - `admin_code_service` - Service for managing admin codes
     /// - `discord_http` - Discord HTTP client for bot operations
-    /// - `app_url` - Application base URL
+    /// - `config` - Application configuration providing the app URL, metrics token and
+    ///   retention policy
     ///
     /// # Returns
     /// - `AppState` - Initialized application state ready for use
@@ -114,7 +127,7 @@ impl AppState {
         oauth_client: OAuth2Client,
         admin_code_service: AdminCodeService,
         discord_http: Arc<Http>,
-        app_url: String,
+        config: &Config,
     ) -> Self {
         Self {
             db,
@@ -122,7 +135,9 @@ impl AppState {
             oauth_client,
             admin_code_service,
             discord_http,
-            app_url,
+            app_url: config.app_url.clone(),
+            metrics_token: config.metrics_token.clone(),
+            retention: config.retention,
         }
     }
 }
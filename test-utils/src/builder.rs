@@ -1,12 +1,15 @@
 use entity::prelude::*;
 use sea_orm::{sea_query::TableCreateStatement, EntityTrait, Schema};
 
+/// Generates the CREATE TABLE statement of an entity for the schema's backend.
+type TableFactory = Box<dyn Fn(&Schema) -> TableCreateStatement>;
+
 use crate::{context::TestContext, error::TestError};
 
 /// Builder for creating test contexts with customizable database schemas.
 ///
 /// Provides a fluent interface for configuring test environments with in-memory SQLite
-/// databases. Use the builder pattern to add entity tables, then call `build()` to
+/// databases, or PostgreSQL when `TEST_DATABASE_URL` is set. Use the builder pattern to add entity tables, then call `build()` to
 /// create the configured test context.
 ///
 /// # Example
@@ -22,11 +25,12 @@ use crate::{context::TestContext, error::TestError};
 ///     .await?;
 /// ```
 pub struct TestBuilder {
-    /// Vector of CREATE TABLE statements to execute during database setup.
+    /// Factories for the CREATE TABLE statements to execute during database setup.
     ///
-    /// Each statement is generated from an entity model using SeaORM's schema builder.
-    /// Statements are executed in the order they were added during `build()`.
-    tables: Vec<TableCreateStatement>,
+    /// Each statement is generated from an entity model using SeaORM's schema builder
+    /// once the database backend is known. Statements are executed in the order they
+    /// were added during `build()`.
+    tables: Vec<TableFactory>,
 }
 
 impl TestBuilder {
@@ -43,8 +47,8 @@ impl TestBuilder {
 
     /// Adds an entity table to the test database schema.
     ///
-    /// Generates a CREATE TABLE statement from the provided SeaORM entity using SQLite
-    /// backend syntax. The table will be created when `build()` is called. Chain multiple
+    /// Generates a CREATE TABLE statement from the provided SeaORM entity using the syntax
+    /// of the test database backend. The table will be created when `build()` is called. Chain multiple
     /// calls to add multiple tables. Tables should be added in dependency order (tables
     /// with foreign keys should be added after their referenced tables).
     ///
@@ -54,8 +58,9 @@ impl TestBuilder {
     /// # Returns
     /// - `Self` - Builder instance for method chaining
     pub fn with_table<E: EntityTrait>(mut self, entity: E) -> Self {
-        let schema = Schema::new(sea_orm::DbBackend::Sqlite);
-        self.tables.push(schema.create_table_from_entity(entity));
+        self.tables.push(Box::new(move |schema| {
+            schema.create_table_from_entity(entity)
+        }));
         self
     }
 
@@ -128,8 +133,8 @@ impl TestBuilder {
 
     /// Builds and initializes the test context with configured tables.
     ///
-    /// Creates an in-memory SQLite database connection and executes all CREATE TABLE
-    /// statements that were added via `with_table()`. Tables are created in the order
+    /// Connects to the test database (in-memory SQLite unless `TEST_DATABASE_URL` is set)
+    /// and executes all CREATE TABLE statements that were added via `with_table()`. Tables are created in the order
     /// they were added to the builder.
     ///
     /// # Returns
@@ -138,7 +143,10 @@ impl TestBuilder {
     pub async fn build(self) -> Result<TestContext, TestError> {
         let mut setup = TestContext::new();
 
-        setup.with_tables(self.tables).await?;
+        let schema = Schema::new(setup.database().await?.get_database_backend());
+        let tables = self.tables.iter().map(|table| table(&schema)).collect();
+
+        setup.with_tables(tables).await?;
 
         Ok(setup)
     }
@@ -87,6 +87,30 @@ impl<'a> DiscordGuildFactory<'a> {
         self
     }
 
+    /// Sets the guild status.
+    ///
+    /// # Arguments
+    /// - `status` - Guild status (`active`, `removed` or `rejoined`)
+    ///
+    /// # Returns
+    /// - `Self` - Factory instance for method chaining
+    pub fn status(mut self, status: impl Into<String>) -> Self {
+        self.entity.status = status.into();
+        self
+    }
+
+    /// Sets when the bot was removed from the guild.
+    ///
+    /// # Arguments
+    /// - `removed_at` - Removal timestamp, None while active
+    ///
+    /// # Returns
+    /// - `Self` - Factory instance for method chaining
+    pub fn removed_at(mut self, removed_at: Option<chrono::DateTime<chrono::Utc>>) -> Self {
+        self.entity.removed_at = removed_at;
+        self
+    }
+
     /// Builds and inserts the guild entity into the database.
     ///
     /// # Returns
@@ -98,6 +122,8 @@ impl<'a> DiscordGuildFactory<'a> {
             name: ActiveValue::Set(self.entity.name),
             icon_hash: ActiveValue::Set(self.entity.icon_hash),
             last_sync_at: ActiveValue::Set(self.entity.last_sync_at),
+            status: ActiveValue::Set(self.entity.status),
+            removed_at: ActiveValue::Set(self.entity.removed_at),
         }
         .insert(self.db)
         .await
@@ -58,6 +58,15 @@ impl<'a> DiscordGuildChannelFactory<'a> {
         self
     }
 
+    /// Sets the channel kind.
+    ///
+    /// # Arguments
+    /// - `kind` - Channel kind (`text`, `announcement` or `forum`)
+    pub fn kind(mut self, kind: &str) -> Self {
+        self.entity.kind = kind.to_string();
+        self
+    }
+
     /// Builds and inserts the Discord guild channel entity.
     ///
     /// # Returns
@@ -69,6 +78,7 @@ impl<'a> DiscordGuildChannelFactory<'a> {
             channel_id: ActiveValue::Set(self.entity.channel_id),
             name: ActiveValue::Set(self.entity.name),
             position: ActiveValue::Set(self.entity.position),
+            kind: ActiveValue::Set(self.entity.kind),
         }
         .insert(self.db)
         .await
@@ -67,6 +67,24 @@ impl<'a> DiscordGuildRoleFactory<'a> {
         self
     }
 
+    /// Sets the role permissions bitfield.
+    ///
+    /// # Arguments
+    /// - `permissions` - Discord permissions bitfield
+    pub fn permissions(mut self, permissions: u64) -> Self {
+        self.entity.permissions = permissions.to_string();
+        self
+    }
+
+    /// Sets whether the role can be mentioned by anyone.
+    ///
+    /// # Arguments
+    /// - `mentionable` - Whether the role is mentionable
+    pub fn mentionable(mut self, mentionable: bool) -> Self {
+        self.entity.mentionable = mentionable;
+        self
+    }
+
     /// Builds and inserts the Discord guild role entity.
     ///
     /// # Returns
@@ -79,6 +97,8 @@ impl<'a> DiscordGuildRoleFactory<'a> {
             name: ActiveValue::Set(self.entity.name),
             color: ActiveValue::Set(self.entity.color),
             position: ActiveValue::Set(self.entity.position),
+            permissions: ActiveValue::Set(self.entity.permissions),
+            mentionable: ActiveValue::Set(self.entity.mentionable),
         }
         .insert(self.db)
         .await
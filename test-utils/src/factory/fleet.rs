@@ -120,6 +120,18 @@ impl<'a> FleetFactory<'a> {
         self
     }
 
+    /// Sets whether the fleet is archived.
+    ///
+    /// # Arguments
+    /// - `archived` - Whether the fleet has been archived by the retention policy
+    ///
+    /// # Returns
+    /// - `Self` - Factory instance for method chaining
+    pub fn archived(mut self, archived: bool) -> Self {
+        self.entity.archived = archived;
+        self
+    }
+
     /// Builds and inserts the fleet entity into the database.
     ///
     /// # Returns
@@ -136,6 +148,7 @@ impl<'a> FleetFactory<'a> {
             hidden: ActiveValue::Set(self.entity.hidden),
             disable_reminder: ActiveValue::Set(self.entity.disable_reminder),
             created_at: ActiveValue::Set(self.entity.created_at),
+            archived: ActiveValue::Set(self.entity.archived),
         }
         .insert(self.db)
         .await
@@ -122,6 +122,7 @@ impl<'a> FleetCategoryFactory<'a> {
             ping_cooldown: ActiveValue::Set(self.entity.ping_cooldown),
             ping_reminder: ActiveValue::Set(self.entity.ping_reminder),
             max_pre_ping: ActiveValue::Set(self.entity.max_pre_ping),
+            staging_system_id: ActiveValue::Set(None),
         }
         .insert(self.db)
         .await
@@ -88,7 +88,7 @@ impl<'a> PingFormatFieldFactory<'a> {
             ping_format_id: ActiveValue::Set(self.entity.ping_format_id),
             name: ActiveValue::Set(self.entity.name),
             priority: ActiveValue::Set(self.entity.priority),
-            field_type: ActiveValue::NotSet,
+            field_type: ActiveValue::Set(self.entity.field_type),
         }
         .insert(self.db)
         .await
@@ -15,6 +15,9 @@ pub const DEFAULT_NAME: &str = "Test Guild";
 /// Default icon hash for test guilds.
 pub const DEFAULT_ICON_HASH: Option<&str> = None;
 
+/// Default guild status.
+pub const DEFAULT_STATUS: &str = "active";
+
 /// Creates a Discord guild entity model with default values.
 ///
 /// This function creates an in-memory guild entity without inserting into the database.
@@ -25,6 +28,8 @@ pub const DEFAULT_ICON_HASH: Option<&str> = None;
 /// - name: `"Test Guild"`
 /// - icon_hash: `None`
 /// - last_sync_at: Current timestamp
+/// - status: `"active"`
+/// - removed_at: `None`
 ///
 /// # Returns
 /// - `discord_guild::Model` - In-memory guild entity
@@ -44,6 +49,8 @@ pub fn entity() -> discord_guild::Model {
         name: DEFAULT_NAME.to_string(),
         icon_hash: DEFAULT_ICON_HASH.map(|s| s.to_string()),
         last_sync_at: Utc::now(),
+        status: DEFAULT_STATUS.to_string(),
+        removed_at: None,
     }
 }
 
@@ -79,6 +86,8 @@ pub struct DiscordGuildEntityBuilder {
     name: String,
     icon_hash: Option<String>,
     last_sync_at: chrono::DateTime<Utc>,
+    status: String,
+    removed_at: Option<chrono::DateTime<Utc>>,
 }
 
 impl Default for DiscordGuildEntityBuilder {
@@ -88,6 +97,8 @@ impl Default for DiscordGuildEntityBuilder {
             name: DEFAULT_NAME.to_string(),
             icon_hash: DEFAULT_ICON_HASH.map(|s| s.to_string()),
             last_sync_at: Utc::now(),
+            status: DEFAULT_STATUS.to_string(),
+            removed_at: None,
         }
     }
 }
@@ -141,6 +152,30 @@ impl DiscordGuildEntityBuilder {
         self
     }
 
+    /// Sets the guild status.
+    ///
+    /// # Arguments
+    /// - `status` - Guild status (`active`, `removed` or `rejoined`)
+    ///
+    /// # Returns
+    /// - `Self` - Builder instance for method chaining
+    pub fn status(mut self, status: impl Into<String>) -> Self {
+        self.status = status.into();
+        self
+    }
+
+    /// Sets when the bot was removed from the guild.
+    ///
+    /// # Arguments
+    /// - `removed_at` - Removal timestamp, None while active
+    ///
+    /// # Returns
+    /// - `Self` - Builder instance for method chaining
+    pub fn removed_at(mut self, removed_at: Option<chrono::DateTime<Utc>>) -> Self {
+        self.removed_at = removed_at;
+        self
+    }
+
     /// Builds and returns the Discord guild entity model.
     ///
     /// # Returns
@@ -151,6 +186,8 @@ impl DiscordGuildEntityBuilder {
             name: self.name,
             icon_hash: self.icon_hash,
             last_sync_at: self.last_sync_at,
+            status: self.status,
+            removed_at: self.removed_at,
         }
     }
 }
@@ -166,6 +203,8 @@ mod tests {
         assert_eq!(guild.guild_id, DEFAULT_GUILD_ID);
         assert_eq!(guild.name, DEFAULT_NAME);
         assert!(guild.icon_hash.is_none());
+        assert_eq!(guild.status, DEFAULT_STATUS);
+        assert!(guild.removed_at.is_none());
     }
 
     #[test]
@@ -175,6 +214,8 @@ mod tests {
         assert_eq!(guild.guild_id, DEFAULT_GUILD_ID);
         assert_eq!(guild.name, DEFAULT_NAME);
         assert!(guild.icon_hash.is_none());
+        assert_eq!(guild.status, DEFAULT_STATUS);
+        assert!(guild.removed_at.is_none());
     }
 
     #[test]
@@ -183,11 +224,13 @@ mod tests {
             .guild_id("111222333")
             .name("Custom Guild")
             .icon_hash(Some("abcd1234".to_string()))
+            .status("removed")
             .build();
 
         assert_eq!(guild.guild_id, "111222333");
         assert_eq!(guild.name, "Custom Guild");
         assert_eq!(guild.icon_hash, Some("abcd1234".to_string()));
+        assert_eq!(guild.status, "removed");
     }
 
     #[test]
@@ -17,6 +17,9 @@ pub const DEFAULT_NAME: &str = "Test Channel";
 /// Default channel position.
 pub const DEFAULT_POSITION: i32 = 0;
 
+/// Default channel kind.
+pub const DEFAULT_KIND: &str = "text";
+
 /// Creates a Discord guild channel entity model with default values.
 ///
 /// This function creates an in-memory guild channel entity without inserting into the database.
@@ -27,6 +30,7 @@ pub const DEFAULT_POSITION: i32 = 0;
 /// - channel_id: `"111222333"`
 /// - name: `"Test Channel"`
 /// - position: `0`
+/// - kind: `"text"`
 ///
 /// # Returns
 /// - `discord_guild_channel::Model` - In-memory guild channel entity
@@ -46,6 +50,7 @@ pub fn entity() -> discord_guild_channel::Model {
         channel_id: DEFAULT_CHANNEL_ID.to_string(),
         name: DEFAULT_NAME.to_string(),
         position: DEFAULT_POSITION,
+        kind: DEFAULT_KIND.to_string(),
     }
 }
 
@@ -81,6 +86,7 @@ pub struct DiscordGuildChannelEntityBuilder {
     channel_id: String,
     name: String,
     position: i32,
+    kind: String,
 }
 
 impl Default for DiscordGuildChannelEntityBuilder {
@@ -90,6 +96,7 @@ impl Default for DiscordGuildChannelEntityBuilder {
             channel_id: DEFAULT_CHANNEL_ID.to_string(),
             name: DEFAULT_NAME.to_string(),
             position: DEFAULT_POSITION,
+            kind: DEFAULT_KIND.to_string(),
         }
     }
 }
@@ -143,6 +150,18 @@ impl DiscordGuildChannelEntityBuilder {
         self
     }
 
+    /// Sets the channel kind.
+    ///
+    /// # Arguments
+    /// - `kind` - Channel kind (`text`, `announcement` or `forum`)
+    ///
+    /// # Returns
+    /// - `Self` - Builder instance for method chaining
+    pub fn kind(mut self, kind: impl Into<String>) -> Self {
+        self.kind = kind.into();
+        self
+    }
+
     /// Builds and returns the Discord guild channel entity model.
     ///
     /// # Returns
@@ -153,6 +172,7 @@ impl DiscordGuildChannelEntityBuilder {
             channel_id: self.channel_id,
             name: self.name,
             position: self.position,
+            kind: self.kind,
         }
     }
 }
@@ -169,6 +189,7 @@ mod tests {
         assert_eq!(channel.channel_id, DEFAULT_CHANNEL_ID);
         assert_eq!(channel.name, DEFAULT_NAME);
         assert_eq!(channel.position, DEFAULT_POSITION);
+        assert_eq!(channel.kind, DEFAULT_KIND);
     }
 
     #[test]
@@ -179,6 +200,7 @@ mod tests {
         assert_eq!(channel.channel_id, DEFAULT_CHANNEL_ID);
         assert_eq!(channel.name, DEFAULT_NAME);
         assert_eq!(channel.position, DEFAULT_POSITION);
+        assert_eq!(channel.kind, DEFAULT_KIND);
     }
 
     #[test]
@@ -188,12 +210,14 @@ mod tests {
             .channel_id("555666777")
             .name("General")
             .position(5)
+            .kind("forum")
             .build();
 
         assert_eq!(channel.guild_id, "111222333");
         assert_eq!(channel.channel_id, "555666777");
         assert_eq!(channel.name, "General");
         assert_eq!(channel.position, 5);
+        assert_eq!(channel.kind, "forum");
     }
 
     #[test]
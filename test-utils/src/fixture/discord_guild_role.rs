@@ -20,6 +20,12 @@ pub const DEFAULT_COLOR: &str = "";
 /// Default role position.
 pub const DEFAULT_POSITION: i16 = 0;
 
+/// Default role permissions bitfield (no permissions).
+pub const DEFAULT_PERMISSIONS: &str = "0";
+
+/// Default role mentionability.
+pub const DEFAULT_MENTIONABLE: bool = false;
+
 /// Creates a Discord guild role entity model with default values.
 ///
 /// This function creates an in-memory guild role entity without inserting into the database.
@@ -31,6 +37,8 @@ pub const DEFAULT_POSITION: i16 = 0;
 /// - name: `"Test Role"`
 /// - color: `""` (empty string)
 /// - position: `0`
+/// - permissions: `"0"`
+/// - mentionable: `false`
 ///
 /// # Returns
 /// - `discord_guild_role::Model` - In-memory guild role entity
@@ -51,6 +59,8 @@ pub fn entity() -> discord_guild_role::Model {
         name: DEFAULT_NAME.to_string(),
         color: DEFAULT_COLOR.to_string(),
         position: DEFAULT_POSITION,
+        permissions: DEFAULT_PERMISSIONS.to_string(),
+        mentionable: DEFAULT_MENTIONABLE,
     }
 }
 
@@ -88,6 +98,8 @@ pub struct DiscordGuildRoleEntityBuilder {
     name: String,
     color: String,
     position: i16,
+    permissions: String,
+    mentionable: bool,
 }
 
 impl Default for DiscordGuildRoleEntityBuilder {
@@ -98,6 +110,8 @@ impl Default for DiscordGuildRoleEntityBuilder {
             name: DEFAULT_NAME.to_string(),
             color: DEFAULT_COLOR.to_string(),
             position: DEFAULT_POSITION,
+            permissions: DEFAULT_PERMISSIONS.to_string(),
+            mentionable: DEFAULT_MENTIONABLE,
         }
     }
 }
@@ -163,6 +177,30 @@ impl DiscordGuildRoleEntityBuilder {
         self
     }
 
+    /// Sets the role permissions bitfield.
+    ///
+    /// # Arguments
+    /// - `permissions` - Discord permissions bitfield as a decimal string
+    ///
+    /// # Returns
+    /// - `Self` - Builder instance for method chaining
+    pub fn permissions(mut self, permissions: impl Into<String>) -> Self {
+        self.permissions = permissions.into();
+        self
+    }
+
+    /// Sets whether the role can be mentioned by anyone.
+    ///
+    /// # Arguments
+    /// - `mentionable` - Whether the role is mentionable
+    ///
+    /// # Returns
+    /// - `Self` - Builder instance for method chaining
+    pub fn mentionable(mut self, mentionable: bool) -> Self {
+        self.mentionable = mentionable;
+        self
+    }
+
     /// Builds and returns the Discord guild role entity model.
     ///
     /// # Returns
@@ -174,6 +212,8 @@ impl DiscordGuildRoleEntityBuilder {
             name: self.name,
             color: self.color,
             position: self.position,
+            permissions: self.permissions,
+            mentionable: self.mentionable,
         }
     }
 }
@@ -191,6 +231,8 @@ mod tests {
         assert_eq!(role.name, DEFAULT_NAME);
         assert_eq!(role.color, DEFAULT_COLOR);
         assert_eq!(role.position, DEFAULT_POSITION);
+        assert_eq!(role.permissions, DEFAULT_PERMISSIONS);
+        assert_eq!(role.mentionable, DEFAULT_MENTIONABLE);
     }
 
     #[test]
@@ -212,6 +254,8 @@ mod tests {
             .name("Admin")
             .color("#FF0000")
             .position(10)
+            .permissions("2048")
+            .mentionable(true)
             .build();
 
         assert_eq!(role.guild_id, "111222333");
@@ -219,6 +263,8 @@ mod tests {
         assert_eq!(role.name, "Admin");
         assert_eq!(role.color, "#FF0000");
         assert_eq!(role.position, 10);
+        assert_eq!(role.permissions, "2048");
+        assert!(role.mentionable);
     }
 
     #[test]
@@ -0,0 +1,21 @@
+//! Doctrine fixtures providing EFT fittings for test data.
+//!
+//! Provides fittings in the EFT format pasted by users when creating doctrines. These
+//! are useful for testing fit parsing and doctrine persistence.
+
+/// EFT fitting of an armor kiting Muninn with a drone bay.
+pub const MUNINN_EFT: &str = "[Muninn, Muninn - Fleet]
+Damage Control II
+Gyrostabilizer II
+
+Large Shield Extender II
+
+720mm Howitzer Artillery II, Republic Fleet Phased Plasma M
+
+Hammerhead II x5";
+
+/// EFT fitting of a shield logistics Scimitar.
+pub const SCIMITAR_EFT: &str = "[Scimitar, Scimitar - Logi]
+Damage Control II
+
+Large Remote Shield Booster II";
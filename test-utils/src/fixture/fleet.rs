@@ -33,6 +33,7 @@ pub const DEFAULT_DISABLE_REMINDER: bool = false;
 /// - hidden: `false`
 /// - disable_reminder: `false`
 /// - created_at: Current timestamp
+/// - archived: `false`
 ///
 /// # Returns
 /// - `fleet::Model` - In-memory fleet entity
@@ -58,6 +59,7 @@ pub fn entity() -> fleet::Model {
         hidden: DEFAULT_HIDDEN,
         disable_reminder: DEFAULT_DISABLE_REMINDER,
         created_at: now,
+        archived: false,
     }
 }
 
@@ -98,6 +100,7 @@ pub struct FleetEntityBuilder {
     hidden: bool,
     disable_reminder: bool,
     created_at: chrono::DateTime<Utc>,
+    archived: bool,
 }
 
 impl Default for FleetEntityBuilder {
@@ -113,6 +116,7 @@ impl Default for FleetEntityBuilder {
             hidden: DEFAULT_HIDDEN,
             disable_reminder: DEFAULT_DISABLE_REMINDER,
             created_at: now,
+            archived: false,
         }
     }
 }
@@ -226,6 +230,18 @@ impl FleetEntityBuilder {
         self
     }
 
+    /// Sets whether the fleet is archived.
+    ///
+    /// # Arguments
+    /// - `archived` - Whether the fleet has been archived by the retention policy
+    ///
+    /// # Returns
+    /// - `Self` - Builder instance for method chaining
+    pub fn archived(mut self, archived: bool) -> Self {
+        self.archived = archived;
+        self
+    }
+
     /// Builds and returns the fleet entity model.
     ///
     /// # Returns
@@ -241,6 +257,7 @@ impl FleetEntityBuilder {
             hidden: self.hidden,
             disable_reminder: self.disable_reminder,
             created_at: self.created_at,
+            archived: self.archived,
         }
     }
 }
@@ -59,6 +59,7 @@ pub fn entity() -> fleet_category::Model {
         ping_cooldown: DEFAULT_PING_COOLDOWN,
         ping_reminder: DEFAULT_PING_REMINDER,
         max_pre_ping: DEFAULT_MAX_PRE_PING,
+        staging_system_id: None,
     }
 }
 
@@ -211,6 +212,7 @@ impl FleetCategoryEntityBuilder {
             ping_cooldown: self.ping_cooldown,
             ping_reminder: self.ping_reminder,
             max_pre_ping: self.max_pre_ping,
+            staging_system_id: None,
         }
     }
 }
@@ -29,6 +29,7 @@ pub mod discord_guild;
 pub mod discord_guild_channel;
 pub mod discord_guild_member;
 pub mod discord_guild_role;
+pub mod doctrine;
 pub mod fleet;
 pub mod fleet_category;
 pub mod ping_format;